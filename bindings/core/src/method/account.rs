@@ -1,6 +1,8 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::BTreeMap;
+
 #[cfg(feature = "participation")]
 use iota_sdk::{
     client::node_manager::node::Node,
@@ -13,7 +15,8 @@ use iota_sdk::{
         secret::GenerateAddressOptions,
     },
     types::block::{
-        output::{dto::OutputDto, OutputId, TokenId},
+        address::Bech32Address,
+        output::{dto::OutputDto, NftId, OutputId, TokenId},
         payload::transaction::TransactionId,
     },
     wallet::{
@@ -21,7 +24,7 @@ use iota_sdk::{
             CreateAliasParamsDto, FilterOptions, MintNativeTokenParamsDto, MintNftParamsDto, OutputParamsDto,
             OutputsToClaim, SyncOptions, TransactionOptionsDto,
         },
-        SendAmountParams, SendNativeTokensParams, SendNftParams,
+        AssetTransfer, SendAmountParams, SendDecimalNativeTokensParams, SendNativeTokensParams, SendNftParams,
     },
     U256,
 };
@@ -42,12 +45,29 @@ pub enum AccountMethod {
     /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
     #[serde(rename_all = "camelCase")]
     ClaimOutputs { output_ids_to_claim: Vec<OutputId> },
+    /// Consolidate all outputs, with multiple transactions if they don't fit into one.
+    /// Expected response: [`Transactions`](crate::Response::Transactions)
+    #[serde(rename_all = "camelCase")]
+    ConsolidateAllOutputs {
+        force: bool,
+        output_consolidation_threshold: Option<usize>,
+        #[serde(default)]
+        target_address: Option<Bech32Address>,
+        #[serde(default)]
+        split_native_tokens: bool,
+    },
     /// Removes a previously registered participation event from local storage.
     /// Expected response: [`Ok`](crate::Response::Ok)
     #[cfg(feature = "participation")]
     #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
     #[serde(rename_all = "camelCase")]
     DeregisterParticipationEvent { event_id: ParticipationEventId },
+    /// Estimate the amounts and storage deposits of a transaction, without locking any inputs.
+    /// Expected response: [`TransactionEstimate`](crate::Response::TransactionEstimate)
+    EstimateTransaction {
+        outputs: Vec<OutputDto>,
+        options: Option<TransactionOptionsDto>,
+    },
     /// Generate new unused addresses.
     /// Expected response: [`GeneratedAddress`](crate::Response::GeneratedAddress)
     GenerateAddresses {
@@ -61,6 +81,14 @@ pub enum AccountMethod {
     /// Expected response: [`Output`](crate::Response::Output)
     #[serde(rename_all = "camelCase")]
     GetFoundryOutput { token_id: TokenId },
+    /// Get the IRC30 metadata of a native token, the foundry is requested from the node if it isn't known yet
+    /// Expected response: [`NativeTokenMetadata`](crate::Response::NativeTokenMetadata)
+    #[serde(rename_all = "camelCase")]
+    GetNativeTokenMetadata { token_id: TokenId },
+    /// Get the decoded immutable metadata of an NFT owned by the account
+    /// Expected response: [`NftMetadata`](crate::Response::NftMetadata)
+    #[serde(rename_all = "camelCase")]
+    GetNftMetadata { nft_id: NftId },
     /// Get the transaction with inputs of an incoming transaction stored in the account
     /// List might not be complete, if the node pruned the data already
     /// Expected response: [`Transaction`](crate::Response::Transaction)
@@ -142,12 +170,20 @@ pub enum AccountMethod {
         burn: BurnDto,
         options: Option<TransactionOptionsDto>,
     },
+    /// Claim outputs.
+    /// Expected response: [`PreparedTransaction`](crate::Response::PreparedTransaction)
+    #[serde(rename_all = "camelCase")]
+    PrepareClaimOutputs { output_ids_to_claim: Vec<OutputId> },
     /// Consolidate outputs.
     /// Expected response: [`PreparedTransaction`](crate::Response::PreparedTransaction)
     #[serde(rename_all = "camelCase")]
     PrepareConsolidateOutputs {
         force: bool,
         output_consolidation_threshold: Option<usize>,
+        #[serde(default)]
+        target_address: Option<Bech32Address>,
+        #[serde(default)]
+        split_native_tokens: bool,
     },
     /// Create an alias output.
     /// Expected response: [`PreparedTransaction`](crate::Response::PreparedTransaction)
@@ -215,18 +251,36 @@ pub enum AccountMethod {
         params: Vec<SendAmountParams>,
         options: Option<TransactionOptionsDto>,
     },
+    /// Prepare to send base coins, native tokens and nfts to multiple addresses in one transaction.
+    /// Expected response: [`PreparedTransaction`](crate::Response::PreparedTransaction)
+    PrepareSendAssets {
+        transfers: Vec<AssetTransfer>,
+        options: Option<TransactionOptionsDto>,
+    },
     /// Prepare to Send native tokens.
     /// Expected response: [`PreparedTransaction`](crate::Response::PreparedTransaction)
     PrepareSendNativeTokens {
         params: Vec<SendNativeTokensParams>,
         options: Option<TransactionOptionsDto>,
     },
+    /// Prepare to send native tokens with decimal amounts.
+    /// Expected response: [`PreparedTransaction`](crate::Response::PreparedTransaction)
+    PrepareSendDecimalNativeTokens {
+        params: Vec<SendDecimalNativeTokensParams>,
+        options: Option<TransactionOptionsDto>,
+    },
     /// Prepare to Send nft.
     /// Expected response: [`PreparedTransaction`](crate::Response::PreparedTransaction)
     PrepareSendNft {
         params: Vec<SendNftParams>,
         options: Option<TransactionOptionsDto>,
     },
+    /// Sets an account's "voting power" to the given amount, by increasing or decreasing it as needed.
+    /// Expected response: [`PreparedTransaction`](crate::Response::PreparedTransaction), or
+    /// [`Ok`](crate::Response::Ok) if the voting power already equals the amount
+    #[cfg(feature = "participation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
+    PrepareSetVotingPower { amount: String },
     /// Stop participating for an event.
     /// Expected response: [`PreparedTransaction`](crate::Response::PreparedTransaction)
     #[cfg(feature = "participation")]
@@ -275,12 +329,30 @@ pub enum AccountMethod {
         params: Vec<SendAmountParams>,
         options: Option<TransactionOptionsDto>,
     },
+    /// Send base coins, native tokens and nfts to multiple addresses in one transaction.
+    /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
+    SendAssets {
+        transfers: Vec<AssetTransfer>,
+        options: Option<TransactionOptionsDto>,
+    },
     /// Send outputs in a transaction.
     /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
     SendOutputs {
         outputs: Vec<OutputDto>,
         options: Option<TransactionOptionsDto>,
     },
+    /// Set or remove the label of an address of the account.
+    /// Expected response: [`Address`](crate::Response::Address)
+    SetAddressLabel {
+        address: Bech32Address,
+        label: Option<String>,
+    },
+    /// Replace the metadata of an address of the account.
+    /// Expected response: [`Address`](crate::Response::Address)
+    SetAddressMetadata {
+        address: Bech32Address,
+        metadata: BTreeMap<String, String>,
+    },
     /// Set the alias of the account.
     /// Expected response: [`Ok`](crate::Response::Ok)
     SetAlias { alias: String },
@@ -288,6 +360,9 @@ pub enum AccountMethod {
     /// If storage is enabled, will persist during restarts.
     /// Expected response: [`Ok`](crate::Response::Ok)
     SetDefaultSyncOptions { options: SyncOptions },
+    /// Enable or disable the sequence numbers of sent transactions.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    SetTransactionSequencing { enabled: bool },
     /// Validate the transaction, sign it, submit it to a node and store it in the account.
     /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
     #[serde(rename_all = "camelCase")]
@@ -306,6 +381,16 @@ pub enum AccountMethod {
     SubmitAndStoreTransaction {
         signed_transaction_data: SignedTransactionDataDto,
     },
+    /// Send the entire balance of the account to an address, NFTs and aliases are only transferred if enabled.
+    /// Expected response: [`Transactions`](crate::Response::Transactions)
+    Sweep {
+        address: Bech32Address,
+        #[serde(default)]
+        nfts: bool,
+        #[serde(default)]
+        aliases: bool,
+        options: Option<TransactionOptionsDto>,
+    },
     /// Sync the account by fetching new information from the nodes. Will also retry pending transactions
     /// if necessary. A custom default can be set using SetDefaultSyncOptions.
     /// Expected response: [`Balance`](crate::Response::Balance)
@@ -320,4 +405,15 @@ pub enum AccountMethod {
     /// Expected response: [`OutputsData`](crate::Response::OutputsData)
     #[serde(rename_all = "camelCase")]
     UnspentOutputs { filter_options: Option<FilterOptions> },
+    /// Waits until all transactions sent by the account up to and including the sequence number are confirmed.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    #[serde(rename_all = "camelCase")]
+    WaitForSequenceConfirmed {
+        /// Sequence number of the last transaction to wait for
+        sequence_number: u64,
+        /// Interval
+        interval: Option<u64>,
+        /// Maximum attempts
+        max_attempts: Option<u64>,
+    },
 }
@@ -8,7 +8,7 @@ use iota_sdk::{
     client::{
         api::{
             ClientBlockBuilderOptions as BuildBlockOptions, GetAddressesBuilderOptions as GenerateAddressesOptions,
-            PreparedTransactionDataDto,
+            PreparedTransactionDataDto, StorageDepositParams,
         },
         node_api::indexer::query_parameters::QueryParameter,
         node_manager::node::NodeAuth,
@@ -17,12 +17,16 @@ use iota_sdk::{
     types::block::{
         address::{Bech32Address, Hrp},
         output::{
-            dto::{NativeTokenDto, TokenSchemeDto},
+            dto::{NativeTokenDto, OutputDto, TokenSchemeDto},
             feature::dto::FeatureDto,
             unlock_condition::dto::UnlockConditionDto,
             AliasId, FoundryId, NftId, OutputId,
         },
-        payload::{dto::PayloadDto, milestone::MilestoneId, transaction::TransactionId},
+        payload::{
+            dto::{MilestonePayloadDto, PayloadDto},
+            milestone::MilestoneId,
+            transaction::{dto::TransactionPayloadDto, TransactionId},
+        },
         BlockDto, BlockId,
     },
 };
@@ -129,6 +133,11 @@ pub enum ClientMethod {
     GetTipsInterval,
     /// Returns the protocol parameters
     GetProtocolParameters,
+    /// Returns the minimum storage deposit of a planned output, without building it
+    MinimumStorageDepositFor {
+        /// The description of the output
+        params: StorageDepositParams,
+    },
     /// Returns if local pow should be used or not
     GetLocalPow,
     /// Get fallback to local proof of work timeout
@@ -452,6 +461,54 @@ pub enum ClientMethod {
         /// Human readable part
         bech32_hrp: Option<Hrp>,
     },
+    /// Decodes and verifies a block from its prefix hex encoded bytes.
+    /// Expected response: [`Block`](crate::Response::Block)
+    DecodeBlock {
+        /// Hex encoded block bytes
+        hex: String,
+    },
+    /// Decodes and verifies a transaction payload from its prefix hex encoded bytes.
+    /// Expected response: [`TransactionPayload`](crate::Response::TransactionPayload)
+    DecodeTransactionPayload {
+        /// Hex encoded transaction payload bytes
+        hex: String,
+    },
+    /// Decodes and verifies a milestone payload from its prefix hex encoded bytes.
+    /// Expected response: [`Milestone`](crate::Response::Milestone)
+    DecodeMilestonePayload {
+        /// Hex encoded milestone payload bytes
+        hex: String,
+    },
+    /// Decodes and verifies an output from its prefix hex encoded bytes.
+    /// Expected response: [`Output`](crate::Response::Output)
+    DecodeOutput {
+        /// Hex encoded output bytes
+        hex: String,
+    },
+    /// Encodes a block to prefix hex encoded bytes.
+    /// Expected response: [`HexBytes`](crate::Response::HexBytes)
+    EncodeBlock {
+        /// Block
+        block: BlockDto,
+    },
+    /// Encodes a transaction payload to prefix hex encoded bytes.
+    /// Expected response: [`HexBytes`](crate::Response::HexBytes)
+    EncodeTransactionPayload {
+        /// Transaction payload
+        payload: TransactionPayloadDto,
+    },
+    /// Encodes a milestone payload to prefix hex encoded bytes.
+    /// Expected response: [`HexBytes`](crate::Response::HexBytes)
+    EncodeMilestonePayload {
+        /// Milestone payload
+        payload: MilestonePayloadDto,
+    },
+    /// Encodes an output to prefix hex encoded bytes.
+    /// Expected response: [`HexBytes`](crate::Response::HexBytes)
+    EncodeOutput {
+        /// Output
+        output: OutputDto,
+    },
     /// Requests funds for a given address from the faucet, for example `https://faucet.testnet.shimmer.network/api/enqueue` or `http://localhost:8091/api/enqueue`.
     RequestFundsFromFaucet {
         /// Faucet URL
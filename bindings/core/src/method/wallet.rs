@@ -1,17 +1,25 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-#[cfg(feature = "stronghold")]
 use std::path::PathBuf;
 
 use derivative::Derivative;
+#[cfg(feature = "participation")]
+use iota_sdk::types::api::plugins::participation::types::ParticipationEventId;
 #[cfg(feature = "events")]
 use iota_sdk::wallet::events::types::{WalletEvent, WalletEventType};
 use iota_sdk::{
-    client::{node_manager::node::NodeAuth, secret::GenerateAddressOptions},
-    types::block::address::Hrp,
+    client::{
+        api::{PreparedTransactionDataDto, SignedTransactionDataDto},
+        node_manager::node::NodeAuth,
+        secret::GenerateAddressOptions,
+    },
+    types::block::{
+        address::{Bech32Address, Hrp},
+        output::dto::OutputDto,
+    },
     wallet::{
-        account::{types::AccountIdentifier, SyncOptions},
+        account::{types::AccountIdentifier, SyncOptions, TransactionOptionsDto},
         ClientOptions,
     },
     Url,
@@ -35,6 +43,9 @@ pub enum WalletMethod {
         alias: Option<String>,
         /// The bech32 HRP.
         bech32_hrp: Option<Hrp>,
+        /// Addresses to create a watch-only account for.
+        #[serde(default)]
+        watch_only_addresses: Option<Vec<Bech32Address>>,
     },
     /// Read account.
     /// Expected response: [`Account`](crate::Response::Account)
@@ -46,6 +57,14 @@ pub enum WalletMethod {
     /// Read accounts.
     /// Expected response: [`Accounts`](crate::Response::Accounts)
     GetAccounts,
+    /// Get the participation overview of all accounts, summed up per event.
+    /// Expected response: [`WalletParticipationOverview`](crate::Response::WalletParticipationOverview)
+    #[cfg(feature = "participation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
+    #[serde(rename_all = "camelCase")]
+    GetParticipationOverview {
+        event_ids: Option<Vec<ParticipationEventId>>,
+    },
     /// Consume an account method.
     /// Returns [`Response`](crate::Response)
     #[serde(rename_all = "camelCase")]
@@ -55,6 +74,15 @@ pub enum WalletMethod {
         /// The account method to call.
         method: AccountMethod,
     },
+    /// Send a single transaction with inputs of multiple accounts.
+    /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
+    #[serde(rename_all = "camelCase")]
+    SendFromAccounts {
+        /// The accounts whose outputs can be used as inputs.
+        account_ids: Vec<AccountIdentifier>,
+        outputs: Vec<OutputDto>,
+        options: Option<TransactionOptionsDto>,
+    },
     /// Backup storage. Password must be the current one, when Stronghold is used as SecretManager.
     /// Expected response: [`Ok`](crate::Response::Ok)
     #[cfg(feature = "stronghold")]
@@ -130,6 +158,45 @@ pub enum WalletMethod {
         /// accounts will be restored.
         ignore_if_bech32_mismatch: Option<Hrp>,
     },
+    /// Restore a subset of the accounts of a Stronghold backup, adding them to the existing accounts instead of
+    /// replacing them. No account is restored if one conflicts with the accounts of the wallet.
+    /// Expected response: [`Accounts`](crate::Response::Accounts)
+    #[cfg(feature = "stronghold")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
+    #[serde(rename_all = "camelCase")]
+    RestoreAccountsFromBackup {
+        /// The path to the backed up Stronghold.
+        source: PathBuf,
+        /// Stronghold file password.
+        #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
+        password: String,
+        /// The accounts to restore, by their index, alias or id in the backup.
+        account_ids: Vec<AccountIdentifier>,
+    },
+    /// Import a Stronghold backup created by Firefly, importing the seed and recreating its accounts.
+    /// Returns an error if accounts were already created, if the Stronghold already contains a mnemonic or if its
+    /// password isn't the one of the backup.
+    /// Expected response: [`Accounts`](crate::Response::Accounts)
+    #[cfg(feature = "stronghold")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
+    ImportFireflyBackup {
+        /// The path to the Firefly Stronghold backup.
+        source: PathBuf,
+        /// Stronghold file password.
+        #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
+        password: String,
+    },
+    /// Opens a Stronghold backup and checks that it can be restored, without changing the wallet.
+    /// Expected response: [`BackupReport`](crate::Response::BackupReport)
+    #[cfg(feature = "stronghold")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
+    VerifyBackup {
+        /// The path to the backed up Stronghold.
+        source: PathBuf,
+        /// Stronghold file password.
+        #[derivative(Debug(format_with = "OmittedDebug::omitted_fmt"))]
+        password: String,
+    },
     /// Removes the latest account (account with the largest account index).
     /// Expected response: [`Ok`](crate::Response::Ok)
     RemoveLatestAccount,
@@ -189,6 +256,15 @@ pub enum WalletMethod {
     /// Stop background syncing.
     /// Expected response: [`Ok`](crate::Response::Ok)
     StopBackgroundSync,
+    /// Pause background syncing.
+    /// Expected response: [`BackgroundSyncStatus`](crate::Response::BackgroundSyncStatus)
+    PauseBackgroundSync,
+    /// Resume paused background syncing.
+    /// Expected response: [`BackgroundSyncStatus`](crate::Response::BackgroundSyncStatus)
+    ResumeBackgroundSync,
+    /// Get the status of background syncing.
+    /// Expected response: [`BackgroundSyncStatus`](crate::Response::BackgroundSyncStatus)
+    GetBackgroundSyncStatus,
     /// Emits an event for testing if the event system is working
     /// Expected response: [`Ok`](crate::Response::Ok)
     #[cfg(feature = "events")]
@@ -208,4 +284,26 @@ pub enum WalletMethod {
         /// Authentication options
         auth: Option<NodeAuth>,
     },
+    /// Writes a prepared transaction as versioned envelope with an integrity hash to a file, to be signed by an
+    /// offline signer.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    #[serde(rename_all = "camelCase")]
+    WritePreparedTransactionToFile {
+        prepared_transaction_data: PreparedTransactionDataDto,
+        path: PathBuf,
+    },
+    /// Reads a prepared transaction from an envelope file and verifies its integrity.
+    /// Expected response: [`PreparedTransaction`](crate::Response::PreparedTransaction)
+    ReadPreparedTransactionFromFile { path: PathBuf },
+    /// Writes a signed transaction as versioned envelope with an integrity hash to a file, to be submitted by an
+    /// online wallet.
+    /// Expected response: [`Ok`](crate::Response::Ok)
+    #[serde(rename_all = "camelCase")]
+    WriteSignedTransactionToFile {
+        signed_transaction_data: SignedTransactionDataDto,
+        path: PathBuf,
+    },
+    /// Reads a signed transaction from an envelope file and verifies its integrity.
+    /// Expected response: [`SignedTransactionData`](crate::Response::SignedTransactionData)
+    ReadSignedTransactionFromFile { path: PathBuf },
 }
@@ -16,8 +16,8 @@ use iota_sdk::{
     wallet::{
         account::{
             types::{AccountBalanceDto, TransactionDto},
-            Account, CreateAliasParams, OutputDataDto, OutputParams, PreparedMintTokenTransactionDto,
-            TransactionOptions,
+            Account, ConsolidationParams, CreateAliasParams, OutputDataDto, OutputParams,
+            PreparedMintTokenTransactionDto, SweepOptions, TransactionOptions,
         },
         MintNativeTokenParams, MintNftParams,
     },
@@ -41,20 +41,56 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             Response::SentTransaction(TransactionDto::from(&transaction))
         }
         #[cfg(feature = "participation")]
+        AccountMethod::ConsolidateAllOutputs {
+            force,
+            output_consolidation_threshold,
+            target_address,
+            split_native_tokens,
+        } => {
+            let transactions = account
+                .consolidate_all_outputs(ConsolidationParams {
+                    force,
+                    output_consolidation_threshold,
+                    target_address,
+                    split_native_tokens,
+                })
+                .await?;
+            Response::Transactions(transactions.iter().map(TransactionDto::from).collect())
+        }
         AccountMethod::DeregisterParticipationEvent { event_id } => {
             account.deregister_participation_event(&event_id).await?;
             Response::Ok
         }
+        AccountMethod::EstimateTransaction { outputs, options } => {
+            let token_supply = account.client().get_token_supply().await?;
+            let estimate = account
+                .estimate_transaction(
+                    outputs
+                        .iter()
+                        .map(|o| Ok(Output::try_from_dto(o, token_supply)?))
+                        .collect::<Result<Vec<Output>>>()?,
+                    options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                )
+                .await?;
+            Response::TransactionEstimate(estimate)
+        }
         AccountMethod::GenerateAddresses { amount, options } => {
             let address: Vec<iota_sdk::wallet::account::types::AccountAddress> =
                 account.generate_addresses(amount, options).await?;
             Response::GeneratedAddress(address)
         }
-        AccountMethod::GetBalance => Response::Balance(AccountBalanceDto::from(&account.balance().await?)),
+        AccountMethod::GetBalance => Response::Balance(
+            AccountBalanceDto::from(&account.balance().await?)
+                .with_native_token_metadata(&account.token_registry().await),
+        ),
         AccountMethod::GetFoundryOutput { token_id } => {
             let output = account.get_foundry_output(token_id).await?;
             Response::Output(OutputDto::from(&output))
         }
+        AccountMethod::GetNativeTokenMetadata { token_id } => {
+            Response::NativeTokenMetadata(account.native_token_metadata(token_id).await?)
+        }
+        AccountMethod::GetNftMetadata { nft_id } => Response::NftMetadata(account.nft_metadata(nft_id).await?),
         AccountMethod::GetIncomingTransaction { transaction_id } => {
             let transaction = account.get_incoming_transaction(&transaction_id).await;
 
@@ -136,12 +172,23 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
                 .await?;
             Response::PreparedTransaction(PreparedTransactionDataDto::from(&data))
         }
+        AccountMethod::PrepareClaimOutputs { output_ids_to_claim } => {
+            let data = account.prepare_claim_outputs(output_ids_to_claim).await?;
+            Response::PreparedTransaction(PreparedTransactionDataDto::from(&data))
+        }
         AccountMethod::PrepareConsolidateOutputs {
             force,
             output_consolidation_threshold,
+            target_address,
+            split_native_tokens,
         } => {
             let data = account
-                .prepare_consolidate_outputs(force, output_consolidation_threshold)
+                .prepare_consolidate_outputs(ConsolidationParams {
+                    force,
+                    output_consolidation_threshold,
+                    target_address,
+                    split_native_tokens,
+                })
                 .await?;
             Response::PreparedTransaction(PreparedTransactionDataDto::from(&data))
         }
@@ -249,6 +296,15 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
                 .await?;
             Response::PreparedTransaction(PreparedTransactionDataDto::from(&data))
         }
+        AccountMethod::PrepareSendAssets { transfers, options } => {
+            let data = account
+                .prepare_send_assets(
+                    transfers,
+                    options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                )
+                .await?;
+            Response::PreparedTransaction(PreparedTransactionDataDto::from(&data))
+        }
         AccountMethod::PrepareSendNativeTokens { params, options } => {
             let data = account
                 .prepare_send_native_tokens(
@@ -258,6 +314,15 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
                 .await?;
             Response::PreparedTransaction(PreparedTransactionDataDto::from(&data))
         }
+        AccountMethod::PrepareSendDecimalNativeTokens { params, options } => {
+            let data = account
+                .prepare_send_decimal_native_tokens(
+                    params,
+                    options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                )
+                .await?;
+            Response::PreparedTransaction(PreparedTransactionDataDto::from(&data))
+        }
         AccountMethod::PrepareSendNft { params, options } => {
             let data = account
                 .prepare_send_nft(
@@ -268,6 +333,17 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             Response::PreparedTransaction(PreparedTransactionDataDto::from(&data))
         }
         #[cfg(feature = "participation")]
+        AccountMethod::PrepareSetVotingPower { amount } => {
+            let data = account
+                .prepare_set_voting_power(
+                    u64::from_str(&amount).map_err(|_| iota_sdk::client::Error::InvalidAmount(amount.clone()))?,
+                )
+                .await?;
+            data.map_or(Response::Ok, |data| {
+                Response::PreparedTransaction(PreparedTransactionDataDto::from(&data))
+            })
+        }
+        #[cfg(feature = "participation")]
         AccountMethod::PrepareStopParticipating { event_id } => {
             let data = account.prepare_stop_participating(event_id).await?;
             Response::PreparedTransaction(PreparedTransactionDataDto::from(&data))
@@ -314,6 +390,15 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
                 .await?;
             Response::SentTransaction(TransactionDto::from(&transaction))
         }
+        AccountMethod::SendAssets { transfers, options } => {
+            let transaction = account
+                .send_assets(
+                    transfers,
+                    options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                )
+                .await?;
+            Response::SentTransaction(TransactionDto::from(&transaction))
+        }
         AccountMethod::SendOutputs { outputs, options } => {
             let token_supply = account.client().get_token_supply().await?;
             let transaction = account
@@ -327,6 +412,12 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
                 .await?;
             Response::SentTransaction(TransactionDto::from(&transaction))
         }
+        AccountMethod::SetAddressLabel { address, label } => {
+            Response::Address(account.set_address_label(&address, label).await?)
+        }
+        AccountMethod::SetAddressMetadata { address, metadata } => {
+            Response::Address(account.set_address_metadata(&address, metadata).await?)
+        }
         AccountMethod::SetAlias { alias } => {
             account.set_alias(&alias).await?;
             Response::Ok
@@ -335,6 +426,10 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             account.set_default_sync_options(options).await?;
             Response::Ok
         }
+        AccountMethod::SetTransactionSequencing { enabled } => {
+            account.set_transaction_sequencing(enabled).await?;
+            Response::Ok
+        }
         AccountMethod::SignAndSubmitTransaction {
             prepared_transaction_data,
         } => {
@@ -367,7 +462,28 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             let transaction = account.submit_and_store_transaction(signed_transaction_data).await?;
             Response::SentTransaction(TransactionDto::from(&transaction))
         }
-        AccountMethod::Sync { options } => Response::Balance(AccountBalanceDto::from(&account.sync(options).await?)),
+        AccountMethod::Sweep {
+            address,
+            nfts,
+            aliases,
+            options,
+        } => {
+            let transactions = account
+                .sweep(
+                    address,
+                    SweepOptions {
+                        nfts,
+                        aliases,
+                        transaction_options: options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                    },
+                )
+                .await?;
+            Response::Transactions(transactions.iter().map(TransactionDto::from).collect())
+        }
+        AccountMethod::Sync { options } => Response::Balance(
+            AccountBalanceDto::from(&account.sync(options).await?)
+                .with_native_token_metadata(&account.token_registry().await),
+        ),
         AccountMethod::Transactions => {
             let transactions = account.transactions().await;
             Response::Transactions(transactions.iter().map(TransactionDto::from).collect())
@@ -376,6 +492,16 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             let outputs = account.unspent_outputs(filter_options).await?;
             Response::OutputsData(outputs.iter().map(OutputDataDto::from).collect())
         }
+        AccountMethod::WaitForSequenceConfirmed {
+            sequence_number,
+            interval,
+            max_attempts,
+        } => {
+            account
+                .wait_for_sequence_confirmed(sequence_number, interval, max_attempts)
+                .await?;
+            Response::Ok
+        }
     };
     Ok(response)
 }
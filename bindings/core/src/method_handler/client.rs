@@ -9,6 +9,7 @@ use iota_sdk::{
     types::{
         api::core::response::OutputWithMetadataResponse,
         block::{
+            helper::RawBytes,
             input::dto::UtxoInputDto,
             output::{
                 dto::{OutputBuilderAmountDto, OutputDto, RentStructureDto},
@@ -16,6 +17,8 @@ use iota_sdk::{
             },
             payload::{
                 dto::{MilestonePayloadDto, PayloadDto},
+                milestone::MilestonePayload,
+                transaction::{dto::TransactionPayloadDto, TransactionPayload},
                 Payload,
             },
             protocol::dto::ProtocolParametersDto,
@@ -235,6 +238,9 @@ pub(crate) async fn call_client_method_internal(client: &Client, method: ClientM
             };
             Response::ProtocolParameters(protocol_response)
         }
+        ClientMethod::MinimumStorageDepositFor { params } => {
+            Response::MinimumRequiredStorageDeposit(client.minimum_storage_deposit_for(&params).await?.to_string())
+        }
         ClientMethod::GetLocalPow => Response::Bool(client.get_local_pow().await),
         ClientMethod::GetFallbackToLocalPow => Response::Bool(client.get_fallback_to_local_pow().await),
         ClientMethod::PrepareTransaction {
@@ -468,6 +474,32 @@ pub(crate) async fn call_client_method_internal(client: &Client, method: ClientM
         ClientMethod::HexPublicKeyToBech32Address { hex, bech32_hrp } => {
             Response::Bech32Address(client.hex_public_key_to_bech32_address(&hex, bech32_hrp).await?)
         }
+        ClientMethod::DecodeBlock { hex } => Response::Block(BlockDto::from(&Block::from_hex(
+            hex,
+            &client.get_protocol_parameters().await?,
+        )?)),
+        ClientMethod::DecodeTransactionPayload { hex } => Response::TransactionPayload(TransactionPayloadDto::from(
+            &TransactionPayload::from_hex(hex, &client.get_protocol_parameters().await?)?,
+        )),
+        ClientMethod::DecodeMilestonePayload { hex } => Response::Milestone(MilestonePayloadDto::from(
+            &MilestonePayload::from_hex(hex, &client.get_protocol_parameters().await?)?,
+        )),
+        ClientMethod::DecodeOutput { hex } => Response::Output(OutputDto::from(&Output::from_hex(
+            hex,
+            &client.get_protocol_parameters().await?,
+        )?)),
+        ClientMethod::EncodeBlock { block } => {
+            Response::HexBytes(Block::try_from_dto(&block, &client.get_protocol_parameters().await?)?.to_hex())
+        }
+        ClientMethod::EncodeTransactionPayload { payload } => Response::HexBytes(
+            TransactionPayload::try_from_dto(&payload, &client.get_protocol_parameters().await?)?.to_hex(),
+        ),
+        ClientMethod::EncodeMilestonePayload { payload } => Response::HexBytes(
+            MilestonePayload::try_from_dto(&payload, &client.get_protocol_parameters().await?)?.to_hex(),
+        ),
+        ClientMethod::EncodeOutput { output } => {
+            Response::HexBytes(Output::try_from_dto(&output, client.get_token_supply().await?)?.to_hex())
+        }
         ClientMethod::RequestFundsFromFaucet { url, address } => {
             Response::Faucet(request_funds_from_faucet(&url, &address).await?)
         }
@@ -37,9 +37,10 @@ pub(crate) async fn call_secret_manager_method_internal(
             prepared_transaction_data,
         } => {
             let payload = &secret_manager
-                .sign_transaction(PreparedTransactionData::try_from_dto_unverified(
-                    &prepared_transaction_data,
-                )?)
+                .sign_transaction(
+                    PreparedTransactionData::try_from_dto_unverified(&prepared_transaction_data)?,
+                    None,
+                )
                 .await?;
             Response::SignedTransaction(PayloadDto::from(payload))
         }
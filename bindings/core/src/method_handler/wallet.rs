@@ -3,7 +3,17 @@
 
 use std::time::Duration;
 
-use iota_sdk::wallet::{message_interface::dtos::AccountDetailsDto, wallet::Wallet};
+use iota_sdk::{
+    client::api::{
+        PreparedTransactionData, PreparedTransactionDataDto, SignedTransactionData, SignedTransactionDataDto,
+    },
+    types::block::output::Output,
+    wallet::{
+        account::{types::TransactionDto, TransactionOptions},
+        message_interface::dtos::AccountDetailsDto,
+        wallet::Wallet,
+    },
+};
 #[cfg(feature = "stronghold")]
 use zeroize::Zeroize;
 
@@ -13,7 +23,11 @@ use crate::{method::WalletMethod, response::Response, Result};
 /// Call a wallet method.
 pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletMethod) -> Result<Response> {
     let response = match method {
-        WalletMethod::CreateAccount { alias, bech32_hrp } => {
+        WalletMethod::CreateAccount {
+            alias,
+            bech32_hrp,
+            watch_only_addresses,
+        } => {
             let mut builder = wallet.create_account();
 
             if let Some(alias) = alias {
@@ -24,6 +38,10 @@ pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletM
                 builder = builder.with_bech32_hrp(bech32_hrp);
             }
 
+            if let Some(addresses) = watch_only_addresses {
+                builder = builder.with_watch_only_addresses(addresses);
+            }
+
             match builder.finish().await {
                 Ok(account) => {
                     let account = account.details().await;
@@ -54,10 +72,32 @@ pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletM
             }
             Response::Accounts(account_dtos)
         }
+        #[cfg(feature = "participation")]
+        WalletMethod::GetParticipationOverview { event_ids } => {
+            Response::WalletParticipationOverview(wallet.get_participation_overview(event_ids).await?)
+        }
         WalletMethod::CallAccountMethod { account_id, method } => {
             let account = wallet.get_account(account_id).await?;
             call_account_method_internal(&account, method).await?
         }
+        WalletMethod::SendFromAccounts {
+            account_ids,
+            outputs,
+            options,
+        } => {
+            let token_supply = wallet.client().get_token_supply().await?;
+            let transaction = wallet
+                .send_from_accounts(
+                    account_ids,
+                    outputs
+                        .iter()
+                        .map(|o| Ok(Output::try_from_dto(o, token_supply)?))
+                        .collect::<iota_sdk::wallet::Result<Vec<Output>>>()?,
+                    options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                )
+                .await?;
+            Response::SentTransaction(TransactionDto::from(&transaction))
+        }
         #[cfg(feature = "stronghold")]
         WalletMethod::Backup { destination, password } => {
             wallet.backup(destination, password).await?;
@@ -101,6 +141,20 @@ pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletM
             }
             Response::Accounts(account_dtos)
         }
+        #[cfg(feature = "stronghold")]
+        WalletMethod::ImportFireflyBackup { source, password } => {
+            let accounts = wallet.import_firefly_backup(source, password).await?;
+            let mut account_dtos = Vec::with_capacity(accounts.len());
+            for account in accounts {
+                let account = account.details().await;
+                account_dtos.push(AccountDetailsDto::from(&*account));
+            }
+            Response::Accounts(account_dtos)
+        }
+        #[cfg(feature = "stronghold")]
+        WalletMethod::VerifyBackup { source, password } => {
+            Response::BackupReport(wallet.verify_backup(source, password).await?)
+        }
         WalletMethod::RemoveLatestAccount => {
             wallet.remove_latest_account().await?;
             Response::Ok
@@ -122,6 +176,21 @@ pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletM
                 .await?;
             Response::Ok
         }
+        #[cfg(feature = "stronghold")]
+        WalletMethod::RestoreAccountsFromBackup {
+            source,
+            password,
+            account_ids,
+        } => {
+            let accounts = wallet
+                .restore_accounts_from_backup(source, password, account_ids)
+                .await?;
+            let mut account_dtos = Vec::with_capacity(accounts.len());
+            for account in accounts {
+                account_dtos.push(AccountDetailsDto::from(&*account.details().await));
+            }
+            Response::Accounts(account_dtos)
+        }
         WalletMethod::SetClientOptions { client_options } => {
             wallet.set_client_options(*client_options).await?;
             Response::Ok
@@ -177,6 +246,15 @@ pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletM
             wallet.stop_background_syncing().await?;
             Response::Ok
         }
+        WalletMethod::PauseBackgroundSync => {
+            wallet.pause_background_syncing();
+            Response::BackgroundSyncStatus(wallet.background_syncing_status())
+        }
+        WalletMethod::ResumeBackgroundSync => {
+            wallet.resume_background_syncing();
+            Response::BackgroundSyncStatus(wallet.background_syncing_status())
+        }
+        WalletMethod::GetBackgroundSyncStatus => Response::BackgroundSyncStatus(wallet.background_syncing_status()),
         #[cfg(feature = "events")]
         WalletMethod::EmitTestEvent { event } => {
             wallet.emit_test_event(event.clone()).await;
@@ -191,6 +269,38 @@ pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletM
             wallet.update_node_auth(url, auth).await?;
             Response::Ok
         }
+        WalletMethod::WritePreparedTransactionToFile {
+            prepared_transaction_data,
+            path,
+        } => {
+            PreparedTransactionData::try_from_dto(
+                &prepared_transaction_data,
+                &wallet.client().get_protocol_parameters().await?,
+            )?
+            .to_file(path)?;
+            Response::Ok
+        }
+        WalletMethod::ReadPreparedTransactionFromFile { path } => {
+            let prepared_transaction_data =
+                PreparedTransactionData::from_file(path, &wallet.client().get_protocol_parameters().await?)?;
+            Response::PreparedTransaction(PreparedTransactionDataDto::from(&prepared_transaction_data))
+        }
+        WalletMethod::WriteSignedTransactionToFile {
+            signed_transaction_data,
+            path,
+        } => {
+            SignedTransactionData::try_from_dto(
+                &signed_transaction_data,
+                &wallet.client().get_protocol_parameters().await?,
+            )?
+            .to_file(path)?;
+            Response::Ok
+        }
+        WalletMethod::ReadSignedTransactionFromFile { path } => {
+            let signed_transaction_data =
+                SignedTransactionData::from_file(path, &wallet.client().get_protocol_parameters().await?)?;
+            Response::SignedTransactionData(SignedTransactionDataDto::from(&signed_transaction_data))
+        }
     };
     Ok(response)
 }
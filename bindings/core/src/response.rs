@@ -7,6 +7,8 @@ use std::collections::HashSet;
 use derivative::Derivative;
 #[cfg(feature = "ledger_nano")]
 use iota_sdk::client::secret::LedgerNanoStatus;
+#[cfg(feature = "stronghold")]
+use iota_sdk::wallet::BackupReport;
 use iota_sdk::{
     client::{
         api::{PreparedTransactionDataDto, SignedTransactionDataDto},
@@ -33,7 +35,7 @@ use iota_sdk::{
             },
             payload::{
                 dto::{MilestonePayloadDto, PayloadDto},
-                transaction::TransactionId,
+                transaction::{dto::TransactionPayloadDto, TransactionId},
             },
             protocol::dto::ProtocolParametersDto,
             signature::dto::Ed25519SignatureDto,
@@ -44,9 +46,10 @@ use iota_sdk::{
     wallet::{
         account::{
             types::{AccountAddress, AccountBalanceDto, AddressWithUnspentOutputs, OutputDataDto, TransactionDto},
-            PreparedMintTokenTransactionDto,
+            Irc30Metadata, NftMetadata, PreparedMintTokenTransactionDto, TransactionEstimate,
         },
         message_interface::dtos::AccountDetailsDto,
+        BackgroundSyncStatus,
     },
 };
 use serde::Serialize;
@@ -54,6 +57,7 @@ use serde::Serialize;
 use {
     iota_sdk::types::api::plugins::participation::types::{ParticipationEventId, ParticipationEventStatus},
     iota_sdk::wallet::account::{AccountParticipationOverview, ParticipationEventWithNodes},
+    iota_sdk::wallet::WalletParticipationOverview,
     std::collections::HashMap,
 };
 
@@ -96,6 +100,9 @@ pub enum Response {
     /// - [`SignTransaction`](crate::method::ClientMethod::SignTransaction)
     SignedTransaction(PayloadDto),
     /// Response for:
+    /// - [`DecodeTransactionPayload`](crate::method::ClientMethod::DecodeTransactionPayload)
+    TransactionPayload(TransactionPayloadDto),
+    /// Response for:
     /// - [`SignatureUnlock`](crate::method::SecretManagerMethod::SignatureUnlock)
     SignatureUnlock(UnlockDto),
     /// Response for:
@@ -120,6 +127,7 @@ pub enum Response {
     /// Response for:
     /// - [`GetBlock`](crate::method::ClientMethod::GetBlock)
     /// - [`GetIncludedBlock`](crate::method::ClientMethod::GetIncludedBlock)
+    /// - [`DecodeBlock`](crate::method::ClientMethod::DecodeBlock)
     Block(BlockDto),
     /// Response for:
     /// - [`BuildAndPostBlock`](crate::method::ClientMethod::BuildAndPostBlock)
@@ -133,6 +141,12 @@ pub enum Response {
     /// - [`GetBlockRaw`](crate::method::ClientMethod::GetBlockRaw)
     BlockRaw(Vec<u8>),
     /// Response for:
+    /// - [`EncodeBlock`](crate::method::ClientMethod::EncodeBlock)
+    /// - [`EncodeTransactionPayload`](crate::method::ClientMethod::EncodeTransactionPayload)
+    /// - [`EncodeMilestonePayload`](crate::method::ClientMethod::EncodeMilestonePayload)
+    /// - [`EncodeOutput`](crate::method::ClientMethod::EncodeOutput)
+    HexBytes(String),
+    /// Response for:
     /// - [`GetOutput`](crate::method::ClientMethod::GetOutput)
     OutputWithMetadataResponse(OutputWithMetadataResponse),
     /// Response for:
@@ -146,6 +160,7 @@ pub enum Response {
     /// Response for:
     /// - [`GetMilestoneById`](crate::method::ClientMethod::GetMilestoneById)
     /// - [`GetMilestoneByIndex`](crate::method::ClientMethod::GetMilestoneByIndex)
+    /// - [`DecodeMilestonePayload`](crate::method::ClientMethod::DecodeMilestonePayload)
     Milestone(MilestonePayloadDto),
     /// Response for:
     /// - [`GetMilestoneByIdRaw`](crate::method::ClientMethod::GetMilestoneByIdRaw)
@@ -230,6 +245,7 @@ pub enum Response {
     /// - [`BuildNftOutput`](crate::method::ClientMethod::BuildNftOutput)
     /// - [`GetFoundryOutput`](crate::method::AccountMethod::GetFoundryOutput)
     /// - [`PrepareOutput`](crate::method::AccountMethod::PrepareOutput)
+    /// - [`DecodeOutput`](crate::method::ClientMethod::DecodeOutput)
     Output(OutputDto),
     /// Response for:
     /// - [`HexToBech32`](crate::method::ClientMethod::HexToBech32)
@@ -263,6 +279,11 @@ pub enum Response {
     /// - [`IsAddressValid`](crate::method::UtilsMethod::IsAddressValid)
     Bool(bool),
     /// Response for
+    /// - [`PauseBackgroundSync`](crate::method::WalletMethod::PauseBackgroundSync)
+    /// - [`ResumeBackgroundSync`](crate::method::WalletMethod::ResumeBackgroundSync)
+    /// - [`GetBackgroundSyncStatus`](crate::method::WalletMethod::GetBackgroundSyncStatus)
+    BackgroundSyncStatus(BackgroundSyncStatus),
+    /// Response for
     /// - [`Backup`](crate::method::WalletMethod::Backup),
     /// - [`ClearStrongholdPassword`](crate::method::WalletMethod::ClearStrongholdPassword),
     /// - [`DeregisterParticipationEvent`](crate::method::AccountMethod::DeregisterParticipationEvent),
@@ -278,6 +299,10 @@ pub enum Response {
     /// - [`EmitTestEvent`](crate::method::WalletMethod::EmitTestEvent),
     /// - [`ClearListeners`](crate::method::WalletMethod::ClearListeners)
     /// - [`StoreMnemonic`](crate::method::WalletMethod::StoreMnemonic)
+    /// - [`PrepareSetVotingPower`](crate::method::AccountMethod::PrepareSetVotingPower)
+    /// - [`WaitForSequenceConfirmed`](crate::method::AccountMethod::WaitForSequenceConfirmed)
+    /// - [`WritePreparedTransactionToFile`](crate::method::WalletMethod::WritePreparedTransactionToFile)
+    /// - [`WriteSignedTransactionToFile`](crate::method::WalletMethod::WriteSignedTransactionToFile)
     Ok,
     /// Response for any method that returns an error.
     Error(Error),
@@ -291,8 +316,19 @@ pub enum Response {
     Account(AccountDetailsDto),
     /// Response for [`GetAccountIndexes`](crate::method::WalletMethod::GetAccountIndexes)
     AccountIndexes(Vec<u32>),
-    /// Response for [`GetAccounts`](crate::method::WalletMethod::GetAccounts)
+    /// Response for
+    /// - [`GetAccounts`](crate::method::WalletMethod::GetAccounts),
+    /// - [`ImportFireflyBackup`](crate::method::WalletMethod::ImportFireflyBackup)
+    /// - [`RestoreAccountsFromBackup`](crate::method::WalletMethod::RestoreAccountsFromBackup)
     Accounts(Vec<AccountDetailsDto>),
+    /// Response for [`VerifyBackup`](crate::method::WalletMethod::VerifyBackup)
+    #[cfg(feature = "stronghold")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
+    BackupReport(BackupReport),
+    /// Response for
+    /// - [`SetAddressLabel`](crate::method::AccountMethod::SetAddressLabel)
+    /// - [`SetAddressMetadata`](crate::method::AccountMethod::SetAddressMetadata)
+    Address(AccountAddress),
     /// Response for [`Addresses`](crate::method::AccountMethod::Addresses)
     Addresses(Vec<AccountAddress>),
     /// Response for
@@ -300,6 +336,7 @@ pub enum Response {
     AddressesWithUnspentOutputs(Vec<AddressWithUnspentOutputs>),
     /// Response for
     /// - [`MinimumRequiredStorageDeposit`](crate::method::AccountMethod::MinimumRequiredStorageDeposit)
+    /// - [`MinimumStorageDepositFor`](crate::method::ClientMethod::MinimumStorageDepositFor)
     MinimumRequiredStorageDeposit(String),
     /// Response for
     /// - [`GetOutputsWithAdditionalUnlockConditions`](crate::method::AccountMethod::GetOutputsWithAdditionalUnlockConditions)
@@ -317,13 +354,16 @@ pub enum Response {
     /// [`PrepareDecreaseNativeTokenSupply`](crate::method::AccountMethod::PrepareDecreaseNativeTokenSupply)
     /// [`PrepareDecreaseVotingPower`](crate::method::AccountMethod::PrepareDecreaseVotingPower)
     /// [`PrepareIncreaseVotingPower`](crate::method::AccountMethod::PrepareIncreaseVotingPower)
+    /// [`PrepareSetVotingPower`](crate::method::AccountMethod::PrepareSetVotingPower)
     /// [`PrepareMintNfts`](crate::method::AccountMethod::PrepareMintNfts),
     /// [`PrepareSendAmount`](crate::method::AccountMethod::PrepareSendAmount),
     /// [`PrepareSendNativeTokens`](crate::method::AccountMethod::PrepareSendNativeTokens),
+    /// [`PrepareSendDecimalNativeTokens`](crate::method::AccountMethod::PrepareSendDecimalNativeTokens),
     /// [`PrepareSendNft`](crate::method::AccountMethod::PrepareSendNft),
     /// [`PrepareStopParticipating`](crate::method::AccountMethod::PrepareStopParticipating)
     /// [`PrepareTransaction`](crate::method::AccountMethod::PrepareTransaction)
     /// [`PrepareVote`](crate::method::AccountMethod::PrepareVote)
+    /// [`ReadPreparedTransactionFromFile`](crate::method::WalletMethod::ReadPreparedTransactionFromFile)
     PreparedTransaction(PreparedTransactionDataDto),
     /// Response for
     /// - [`PrepareIncreaseNativeTokenSupply`](crate::method::AccountMethod::PrepareIncreaseNativeTokenSupply),
@@ -336,10 +376,12 @@ pub enum Response {
     /// Response for
     /// - [`Transactions`](crate::method::AccountMethod::Transactions),
     /// - [`PendingTransactions`](crate::method::AccountMethod::PendingTransactions),
-    /// - [`IncomingTransactions`](crate::method::AccountMethod::IncomingTransactions)
+    /// - [`IncomingTransactions`](crate::method::AccountMethod::IncomingTransactions),
+    /// - [`Sweep`](crate::method::AccountMethod::Sweep)
     Transactions(Vec<TransactionDto>),
     /// Response for
     /// - [`SignTransactionEssence`](crate::method::AccountMethod::SignTransactionEssence)
+    /// - [`ReadSignedTransactionFromFile`](crate::method::WalletMethod::ReadSignedTransactionFromFile)
     SignedTransactionData(SignedTransactionDataDto),
     /// GenerateAddress response.
     /// Response for [`GenerateAddresses`](crate::method::AccountMethod::GenerateAddresses)
@@ -349,9 +391,19 @@ pub enum Response {
     /// - [`Sync`](crate::method::AccountMethod::Sync)
     Balance(AccountBalanceDto),
     /// Response for
+    /// - [`GetNativeTokenMetadata`](crate::method::AccountMethod::GetNativeTokenMetadata)
+    NativeTokenMetadata(Option<Irc30Metadata>),
+    /// Response for
+    /// - [`GetNftMetadata`](crate::method::AccountMethod::GetNftMetadata)
+    NftMetadata(Option<NftMetadata>),
+    /// Response for
+    /// - [`EstimateTransaction`](crate::method::AccountMethod::EstimateTransaction)
+    TransactionEstimate(TransactionEstimate),
+    /// Response for
     /// - [`ClaimOutputs`](crate::method::AccountMethod::ClaimOutputs)
     /// - [`SendAmount`](crate::method::AccountMethod::SendAmount)
     /// - [`SendOutputs`](crate::method::AccountMethod::SendOutputs)
+    /// - [`SendFromAccounts`](crate::method::WalletMethod::SendFromAccounts)
     /// - [`SignAndSubmitTransaction`](crate::method::AccountMethod::SignAndSubmitTransaction)
     /// - [`SubmitAndStoreTransaction`](crate::method::AccountMethod::SubmitAndStoreTransaction)
     SentTransaction(TransactionDto),
@@ -386,4 +438,9 @@ pub enum Response {
     #[cfg(feature = "participation")]
     #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
     AccountParticipationOverview(AccountParticipationOverview),
+    /// Response for
+    /// - [`GetParticipationOverview`](crate::method::WalletMethod::GetParticipationOverview)
+    #[cfg(feature = "participation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
+    WalletParticipationOverview(WalletParticipationOverview),
 }
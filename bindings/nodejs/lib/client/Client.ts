@@ -19,6 +19,7 @@ import type {
     AliasQueryParameter,
     OutputIdsResponse,
     IBip32Chain,
+    StorageDepositParams,
 } from '../types/client';
 import type {
     IUTXOInput,
@@ -35,6 +36,8 @@ import type {
     INodeInfoProtocol,
     UnlockTypes,
     HexEncodedString,
+    ITransactionPayload,
+    OutputTypes,
 } from '@iota/types';
 import type { INodeInfoWrapper } from '../types/client/nodeInfo';
 import { SecretManagerType } from '../types/secretManager/secretManager';
@@ -381,6 +384,23 @@ export class Client {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Returns the minimum storage deposit of a planned output without building it, for example to preview the
+     * deposit while the metadata is typed.
+     */
+    async minimumStorageDepositFor(
+        params: StorageDepositParams,
+    ): Promise<string> {
+        const response = await this.methodHandler.callMethod({
+            name: 'minimumStorageDepositFor',
+            data: {
+                params,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
     /**
      * Returns if local pow should be used or not.
      */
@@ -983,6 +1003,118 @@ export class Client {
         });
     }
 
+    /**
+     * Decode a block from its hex encoded packed bytes.
+     */
+    async decodeBlock(hex: HexEncodedString): Promise<IBlock> {
+        const response = await this.methodHandler.callMethod({
+            name: 'decodeBlock',
+            data: {
+                hex,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Decode a transaction payload from its hex encoded packed bytes.
+     */
+    async decodeTransactionPayload(hex: HexEncodedString): Promise<ITransactionPayload> {
+        const response = await this.methodHandler.callMethod({
+            name: 'decodeTransactionPayload',
+            data: {
+                hex,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Decode a milestone payload from its hex encoded packed bytes.
+     */
+    async decodeMilestonePayload(hex: HexEncodedString): Promise<IMilestonePayload> {
+        const response = await this.methodHandler.callMethod({
+            name: 'decodeMilestonePayload',
+            data: {
+                hex,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Decode an output from its hex encoded packed bytes.
+     */
+    async decodeOutput(hex: HexEncodedString): Promise<OutputTypes> {
+        const response = await this.methodHandler.callMethod({
+            name: 'decodeOutput',
+            data: {
+                hex,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Encode a block to its hex encoded packed bytes.
+     */
+    async encodeBlock(block: IBlock): Promise<HexEncodedString> {
+        const response = await this.methodHandler.callMethod({
+            name: 'encodeBlock',
+            data: {
+                block,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Encode a transaction payload to its hex encoded packed bytes.
+     */
+    async encodeTransactionPayload(payload: ITransactionPayload): Promise<HexEncodedString> {
+        const response = await this.methodHandler.callMethod({
+            name: 'encodeTransactionPayload',
+            data: {
+                payload,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Encode a milestone payload to its hex encoded packed bytes.
+     */
+    async encodeMilestonePayload(payload: IMilestonePayload): Promise<HexEncodedString> {
+        const response = await this.methodHandler.callMethod({
+            name: 'encodeMilestonePayload',
+            data: {
+                payload,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Encode an output to its hex encoded packed bytes.
+     */
+    async encodeOutput(output: OutputTypes): Promise<HexEncodedString> {
+        const response = await this.methodHandler.callMethod({
+            name: 'encodeOutput',
+            data: {
+                output,
+            },
+        });
+
+        return JSON.parse(response).payload;
+    }
+
     /**
      * Request funds from a faucet, for example `https://faucet.testnet.shimmer.network/api/enqueue` or `http://localhost:8091/api/enqueue`.
      */
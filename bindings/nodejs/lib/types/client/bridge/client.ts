@@ -1,4 +1,11 @@
-import type { HexEncodedString, IBlock, PayloadTypes } from '@iota/types';
+import type {
+    HexEncodedString,
+    IBlock,
+    IMilestonePayload,
+    ITransactionPayload,
+    OutputTypes,
+    PayloadTypes,
+} from '@iota/types';
 import type { SecretManagerType } from '../../secretManager/secretManager';
 import type { IGenerateAddressesOptions } from '../generateAddressesOptions';
 import type { IBuildBlockOptions } from '../buildBlockOptions';
@@ -14,6 +21,7 @@ import type {
     QueryParameter,
 } from '../queryParameters';
 import type { IAuth } from '../network';
+import type { StorageDepositParams } from '../storageDepositParams';
 import type { BasicOutputBuilderParams } from '../outputBuilderParams/basicOutputParams';
 import type { AliasOutputBuilderParams } from '../outputBuilderParams/aliasOutputParams';
 import type { FoundryOutputBuilderParams } from '../outputBuilderParams/foundryOutputParams';
@@ -161,6 +169,13 @@ export interface __GetProtocolParametersMethod__ {
     name: 'getProtocolParameters';
 }
 
+export interface __MinimumStorageDepositForMethod__ {
+    name: 'minimumStorageDepositFor';
+    data: {
+        params: StorageDepositParams;
+    };
+}
+
 export interface __GetLocalPowMethod__ {
     name: 'getLocalPow';
 }
@@ -429,6 +444,62 @@ export interface __ClearListenersMethod__ {
     };
 }
 
+export interface __DecodeBlockMethod__ {
+    name: 'decodeBlock';
+    data: {
+        hex: HexEncodedString;
+    };
+}
+
+export interface __DecodeTransactionPayloadMethod__ {
+    name: 'decodeTransactionPayload';
+    data: {
+        hex: HexEncodedString;
+    };
+}
+
+export interface __DecodeMilestonePayloadMethod__ {
+    name: 'decodeMilestonePayload';
+    data: {
+        hex: HexEncodedString;
+    };
+}
+
+export interface __DecodeOutputMethod__ {
+    name: 'decodeOutput';
+    data: {
+        hex: HexEncodedString;
+    };
+}
+
+export interface __EncodeBlockMethod__ {
+    name: 'encodeBlock';
+    data: {
+        block: IBlock;
+    };
+}
+
+export interface __EncodeTransactionPayloadMethod__ {
+    name: 'encodeTransactionPayload';
+    data: {
+        payload: ITransactionPayload;
+    };
+}
+
+export interface __EncodeMilestonePayloadMethod__ {
+    name: 'encodeMilestonePayload';
+    data: {
+        payload: IMilestonePayload;
+    };
+}
+
+export interface __EncodeOutputMethod__ {
+    name: 'encodeOutput';
+    data: {
+        output: OutputTypes;
+    };
+}
+
 export type __RequestFundsFromFaucetMethod__ = {
     name: 'requestFundsFromFaucet';
     data: {
@@ -21,6 +21,7 @@ import type {
     __GetMinPowScoreMethod__,
     __GetTipsIntervalMethod__,
     __GetProtocolParametersMethod__,
+    __MinimumStorageDepositForMethod__,
     __GetLocalPowMethod__,
     __GetFallbackToLocalPowMethod__,
     __GetHealthMethod__,
@@ -63,6 +64,14 @@ import type {
     __BuildNftOutputMethod__,
     __ClearListenersMethod__,
     __SignatureUnlockMethod__,
+    __DecodeBlockMethod__,
+    __DecodeTransactionPayloadMethod__,
+    __DecodeMilestonePayloadMethod__,
+    __DecodeOutputMethod__,
+    __EncodeBlockMethod__,
+    __EncodeTransactionPayloadMethod__,
+    __EncodeMilestonePayloadMethod__,
+    __EncodeOutputMethod__,
     __RequestFundsFromFaucetMethod__,
 } from './client';
 
@@ -90,6 +99,7 @@ export type __ClientMethods__ =
     | __GetMinPowScoreMethod__
     | __GetTipsIntervalMethod__
     | __GetProtocolParametersMethod__
+    | __MinimumStorageDepositForMethod__
     | __GetLocalPowMethod__
     | __GetFallbackToLocalPowMethod__
     | __GetHealthMethod__
@@ -131,4 +141,12 @@ export type __ClientMethods__ =
     | __BuildFoundryOutputMethod__
     | __BuildNftOutputMethod__
     | __ClearListenersMethod__
+    | __DecodeBlockMethod__
+    | __DecodeTransactionPayloadMethod__
+    | __DecodeMilestonePayloadMethod__
+    | __DecodeOutputMethod__
+    | __EncodeBlockMethod__
+    | __EncodeTransactionPayloadMethod__
+    | __EncodeMilestonePayloadMethod__
+    | __EncodeOutputMethod__
     | __RequestFundsFromFaucetMethod__;
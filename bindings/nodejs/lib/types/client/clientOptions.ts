@@ -1,5 +1,6 @@
 // Copyright 2021-2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
+import type { IRent } from '@iota/types';
 import type { IMqttBrokerOptions, INetworkInfo, INode } from './network';
 
 /** Options for the client builder */
@@ -22,6 +23,12 @@ export interface IClientOptions {
     minQuorumSize?: number;
     /** % of nodes that have to return the same response so it gets accepted */
     quorumThreshold?: number;
+    /** If reads like blocks, outputs and indexer queries are compared with multiple nodes, with the same
+     * minQuorumSize and quorumThreshold as the quorum
+     */
+    readQuorum?: boolean;
+    /** The policy that decides in which order the nodes are tried for a request */
+    nodeSelectionPolicy?: NodeSelectionPolicy;
     /** Data related to the used network */
     networkInfo?: INetworkInfo;
     /** Options for the MQTT broker */
@@ -34,6 +41,34 @@ export interface IClientOptions {
     powWorkerCount?: number;
     /** Whether the PoW should be done locally or remotely. */
     localPow?: boolean;
+    /** Options of the tip cache, tips are requested for every block if not set */
+    tipCache?: ITipCacheOptions;
+    /** Whether the client works without nodes, with the protocol parameters of the network info */
+    offline?: boolean;
+    /** Rent structure replacing the one of the protocol parameters, once the client is built */
+    rentStructure?: IRent;
+    /** The source of the local time, which is used for the time based unlock conditions */
+    timeSource?: TimeSource;
+}
+
+/** The source of the local time, the process wide time or a fixed unix timestamp in seconds */
+export type TimeSource =
+    | { type: 'system' }
+    | { type: 'fixed'; timestamp: number };
+
+/** The policy that decides in which order the nodes are tried for a request */
+export type NodeSelectionPolicy =
+    | 'primaryWithFallback'
+    | 'latencyWeighted'
+    | 'roundRobin'
+    | 'quorum';
+
+/** Options of the tip cache, which provides the parents of new blocks */
+export interface ITipCacheOptions {
+    /** How long cached tips are used before new tips are requested */
+    ttl: IDuration;
+    /** Whether the previously submitted block is used as one of the parents of the next block */
+    chainBlocks: boolean;
 }
 
 /** Time duration */
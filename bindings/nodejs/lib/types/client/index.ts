@@ -12,3 +12,4 @@ export * from './outputIdsResponse';
 export * from './preparedTransactionData';
 export * from './queryParameters';
 export * from './range';
+export * from './storageDepositParams';
@@ -0,0 +1,31 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+/** The kind of a planned output. */
+export type PlannedOutputKind = 'basic' | 'alias' | 'foundry' | 'nft';
+
+/** A lightweight description of an output to calculate its minimum storage deposit without building it. */
+export interface StorageDepositParams {
+    /** The kind of the output, defaults to `basic`. */
+    kind?: PlannedOutputKind;
+    /** The amount of different native tokens. */
+    nativeTokens?: number;
+    /** The length of the data of a metadata feature. */
+    metadataLength?: number;
+    /** The length of a tag feature, only allowed for basic and NFT outputs. */
+    tagLength?: number;
+    /** Whether the output has a sender feature, not allowed for foundry outputs. */
+    sender?: boolean;
+    /** Whether the output has an issuer feature, only allowed for alias and NFT outputs. */
+    issuer?: boolean;
+    /** The length of the data of an immutable metadata feature, not allowed for basic outputs. */
+    immutableMetadataLength?: number;
+    /** The length of the state metadata, ignored for other than alias outputs. */
+    stateMetadataLength?: number;
+    /** Whether the output has a storage deposit return unlock condition, only allowed for basic and NFT outputs. */
+    storageDepositReturn?: boolean;
+    /** Whether the output has a timelock unlock condition, only allowed for basic and NFT outputs. */
+    timelock?: boolean;
+    /** Whether the output has an expiration unlock condition, only allowed for basic and NFT outputs. */
+    expiration?: boolean;
+}
@@ -6,6 +6,7 @@ import type {
     __ComputeFoundryIdMethod__,
     __ParseBech32AddressMethod__,
     __BlockIdMethod__,
+    __TransactionIdMethod__,
     __Bech32ToHexMethod__,
     __HexToBech32Method__,
     __AliasIdToBech32Method__,
@@ -26,6 +27,7 @@ export type __UtilsMethods__ =
     | __ComputeFoundryIdMethod__
     | __ParseBech32AddressMethod__
     | __BlockIdMethod__
+    | __TransactionIdMethod__
     | __Bech32ToHexMethod__
     | __HexToBech32Method__
     | __AliasIdToBech32Method__
@@ -1,6 +1,7 @@
 import type {
     IBlock,
     ITransactionEssence,
+    ITransactionPayload,
     HexEncodedString,
     IEd25519Signature,
 } from '@iota/types';
@@ -54,6 +55,13 @@ export interface __BlockIdMethod__ {
     };
 }
 
+export interface __TransactionIdMethod__ {
+    name: 'transactionId';
+    data: {
+        payload: ITransactionPayload;
+    };
+}
+
 export interface __Bech32ToHexMethod__ {
     name: 'bech32ToHex';
     data: {
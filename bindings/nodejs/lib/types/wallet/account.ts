@@ -20,6 +20,8 @@ export interface AccountBalance {
     baseCoin: BaseCoinBalance;
     /** The required storage deposit for the outputs */
     requiredStorageDeposit: RequiredStorageDeposit;
+    /** The amount of the base coin that can be claimed now, without storage deposit returns */
+    claimable: string;
     /** The balance of the native tokens */
     nativeTokens: NativeTokenBalance[];
     /** Nft outputs */
@@ -33,6 +35,20 @@ export interface AccountBalance {
      * TimelockUnlockCondition or ExpirationUnlockCondition this can change at any time
      */
     potentiallyLockedOutputs: { [outputId: string]: boolean };
+    /** Accumulated staking rewards per event, from the participation plugin of the node during the last sync */
+    participationRewards: { [eventId: string]: ParticipationRewards };
+}
+
+/** The staking rewards of an event */
+export interface ParticipationRewards {
+    /** The accumulated rewards of all addresses of the account */
+    amount: string;
+    /** The symbol of the rewards */
+    symbol: string;
+    /** Whether the minimum rewards of the event are reached by at least one address */
+    minimumReached: boolean;
+    /** The milestone index the rewards were calculated for */
+    milestoneIndex: number;
 }
 
 /** The balance of the base coin */
@@ -59,8 +75,55 @@ export interface NativeTokenBalance {
     metadata?: string;
     total: HexEncodedAmount;
     available: HexEncodedAmount;
+    /** Total amount as decimal string, if the decimals of the token are known */
+    formattedTotal?: string;
+    /** Available amount as decimal string, if the decimals of the token are known */
+    formattedAvailable?: string;
+    /** Symbol from the IRC30 metadata of the token, if known */
+    symbol?: string;
+    /** Decimals from the IRC30 metadata of the token, if known */
+    decimals?: number;
+}
+
+/** IRC30 metadata of a native token, stored in the immutable metadata feature of its foundry */
+export interface Irc30Metadata {
+    standard: 'IRC30';
+    name: string;
+    symbol: string;
+    decimals: number;
+    description?: string;
+    url?: string;
+    logoUrl?: string;
+    logo?: string;
+}
+
+/** An attribute of an NFT with IRC27 metadata */
+export interface Irc27Attribute {
+    trait_type: string;
+    value: any;
 }
 
+/** IRC27 metadata of an NFT, stored in the immutable metadata feature of the NFT output */
+export interface Irc27Metadata {
+    standard: 'IRC27';
+    version: string;
+    /** The MIME type of the NFT file */
+    type: string;
+    uri: string;
+    name: string;
+    collectionName?: string;
+    /** Bech32 addresses that receive a share of a sale, with their share from 0 to 1 */
+    royalties?: { [address: string]: number };
+    issuerName?: string;
+    description?: string;
+    attributes?: Irc27Attribute[];
+}
+
+/** The decoded immutable metadata of an NFT, raw metadata is hex encoded */
+export type NftMetadata =
+    | { type: 'irc27'; data: Irc27Metadata }
+    | { type: 'raw'; data: string };
+
 /** Sync options for an account */
 export interface SyncOptions {
     /**
@@ -99,6 +162,11 @@ export interface SyncOptions {
     syncOnlyMostBasicOutputs?: boolean;
     /** Sync native token foundries, so their metadata can be returned in the balance. Default: false. */
     syncNativeTokenFoundries?: boolean;
+    /**
+     * Generate internal addresses while syncing until the account has at least this amount of unused internal
+     * addresses, so no address needs to be generated for remainders when preparing a transaction. Default: 0.
+     */
+    minUnusedInternalAddresses?: number;
 }
 
 /** Specifies what outputs should be synced for the ed25519 addresses from the account. */
@@ -142,6 +210,8 @@ export interface AccountMeta {
     incomingTransactions: {
         [transactionId: string]: [ITransactionPayload, IOutputResponse[]];
     };
+    /** Whether the account is watch-only */
+    watchOnly: boolean;
 }
 
 /** The account metadata */
@@ -158,6 +228,8 @@ export interface AccountMetadata {
 export interface CreateAccountPayload {
     alias?: string;
     bech32Hrp?: string;
+    /** Bech32 addresses to create a watch-only account for, which can't generate addresses or sign */
+    watchOnlyAddresses?: string[];
 }
 
 /** Options to filter outputs */
@@ -168,4 +240,12 @@ export interface FilterOptions {
     upperBoundBookedTimestamp?: number;
     /** Filter all outputs for the provided types (Basic = 3, Alias = 4, Foundry = 5, NFT = 6) */
     outputTypes?: Uint8Array;
+    /** Filter all outputs by their spent status */
+    isSpent?: boolean;
+    /** Filter all outputs where the spent milestone timestamp is below the specified timestamp */
+    lowerBoundSpentTimestamp?: number;
+    /** Filter all outputs where the spent milestone timestamp is above the specified timestamp */
+    upperBoundSpentTimestamp?: number;
+    /** Filter all outputs by whether they were claimed by the account */
+    claimed?: boolean;
 }
@@ -13,6 +13,8 @@ export interface AccountAddress {
     keyIndex: number;
     internal: boolean;
     used: boolean;
+    label?: string;
+    metadata: { [key: string]: string };
 }
 
 /** Address with a base token amount */
@@ -23,6 +25,15 @@ export interface SendAmountParams {
     expiration?: number;
 }
 
+/** Base coins, native tokens and an NFT to send to an address */
+export interface AssetTransfer {
+    address: string;
+    /** Amount of base coins */
+    amount?: string;
+    nativeTokens?: [string, HexEncodedAmount][];
+    nftId?: string;
+}
+
 /** Address with unspent outputs */
 export interface AddressWithUnspentOutputs {
     address: string;
@@ -39,6 +50,23 @@ export interface SendNativeTokensParams {
     expiration?: number;
 }
 
+/** Address with native tokens with decimal amounts */
+export interface SendDecimalNativeTokensParams {
+    address: string;
+    /** Native tokens with decimal amounts, referenced by token id or IRC30 symbol */
+    nativeTokens: DecimalNativeToken[];
+    returnAddress?: string;
+    expiration?: number;
+}
+
+/** A native token amount as decimal string */
+export interface DecimalNativeToken {
+    /** Token id or IRC30 symbol of the native token */
+    token: string;
+    /** Decimal amount, e.g. "1.5" */
+    amount: string;
+}
+
 /** Address with an NftId */
 export interface SendNftParams {
     address: string;
@@ -1,7 +1,9 @@
 import type { HexEncodedAmount } from '@iota/types';
 import type { SyncOptions, FilterOptions } from '../account';
 import type {
+    AssetTransfer,
     SendAmountParams,
+    SendDecimalNativeTokensParams,
     SendNativeTokensParams,
     SendNftParams,
     GenerateAddressOptions,
@@ -65,11 +67,30 @@ export type __ClaimOutputsMethod__ = {
     };
 };
 
+export type __ConsolidateAllOutputsMethod__ = {
+    name: 'consolidateAllOutputs';
+    data: {
+        force: boolean;
+        outputConsolidationThreshold?: number;
+        targetAddress?: string;
+        splitNativeTokens?: boolean;
+    };
+};
+
+export type __PrepareClaimOutputsMethod__ = {
+    name: 'prepareClaimOutputs';
+    data: {
+        outputIdsToClaim: string[];
+    };
+};
+
 export type __PrepareConsolidateOutputsMethod__ = {
     name: 'prepareConsolidateOutputs';
     data: {
         force: boolean;
         outputConsolidationThreshold?: number;
+        targetAddress?: string;
+        splitNativeTokens?: boolean;
     };
 };
 
@@ -130,6 +151,20 @@ export type __GetFoundryOutputMethod__ = {
     };
 };
 
+export type __GetNativeTokenMetadataMethod__ = {
+    name: 'getNativeTokenMetadata';
+    data: {
+        tokenId: string;
+    };
+};
+
+export type __GetNftMetadataMethod__ = {
+    name: 'getNftMetadata';
+    data: {
+        nftId: string;
+    };
+};
+
 export type __GetOutputsWithAdditionalUnlockConditionsMethod__ = {
     name: 'getOutputsWithAdditionalUnlockConditions';
     data: {
@@ -226,6 +261,14 @@ export type __PrepareSendAmountMethod__ = {
     };
 };
 
+export type __EstimateTransactionMethod__ = {
+    name: 'estimateTransaction';
+    data: {
+        outputs: Output[];
+        options?: TransactionOptions;
+    };
+};
+
 export type __PrepareTransactionMethod__ = {
     name: 'prepareTransaction';
     data: {
@@ -258,6 +301,22 @@ export type __SendAmountMethod__ = {
     };
 };
 
+export type __SendAssetsMethod__ = {
+    name: 'sendAssets';
+    data: {
+        transfers: AssetTransfer[];
+        options?: TransactionOptions;
+    };
+};
+
+export type __PrepareSendAssetsMethod__ = {
+    name: 'prepareSendAssets';
+    data: {
+        transfers: AssetTransfer[];
+        options?: TransactionOptions;
+    };
+};
+
 export type __PrepareSendNativeTokensMethod__ = {
     name: 'prepareSendNativeTokens';
     data: {
@@ -266,6 +325,14 @@ export type __PrepareSendNativeTokensMethod__ = {
     };
 };
 
+export type __PrepareSendDecimalNativeTokensMethod__ = {
+    name: 'prepareSendDecimalNativeTokens';
+    data: {
+        params: SendDecimalNativeTokensParams[];
+        options?: TransactionOptions;
+    };
+};
+
 export type __PrepareSendNftMethod__ = {
     name: 'prepareSendNft';
     data: {
@@ -282,6 +349,32 @@ export type __SendOutputsMethod__ = {
     };
 };
 
+export type __SweepMethod__ = {
+    name: 'sweep';
+    data: {
+        address: string;
+        nfts?: boolean;
+        aliases?: boolean;
+        options?: TransactionOptions;
+    };
+};
+
+export type __SetAddressLabelMethod__ = {
+    name: 'setAddressLabel';
+    data: {
+        address: string;
+        label?: string;
+    };
+};
+
+export type __SetAddressMetadataMethod__ = {
+    name: 'setAddressMetadata';
+    data: {
+        address: string;
+        metadata: { [key: string]: string };
+    };
+};
+
 export type __SetAliasMethod__ = {
     name: 'setAlias';
     data: {
@@ -296,6 +389,28 @@ export type __SetDefaultSyncOptionsMethod__ = {
     };
 };
 
+export type __SetTransactionSequencingMethod__ = {
+    name: 'setTransactionSequencing';
+    data: {
+        enabled: boolean;
+    };
+};
+
+export type __SetPrimaryAliasMethod__ = {
+    name: 'setPrimaryAlias';
+    data: {
+        aliasId?: string;
+    };
+};
+
+export type __GetPrimaryAliasMethod__ = {
+    name: 'getPrimaryAlias';
+};
+
+export type __GetPrimaryAddressMethod__ = {
+    name: 'getPrimaryAddress';
+};
+
 export type __SignTransactionEssenceMethod__ = {
     name: 'signTransactionEssence';
     data: {
@@ -385,3 +500,19 @@ export type __PrepareDecreaseVotingPowerMethod__ = {
         amount: string;
     };
 };
+
+export type __PrepareSetVotingPowerMethod__ = {
+    name: 'prepareSetVotingPower';
+    data: {
+        amount: string;
+    };
+};
+
+export type __WaitForSequenceConfirmedMethod__ = {
+    name: 'waitForSequenceConfirmed';
+    data: {
+        sequenceNumber: number;
+        interval?: number;
+        maxAttempts?: number;
+    };
+};
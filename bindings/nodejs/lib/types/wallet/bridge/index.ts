@@ -6,6 +6,8 @@ import type {
     __BuildNftOutputMethod__,
     __PrepareBurnMethod__,
     __ClaimOutputsMethod__,
+    __ConsolidateAllOutputsMethod__,
+    __PrepareClaimOutputsMethod__,
     __PrepareConsolidateOutputsMethod__,
     __PrepareCreateAliasOutputMethod__,
     __PrepareDecreaseNativeTokenSupplyMethod__,
@@ -14,6 +16,8 @@ import type {
     __GetBalanceMethod__,
     __GetOutputMethod__,
     __GetFoundryOutputMethod__,
+    __GetNativeTokenMetadataMethod__,
+    __GetNftMetadataMethod__,
     __GetOutputsWithAdditionalUnlockConditionsMethod__,
     __GetTransactionMethod__,
     __AddressesMethod__,
@@ -29,15 +33,26 @@ import type {
     __PrepareMintNftsMethod__,
     __PrepareOutputMethod__,
     __PrepareSendAmountMethod__,
+    __EstimateTransactionMethod__,
     __PrepareTransactionMethod__,
     __RegisterParticipationEventsMethod__,
     __RetryTransactionUntilIncludedMethod__,
     __SendAmountMethod__,
+    __SendAssetsMethod__,
+    __PrepareSendAssetsMethod__,
     __PrepareSendNativeTokensMethod__,
+    __PrepareSendDecimalNativeTokensMethod__,
     __PrepareSendNftMethod__,
     __SendOutputsMethod__,
+    __SweepMethod__,
+    __SetAddressLabelMethod__,
+    __SetAddressMetadataMethod__,
     __SetAliasMethod__,
     __SetDefaultSyncOptionsMethod__,
+    __SetTransactionSequencingMethod__,
+    __SetPrimaryAliasMethod__,
+    __GetPrimaryAliasMethod__,
+    __GetPrimaryAddressMethod__,
     __SignTransactionEssenceMethod__,
     __SignAndSubmitTransactionMethod__,
     __SubmitAndStoreTransactionMethod__,
@@ -51,7 +66,9 @@ import type {
     __GetParticipationEventIdsMethod__,
     __PrepareIncreaseVotingPowerMethod__,
     __PrepareDecreaseVotingPowerMethod__,
+    __PrepareSetVotingPowerMethod__,
     __PrepareStopParticipatingMethod__,
+    __WaitForSequenceConfirmedMethod__,
 } from './account';
 import type {
     __BackupMethod__,
@@ -64,19 +81,31 @@ import type {
     __GetAccountMethod__,
     __GetAccountIndexesMethod__,
     __GetAccountsMethod__,
+    __GetWalletParticipationOverviewMethod__,
     __GetLedgerNanoStatusMethod__,
     __GenerateAddressMethod__,
+    __ImportFireflyBackupMethod__,
     __IsStrongholdPasswordAvailableMethod__,
     __RecoverAccountsMethod__,
     __RemoveLatestAccountMethod__,
     __RestoreBackupMethod__,
+    __RestoreAccountsFromBackupMethod__,
+    __VerifyBackupMethod__,
+    __SendFromAccountsMethod__,
     __SetClientOptionsMethod__,
     __SetStrongholdPasswordClearIntervalMethod__,
     __SetStrongholdPasswordMethod__,
     __StartBackgroundSyncMethod__,
     __StopBackgroundSyncMethod__,
+    __PauseBackgroundSyncMethod__,
+    __ResumeBackgroundSyncMethod__,
+    __GetBackgroundSyncStatusMethod__,
     __StoreMnemonicMethod__,
     __UpdateNodeAuthMethod__,
+    __WritePreparedTransactionToFileMethod__,
+    __ReadPreparedTransactionFromFileMethod__,
+    __WriteSignedTransactionToFileMethod__,
+    __ReadSignedTransactionFromFileMethod__,
 } from './wallet';
 
 export type __AccountMethod__ =
@@ -86,6 +115,8 @@ export type __AccountMethod__ =
     | __BuildNftOutputMethod__
     | __PrepareBurnMethod__
     | __ClaimOutputsMethod__
+    | __ConsolidateAllOutputsMethod__
+    | __PrepareClaimOutputsMethod__
     | __PrepareConsolidateOutputsMethod__
     | __PrepareCreateAliasOutputMethod__
     | __DeregisterParticipationEventMethod__
@@ -94,6 +125,8 @@ export type __AccountMethod__ =
     | __GetOutputMethod__
     | __GetIncomingTransactionMethod__
     | __GetFoundryOutputMethod__
+    | __GetNativeTokenMetadataMethod__
+    | __GetNftMetadataMethod__
     | __GetOutputsWithAdditionalUnlockConditionsMethod__
     | __GetParticipationEventMethod__
     | __GetParticipationEventIdsMethod__
@@ -114,15 +147,26 @@ export type __AccountMethod__ =
     | __PrepareMintNftsMethod__
     | __PrepareOutputMethod__
     | __PrepareSendAmountMethod__
+    | __EstimateTransactionMethod__
     | __PrepareTransactionMethod__
     | __RegisterParticipationEventsMethod__
     | __RetryTransactionUntilIncludedMethod__
     | __SendAmountMethod__
+    | __SendAssetsMethod__
+    | __PrepareSendAssetsMethod__
     | __PrepareSendNativeTokensMethod__
+    | __PrepareSendDecimalNativeTokensMethod__
     | __PrepareSendNftMethod__
     | __SendOutputsMethod__
+    | __SweepMethod__
+    | __SetAddressLabelMethod__
+    | __SetAddressMetadataMethod__
     | __SetAliasMethod__
     | __SetDefaultSyncOptionsMethod__
+    | __SetTransactionSequencingMethod__
+    | __SetPrimaryAliasMethod__
+    | __GetPrimaryAliasMethod__
+    | __GetPrimaryAddressMethod__
     | __SignTransactionEssenceMethod__
     | __SignAndSubmitTransactionMethod__
     | __SubmitAndStoreTransactionMethod__
@@ -131,7 +175,9 @@ export type __AccountMethod__ =
     | __PrepareStopParticipatingMethod__
     | __GetParticipationOverviewMethod__
     | __PrepareIncreaseVotingPowerMethod__
-    | __PrepareDecreaseVotingPowerMethod__;
+    | __PrepareDecreaseVotingPowerMethod__
+    | __PrepareSetVotingPowerMethod__
+    | __WaitForSequenceConfirmedMethod__;
 
 export type __CallAccountMethodMethod__ = {
     name: 'callAccountMethod';
@@ -153,16 +199,28 @@ export type __Method__ =
     | __GetAccountMethod__
     | __GetAccountIndexesMethod__
     | __GetAccountsMethod__
+    | __GetWalletParticipationOverviewMethod__
     | __GetLedgerNanoStatusMethod__
     | __GenerateAddressMethod__
+    | __ImportFireflyBackupMethod__
     | __IsStrongholdPasswordAvailableMethod__
     | __RecoverAccountsMethod__
     | __RemoveLatestAccountMethod__
     | __RestoreBackupMethod__
+    | __RestoreAccountsFromBackupMethod__
+    | __VerifyBackupMethod__
+    | __SendFromAccountsMethod__
     | __SetClientOptionsMethod__
     | __SetStrongholdPasswordClearIntervalMethod__
     | __SetStrongholdPasswordMethod__
     | __StartBackgroundSyncMethod__
     | __StopBackgroundSyncMethod__
+    | __PauseBackgroundSyncMethod__
+    | __ResumeBackgroundSyncMethod__
+    | __GetBackgroundSyncStatusMethod__
     | __StoreMnemonicMethod__
-    | __UpdateNodeAuthMethod__;
+    | __UpdateNodeAuthMethod__
+    | __WritePreparedTransactionToFileMethod__
+    | __ReadPreparedTransactionFromFileMethod__
+    | __WriteSignedTransactionToFileMethod__
+    | __ReadSignedTransactionFromFileMethod__;
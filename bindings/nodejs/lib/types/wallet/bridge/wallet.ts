@@ -1,7 +1,15 @@
 import type { AccountId, CreateAccountPayload, SyncOptions } from '../account';
 import type { GenerateAddressOptions } from '../address';
+import type { ParticipationEventId } from '../participation';
 import type { EventType, WalletEvent } from '../event';
-import type { IAuth, IClientOptions } from '../../client';
+import type { SignedTransactionEssence } from '../signedTransactionEssence';
+import type { TransactionOptions } from '../transactionOptions';
+import type {
+    IAuth,
+    IClientOptions,
+    IPreparedTransactionData,
+} from '../../client';
+import { Output } from '../../';
 
 export type __BackupMethod__ = {
     name: 'backup';
@@ -50,6 +58,13 @@ export type __GetAccountsMethod__ = {
     name: 'getAccounts';
 };
 
+export type __GetWalletParticipationOverviewMethod__ = {
+    name: 'getParticipationOverview';
+    data: {
+        eventIds?: ParticipationEventId[];
+    };
+};
+
 export type __GetAccountMethod__ = {
     name: 'getAccount';
     data: { accountId: AccountId };
@@ -83,6 +98,14 @@ export type __RecoverAccountsMethod__ = {
     };
 };
 
+export type __ImportFireflyBackupMethod__ = {
+    name: 'importFireflyBackup';
+    data: {
+        source: string;
+        password: string;
+    };
+};
+
 export type __RemoveLatestAccountMethod__ = {
     name: 'removeLatestAccount';
 };
@@ -97,6 +120,32 @@ export type __RestoreBackupMethod__ = {
     };
 };
 
+export type __RestoreAccountsFromBackupMethod__ = {
+    name: 'restoreAccountsFromBackup';
+    data: {
+        source: string;
+        password: string;
+        accountIds: AccountId[];
+    };
+};
+
+export type __VerifyBackupMethod__ = {
+    name: 'verifyBackup';
+    data: {
+        source: string;
+        password: string;
+    };
+};
+
+export type __SendFromAccountsMethod__ = {
+    name: 'sendFromAccounts';
+    data: {
+        accountIds: AccountId[];
+        outputs: Output[];
+        options?: TransactionOptions;
+    };
+};
+
 export type __SetClientOptionsMethod__ = {
     name: 'setClientOptions';
     data: { clientOptions: IClientOptions };
@@ -124,6 +173,18 @@ export type __StopBackgroundSyncMethod__ = {
     name: 'stopBackgroundSync';
 };
 
+export type __PauseBackgroundSyncMethod__ = {
+    name: 'pauseBackgroundSync';
+};
+
+export type __ResumeBackgroundSyncMethod__ = {
+    name: 'resumeBackgroundSync';
+};
+
+export type __GetBackgroundSyncStatusMethod__ = {
+    name: 'getBackgroundSyncStatus';
+};
+
 export type __StoreMnemonicMethod__ = {
     name: 'storeMnemonic';
     data: { mnemonic: string };
@@ -133,3 +194,29 @@ export type __UpdateNodeAuthMethod__ = {
     name: 'updateNodeAuth';
     data: { url: string; auth?: IAuth };
 };
+
+export type __WritePreparedTransactionToFileMethod__ = {
+    name: 'writePreparedTransactionToFile';
+    data: {
+        preparedTransactionData: IPreparedTransactionData;
+        path: string;
+    };
+};
+
+export type __ReadPreparedTransactionFromFileMethod__ = {
+    name: 'readPreparedTransactionFromFile';
+    data: { path: string };
+};
+
+export type __WriteSignedTransactionToFileMethod__ = {
+    name: 'writeSignedTransactionToFile';
+    data: {
+        signedTransactionData: SignedTransactionEssence;
+        path: string;
+    };
+};
+
+export type __ReadSignedTransactionFromFileMethod__ = {
+    name: 'readSignedTransactionFromFile';
+    data: { path: string };
+};
@@ -4,10 +4,16 @@ import type { OutputData } from './output';
 /** Wallet event types */
 export type EventType =
     | '*'
+    | 'AutoBackup'
+    | 'ClientOptionsChanged'
+    | 'ClockSkew'
     | 'ConsolidationRequired'
     | 'LedgerAddressGeneration'
     | 'NewOutput'
     | 'SpentOutput'
+    | 'SyncCompleted'
+    | 'SyncSummary'
+    | 'ThresholdAlert'
     | 'TransactionInclusion'
     | 'TransactionProgress';
 
@@ -17,12 +23,78 @@ export type NewOutputEvent = {
     transactionInputs?: IOutputResponse;
 };
 
+/** The result of an automatic backup */
+export type AutoBackupEvent = {
+    /** The path of the written backup */
+    path?: string;
+    /** The error if the backup failed */
+    error?: string;
+};
+
+export type ClientOptionsChangedEvent = {
+    nodes: boolean;
+    networkInfo: boolean;
+    mqtt: boolean;
+    timeouts: boolean;
+    pow: boolean;
+    bech32Hrp?: string;
+};
+
+export type ClockSkewEvent = {
+    /** The local unix timestamp in seconds, taken in the middle of the node info request */
+    localTime: number;
+    /** The unix timestamp in seconds of the latest milestone */
+    milestoneTimestamp: number;
+    /** How long the node info request took, in milliseconds */
+    roundTripTime: number;
+    /** The milestone timestamp minus the local time in seconds, positive if the local clock is behind */
+    skew: number;
+};
+
+export type SyncCompletedEvent = {
+    accountIndex: number;
+    newOutputs: number;
+    spentOutputs: number;
+    duration: number;
+    ledgerIndex?: number;
+};
+
+export type SyncSummaryEvent = {
+    syncedAccounts: number[];
+    failedAccounts: number[];
+    duration: number;
+};
+
+/** A threshold of the alert thresholds of an account that was reached */
+export type ThresholdAlertEvent =
+    | {
+          ReceivedAmount: {
+              outputId: string;
+              amount: string;
+              threshold: string;
+          };
+      }
+    | {
+          BalanceDrop: {
+              previousBalance: string;
+              balance: string;
+              percentage: number;
+              threshold: number;
+          };
+      };
+
 /** Wallet events */
 export enum WalletEvent {
+    AutoBackup = 'AutoBackup',
+    ClientOptionsChanged = 'ClientOptionsChanged',
+    ClockSkew = 'ClockSkew',
     ConsolidationRequired = 'ConsolidationRequired',
     LedgerAddressGeneration = 'LedgerAddressGeneration',
     NewOutput = 'NewOutput',
     SpentOutput = 'SpentOutput',
+    SyncCompleted = 'SyncCompleted',
+    SyncSummary = 'SyncSummary',
+    ThresholdAlert = 'ThresholdAlert',
     TransactionInclusion = 'TransactionInclusion',
     TransactionProgress = 'TransactionProgress',
 }
@@ -7,6 +7,8 @@ export * from './event';
 export * from './output';
 export * from './outputParams';
 export * from './participation';
+export * from './preparedCreateAliasTransactionData';
+export * from './preparedMintNftTransactionData';
 export * from './preparedMintTokenTransactionData';
 export * from './preparedTransactionData';
 export * from './signedTransactionEssence';
@@ -3,6 +3,34 @@ import type { OutputId } from './output';
 
 export interface ParticipationOverview {
     participations: Participations;
+    /** Whether the overview was restored from the cache, because the event nodes couldn't be reached */
+    stale?: boolean;
+}
+
+/** The participation overview of all accounts of a wallet */
+export interface WalletParticipationOverview {
+    /** The participation overviews of the accounts by account index */
+    accounts: { [accountIndex: number]: ParticipationOverview };
+    /** The participations of all accounts summed up per event */
+    events: { [eventId: ParticipationEventId]: ParticipationEventTotals };
+    /** Whether the overview of any account was restored from the cache */
+    stale: boolean;
+}
+
+/** The participations of all accounts of a wallet for an event */
+export interface ParticipationEventTotals {
+    /** The indices of the accounts participating in the event */
+    accountIndices: number[];
+    /** The amount of outputs that participated in the event */
+    outputCount: number;
+    /** The amount of the participations that are still active */
+    activeAmount: string;
+    /** The milestone index of the event status, if an account registered the event */
+    milestoneIndex?: number;
+    /** The staking rewards accumulated up to the milestone index of the event status */
+    rewards?: string;
+    /** The staking rewards at the end of the event if the active participations are kept */
+    projectedRewards?: string;
 }
 
 export interface Participations {
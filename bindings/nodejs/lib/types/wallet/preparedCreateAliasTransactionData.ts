@@ -0,0 +1,41 @@
+import { ALIAS_OUTPUT_TYPE, IAliasOutput } from '@iota/types';
+
+import { Transaction } from '../..';
+import { Utils } from '../../utils';
+import { PreparedTransactionData } from './preparedTransactionData';
+
+/*
+ * The class PreparedCreateAliasTransactionData represents prepared data for a transaction creating an alias output.
+ */
+export class PreparedCreateAliasTransactionData extends PreparedTransactionData {
+    /**
+     * The `send()` method is returning a `Promise` that resolves to a `CreateAliasTransaction` object
+     * with the id of the created alias after the transaction has been signed and submitted.
+     */
+    public async send(): Promise<CreateAliasTransaction> {
+        const transaction = await this.signAndSubmitTransaction();
+        // The new alias is the alias output with a null id, its id is derived from the output id
+        const index = transaction.payload.essence.outputs.findIndex(
+            (output) =>
+                output.type === ALIAS_OUTPUT_TYPE &&
+                BigInt((output as IAliasOutput).aliasId) === BigInt(0),
+        );
+        if (index === -1) {
+            throw new Error('the transaction does not create an alias output');
+        }
+        return {
+            aliasId: Utils.computeAliasId(
+                Utils.computeOutputId(transaction.transactionId, index),
+            ),
+            transaction,
+        };
+    }
+}
+
+/** The result of a transaction creating an alias output */
+export interface CreateAliasTransaction {
+    /** The id of the created alias */
+    aliasId: string;
+    /** The transaction which created the alias */
+    transaction: Transaction;
+}
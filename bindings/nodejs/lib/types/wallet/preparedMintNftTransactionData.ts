@@ -0,0 +1,41 @@
+import { INftOutput, NFT_OUTPUT_TYPE } from '@iota/types';
+
+import { Transaction } from '../..';
+import { Utils } from '../../utils';
+import { PreparedTransactionData } from './preparedTransactionData';
+
+/*
+ * The class PreparedMintNftTransactionData represents prepared data for a transaction minting nfts.
+ */
+export class PreparedMintNftTransactionData extends PreparedTransactionData {
+    /**
+     * The `send()` method is returning a `Promise` that resolves to a `MintNftTransaction` object
+     * with the ids of the minted nfts after the transaction has been signed and submitted.
+     */
+    public async send(): Promise<MintNftTransaction> {
+        const transaction = await this.signAndSubmitTransaction();
+        // The minted nfts are the nft outputs with a null id, their ids are derived from the output ids
+        const nftIds: string[] = [];
+        transaction.payload.essence.outputs.forEach((output, index) => {
+            if (
+                output.type === NFT_OUTPUT_TYPE &&
+                BigInt((output as INftOutput).nftId) === BigInt(0)
+            ) {
+                nftIds.push(
+                    Utils.computeNftId(
+                        Utils.computeOutputId(transaction.transactionId, index),
+                    ),
+                );
+            }
+        });
+        return { nftIds, transaction };
+    }
+}
+
+/** The result of a minting nfts transaction */
+export interface MintNftTransaction {
+    /** The ids of the minted nfts, in the order of the params */
+    nftIds: string[];
+    /** The transaction which minted the nfts */
+    transaction: Transaction;
+}
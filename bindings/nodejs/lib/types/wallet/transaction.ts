@@ -4,6 +4,8 @@ import type { IOutputResponse, ITransactionPayload } from '@iota/types';
 export enum InclusionState {
     /** The transaction is pending */
     Pending = 'Pending',
+    /** The transaction is referenced by a milestone, but its confirmation depth isn't reached yet */
+    Included = 'Included',
     /** The transaction is confirmed */
     Confirmed = 'Confirmed',
     /** The transaction is conflicting */
@@ -12,6 +14,24 @@ export enum InclusionState {
     UnknownPruned = 'UnknownPruned',
 }
 
+/** Reasons why a transaction conflicts with the ledger state */
+export enum ConflictReason {
+    None = 'None',
+    InputUtxoAlreadySpent = 'InputUtxoAlreadySpent',
+    InputUtxoAlreadySpentInThisMilestone = 'InputUtxoAlreadySpentInThisMilestone',
+    InputUtxoNotFound = 'InputUtxoNotFound',
+    CreatedConsumedAmountMismatch = 'CreatedConsumedAmountMismatch',
+    InvalidSignature = 'InvalidSignature',
+    TimelockNotExpired = 'TimelockNotExpired',
+    InvalidNativeTokens = 'InvalidNativeTokens',
+    StorageDepositReturnUnfulfilled = 'StorageDepositReturnUnfulfilled',
+    InvalidUnlock = 'InvalidUnlock',
+    InputsCommitmentsMismatch = 'InputsCommitmentsMismatch',
+    UnverifiedSender = 'UnverifiedSender',
+    InvalidChainStateTransition = 'InvalidChainStateTransition',
+    SemanticValidationFailed = 'SemanticValidationFailed',
+}
+
 /** A Transaction with metadata */
 export interface Transaction {
     /** The transaction payload */
@@ -30,5 +50,43 @@ export interface Transaction {
     incoming: boolean;
     /** Note that can be set when sending a transaction and is only stored locally */
     note?: string;
+    /** Amount of milestones after the including one, before the transaction is considered confirmed */
+    confirmationDepth?: number;
     inputs: IOutputResponse[];
+    /** The reason why the transaction conflicts with the ledger state, if it's conflicting and the reason is known */
+    conflictReason?: ConflictReason;
+    /** Position of the transaction among the transactions sent by the account */
+    sequenceNumber?: number;
+    /** Amounts of the native tokens in the outputs as decimal strings, for tokens with known decimals */
+    formattedNativeTokenAmounts?: { [tokenId: string]: string };
+}
+
+/** An estimate of a transaction, created without locking any inputs */
+export interface TransactionEstimate {
+    /** The estimates of the provided outputs, in the same order */
+    outputs: OutputEstimate[];
+    /** Amount of the inputs that would be selected, which is consumed by the transaction */
+    inputsAmount: string;
+    /** Amount of inputs that would be selected */
+    inputCount: number;
+    /** Amount of the remainder output, if one is needed */
+    remainderAmount?: string;
+    /** Storage deposit that would additionally be locked */
+    storageDeposit: string;
+    /** If any output needs to be claimed by its recipient */
+    requiresClaiming: boolean;
+}
+
+/** An estimate of an output of a transaction */
+export interface OutputEstimate {
+    /** Amount of the output */
+    amount: string;
+    /** Storage deposit the output requires */
+    requiredStorageDeposit: string;
+    /** Amount that needs to be returned to the sender */
+    storageDepositReturn?: string;
+    /** Unix timestamp after which the output returns to the sender */
+    expiration?: number;
+    /** Unix timestamp until which the output is locked */
+    timelock?: number;
 }
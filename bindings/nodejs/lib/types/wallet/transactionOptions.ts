@@ -1,9 +1,15 @@
-import type { ITaggedDataPayload, HexEncodedAmount } from '@iota/types';
+import type {
+    ITaggedDataPayload,
+    HexEncodedAmount,
+    INativeToken,
+} from '@iota/types';
 import type { Burn } from './burn';
 
 /** Options for the transaction creation */
 export interface TransactionOptions {
     remainderValueStrategy?: RemainderValueStrategy;
+    /** Split the remainder into multiple outputs */
+    remainderSplit?: RemainderSplit;
     taggedDataPayload?: ITaggedDataPayload;
     /** Custom inputs that should be used for the transaction */
     customInputs?: string[];
@@ -12,13 +18,56 @@ export interface TransactionOptions {
     /** Optional note, that is only stored locally */
     note?: string;
     allowMicroAmount: boolean;
+    /** Allow burning native tokens that aren't listed in `burn` */
+    allowBurningTokens?: boolean;
+    /** Allow destroying alias, foundry and nft outputs that aren't listed in `burn` */
+    allowDestroyingChains?: boolean;
+    /** Allow sending to addresses which aren't in the address book, if its verification is enabled */
+    force?: boolean;
+    /** Id of the spending allowance that the transaction is charged to */
+    allowance?: string;
+    /** Allow sending native tokens that aren't allowed by the token policy of the account */
+    allowRestrictedTokens?: boolean;
+    /** Amount of milestones after the including one, before the transaction is considered confirmed */
+    confirmationDepth?: number;
+}
+
+/** A spending allowance of an account */
+export interface SpendingAllowance {
+    /** The identifier that spenders reference */
+    id: string;
+    /** The maximum total amount that can be spent with this allowance */
+    maxTotal: string;
+    /** The amount that was already spent with this allowance */
+    spent?: string;
+    /** The maximum total amounts of native tokens that can be spent with this allowance, by token id */
+    maxNativeTokens?: { [tokenId: string]: HexEncodedAmount };
+    /** The amounts of native tokens that were already spent with this allowance, by token id */
+    spentNativeTokens?: { [tokenId: string]: HexEncodedAmount };
+    /** Unix timestamp in seconds after which the allowance can't be used anymore */
+    expiry?: number;
+    /** The recorded usage of the allowance */
+    usage?: AllowanceUsage[];
+}
+
+/** A recorded use of a spending allowance */
+export interface AllowanceUsage {
+    /** The amount of the base coin in the outputs, without the storage deposits returned to the account */
+    amount: string;
+    /** The native tokens in the outputs of the transaction */
+    nativeTokens?: INativeToken[];
+    /** Unix timestamp in seconds of when the transaction was submitted */
+    timestamp: number;
+    /** The transaction that used the allowance */
+    transactionId?: string;
 }
 
 /** The RemainderValueStrategy */
 export type RemainderValueStrategy =
     | ChangeAddress
     | ReuseAddress
-    | CustomAddress;
+    | CustomAddress
+    | Split;
 
 /** ChangeAddress variant of RemainderValueStrategy */
 export type ChangeAddress = {
@@ -38,6 +87,34 @@ export type CustomAddress = {
     value: string;
 };
 
+/** Split variant of RemainderValueStrategy */
+export type Split = {
+    strategy: 'Split';
+    value: {
+        /** The addresses the remainder is sent to */
+        addresses: string[];
+        /** The relative share of the remainder for each address */
+        weights: number[];
+    };
+};
+
+/** The RemainderSplit */
+export type RemainderSplit = EqualParts | Denominations;
+
+/** EqualParts variant of RemainderSplit */
+export type EqualParts = {
+    strategy: 'EqualParts';
+    /** The number of outputs */
+    value: number;
+};
+
+/** Denominations variant of RemainderSplit */
+export type Denominations = {
+    strategy: 'Denominations';
+    /** The amounts of the outputs */
+    value: number[];
+};
+
 /** Native token options for minting */
 export interface MintNativeTokenParams {
     aliasId?: string;
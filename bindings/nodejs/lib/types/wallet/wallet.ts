@@ -8,3 +8,80 @@ export interface WalletOptions {
     coinType?: CoinType;
     secretManager?: SecretManagerType;
 }
+
+/** The status of the background syncing process */
+export enum BackgroundSyncStatus {
+    /** The process isn't running */
+    Stopped = 'Stopped',
+    /** The accounts are synced in the configured interval */
+    Running = 'Running',
+    /** The process got requested to stop */
+    Stopping = 'Stopping',
+    /** The process is running, but doesn't sync the accounts until it gets resumed */
+    Paused = 'Paused',
+}
+
+/** A report about the content of a Stronghold backup */
+export interface BackupReport {
+    /** The coin type of the backed up wallet */
+    coinType?: CoinType;
+    /** The client options of the backed up wallet */
+    clientOptions?: IClientOptions;
+    /** The kind of the backed up secret manager */
+    secretManager?: BackupSecretManagerKind;
+    /** Whether the backup contains a seed */
+    hasSeed: boolean;
+    /** The backed up accounts */
+    accounts: BackupAccount[];
+    /** Inconsistencies found in the backup */
+    issues: BackupIssue[];
+}
+
+/** The kind of secret manager in a Stronghold backup */
+export type BackupSecretManagerKind =
+    | 'stronghold'
+    | 'ledgerNano'
+    | 'mnemonic'
+    | 'hexSeed'
+    | 'placeholder';
+
+/** An inconsistency in a Stronghold backup */
+export type BackupIssue =
+    /** The account isn't stored at the position of its index */
+    | { type: 'accountIndexMismatch'; accountIndex: number; position: number }
+    /** The account has another coin type than the wallet */
+    | {
+          type: 'coinTypeMismatch';
+          accountIndex: number;
+          coinType: CoinType;
+          walletCoinType: CoinType;
+      }
+    /** The account has no public address */
+    | { type: 'missingPublicAddress'; accountIndex: number }
+    /** The key indexes of the public or internal addresses of the account have a gap */
+    | { type: 'addressGap'; accountIndex: number }
+    /** The account has an address with another bech32 HRP than the addresses before it */
+    | {
+          type: 'bech32HrpMismatch';
+          accountIndex: number;
+          bech32Hrp: string;
+          expectedBech32Hrp: string;
+      }
+    /** The first address derived from the seed isn't the first address of the first account */
+    | { type: 'seedMismatch' };
+
+/** An account in a Stronghold backup */
+export interface BackupAccount {
+    /** The account index */
+    index: number;
+    /** The account alias */
+    alias: string;
+    /** The first public address of the account */
+    firstAddress?: string;
+    /** The amount of public addresses */
+    publicAddressCount: number;
+    /** The amount of internal addresses */
+    internalAddressCount: number;
+    /** The amount of transactions sent by the account */
+    transactionCount: number;
+}
@@ -4,6 +4,7 @@
 import type {
     IBlock,
     ITransactionEssence,
+    ITransactionPayload,
     HexEncodedString,
     IEd25519Signature,
 } from '@iota/types';
@@ -58,6 +59,23 @@ export class Utils {
         });
     }
 
+    /**
+     * Computes the output id from a transaction id and the index of the output in the transaction.
+     */
+    static computeOutputId(
+        transactionId: HexEncodedString,
+        outputIndex: number,
+    ): HexEncodedString {
+        // The output index is appended as little endian u16
+        const index = new Uint8Array(new Uint16Array([outputIndex]).buffer);
+        return (
+            transactionId +
+            Array.from(index, (byte) => byte.toString(16).padStart(2, '0')).join(
+                '',
+            )
+        );
+    }
+
     /**
      * Computes the foundry id.
      */
@@ -100,6 +118,18 @@ export class Utils {
         });
     }
 
+    /**
+     * Returns the transaction ID (Blake2b256 hash of the transaction payload bytes)
+     */
+    static transactionId(payload: ITransactionPayload): HexEncodedString {
+        return callUtilsMethod({
+            name: 'transactionId',
+            data: {
+                payload,
+            },
+        });
+    }
+
     /**
      * Transforms bech32 to hex.
      */
@@ -9,19 +9,24 @@ import {
     AccountMeta,
     AccountAddress,
     SendAmountParams,
+    AssetTransfer,
+    SendDecimalNativeTokensParams,
     SendNativeTokensParams,
     SendNftParams,
     AddressWithUnspentOutputs,
     AliasOutputParams,
     FilterOptions,
     GenerateAddressOptions,
+    Irc30Metadata,
     MintNativeTokenParams,
     MintNftParams,
+    NftMetadata,
     OutputData,
     OutputParams,
     OutputsToClaim,
     PreparedTransactionData,
     Transaction,
+    TransactionEstimate,
     TransactionOptions,
     ParticipationOverview,
     ParticipationEventId,
@@ -31,6 +36,8 @@ import {
     ParticipationEventRegistrationOptions,
     ParticipationEventMap,
     PreparedMintTokenTransactionData,
+    PreparedCreateAliasTransactionData,
+    PreparedMintNftTransactionData,
     BuildAliasOutputData,
     BuildBasicOutputData,
     BuildFoundryOutputData,
@@ -221,17 +228,73 @@ export class Account {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Prepare claiming basic or nft outputs that have additional unlock conditions
+     * to their `AddressUnlockCondition` from the account.
+     * @param outputIds The outputs to claim.
+     * @returns The prepared transaction.
+     */
+    async prepareClaimOutputs(
+        outputIds: string[],
+    ): Promise<PreparedTransactionData> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'prepareClaimOutputs',
+                data: {
+                    outputIdsToClaim: outputIds,
+                },
+            },
+        );
+        return new PreparedTransactionData(JSON.parse(response).payload, this);
+    }
+
+    /**
+     * Consolidate all basic outputs with only an `AddressUnlockCondition` from an account,
+     * with multiple transactions if they don't fit into a single one.
+     * @param force Force consolidation on addresses where the threshold isn't met.
+     * @param outputConsolidationThreshold A default threshold is used if this is omitted.
+     * @param targetAddress The address to send the consolidated outputs to, defaults to the address of the first output.
+     * @param splitNativeTokens Create a separate output for every native token.
+     * @returns The sent consolidation transactions.
+     */
+    async consolidateAllOutputs(
+        force: boolean,
+        outputConsolidationThreshold?: number,
+        targetAddress?: string,
+        splitNativeTokens?: boolean,
+    ): Promise<Transaction[]> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'consolidateAllOutputs',
+                data: {
+                    force,
+                    outputConsolidationThreshold,
+                    targetAddress,
+                    splitNativeTokens,
+                },
+            },
+        );
+
+        return JSON.parse(response).payload;
+    }
+
     /**
      * Consolidate basic outputs with only an `AddressUnlockCondition` from an account
      * by sending them to an own address again if the output amount is greater or
      * equal to the output consolidation threshold.
      * @param force Force consolidation on addresses where the threshold isn't met.
      * @param outputConsolidationThreshold A default threshold is used if this is omitted.
+     * @param targetAddress The address to send the consolidated outputs to, defaults to the address of the first output.
+     * @param splitNativeTokens Create a separate output for every native token.
      * @returns The consolidation transaction.
      */
     async prepareConsolidateOutputs(
         force: boolean,
         outputConsolidationThreshold?: number,
+        targetAddress?: string,
+        splitNativeTokens?: boolean,
     ): Promise<PreparedTransactionData> {
         const response = await this.methodHandler.callAccountMethod(
             this.meta.index,
@@ -240,6 +303,8 @@ export class Account {
                 data: {
                     force,
                     outputConsolidationThreshold,
+                    targetAddress,
+                    splitNativeTokens,
                 },
             },
         );
@@ -251,12 +316,12 @@ export class Account {
      * @param params The alias output options.
      * @param transactionOptions The options to define a `RemainderValueStrategy`
      * or custom inputs.
-     * @returns A transaction object.
+     * @returns The prepared transaction, which resolves to the created alias id and the transaction when sent.
      */
     async prepareCreateAliasOutput(
         params?: AliasOutputParams,
         transactionOptions?: TransactionOptions,
-    ): Promise<PreparedTransactionData> {
+    ): Promise<PreparedCreateAliasTransactionData> {
         const response = await this.methodHandler.callAccountMethod(
             this.meta.index,
             {
@@ -267,7 +332,10 @@ export class Account {
                 },
             },
         );
-        return new PreparedTransactionData(JSON.parse(response).payload, this);
+        return new PreparedCreateAliasTransactionData(
+            JSON.parse(response).payload,
+            this,
+        );
     }
 
     /**
@@ -510,6 +578,47 @@ export class Account {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Get the IRC30 metadata of a native token, the foundry is requested from
+     * the node if it isn't known to the account yet.
+     * @param tokenId The native token ID to get the metadata for.
+     * @returns The IRC30 metadata, or null if the foundry doesn't exist or has
+     * no IRC30 metadata.
+     */
+    async getNativeTokenMetadata(
+        tokenId: string,
+    ): Promise<Irc30Metadata | null> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'getNativeTokenMetadata',
+                data: {
+                    tokenId,
+                },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Get the decoded immutable metadata of an NFT owned by the account.
+     * @param nftId The ID of the NFT.
+     * @returns The IRC27 metadata or the hex encoded raw metadata, or null if
+     * the NFT has no metadata feature.
+     */
+    async getNftMetadata(nftId: string): Promise<NftMetadata | null> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'getNftMetadata',
+                data: {
+                    nftId,
+                },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
     /**
      * Get outputs with additional unlock conditions.
      * @param outputs The type of outputs to claim.
@@ -769,12 +878,12 @@ export class Account {
      * @param params The options for minting nfts.
      * @param transactionOptions The options to define a `RemainderValueStrategy`
      * or custom inputs.
-     * @returns The minting transaction.
+     * @returns The prepared minting transaction, which resolves to the minted nft ids and the transaction when sent.
      */
     async prepareMintNfts(
         params: MintNftParams[],
         transactionOptions?: TransactionOptions,
-    ): Promise<PreparedTransactionData> {
+    ): Promise<PreparedMintNftTransactionData> {
         const response = await this.methodHandler.callAccountMethod(
             this.meta.index,
             {
@@ -785,7 +894,10 @@ export class Account {
                 },
             },
         );
-        return new PreparedTransactionData(JSON.parse(response).payload, this);
+        return new PreparedMintNftTransactionData(
+            JSON.parse(response).payload,
+            this,
+        );
     }
 
     /**
@@ -843,6 +955,31 @@ export class Account {
         return new PreparedTransactionData(JSON.parse(response).payload, this);
     }
 
+    /**
+     * Estimate the amounts and storage deposits of a transaction, without
+     * locking any inputs.
+     * @param outputs Outputs to use in the transaction.
+     * @param options The options to define a `RemainderValueStrategy`
+     * or custom inputs.
+     * @returns The transaction estimate.
+     */
+    async estimateTransaction(
+        outputs: Output[],
+        options?: TransactionOptions,
+    ): Promise<TransactionEstimate> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'estimateTransaction',
+                data: {
+                    outputs,
+                    options,
+                },
+            },
+        );
+        return JSON.parse(response).payload;
+    }
+
     /**
      * Prepare a transaction, useful for offline signing.
      * @param outputs Outputs to use in the transaction.
@@ -905,6 +1042,28 @@ export class Account {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Wait until all transactions sent by the account up to and including
+     * the sequence number are confirmed.
+     * @param sequenceNumber The sequence number of the last transaction to wait for.
+     * @param interval Delay in seconds between the confirmation checks.
+     * @param maxAttempts Maximum number of confirmation checks per transaction.
+     */
+    async waitForSequenceConfirmed(
+        sequenceNumber: number,
+        interval?: number,
+        maxAttempts?: number,
+    ): Promise<void> {
+        await this.methodHandler.callAccountMethod(this.meta.index, {
+            name: 'waitForSequenceConfirmed',
+            data: {
+                sequenceNumber,
+                interval,
+                maxAttempts,
+            },
+        });
+    }
+
     /**
      * Send a transaction with amounts from input addresses.
      * @param params Addresses with amounts.
@@ -930,6 +1089,57 @@ export class Account {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Send base coins, native tokens and NFTs to multiple addresses in a
+     * single transaction, transfers to the same address share outputs.
+     * @param transfers The assets to send, each to a single address.
+     * @param transactionOptions The options to define a `RemainderValueStrategy`
+     * or custom inputs.
+     * @returns The sent transaction.
+     */
+    async sendAssets(
+        transfers: AssetTransfer[],
+        transactionOptions?: TransactionOptions,
+    ): Promise<Transaction> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'sendAssets',
+                data: {
+                    transfers,
+                    options: transactionOptions,
+                },
+            },
+        );
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Prepare a transaction sending base coins, native tokens and NFTs to
+     * multiple addresses.
+     * @param transfers The assets to send, each to a single address.
+     * @param transactionOptions The options to define a `RemainderValueStrategy`
+     * or custom inputs.
+     * @returns The prepared transaction.
+     */
+    async prepareSendAssets(
+        transfers: AssetTransfer[],
+        transactionOptions?: TransactionOptions,
+    ): Promise<PreparedTransactionData> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'prepareSendAssets',
+                data: {
+                    transfers,
+                    options: transactionOptions,
+                },
+            },
+        );
+        return new PreparedTransactionData(JSON.parse(response).payload, this);
+    }
+
     /**
      * Send native tokens.
      * @param params Addresses amounts and native tokens.
@@ -954,6 +1164,30 @@ export class Account {
         return new PreparedTransactionData(JSON.parse(response).payload, this);
     }
 
+    /**
+     * Send native tokens with decimal amounts, referenced by token id or IRC30 symbol.
+     * @param params Addresses and native tokens with decimal amounts.
+     * @param transactionOptions The options to define a `RemainderValueStrategy`
+     * or custom inputs.
+     * @returns The prepared transaction.
+     */
+    async prepareSendDecimalNativeTokens(
+        params: SendDecimalNativeTokensParams[],
+        transactionOptions?: TransactionOptions,
+    ): Promise<PreparedTransactionData> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'prepareSendDecimalNativeTokens',
+                data: {
+                    params,
+                    options: transactionOptions,
+                },
+            },
+        );
+        return new PreparedTransactionData(JSON.parse(response).payload, this);
+    }
+
     /**
      * Send nft.
      * @param params Addresses and nft ids.
@@ -1003,6 +1237,84 @@ export class Account {
         return JSON.parse(response).payload;
     }
 
+    /**
+     * Send the entire balance of the account to an address.
+     * @param address The Bech32 encoded address to send to.
+     * @param nfts Also send the NFTs of the account.
+     * @param aliases Also transfer the aliases the account is the governor of.
+     * @param transactionOptions The options for the transactions, custom inputs are replaced by the swept outputs.
+     * @returns The sent transactions, empty if there was nothing to sweep.
+     */
+    async sweep(
+        address: string,
+        nfts?: boolean,
+        aliases?: boolean,
+        transactionOptions?: TransactionOptions,
+    ): Promise<Transaction[]> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'sweep',
+                data: {
+                    address,
+                    nfts,
+                    aliases,
+                    options: transactionOptions,
+                },
+            },
+        );
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Set or remove the label of an address of the account.
+     * @param address The address to label.
+     * @param label The label, `undefined` removes it.
+     * @returns The updated address.
+     */
+    async setAddressLabel(
+        address: string,
+        label?: string,
+    ): Promise<AccountAddress> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'setAddressLabel',
+                data: {
+                    address,
+                    label,
+                },
+            },
+        );
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Replace the metadata of an address of the account.
+     * @param address The address to update.
+     * @param metadata The new metadata.
+     * @returns The updated address.
+     */
+    async setAddressMetadata(
+        address: string,
+        metadata: { [key: string]: string },
+    ): Promise<AccountAddress> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'setAddressMetadata',
+                data: {
+                    address,
+                    metadata,
+                },
+            },
+        );
+
+        return JSON.parse(response).payload;
+    }
+
     /**
      * Set the alias for the account
      * @param alias The account alias to set.
@@ -1030,6 +1342,63 @@ export class Account {
         });
     }
 
+    /**
+     * Enable or disable the sequence numbers of sent transactions.
+     * @param enabled Whether sent transactions get the next sequence number of the account.
+     */
+    async setTransactionSequencing(enabled: boolean): Promise<void> {
+        await this.methodHandler.callAccountMethod(this.meta.index, {
+            name: 'setTransactionSequencing',
+            data: {
+                enabled,
+            },
+        });
+    }
+
+    /**
+     * Set the primary alias of the account, whose address holds the funds of the account.
+     * The remainder of transactions that reuse the address is sent to the alias address.
+     * @param aliasId The id of an alias output controlled by the account, `undefined` removes it.
+     */
+    async setPrimaryAlias(aliasId?: string): Promise<void> {
+        await this.methodHandler.callAccountMethod(this.meta.index, {
+            name: 'setPrimaryAlias',
+            data: {
+                aliasId,
+            },
+        });
+    }
+
+    /**
+     * Get the primary alias of the account.
+     * @returns The alias id, or `null` if no primary alias is set.
+     */
+    async getPrimaryAlias(): Promise<string | null> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'getPrimaryAlias',
+            },
+        );
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Get the address that receives the funds of the account.
+     * @returns The address of the primary alias if one is set, otherwise the first public address.
+     */
+    async getPrimaryAddress(): Promise<string> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'getPrimaryAddress',
+            },
+        );
+
+        return JSON.parse(response).payload;
+    }
+
     /**
      * Sign a prepared transaction, useful for offline signing.
      * @param preparedTransactionData The prepared transaction data to sign.
@@ -1190,4 +1559,30 @@ export class Account {
         );
         return new PreparedTransactionData(JSON.parse(response).payload, this);
     }
+
+    /**
+     * Set the voting power of the account to the given amount, by increasing
+     * or decreasing it by the difference to the current voting power.
+     * @param amount The target voting power.
+     * @returns The prepared transaction, or `undefined` if the voting power
+     * already equals the amount.
+     */
+    async prepareSetVotingPower(
+        amount: string,
+    ): Promise<PreparedTransactionData | undefined> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'prepareSetVotingPower',
+                data: {
+                    amount,
+                },
+            },
+        );
+        const parsed = JSON.parse(response);
+        if (parsed.type === 'ok') {
+            return undefined;
+        }
+        return new PreparedTransactionData(parsed.payload, this);
+    }
 }
@@ -6,15 +6,28 @@ import { Account } from './Account';
 
 import type {
     AccountId,
+    BackgroundSyncStatus,
+    BackupReport,
     WalletOptions,
     CreateAccountPayload,
     EventType,
     GenerateAddressOptions,
+    ParticipationEventId,
+    SignedTransactionEssence,
     SyncOptions,
+    Transaction,
+    TransactionOptions,
     WalletEvent,
+    WalletParticipationOverview,
 } from '../types/wallet';
-import { IAuth, IClientOptions, LedgerNanoStatus } from '../types/client';
+import {
+    IAuth,
+    IClientOptions,
+    IPreparedTransactionData,
+    LedgerNanoStatus,
+} from '../types/client';
 import { Client } from '../client';
+import { Output } from '../types';
 
 /** The Wallet class. */
 export class Wallet {
@@ -136,6 +149,24 @@ export class Wallet {
         return accounts;
     }
 
+    /**
+     * Calculates the participation overview of all accounts and sums it up
+     * per event, with the staking rewards projected to the end of the event.
+     * @param eventIds Optional, filters participations only for provided events.
+     * @returns WalletParticipationOverview
+     */
+    async getParticipationOverview(
+        eventIds?: ParticipationEventId[],
+    ): Promise<WalletParticipationOverview> {
+        const response = await this.methodHandler.callMethod({
+            name: 'getParticipationOverview',
+            data: {
+                eventIds,
+            },
+        });
+        return JSON.parse(response).payload;
+    }
+
     /**
      * Get client.
      */
@@ -231,6 +262,30 @@ export class Wallet {
         return accounts;
     }
 
+    /**
+     * Import a Stronghold backup created by Firefly. The seed of the backup is imported and the accounts are recreated
+     * with their aliases and addresses. Returns an error if accounts were already created, if the Stronghold already
+     * contains a mnemonic or if its password isn't the one of the backup.
+     */
+    async importFireflyBackup(
+        source: string,
+        password: string,
+    ): Promise<Account[]> {
+        const response = await this.methodHandler.callMethod({
+            name: 'importFireflyBackup',
+            data: {
+                source,
+                password,
+            },
+        });
+        const accounts: Account[] = [];
+
+        for (const account of JSON.parse(response).payload) {
+            accounts.push(new Account(account, this.methodHandler));
+        }
+        return accounts;
+    }
+
     /**
      * Delete the latest account.
      */
@@ -267,6 +322,78 @@ export class Wallet {
         });
     }
 
+    /**
+     * Restore a subset of the accounts of a Stronghold backup, adding them to
+     * the existing accounts instead of replacing them. The client options,
+     * coin type and secret manager of the wallet are kept. No account is
+     * restored if one conflicts with the accounts of the wallet.
+     * @param source The path to the backed up Stronghold.
+     * @param password The Stronghold file password.
+     * @param accountIds The accounts to restore, by their index, alias or id
+     * in the backup.
+     * @returns The restored accounts.
+     */
+    async restoreAccountsFromBackup(
+        source: string,
+        password: string,
+        accountIds: AccountId[],
+    ): Promise<Account[]> {
+        const response = await this.methodHandler.callMethod({
+            name: 'restoreAccountsFromBackup',
+            data: {
+                source,
+                password,
+                accountIds,
+            },
+        });
+        const accounts: Account[] = [];
+
+        for (const account of JSON.parse(response).payload) {
+            accounts.push(new Account(account, this.methodHandler));
+        }
+        return accounts;
+    }
+
+    /**
+     * Verify a backup in a Stronghold file without restoring it.
+     */
+    async verifyBackup(source: string, password: string): Promise<BackupReport> {
+        const response = await this.methodHandler.callMethod({
+            name: 'verifyBackup',
+            data: {
+                source,
+                password,
+            },
+        });
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Send a single transaction with inputs of multiple accounts, for example
+     * to fund a payment that no account can cover alone. The transaction is
+     * stored in each account that contributed inputs.
+     * @param accountIds The accounts whose outputs can be used as inputs.
+     * @param outputs Outputs to use in the transaction.
+     * @param transactionOptions The options to define a `RemainderValueStrategy`
+     * or custom inputs.
+     * @returns The sent transaction.
+     */
+    async sendFromAccounts(
+        accountIds: AccountId[],
+        outputs: Output[],
+        transactionOptions?: TransactionOptions,
+    ): Promise<Transaction> {
+        const response = await this.methodHandler.callMethod({
+            name: 'sendFromAccounts',
+            data: {
+                accountIds,
+                outputs,
+                options: transactionOptions,
+            },
+        });
+        return JSON.parse(response).payload;
+    }
+
     /**
      * Set ClientOptions.
      */
@@ -324,6 +451,37 @@ export class Wallet {
         });
     }
 
+    /**
+     * Pause the background syncing process, it keeps running, but doesn't
+     * sync the accounts until it gets resumed.
+     */
+    async pauseBackgroundSync(): Promise<BackgroundSyncStatus> {
+        const response = await this.methodHandler.callMethod({
+            name: 'pauseBackgroundSync',
+        });
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Resume the paused background syncing process.
+     */
+    async resumeBackgroundSync(): Promise<BackgroundSyncStatus> {
+        const response = await this.methodHandler.callMethod({
+            name: 'resumeBackgroundSync',
+        });
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Get the status of the background syncing process.
+     */
+    async getBackgroundSyncStatus(): Promise<BackgroundSyncStatus> {
+        const response = await this.methodHandler.callMethod({
+            name: 'getBackgroundSyncStatus',
+        });
+        return JSON.parse(response).payload;
+    }
+
     /**
      * Store a mnemonic in the Stronghold snapshot.
      */
@@ -343,4 +501,60 @@ export class Wallet {
             data: { url, auth },
         });
     }
+
+    /**
+     * Write a prepared transaction as versioned envelope with an integrity
+     * hash to a file, to be signed by an offline signer.
+     */
+    async writePreparedTransactionToFile(
+        preparedTransactionData: IPreparedTransactionData,
+        path: string,
+    ): Promise<void> {
+        await this.methodHandler.callMethod({
+            name: 'writePreparedTransactionToFile',
+            data: { preparedTransactionData, path },
+        });
+    }
+
+    /**
+     * Read a prepared transaction from an envelope file and verify its
+     * integrity.
+     */
+    async readPreparedTransactionFromFile(
+        path: string,
+    ): Promise<IPreparedTransactionData> {
+        const response = await this.methodHandler.callMethod({
+            name: 'readPreparedTransactionFromFile',
+            data: { path },
+        });
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Write a signed transaction as versioned envelope with an integrity
+     * hash to a file, to be submitted by an online wallet.
+     */
+    async writeSignedTransactionToFile(
+        signedTransactionData: SignedTransactionEssence,
+        path: string,
+    ): Promise<void> {
+        await this.methodHandler.callMethod({
+            name: 'writeSignedTransactionToFile',
+            data: { signedTransactionData, path },
+        });
+    }
+
+    /**
+     * Read a signed transaction from an envelope file and verify its
+     * integrity.
+     */
+    async readSignedTransactionFromFile(
+        path: string,
+    ): Promise<SignedTransactionEssence> {
+        const response = await this.methodHandler.callMethod({
+            name: 'readSignedTransactionFromFile',
+            data: { path },
+        });
+        return JSON.parse(response).payload;
+    }
 }
@@ -84,13 +84,17 @@ class Account:
 
     def prepare_consolidate_outputs(self,
                             force: bool,
-                            output_consolidation_threshold: Optional[int] = None):
+                            output_consolidation_threshold: Optional[int] = None,
+                            target_address: Optional[str] = None,
+                            split_native_tokens: bool = False):
         """Consolidate outputs.
         """
         prepared = self._call_account_method(
             'prepareConsolidateOutputs', {
                 'force': force,
-                'outputConsolidationThreshold': output_consolidation_threshold
+                'outputConsolidationThreshold': output_consolidation_threshold,
+                'targetAddress': target_address,
+                'splitNativeTokens': split_native_tokens
             }
         )
         return PreparedTransactionData(self, prepared)
@@ -383,6 +387,26 @@ class Account:
         )
         return PreparedTransactionData(self, prepared)
 
+    def set_address_label(self, address: str, label: Optional[str] = None):
+        """Set or remove the label of an address of the account and return the updated address.
+        """
+        return self._call_account_method(
+            'setAddressLabel', {
+                'address': address,
+                'label': label
+            }
+        )
+
+    def set_address_metadata(self, address: str, metadata: dict[str, str]):
+        """Replace the metadata of an address of the account and return the updated address.
+        """
+        return self._call_account_method(
+            'setAddressMetadata', {
+                'address': address,
+                'metadata': metadata
+            }
+        )
+
     def set_alias(self, alias: str):
         """Set alias.
         """
@@ -438,6 +462,16 @@ class Account:
             }
         )
 
+    def prepare_claim_outputs(self, output_ids_to_claim: List[OutputId]):
+        """Prepare claiming outputs.
+        """
+        prepared = self._call_account_method(
+            'prepareClaimOutputs', {
+                'outputIdsToClaim': output_ids_to_claim
+            }
+        )
+        return PreparedTransactionData(self, prepared)
+
     def send_outputs(self, outputs, options=None):
         """Send outputs in a transaction.
         """
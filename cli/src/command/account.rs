@@ -18,7 +18,7 @@ use iota_sdk::{
         },
     },
     wallet::{
-        account::{types::AccountAddress, Account, OutputsToClaim, TransactionOptions},
+        account::{types::AccountAddress, Account, ConsolidationParams, OutputsToClaim, TransactionOptions},
         MintNativeTokenParams, MintNftParams, SendAmountParams, SendNativeTokensParams, SendNftParams,
     },
     U256,
@@ -387,7 +387,12 @@ pub async fn claimable_outputs_command(account: &Account) -> Result<(), Error> {
 pub async fn consolidate_command(account: &Account) -> Result<(), Error> {
     println_log_info!("Consolidating outputs.");
 
-    let transaction = account.consolidate_outputs(true, None).await?;
+    let transaction = account
+        .consolidate_outputs(ConsolidationParams {
+            force: true,
+            ..Default::default()
+        })
+        .await?;
 
     println_log_info!(
         "Consolidation transaction sent:\n{:?}\n{:?}",
@@ -402,12 +407,13 @@ pub async fn consolidate_command(account: &Account) -> Result<(), Error> {
 pub async fn create_alias_outputs_command(account: &Account) -> Result<(), Error> {
     println_log_info!("Creating alias output.");
 
-    let transaction = account.create_alias_output(None, None).await?;
+    let alias_transaction = account.create_alias_output(None, None).await?;
 
     println_log_info!(
-        "Alias output creation transaction sent:\n{:?}\n{:?}",
-        transaction.transaction_id,
-        transaction.block_id
+        "Alias output creation transaction sent:\n{:?}\n{:?}\nAlias id: {}",
+        alias_transaction.transaction.transaction_id,
+        alias_transaction.transaction.block_id,
+        alias_transaction.alias_id
     );
 
     Ok(())
@@ -513,7 +519,7 @@ pub async fn mint_native_token_command(
 ) -> Result<(), Error> {
     // If no alias output exists, create one first
     if account.balance().await?.aliases().is_empty() {
-        let transaction = account.create_alias_output(None, None).await?;
+        let transaction = account.create_alias_output(None, None).await?.transaction;
         println_log_info!(
             "Alias output minting transaction sent:\n{:?}\n{:?}",
             transaction.transaction_id,
@@ -567,12 +573,13 @@ pub async fn mint_nft_command(
         immutable_metadata,
         metadata,
     }];
-    let transaction = account.mint_nfts(nft_options, None).await?;
+    let mint_transaction = account.mint_nfts(nft_options, None).await?;
 
     println_log_info!(
-        "NFT minting transaction sent:\n{:?}\n{:?}",
-        transaction.transaction_id,
-        transaction.block_id
+        "NFT minting transaction sent:\n{:?}\n{:?}\nNFT id: {}",
+        mint_transaction.transaction.transaction_id,
+        mint_transaction.transaction.block_id,
+        mint_transaction.nft_ids[0]
     );
 
     Ok(())
@@ -30,7 +30,7 @@ async fn main() -> Result<()> {
     println!("Preparing alias output transaction...");
 
     // First create an alias output, this needs to be done only once, because an alias can have many foundry outputs
-    let transaction = account.create_alias_output(None, None).await?;
+    let transaction = account.create_alias_output(None, None).await?.transaction;
     println!("Transaction sent: {}", transaction.transaction_id);
 
     // Wait for transaction to get included
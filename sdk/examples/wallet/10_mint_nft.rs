@@ -49,7 +49,7 @@ async fn main() -> Result<()> {
         immutable_metadata: Some(b"some NFT immutable metadata".to_vec()),
     }];
 
-    let transaction = account.mint_nfts(nft_options, None).await?;
+    let transaction = account.mint_nfts(nft_options, None).await?.transaction;
     println!("Transaction sent: {}", transaction.transaction_id);
 
     // Wait for transaction to get included
@@ -29,8 +29,10 @@ async fn main() -> Result<()> {
         .await?;
 
     // Create an alias output
-    let transaction = account.create_alias_output(None, None).await?;
+    let alias_transaction = account.create_alias_output(None, None).await?;
+    let transaction = alias_transaction.transaction;
     println!("Transaction sent: {}", transaction.transaction_id);
+    println!("Alias id: {}", alias_transaction.alias_id);
 
     let block_id = account
         .retry_transaction_until_included(&transaction.transaction_id, None, None)
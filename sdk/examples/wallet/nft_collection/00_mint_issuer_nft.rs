@@ -6,13 +6,7 @@
 //!
 //! `cargo run --example mint_issuer_nft --release`
 
-use iota_sdk::{
-    types::block::{
-        output::{NftId, Output, OutputId},
-        payload::transaction::TransactionEssence,
-    },
-    wallet::{MintNftParams, Result, Wallet},
-};
+use iota_sdk::wallet::{MintNftParams, Result, Wallet};
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -41,7 +35,8 @@ async fn main() -> Result<()> {
         tag: None,
     }];
 
-    let transaction = account.mint_nfts(nft_options, None).await?;
+    let mint_transaction = account.mint_nfts(nft_options, None).await?;
+    let transaction = mint_transaction.transaction;
     println!("Transaction sent: {}", transaction.transaction_id);
 
     let block_id = account
@@ -53,16 +48,8 @@ async fn main() -> Result<()> {
         block_id
     );
 
-    let TransactionEssence::Regular(essence) = transaction.payload.essence();
-    for (output_index, output) in essence.outputs().iter().enumerate() {
-        if let Output::Nft(nft_output) = output {
-            // New minted nft id is empty in the output
-            if nft_output.nft_id().is_null() {
-                let output_id = OutputId::new(transaction.transaction_id, output_index as u16)?;
-                let nft_id = NftId::from(&output_id);
-                println!("New minted NFT id: {nft_id}");
-            }
-        }
+    for nft_id in mint_transaction.nft_ids {
+        println!("New minted NFT id: {nft_id}");
     }
 
     Ok(())
@@ -56,7 +56,7 @@ async fn main() -> Result<()> {
 
     // Mint nfts in chunks, since the transaction size is limited
     for nfts in nft_options.chunks(50) {
-        let transaction = account.mint_nfts(nfts.to_vec(), None).await?;
+        let transaction = account.mint_nfts(nfts.to_vec(), None).await?.transaction;
 
         println!(
             "Transaction with chunk of NFTs mint sent: {}/transaction/{}",
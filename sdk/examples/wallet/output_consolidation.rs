@@ -12,7 +12,7 @@ use iota_sdk::{
         constants::SHIMMER_COIN_TYPE,
         secret::{mnemonic::MnemonicSecretManager, SecretManager},
     },
-    wallet::{ClientOptions, Result, Wallet},
+    wallet::{account::ConsolidationParams, ClientOptions, Result, Wallet},
 };
 
 #[tokio::main]
@@ -63,7 +63,12 @@ async fn main() -> Result<()> {
 
     // Consolidate unspent outputs and print the consolidation transaction IDs
     // Set `force` to true to force the consolidation even though the `output_consolidation_threshold` isn't reached
-    let transaction = account.consolidate_outputs(true, None).await?;
+    let transaction = account
+        .consolidate_outputs(ConsolidationParams {
+            force: true,
+            ..Default::default()
+        })
+        .await?;
     println!("Consolidation transaction id:\n{transaction:?}\n");
 
     // Wait for the consolidation transaction to get confirmed
@@ -20,7 +20,6 @@ use crate::{
         Error, Result,
     },
     types::block::{address::Bech32AddressLike, output::OutputWithMetadata, protocol::ProtocolParameters},
-    utils::unix_timestamp_now,
 };
 
 impl<'a> ClientBlockBuilder<'a> {
@@ -51,7 +50,7 @@ impl<'a> ClientBlockBuilder<'a> {
                     QueryParameter::HasExpiration(true),
                     QueryParameter::HasStorageDepositReturn(false),
                     // Ignore outputs that aren't expired yet
-                    QueryParameter::ExpiresBefore(unix_timestamp_now().as_secs() as u32),
+                    QueryParameter::ExpiresBefore(self.client.local_time().await),
                 ])
                 .await?
                 .items,
@@ -5,6 +5,7 @@ pub(crate) mod burn;
 pub(crate) mod error;
 pub(crate) mod remainder;
 pub(crate) mod requirement;
+pub(crate) mod strategy;
 pub(crate) mod transition;
 
 use core::ops::Deref;
@@ -17,6 +18,7 @@ pub use self::{
     burn::{Burn, BurnDto},
     error::Error,
     requirement::Requirement,
+    strategy::InputSelectionStrategy,
 };
 use crate::{
     client::{api::types::RemainderData, secret::types::InputSigningData},
@@ -25,7 +27,7 @@ use crate::{
         input::INPUT_COUNT_RANGE,
         output::{
             AliasOutput, AliasTransition, ChainId, FoundryOutput, NativeTokensBuilder, NftOutput, Output, OutputId,
-            OUTPUT_COUNT_RANGE,
+            Rent, RentStructure, OUTPUT_COUNT_RANGE,
         },
         protocol::ProtocolParameters,
     },
@@ -45,6 +47,7 @@ pub struct InputSelection {
     addresses: HashSet<Address>,
     burn: Option<Burn>,
     remainder_address: Option<Address>,
+    strategy: InputSelectionStrategy,
     protocol_parameters: ProtocolParameters,
     timestamp: u32,
     requirements: Vec<Requirement>,
@@ -62,6 +65,23 @@ pub struct Selected {
     pub remainder: Option<RemainderData>,
 }
 
+impl Selected {
+    /// Storage deposit that is additionally locked by the transaction, the storage deposit of the outputs minus the
+    /// one released by the inputs.
+    pub fn additional_storage_deposit(&self, rent_structure: &RentStructure) -> u64 {
+        self.outputs
+            .iter()
+            .map(|output| output.rent_cost(rent_structure))
+            .sum::<u64>()
+            .saturating_sub(
+                self.inputs
+                    .iter()
+                    .map(|input| input.output.rent_cost(rent_structure))
+                    .sum::<u64>(),
+            )
+    }
+}
+
 impl InputSelection {
     fn required_alias_nft_addresses(&self, input: &InputSigningData) -> Result<Option<Requirement>, Error> {
         let alias_transition = is_alias_transition(input, &self.outputs).map(|transition| transition.0);
@@ -190,6 +210,7 @@ impl InputSelection {
             addresses,
             burn: None,
             remainder_address: None,
+            strategy: InputSelectionStrategy::default(),
             protocol_parameters,
             timestamp: unix_timestamp_now().as_secs() as u32,
             requirements: Vec::new(),
@@ -221,7 +242,15 @@ impl InputSelection {
         self
     }
 
-    /// Sets the timestamp of an [`InputSelection`].
+    /// Sets the strategy an [`InputSelection`] uses to cover the amount of the outputs.
+    pub fn strategy(mut self, strategy: InputSelectionStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Sets the timestamp of an [`InputSelection`], at which the time based unlock conditions are evaluated. Defaults
+    /// to the time of the process wide [`TimeProvider`](crate::utils::TimeProvider), clients with a
+    /// [`TimeSource`](crate::client::TimeSource) pass their [`local_time()`](crate::client::ClientInner::local_time).
     pub fn timestamp(mut self, timestamp: u32) -> Self {
         self.timestamp = timestamp;
         self
@@ -5,7 +5,7 @@ use std::collections::HashMap;
 
 use super::{Error, InputSelection, Requirement};
 use crate::{
-    client::secret::types::InputSigningData,
+    client::{api::input_selection::InputSelectionStrategy, secret::types::InputSigningData},
     types::block::{
         address::Address,
         input::INPUT_COUNT_MAX,
@@ -296,29 +296,27 @@ impl InputSelection {
             );
         }
 
-        // TODO if consolidate strategy: sum all the lowest amount until diff is covered.
-        // TODO this would be lowest amount of input strategy.
+        // Try to select outputs first with the ordering of the strategy, if that results in too many inputs, try from
+        // high to low amount.
 
-        // Try to select outputs first with ordering from low to high amount, if that fails, try reversed.
-
-        log::debug!("Ordering inputs from low to high amount");
-        // Sort inputs per amount, low to high.
-        self.available_inputs
-            .sort_by(|left, right| left.output.amount().cmp(&right.output.amount()));
+        log::debug!("Ordering inputs with the {:?} strategy", self.strategy);
+        self.strategy
+            .order_inputs(&mut self.available_inputs, amount_selection.missing_amount());
 
         if let Some(r) = self.fulfill_amount_requirement_inner(&mut amount_selection) {
             return Ok(r);
         }
 
-        if self.selected_inputs.len() + amount_selection.newly_selected_inputs.len() > INPUT_COUNT_MAX.into() {
+        if self.strategy != InputSelectionStrategy::LargestFirst
+            && self.selected_inputs.len() + amount_selection.newly_selected_inputs.len() > INPUT_COUNT_MAX.into()
+        {
             // Clear before trying with reversed ordering.
             log::debug!("Clearing amount selection");
             amount_selection = AmountSelection::new(self)?;
 
             log::debug!("Ordering inputs from high to low amount");
-            // Sort inputs per amount, high to low.
-            self.available_inputs
-                .sort_by(|left, right| right.output.amount().cmp(&left.output.amount()));
+            InputSelectionStrategy::LargestFirst
+                .order_inputs(&mut self.available_inputs, amount_selection.missing_amount());
 
             if let Some(r) = self.fulfill_amount_requirement_inner(&mut amount_selection) {
                 return Ok(r);
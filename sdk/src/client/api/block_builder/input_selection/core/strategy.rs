@@ -0,0 +1,58 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hash, Hasher},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::client::secret::types::InputSigningData;
+
+/// The order in which the input selection picks inputs to cover the amount of the outputs. Inputs that are needed
+/// for other requirements, like native tokens or chains, are selected regardless of the strategy.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum InputSelectionStrategy {
+    /// Picks the inputs with the largest amount first, which results in few inputs.
+    LargestFirst,
+    /// Picks the inputs with the smallest amount first, which consolidates small outputs.
+    #[default]
+    SmallestFirst,
+    /// Picks the inputs in a random order, so the selection doesn't reveal which outputs belong together.
+    Random,
+    /// Picks the single input that covers the amount with the smallest remainder first, falls back to the largest
+    /// inputs.
+    MinimizeDustChange,
+    /// Picks the inputs that were booked first.
+    OldestFirst,
+}
+
+impl InputSelectionStrategy {
+    // Orders the inputs in which they should be selected to cover the missing amount
+    pub(crate) fn order_inputs(&self, inputs: &mut [InputSigningData], missing_amount: u64) {
+        match self {
+            Self::LargestFirst => inputs.sort_by(|left, right| right.output.amount().cmp(&left.output.amount())),
+            Self::SmallestFirst => inputs.sort_by(|left, right| left.output.amount().cmp(&right.output.amount())),
+            Self::Random => {
+                // The keys of the hasher are random for every instance
+                let random_state = RandomState::new();
+                inputs.sort_by_cached_key(|input| {
+                    let mut hasher = random_state.build_hasher();
+                    Hash::hash(input.output_id(), &mut hasher);
+                    hasher.finish()
+                });
+            }
+            Self::MinimizeDustChange => {
+                inputs.sort_by(|left, right| right.output.amount().cmp(&left.output.amount()));
+                // The inputs are ordered from high to low, so the last one that covers the amount is the closest one
+                if let Some(index) = inputs.iter().rposition(|input| input.output.amount() >= missing_amount) {
+                    inputs[..=index].rotate_right(1);
+                }
+            }
+            Self::OldestFirst => {
+                inputs.sort_by_key(|input| (input.output_metadata.milestone_index_booked(), *input.output_id()))
+            }
+        }
+    }
+}
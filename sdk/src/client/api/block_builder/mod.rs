@@ -10,6 +10,8 @@ use std::ops::Range;
 use packable::bounded::TryIntoBoundedU16Error;
 use serde::{Deserialize, Serialize};
 
+#[cfg(not(target_family = "wasm"))]
+pub use self::pow::{LocalPowProvider, PowProvider, RemotePowProvider, SharedPowProvider};
 pub use self::transaction::verify_semantic;
 use crate::{
     client::{
@@ -3,6 +3,16 @@
 
 //! PoW functions.
 
+#[cfg(not(target_family = "wasm"))]
+use std::sync::Arc;
+
+#[cfg(not(target_family = "wasm"))]
+use packable::PackableExt;
+#[cfg(not(target_family = "wasm"))]
+use serde::{Deserialize, Serialize};
+#[cfg(not(target_family = "wasm"))]
+use url::Url;
+
 #[cfg(not(target_family = "wasm"))]
 use crate::pow::miner::{Miner, MinerBuilder, MinerCancel};
 #[cfg(target_family = "wasm")]
@@ -12,17 +22,168 @@ use crate::{
     types::block::{parent::Parents, payload::Payload, Block, BlockBuilder, Error as BlockError},
 };
 
+/// Computes the nonce of blocks. Can be set with
+/// [`ClientBuilder::with_pow_provider()`](crate::client::ClientBuilder::with_pow_provider) to replace the built-in
+/// local PoW, for example with a GPU miner or a PoW service.
+#[cfg(not(target_family = "wasm"))]
+#[async_trait::async_trait]
+pub trait PowProvider: Send + Sync {
+    /// Returns a nonce with which the block bytes, that don't include the nonce, reach `min_pow_score`. Should return
+    /// `Ok(None)` as soon as possible after `cancel` got triggered, which happens when PoW needs to be restarted with
+    /// new tips.
+    async fn nonce(&self, bytes: &[u8], min_pow_score: u32, cancel: MinerCancel) -> Result<Option<u64>>;
+}
+
+/// A [`PowProvider`] that can be shared between clients.
+#[cfg(not(target_family = "wasm"))]
+#[derive(Clone)]
+pub struct SharedPowProvider(pub Arc<dyn PowProvider>);
+
+#[cfg(not(target_family = "wasm"))]
+impl SharedPowProvider {
+    /// Creates a new [`SharedPowProvider`].
+    pub fn new(pow_provider: impl PowProvider + 'static) -> Self {
+        Self(Arc::new(pow_provider))
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl core::fmt::Debug for SharedPowProvider {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "SharedPowProvider")
+    }
+}
+
+// Providers are only equal if they're the same instance.
+#[cfg(not(target_family = "wasm"))]
+impl PartialEq for SharedPowProvider {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::as_ptr(&self.0) as *const () == Arc::as_ptr(&other.0) as *const ()
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl Eq for SharedPowProvider {}
+
+/// A [`PowProvider`] using the built-in multi-threaded miner.
+#[cfg(not(target_family = "wasm"))]
+#[derive(Debug, Default, Clone)]
+pub struct LocalPowProvider {
+    worker_count: Option<usize>,
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl LocalPowProvider {
+    /// Creates a new [`LocalPowProvider`], uses all CPUs if no worker count is provided.
+    pub fn new(worker_count: impl Into<Option<usize>>) -> Self {
+        Self {
+            worker_count: worker_count.into(),
+        }
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+#[async_trait::async_trait]
+impl PowProvider for LocalPowProvider {
+    async fn nonce(&self, bytes: &[u8], min_pow_score: u32, cancel: MinerCancel) -> Result<Option<u64>> {
+        let bytes = bytes.to_vec();
+        let worker_count = self.worker_count;
+
+        Ok(tokio::task::spawn_blocking(move || {
+            let mut miner = MinerBuilder::new().with_cancel(cancel);
+            if let Some(worker_count) = worker_count {
+                miner = miner.with_num_workers(worker_count);
+            }
+            miner.finish().nonce(&bytes, min_pow_score)
+        })
+        .await?)
+    }
+}
+
+/// A [`PowProvider`] that delegates the PoW to a remote worker. The worker gets a POST request with a JSON body like
+/// `{"bytes":"0x...","minPowScore":1500}` and needs to respond with a JSON body like `{"nonce":"12345"}`.
+#[cfg(not(target_family = "wasm"))]
+#[derive(Debug, Clone)]
+pub struct RemotePowProvider {
+    url: Url,
+    client: reqwest::Client,
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl RemotePowProvider {
+    /// Creates a new [`RemotePowProvider`] for a worker URL.
+    pub fn new(url: Url) -> Self {
+        Self {
+            url,
+            client: reqwest::Client::new(),
+        }
+    }
+}
+
+#[cfg(not(target_family = "wasm"))]
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RemotePowRequest {
+    bytes: String,
+    min_pow_score: u32,
+}
+
+#[cfg(not(target_family = "wasm"))]
+#[derive(Deserialize)]
+struct RemotePowResponse {
+    #[serde(with = "crate::utils::serde::string")]
+    nonce: u64,
+}
+
+#[cfg(not(target_family = "wasm"))]
+#[async_trait::async_trait]
+impl PowProvider for RemotePowProvider {
+    async fn nonce(&self, bytes: &[u8], min_pow_score: u32, cancel: MinerCancel) -> Result<Option<u64>> {
+        if cancel.is_cancelled() {
+            return Ok(None);
+        }
+
+        let request = RemotePowRequest {
+            bytes: prefix_hex::encode(bytes),
+            min_pow_score,
+        };
+        let response = self
+            .client
+            .post(self.url.clone())
+            .json(&request)
+            .send()
+            .await
+            .and_then(|response| response.error_for_status())
+            .map_err(|e| Error::PowProvider(e.to_string()))?
+            .json::<RemotePowResponse>()
+            .await
+            .map_err(|e| Error::PowProvider(e.to_string()))?;
+
+        // The nonce might be for outdated tips.
+        if cancel.is_cancelled() {
+            return Ok(None);
+        }
+
+        Ok(Some(response.nonce))
+    }
+}
+
 impl ClientInner {
-    /// Finishes the block with local PoW if needed.
+    /// Finishes the block with local PoW if needed, or with the PoW provider if one is set.
     /// Without local PoW, it will finish the block with a 0 nonce.
     pub async fn finish_block_builder(&self, parents: Option<Parents>, payload: Option<Payload>) -> Result<Block> {
+        #[cfg(not(target_family = "wasm"))]
+        if let Some(pow_provider) = self.pow_provider.read().await.clone() {
+            return self.finish_provider_pow(&pow_provider, parents, payload).await;
+        }
+
         if self.get_local_pow().await {
             self.finish_pow(parents, payload).await
         } else {
             // Finish block without doing PoW.
             let parents = match parents {
                 Some(parents) => parents,
-                None => Parents::from_vec(self.get_tips().await?)?,
+                None => self.get_parents().await?,
             };
 
             Ok(BlockBuilder::new(parents).with_payload(payload).finish()?)
@@ -54,7 +215,7 @@ impl ClientInner {
             let payload_ = payload.clone();
             let parents = match &parents {
                 Some(parents) => parents.clone(),
-                None => Parents::from_vec(self.get_tips().await?)?,
+                None => self.get_parents().await?,
             };
             let time_thread = std::thread::spawn(move || Ok(pow_timeout(tips_interval, cancel)));
             let pow_thread = std::thread::spawn(move || {
@@ -83,6 +244,55 @@ impl ClientInner {
         }
     }
 
+    /// Performs proof-of-work with a [`PowProvider`].
+    ///
+    /// Cancels the provider and fetches new tips after each tips interval elapses if no parents are provided. Provided
+    /// parents don't change, so the provider isn't restarted then.
+    #[cfg(not(target_family = "wasm"))]
+    async fn finish_provider_pow(
+        &self,
+        pow_provider: &SharedPowProvider,
+        parents: Option<Parents>,
+        payload: Option<Payload>,
+    ) -> Result<Block> {
+        let min_pow_score = self.get_min_pow_score().await?;
+        let tips_interval = self.get_tips_interval().await;
+        let fetch_tips = parents.is_none();
+
+        loop {
+            let parents = match &parents {
+                Some(parents) => parents.clone(),
+                None => self.get_parents().await?,
+            };
+            let block_bytes = BlockBuilder::new(parents.clone())
+                .with_payload(payload.clone())
+                .finish()?
+                .pack_to_vec();
+            let bytes = &block_bytes[..block_bytes.len() - core::mem::size_of::<u64>()];
+
+            let cancel = MinerCancel::new();
+            let nonce = pow_provider.0.nonce(bytes, min_pow_score, cancel.clone());
+            let nonce = if fetch_tips {
+                tokio::time::timeout(std::time::Duration::from_secs(tips_interval), nonce).await
+            } else {
+                Ok(nonce.await)
+            };
+            cancel.trigger();
+
+            match nonce {
+                Ok(Ok(Some(nonce))) => {
+                    return Ok(BlockBuilder::new(parents)
+                        .with_payload(payload)
+                        .with_nonce(nonce)
+                        .finish()?);
+                }
+                // Timed out or cancelled, restart with new tips
+                Err(_) | Ok(Ok(None)) => {}
+                Ok(Err(err)) => return Err(err),
+            }
+        }
+    }
+
     /// Single threaded proof-of-work for Wasm, which cannot generally spawn the native threads used
     /// by the `ClientMiner`.
     ///
@@ -95,7 +305,7 @@ impl ClientInner {
         loop {
             let parents = match &parents {
                 Some(parents) => parents.clone(),
-                None => Parents::from_vec(self.get_tips().await?)?,
+                None => self.get_parents().await?,
             };
 
             let single_threaded_miner = SingleThreadedMinerBuilder::new()
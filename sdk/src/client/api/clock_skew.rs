@@ -0,0 +1,109 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{constants::CLOCK_SKEW_WARNING_THRESHOLD_IN_SECONDS, ClientInner, Result},
+    utils::TimeProvider,
+};
+
+/// The difference between the local time and the time of the network, returned from
+/// [`ClientInner::clock_skew()`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClockSkew {
+    /// The local unix timestamp in seconds, taken in the middle of the node info request.
+    pub local_time: u32,
+    /// The unix timestamp in seconds of the latest milestone.
+    pub milestone_timestamp: u32,
+    /// How long the node info request took, in milliseconds.
+    pub round_trip_time: u64,
+    /// The milestone timestamp minus the local time in seconds. Positive if the local clock is behind, negative if
+    /// it's ahead.
+    pub skew: i64,
+}
+
+impl ClockSkew {
+    // Like NTP, the local time is taken in the middle of the request to account for its latency.
+    fn new(request_start: Duration, request_end: Duration, milestone_timestamp: u32) -> Self {
+        let round_trip_time = request_end.saturating_sub(request_start);
+        let local_time = (request_start + round_trip_time / 2).as_secs() as u32;
+        Self {
+            local_time,
+            milestone_timestamp,
+            round_trip_time: round_trip_time.as_millis() as u64,
+            skew: i64::from(milestone_timestamp) - i64::from(local_time),
+        }
+    }
+
+    /// Returns whether the skew is large enough that timelocks and expirations might not be handled as expected.
+    /// Milestones are only issued every few seconds, so small skews are expected.
+    pub fn exceeds_threshold(&self) -> bool {
+        self.skew.unsigned_abs() > u64::from(CLOCK_SKEW_WARNING_THRESHOLD_IN_SECONDS)
+    }
+}
+
+impl ClientInner {
+    /// Estimates the skew of the [`local_time()`](ClientInner::local_time) by comparing it to the timestamp of the
+    /// latest milestone. Like NTP, the local time is taken in the middle of the request to account for its latency.
+    /// Returns `None` if the node doesn't know the latest milestone timestamp.
+    pub async fn clock_skew(&self) -> Result<Option<ClockSkew>> {
+        let time_source = *self.time_source.read().await;
+        let request_start = time_source.now();
+        let node_info = self.get_info().await?.node_info;
+        let request_end = time_source.now();
+
+        let Some(milestone_timestamp) = node_info.status.latest_milestone.timestamp else {
+            return Ok(None);
+        };
+        let clock_skew = ClockSkew::new(request_start, request_end, milestone_timestamp);
+        if clock_skew.exceeds_threshold() {
+            log::warn!("[clock_skew] local clock is off by {}s", -clock_skew.skew);
+        }
+
+        Ok(Some(clock_skew))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clock_skew_in_the_middle_of_the_request() {
+        let clock_skew = ClockSkew::new(Duration::from_secs(1_000), Duration::from_millis(1_004_000), 1_010);
+
+        assert_eq!(
+            clock_skew,
+            ClockSkew {
+                local_time: 1_002,
+                milestone_timestamp: 1_010,
+                round_trip_time: 4_000,
+                skew: 8,
+            }
+        );
+        assert!(!clock_skew.exceeds_threshold());
+    }
+
+    #[test]
+    fn clock_skew_threshold() {
+        let threshold = u64::from(CLOCK_SKEW_WARNING_THRESHOLD_IN_SECONDS);
+        let local_time = Duration::from_secs(1_000_000);
+        let milestone_timestamp = |offset: i64| (1_000_000 + offset) as u32;
+
+        // The local clock is behind
+        let clock_skew = ClockSkew::new(local_time, local_time, milestone_timestamp(threshold as i64));
+        assert!(!clock_skew.exceeds_threshold());
+        let clock_skew = ClockSkew::new(local_time, local_time, milestone_timestamp(threshold as i64 + 1));
+        assert!(clock_skew.exceeds_threshold());
+        // The local clock is ahead
+        let clock_skew = ClockSkew::new(local_time, local_time, milestone_timestamp(-(threshold as i64)));
+        assert!(!clock_skew.exceeds_threshold());
+        let clock_skew = ClockSkew::new(local_time, local_time, milestone_timestamp(-(threshold as i64) - 1));
+        assert_eq!(clock_skew.skew, -(threshold as i64) - 1);
+        assert!(clock_skew.exceeds_threshold());
+    }
+}
@@ -0,0 +1,117 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{collections::HashMap, str::FromStr};
+
+use instant::Instant;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{node_api::error::Error as NodeApiError, ClientInner, Error, Result},
+    types::{
+        api::core::{dto::LedgerInclusionStateDto, response::BlockMetadataResponse},
+        block::{payload::transaction::TransactionId, semantic::ConflictReason, BlockId},
+    },
+};
+
+/// How long a confirmation status is served from the cache before the node is queried again.
+const CONFIRMATION_STATUS_CACHE_TTL: core::time::Duration = core::time::Duration::from_secs(5);
+
+// The cached confirmation statuses with the time they were queried, by transaction and attachment
+pub(crate) type ConfirmationStatusCache = HashMap<(TransactionId, Option<BlockId>), (Instant, ConfirmationStatus)>;
+
+/// The confirmation status of a transaction.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum ConfirmationStatus {
+    /// The transaction is not (yet) known to be included in the ledger.
+    Pending,
+    /// The transaction got included in the ledger.
+    #[serde(rename_all = "camelCase")]
+    Included {
+        /// The block in which the transaction got included.
+        block_id: BlockId,
+        /// The index of the milestone that referenced the block.
+        milestone_index: Option<u32>,
+    },
+    /// The transaction conflicts with the ledger state.
+    #[serde(rename_all = "camelCase")]
+    Conflicting {
+        /// The conflicting attachment of the transaction.
+        block_id: BlockId,
+        /// The reason of the conflict, if known.
+        reason: Option<ConflictReason>,
+    },
+}
+
+impl ClientInner {
+    /// Returns the confirmation status of a transaction. The metadata of `block_id`, the block the transaction was
+    /// attached in, is used to detect conflicts, while the included block of the transaction is used to detect if any
+    /// attachment got confirmed. Results are cached for a few seconds per transaction and block.
+    pub async fn confirmation_status(
+        &self,
+        transaction_id: &TransactionId,
+        block_id: Option<&BlockId>,
+    ) -> Result<ConfirmationStatus> {
+        // The status depends on the attachment, a conflicting attachment can be reported for a confirmed transaction
+        let cache_key = (*transaction_id, block_id.copied());
+        if let Some((timestamp, status)) = self.confirmation_status_cache.read().await.get(&cache_key) {
+            if timestamp.elapsed() < CONFIRMATION_STATUS_CACHE_TTL {
+                return Ok(*status);
+            }
+        }
+
+        let status = match block_id {
+            Some(block_id) => match self.get_block_metadata(block_id).await {
+                Ok(metadata) => match metadata.ledger_inclusion_state {
+                    Some(LedgerInclusionStateDto::Included) => included_status(&metadata)?,
+                    // Only this attachment may be conflicting, because the transaction got confirmed in another block.
+                    Some(LedgerInclusionStateDto::Conflicting) => {
+                        match self.included_confirmation_status(transaction_id).await? {
+                            Some(status) => status,
+                            None => ConfirmationStatus::Conflicting {
+                                block_id: *block_id,
+                                reason: metadata.conflict_reason.and_then(|r| ConflictReason::try_from(r).ok()),
+                            },
+                        }
+                    }
+                    Some(LedgerInclusionStateDto::NoTransaction) => {
+                        return Err(Error::UnexpectedApiResponse);
+                    }
+                    None => ConfirmationStatus::Pending,
+                },
+                Err(Error::Node(NodeApiError::NotFound(_))) => self
+                    .included_confirmation_status(transaction_id)
+                    .await?
+                    .unwrap_or(ConfirmationStatus::Pending),
+                Err(e) => return Err(e),
+            },
+            None => self
+                .included_confirmation_status(transaction_id)
+                .await?
+                .unwrap_or(ConfirmationStatus::Pending),
+        };
+
+        let mut cache = self.confirmation_status_cache.write().await;
+        cache.retain(|_, (timestamp, _)| timestamp.elapsed() < CONFIRMATION_STATUS_CACHE_TTL);
+        cache.insert(cache_key, (Instant::now(), status));
+
+        Ok(status)
+    }
+
+    /// Returns the status of the block that got included in the ledger for a transaction, if there is one.
+    async fn included_confirmation_status(&self, transaction_id: &TransactionId) -> Result<Option<ConfirmationStatus>> {
+        match self.get_included_block_metadata(transaction_id).await {
+            Ok(metadata) => Ok(Some(included_status(&metadata)?)),
+            Err(Error::Node(NodeApiError::NotFound(_))) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+fn included_status(metadata: &BlockMetadataResponse) -> Result<ConfirmationStatus> {
+    Ok(ConfirmationStatus::Included {
+        block_id: BlockId::from_str(&metadata.block_id)?,
+        milestone_index: metadata.referenced_by_milestone_index,
+    })
+}
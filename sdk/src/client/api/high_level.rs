@@ -28,7 +28,6 @@ use crate::{
             Block, BlockId,
         },
     },
-    utils::unix_timestamp_now,
 };
 
 impl Client {
@@ -332,7 +331,7 @@ impl Client {
     /// Returns the local time checked with the timestamp of the latest milestone, if the difference is larger than 5
     /// minutes an error is returned to prevent locking outputs by accident for a wrong time.
     pub async fn get_time_checked(&self) -> Result<u32> {
-        let current_time = unix_timestamp_now().as_secs() as u32;
+        let current_time = self.local_time().await;
 
         let network_info = self.get_network_info().await?;
 
@@ -0,0 +1,121 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{
+        constants::{DEFAULT_MILESTONE_INTERVAL, DEFAULT_WAIT_FOR_MILESTONE_INTERVAL, MILESTONE_INTERVAL_SAMPLE_SIZE},
+        error::{Error, Result},
+        Client,
+    },
+    types::{
+        api::core::response::ConfirmedMilestoneResponse, block::output::unlock_condition::TimelockUnlockCondition,
+    },
+};
+
+/// The milestone to wait for with [`Client::wait_for_milestone()`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum MilestoneTarget {
+    /// Wait until a milestone with at least this index is confirmed.
+    Index(u32),
+    /// Wait until a milestone with at least this unix timestamp in seconds is confirmed.
+    Timestamp(u32),
+}
+
+impl MilestoneTarget {
+    fn is_reached_by(&self, index: u32, timestamp: Option<u32>) -> bool {
+        match self {
+            Self::Index(target_index) => index >= *target_index,
+            Self::Timestamp(target_timestamp) => timestamp.map_or(false, |timestamp| timestamp >= *target_timestamp),
+        }
+    }
+}
+
+impl fmt::Display for MilestoneTarget {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Index(index) => write!(f, "index {index}"),
+            Self::Timestamp(timestamp) => write!(f, "timestamp {timestamp}"),
+        }
+    }
+}
+
+impl Client {
+    /// Waits until the confirmed milestone reaches the given index or timestamp and returns it. The node is polled
+    /// every `interval` seconds, 5 by default, for at most `max_attempts` times, unlimited by default.
+    pub async fn wait_for_milestone(
+        &self,
+        target: MilestoneTarget,
+        interval: Option<u64>,
+        max_attempts: Option<u64>,
+    ) -> Result<ConfirmedMilestoneResponse> {
+        log::debug!("[wait_for_milestone] {target}");
+        let mut attempts = 0;
+        loop {
+            let confirmed_milestone = self.get_info().await?.node_info.status.confirmed_milestone;
+            if target.is_reached_by(confirmed_milestone.index, confirmed_milestone.timestamp) {
+                return Ok(confirmed_milestone);
+            }
+
+            attempts += 1;
+            if max_attempts.map_or(false, |max_attempts| attempts >= max_attempts) {
+                return Err(Error::MilestoneNotReached(target.to_string()));
+            }
+
+            #[cfg(target_family = "wasm")]
+            gloo_timers::future::TimeoutFuture::new(
+                (interval.unwrap_or(DEFAULT_WAIT_FOR_MILESTONE_INTERVAL) * 1000)
+                    .try_into()
+                    .unwrap(),
+            )
+            .await;
+
+            #[cfg(not(target_family = "wasm"))]
+            tokio::time::sleep(std::time::Duration::from_secs(
+                interval.unwrap_or(DEFAULT_WAIT_FOR_MILESTONE_INTERVAL),
+            ))
+            .await;
+        }
+    }
+
+    /// Estimates the unix timestamp in seconds at which the milestone `milestones` milestones after the currently
+    /// confirmed one will be issued. The milestone interval is averaged over the last confirmed milestones.
+    pub async fn timestamp_in_milestones(&self, milestones: u32) -> Result<u32> {
+        let confirmed_milestone = self.get_info().await?.node_info.status.confirmed_milestone;
+        let confirmed_timestamp = match confirmed_milestone.timestamp {
+            Some(timestamp) => timestamp,
+            None => self
+                .get_milestone_by_index(confirmed_milestone.index)
+                .await?
+                .essence()
+                .timestamp(),
+        };
+
+        // The first milestone has index 1, so there might not be enough milestones to average over yet
+        let sample_size = MILESTONE_INTERVAL_SAMPLE_SIZE.min(confirmed_milestone.index.saturating_sub(1));
+        let milestone_interval = if sample_size == 0 {
+            DEFAULT_MILESTONE_INTERVAL
+        } else {
+            let sample_timestamp = self
+                .get_milestone_by_index(confirmed_milestone.index - sample_size)
+                .await?
+                .essence()
+                .timestamp();
+            confirmed_timestamp.saturating_sub(sample_timestamp) / sample_size
+        };
+        log::debug!("[timestamp_in_milestones] estimated milestone interval: {milestone_interval}s");
+
+        Ok(confirmed_timestamp.saturating_add(milestone_interval.saturating_mul(milestones)))
+    }
+
+    /// Creates a [`TimelockUnlockCondition`] that expires `milestones` milestones after the currently confirmed one,
+    /// see [`Client::timestamp_in_milestones()`].
+    pub async fn timelock_in_milestones(&self, milestones: u32) -> Result<TimelockUnlockCondition> {
+        let timestamp = self.timestamp_in_milestones(milestones).await?;
+        Ok(TimelockUnlockCondition::new(timestamp)?)
+    }
+}
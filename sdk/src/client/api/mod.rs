@@ -5,10 +5,31 @@
 
 mod address;
 mod block_builder;
+mod clock_skew;
+mod confirmation;
 mod consolidation;
 mod high_level;
+mod milestone;
+mod offline_envelope;
+mod partial_signing;
+mod storage_deposit;
+mod tip_cache;
 mod types;
 
-pub use self::{address::*, block_builder::*, types::*};
+pub use self::{
+    address::*,
+    block_builder::*,
+    clock_skew::ClockSkew,
+    confirmation::ConfirmationStatus,
+    milestone::MilestoneTarget,
+    offline_envelope::{OfflineEnvelope, OfflineEnvelopeKind, OFFLINE_ENVELOPE_VERSION},
+    partial_signing::{PartiallySignedTransaction, PartiallySignedTransactionDto},
+    storage_deposit::{PlannedOutputKind, StorageDepositParams},
+    tip_cache::TipCacheOptions,
+    types::*,
+};
+pub(crate) use self::{
+    confirmation::ConfirmationStatusCache, partial_signing::unlocks_without_signatures, tip_cache::TipCache,
+};
 
 const ADDRESS_GAP_RANGE: u32 = 20;
@@ -0,0 +1,150 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::path::Path;
+
+use crypto::hashes::{blake2b::Blake2b256, Digest};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    client::{
+        api::{PreparedTransactionData, PreparedTransactionDataDto, SignedTransactionData, SignedTransactionDataDto},
+        Error, Result,
+    },
+    types::block::protocol::ProtocolParameters,
+};
+
+/// The current version of the offline signing envelope format.
+pub const OFFLINE_ENVELOPE_VERSION: u8 = 1;
+
+/// The kind of the data in an [`OfflineEnvelope`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum OfflineEnvelopeKind {
+    /// A [`PreparedTransactionData`] that needs to be signed.
+    PreparedTransaction,
+    /// A [`SignedTransactionData`] that can be submitted.
+    SignedTransaction,
+}
+
+/// A versioned envelope around the data exchanged between a watch-only online wallet and an offline signer. The hash
+/// of the data is checked when the envelope is read, so corrupted or truncated data is detected. The hash isn't keyed,
+/// so it doesn't protect against deliberate modifications: whoever can change the data can also update the hash. The
+/// offline signer still has to review the transaction before signing it.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OfflineEnvelope<T> {
+    /// The version of the envelope format.
+    pub version: u8,
+    /// The kind of the data.
+    pub kind: OfflineEnvelopeKind,
+    /// The prefix hex encoded Blake2b256 checksum of the data in its canonical JSON form.
+    pub hash: String,
+    /// The data.
+    pub data: T,
+}
+
+impl<T: Serialize + DeserializeOwned> OfflineEnvelope<T> {
+    fn new(kind: OfflineEnvelopeKind, data: T) -> Result<Self> {
+        Ok(Self {
+            version: OFFLINE_ENVELOPE_VERSION,
+            kind,
+            hash: hash_data(&data)?,
+            data,
+        })
+    }
+
+    // Parses an envelope and returns its data, after checking the version, kind and hash
+    fn verified_data(json: &str, kind: OfflineEnvelopeKind) -> Result<T> {
+        // The data is only parsed into `T` after the checks, so an envelope of another kind is reported as such
+        let envelope = serde_json::from_str::<OfflineEnvelope<Value>>(json)?;
+
+        if envelope.version != OFFLINE_ENVELOPE_VERSION {
+            return Err(Error::InvalidOfflineEnvelope(format!(
+                "unsupported version {}, expected {OFFLINE_ENVELOPE_VERSION}",
+                envelope.version
+            )));
+        }
+        if envelope.kind != kind {
+            return Err(Error::InvalidOfflineEnvelope(format!(
+                "expected {kind:?}, found {:?}",
+                envelope.kind
+            )));
+        }
+        if envelope.hash != hash_data(&envelope.data)? {
+            return Err(Error::InvalidOfflineEnvelope(
+                "hash mismatch, the data is corrupted".to_string(),
+            ));
+        }
+
+        serde_json::from_value(envelope.data).map_err(|e| Error::InvalidOfflineEnvelope(e.to_string()))
+    }
+}
+
+// Hashes the JSON value of the data instead of the bytes of the file, so the hash doesn't depend on the formatting of
+// the envelope or on the data serializing to the same bytes again after a roundtrip through its type
+fn hash_data<T: Serialize>(data: &T) -> Result<String> {
+    let hash: [u8; 32] = Blake2b256::digest(serde_json::to_vec(&serde_json::to_value(data)?)?).into();
+    Ok(prefix_hex::encode(hash))
+}
+
+impl PreparedTransactionData {
+    /// Serializes the prepared transaction into a JSON [`OfflineEnvelope`], to be signed by an offline signer.
+    pub fn to_envelope_json(&self) -> Result<String> {
+        let envelope = OfflineEnvelope::new(
+            OfflineEnvelopeKind::PreparedTransaction,
+            PreparedTransactionDataDto::from(self),
+        )?;
+        Ok(serde_json::to_string_pretty(&envelope)?)
+    }
+
+    /// Parses a prepared transaction from a JSON [`OfflineEnvelope`] and verifies its integrity.
+    pub fn from_envelope_json(json: &str, protocol_parameters: &ProtocolParameters) -> Result<Self> {
+        let dto = OfflineEnvelope::<PreparedTransactionDataDto>::verified_data(
+            json,
+            OfflineEnvelopeKind::PreparedTransaction,
+        )?;
+        Ok(Self::try_from_dto(&dto, protocol_parameters)?)
+    }
+
+    /// Writes the prepared transaction as [`OfflineEnvelope`] to a file.
+    pub fn to_file(&self, path: impl AsRef<Path>) -> Result<()> {
+        std::fs::write(path, self.to_envelope_json()?)?;
+        Ok(())
+    }
+
+    /// Reads a prepared transaction from an [`OfflineEnvelope`] file and verifies its integrity.
+    pub fn from_file(path: impl AsRef<Path>, protocol_parameters: &ProtocolParameters) -> Result<Self> {
+        Self::from_envelope_json(&std::fs::read_to_string(path)?, protocol_parameters)
+    }
+}
+
+impl SignedTransactionData {
+    /// Serializes the signed transaction into a JSON [`OfflineEnvelope`], to be submitted by an online wallet.
+    pub fn to_envelope_json(&self) -> Result<String> {
+        let envelope = OfflineEnvelope::new(
+            OfflineEnvelopeKind::SignedTransaction,
+            SignedTransactionDataDto::from(self),
+        )?;
+        Ok(serde_json::to_string_pretty(&envelope)?)
+    }
+
+    /// Parses a signed transaction from a JSON [`OfflineEnvelope`] and verifies its integrity.
+    pub fn from_envelope_json(json: &str, protocol_parameters: &ProtocolParameters) -> Result<Self> {
+        let dto =
+            OfflineEnvelope::<SignedTransactionDataDto>::verified_data(json, OfflineEnvelopeKind::SignedTransaction)?;
+        Ok(Self::try_from_dto(&dto, protocol_parameters)?)
+    }
+
+    /// Writes the signed transaction as [`OfflineEnvelope`] to a file.
+    pub fn to_file(&self, path: impl AsRef<Path>) -> Result<()> {
+        std::fs::write(path, self.to_envelope_json()?)?;
+        Ok(())
+    }
+
+    /// Reads a signed transaction from an [`OfflineEnvelope`] file and verifies its integrity.
+    pub fn from_file(path: impl AsRef<Path>, protocol_parameters: &ProtocolParameters) -> Result<Self> {
+        Self::from_envelope_json(&std::fs::read_to_string(path)?, protocol_parameters)
+    }
+}
@@ -0,0 +1,293 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{
+        api::{
+            input_selection::{is_alias_transition, Error as InputSelectionError},
+            transaction::validate_transaction_payload_length,
+            verify_semantic, PreparedTransactionData, RemainderData, RemainderDataDto, SignedTransactionData,
+        },
+        secret::types::{InputSigningData, InputSigningDataDto},
+        Error, Result,
+    },
+    types::block::{
+        address::Address,
+        output::Output,
+        payload::{
+            transaction::{dto::TransactionEssenceDto, TransactionEssence},
+            TransactionPayload,
+        },
+        protocol::ProtocolParameters,
+        semantic::ConflictReason,
+        unlock::{dto::UnlockDto, AliasUnlock, NftUnlock, ReferenceUnlock, Unlock, Unlocks},
+        Error as BlockError,
+    },
+};
+
+/// A transaction whose inputs are unlocked by multiple parties, each holding the keys of some of the inputs. It's
+/// passed from party to party and every party adds the signature unlocks it can create with
+/// [`SecretManager::sign_partially_signed_transaction()`](crate::client::secret::SecretManager::sign_partially_signed_transaction).
+/// Once all signature unlocks are present, it can be finalized into a [`SignedTransactionData`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PartiallySignedTransaction {
+    /// Transaction essence
+    pub essence: TransactionEssence,
+    /// Required input information for signing. Inputs need to be ordered by address type
+    pub inputs_data: Vec<InputSigningData>,
+    /// Optional remainder output information
+    pub remainder: Option<RemainderData>,
+    /// Unix timestamp in seconds that determines the addresses that need to unlock the inputs
+    pub time: u32,
+    /// One unlock per input, `None` if the signature for the input is still missing
+    pub unlocks: Vec<Option<Unlock>>,
+}
+
+impl PartiallySignedTransaction {
+    /// Creates a partially signed transaction without signatures. The reference, alias and NFT unlocks don't need a
+    /// signature, so they're already set.
+    pub fn new(prepared_transaction_data: PreparedTransactionData, time: u32) -> Result<Self> {
+        let unlocks = unlocks_without_signatures(
+            &prepared_transaction_data.inputs_data,
+            &prepared_transaction_data.essence,
+            time,
+        )?;
+
+        Ok(Self {
+            essence: prepared_transaction_data.essence,
+            inputs_data: prepared_transaction_data.inputs_data,
+            remainder: prepared_transaction_data.remainder,
+            time,
+            unlocks,
+        })
+    }
+
+    /// Returns the addresses whose signatures are still missing, with the index of the input they need to unlock.
+    pub fn missing_signatures(&self) -> Result<Vec<(usize, Address)>> {
+        self.verify_unlock_count()?;
+        let TransactionEssence::Regular(regular) = &self.essence;
+
+        self.unlocks
+            .iter()
+            .enumerate()
+            .filter(|(_, unlock)| unlock.is_none())
+            .map(|(index, _)| {
+                Ok((
+                    index,
+                    required_address(&self.inputs_data[index], regular.outputs(), self.time)?,
+                ))
+            })
+            .collect()
+    }
+
+    /// Returns whether all signature unlocks are present.
+    pub fn is_complete(&self) -> bool {
+        self.unlocks.iter().all(Option::is_some)
+    }
+
+    /// Adds the signature unlocks of another copy of the same transaction, so parties can sign in parallel.
+    pub fn merge(&mut self, other: &Self) -> Result<()> {
+        self.verify_unlock_count()?;
+        if self.essence != other.essence || self.time != other.time || self.unlocks.len() != other.unlocks.len() {
+            return Err(Error::PartiallySignedTransactionMismatch);
+        }
+
+        for (unlock, other_unlock) in self.unlocks.iter_mut().zip(&other.unlocks) {
+            let Some(other_unlock) = other_unlock else {
+                continue;
+            };
+            if unlock.is_none() {
+                unlock.replace(other_unlock.clone());
+            } else if unlock.as_ref() != Some(other_unlock) {
+                return Err(Error::PartiallySignedTransactionMismatch);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Creates the signed transaction, fails if signatures are missing or the transaction is semantically invalid.
+    pub fn finalize(self) -> Result<SignedTransactionData> {
+        self.verify_unlock_count()?;
+        let missing_signatures = self.unlocks.iter().filter(|unlock| unlock.is_none()).count();
+        if missing_signatures > 0 {
+            return Err(Error::MissingSignatures(missing_signatures));
+        }
+
+        let unlocks = Unlocks::new(self.unlocks.into_iter().flatten().collect())?;
+        let transaction_payload = TransactionPayload::new(self.essence, unlocks)?;
+
+        validate_transaction_payload_length(&transaction_payload)?;
+
+        let conflict = verify_semantic(&self.inputs_data, &transaction_payload, self.time)?;
+        if conflict != ConflictReason::None {
+            log::debug!("[finalize] conflict: {conflict:?} for {:#?}", transaction_payload);
+            return Err(Error::TransactionSemantic(conflict));
+        }
+
+        Ok(SignedTransactionData {
+            transaction_payload,
+            inputs_data: self.inputs_data,
+        })
+    }
+
+    // The fields are public, so the unlocks could have been changed to not match the inputs anymore
+    fn verify_unlock_count(&self) -> Result<()> {
+        if self.unlocks.len() != self.inputs_data.len() {
+            return Err(Error::UnlockCountMismatch {
+                inputs: self.inputs_data.len(),
+                unlocks: self.unlocks.len(),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+/// Creates one unlock per input, `None` if the input needs a signature unlock. Inputs whose address was already
+/// unlocked by an earlier input get a reference, alias or NFT unlock.
+pub(crate) fn unlocks_without_signatures(
+    inputs_data: &[InputSigningData],
+    essence: &TransactionEssence,
+    time: u32,
+) -> Result<Vec<Option<Unlock>>> {
+    let TransactionEssence::Regular(regular) = essence;
+    let mut unlocks = Vec::new();
+    let mut unlock_indexes = HashMap::<Address, usize>::new();
+
+    // Assuming inputs_data is ordered by address type
+    for (current_unlock_index, input) in inputs_data.iter().enumerate() {
+        let input_address = required_address(input, regular.outputs(), time)?;
+
+        match unlock_indexes.get(&input_address) {
+            Some(unlock_index) => unlocks.push(Some(match input_address {
+                Address::Alias(_) => Unlock::Alias(AliasUnlock::new(*unlock_index as u16)?),
+                Address::Ed25519(_) => Unlock::Reference(ReferenceUnlock::new(*unlock_index as u16)?),
+                Address::Nft(_) => Unlock::Nft(NftUnlock::new(*unlock_index as u16)?),
+            })),
+            None => {
+                // Alias and NFT addresses need to be unlocked by an earlier input
+                if !input_address.is_ed25519() {
+                    return Err(InputSelectionError::MissingInputWithEd25519Address)?;
+                }
+                unlocks.push(None);
+                unlock_indexes.insert(input_address, current_unlock_index);
+            }
+        }
+
+        // Alias and NFT outputs can unlock later inputs that are owned by their address
+        match &input.output {
+            Output::Alias(alias_output) => unlock_indexes.insert(
+                Address::Alias(alias_output.alias_address(input.output_id())),
+                current_unlock_index,
+            ),
+            Output::Nft(nft_output) => unlock_indexes.insert(
+                Address::Nft(nft_output.nft_address(input.output_id())),
+                current_unlock_index,
+            ),
+            _ => None,
+        };
+    }
+
+    Ok(unlocks)
+}
+
+// Returns the address that needs to unlock the input at the given time
+fn required_address(input: &InputSigningData, outputs: &[Output], time: u32) -> Result<Address> {
+    let alias_transition = is_alias_transition(input, outputs).map(|t| t.0);
+    let (input_address, _) = input
+        .output
+        .required_and_unlocked_address(time, input.output_id(), alias_transition)?;
+
+    Ok(input_address)
+}
+
+/// PartiallySignedTransaction Dto
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PartiallySignedTransactionDto {
+    /// Transaction essence
+    pub essence: TransactionEssenceDto,
+    /// Required address information for signing
+    pub inputs_data: Vec<InputSigningDataDto>,
+    /// Optional remainder output information
+    pub remainder: Option<RemainderDataDto>,
+    /// Unix timestamp in seconds that determines the addresses that need to unlock the inputs
+    pub time: u32,
+    /// One unlock per input, `None` if the signature for the input is still missing
+    pub unlocks: Vec<Option<UnlockDto>>,
+}
+
+impl From<&PartiallySignedTransaction> for PartiallySignedTransactionDto {
+    fn from(value: &PartiallySignedTransaction) -> Self {
+        Self {
+            essence: TransactionEssenceDto::from(&value.essence),
+            inputs_data: value.inputs_data.iter().map(InputSigningDataDto::from).collect(),
+            remainder: value.remainder.as_ref().map(RemainderDataDto::from),
+            time: value.time,
+            unlocks: value
+                .unlocks
+                .iter()
+                .map(|unlock| unlock.as_ref().map(UnlockDto::from))
+                .collect(),
+        }
+    }
+}
+
+impl PartiallySignedTransaction {
+    /// Conversion from [`PartiallySignedTransactionDto`] to [`PartiallySignedTransaction`].
+    pub fn try_from_dto(
+        value: &PartiallySignedTransactionDto,
+        protocol_parameters: &ProtocolParameters,
+    ) -> core::result::Result<Self, BlockError> {
+        let prepared_transaction_data = PreparedTransactionData::try_from_dto(
+            &super::PreparedTransactionDataDto {
+                essence: value.essence.clone(),
+                inputs_data: value.inputs_data.clone(),
+                remainder: value.remainder.clone(),
+            },
+            protocol_parameters,
+        )?;
+
+        Self::from_prepared_transaction_data(prepared_transaction_data, value)
+    }
+
+    /// Unverified conversion from [`PartiallySignedTransactionDto`] to [`PartiallySignedTransaction`].
+    pub fn try_from_dto_unverified(value: &PartiallySignedTransactionDto) -> core::result::Result<Self, BlockError> {
+        let prepared_transaction_data =
+            PreparedTransactionData::try_from_dto_unverified(&super::PreparedTransactionDataDto {
+                essence: value.essence.clone(),
+                inputs_data: value.inputs_data.clone(),
+                remainder: value.remainder.clone(),
+            })?;
+
+        Self::from_prepared_transaction_data(prepared_transaction_data, value)
+    }
+
+    fn from_prepared_transaction_data(
+        prepared_transaction_data: PreparedTransactionData,
+        value: &PartiallySignedTransactionDto,
+    ) -> core::result::Result<Self, BlockError> {
+        if value.unlocks.len() != prepared_transaction_data.inputs_data.len() {
+            return Err(BlockError::InvalidField("unlocks"));
+        }
+
+        Ok(Self {
+            essence: prepared_transaction_data.essence,
+            inputs_data: prepared_transaction_data.inputs_data,
+            remainder: prepared_transaction_data.remainder,
+            time: value.time,
+            unlocks: value
+                .unlocks
+                .iter()
+                .map(|unlock| unlock.as_ref().map(Unlock::try_from).transpose())
+                .collect::<core::result::Result<_, _>>()
+                .map_err(|_| BlockError::InvalidField("unlocks"))?,
+        })
+    }
+}
@@ -0,0 +1,180 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use primitive_types::U256;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{ClientInner, Result},
+    types::block::{
+        address::{Address, AliasAddress, Ed25519Address},
+        output::{
+            feature::{IssuerFeature, MetadataFeature, SenderFeature, TagFeature},
+            unlock_condition::{
+                AddressUnlockCondition, ExpirationUnlockCondition, GovernorAddressUnlockCondition,
+                ImmutableAliasAddressUnlockCondition, StateControllerAddressUnlockCondition,
+                StorageDepositReturnUnlockCondition, TimelockUnlockCondition, UnlockCondition,
+            },
+            AliasId, AliasOutputBuilder, BasicOutputBuilder, Feature, FoundryOutputBuilder, NativeToken, NftId,
+            NftOutputBuilder, Output, RentStructure, SimpleTokenScheme, TokenId,
+        },
+    },
+};
+
+/// The kind of a planned output, see [`StorageDepositParams`].
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum PlannedOutputKind {
+    /// A basic output.
+    #[default]
+    Basic,
+    /// An alias output.
+    Alias,
+    /// A foundry output.
+    Foundry,
+    /// An NFT output.
+    Nft,
+}
+
+/// A lightweight description of an output, to calculate its minimum storage deposit with
+/// [`ClientInner::minimum_storage_deposit_for()`] without building it. Only the sizes of the contained data matter,
+/// so no addresses, ids or tokens are needed.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct StorageDepositParams {
+    /// The kind of the output.
+    pub kind: PlannedOutputKind,
+    /// The amount of different native tokens.
+    pub native_tokens: usize,
+    /// The length of the data of a metadata feature.
+    pub metadata_length: Option<usize>,
+    /// The length of a tag feature, only allowed for basic and NFT outputs.
+    pub tag_length: Option<usize>,
+    /// Whether the output has a sender feature, not allowed for foundry outputs.
+    pub sender: bool,
+    /// Whether the output has an issuer feature, only allowed for alias and NFT outputs.
+    pub issuer: bool,
+    /// The length of the data of an immutable metadata feature, not allowed for basic outputs.
+    pub immutable_metadata_length: Option<usize>,
+    /// The length of the state metadata, ignored for other than alias outputs.
+    pub state_metadata_length: usize,
+    /// Whether the output has a storage deposit return unlock condition, only allowed for basic and NFT outputs.
+    pub storage_deposit_return: bool,
+    /// Whether the output has a timelock unlock condition, only allowed for basic and NFT outputs.
+    pub timelock: bool,
+    /// Whether the output has an expiration unlock condition, only allowed for basic and NFT outputs.
+    pub expiration: bool,
+}
+
+impl StorageDepositParams {
+    /// Calculates the minimum storage deposit of the described output by building it with placeholder data. Fails
+    /// if the output kind doesn't allow the described features or unlock conditions, or if a length is invalid.
+    pub fn minimum_storage_deposit(&self, rent_structure: RentStructure, token_supply: u64) -> Result<u64> {
+        let address = Address::Ed25519(Ed25519Address::new([0; Ed25519Address::LENGTH]));
+
+        let native_tokens = (0..self.native_tokens)
+            .map(|i| {
+                let mut token_id = [0; TokenId::LENGTH];
+                token_id[..8].copy_from_slice(&(i as u64).to_le_bytes());
+                NativeToken::new(TokenId::new(token_id), U256::from(1))
+            })
+            .collect::<std::result::Result<Vec<_>, _>>()?;
+
+        let mut features = Vec::<Feature>::new();
+        if let Some(length) = self.metadata_length {
+            features.push(MetadataFeature::new(vec![0; length])?.into());
+        }
+        if let Some(length) = self.tag_length {
+            features.push(TagFeature::new(vec![0; length])?.into());
+        }
+        if self.sender {
+            features.push(SenderFeature::new(address).into());
+        }
+
+        let mut immutable_features = Vec::<Feature>::new();
+        if self.issuer {
+            immutable_features.push(IssuerFeature::new(address).into());
+        }
+        if let Some(length) = self.immutable_metadata_length {
+            immutable_features.push(MetadataFeature::new(vec![0; length])?.into());
+        }
+
+        let mut unlock_conditions = Vec::<UnlockCondition>::new();
+        if self.storage_deposit_return {
+            unlock_conditions
+                .push(StorageDepositReturnUnlockCondition::new(address, Output::AMOUNT_MIN, token_supply)?.into());
+        }
+        if self.timelock {
+            unlock_conditions.push(TimelockUnlockCondition::new(1)?.into());
+        }
+        if self.expiration {
+            unlock_conditions.push(ExpirationUnlockCondition::new(address, 1)?.into());
+        }
+
+        // Invalid combinations are rejected when the builders verify the allowed features and unlock conditions
+        let output = match self.kind {
+            PlannedOutputKind::Basic => {
+                if let Some(feature) = immutable_features.first() {
+                    return Err(crate::types::block::Error::UnallowedFeature {
+                        index: 0,
+                        kind: feature.kind(),
+                    }
+                    .into());
+                }
+                unlock_conditions.push(AddressUnlockCondition::new(address).into());
+                BasicOutputBuilder::new_with_minimum_storage_deposit(rent_structure)
+                    .with_native_tokens(native_tokens)
+                    .with_unlock_conditions(unlock_conditions)
+                    .with_features(features)
+                    .finish_output(token_supply)?
+            }
+            PlannedOutputKind::Alias => {
+                unlock_conditions.push(StateControllerAddressUnlockCondition::new(address).into());
+                unlock_conditions.push(GovernorAddressUnlockCondition::new(address).into());
+                let mut builder = AliasOutputBuilder::new_with_minimum_storage_deposit(rent_structure, AliasId::null())
+                    .with_native_tokens(native_tokens)
+                    .with_unlock_conditions(unlock_conditions)
+                    .with_features(features)
+                    .with_immutable_features(immutable_features);
+                if self.state_metadata_length > 0 {
+                    builder = builder.with_state_metadata(vec![0; self.state_metadata_length]);
+                }
+                builder.finish_output(token_supply)?
+            }
+            PlannedOutputKind::Foundry => {
+                unlock_conditions
+                    .push(ImmutableAliasAddressUnlockCondition::new(AliasAddress::from(AliasId::null())).into());
+                FoundryOutputBuilder::new_with_minimum_storage_deposit(
+                    rent_structure,
+                    1,
+                    SimpleTokenScheme::new(U256::from(1), U256::from(0), U256::from(1))?.into(),
+                )
+                .with_native_tokens(native_tokens)
+                .with_unlock_conditions(unlock_conditions)
+                .with_features(features)
+                .with_immutable_features(immutable_features)
+                .finish_output(token_supply)?
+            }
+            PlannedOutputKind::Nft => {
+                unlock_conditions.push(AddressUnlockCondition::new(address).into());
+                NftOutputBuilder::new_with_minimum_storage_deposit(rent_structure, NftId::null())
+                    .with_native_tokens(native_tokens)
+                    .with_unlock_conditions(unlock_conditions)
+                    .with_features(features)
+                    .with_immutable_features(immutable_features)
+                    .finish_output(token_supply)?
+            }
+        };
+
+        Ok(output.amount())
+    }
+}
+
+impl ClientInner {
+    /// Returns the minimum storage deposit of an output described by the params, with the rent structure and token
+    /// supply of the node. Useful to preview the deposit, for example while the metadata is typed, without building
+    /// the output first.
+    pub async fn minimum_storage_deposit_for(&self, params: &StorageDepositParams) -> Result<u64> {
+        params.minimum_storage_deposit(self.get_rent_structure().await?, self.get_token_supply().await?)
+    }
+}
@@ -0,0 +1,166 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use instant::Instant;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{
+        constants::{CHAINED_BLOCK_MAX_AGE, DEFAULT_TIP_CACHE_TTL},
+        ClientInner, Result,
+    },
+    types::block::{parent::Parents, BlockId},
+};
+
+/// Options of the tip cache, which provides the parents of new blocks without requesting the tips from the node for
+/// every block. Useful when many blocks are sent in a burst.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TipCacheOptions {
+    /// How long cached tips are used before new tips are requested from the node.
+    pub ttl: Duration,
+    /// Whether the previously submitted block is used as one of the parents of the next block, so the blocks of a
+    /// burst approve each other.
+    pub chain_blocks: bool,
+}
+
+impl Default for TipCacheOptions {
+    fn default() -> Self {
+        Self {
+            ttl: DEFAULT_TIP_CACHE_TTL,
+            chain_blocks: true,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct TipCache {
+    pub(crate) options: Option<TipCacheOptions>,
+    tips: Vec<BlockId>,
+    updated: Option<Instant>,
+    last_block: Option<(Instant, BlockId)>,
+}
+
+impl TipCache {
+    pub(crate) fn new(options: Option<TipCacheOptions>) -> Self {
+        Self {
+            options,
+            ..Default::default()
+        }
+    }
+
+    // Returns the cached tips, if they aren't expired
+    fn tips(&self, ttl: Duration) -> Option<Vec<BlockId>> {
+        self.updated
+            .filter(|updated| updated.elapsed() < ttl)
+            .map(|_| self.tips.clone())
+    }
+
+    fn set_tips(&mut self, tips: Vec<BlockId>) {
+        self.tips = tips;
+        self.updated = Some(Instant::now());
+    }
+
+    // Adds a new tip, the oldest tip is replaced if the maximum amount of parents is reached
+    fn add_tip(&mut self, tip: BlockId) {
+        if !self.tips.contains(&tip) {
+            if self.tips.len() >= usize::from(*Parents::COUNT_RANGE.end()) {
+                self.tips.remove(0);
+            }
+            self.tips.push(tip);
+        }
+        self.updated = Some(Instant::now());
+    }
+
+    // Returns the previously submitted block, if it isn't too old to be used as parent
+    fn last_block_id(&self) -> Option<BlockId> {
+        self.last_block
+            .filter(|(submitted, _)| submitted.elapsed() < CHAINED_BLOCK_MAX_AGE)
+            .map(|(_, block_id)| block_id)
+    }
+}
+
+impl ClientInner {
+    /// Returns the parents for a new block. Without a tip cache the tips are requested from the node, with a tip cache
+    /// the cached tips are used until they expire. If blocks are chained, the previously submitted block is one of
+    /// the parents.
+    pub async fn get_parents(&self) -> Result<Parents> {
+        let options = self.tip_cache.lock().expect("failed to lock the tip cache").options;
+        let Some(options) = options else {
+            return Ok(Parents::from_vec(self.get_tips().await?)?);
+        };
+
+        let cached_tips = self
+            .tip_cache
+            .lock()
+            .expect("failed to lock the tip cache")
+            .tips(options.ttl);
+        let mut tips = match cached_tips {
+            Some(tips) => tips,
+            None => {
+                let tips = self.get_tips().await?;
+                self.tip_cache
+                    .lock()
+                    .expect("failed to lock the tip cache")
+                    .set_tips(tips.clone());
+                tips
+            }
+        };
+
+        if options.chain_blocks {
+            let last_block_id = self
+                .tip_cache
+                .lock()
+                .expect("failed to lock the tip cache")
+                .last_block_id();
+            if let Some(last_block_id) = last_block_id {
+                tips.retain(|tip| tip != &last_block_id);
+                tips.truncate(usize::from(*Parents::COUNT_RANGE.end()) - 1);
+                tips.push(last_block_id);
+            }
+        }
+
+        Ok(Parents::from_vec(tips)?)
+    }
+
+    /// Adds tips to the tip cache, for example blocks received via MQTT. Does nothing if the tip cache isn't enabled.
+    pub fn add_cached_tips(&self, tips: impl IntoIterator<Item = BlockId>) {
+        let mut tip_cache = self.tip_cache.lock().expect("failed to lock the tip cache");
+        if tip_cache.options.is_some() {
+            for tip in tips {
+                tip_cache.add_tip(tip);
+            }
+        }
+    }
+
+    // Remembers a submitted block, so it can be used as parent of the next block
+    pub(crate) fn set_last_submitted_block(&self, block_id: BlockId) {
+        let mut tip_cache = self.tip_cache.lock().expect("failed to lock the tip cache");
+        if matches!(tip_cache.options, Some(options) if options.chain_blocks) {
+            tip_cache.last_block = Some((Instant::now(), block_id));
+        }
+    }
+}
+
+#[cfg(feature = "mqtt")]
+impl crate::client::Client {
+    /// Subscribes to new blocks via MQTT and adds them to the tip cache, so fresh tips are available without requesting
+    /// them from the node. Requires the tip cache to be enabled with
+    /// [`ClientBuilder::with_tip_cache()`](crate::client::ClientBuilder::with_tip_cache).
+    pub async fn subscribe_tip_cache(&self) -> Result<()> {
+        use crate::client::mqtt::{MqttPayload, Topic};
+
+        // A weak reference, so the handler stored in the client doesn't keep the client alive
+        let client = std::sync::Arc::downgrade(&self.inner);
+        self.subscribe(vec![Topic::try_new("blocks")?], move |event| {
+            if let (MqttPayload::Block(block), Some(client)) = (&event.payload, client.upgrade()) {
+                client.add_cached_tips([block.id()]);
+            }
+        })
+        .await?;
+
+        Ok(())
+    }
+}
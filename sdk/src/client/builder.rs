@@ -2,27 +2,36 @@
 // SPDX-License-Identifier: Apache-2.0
 
 //! Builder of the Client Instance
-use std::{collections::HashMap, sync::Arc, time::Duration};
+use std::{
+    collections::HashMap,
+    sync::{atomic::AtomicBool, Arc},
+    time::Duration,
+};
 
 use serde::{Deserialize, Serialize};
 
 use super::{node_manager::builder::NodeManagerBuilder, ClientInner};
+#[cfg(not(target_family = "wasm"))]
+use crate::client::api::SharedPowProvider;
 #[cfg(feature = "mqtt")]
 use crate::client::node_api::mqtt::{BrokerOptions, MqttEvent};
 use crate::{
     client::{
+        api::{TipCache, TipCacheOptions},
         constants::{DEFAULT_API_TIMEOUT, DEFAULT_REMOTE_POW_API_TIMEOUT, DEFAULT_TIPS_INTERVAL},
         error::Result,
         node_manager::{
             builder::validate_url,
+            health::NodeSelectionPolicy,
             node::{Node, NodeAuth},
         },
         Client,
     },
     types::block::{
-        output::dto::RentStructureDto,
+        output::{dto::RentStructureDto, RentStructure},
         protocol::{dto::ProtocolParametersDto, ProtocolParameters},
     },
+    utils::{unix_timestamp_now, TimeProvider},
 };
 
 /// Struct containing network and PoW related information
@@ -46,6 +55,25 @@ pub struct NetworkInfo {
     pub latest_milestone_timestamp: Option<u32>,
 }
 
+impl NetworkInfo {
+    // Replaces the rent structure of the protocol parameters, if one is provided
+    pub(crate) fn with_rent_structure(mut self, rent_structure: Option<RentStructure>) -> Result<Self> {
+        if let Some(rent_structure) = rent_structure {
+            let protocol_parameters = &self.protocol_parameters;
+            self.protocol_parameters = ProtocolParameters::new(
+                protocol_parameters.protocol_version(),
+                protocol_parameters.network_name().to_string(),
+                *protocol_parameters.bech32_hrp(),
+                protocol_parameters.min_pow_score(),
+                protocol_parameters.below_max_depth(),
+                rent_structure,
+                protocol_parameters.token_supply(),
+            )?;
+        }
+        Ok(self)
+    }
+}
+
 /// Dto for the NetworkInfo
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
@@ -83,6 +111,31 @@ impl From<NetworkInfo> for NetworkInfoDto {
     }
 }
 
+/// The source of the local time of a client, which is used for the time based unlock conditions of outputs. Unlike
+/// [`set_time_provider()`](crate::utils::set_time_provider), which changes the time of the whole process, it only
+/// applies to a single client.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum TimeSource {
+    /// The time of the process wide [`TimeProvider`], which is the system time by default.
+    #[default]
+    System,
+    /// A fixed unix timestamp in seconds, for example the time at which an offline transaction will be sent.
+    Fixed {
+        /// The unix timestamp in seconds.
+        timestamp: u32,
+    },
+}
+
+impl TimeProvider for TimeSource {
+    fn now(&self) -> Duration {
+        match self {
+            Self::System => unix_timestamp_now(),
+            Self::Fixed { timestamp } => Duration::from_secs(u64::from(*timestamp)),
+        }
+    }
+}
+
 fn default_local_pow() -> bool {
     #[cfg(not(target_family = "wasm"))]
     {
@@ -128,6 +181,22 @@ pub struct ClientBuilder {
     #[serde(default)]
     #[cfg(not(target_family = "wasm"))]
     pub pow_worker_count: Option<usize>,
+    /// Custom PoW provider, used instead of the local PoW
+    #[serde(skip)]
+    #[cfg(not(target_family = "wasm"))]
+    pub pow_provider: Option<SharedPowProvider>,
+    /// Options of the tip cache, tips are requested for every block if not set
+    #[serde(default)]
+    pub tip_cache: Option<TipCacheOptions>,
+    /// Whether the client works without nodes, with the protocol parameters of the network info
+    #[serde(default)]
+    pub offline: bool,
+    /// Rent structure replacing the one of the protocol parameters, once the client is built
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rent_structure: Option<RentStructure>,
+    /// The source of the local time
+    #[serde(default)]
+    pub time_source: TimeSource,
 }
 
 fn default_api_timeout() -> Duration {
@@ -162,6 +231,12 @@ impl Default for ClientBuilder {
             remote_pow_timeout: DEFAULT_REMOTE_POW_API_TIMEOUT,
             #[cfg(not(target_family = "wasm"))]
             pow_worker_count: None,
+            #[cfg(not(target_family = "wasm"))]
+            pow_provider: None,
+            tip_cache: None,
+            offline: false,
+            rent_structure: None,
+            time_source: TimeSource::default(),
         }
     }
 }
@@ -259,6 +334,22 @@ impl ClientBuilder {
         self
     }
 
+    /// Sets the policy that decides in which order the nodes are tried for a request.
+    pub fn with_node_selection_policy(mut self, node_selection_policy: NodeSelectionPolicy) -> Self {
+        self.node_manager_builder = self
+            .node_manager_builder
+            .with_node_selection_policy(node_selection_policy);
+        self
+    }
+
+    /// Set if quorum should be used for reads like outputs, blocks and indexer queries, independent of
+    /// [`ClientBuilder::with_quorum()`]. They're requested from `min_quorum_size` nodes and an error is returned if
+    /// less than `quorum_threshold`% of the nodes return the same response.
+    pub fn with_read_quorum(mut self, read_quorum: bool) -> Self {
+        self.node_manager_builder = self.node_manager_builder.with_read_quorum(read_quorum);
+        self
+    }
+
     /// Set amount of nodes which should be used for quorum
     pub fn with_min_quorum_size(mut self, min_quorum_size: usize) -> Self {
         self.node_manager_builder = self.node_manager_builder.with_min_quorum_size(min_quorum_size);
@@ -293,6 +384,21 @@ impl ClientBuilder {
         self
     }
 
+    /// Sets a custom PoW provider that computes the nonces of blocks instead of the local PoW, for example
+    /// [`RemotePowProvider`](crate::client::api::RemotePowProvider) or a GPU miner. It's not part of the serialized
+    /// options.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn with_pow_provider(mut self, pow_provider: impl Into<Option<SharedPowProvider>>) -> Self {
+        self.pow_provider = pow_provider.into();
+        self
+    }
+
+    /// Sets the options of the tip cache, so bursts of blocks don't request the tips from the node for every block.
+    pub fn with_tip_cache(mut self, options: impl Into<Option<TipCacheOptions>>) -> Self {
+        self.tip_cache = options.into();
+        self
+    }
+
     /// Sets whether the PoW should be done locally in case a node doesn't support remote PoW.
     pub fn with_fallback_to_local_pow(mut self, fallback_to_local_pow: bool) -> Self {
         self.network_info.fallback_to_local_pow = fallback_to_local_pow;
@@ -317,6 +423,29 @@ impl ClientBuilder {
         self
     }
 
+    /// Builds the client in offline mode with the provided protocol parameters, so addresses, outputs and transactions
+    /// can be prepared without a node. The nodes aren't synced, so requests only work with nodes that are reachable.
+    pub fn with_offline_mode(mut self, protocol_parameters: ProtocolParameters) -> Self {
+        self.offline = true;
+        self.network_info.protocol_parameters = protocol_parameters;
+        self
+    }
+
+    /// Sets the rent structure of the protocol parameters, which is replaced by the one of the nodes, unless the client
+    /// is in offline mode. It's applied when the client is built, so it isn't overwritten by
+    /// [`ClientBuilder::with_offline_mode()`].
+    pub fn with_rent_structure(mut self, rent_structure: RentStructure) -> Self {
+        self.rent_structure.replace(rent_structure);
+        self
+    }
+
+    /// Sets the source of the local time, which is used instead of the system time for the time based unlock
+    /// conditions.
+    pub fn with_time_source(mut self, time_source: TimeSource) -> Self {
+        self.time_source = time_source;
+        self
+    }
+
     /// Set User-Agent header for requests
     /// Default is "iota-client/{version}"
     pub fn with_user_agent(mut self, user_agent: String) -> Self {
@@ -344,10 +473,11 @@ impl ClientBuilder {
 
         let client_inner = Arc::new(ClientInner {
             node_manager: RwLock::new(self.node_manager_builder.build(HashMap::new())),
-            network_info: RwLock::new(self.network_info),
+            network_info: RwLock::new(self.network_info.with_rent_structure(self.rent_structure)?),
             api_timeout: RwLock::new(self.api_timeout),
             remote_pow_timeout: RwLock::new(self.remote_pow_timeout),
             pow_worker_count: RwLock::new(self.pow_worker_count),
+            pow_provider: RwLock::new(self.pow_provider),
             #[cfg(feature = "mqtt")]
             mqtt: super::MqttInner {
                 client: Default::default(),
@@ -356,20 +486,29 @@ impl ClientBuilder {
                 sender: RwLock::new(mqtt_event_tx),
                 receiver: RwLock::new(mqtt_event_rx),
             },
+            confirmation_status_cache: Default::default(),
+            tip_cache: std::sync::Mutex::new(TipCache::new(self.tip_cache)),
+            offline: AtomicBool::new(self.offline),
+            time_source: RwLock::new(self.time_source),
         });
 
-        client_inner.sync_nodes(&nodes, ignore_node_health).await?;
-        let client_clone = client_inner.clone();
-
-        let sync_handle = tokio::spawn(async move {
-            client_clone
-                .start_sync_process(nodes, node_sync_interval, ignore_node_health)
-                .await
-        });
+        // The nodes aren't synced in offline mode, so the provided protocol parameters are kept
+        let sync_handle = if self.offline {
+            None
+        } else {
+            client_inner.sync_nodes(&nodes, ignore_node_health).await?;
+            let client_clone = client_inner.clone();
+
+            Some(tokio::spawn(async move {
+                client_clone
+                    .start_sync_process(nodes, node_sync_interval, ignore_node_health)
+                    .await
+            }))
+        };
 
         let client = Client {
             inner: client_inner,
-            _sync_handle: Arc::new(RwLock::new(super::SyncHandle(Some(sync_handle)))),
+            _sync_handle: Arc::new(RwLock::new(super::SyncHandle(sync_handle))),
         };
 
         Ok(client)
@@ -386,7 +525,7 @@ impl ClientBuilder {
         let client = Client {
             inner: Arc::new(ClientInner {
                 node_manager: RwLock::new(self.node_manager_builder.build(HashMap::new())),
-                network_info: RwLock::new(self.network_info),
+                network_info: RwLock::new(self.network_info.with_rent_structure(self.rent_structure)?),
                 api_timeout: RwLock::new(self.api_timeout),
                 remote_pow_timeout: RwLock::new(self.remote_pow_timeout),
                 #[cfg(feature = "mqtt")]
@@ -397,6 +536,10 @@ impl ClientBuilder {
                     sender: RwLock::new(mqtt_event_tx),
                     receiver: RwLock::new(mqtt_event_rx),
                 },
+                confirmation_status_cache: Default::default(),
+                tip_cache: std::sync::Mutex::new(TipCache::new(self.tip_cache)),
+                offline: AtomicBool::new(self.offline),
+                time_source: RwLock::new(self.time_source),
             }),
         };
 
@@ -404,6 +547,8 @@ impl ClientBuilder {
     }
 
     pub async fn from_client(client: &Client) -> Self {
+        // Read before the tip cache is locked, its std mutex guard can't be held across an await
+        let time_source = *client.time_source.read().await;
         Self {
             node_manager_builder: NodeManagerBuilder::from(&*client.node_manager.read().await),
             #[cfg(feature = "mqtt")]
@@ -413,6 +558,12 @@ impl ClientBuilder {
             remote_pow_timeout: client.get_remote_pow_timeout().await,
             #[cfg(not(target_family = "wasm"))]
             pow_worker_count: *client.pow_worker_count.read().await,
+            #[cfg(not(target_family = "wasm"))]
+            pow_provider: client.pow_provider.read().await.clone(),
+            tip_cache: client.tip_cache.lock().expect("failed to lock the tip cache").options,
+            offline: client.is_offline(),
+            rent_structure: None,
+            time_source,
         }
     }
 }
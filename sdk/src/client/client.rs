@@ -3,7 +3,13 @@
 
 //! The Client module to connect through HORNET or Bee with API usages
 
-use std::{sync::Arc, time::Duration};
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
 
 use tokio::sync::RwLock;
 #[cfg(feature = "mqtt")]
@@ -13,16 +19,20 @@ use {
     tokio::sync::watch::{Receiver as WatchReceiver, Sender as WatchSender},
 };
 
+#[cfg(not(target_family = "wasm"))]
+use crate::client::api::SharedPowProvider;
 #[cfg(target_family = "wasm")]
 use crate::client::constants::CACHE_NETWORK_INFO_TIMEOUT_IN_SECONDS;
 use crate::{
     client::{
-        builder::{ClientBuilder, NetworkInfo},
+        api::{ConfirmationStatusCache, TipCache},
+        builder::{ClientBuilder, NetworkInfo, TimeSource},
         error::Result,
         node_manager::NodeManager,
         Error,
     },
     types::block::{address::Hrp, output::RentStructure, protocol::ProtocolParameters},
+    utils::TimeProvider,
 };
 
 /// An instance of the client using HORNET or Bee URI
@@ -52,8 +62,20 @@ pub struct ClientInner {
     /// pow_worker_count for local PoW.
     #[cfg(not(target_family = "wasm"))]
     pub(crate) pow_worker_count: RwLock<Option<usize>>,
+    /// Custom PoW provider, used instead of the local PoW.
+    #[cfg(not(target_family = "wasm"))]
+    pub(crate) pow_provider: RwLock<Option<SharedPowProvider>>,
     #[cfg(feature = "mqtt")]
     pub(crate) mqtt: MqttInner,
+    /// Recently queried transaction confirmation statuses, by transaction and attachment.
+    pub(crate) confirmation_status_cache: RwLock<ConfirmationStatusCache>,
+    /// Cached tips and the last submitted block, used as parents of new blocks. A std mutex, so it can be updated from
+    /// MQTT handlers.
+    pub(crate) tip_cache: std::sync::Mutex<TipCache>,
+    /// Whether the nodes aren't synced and the provided protocol parameters are used.
+    pub(crate) offline: AtomicBool,
+    /// The source of the local time.
+    pub(crate) time_source: RwLock<TimeSource>,
 }
 
 #[derive(Default)]
@@ -103,7 +125,7 @@ impl ClientInner {
         // difficulty or the byte cost could change via a milestone, so we request the node info every time, so we don't
         // create invalid transactions/blocks.
         #[cfg(target_family = "wasm")]
-        {
+        if !self.is_offline() {
             lazy_static::lazy_static! {
                 static ref LAST_SYNC: std::sync::Mutex<Option<u32>> = std::sync::Mutex::new(None);
             };
@@ -168,6 +190,18 @@ impl ClientInner {
         Ok(self.get_network_info().await?.protocol_parameters.token_supply())
     }
 
+    /// Returns whether the client is in offline mode, see
+    /// [`ClientBuilder::with_offline_mode()`](crate::client::ClientBuilder::with_offline_mode).
+    pub fn is_offline(&self) -> bool {
+        self.offline.load(Ordering::Relaxed)
+    }
+
+    /// Returns the local unix timestamp in seconds of the [`TimeSource`] of the client, without checking it against the
+    /// time of the network like [`ClientInner::get_time_checked()`].
+    pub async fn local_time(&self) -> u32 {
+        self.time_source.read().await.now().as_secs() as u32
+    }
+
     /// returns the tips interval
     pub async fn get_tips_interval(&self) -> u64 {
         self.network_info.read().await.tips_interval
@@ -12,9 +12,19 @@ pub(crate) const DEFAULT_API_TIMEOUT: Duration = Duration::from_secs(15);
 pub(crate) const DEFAULT_REMOTE_POW_API_TIMEOUT: Duration = Duration::from_secs(100);
 pub(crate) const DEFAULT_RETRY_UNTIL_INCLUDED_INTERVAL: u64 = 1;
 pub(crate) const DEFAULT_RETRY_UNTIL_INCLUDED_MAX_AMOUNT: u64 = 40;
+/// Interval in seconds in which the confirmed milestone is requested while waiting for a milestone
+pub(crate) const DEFAULT_WAIT_FOR_MILESTONE_INTERVAL: u64 = 5;
+/// Milestone interval in seconds that is assumed if there aren't enough milestones yet to average over
+pub(crate) const DEFAULT_MILESTONE_INTERVAL: u32 = 5;
+/// Amount of milestones over which the milestone interval is averaged
+pub(crate) const MILESTONE_INTERVAL_SAMPLE_SIZE: u32 = 10;
 /// Interval in seconds when new tips will be requested during PoW, so the final block always will be attached to a
 /// new part of the Tangle
 pub(crate) const DEFAULT_TIPS_INTERVAL: u64 = 5;
+/// How long tips from the tip cache are used before new tips are requested
+pub(crate) const DEFAULT_TIP_CACHE_TTL: Duration = Duration::from_secs(2);
+/// How long after its submission a block is still used as parent of the next block, when blocks are chained
+pub(crate) const CHAINED_BLOCK_MAX_AGE: Duration = Duration::from_secs(30);
 /// Interval in which the node info will be requested and healthy nodes will be added to the healthy node pool
 pub(crate) const NODE_SYNC_INTERVAL: Duration = Duration::from_secs(60);
 pub(crate) const DEFAULT_MIN_QUORUM_SIZE: usize = 3;
@@ -24,6 +34,8 @@ pub(crate) const DEFAULT_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/",
 pub(crate) const MAX_PARALLEL_API_REQUESTS: usize = 100;
 /// Max allowed difference between the local time and latest milestone time, 5 minutes in seconds
 pub(crate) const FIVE_MINUTES_IN_SECONDS: u32 = 300;
+/// Difference between the local time and latest milestone time above which a clock skew warning is emitted
+pub(crate) const CLOCK_SKEW_WARNING_THRESHOLD_IN_SECONDS: u32 = 60;
 /// Delay for caching a node info response in WASM runtime
 #[cfg(target_family = "wasm")]
 pub(crate) const CACHE_NETWORK_INFO_TIMEOUT_IN_SECONDS: u32 = 60;
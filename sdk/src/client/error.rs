@@ -62,6 +62,9 @@ pub enum Error {
     /// Invalid mnemonic error
     #[error("invalid mnemonic {0}")]
     InvalidMnemonic(String),
+    /// The offline signing envelope is invalid
+    #[error("invalid offline signing envelope: {0}")]
+    InvalidOfflineEnvelope(String),
     /// The transaction essence is too large
     #[error("the transaction essence is too large. Its length is {length}, max length is {max_length}")]
     InvalidRegularTransactionEssenceLength {
@@ -81,9 +84,15 @@ pub enum Error {
     /// JSON error
     #[error("{0}")]
     Json(#[from] serde_json::Error),
+    /// The milestone to wait for wasn't confirmed within the maximum attempts
+    #[error("milestone with {0} wasn't confirmed within the maximum attempts")]
+    MilestoneNotReached(String),
     /// Missing required parameters
     #[error("must provide required parameter: {0}")]
     MissingParameter(&'static str),
+    /// IO error
+    #[error("`{0}`")]
+    Io(#[from] std::io::Error),
     /// Error on API request
     #[error("node error: {0}")]
     Node(#[from] crate::client::node_api::error::Error),
@@ -99,6 +108,9 @@ pub enum Error {
     /// Rw lock failed.
     #[error("rw lock failed")]
     PoisonError,
+    /// Error from a custom PoW provider
+    #[error("PoW provider error: {0}")]
+    PowProvider(String),
     /// Prefix hex string convert error
     #[error("{0}")]
     PrefixHex(#[from] prefix_hex::Error),
@@ -124,6 +136,31 @@ pub enum Error {
     /// No node available in the healthy node pool
     #[error("no healthy node available")]
     HealthyNodePoolEmpty,
+    /// No available node provides the plugin that is needed for the request
+    #[error("no available node provides the {plugin} plugin, nodes with the plugin: {capable_nodes:?}")]
+    PluginUnavailable {
+        /// The name of the plugin.
+        plugin: &'static str,
+        /// The urls of the nodes that provide the plugin, but are currently not healthy or disabled.
+        capable_nodes: Vec<String>,
+    },
+    /// Signature unlocks of a partially signed transaction are missing
+    #[error("{0} signature unlocks are missing")]
+    MissingSignatures(usize),
+    /// Partially signed transactions that should be merged are not the same transaction
+    #[error("partially signed transactions don't match")]
+    PartiallySignedTransactionMismatch,
+    /// The amount of unlocks of a partially signed transaction doesn't match the amount of inputs
+    #[error("{unlocks} unlocks provided for {inputs} inputs")]
+    UnlockCountMismatch {
+        /// The amount of inputs
+        inputs: usize,
+        /// The amount of unlocks
+        unlocks: usize,
+    },
+    /// The milestone was pruned on all available nodes
+    #[error("milestone {0} is pruned on all available nodes")]
+    MilestonePruned(u32),
     /// Error when building tagged_data blocks
     #[error("error when building tagged_data block: {0}")]
     TaggedData(String),
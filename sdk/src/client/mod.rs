@@ -53,7 +53,7 @@ pub mod utils;
 #[cfg(feature = "mqtt")]
 pub use self::node_api::mqtt;
 pub use self::{
-    builder::{ClientBuilder, NetworkInfo, NetworkInfoDto},
+    builder::{ClientBuilder, NetworkInfo, NetworkInfoDto, TimeSource},
     client::*,
     error::*,
     node_api::core::routes::NodeInfoWrapper,
@@ -61,7 +61,7 @@ pub use self::{
 };
 
 #[cfg(feature = "mqtt")]
-mod async_runtime {
+pub(crate) mod async_runtime {
     use std::sync::Mutex;
 
     use once_cell::sync::OnceCell;
@@ -37,6 +37,7 @@ use crate::{
 
 /// Info path is the exact path extension for node APIs to request their info.
 pub(crate) static INFO_PATH: &str = "api/core/v2/info";
+pub(crate) static TIPS_PATH: &str = "api/core/v2/tips";
 
 /// NodeInfo wrapper which contains the node info and the url from the node (useful when multiple nodes are used)
 #[derive(Debug, Serialize, Deserialize)]
@@ -103,13 +104,11 @@ impl ClientInner {
     /// Returns tips that are ideal for attaching a block.
     /// GET /api/core/v2/tips
     pub async fn get_tips(&self) -> Result<Vec<BlockId>> {
-        let path = "api/core/v2/tips";
-
         let resp = self
             .node_manager
             .read()
             .await
-            .get_request::<TipsResponse>(path, None, self.get_timeout().await, false, false)
+            .get_request::<TipsResponse>(TIPS_PATH, None, self.get_timeout().await, false, false)
             .await?;
 
         resp.tips
@@ -172,7 +171,10 @@ impl ClientInner {
             Err(e) => return Err(e),
         };
 
-        Ok(BlockId::from_str(&resp.block_id)?)
+        let block_id = BlockId::from_str(&resp.block_id)?;
+        self.set_last_submitted_block(block_id);
+
+        Ok(block_id)
     }
 
     /// Returns the BlockId of the submitted block.
@@ -224,7 +226,10 @@ impl ClientInner {
             Err(e) => return Err(e),
         };
 
-        Ok(BlockId::from_str(&resp.block_id)?)
+        let block_id = BlockId::from_str(&resp.block_id)?;
+        self.set_last_submitted_block(block_id);
+
+        Ok(block_id)
     }
 
     /// Finds a block by its BlockId. This method returns the given block object.
@@ -236,7 +241,7 @@ impl ClientInner {
             .node_manager
             .read()
             .await
-            .get_request::<BlockResponse>(path, None, self.get_timeout().await, false, true)
+            .get_read_request::<BlockResponse>(path, None, self.get_timeout().await, false, true)
             .await?;
 
         match resp {
@@ -280,7 +285,7 @@ impl ClientInner {
             .node_manager
             .read()
             .await
-            .get_request(path, None, self.get_timeout().await, false, true)
+            .get_read_request(path, None, self.get_timeout().await, false, true)
             .await?;
 
         let token_supply = self.get_token_supply().await?;
@@ -310,7 +315,7 @@ impl ClientInner {
         self.node_manager
             .read()
             .await
-            .get_request::<OutputMetadataDto>(path, None, self.get_timeout().await, false, true)
+            .get_read_request::<OutputMetadataDto>(path, None, self.get_timeout().await, false, true)
             .await
     }
 
@@ -559,4 +564,34 @@ impl Client {
 
         Ok(resp)
     }
+
+    /// GET /api/routes endpoint
+    pub async fn get_node_routes(url: &str, auth: Option<NodeAuth>) -> Result<RoutesResponse> {
+        let mut url = crate::client::node_manager::builder::validate_url(Url::parse(url)?)?;
+        if let Some(auth) = &auth {
+            if let Some((name, password)) = &auth.basic_auth_name_pwd {
+                url.set_username(name)
+                    .map_err(|_| crate::client::Error::UrlAuth("username"))?;
+                url.set_password(Some(password))
+                    .map_err(|_| crate::client::Error::UrlAuth("password"))?;
+            }
+        }
+        url.set_path("api/routes");
+
+        let resp: RoutesResponse =
+            crate::client::node_manager::http_client::HttpClient::new(DEFAULT_USER_AGENT.to_string())
+                .get(
+                    Node {
+                        url,
+                        auth,
+                        disabled: false,
+                    },
+                    DEFAULT_API_TIMEOUT,
+                )
+                .await?
+                .into_json()
+                .await?;
+
+        Ok(resp)
+    }
 }
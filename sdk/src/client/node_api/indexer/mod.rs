@@ -3,9 +3,11 @@
 
 //! Node indexer API.
 
+pub mod query_builder;
 pub mod query_parameters;
 pub mod routes;
 
+use self::query_builder::OutputQuery;
 pub(crate) use self::query_parameters::{QueryParameter, QueryParameters};
 use crate::{
     client::{ClientInner, Result},
@@ -33,16 +35,7 @@ impl ClientInner {
 
         while let Some(cursor) = {
             let output_ids_response = self
-                .node_manager
-                .read()
-                .await
-                .get_request::<OutputIdsResponse>(
-                    route,
-                    query_parameters.to_query_string().as_deref(),
-                    self.get_timeout().await,
-                    need_quorum,
-                    prefer_permanode,
-                )
+                .get_output_ids_page(route, &query_parameters, need_quorum, prefer_permanode)
                 .await?;
 
             if return_early {
@@ -60,4 +53,47 @@ impl ClientInner {
 
         Ok(merged_output_ids_response)
     }
+
+    /// Get all output ids matching a typed query, all pages are queried and merged.
+    /// If a cursor is set in the query, only a single page will be queried.
+    pub async fn output_ids<Q: OutputQuery + Send>(&self, query: Q) -> Result<OutputIdsResponse> {
+        self.get_output_ids(Q::ROUTE, query.into_query_parameters(), true, false)
+            .await
+    }
+
+    /// Get a single page of output ids matching a typed query. The first page is returned if no cursor is provided,
+    /// the cursor of the response can be used to get the next page.
+    pub async fn output_ids_page<Q: OutputQuery + Send>(
+        &self,
+        query: Q,
+        cursor: Option<String>,
+    ) -> Result<OutputIdsResponse> {
+        let mut query_parameters = query.into_query_parameters();
+
+        if let Some(cursor) = cursor {
+            query_parameters.replace(QueryParameter::Cursor(cursor));
+        }
+
+        self.get_output_ids_page(Q::ROUTE, &query_parameters, true, false).await
+    }
+
+    async fn get_output_ids_page(
+        &self,
+        route: &str,
+        query_parameters: &QueryParameters,
+        need_quorum: bool,
+        prefer_permanode: bool,
+    ) -> Result<OutputIdsResponse> {
+        self.node_manager
+            .read()
+            .await
+            .get_read_request::<OutputIdsResponse>(
+                route,
+                query_parameters.to_query_string().as_deref(),
+                self.get_timeout().await,
+                need_quorum,
+                prefer_permanode,
+            )
+            .await
+    }
 }
@@ -0,0 +1,231 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Typed query builders for output_id requests
+
+use crate::{
+    client::{
+        node_api::indexer::query_parameters::{QueryParameter, QueryParameters},
+        Error, Result,
+    },
+    types::block::address::Bech32Address,
+};
+
+/// A typed query for one of the indexer output routes.
+pub trait OutputQuery {
+    /// The indexer route of the query.
+    const ROUTE: &'static str;
+
+    /// Returns the query parameters of the query.
+    fn into_query_parameters(self) -> QueryParameters;
+}
+
+// Generates a setter for each query parameter supported by the route, next to the ones supported by all output
+// routes. The supported parameters are also used to verify untyped `QueryParameter`s for the route.
+macro_rules! impl_output_query {
+    ($ty:ident, $route:literal, { $($method:ident: $param_ty:ty => $variant:ident),* $(,)? } $(, also: [$($extra:ident),*])?) => {
+        impl $ty {
+            /// Creates a new query without any filter.
+            pub fn new() -> Self {
+                Self(QueryParameters::empty())
+            }
+
+            impl_output_query!(@setters
+                $($method: $param_ty => $variant,)*
+                page_size: usize => PageSize,
+                created_after: u32 => CreatedAfter,
+                created_before: u32 => CreatedBefore,
+                has_native_tokens: bool => HasNativeTokens,
+                min_native_token_count: u32 => MinNativeTokenCount,
+                max_native_token_count: u32 => MaxNativeTokenCount,
+            );
+
+            /// Sets [`QueryParameter::Cursor`], only the single page starting at the cursor will be queried.
+            #[must_use]
+            pub fn cursor(mut self, cursor: impl Into<String>) -> Self {
+                self.0.replace(QueryParameter::Cursor(cursor.into()));
+                self
+            }
+
+            // Returns true if the route supports the query parameter.
+            fn supports(query_parameter: &QueryParameter) -> bool {
+                matches!(
+                    query_parameter,
+                    $(QueryParameter::$variant(_) |)*
+                    $($(QueryParameter::$extra(_) |)*)?
+                    QueryParameter::PageSize(_)
+                        | QueryParameter::Cursor(_)
+                        | QueryParameter::CreatedAfter(_)
+                        | QueryParameter::CreatedBefore(_)
+                        | QueryParameter::HasNativeTokens(_)
+                        | QueryParameter::MinNativeTokenCount(_)
+                        | QueryParameter::MaxNativeTokenCount(_)
+                )
+            }
+        }
+
+        impl Default for $ty {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl TryFrom<Vec<QueryParameter>> for $ty {
+            type Error = Error;
+
+            fn try_from(query_parameters: Vec<QueryParameter>) -> Result<Self> {
+                if let Some(query_parameter) = query_parameters.iter().find(|qp| !Self::supports(qp)) {
+                    return Err(Error::UnsupportedQueryParameter(query_parameter.clone()));
+                }
+
+                Ok(Self(QueryParameters::new(query_parameters)))
+            }
+        }
+
+        impl OutputQuery for $ty {
+            const ROUTE: &'static str = $route;
+
+            fn into_query_parameters(self) -> QueryParameters {
+                self.0
+            }
+        }
+    };
+    (@setters $($method:ident: $param_ty:ty => $variant:ident,)*) => {
+        $(
+            #[doc = concat!("Sets [`QueryParameter::", stringify!($variant), "`].")]
+            #[must_use]
+            pub fn $method(mut self, value: $param_ty) -> Self {
+                self.0.replace(QueryParameter::$variant(value));
+                self
+            }
+        )*
+    };
+}
+
+/// Query for basic outputs, see [`ClientInner::output_ids()`](crate::client::ClientInner::output_ids).
+/// ```ignore
+/// let query = BasicOutputQuery::new().address(address).has_expiration(false).created_after(timestamp);
+/// let output_ids = client.output_ids(query).await?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct BasicOutputQuery(QueryParameters);
+
+impl_output_query!(BasicOutputQuery, "api/indexer/v1/outputs/basic", {
+    address: Bech32Address => Address,
+    has_storage_deposit_return: bool => HasStorageDepositReturn,
+    storage_deposit_return_address: Bech32Address => StorageDepositReturnAddress,
+    has_timelock: bool => HasTimelock,
+    timelocked_before: u32 => TimelockedBefore,
+    timelocked_after: u32 => TimelockedAfter,
+    has_expiration: bool => HasExpiration,
+    expires_before: u32 => ExpiresBefore,
+    expires_after: u32 => ExpiresAfter,
+    expiration_return_address: Bech32Address => ExpirationReturnAddress,
+    sender: Bech32Address => Sender,
+}, also: [Tag]);
+
+impl BasicOutputQuery {
+    /// Sets [`QueryParameter::Tag`] to the hex encoded tag.
+    #[must_use]
+    pub fn tag(mut self, tag: impl AsRef<[u8]>) -> Self {
+        self.0.replace(QueryParameter::Tag(prefix_hex::encode(tag.as_ref())));
+        self
+    }
+}
+
+/// Query for alias outputs, see [`ClientInner::output_ids()`](crate::client::ClientInner::output_ids).
+#[derive(Debug, Clone)]
+pub struct AliasOutputQuery(QueryParameters);
+
+impl_output_query!(AliasOutputQuery, "api/indexer/v1/outputs/alias", {
+    state_controller: Bech32Address => StateController,
+    governor: Bech32Address => Governor,
+    issuer: Bech32Address => Issuer,
+    sender: Bech32Address => Sender,
+});
+
+/// Query for foundry outputs, see [`ClientInner::output_ids()`](crate::client::ClientInner::output_ids).
+#[derive(Debug, Clone)]
+pub struct FoundryOutputQuery(QueryParameters);
+
+impl_output_query!(FoundryOutputQuery, "api/indexer/v1/outputs/foundry", {
+    alias_address: Bech32Address => AliasAddress,
+});
+
+/// Query for nft outputs, see [`ClientInner::output_ids()`](crate::client::ClientInner::output_ids).
+#[derive(Debug, Clone)]
+pub struct NftOutputQuery(QueryParameters);
+
+impl_output_query!(NftOutputQuery, "api/indexer/v1/outputs/nft", {
+    address: Bech32Address => Address,
+    has_storage_deposit_return: bool => HasStorageDepositReturn,
+    storage_deposit_return_address: Bech32Address => StorageDepositReturnAddress,
+    has_timelock: bool => HasTimelock,
+    timelocked_before: u32 => TimelockedBefore,
+    timelocked_after: u32 => TimelockedAfter,
+    has_expiration: bool => HasExpiration,
+    expires_before: u32 => ExpiresBefore,
+    expires_after: u32 => ExpiresAfter,
+    expiration_return_address: Bech32Address => ExpirationReturnAddress,
+    issuer: Bech32Address => Issuer,
+    sender: Bech32Address => Sender,
+}, also: [Tag]);
+
+impl NftOutputQuery {
+    /// Sets [`QueryParameter::Tag`] to the hex encoded tag.
+    #[must_use]
+    pub fn tag(mut self, tag: impl AsRef<[u8]>) -> Self {
+        self.0.replace(QueryParameter::Tag(prefix_hex::encode(tag.as_ref())));
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn basic_output_query() {
+        let address =
+            Bech32Address::try_from_str("atoi1qzt0nhsf38nh6rs4p6zs5knqp6psgha9wsv74uajqgjmwc75ugupx3y7x0r").unwrap();
+
+        let query_parameters = BasicOutputQuery::new()
+            .created_after(1)
+            .has_expiration(false)
+            .address(address)
+            .tag(b"tag")
+            .created_after(2)
+            .into_query_parameters();
+
+        // Parameters are sorted and replaced by later ones of the same kind
+        assert_eq!(
+            query_parameters.to_query_string().unwrap(),
+            "address=atoi1qzt0nhsf38nh6rs4p6zs5knqp6psgha9wsv74uajqgjmwc75ugupx3y7x0r&createdAfter=2&hasExpiration=false&tag=0x746167"
+        );
+        assert_eq!(NftOutputQuery::new().into_query_parameters().to_query_string(), None);
+    }
+
+    #[test]
+    fn output_query_from_query_parameters() {
+        let address =
+            Bech32Address::try_from_str("atoi1qzt0nhsf38nh6rs4p6zs5knqp6psgha9wsv74uajqgjmwc75ugupx3y7x0r").unwrap();
+
+        let query_parameters = FoundryOutputQuery::try_from(vec![
+            QueryParameter::AliasAddress(address.clone()),
+            QueryParameter::PageSize(10),
+        ])
+        .unwrap()
+        .into_query_parameters();
+        assert_eq!(
+            query_parameters.to_query_string().unwrap(),
+            "aliasAddress=atoi1qzt0nhsf38nh6rs4p6zs5knqp6psgha9wsv74uajqgjmwc75ugupx3y7x0r&pageSize=10"
+        );
+
+        assert!(matches!(
+            FoundryOutputQuery::try_from(vec![QueryParameter::Address(address.clone())]),
+            Err(Error::UnsupportedQueryParameter(QueryParameter::Address(_)))
+        ));
+        assert!(AliasOutputQuery::try_from(vec![QueryParameter::Tag("0x00".to_string())]).is_err());
+        assert!(NftOutputQuery::try_from(vec![QueryParameter::Tag("0x00".to_string())]).is_ok());
+    }
+}
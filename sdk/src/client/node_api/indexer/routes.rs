@@ -6,10 +6,8 @@
 use crate::{
     client::{
         node_api::indexer::{
-            query_parameters::{
-                verify_query_parameters_alias_outputs, verify_query_parameters_basic_outputs,
-                verify_query_parameters_foundry_outputs, verify_query_parameters_nft_outputs, QueryParameter,
-            },
+            query_builder::{AliasOutputQuery, BasicOutputQuery, FoundryOutputQuery, NftOutputQuery},
+            query_parameters::QueryParameter,
             QueryParameters,
         },
         ClientInner, Error, Result,
@@ -30,11 +28,7 @@ impl ClientInner {
     /// "timelockedAfter", "sender", "tag", "createdBefore" and "createdAfter". Returns an empty Vec if no results
     /// are found. api/indexer/v1/outputs/basic
     pub async fn basic_output_ids(&self, query_parameters: Vec<QueryParameter>) -> Result<OutputIdsResponse> {
-        let route = "api/indexer/v1/outputs/basic";
-
-        let query_parameters = verify_query_parameters_basic_outputs(query_parameters)?;
-
-        self.get_output_ids(route, query_parameters, true, false).await
+        self.output_ids(BasicOutputQuery::try_from(query_parameters)?).await
     }
 
     /// Get alias outputs filtered by the given parameters.
@@ -43,11 +37,7 @@ impl ClientInner {
     /// Returns an empty list if no results are found.
     /// api/indexer/v1/outputs/alias
     pub async fn alias_output_ids(&self, query_parameters: Vec<QueryParameter>) -> Result<OutputIdsResponse> {
-        let route = "api/indexer/v1/outputs/alias";
-
-        let query_parameters = verify_query_parameters_alias_outputs(query_parameters)?;
-
-        self.get_output_ids(route, query_parameters, true, false).await
+        self.output_ids(AliasOutputQuery::try_from(query_parameters)?).await
     }
 
     /// Get alias output by its aliasID.
@@ -68,11 +58,7 @@ impl ClientInner {
     /// Returns an empty list if no results are found.
     /// api/indexer/v1/outputs/foundry
     pub async fn foundry_output_ids(&self, query_parameters: Vec<QueryParameter>) -> Result<OutputIdsResponse> {
-        let route = "api/indexer/v1/outputs/foundry";
-
-        let query_parameters = verify_query_parameters_foundry_outputs(query_parameters)?;
-
-        self.get_output_ids(route, query_parameters, true, false).await
+        self.output_ids(FoundryOutputQuery::try_from(query_parameters)?).await
     }
 
     /// Get foundry output by its foundryID.
@@ -94,11 +80,7 @@ impl ClientInner {
     /// Returns an empty list if no results are found.
     /// api/indexer/v1/outputs/nft
     pub async fn nft_output_ids(&self, query_parameters: Vec<QueryParameter>) -> Result<OutputIdsResponse> {
-        let route = "api/indexer/v1/outputs/nft";
-
-        let query_parameters = verify_query_parameters_nft_outputs(query_parameters)?;
-
-        self.get_output_ids(route, query_parameters, true, false).await
+        self.output_ids(NftOutputQuery::try_from(query_parameters)?).await
     }
 
     /// Get NFT output by its nftID.
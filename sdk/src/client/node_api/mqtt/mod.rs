@@ -106,7 +106,8 @@ async fn set_mqtt_client(client: &Client) -> Result<(), Error> {
                 }
                 mqtt_options
             };
-            let (_, mut connection) = AsyncClient::new(mqtt_options.clone(), 10);
+            let (mqtt_client, mut connection) = AsyncClient::new(mqtt_options, 10);
+            // the event loop that is checked is also the one that is kept, so the timeout applies to its reconnections
             connection
                 .set_network_options(*NetworkOptions::new().set_connection_timeout(broker_options.timeout.as_secs()));
             // poll the event loop until we find a ConnAck event,
@@ -122,9 +123,10 @@ async fn set_mqtt_client(client: &Client) -> Result<(), Error> {
 
             // if we found a valid mqtt connection, loop it on a separate thread
             if got_ack {
-                let (mqtt_client, connection) = AsyncClient::new(mqtt_options, 10);
+                let _ = client.mqtt.sender.read().await.send(MqttEvent::Connected);
                 client.mqtt.client.write().await.replace(mqtt_client);
                 poll_mqtt(client, connection);
+                break;
             }
         }
     }
@@ -311,6 +313,45 @@ impl<'a> MqttManager<'a> {
 
         Ok(())
     }
+
+    /// Reconnects the broker with the current broker options and nodes.
+    /// The stored topic handlers are kept and their topics are subscribed again. Without topic handlers there is nothing
+    /// to subscribe to, so the connection is only closed and opened again with the next subscription.
+    pub async fn reconnect(self) -> Result<(), Error> {
+        if let Some(client) = self.client.mqtt.client.write().await.take() {
+            client.disconnect().await?;
+        }
+
+        let topics = self
+            .client
+            .mqtt
+            .topic_handlers
+            .read()
+            .await
+            .keys()
+            .cloned()
+            .collect::<Vec<_>>();
+        if topics.is_empty() {
+            return Ok(());
+        }
+
+        set_mqtt_client(self.client).await?;
+        self.client
+            .mqtt
+            .client
+            .read()
+            .await
+            .as_ref()
+            .ok_or(Error::ConnectionNotFound)?
+            .subscribe_many(
+                topics
+                    .iter()
+                    .map(|t| SubscribeFilter::new(t.topic().to_string(), QoS::AtLeastOnce)),
+            )
+            .await?;
+
+        Ok(())
+    }
 }
 
 /// The MQTT topic manager.
@@ -17,6 +17,7 @@ use crate::{
         constants::{DEFAULT_MIN_QUORUM_SIZE, DEFAULT_QUORUM_THRESHOLD, DEFAULT_USER_AGENT, NODE_SYNC_INTERVAL},
         error::{Error, Result},
         node_manager::{
+            health::NodeSelectionPolicy,
             http_client::HttpClient,
             node::{Node, NodeAuth, NodeDto},
             NodeManager,
@@ -55,6 +56,13 @@ pub struct NodeManagerBuilder {
     /// % of nodes that have to return the same response so it gets accepted
     #[serde(default = "default_quorum_threshold")]
     pub quorum_threshold: usize,
+    /// If reads like blocks, outputs and indexer queries are compared with multiple nodes, with the same
+    /// `min_quorum_size` and `quorum_threshold` as the quorum
+    #[serde(default)]
+    pub read_quorum: bool,
+    /// The policy that decides in which order the nodes are tried for a request
+    #[serde(default)]
+    pub node_selection_policy: NodeSelectionPolicy,
     /// The User-Agent header for requests
     #[serde(default = "default_user_agent")]
     pub user_agent: String,
@@ -203,6 +211,11 @@ impl NodeManagerBuilder {
         self
     }
 
+    pub(crate) fn with_read_quorum(mut self, read_quorum: bool) -> Self {
+        self.read_quorum = read_quorum;
+        self
+    }
+
     pub(crate) fn with_min_quorum_size(mut self, min_quorum_size: usize) -> Self {
         self.min_quorum_size = min_quorum_size;
         self
@@ -213,6 +226,11 @@ impl NodeManagerBuilder {
         self
     }
 
+    pub(crate) fn with_node_selection_policy(mut self, node_selection_policy: NodeSelectionPolicy) -> Self {
+        self.node_selection_policy = node_selection_policy;
+        self
+    }
+
     pub(crate) fn with_user_agent(mut self, user_agent: String) -> Self {
         self.user_agent = user_agent;
         self
@@ -229,10 +247,18 @@ impl NodeManagerBuilder {
             ignore_node_health: self.ignore_node_health,
             node_sync_interval: self.node_sync_interval,
             healthy_nodes: RwLock::new(healthy_nodes),
+            node_capabilities: RwLock::new(HashMap::new()),
+            node_routes: RwLock::new(HashMap::new()),
+            routing_metrics: RwLock::new(HashMap::new()),
             quorum: self.quorum,
             min_quorum_size: self.min_quorum_size,
             quorum_threshold: self.quorum_threshold,
+            read_quorum: self.read_quorum,
+            node_selection_policy: self.node_selection_policy,
+            node_health: RwLock::new(HashMap::new()),
+            round_robin_offset: Default::default(),
             http_client: HttpClient::new(self.user_agent),
+            in_flight_requests: Default::default(),
         }
     }
 }
@@ -249,6 +275,8 @@ impl Default for NodeManagerBuilder {
             quorum: false,
             min_quorum_size: DEFAULT_MIN_QUORUM_SIZE,
             quorum_threshold: DEFAULT_QUORUM_THRESHOLD,
+            read_quorum: false,
+            node_selection_policy: NodeSelectionPolicy::default(),
             user_agent: DEFAULT_USER_AGENT.to_string(),
         }
     }
@@ -277,6 +305,8 @@ impl From<&NodeManager> for NodeManagerBuilder {
             quorum: value.quorum,
             min_quorum_size: value.min_quorum_size,
             quorum_threshold: value.quorum_threshold,
+            read_quorum: value.read_quorum,
+            node_selection_policy: value.node_selection_policy,
             user_agent: value.http_client.user_agent.clone(),
         }
     }
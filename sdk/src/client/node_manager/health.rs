@@ -0,0 +1,89 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+// Weight of the latest request outcome in the moving averages
+const SMOOTHING_FACTOR: f64 = 0.2;
+// Latency in milliseconds at which the latency halves the score
+const REFERENCE_LATENCY_MS: f64 = 500.0;
+
+/// The policy that decides in which order the nodes are tried for a request.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum NodeSelectionPolicy {
+    /// The primary node is tried first, then the other nodes ordered by their [`NodeHealth::score()`].
+    #[default]
+    PrimaryWithFallback,
+    /// All nodes, including the primary node, are ordered by their [`NodeHealth::score()`], so the fastest and most
+    /// reliable node is tried first.
+    LatencyWeighted,
+    /// The requests are distributed evenly over the nodes, by starting with the next node for every request.
+    RoundRobin,
+    /// The responses of multiple nodes are compared, like with
+    /// [`ClientBuilder::with_quorum()`](crate::client::ClientBuilder::with_quorum).
+    Quorum,
+}
+
+/// The health of a node, continuously updated with the outcomes of the requests and the milestone lag detected while
+/// syncing the nodes.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeHealth {
+    /// Moving average of the successful requests, between 0 and 1.
+    pub success_rate: f64,
+    /// Moving average of the request latency in milliseconds.
+    pub latency_ms: f64,
+    /// Amount of milestones the node is behind the most synced node.
+    pub milestone_lag: u32,
+    /// Amount of requests sent to the node.
+    pub requests: u64,
+    /// Amount of requests that failed, because the node couldn't be reached or had an internal error.
+    pub failures: u64,
+}
+
+impl Default for NodeHealth {
+    fn default() -> Self {
+        Self {
+            success_rate: 1.0,
+            latency_ms: 0.0,
+            milestone_lag: 0,
+            requests: 0,
+            failures: 0,
+        }
+    }
+}
+
+impl NodeHealth {
+    /// Returns the score of the node between 0 and 1, higher is better. Failed requests, high latency and milestone
+    /// lag lower the score. Nodes without requests are assumed to have the reference latency of 500ms, so they don't
+    /// outrank nodes that were measured to be fast.
+    pub fn score(&self) -> f64 {
+        let latency_ms = if self.requests == 0 {
+            REFERENCE_LATENCY_MS
+        } else {
+            self.latency_ms
+        };
+        self.success_rate
+            * (1.0 / (1.0 + self.milestone_lag as f64))
+            * (REFERENCE_LATENCY_MS / (REFERENCE_LATENCY_MS + latency_ms))
+    }
+
+    /// Updates the moving averages with the outcome of a request.
+    pub(crate) fn record_request(&mut self, success: bool, latency: Duration) {
+        let latency_ms = latency.as_secs_f64() * 1000.0;
+        if self.requests == 0 {
+            self.latency_ms = latency_ms;
+        } else {
+            self.latency_ms = SMOOTHING_FACTOR.mul_add(latency_ms - self.latency_ms, self.latency_ms);
+        }
+        let outcome = if success { 1.0 } else { 0.0 };
+        self.success_rate = SMOOTHING_FACTOR.mul_add(outcome - self.success_rate, self.success_rate);
+        self.requests += 1;
+        if !success {
+            self.failures += 1;
+        }
+    }
+}
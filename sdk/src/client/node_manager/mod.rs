@@ -4,6 +4,8 @@
 //! The node manager that takes care of sending requests with healthy nodes and quorum if enabled
 
 pub mod builder;
+/// Node health scoring and selection policies
+pub mod health;
 pub(crate) mod http_client;
 /// Structs for nodes
 pub mod node;
@@ -11,13 +13,22 @@ pub(crate) mod syncing;
 
 use std::{
     collections::{HashMap, HashSet},
-    sync::RwLock,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex, RwLock,
+    },
     time::Duration,
 };
 
+use instant::Instant;
 use serde_json::Value;
+use tokio::sync::watch;
 
-use self::{http_client::HttpClient, node::Node};
+use self::{
+    health::{NodeHealth, NodeSelectionPolicy},
+    http_client::HttpClient,
+    node::{Node, NodeCapabilities, RequiredCapability, RoutingMetrics},
+};
 use crate::{
     client::{
         error::{Error, Result},
@@ -36,10 +47,26 @@ pub struct NodeManager {
     pub(crate) ignore_node_health: bool,
     node_sync_interval: Duration,
     pub(crate) healthy_nodes: RwLock<HashMap<Node, InfoResponse>>,
+    pub(crate) node_capabilities: RwLock<HashMap<Node, NodeCapabilities>>,
+    // The routes of the nodes with the node version they were requested for, so they're only requested again when the
+    // node got updated
+    pub(crate) node_routes: RwLock<HashMap<Node, (String, Vec<String>)>>,
+    // Locked per capability like the node health
+    pub(crate) routing_metrics: RwLock<HashMap<&'static str, Mutex<RoutingMetrics>>>,
     quorum: bool,
     min_quorum_size: usize,
     quorum_threshold: usize,
+    read_quorum: bool,
+    node_selection_policy: NodeSelectionPolicy,
+    // The health of the nodes by url origin, updated with every request and while syncing the nodes. The nodes are
+    // locked separately, so the map is only locked for writing when a node is added.
+    pub(crate) node_health: RwLock<HashMap<String, Mutex<NodeHealth>>>,
+    // Offset of the first node for the round robin policy, increased with every request
+    round_robin_offset: AtomicUsize,
     pub(crate) http_client: HttpClient,
+    // Receivers for the JSON responses of the GET requests that are currently sent, by request key, so identical
+    // concurrent requests are only sent once
+    in_flight_requests: Mutex<HashMap<String, watch::Receiver<Option<String>>>>,
 }
 
 impl std::fmt::Debug for NodeManager {
@@ -52,9 +79,15 @@ impl std::fmt::Debug for NodeManager {
         d.field("ignore_node_health", &self.ignore_node_health);
         d.field("node_sync_interval", &self.node_sync_interval);
         d.field("healthy_nodes", &self.healthy_nodes);
+        d.field("node_capabilities", &self.node_capabilities);
+        d.field("node_routes", &self.node_routes);
+        d.field("routing_metrics", &self.routing_metrics);
         d.field("quorum", &self.quorum);
         d.field("min_quorum_size", &self.min_quorum_size);
-        d.field("quorum_threshold", &self.quorum_threshold).finish()
+        d.field("quorum_threshold", &self.quorum_threshold);
+        d.field("read_quorum", &self.read_quorum);
+        d.field("node_selection_policy", &self.node_selection_policy);
+        d.field("node_health", &self.node_health).finish()
     }
 }
 
@@ -90,34 +123,16 @@ impl NodeManager {
             }
         }
 
-        if let Some(primary_node) = self.primary_node.clone() {
-            if !nodes_with_modified_url.iter().any(|n| n.url == primary_node.url) {
-                nodes_with_modified_url.push(primary_node);
-            }
-        }
+        let mut candidates: Vec<Node> = self.primary_node.iter().cloned().collect();
 
-        // Add other nodes in random order, so they are not always used in the same order
-        let nodes_random_order = if !self.ignore_node_health {
+        let pool_nodes = if !self.ignore_node_health {
             #[cfg(not(target_family = "wasm"))]
             {
                 self.healthy_nodes
                     .read()
                     .map_err(|_| crate::client::Error::PoisonError)?
-                    .iter()
-                    .filter_map(|(n, info)| {
-                        // Only add nodes with pow feature enabled, when remote PoW is used
-                        if use_pow_nodes {
-                            let pow_feature = String::from("pow");
-
-                            if info.features.contains(&pow_feature) {
-                                Some(n.clone())
-                            } else {
-                                None
-                            }
-                        } else {
-                            Some(n.clone())
-                        }
-                    })
+                    .keys()
+                    .cloned()
                     .collect()
             }
             #[cfg(target_family = "wasm")]
@@ -128,8 +143,14 @@ impl NodeManager {
             self.nodes.clone()
         };
 
-        // Add remaining nodes in random order
-        for node in nodes_random_order {
+        for node in pool_nodes {
+            if !candidates.iter().any(|n| n.url == node.url) {
+                candidates.push(node);
+            }
+        }
+
+        // Order the primary node and the pool nodes with the selection policy
+        for node in self.order_nodes(candidates)? {
             if !nodes_with_modified_url.iter().any(|n| n.url == node.url) {
                 nodes_with_modified_url.push(node);
             }
@@ -138,6 +159,12 @@ impl NodeManager {
         // remove disabled nodes
         nodes_with_modified_url.retain(|n| !n.disabled);
 
+        // Nodes known to lack a capability that the request needs are skipped and permanodes are used as fallback if no
+        // other node is left
+        for capability in RequiredCapability::for_request(path, use_pow_nodes) {
+            self.retain_capable_nodes(&mut nodes_with_modified_url, capability)?;
+        }
+
         if nodes_with_modified_url.is_empty() {
             if use_pow_nodes {
                 return Err(crate::client::Error::Node(
@@ -166,6 +193,152 @@ impl NodeManager {
         Ok(nodes_with_modified_url)
     }
 
+    // Orders the candidates, which start with the primary node if one is set, with the node selection policy. Nodes
+    // with the same score keep the random order of the node pool, so they are not always used in the same order.
+    fn order_nodes(&self, mut nodes: Vec<Node>) -> Result<Vec<Node>> {
+        match self.node_selection_policy {
+            NodeSelectionPolicy::PrimaryWithFallback | NodeSelectionPolicy::Quorum => {
+                let skip = usize::from(self.primary_node.is_some()).min(nodes.len());
+                self.sort_by_score(&mut nodes[skip..])?;
+            }
+            NodeSelectionPolicy::LatencyWeighted => self.sort_by_score(&mut nodes)?,
+            NodeSelectionPolicy::RoundRobin => {
+                nodes.sort_by(|a, b| a.url.as_str().cmp(b.url.as_str()));
+                if !nodes.is_empty() {
+                    let offset = self.round_robin_offset.fetch_add(1, Ordering::Relaxed) % nodes.len();
+                    nodes.rotate_left(offset);
+                }
+            }
+        }
+        Ok(nodes)
+    }
+
+    fn sort_by_score(&self, nodes: &mut [Node]) -> Result<()> {
+        let node_health = self.node_health.read().map_err(|_| crate::client::Error::PoisonError)?;
+        let score = |node: &Node| {
+            node_health
+                .get(&health_key(node))
+                .and_then(|health| health.lock().ok().map(|health| health.score()))
+                .unwrap_or_else(|| NodeHealth::default().score())
+        };
+        nodes.sort_by(|a, b| score(b).total_cmp(&score(a)));
+        Ok(())
+    }
+
+    // Updates the health of a node with the outcome of a request, errors of the node api count as responses, unless
+    // the node couldn't be reached or had an internal error
+    fn record_request<T>(&self, node: &Node, result: &crate::client::node_api::error::Result<T>, latency: Duration) {
+        use crate::client::node_api::error::Error as NodeError;
+        let success = !matches!(
+            result,
+            Err(NodeError::Reqwest(_) | NodeError::ResponseError { code: 500.., .. })
+        );
+        let key = health_key(node);
+        if let Ok(node_health) = self.node_health.read() {
+            if let Some(health) = node_health.get(&key) {
+                if let Ok(mut health) = health.lock() {
+                    health.record_request(success, latency);
+                }
+                return;
+            }
+        }
+        // First request to the node
+        if let Ok(mut node_health) = self.node_health.write() {
+            if let Ok(health) = node_health.entry(key).or_default().get_mut() {
+                health.record_request(success, latency);
+            }
+        }
+    }
+
+    // Quorum is used if it's enabled or the node selection policy is quorum
+    fn quorum_enabled(&self) -> bool {
+        self.quorum || self.node_selection_policy == NodeSelectionPolicy::Quorum
+    }
+
+    fn retain_capable_nodes(&self, nodes: &mut Vec<Node>, capability: RequiredCapability) -> Result<()> {
+        let node_capabilities = self
+            .node_capabilities
+            .read()
+            .map_err(|_| crate::client::Error::PoisonError)?;
+        // The counters of this request, added to the routing metrics once the nodes are selected
+        let mut metrics = RoutingMetrics {
+            requests: 1,
+            ..Default::default()
+        };
+
+        let (capable_nodes, skipped_nodes): (Vec<Node>, Vec<Node>) = nodes.drain(..).partition(|n| {
+            node_capabilities
+                .get(n)
+                .map_or(true, |capabilities| capabilities.supports(capability))
+        });
+        *nodes = capable_nodes;
+        if !skipped_nodes.is_empty() {
+            metrics.skipped_nodes += skipped_nodes.len() as u64;
+            log::debug!(
+                "[get_nodes] skipped nodes without {capability:?}: {:?}",
+                skipped_nodes.iter().map(|n| n.url.as_str()).collect::<Vec<_>>()
+            );
+        }
+
+        // Permanodes don't do remote PoW
+        if nodes.is_empty() && capability != RequiredCapability::Pow {
+            if let Some(permanodes) = &self.permanodes {
+                nodes.extend(permanodes.iter().filter(|n| !n.disabled).cloned());
+            }
+            if !nodes.is_empty() {
+                metrics.permanode_fallbacks += 1;
+                log::debug!("[get_nodes] no node with {capability:?}, falling back to the permanodes");
+            }
+        }
+        metrics.unavailable = u64::from(nodes.is_empty());
+        self.record_routing_metrics(capability.name(), metrics);
+        if nodes.is_empty() {
+            log::debug!("[get_nodes] no node with {capability:?} available");
+            return Err(match capability {
+                RequiredCapability::Pow => {
+                    crate::client::Error::Node(crate::client::node_api::error::Error::UnavailablePow)
+                }
+                RequiredCapability::MilestoneIndex(index) => crate::client::Error::MilestonePruned(index),
+                RequiredCapability::Indexer | RequiredCapability::Participation => {
+                    crate::client::Error::PluginUnavailable {
+                        plugin: capability.name(),
+                        capable_nodes: node_capabilities
+                            .iter()
+                            .filter(|(_, capabilities)| capabilities.supports(capability))
+                            .map(|(n, _)| n.url.to_string())
+                            .collect(),
+                    }
+                }
+            });
+        }
+
+        Ok(())
+    }
+
+    // Adds the counters of a request to the routing metrics of the capability
+    fn record_routing_metrics(&self, capability: &'static str, metrics: RoutingMetrics) {
+        let add = |total: &mut RoutingMetrics| {
+            total.requests += metrics.requests;
+            total.skipped_nodes += metrics.skipped_nodes;
+            total.permanode_fallbacks += metrics.permanode_fallbacks;
+            total.unavailable += metrics.unavailable;
+        };
+        if let Ok(routing_metrics) = self.routing_metrics.read() {
+            if let Some(total) = routing_metrics.get(capability) {
+                if let Ok(mut total) = total.lock() {
+                    add(&mut total);
+                }
+                return;
+            }
+        }
+        // First request that needs the capability
+        if let Ok(mut routing_metrics) = self.routing_metrics.write() {
+            if let Ok(total) = routing_metrics.entry(capability).or_default().get_mut() {
+                add(total);
+            }
+        }
+    }
+
     pub(crate) async fn get_request<T: serde::de::DeserializeOwned + std::fmt::Debug + serde::Serialize>(
         &self,
         path: &str,
@@ -174,11 +347,106 @@ impl NodeManager {
         need_quorum: bool,
         prefer_permanode: bool,
     ) -> Result<T> {
-        let mut result: HashMap<String, usize> = HashMap::new();
+        // With query we ignore quorum because the nodes can store a different amount of history
+        let quorum = self.quorum_enabled() && need_quorum && query.is_none();
+        self.get_request_with_quorum(path, query, timeout, quorum, prefer_permanode)
+            .await
+    }
+
+    // Like `get_request()`, for reads like blocks, outputs and indexer queries, which also use quorum, including
+    // queries, if read quorum is enabled
+    pub(crate) async fn get_read_request<T: serde::de::DeserializeOwned + std::fmt::Debug + serde::Serialize>(
+        &self,
+        path: &str,
+        query: Option<&str>,
+        timeout: Duration,
+        need_quorum: bool,
+        prefer_permanode: bool,
+    ) -> Result<T> {
+        let quorum = self.read_quorum || (self.quorum_enabled() && need_quorum && query.is_none());
+        self.get_request_with_quorum(path, query, timeout, quorum, prefer_permanode)
+            .await
+    }
+
+    async fn get_request_with_quorum<T: serde::de::DeserializeOwned + std::fmt::Debug + serde::Serialize>(
+        &self,
+        path: &str,
+        query: Option<&str>,
+        timeout: Duration,
+        quorum: bool,
+        prefer_permanode: bool,
+    ) -> Result<T> {
+        // Concurrent blocks need different tips, so they're not shared
+        if path == crate::client::node_api::core::routes::TIPS_PATH {
+            return self
+                .send_get_request(path, query, timeout, quorum, prefer_permanode)
+                .await;
+        }
+
+        let key = format!("{path}?{}#{quorum}:{prefer_permanode}", query.unwrap_or_default());
+
+        // Join an identical request that is already in flight instead of sending it again
+        let sender = {
+            let mut in_flight_requests = self
+                .in_flight_requests
+                .lock()
+                .map_err(|_| crate::client::Error::PoisonError)?;
+            match in_flight_requests.get(&key) {
+                Some(receiver) => Err(receiver.clone()),
+                None => {
+                    let (sender, receiver) = watch::channel(None);
+                    in_flight_requests.insert(key.clone(), receiver);
+                    Ok(sender)
+                }
+            }
+        };
+
+        match sender {
+            Ok(sender) => {
+                let in_flight_request = InFlightRequest {
+                    in_flight_requests: &self.in_flight_requests,
+                    key,
+                };
+                let result = self
+                    .send_get_request::<T>(path, query, timeout, quorum, prefer_permanode)
+                    .await;
+                // No request can join anymore once it's removed, so the response is only serialized if others joined
+                drop(in_flight_request);
+                if sender.receiver_count() > 0 {
+                    if let Ok(data) = &result {
+                        sender.send_replace(serde_json::to_string(data).ok());
+                    }
+                }
+                result
+            }
+            Err(mut receiver) => {
+                log::debug!("[NODE_MANAGER] joining in-flight request {key}");
+                if receiver.changed().await.is_ok() {
+                    if let Some(response) = receiver.borrow().as_deref() {
+                        return Ok(serde_json::from_str(response)?);
+                    }
+                }
+                // The in-flight request failed, errors aren't shared, so the request is sent again
+                self.send_get_request(path, query, timeout, quorum, prefer_permanode)
+                    .await
+            }
+        }
+    }
+
+    async fn send_get_request<T: serde::de::DeserializeOwned + std::fmt::Debug + serde::Serialize>(
+        &self,
+        path: &str,
+        query: Option<&str>,
+        timeout: Duration,
+        quorum: bool,
+        prefer_permanode: bool,
+    ) -> Result<T> {
+        // The responses and how often they were returned, by quorum key
+        let mut result: HashMap<String, (usize, String)> = HashMap::new();
         // primary_pow_node should only be used for post request with remote PoW
         // Get node urls and set path
         let nodes = self.get_nodes(path, query, false, prefer_permanode)?;
-        if self.quorum && need_quorum && nodes.len() < self.min_quorum_size {
+        if quorum && nodes.len() < self.min_quorum_size {
             return Err(Error::QuorumPoolSizeError {
                 available_nodes: nodes.len(),
                 minimum_threshold: self.min_quorum_size,
@@ -193,25 +461,33 @@ impl NodeManager {
         let wasm = true;
         #[cfg(not(target_family = "wasm"))]
         let wasm = false;
-        if !wasm && self.quorum && need_quorum && query.is_none() {
+        if !wasm && quorum {
             #[cfg(not(target_family = "wasm"))]
             {
                 let mut tasks = Vec::new();
                 for (index, node) in nodes.into_iter().enumerate() {
                     if index < self.min_quorum_size {
                         let client_ = self.http_client.clone();
-                        tasks.push(async move { tokio::spawn(async move { client_.get(node, timeout).await }).await });
+                        tasks.push(async move {
+                            tokio::spawn(async move {
+                                let start_time = Instant::now();
+                                let res = client_.get(node.clone(), timeout).await;
+                                (node, res, start_time.elapsed())
+                            })
+                            .await
+                        });
                     }
                 }
-                for res in futures::future::try_join_all(tasks).await? {
+                for (node, res, latency) in futures::future::try_join_all(tasks).await? {
+                    self.record_request(&node, &res, latency);
                     match res {
                         Ok(res) => (res.into_text().await).map_or_else(
                             |_| {
                                 log::warn!("couldn't convert node response to text");
                             },
                             |res_text| {
-                                let counters = result.entry(res_text).or_insert(0);
-                                *counters += 1;
+                                let counters = result.entry(quorum_key(&res_text)).or_insert((0, res_text));
+                                counters.0 += 1;
                                 result_counter += 1;
                             },
                         ),
@@ -224,7 +500,10 @@ impl NodeManager {
         } else {
             // Send requests
             for node in nodes {
-                match self.http_client.get(node.clone(), timeout).await {
+                let start_time = Instant::now();
+                let res = self.http_client.get(node.clone(), timeout).await;
+                self.record_request(&node, &res, start_time.elapsed());
+                match res {
                     Ok(res) => {
                         // Handle node_info extra because we also want to return the url
                         if path == crate::client::node_api::core::routes::INFO_PATH {
@@ -239,16 +518,12 @@ impl NodeManager {
 
                         match res.into_json::<T>().await {
                             Ok(result_data) => {
-                                let counters = result.entry(serde_json::to_string(&result_data)?).or_insert(0);
-                                *counters += 1;
+                                let res_text = serde_json::to_string(&result_data)?;
+                                let counters = result.entry(quorum_key(&res_text)).or_insert((0, res_text));
+                                counters.0 += 1;
                                 result_counter += 1;
                                 // Without quorum it's enough if we got one response
-                                if !self.quorum
-                                    || result_counter >= self.min_quorum_size
-                                    || !need_quorum
-                                    // with query we ignore quorum because the nodes can store a different amount of history
-                                    || query.is_some()
-                                {
+                                if !quorum || result_counter >= self.min_quorum_size {
                                     break;
                                 }
                             }
@@ -266,22 +541,21 @@ impl NodeManager {
 
         // Safe unwrap, there are nodes because we throw on empty nodepool.
         // Each node will throw an error or return Ok()
-        let res = result.into_iter().max_by_key(|v| v.1).ok_or_else(|| error.unwrap())?;
-
-        // Return if quorum is false or check if quorum was reached
-        if !self.quorum
-            || res.1 as f64 >= self.min_quorum_size as f64 * (self.quorum_threshold as f64 / 100.0)
-            || !need_quorum
-            // with query we ignore quorum because the nodes can store a different amount of history
-            || query.is_some()
-        {
-            Ok(serde_json::from_str(&res.0)?)
-        } else {
-            Err(Error::QuorumThresholdError {
-                quorum_size: res.1,
-                minimum_threshold: self.min_quorum_size,
-            })
+        if result.is_empty() {
+            return Err(error.unwrap());
         }
+
+        // Without quorum any response is accepted
+        let (min_quorum_size, quorum_threshold) = if quorum {
+            (self.min_quorum_size, self.quorum_threshold)
+        } else {
+            (0, 0)
+        };
+        Ok(serde_json::from_str(&quorum_response(
+            result,
+            min_quorum_size,
+            quorum_threshold,
+        )?)?)
     }
 
     // Only used for api/core/v2/blocks/{blockID}, that's why we don't need the quorum stuff
@@ -297,7 +571,10 @@ impl NodeManager {
         let mut error = None;
         // Send requests
         for node in nodes {
-            match self.http_client.get_bytes(node, timeout).await {
+            let start_time = Instant::now();
+            let res = self.http_client.get_bytes(node.clone(), timeout).await;
+            self.record_request(&node, &res, start_time.elapsed());
+            match res {
                 Ok(res) => {
                     match res.into_bytes().await {
                         Ok(res_text) => return Ok(res_text),
@@ -326,7 +603,10 @@ impl NodeManager {
         let mut error = None;
         // Send requests
         for node in nodes {
-            match self.http_client.post_bytes(node, timeout, body).await {
+            let start_time = Instant::now();
+            let res = self.http_client.post_bytes(node.clone(), timeout, body).await;
+            self.record_request(&node, &res, start_time.elapsed());
+            match res {
                 Ok(res) => {
                     match res.into_json::<T>().await {
                         Ok(res) => return Ok(res),
@@ -355,7 +635,10 @@ impl NodeManager {
         let mut error = None;
         // Send requests
         for node in nodes {
-            match self.http_client.post_json(node, timeout, json.clone()).await {
+            let start_time = Instant::now();
+            let res = self.http_client.post_json(node.clone(), timeout, json.clone()).await;
+            self.record_request(&node, &res, start_time.elapsed());
+            match res {
                 Ok(res) => {
                     match res.into_json::<T>().await {
                         Ok(res) => return Ok(res),
@@ -372,3 +655,296 @@ impl NodeManager {
         Err(error.unwrap())
     }
 }
+
+// Returns the key to compare responses for quorum. The ledger index of outputs and indexer queries is removed, because
+// it differs between nodes that are at a different milestone, even if they return the same data.
+fn quorum_key(response: &str) -> String {
+    fn remove_ledger_index(value: &mut Value) {
+        if let Value::Object(map) = value {
+            map.remove("ledgerIndex");
+            if let Some(metadata) = map.get_mut("metadata") {
+                remove_ledger_index(metadata);
+            }
+        }
+    }
+
+    match serde_json::from_str::<Value>(response) {
+        Ok(mut value) => {
+            remove_ledger_index(&mut value);
+            value.to_string()
+        }
+        Err(_) => response.to_string(),
+    }
+}
+
+// Returns the response that was returned most often, if `quorum_threshold`% of the `min_quorum_size` nodes returned it
+fn quorum_response(
+    responses: HashMap<String, (usize, String)>,
+    min_quorum_size: usize,
+    quorum_threshold: usize,
+) -> Result<String> {
+    let (count, response) = responses
+        .into_values()
+        .max_by_key(|(count, _)| *count)
+        .unwrap_or_default();
+    if count as f64 >= min_quorum_size as f64 * (quorum_threshold as f64 / 100.0) {
+        Ok(response)
+    } else {
+        Err(Error::QuorumThresholdError {
+            quorum_size: count,
+            minimum_threshold: min_quorum_size,
+        })
+    }
+}
+
+// The key of a node in the node health, the url without path, query and auth, because the path and query are set for
+// every request
+pub(crate) fn health_key(node: &Node) -> String {
+    node.url.origin().ascii_serialization()
+}
+
+// Removes an in-flight request when its response is available or it got cancelled, so following requests are sent
+// again
+struct InFlightRequest<'a> {
+    in_flight_requests: &'a Mutex<HashMap<String, watch::Receiver<Option<String>>>>,
+    key: String,
+}
+
+impl Drop for InFlightRequest<'_> {
+    fn drop(&mut self) {
+        if let Ok(mut in_flight_requests) = self.in_flight_requests.lock() {
+            in_flight_requests.remove(&self.key);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NODES: [&str; 3] = ["http://node1:14265", "http://node2:14265", "http://node3:14265"];
+
+    fn node_manager(node_selection_policy: NodeSelectionPolicy) -> NodeManager {
+        NodeManager::builder()
+            .with_nodes(&NODES)
+            .unwrap()
+            .with_ignore_node_health()
+            .with_node_selection_policy(node_selection_policy)
+            .build(HashMap::new())
+    }
+
+    fn nodes() -> Vec<Node> {
+        NODES
+            .iter()
+            .map(|url| Node {
+                url: url.parse().unwrap(),
+                auth: None,
+                disabled: false,
+            })
+            .collect()
+    }
+
+    fn node_health(node_manager: &NodeManager, node: &Node) -> NodeHealth {
+        let node_health = node_manager.node_health.read().unwrap();
+        let health = node_health[&health_key(node)].lock().unwrap();
+        *health
+    }
+
+    fn urls(nodes: &[Node]) -> Vec<String> {
+        nodes.iter().map(|node| node.url.to_string()).collect()
+    }
+
+    #[test]
+    fn record_request() {
+        use crate::client::node_api::error::Error as NodeError;
+        let node_manager = node_manager(NodeSelectionPolicy::LatencyWeighted);
+        let node = &nodes()[0];
+        let response_error = |code| NodeError::ResponseError {
+            code,
+            text: String::new(),
+            url: node.url.to_string(),
+        };
+
+        // The first request sets the latency
+        node_manager.record_request::<()>(node, &Ok(()), Duration::from_millis(100));
+        let health = node_health(&node_manager, node);
+        assert_eq!((health.requests, health.failures), (1, 0));
+        assert_eq!(health.latency_ms, 100.0);
+        assert_eq!(health.success_rate, 1.0);
+
+        // Internal errors count as failures
+        node_manager.record_request::<()>(node, &Err(response_error(500)), Duration::from_millis(200));
+        let health = node_health(&node_manager, node);
+        assert_eq!((health.requests, health.failures), (2, 1));
+        assert!((health.latency_ms - 120.0).abs() < f64::EPSILON);
+        assert!((health.success_rate - 0.8).abs() < f64::EPSILON);
+
+        // Other errors of the node api are responses
+        node_manager.record_request::<()>(node, &Err(response_error(404)), Duration::from_millis(120));
+        let health = node_health(&node_manager, node);
+        assert_eq!((health.requests, health.failures), (3, 1));
+        assert!(health.success_rate > 0.8);
+    }
+
+    #[test]
+    fn routing_metrics() {
+        let node_manager = node_manager(NodeSelectionPolicy::PrimaryWithFallback);
+        for _ in 0..2 {
+            node_manager
+                .get_nodes("api/indexer/v1/outputs/basic", None, false, false)
+                .unwrap();
+        }
+        let routing_metrics = node_manager.routing_metrics.read().unwrap();
+        let indexer_metrics = *routing_metrics["indexer"].lock().unwrap();
+        assert_eq!(
+            indexer_metrics,
+            RoutingMetrics {
+                requests: 2,
+                ..Default::default()
+            }
+        );
+        assert!(!routing_metrics.contains_key("participation"));
+    }
+
+    #[test]
+    fn latency_weighted_order() {
+        let node_manager = node_manager(NodeSelectionPolicy::LatencyWeighted);
+        let nodes = nodes();
+        node_manager.record_request::<()>(&nodes[0], &Ok(()), Duration::from_millis(800));
+        node_manager.record_request::<()>(&nodes[2], &Ok(()), Duration::from_millis(50));
+
+        // The unmeasured node is assumed to have the reference latency, so it's tried between the fast and slow node
+        assert_eq!(
+            urls(&node_manager.order_nodes(nodes).unwrap()),
+            [NODES[2], NODES[1], NODES[0]].map(|url| format!("{url}/")),
+        );
+    }
+
+    #[test]
+    fn round_robin_order() {
+        let node_manager = node_manager(NodeSelectionPolicy::RoundRobin);
+        let mut nodes = nodes();
+        nodes.reverse();
+
+        let first_nodes = (0..4)
+            .map(|_| node_manager.order_nodes(nodes.clone()).unwrap()[0].url.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            first_nodes,
+            [NODES[0], NODES[1], NODES[2], NODES[0]].map(|url| format!("{url}/"))
+        );
+    }
+
+    fn count_responses<'a>(responses: impl IntoIterator<Item = &'a str>) -> HashMap<String, (usize, String)> {
+        let mut result = HashMap::new();
+        for response in responses {
+            result
+                .entry(quorum_key(response))
+                .or_insert_with(|| (0, response.to_string()))
+                .0 += 1;
+        }
+        result
+    }
+
+    // Serves the body for every request after a delay and returns the url and the amount of accepted connections
+    fn serve(body: &'static str) -> (String, std::sync::Arc<AtomicUsize>) {
+        use std::io::{Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let connections = std::sync::Arc::new(AtomicUsize::new(0));
+        let counter = connections.clone();
+        std::thread::spawn(move || {
+            for mut stream in listener.incoming().flatten() {
+                counter.fetch_add(1, Ordering::SeqCst);
+                std::thread::spawn(move || {
+                    let mut buffer = [0u8; 1024];
+                    stream.read(&mut buffer).ok();
+                    std::thread::sleep(Duration::from_millis(200));
+                    let response = format!(
+                        "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                        body.len()
+                    );
+                    stream.write_all(response.as_bytes()).ok();
+                });
+            }
+        });
+        (url, connections)
+    }
+
+    #[tokio::test]
+    async fn concurrent_requests_are_shared() {
+        let (url, connections) = serve(r#"{"tips":["0x01"]}"#);
+        let node_manager = NodeManager::builder()
+            .with_node(&url)
+            .unwrap()
+            .with_ignore_node_health()
+            .build(HashMap::new());
+        let timeout = Duration::from_secs(5);
+        let path = "api/core/v2/outputs/0x01";
+
+        let (first, second) = tokio::join!(
+            node_manager.get_request::<Value>(path, None, timeout, false, false),
+            node_manager.get_request::<Value>(path, None, timeout, false, false),
+        );
+        assert_eq!(first.unwrap(), second.unwrap());
+        assert_eq!(connections.load(Ordering::SeqCst), 1);
+
+        // Tips are requested for every caller
+        let tips_path = crate::client::node_api::core::routes::TIPS_PATH;
+        let (first, second) = tokio::join!(
+            node_manager.get_request::<Value>(tips_path, None, timeout, false, false),
+            node_manager.get_request::<Value>(tips_path, None, timeout, false, false),
+        );
+        assert!(first.is_ok() && second.is_ok());
+        assert_eq!(connections.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn quorum_key_ignores_ledger_index() {
+        let output = r#"{"metadata":{"blockId":"0x01","ledgerIndex":5},"output":{"amount":"1"}}"#;
+        let later_output = r#"{"metadata":{"blockId":"0x01","ledgerIndex":6},"output":{"amount":"1"}}"#;
+        assert_eq!(quorum_key(output), quorum_key(later_output));
+
+        let query = r#"{"ledgerIndex":5,"items":["0x01"]}"#;
+        let later_query = r#"{"ledgerIndex":6,"items":["0x01"]}"#;
+        assert_eq!(quorum_key(query), quorum_key(later_query));
+
+        let other_output = r#"{"metadata":{"blockId":"0x02","ledgerIndex":5},"output":{"amount":"1"}}"#;
+        assert_ne!(quorum_key(output), quorum_key(other_output));
+
+        // Responses that aren't JSON are compared as they are
+        assert_eq!(quorum_key("not json"), "not json");
+    }
+
+    #[test]
+    fn quorum_response_threshold() {
+        let response = r#"{"ledgerIndex":5,"items":["0x01"]}"#;
+        let later_response = r#"{"ledgerIndex":6,"items":["0x01"]}"#;
+        let other_response = r#"{"ledgerIndex":5,"items":["0x02"]}"#;
+
+        // Nodes at different milestones agree
+        assert_eq!(
+            quorum_response(count_responses([response, later_response]), 2, 100).unwrap(),
+            response
+        );
+        // 2 of 3 nodes agree, which is enough with a threshold of 66%
+        assert_eq!(
+            quorum_response(count_responses([response, other_response, later_response]), 3, 66).unwrap(),
+            response
+        );
+        // But not with a threshold of 100%
+        assert!(matches!(
+            quorum_response(count_responses([response, other_response, later_response]), 3, 100),
+            Err(Error::QuorumThresholdError {
+                quorum_size: 2,
+                minimum_threshold: 3
+            })
+        ));
+        // Without quorum any response is accepted
+        assert_eq!(
+            quorum_response(count_responses([other_response]), 0, 0).unwrap(),
+            other_response
+        );
+    }
+}
@@ -5,7 +5,7 @@ use std::hash::Hash;
 
 use serde::{Deserialize, Serialize};
 
-use crate::Url;
+use crate::{types::api::core::response::InfoResponse, Url};
 
 /// Node authentication object.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
@@ -39,6 +39,126 @@ impl From<Url> for Node {
     }
 }
 
+/// The optional APIs of a node, detected from its routes, and the node info relevant for the node selection.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct NodeCapabilities {
+    /// Whether the node provides the indexer plugin.
+    pub indexer: bool,
+    /// Whether the node provides the MQTT plugin.
+    pub mqtt: bool,
+    /// Whether the node provides the participation plugin.
+    pub participation: bool,
+    /// Whether the node has remote PoW enabled.
+    pub pow: bool,
+    /// The index of the newest milestone the node has pruned.
+    pub pruning_index: u32,
+}
+
+impl NodeCapabilities {
+    /// Creates the capabilities from the route groups returned by `/api/routes`.
+    pub fn from_routes(routes: &[String]) -> Self {
+        let has_route = |prefix: &str| routes.iter().any(|route| route.starts_with(prefix));
+        Self {
+            indexer: has_route("indexer/"),
+            mqtt: has_route("mqtt/"),
+            participation: has_route("participation/"),
+            ..Default::default()
+        }
+    }
+
+    /// Creates the capabilities from the node info and the route groups. If the routes couldn't be requested, the
+    /// plugins are assumed to be available, so the node isn't skipped because of missing data.
+    pub fn from_node_info(info: &InfoResponse, routes: Option<&[String]>) -> Self {
+        let capabilities = routes.map_or(
+            Self {
+                indexer: true,
+                mqtt: true,
+                participation: true,
+                ..Default::default()
+            },
+            Self::from_routes,
+        );
+        Self {
+            pow: info.features.iter().any(|feature| feature == "pow"),
+            pruning_index: info.status.pruning_index,
+            ..capabilities
+        }
+    }
+
+    /// Returns whether the node can answer requests that need the capability.
+    pub fn supports(&self, capability: RequiredCapability) -> bool {
+        match capability {
+            RequiredCapability::Indexer => self.indexer,
+            RequiredCapability::Participation => self.participation,
+            RequiredCapability::Pow => self.pow,
+            RequiredCapability::MilestoneIndex(index) => index > self.pruning_index,
+        }
+    }
+}
+
+/// A capability a node needs to answer a request.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[serde(tag = "type", content = "data", rename_all = "camelCase")]
+pub enum RequiredCapability {
+    /// The indexer plugin.
+    Indexer,
+    /// The participation plugin.
+    Participation,
+    /// Remote PoW.
+    Pow,
+    /// The milestone with this index must not be pruned.
+    MilestoneIndex(u32),
+}
+
+impl RequiredCapability {
+    /// Returns the capabilities that are needed for a request to the path.
+    pub fn for_request(path: &str, remote_pow: bool) -> Vec<Self> {
+        let mut capabilities = Vec::new();
+        if path.starts_with("api/indexer/") {
+            capabilities.push(Self::Indexer);
+        }
+        if path.starts_with("api/participation/") {
+            capabilities.push(Self::Participation);
+        }
+        if remote_pow {
+            capabilities.push(Self::Pow);
+        }
+        if let Some(index) = path
+            .strip_prefix("api/core/v2/milestones/by-index/")
+            .and_then(|index| index.split('/').next())
+            .and_then(|index| index.parse().ok())
+        {
+            capabilities.push(Self::MilestoneIndex(index));
+        }
+        capabilities
+    }
+
+    /// The name of the capability, used in errors and the routing metrics.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Indexer => "indexer",
+            Self::Participation => "participation",
+            Self::Pow => "pow",
+            Self::MilestoneIndex(_) => "milestone",
+        }
+    }
+}
+
+/// Counters of the capability based node selection for one capability.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RoutingMetrics {
+    /// Amount of requests that needed the capability.
+    pub requests: u64,
+    /// Amount of nodes that were skipped, because they lack the capability.
+    pub skipped_nodes: u64,
+    /// Amount of requests that were sent to the permanodes, because no other node has the capability.
+    pub permanode_fallbacks: u64,
+    /// Amount of requests that failed, because no node has the capability.
+    pub unavailable: u64,
+}
+
 /// NodeDto enum to accept just a single url or also provided auth options or set if the node should be disabled.
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, Hash)]
 #[serde(untagged)]
@@ -3,8 +3,16 @@
 
 #[cfg(not(target_family = "wasm"))]
 use {
+    super::{
+        health::NodeHealth,
+        health_key,
+        node::{NodeCapabilities, RoutingMetrics},
+    },
     crate::types::{api::core::response::InfoResponse, block::protocol::ProtocolParameters},
-    std::{collections::HashSet, time::Duration},
+    std::{
+        collections::{HashMap, HashSet},
+        time::Duration,
+    },
     tokio::time::sleep,
 };
 
@@ -40,6 +48,58 @@ impl ClientInner {
                     .collect()
             })
     }
+
+    /// Returns the detected capabilities of the reachable nodes.
+    #[cfg(not(target_family = "wasm"))]
+    pub async fn node_capabilities(&self) -> Result<HashMap<Node, NodeCapabilities>> {
+        Ok(self
+            .node_manager
+            .read()
+            .await
+            .node_capabilities
+            .read()
+            .map_err(|_| crate::client::Error::PoisonError)?
+            .clone())
+    }
+
+    /// Returns the health of the nodes that were requested or synced, by url origin, see [`NodeHealth::score()`].
+    #[cfg(not(target_family = "wasm"))]
+    pub async fn node_health(&self) -> Result<HashMap<String, NodeHealth>> {
+        self.node_manager
+            .read()
+            .await
+            .node_health
+            .read()
+            .map_err(|_| crate::client::Error::PoisonError)?
+            .iter()
+            .map(|(key, health)| {
+                Ok((
+                    key.clone(),
+                    *health.lock().map_err(|_| crate::client::Error::PoisonError)?,
+                ))
+            })
+            .collect()
+    }
+
+    /// Returns how often nodes were skipped or the permanodes were used, because of a missing capability, by
+    /// [`RequiredCapability::name()`](super::node::RequiredCapability::name).
+    #[cfg(not(target_family = "wasm"))]
+    pub async fn routing_metrics(&self) -> Result<HashMap<String, RoutingMetrics>> {
+        self.node_manager
+            .read()
+            .await
+            .routing_metrics
+            .read()
+            .map_err(|_| crate::client::Error::PoisonError)?
+            .iter()
+            .map(|(capability, metrics)| {
+                Ok((
+                    capability.to_string(),
+                    *metrics.lock().map_err(|_| crate::client::Error::PoisonError)?,
+                ))
+            })
+            .collect()
+    }
 }
 
 #[cfg(not(target_family = "wasm"))]
@@ -62,16 +122,52 @@ impl ClientInner {
     }
 
     pub(crate) async fn sync_nodes(&self, nodes: &HashSet<Node>, ignore_node_health: bool) -> Result<()> {
-        use std::collections::HashMap;
-
         log::debug!("sync_nodes");
         let mut healthy_nodes = HashMap::new();
+        let mut node_capabilities = HashMap::new();
+        let mut node_routes = self
+            .node_manager
+            .read()
+            .await
+            .node_routes
+            .read()
+            .map_err(|_| crate::client::Error::PoisonError)?
+            .clone();
         let mut network_nodes: HashMap<String, Vec<(InfoResponse, Node)>> = HashMap::new();
+        // The latest milestone index of the reachable nodes, `None` for unreachable nodes, by health key
+        let mut latest_milestone_indexes = HashMap::new();
 
         for node in nodes {
             // Put the healthy node url into the network_nodes
-            match crate::client::Client::get_node_info(node.url.as_ref(), node.auth.clone()).await {
+            let start_time = instant::Instant::now();
+            let node_info = crate::client::Client::get_node_info(node.url.as_ref(), node.auth.clone()).await;
+            latest_milestone_indexes.insert(
+                health_key(node),
+                (
+                    node_info.as_ref().ok().map(|info| info.status.latest_milestone.index),
+                    start_time.elapsed(),
+                ),
+            );
+            match node_info {
                 Ok(info) => {
+                    // Detect the capabilities of the node, so requests are only sent to nodes that can answer them. The
+                    // routes only change with the node version, so they're requested once per version.
+                    let routes = match node_routes.get(node) {
+                        Some((version, routes)) if version == &info.version => Some(routes.clone()),
+                        _ => match crate::client::Client::get_node_routes(node.url.as_ref(), node.auth.clone()).await {
+                            Ok(routes) => {
+                                node_routes.insert(node.clone(), (info.version.clone(), routes.routes.clone()));
+                                Some(routes.routes)
+                            }
+                            Err(err) => {
+                                log::debug!("Couldn't get routes of {}: {err}", node.url);
+                                None
+                            }
+                        },
+                    };
+                    let capabilities = NodeCapabilities::from_node_info(&info, routes.as_deref());
+                    log::debug!("{} capabilities: {capabilities:?}", node.url);
+                    node_capabilities.insert(node.clone(), capabilities);
                     if info.status.is_healthy || ignore_node_health {
                         match network_nodes.get_mut(&info.protocol.network_name) {
                             Some(network_node_entry) => {
@@ -114,13 +210,42 @@ impl ClientInner {
         }
 
         // Update the sync list.
-        *self
-            .node_manager
-            .read()
-            .await
+        let node_manager = self.node_manager.read().await;
+        *node_manager
             .healthy_nodes
             .write()
             .map_err(|_| crate::client::Error::PoisonError)? = healthy_nodes;
+        *node_manager
+            .node_capabilities
+            .write()
+            .map_err(|_| crate::client::Error::PoisonError)? = node_capabilities;
+        node_routes.retain(|node, _| nodes.contains(node));
+        *node_manager
+            .node_routes
+            .write()
+            .map_err(|_| crate::client::Error::PoisonError)? = node_routes;
+
+        // Update the milestone lag of the reachable nodes and count the unreachable ones as failed requests
+        let max_milestone_index = latest_milestone_indexes
+            .values()
+            .filter_map(|(index, _)| *index)
+            .max()
+            .unwrap_or_default();
+        let mut node_health = node_manager
+            .node_health
+            .write()
+            .map_err(|_| crate::client::Error::PoisonError)?;
+        for (key, (milestone_index, latency)) in latest_milestone_indexes {
+            let health = node_health
+                .entry(key)
+                .or_default()
+                .get_mut()
+                .map_err(|_| crate::client::Error::PoisonError)?;
+            match milestone_index {
+                Some(index) => health.milestone_lag = max_milestone_index - index,
+                None => health.record_request(false, latency),
+            }
+        }
 
         Ok(())
     }
@@ -128,7 +253,7 @@ impl ClientInner {
 
 impl Client {
     #[cfg(not(target_family = "wasm"))]
-    pub async fn update_node_manager(&self, node_manager: NodeManager) -> crate::wallet::Result<()> {
+    pub async fn update_node_manager(&self, node_manager: NodeManager) -> crate::client::Result<()> {
         let node_sync_interval = node_manager.node_sync_interval;
         let ignore_node_health = node_manager.ignore_node_health;
         let nodes = node_manager
@@ -140,6 +265,12 @@ impl Client {
 
         *self.node_manager.write().await = node_manager;
 
+        // The nodes aren't synced in offline mode, so the provided protocol parameters are kept
+        if self.is_offline() {
+            *self._sync_handle.write().await = crate::client::SyncHandle(None);
+            return Ok(());
+        }
+
         self.sync_nodes(&nodes, ignore_node_health).await?;
         let client = self.clone();
 
@@ -154,7 +285,7 @@ impl Client {
     }
 
     #[cfg(target_family = "wasm")]
-    pub async fn update_node_manager(&self, node_manager: NodeManager) -> crate::wallet::Result<()> {
+    pub async fn update_node_manager(&self, node_manager: NodeManager) -> crate::client::Result<()> {
         *self.node_manager.write().await = node_manager;
         Ok(())
     }
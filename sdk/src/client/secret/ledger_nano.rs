@@ -18,10 +18,12 @@ use tokio::sync::Mutex;
 
 use super::{GenerateAddressOptions, SecretManage, SignTransactionEssence};
 use crate::{
-    client::secret::{
-        is_alias_transition,
-        types::{LedgerApp, LedgerDeviceType},
-        LedgerNanoStatus, PreparedTransactionData,
+    client::{
+        api::input_selection::is_alias_transition,
+        secret::{
+            types::{LedgerApp, LedgerDeviceType},
+            LedgerNanoStatus, PreparedTransactionData,
+        },
     },
     types::block::{
         address::{Address, AliasAddress, Ed25519Address, NftAddress},
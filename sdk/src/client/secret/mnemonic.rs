@@ -3,7 +3,7 @@
 
 //! Implementation of [`MnemonicSecretManager`].
 
-use std::ops::Range;
+use std::{ops::Range, sync::Arc};
 
 use async_trait::async_trait;
 use crypto::{
@@ -13,7 +13,7 @@ use crypto::{
 };
 use zeroize::Zeroize;
 
-use super::{GenerateAddressOptions, SecretManage};
+use super::{derive_batch, GenerateAddressOptions, SecretManage};
 use crate::{
     client::{constants::HD_WALLET_TYPE, Client, Error},
     types::block::{
@@ -25,7 +25,7 @@ use crate::{
 /// Secret manager that uses only a mnemonic.
 ///
 /// Computation are done in-memory. A mnemonic needs to be supplied upon the creation of [`MnemonicSecretManager`].
-pub struct MnemonicSecretManager(Seed);
+pub struct MnemonicSecretManager(Arc<Seed>);
 
 #[async_trait]
 impl SecretManage for MnemonicSecretManager {
@@ -39,9 +39,9 @@ impl SecretManage for MnemonicSecretManager {
         options: Option<GenerateAddressOptions>,
     ) -> Result<Vec<Address>, Self::Error> {
         let internal = options.map(|o| o.internal).unwrap_or_default();
-        let mut addresses = Vec::new();
+        let seed = self.0.clone();
 
-        for address_index in address_indexes {
+        derive_batch(address_indexes, move |address_index| {
             let chain = Chain::from_u32_hardened(vec![
                 HD_WALLET_TYPE,
                 coin_type,
@@ -50,8 +50,7 @@ impl SecretManage for MnemonicSecretManager {
                 address_index,
             ]);
 
-            let public_key = self
-                .0
+            let public_key = seed
                 .derive::<ed25519::SecretKey>(&chain)?
                 .secret_key()
                 .public_key()
@@ -62,10 +61,9 @@ impl SecretManage for MnemonicSecretManager {
                 crate::client::Error::Blake2b256("hashing the public key while generating the address failed.")
             });
 
-            addresses.push(Address::Ed25519(Ed25519Address::new(result?)));
-        }
-
-        Ok(addresses)
+            Ok(Address::Ed25519(Ed25519Address::new(result?)))
+        })
+        .await
     }
 
     async fn sign_ed25519(&self, msg: &[u8], chain: &Chain) -> Result<Ed25519Signature, Self::Error> {
@@ -83,7 +81,17 @@ impl MnemonicSecretManager {
     ///
     /// For more information, see <https://github.com/bitcoin/bips/blob/master/bip-0039.mediawiki>.
     pub fn try_from_mnemonic(mnemonic: &str) -> Result<Self, Error> {
-        Ok(Self(Client::mnemonic_to_seed(mnemonic)?))
+        Ok(Self(Arc::new(Client::mnemonic_to_seed(mnemonic)?)))
+    }
+
+    /// Derives the Ed25519 public key of the given BIP32 chain, without signing anything.
+    pub fn derive_ed25519_public_key(&self, chain: &Chain) -> Result<[u8; 32], Error> {
+        Ok(self
+            .0
+            .derive::<ed25519::SecretKey>(chain)?
+            .secret_key()
+            .public_key()
+            .to_bytes())
     }
 
     /// Create a new [`MnemonicSecretManager`] from a hex-encoded raw seed string.
@@ -92,7 +100,7 @@ impl MnemonicSecretManager {
         let seed = Seed::from_bytes(&bytes);
         hex.zeroize();
         bytes.zeroize();
-        Ok(Self(seed))
+        Ok(Self(Arc::new(seed)))
     }
 }
 
@@ -135,4 +143,26 @@ mod tests {
             "atoi1qzt0nhsf38nh6rs4p6zs5knqp6psgha9wsv74uajqgjmwc75ugupx3y7x0r"
         );
     }
+
+    #[tokio::test]
+    async fn batch_addresses_in_order() {
+        use crate::client::constants::IOTA_COIN_TYPE;
+
+        let mnemonic = "giant dynamic museum toddler six deny defense ostrich bomb access mercy blood explain muscle shoot shallow glad autumn author calm heavy hawk abuse rally";
+        let secret_manager = MnemonicSecretManager::try_from_mnemonic(mnemonic).unwrap();
+
+        let batch = secret_manager
+            .generate_addresses(IOTA_COIN_TYPE, 0, 0..100, None)
+            .await
+            .unwrap();
+        assert_eq!(batch.len(), 100);
+
+        for index in [0, 63, 64, 99] {
+            let single = secret_manager
+                .generate_addresses(IOTA_COIN_TYPE, 0, index..index + 1, None)
+                .await
+                .unwrap();
+            assert_eq!(batch[index as usize], single[0]);
+        }
+    }
 }
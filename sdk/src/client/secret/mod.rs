@@ -19,10 +19,10 @@ pub mod types;
 
 #[cfg(feature = "stronghold")]
 use std::time::Duration;
-use std::{collections::HashMap, ops::Range, str::FromStr};
+use std::{ops::Range, str::FromStr};
 
 use async_trait::async_trait;
-use crypto::keys::slip10::Chain;
+use crypto::keys::slip10::{Chain, Segment};
 use serde::{Deserialize, Serialize};
 use zeroize::ZeroizeOnDrop;
 
@@ -37,19 +37,18 @@ use crate::client::secret::types::StrongholdDto;
 use crate::{
     client::{
         api::{
-            input_selection::{is_alias_transition, Error as InputSelectionError},
-            transaction::validate_transaction_payload_length,
-            verify_semantic, PreparedTransactionData,
+            transaction::validate_transaction_payload_length, unlocks_without_signatures, verify_semantic,
+            PartiallySignedTransaction, PreparedTransactionData,
         },
+        constants::HD_WALLET_TYPE,
         Error,
     },
     types::block::{
         address::Address,
-        output::Output,
-        payload::{transaction::TransactionEssence, Payload, TransactionPayload},
+        payload::{Payload, TransactionPayload},
         semantic::ConflictReason,
         signature::{Ed25519Signature, Signature},
-        unlock::{AliasUnlock, NftUnlock, ReferenceUnlock, SignatureUnlock, Unlock, Unlocks},
+        unlock::{SignatureUnlock, Unlock, Unlocks},
     },
     utils::unix_timestamp_now,
 };
@@ -320,75 +319,37 @@ impl SecretManager {
     ) -> crate::client::Result<Unlocks> {
         // The hashed_essence gets signed
         let hashed_essence = prepared_transaction_data.essence.hash();
+        let unlocks = unlocks_without_signatures(
+            &prepared_transaction_data.inputs_data,
+            &prepared_transaction_data.essence,
+            time.unwrap_or_else(|| unix_timestamp_now().as_secs() as u32),
+        )?;
         let mut blocks = Vec::new();
-        let mut block_indexes = HashMap::<Address, usize>::new();
-
-        // Assuming inputs_data is ordered by address type
-        for (current_block_index, input) in prepared_transaction_data.inputs_data.iter().enumerate() {
-            // Get the address that is required to unlock the input
-            let TransactionEssence::Regular(regular) = &prepared_transaction_data.essence;
-            let alias_transition = is_alias_transition(input, regular.outputs()).map(|t| t.0);
-            let (input_address, _) = input.output.required_and_unlocked_address(
-                time.unwrap_or_else(|| unix_timestamp_now().as_secs() as u32),
-                input.output_metadata.output_id(),
-                alias_transition,
-            )?;
-
-            // Check if we already added an [Unlock] for this address
-            match block_indexes.get(&input_address) {
-                // If we already have an [Unlock] for this address, add a [Unlock] based on the address type
-                Some(block_index) => match input_address {
-                    Address::Alias(_alias) => blocks.push(Unlock::Alias(AliasUnlock::new(*block_index as u16)?)),
-                    Address::Ed25519(_ed25519) => {
-                        blocks.push(Unlock::Reference(ReferenceUnlock::new(*block_index as u16)?));
-                    }
-                    Address::Nft(_nft) => blocks.push(Unlock::Nft(NftUnlock::new(*block_index as u16)?)),
-                },
-                None => {
-                    // We can only sign ed25519 addresses and block_indexes needs to contain the alias or nft
-                    // address already at this point, because the reference index needs to be lower
-                    // than the current block index
-                    if !input_address.is_ed25519() {
-                        return Err(InputSelectionError::MissingInputWithEd25519Address)?;
-                    }
 
+        for (input, unlock) in prepared_transaction_data.inputs_data.iter().zip(unlocks) {
+            match unlock {
+                Some(unlock) => blocks.push(unlock),
+                None => {
                     let chain = input.chain.as_ref().ok_or(Error::MissingBip32Chain)?;
-
-                    let block = self.signature_unlock(&hashed_essence, chain).await?;
-                    blocks.push(block);
-
-                    // Add the ed25519 address to the block_indexes, so it gets referenced if further inputs have
-                    // the same address in their unlock condition
-                    block_indexes.insert(input_address, current_block_index);
+                    blocks.push(self.signature_unlock(&hashed_essence, chain).await?);
                 }
             }
-
-            // When we have an alias or Nft output, we will add their alias or nft address to block_indexes,
-            // because they can be used to unlock outputs via [Unlock::Alias] or [Unlock::Nft],
-            // that have the corresponding alias or nft address in their unlock condition
-            match &input.output {
-                Output::Alias(alias_output) => block_indexes.insert(
-                    Address::Alias(alias_output.alias_address(input.output_id())),
-                    current_block_index,
-                ),
-                Output::Nft(nft_output) => block_indexes.insert(
-                    Address::Nft(nft_output.nft_address(input.output_id())),
-                    current_block_index,
-                ),
-                _ => None,
-            };
         }
 
         Ok(Unlocks::new(blocks)?)
     }
 
-    /// Sign a transaction
+    /// Sign a transaction. The time based unlock conditions are evaluated at `time`, for example the
+    /// [`ClientInner::local_time()`](crate::client::ClientInner::local_time) of a client with a
+    /// [`TimeSource`](crate::client::TimeSource), or the time of the process wide
+    /// [`TimeProvider`](crate::utils::TimeProvider) if it's `None`.
     pub async fn sign_transaction(
         &self,
         prepared_transaction_data: PreparedTransactionData,
+        time: Option<u32>,
     ) -> crate::client::Result<Payload> {
         log::debug!("[sign_transaction] {:?}", prepared_transaction_data);
-        let current_time = unix_timestamp_now().as_secs() as u32;
+        let current_time = time.unwrap_or_else(|| unix_timestamp_now().as_secs() as u32);
 
         let unlocks = self
             .sign_transaction_essence(&prepared_transaction_data, Some(current_time))
@@ -406,4 +367,104 @@ impl SecretManager {
 
         Ok(Payload::from(tx_payload))
     }
+
+    /// Adds the signature unlocks that can be created with the keys of this secret manager to the partially signed
+    /// transaction and returns how many were added. Inputs without a BIP32 chain or whose address belongs to another
+    /// party are skipped, their signatures need to be added by the other parties.
+    pub async fn sign_partially_signed_transaction(
+        &self,
+        partially_signed_transaction: &mut PartiallySignedTransaction,
+    ) -> crate::client::Result<usize> {
+        log::debug!("[sign_partially_signed_transaction]");
+        let hashed_essence = partially_signed_transaction.essence.hash();
+        let mut added_signatures = 0;
+
+        for (index, address) in partially_signed_transaction.missing_signatures()? {
+            let Some(chain) = &partially_signed_transaction.inputs_data[index].chain else {
+                continue;
+            };
+            // The key of the chain only unlocks the input if it belongs to the required address
+            if address.is_ed25519() && self.chain_address(chain).await? == Some(address) {
+                partially_signed_transaction.unlocks[index]
+                    .replace(self.signature_unlock(&hashed_essence, chain).await?);
+                added_signatures += 1;
+            }
+        }
+
+        Ok(added_signatures)
+    }
+
+    // Returns the address of the key of a BIP44 chain, `None` if the chain doesn't have the BIP44 address segments
+    async fn chain_address(&self, chain: &Chain) -> crate::client::Result<Option<Address>> {
+        let segments = chain
+            .segments()
+            .iter()
+            .map(|seg| u32::from_be_bytes(seg.bs()) & !Segment::HARDEN_MASK)
+            .collect::<Vec<u32>>();
+        let [HD_WALLET_TYPE, coin_type, account_index, internal @ (0 | 1), address_index] = segments[..] else {
+            return Ok(None);
+        };
+        let options = GenerateAddressOptions {
+            internal: internal == 1,
+            ledger_nano_prompt: false,
+        };
+
+        Ok(self
+            .generate_addresses(
+                coin_type,
+                account_index,
+                address_index..address_index + 1,
+                Some(options),
+            )
+            .await?
+            .first()
+            .copied())
+    }
+}
+
+// Below this amount of addresses, spawning blocking tasks costs more than the derivation itself.
+#[cfg(not(target_family = "wasm"))]
+const PARALLEL_DERIVATION_THRESHOLD: usize = 64;
+
+/// Derives a value for each address index on the blocking thread pool, spreading large ranges over the available CPU
+/// cores, so the async executor isn't stalled while a secret manager holds its lock. The results are returned in the
+/// order of the address indexes.
+pub(crate) async fn derive_batch<T, E>(
+    address_indexes: Range<u32>,
+    derive: impl Fn(u32) -> Result<T, E> + Send + Sync + 'static,
+) -> Result<Vec<T>, E>
+where
+    T: Send + 'static,
+    E: Send + 'static + From<tokio::task::JoinError>,
+{
+    #[cfg(not(target_family = "wasm"))]
+    {
+        let parallelism = std::thread::available_parallelism().map_or(1, std::num::NonZeroUsize::get);
+        let amount = address_indexes.len();
+        let chunk_size = if parallelism > 1 && amount >= PARALLEL_DERIVATION_THRESHOLD {
+            (amount + parallelism - 1) / parallelism
+        } else {
+            amount.max(1)
+        };
+        let derive = std::sync::Arc::new(derive);
+
+        let handles = address_indexes
+            .collect::<Vec<_>>()
+            .chunks(chunk_size)
+            .map(|chunk| {
+                let chunk = chunk.to_vec();
+                let derive = derive.clone();
+                tokio::task::spawn_blocking(move || chunk.into_iter().map(&*derive).collect::<Result<Vec<_>, _>>())
+            })
+            .collect::<Vec<_>>();
+
+        let mut results = Vec::with_capacity(amount);
+        for handle in handles {
+            results.extend(handle.await??);
+        }
+        Ok(results)
+    }
+
+    #[cfg(target_family = "wasm")]
+    address_indexes.map(derive).collect()
 }
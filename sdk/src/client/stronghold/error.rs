@@ -43,6 +43,9 @@ pub enum Error {
     /// Migration error
     #[error("stronghold migration error: {0}")]
     Migration(#[from] iota_stronghold::engine::snapshot::migration::Error),
+    /// Invalid Shamir shares of a mnemonic
+    #[error("invalid shares: {0}")]
+    InvalidShares(String),
     /// Invalid rounds error
     #[error("invalid number of hash rounds: {0}")]
     InvalidRounds(u32),
@@ -52,4 +55,7 @@ pub enum Error {
     /// Io error
     #[error("io error: {0}")]
     Io(#[from] std::io::Error),
+    /// Tokio task join error
+    #[error("{0}")]
+    TaskJoin(#[from] tokio::task::JoinError),
 }
@@ -50,6 +50,7 @@ mod common;
 mod error;
 mod migration;
 mod secret;
+mod shares;
 mod storage;
 
 use std::{
@@ -68,7 +69,7 @@ use tokio::{
 use zeroize::Zeroizing;
 
 use self::common::PRIVATE_DATA_CLIENT_PATH;
-pub use self::error::Error;
+pub use self::{error::Error, shares::ShareSnapshot};
 use crate::client::storage::StorageProvider;
 
 /// A wrapper on [Stronghold].
@@ -8,7 +8,7 @@ use std::ops::Range;
 use async_trait::async_trait;
 use crypto::hashes::{blake2b::Blake2b256, Digest};
 use iota_stronghold::{
-    procedures::{self, Chain, Curve, KeyType, Slip10DeriveInput},
+    procedures::{self, Chain, Curve, KeyType, ProcedureError, Slip10DeriveInput},
     Location,
 };
 use zeroize::Zeroize;
@@ -20,7 +20,7 @@ use super::{
 use crate::{
     client::{
         constants::HD_WALLET_TYPE,
-        secret::{GenerateAddressOptions, SecretManage},
+        secret::{derive_batch, GenerateAddressOptions, SecretManage},
         stronghold::Error,
     },
     types::block::{
@@ -51,12 +51,14 @@ impl SecretManage for StrongholdAdapter {
 
         // Stronghold arguments.
         let seed_location = Slip10DeriveInput::Seed(Location::generic(SECRET_VAULT_PATH, SEED_RECORD_PATH));
-
-        // Addresses to return.
-        let mut addresses = Vec::new();
         let internal = options.map(|o| o.internal).unwrap_or_default();
 
-        for address_index in address_indexes {
+        // Lock Stronghold only once for the whole batch of addresses.
+        let stronghold = self.stronghold.lock().await;
+        let client = stronghold.get_client(PRIVATE_DATA_CLIENT_PATH)?;
+
+        // The lock is held until the whole batch has been derived on the blocking thread pool.
+        derive_batch(address_indexes, move |address_index| {
             let bip_path = vec![HD_WALLET_TYPE, coin_type, account_index, internal as u32, address_index];
             let chain = Chain::from_u32_hardened(bip_path);
 
@@ -70,17 +72,26 @@ impl SecretManage for StrongholdAdapter {
             );
 
             // Derive a SLIP-10 private key in the vault.
-            self.slip10_derive(Curve::Ed25519, chain, seed_location.clone(), derive_location.clone())
-                .await?;
+            client
+                .execute_procedure(procedures::Slip10Derive {
+                    curve: Curve::Ed25519,
+                    chain,
+                    input: seed_location.clone(),
+                    output: derive_location.clone(),
+                })
+                .map_err(slip10_derive_error)?;
 
             // Get the Ed25519 public key from the derived SLIP-10 private key in the vault.
-            let public_key = self.ed25519_public_key(derive_location.clone()).await?;
+            let public_key: [u8; 32] = client
+                .execute_procedure(procedures::PublicKey {
+                    ty: KeyType::Ed25519,
+                    private_key: derive_location.clone(),
+                })?
+                .try_into()
+                .unwrap();
 
             // Cleanup location afterwards
-            self.stronghold
-                .lock()
-                .await
-                .get_client(PRIVATE_DATA_CLIENT_PATH)?
+            client
                 .vault(SECRET_VAULT_PATH)
                 .delete_secret(derive_location.record_path())?;
 
@@ -88,13 +99,9 @@ impl SecretManage for StrongholdAdapter {
             let hash = Blake2b256::digest(public_key);
 
             // Convert the hash into [Address].
-            let address = Address::Ed25519(Ed25519Address::new(hash.into()));
-
-            // Collect it.
-            addresses.push(address);
-        }
-
-        Ok(addresses)
+            Ok(Address::Ed25519(Ed25519Address::new(hash.into())))
+        })
+        .await
     }
 
     async fn sign_ed25519(&self, msg: &[u8], chain: &Chain) -> Result<Ed25519Signature, Self::Error> {
@@ -139,6 +146,40 @@ impl SecretManage for StrongholdAdapter {
     }
 }
 
+impl StrongholdAdapter {
+    /// Derives the Ed25519 public key of the given BIP32 chain, without signing anything.
+    pub async fn derive_ed25519_public_key(&self, chain: &Chain) -> Result<[u8; 32], Error> {
+        // Same guard as for signing, the key is needed to derive from the seed
+        if !self.is_key_available().await {
+            return Err(Error::KeyCleared);
+        }
+
+        let seed_location = Slip10DeriveInput::Seed(Location::generic(SECRET_VAULT_PATH, SEED_RECORD_PATH));
+        let derive_location = Location::generic(
+            SECRET_VAULT_PATH,
+            [
+                DERIVE_OUTPUT_RECORD_PATH,
+                &chain.segments().iter().flat_map(|seg| seg.bs()).collect::<Vec<u8>>(),
+            ]
+            .concat(),
+        );
+
+        self.slip10_derive(Curve::Ed25519, chain.clone(), seed_location, derive_location.clone())
+            .await?;
+        let public_key = self.ed25519_public_key(derive_location.clone()).await?;
+
+        // Cleanup location afterwards
+        self.stronghold
+            .lock()
+            .await
+            .get_client(PRIVATE_DATA_CLIENT_PATH)?
+            .vault(SECRET_VAULT_PATH)
+            .delete_secret(derive_location.record_path())?;
+
+        Ok(public_key)
+    }
+}
+
 /// Private methods for the secret manager implementation.
 impl StrongholdAdapter {
     /// Execute [Procedure::BIP39Recover] in Stronghold to put a mnemonic into the Stronghold vault.
@@ -164,8 +205,7 @@ impl StrongholdAdapter {
         input: Slip10DeriveInput,
         output: Location,
     ) -> Result<(), Error> {
-        if let Err(err) = self
-            .stronghold
+        self.stronghold
             .lock()
             .await
             .get_client(PRIVATE_DATA_CLIENT_PATH)?
@@ -175,24 +215,7 @@ impl StrongholdAdapter {
                 input,
                 output,
             })
-        {
-            match err {
-                iota_stronghold::procedures::ProcedureError::Engine(ref e) => {
-                    // Custom error for missing vault error: https://github.com/iotaledger/stronghold.rs/blob/7f0a2e0637394595e953f9071fa74b1d160f51ec/client/src/types/error.rs#L170
-                    if e.to_string().contains("does not exist") {
-                        // Actually the seed, derived from the mnemonic, is not stored.
-                        return Err(Error::MnemonicMissing);
-                    } else {
-                        return Err(err.into());
-                    }
-                }
-                _ => {
-                    return Err(err.into());
-                }
-            }
-        };
-
-        Ok(())
+            .map_err(slip10_derive_error)
     }
 
     /// Execute [Procedure::Ed25519PublicKey] in Stronghold to get an Ed25519 public key from the SLIP-10 private key
@@ -263,6 +286,22 @@ impl StrongholdAdapter {
     }
 }
 
+// Maps the error of a SLIP-10 derivation, which fails with an engine error if no seed is stored.
+fn slip10_derive_error(err: ProcedureError) -> Error {
+    match err {
+        ProcedureError::Engine(ref e) => {
+            // Custom error for missing vault error: https://github.com/iotaledger/stronghold.rs/blob/7f0a2e0637394595e953f9071fa74b1d160f51ec/client/src/types/error.rs#L170
+            if e.to_string().contains("does not exist") {
+                // Actually the seed, derived from the mnemonic, is not stored.
+                Error::MnemonicMissing
+            } else {
+                err.into()
+            }
+        }
+        _ => err.into(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::path::Path;
@@ -0,0 +1,324 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Backups of the mnemonic of a [`StrongholdAdapter`] with Shamir's secret sharing, so it can be recovered from a
+//! threshold of shares.
+//!
+//! The seed can't be read out of the Stronghold vault, so the mnemonic is split instead, after checking that it
+//! belongs to the stored seed. Each share is written to its own Stronghold snapshot, protected by its own password.
+
+use std::path::{Path, PathBuf};
+
+use crypto::{
+    hashes::{blake2b::Blake2b256, Digest},
+    keys::bip39::wordlist,
+};
+use sharks::{Share, Sharks};
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
+
+use super::StrongholdAdapter;
+use crate::client::{
+    constants::SHIMMER_COIN_TYPE,
+    secret::{mnemonic::MnemonicSecretManager, SecretManage},
+    storage::StorageProvider,
+    stronghold::Error,
+};
+
+/// The key of a share in the store of its Stronghold snapshot.
+const SHARE_STORE_KEY: &[u8] = b"iota-wallet-mnemonic-share";
+/// The length of the checksum of the secret in a share.
+const CHECKSUM_LENGTH: usize = 4;
+/// The length of the threshold and the checksum that precede the share.
+const SHARE_HEADER_LENGTH: usize = 1 + CHECKSUM_LENGTH;
+
+/// A Stronghold snapshot holding a single share of a mnemonic, protected by its own password.
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+pub struct ShareSnapshot {
+    /// The path of the snapshot file.
+    #[zeroize(skip)]
+    pub path: PathBuf,
+    /// The password of the snapshot.
+    pub password: String,
+}
+
+impl StrongholdAdapter {
+    /// Splits the mnemonic into one Shamir share for each of the `snapshots`, of which `threshold` shares are needed
+    /// to recover it with [`StrongholdAdapter::from_shares()`]. Fewer shares reveal nothing about the mnemonic.
+    ///
+    /// The mnemonic needs to be provided, because the seed can't be read out of the vault, it's checked to belong to
+    /// the stored seed first. The snapshot files must not exist yet.
+    pub async fn export_shares(
+        &self,
+        mut mnemonic: String,
+        threshold: u8,
+        snapshots: &[ShareSnapshot],
+    ) -> Result<(), Error> {
+        let trimmed_mnemonic = Zeroizing::new(mnemonic.trim().to_string());
+        mnemonic.zeroize();
+
+        if threshold == 0 || snapshots.len() > u8::MAX as usize || usize::from(threshold) > snapshots.len() {
+            return Err(Error::InvalidShares(format!(
+                "threshold {threshold} for {} shares",
+                snapshots.len()
+            )));
+        }
+        if let Some(snapshot) = snapshots.iter().find(|snapshot| snapshot.path.exists()) {
+            return Err(Error::PathAlreadyExists(snapshot.path.clone()));
+        }
+
+        // Compare the first addresses, so the shares can't recover another seed
+        let mnemonic_secret_manager = MnemonicSecretManager::try_from_mnemonic(&trimmed_mnemonic)
+            .map_err(|e| Error::InvalidMnemonic(e.to_string()))?;
+        let mnemonic_address = mnemonic_secret_manager
+            .generate_addresses(SHIMMER_COIN_TYPE, 0, 0..1, None)
+            .await
+            .map_err(|e| Error::InvalidMnemonic(e.to_string()))?;
+        if mnemonic_address != self.generate_addresses(SHIMMER_COIN_TYPE, 0, 0..1, None).await? {
+            return Err(Error::InvalidMnemonic(
+                "the mnemonic doesn't belong to the stored seed".to_string(),
+            ));
+        }
+
+        let entropy = Zeroizing::new(
+            wordlist::decode(&trimmed_mnemonic, &wordlist::ENGLISH)
+                .map_err(|e| Error::InvalidMnemonic(format!("{e:?}")))?,
+        );
+
+        let shares = split(&entropy, threshold, snapshots.len() as u8);
+        let write_shares = async {
+            for (snapshot, share) in snapshots.iter().zip(&shares) {
+                let share_adapter = Self::builder().password(&snapshot.password).build(&snapshot.path)?;
+                share_adapter.insert(SHARE_STORE_KEY, share).await?;
+                share_adapter.write_stronghold_snapshot(None).await?;
+            }
+            Ok::<_, Error>(())
+        };
+
+        let result = write_shares.await;
+        if result.is_err() {
+            // An incomplete set of shares is useless, the files didn't exist before
+            for snapshot in snapshots {
+                std::fs::remove_file(&snapshot.path).ok();
+            }
+        }
+
+        result
+    }
+
+    /// Recovers a mnemonic from the shares exported with [`StrongholdAdapter::export_shares()`] and stores it in a new
+    /// Stronghold snapshot at `snapshot_path`, protected by `password`. At least as many shares as the threshold they
+    /// were exported with are needed.
+    pub async fn from_shares<P: AsRef<Path>>(
+        snapshots: &[ShareSnapshot],
+        snapshot_path: P,
+        password: &str,
+    ) -> Result<Self, Error> {
+        let mut shares = Vec::with_capacity(snapshots.len());
+        for snapshot in snapshots {
+            // Building the adapter would create a missing snapshot
+            if !snapshot.path.exists() {
+                return Err(Error::InvalidShares(format!(
+                    "{} doesn't exist",
+                    snapshot.path.display()
+                )));
+            }
+            let share_adapter = Self::builder().password(&snapshot.password).build(&snapshot.path)?;
+            let share = share_adapter
+                .get(SHARE_STORE_KEY)
+                .await?
+                .ok_or_else(|| Error::InvalidShares(format!("{} doesn't contain a share", snapshot.path.display())))?;
+            shares.push(Zeroizing::new(share));
+        }
+
+        // The checksum makes sure the shares recovered the exported secret, before it's stored as seed
+        let entropy = combine(&shares)?;
+        let mnemonic = wordlist::encode(&entropy, &wordlist::ENGLISH)
+            .map_err(|e| Error::InvalidShares(format!("recovered invalid mnemonic: {e:?}")))?;
+
+        let stronghold_adapter = Self::builder().password(password).build(snapshot_path)?;
+        stronghold_adapter.store_mnemonic(mnemonic).await?;
+
+        Ok(stronghold_adapter)
+    }
+}
+
+// A share is encoded as `threshold || checksum || x || y`, with one `y` byte for each byte of the secret. The checksum
+// is the same for all shares of a secret, so shares of different secrets can be told apart, and it verifies the
+// recovered secret.
+fn split(secret: &[u8], threshold: u8, share_count: u8) -> Vec<Zeroizing<Vec<u8>>> {
+    let checksum = checksum(secret);
+
+    Sharks(threshold)
+        .dealer(secret)
+        .take(share_count as usize)
+        .map(|share| {
+            let mut encoded_share = Zeroizing::new(Vec::with_capacity(SHARE_HEADER_LENGTH + secret.len() + 1));
+            encoded_share.push(threshold);
+            encoded_share.extend(checksum);
+            encoded_share.extend(Vec::from(&share));
+            encoded_share
+        })
+        .collect()
+}
+
+fn combine(shares: &[Zeroizing<Vec<u8>>]) -> Result<Zeroizing<Vec<u8>>, Error> {
+    let first_share = shares
+        .first()
+        .ok_or_else(|| Error::InvalidShares("no shares provided".to_string()))?;
+    // At least the header, the `x` and one `y` byte
+    if first_share.len() < SHARE_HEADER_LENGTH + 2 {
+        return Err(Error::InvalidShares("malformed share".to_string()));
+    }
+    let header = &first_share[..SHARE_HEADER_LENGTH];
+    let threshold = header[0];
+
+    let mut decoded_shares = Vec::with_capacity(shares.len());
+    for share in shares {
+        if share.len() != first_share.len() || &share[..SHARE_HEADER_LENGTH] != header {
+            return Err(Error::InvalidShares(
+                "the shares belong to different secrets".to_string(),
+            ));
+        }
+        let share = Share::try_from(&share[SHARE_HEADER_LENGTH..]).map_err(|e| Error::InvalidShares(e.to_string()))?;
+        if share.x.0 == 0 || decoded_shares.iter().any(|decoded: &Share| decoded.x.0 == share.x.0) {
+            return Err(Error::InvalidShares("duplicate or malformed share".to_string()));
+        }
+        decoded_shares.push(share);
+    }
+    if shares.len() < threshold as usize {
+        return Err(Error::InvalidShares(format!(
+            "{} shares provided, but {threshold} are required",
+            shares.len()
+        )));
+    }
+
+    let secret = Zeroizing::new(
+        Sharks(threshold)
+            .recover(&decoded_shares)
+            .map_err(|e| Error::InvalidShares(e.to_string()))?,
+    );
+    if checksum(&secret) != header[1..] {
+        return Err(Error::InvalidShares(
+            "the recovered secret doesn't match the checksum of the shares".to_string(),
+        ));
+    }
+
+    Ok(secret)
+}
+
+fn checksum(secret: &[u8]) -> [u8; CHECKSUM_LENGTH] {
+    let mut checksum = [0; CHECKSUM_LENGTH];
+    checksum.copy_from_slice(&Blake2b256::digest(secret)[..CHECKSUM_LENGTH]);
+    checksum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_and_combine() {
+        let secret = [7u8; 32];
+        let shares = split(&secret, 3, 5);
+        assert_eq!(shares.len(), 5);
+
+        assert_eq!(*combine(&shares[..3]).unwrap(), secret);
+        assert_eq!(
+            *combine(&[shares[4].clone(), shares[1].clone(), shares[3].clone()]).unwrap(),
+            secret
+        );
+        assert!(matches!(combine(&shares[..2]), Err(Error::InvalidShares(_))));
+        assert!(matches!(
+            combine(&[shares[0].clone(), shares[0].clone(), shares[1].clone()]),
+            Err(Error::InvalidShares(_))
+        ));
+
+        // Shares of another secret are detected by the checksum in the header
+        let other_shares = split(&[8u8; 32], 3, 5);
+        assert!(matches!(
+            combine(&[shares[0].clone(), shares[1].clone(), other_shares[2].clone()]),
+            Err(Error::InvalidShares(_))
+        ));
+
+        // A corrupted share recovers another secret, which doesn't match the checksum
+        let mut corrupted_share = shares[2].clone();
+        *corrupted_share.last_mut().unwrap() ^= 1;
+        assert!(matches!(
+            combine(&[shares[0].clone(), shares[1].clone(), corrupted_share]),
+            Err(Error::InvalidShares(_))
+        ));
+    }
+
+    fn share_snapshots(directory: &Path, count: usize) -> Vec<ShareSnapshot> {
+        (0..count)
+            .map(|index| ShareSnapshot {
+                path: directory.join(format!("share_{index}.stronghold")),
+                password: format!("share_password_{index}"),
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn export_and_recover_shares() {
+        let directory = std::env::temp_dir().join("iota_sdk_export_and_recover_shares");
+        std::fs::remove_dir_all(&directory).ok();
+        std::fs::create_dir_all(&directory).unwrap();
+        let snapshots = share_snapshots(&directory, 3);
+
+        let mnemonic = crate::client::Client::generate_mnemonic().unwrap();
+        let stronghold_adapter = StrongholdAdapter::builder()
+            .password("drowssap")
+            .build(directory.join("wallet.stronghold"))
+            .unwrap();
+        stronghold_adapter.store_mnemonic(mnemonic.clone()).await.unwrap();
+
+        // Another mnemonic isn't accepted
+        assert!(matches!(
+            stronghold_adapter
+                .export_shares(crate::client::Client::generate_mnemonic().unwrap(), 2, &snapshots)
+                .await,
+            Err(Error::InvalidMnemonic(_))
+        ));
+
+        stronghold_adapter.export_shares(mnemonic, 2, &snapshots).await.unwrap();
+
+        let recovered_adapter =
+            StrongholdAdapter::from_shares(&snapshots[1..], directory.join("recovered.stronghold"), "recovered")
+                .await
+                .unwrap();
+        assert_eq!(
+            recovered_adapter
+                .generate_addresses(SHIMMER_COIN_TYPE, 0, 0..1, None)
+                .await
+                .unwrap(),
+            stronghold_adapter
+                .generate_addresses(SHIMMER_COIN_TYPE, 0, 0..1, None)
+                .await
+                .unwrap()
+        );
+
+        std::fs::remove_dir_all(directory).ok();
+    }
+
+    #[tokio::test]
+    async fn failed_export_removes_shares() {
+        let directory = std::env::temp_dir().join("iota_sdk_failed_export_removes_shares");
+        std::fs::remove_dir_all(&directory).ok();
+        std::fs::create_dir_all(&directory).unwrap();
+        let mut snapshots = share_snapshots(&directory, 2);
+        // The last share can't be written
+        snapshots[1].path = directory.join("missing").join("share_1.stronghold");
+
+        let mnemonic = crate::client::Client::generate_mnemonic().unwrap();
+        let stronghold_adapter = StrongholdAdapter::builder()
+            .password("drowssap")
+            .build(directory.join("wallet.stronghold"))
+            .unwrap();
+        stronghold_adapter.store_mnemonic(mnemonic.clone()).await.unwrap();
+
+        assert!(stronghold_adapter.export_shares(mnemonic, 2, &snapshots).await.is_err());
+        assert!(!snapshots[0].path.exists());
+
+        std::fs::remove_dir_all(directory).ok();
+    }
+}
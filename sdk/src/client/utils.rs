@@ -37,6 +37,15 @@ pub fn hex_to_bech32(hex: &str, bech32_hrp: impl HrpLike) -> Result<Bech32Addres
     Ok(Address::Ed25519(address).try_to_bech32(bech32_hrp)?)
 }
 
+/// Transforms a Chrysalis bech32 encoded address, like `iota1...`, to a bech32 encoded address with the given HRP.
+/// Chrysalis only had Ed25519 addresses, which are encoded the same way in Stardust.
+pub fn chrysalis_address_to_bech32(address: impl Bech32AddressLike, bech32_hrp: impl HrpLike) -> Result<Bech32Address> {
+    match address.to_bech32()?.inner() {
+        address @ Address::Ed25519(_) => Ok(address.try_to_bech32(bech32_hrp)?),
+        address => Err(crate::types::block::Error::InvalidAddressKind(address.kind()).into()),
+    }
+}
+
 /// Transforms a prefix hex encoded public key to a bech32 encoded address
 pub fn hex_public_key_to_bech32_address(hex: &str, bech32_hrp: impl HrpLike) -> Result<Bech32Address> {
     let public_key: [u8; Ed25519Address::LENGTH] = prefix_hex::decode(hex)?;
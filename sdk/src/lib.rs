@@ -22,8 +22,8 @@ pub mod client;
 #[cfg_attr(docsrs, doc(cfg(feature = "pow")))]
 pub mod pow;
 pub mod types;
-#[cfg(feature = "wallet")]
-#[cfg_attr(docsrs, doc(cfg(feature = "wallet")))]
+#[cfg(feature = "wallet-core")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wallet-core")))]
 pub mod wallet;
 // Utilities used in multiple submodules
 pub mod utils;
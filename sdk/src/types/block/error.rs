@@ -49,6 +49,7 @@ pub enum Error {
     InvalidEssenceKind(u8),
     InvalidFeatureCount(<FeatureCount as TryFrom<usize>>::Error),
     InvalidFeatureKind(u8),
+    FoundryAliasMismatch { foundry: AliasId, alias: AliasId },
     InvalidFoundryOutputSupply { minted: U256, melted: U256, max: U256 },
     Hex(HexError),
     InvalidInputKind(u8),
@@ -112,6 +113,7 @@ pub enum Error {
     ProtocolVersionMismatch { expected: u8, actual: u8 },
     NonceNotFound,
     ReceiptFundsNotUniqueSorted,
+    RemainingBytes,
     RemainingBytesAfterBlock,
     SelfControlledAliasOutput(AliasId),
     SelfDepositNft(NftId),
@@ -188,6 +190,9 @@ impl fmt::Display for Error {
             Self::InvalidEssenceKind(k) => write!(f, "invalid essence kind: {k}"),
             Self::InvalidFeatureCount(count) => write!(f, "invalid feature count: {count}"),
             Self::InvalidFeatureKind(k) => write!(f, "invalid feature kind: {k}"),
+            Self::FoundryAliasMismatch { foundry, alias } => {
+                write!(f, "foundry is controlled by alias {foundry} but got alias {alias}")
+            }
             Self::InvalidFoundryOutputSupply { minted, melted, max } => write!(
                 f,
                 "invalid foundry output supply: minted {minted}, melted {melted} max {max}",
@@ -302,6 +307,9 @@ impl fmt::Display for Error {
             Self::ReceiptFundsNotUniqueSorted => {
                 write!(f, "receipt funds are not unique and/or sorted")
             }
+            Self::RemainingBytes => {
+                write!(f, "remaining bytes after unpacking")
+            }
             Self::RemainingBytesAfterBlock => {
                 write!(f, "remaining bytes after block")
             }
@@ -1,7 +1,21 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use alloc::{string::String, vec::Vec};
+
 use crypto::hashes::{blake2b::Blake2b256, Digest};
+use packable::{
+    error::{UnexpectedEOF, UnpackError},
+    unpacker::{CounterUnpacker, SliceUnpacker},
+    Packable, PackableExt,
+};
+
+use crate::types::block::{
+    output::Output,
+    payload::{milestone::MilestonePayload, transaction::TransactionPayload},
+    protocol::ProtocolParameters,
+    Block, Error,
+};
 
 /// Hashes a string network name to a digit network ID.
 pub fn network_name_to_id(network_name: &str) -> u64 {
@@ -9,3 +23,56 @@ pub fn network_name_to_id(network_name: &str) -> u64 {
     // that slice to an array of 8 bytes.
     u64::from_le_bytes(Blake2b256::digest(network_name.as_bytes())[0..8].try_into().unwrap())
 }
+
+/// Conversion of types from and to their raw bytes, as they are sent by nodes. Unpacking runs the same syntactic
+/// checks as the node, with the given [`ProtocolParameters`].
+pub trait RawBytes: Packable<UnpackVisitor = ProtocolParameters, UnpackError = Error> {
+    /// Returns the packed bytes.
+    fn to_bytes(&self) -> Vec<u8> {
+        self.pack_to_vec()
+    }
+
+    /// Returns the packed bytes as prefix hex encoded string.
+    fn to_hex(&self) -> String {
+        prefix_hex::encode(self.pack_to_vec())
+    }
+
+    /// Unpacks and verifies a value from bytes, without allowing trailing bytes.
+    fn from_bytes<T: AsRef<[u8]>>(
+        bytes: T,
+        protocol_parameters: &ProtocolParameters,
+    ) -> Result<Self, UnpackError<Error, UnexpectedEOF>> {
+        let mut unpacker = CounterUnpacker::new(SliceUnpacker::new(bytes.as_ref()));
+        let value = Self::unpack::<_, true>(&mut unpacker, protocol_parameters)?;
+
+        if u8::unpack::<_, true>(&mut unpacker, &()).is_ok() {
+            return Err(UnpackError::Packable(Error::RemainingBytes));
+        }
+
+        Ok(value)
+    }
+
+    /// Unpacks and verifies a value from prefix hex encoded bytes.
+    fn from_hex<T: AsRef<str>>(
+        hex: T,
+        protocol_parameters: &ProtocolParameters,
+    ) -> Result<Self, UnpackError<Error, UnexpectedEOF>> {
+        let bytes = prefix_hex::decode::<Vec<u8>>(hex.as_ref()).map_err(|e| UnpackError::Packable(Error::Hex(e)))?;
+        Self::from_bytes(bytes, protocol_parameters)
+    }
+}
+
+impl RawBytes for Block {
+    fn from_bytes<T: AsRef<[u8]>>(
+        bytes: T,
+        protocol_parameters: &ProtocolParameters,
+    ) -> Result<Self, UnpackError<Error, UnexpectedEOF>> {
+        Self::unpack_strict(bytes, protocol_parameters)
+    }
+}
+
+impl RawBytes for MilestonePayload {}
+
+impl RawBytes for Output {}
+
+impl RawBytes for TransactionPayload {}
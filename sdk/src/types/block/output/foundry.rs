@@ -3,6 +3,7 @@
 
 use alloc::{
     collections::{BTreeMap, BTreeSet},
+    vec,
     vec::Vec,
 };
 use core::cmp::Ordering;
@@ -20,8 +21,9 @@ use crate::types::block::{
     output::{
         feature::{verify_allowed_features, Feature, FeatureFlags, Features},
         unlock_condition::{verify_allowed_unlock_conditions, UnlockCondition, UnlockConditionFlags, UnlockConditions},
-        verify_output_amount, ChainId, FoundryId, NativeToken, NativeTokens, Output, OutputBuilderAmount, OutputId,
-        Rent, RentStructure, StateTransitionError, StateTransitionVerifier, TokenId, TokenScheme,
+        verify_output_amount, AliasId, AliasOutput, AliasOutputBuilder, ChainId, FoundryId, NativeToken, NativeTokens,
+        Output, OutputBuilderAmount, OutputId, Rent, RentStructure, SimpleTokenScheme, StateTransitionError,
+        StateTransitionVerifier, TokenId, TokenScheme,
     },
     protocol::ProtocolParameters,
     semantic::{ConflictReason, ValidationContext},
@@ -267,6 +269,94 @@ impl From<&FoundryOutput> for FoundryOutputBuilder {
     }
 }
 
+/// Builds the next alias and foundry outputs for a change of the supply of a foundry, by minting and/or melting native
+/// tokens. The new token scheme is validated client-side, the minted and melted tokens can only increase and the
+/// circulating supply can't exceed the maximum supply.
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct FoundryTransitionBuilder {
+    alias_id: AliasId,
+    alias_output: AliasOutput,
+    foundry_output: FoundryOutput,
+    mint_amount: U256,
+    melt_amount: U256,
+}
+
+impl FoundryTransitionBuilder {
+    /// Creates a [`FoundryTransitionBuilder`] from the current alias output controlling the foundry, the output id of
+    /// the alias output and the current foundry output.
+    pub fn new(alias_output: AliasOutput, alias_output_id: &OutputId, foundry_output: FoundryOutput) -> Self {
+        Self {
+            alias_id: alias_output.alias_id_non_null(alias_output_id),
+            alias_output,
+            foundry_output,
+            mint_amount: U256::zero(),
+            melt_amount: U256::zero(),
+        }
+    }
+
+    /// Sets the amount of native tokens to mint.
+    #[inline(always)]
+    pub fn with_mint_amount(mut self, mint_amount: impl Into<U256>) -> Self {
+        self.mint_amount = mint_amount.into();
+        self
+    }
+
+    /// Sets the amount of native tokens to melt.
+    #[inline(always)]
+    pub fn with_melt_amount(mut self, melt_amount: impl Into<U256>) -> Self {
+        self.melt_amount = melt_amount.into();
+        self
+    }
+
+    /// Returns the validated token scheme of the next foundry output.
+    pub fn token_scheme(&self) -> Result<SimpleTokenScheme, Error> {
+        let foundry_alias_id = *self.foundry_output.alias_address().alias_id();
+
+        if foundry_alias_id != self.alias_id {
+            return Err(Error::FoundryAliasMismatch {
+                foundry: foundry_alias_id,
+                alias: self.alias_id,
+            });
+        }
+
+        let TokenScheme::Simple(token_scheme) = self.foundry_output.token_scheme();
+        let minted_tokens = token_scheme
+            .minted_tokens()
+            .checked_add(self.mint_amount)
+            .ok_or(Error::NativeTokensOverflow)?;
+        let melted_tokens = token_scheme
+            .melted_tokens()
+            .checked_add(self.melt_amount)
+            .ok_or(Error::NativeTokensOverflow)?;
+
+        SimpleTokenScheme::new(minted_tokens, melted_tokens, token_scheme.maximum_supply())
+    }
+
+    /// Finishes the [`FoundryTransitionBuilder`] into the next alias output, with an incremented state index, and the
+    /// next foundry output.
+    pub fn finish(self, token_supply: u64) -> Result<(AliasOutput, FoundryOutput), Error> {
+        let token_scheme = self.token_scheme()?;
+
+        let alias_output = AliasOutputBuilder::from(&self.alias_output)
+            .with_alias_id(self.alias_id)
+            .with_state_index(self.alias_output.state_index() + 1)
+            .finish(token_supply)?;
+        let foundry_output = FoundryOutputBuilder::from(&self.foundry_output)
+            .with_token_scheme(TokenScheme::Simple(token_scheme))
+            .finish(token_supply)?;
+
+        Ok((alias_output, foundry_output))
+    }
+
+    /// Finishes the [`FoundryTransitionBuilder`] into the next alias and foundry [`Output`]s, in that order.
+    pub fn finish_outputs(self, token_supply: u64) -> Result<Vec<Output>, Error> {
+        let (alias_output, foundry_output) = self.finish(token_supply)?;
+
+        Ok(vec![Output::Alias(alias_output), Output::Foundry(foundry_output)])
+    }
+}
+
 /// Describes a foundry output that is controlled by an alias.
 #[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
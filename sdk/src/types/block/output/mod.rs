@@ -52,7 +52,7 @@ pub use self::{
     basic::{BasicOutput, BasicOutputBuilder},
     chain_id::ChainId,
     feature::{Feature, Features},
-    foundry::{FoundryOutput, FoundryOutputBuilder},
+    foundry::{FoundryOutput, FoundryOutputBuilder, FoundryTransitionBuilder},
     foundry_id::FoundryId,
     inputs_commitment::InputsCommitment,
     metadata::OutputMetadata,
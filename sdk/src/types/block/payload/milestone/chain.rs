@@ -0,0 +1,99 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::types::block::payload::milestone::{
+    MilestoneId, MilestoneIndex, MilestoneKeyManager, MilestonePayload, MilestoneValidationError,
+};
+
+/// Validates a sequence of milestones, for example the milestone history provided by a node, one milestone at a
+/// time. Every milestone needs to reference the previous one, have the next index and a timestamp that isn't
+/// smaller, and needs to be signed by at least `min_threshold` keys that are applicable for its index.
+#[derive(Clone, Debug)]
+pub struct MilestoneChainValidator {
+    key_manager: MilestoneKeyManager,
+    min_threshold: usize,
+    latest: Option<(MilestoneIndex, MilestoneId, u32)>,
+}
+
+impl MilestoneChainValidator {
+    /// Creates a new [`MilestoneChainValidator`], the first validated milestone is the start of the chain.
+    pub fn new(key_manager: MilestoneKeyManager, min_threshold: usize) -> Self {
+        Self {
+            key_manager,
+            min_threshold,
+            latest: None,
+        }
+    }
+
+    /// Creates a new [`MilestoneChainValidator`] that continues the chain after an already trusted milestone.
+    pub fn with_trusted_milestone(
+        key_manager: MilestoneKeyManager,
+        min_threshold: usize,
+        milestone: &MilestonePayload,
+    ) -> Self {
+        Self {
+            key_manager,
+            min_threshold,
+            latest: Some((
+                milestone.essence().index(),
+                milestone.id(),
+                milestone.essence().timestamp(),
+            )),
+        }
+    }
+
+    /// Returns the index and id of the latest validated milestone.
+    pub fn latest_milestone(&self) -> Option<(MilestoneIndex, MilestoneId)> {
+        self.latest.map(|(index, id, _)| (index, id))
+    }
+
+    /// Validates the next milestone of the chain and returns its id. An invalid milestone doesn't change the chain.
+    pub fn validate_next(&mut self, milestone: &MilestonePayload) -> Result<MilestoneId, MilestoneValidationError> {
+        let essence = milestone.essence();
+
+        if let Some((index, id, timestamp)) = self.latest {
+            let next_index = index
+                .checked_add(1)
+                .map(MilestoneIndex)
+                .ok_or(MilestoneValidationError::IndexOverflow(index))?;
+            if essence.index() != next_index {
+                return Err(MilestoneValidationError::UnexpectedIndex(next_index, essence.index()));
+            }
+            if *essence.previous_milestone_id() != id {
+                return Err(MilestoneValidationError::PreviousMilestoneIdMismatch(
+                    id,
+                    *essence.previous_milestone_id(),
+                ));
+            }
+            if essence.timestamp() < timestamp {
+                return Err(MilestoneValidationError::DecreasingTimestamp(
+                    timestamp,
+                    essence.timestamp(),
+                ));
+            }
+        }
+
+        milestone.validate(
+            &self.key_manager.applicable_public_keys(essence.index()),
+            self.min_threshold,
+        )?;
+
+        let id = milestone.id();
+        self.latest = Some((essence.index(), id, essence.timestamp()));
+
+        Ok(id)
+    }
+
+    /// Validates all milestones of an iterator in order and returns the index and id of the last one. Fails with the
+    /// index of the first invalid milestone in the iterator.
+    pub fn validate_all<'a>(
+        &mut self,
+        milestones: impl IntoIterator<Item = &'a MilestonePayload>,
+    ) -> Result<Option<(MilestoneIndex, MilestoneId)>, (usize, MilestoneValidationError)> {
+        for (position, milestone) in milestones.into_iter().enumerate() {
+            self.validate_next(milestone).map_err(|error| (position, error))?;
+        }
+
+        Ok(self.latest_milestone())
+    }
+}
@@ -0,0 +1,88 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use alloc::{string::String, vec::Vec};
+
+use crate::types::block::payload::milestone::MilestoneIndex;
+
+/// A public key of the coordinator and the range of milestones it is applicable for.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct MilestoneKeyRange {
+    public_key: String,
+    start_index: MilestoneIndex,
+    end_index: MilestoneIndex,
+}
+
+impl MilestoneKeyRange {
+    /// Creates a new [`MilestoneKeyRange`] from a hex encoded public key, an `end_index` of 0 means the key doesn't
+    /// expire.
+    pub fn new(public_key: impl Into<String>, start_index: MilestoneIndex, end_index: MilestoneIndex) -> Self {
+        Self {
+            public_key: public_key.into(),
+            start_index,
+            end_index,
+        }
+    }
+
+    /// Returns the hex encoded public key of a [`MilestoneKeyRange`].
+    pub fn public_key(&self) -> &str {
+        &self.public_key
+    }
+
+    /// Returns the first milestone index a [`MilestoneKeyRange`] is applicable for.
+    pub fn start_index(&self) -> MilestoneIndex {
+        self.start_index
+    }
+
+    /// Returns the last milestone index a [`MilestoneKeyRange`] is applicable for, 0 if it doesn't expire.
+    pub fn end_index(&self) -> MilestoneIndex {
+        self.end_index
+    }
+
+    /// Returns whether the key is applicable for a milestone index.
+    pub fn is_applicable(&self, index: MilestoneIndex) -> bool {
+        index >= self.start_index && (*self.end_index == 0 || index <= self.end_index)
+    }
+}
+
+/// Manages the public keys of the coordinator, like the key manager of a node, to get the keys that are applicable
+/// for a milestone.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct MilestoneKeyManager {
+    key_ranges: Vec<MilestoneKeyRange>,
+}
+
+impl MilestoneKeyManager {
+    /// Creates a new [`MilestoneKeyManager`].
+    pub fn new(key_ranges: Vec<MilestoneKeyRange>) -> Self {
+        Self { key_ranges }
+    }
+
+    /// Returns the key ranges of a [`MilestoneKeyManager`].
+    pub fn key_ranges(&self) -> &[MilestoneKeyRange] {
+        &self.key_ranges
+    }
+
+    /// Returns the public keys that are applicable for a milestone index, hex encoded without prefix as expected by
+    /// [`MilestonePayload::validate()`](crate::types::block::payload::milestone::MilestonePayload::validate).
+    pub fn applicable_public_keys(&self, index: MilestoneIndex) -> Vec<String> {
+        let mut public_keys = Vec::new();
+        for key_range in self
+            .key_ranges
+            .iter()
+            .filter(|key_range| key_range.is_applicable(index))
+        {
+            let public_key = key_range.public_key.strip_prefix("0x").unwrap_or(&key_range.public_key);
+            let public_key = public_key.to_lowercase();
+            if !public_keys.contains(&public_key) {
+                public_keys.push(public_key);
+            }
+        }
+        public_keys
+    }
+}
@@ -3,8 +3,10 @@
 
 //! Module describing the milestone payload.
 
+mod chain;
 mod essence;
 mod index;
+mod key_manager;
 mod merkle;
 mod milestone_id;
 
@@ -20,8 +22,10 @@ pub(crate) use option::{MilestoneOptionCount, ReceiptFundsCount};
 use packable::{bounded::BoundedU8, prefix::VecPrefix, Packable};
 
 pub use self::{
+    chain::MilestoneChainValidator,
     essence::MilestoneEssence,
     index::MilestoneIndex,
+    key_manager::{MilestoneKeyManager, MilestoneKeyRange},
     merkle::MerkleRoot,
     milestone_id::MilestoneId,
     option::{MilestoneOption, MilestoneOptions, ParametersMilestoneOption, ReceiptMilestoneOption},
@@ -38,6 +42,14 @@ pub enum MilestoneValidationError {
     UnapplicablePublicKey(String),
     InvalidSignature(usize, String),
     Crypto(CryptoError),
+    /// The index of a milestone isn't the next one of the chain (expected, found).
+    UnexpectedIndex(MilestoneIndex, MilestoneIndex),
+    /// The chain can't be continued after a milestone with the maximum index (latest).
+    IndexOverflow(MilestoneIndex),
+    /// A milestone doesn't reference the previous milestone of the chain (expected, found).
+    PreviousMilestoneIdMismatch(MilestoneId, MilestoneId),
+    /// The timestamp of a milestone is smaller than the one of the previous milestone (previous, found).
+    DecreasingTimestamp(u32, u32),
 }
 
 impl From<CryptoError> for MilestoneValidationError {
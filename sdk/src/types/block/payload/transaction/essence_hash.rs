@@ -0,0 +1,11 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+impl_id!(
+    pub TransactionEssenceHash,
+    32,
+    "The BLAKE2b-256 hash of the transaction essence bytes, which is signed by the unlocks. See <https://www.blake2.net/> for more information."
+);
+
+#[cfg(feature = "serde")]
+string_serde_impl!(TransactionEssenceHash);
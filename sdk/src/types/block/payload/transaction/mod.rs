@@ -4,6 +4,7 @@
 //! Module describing the transaction payload.
 
 mod essence;
+mod essence_hash;
 mod transaction_id;
 
 use crypto::hashes::{blake2b::Blake2b256, Digest};
@@ -12,6 +13,7 @@ use packable::{error::UnpackError, packer::Packer, unpacker::Unpacker, Packable,
 pub(crate) use self::essence::{InputCount, OutputCount};
 pub use self::{
     essence::{RegularTransactionEssence, RegularTransactionEssenceBuilder, TransactionEssence},
+    essence_hash::TransactionEssenceHash,
     transaction_id::TransactionId,
 };
 use crate::types::block::{protocol::ProtocolParameters, unlock::Unlocks, Error};
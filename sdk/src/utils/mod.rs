@@ -3,9 +3,24 @@
 
 #[cfg(feature = "serde")]
 pub mod serde;
+#[cfg(all(feature = "instant", feature = "std"))]
+mod time;
 
+#[cfg(all(feature = "instant", feature = "std"))]
+pub use self::time::{set_time_provider, SystemTimeProvider, TimeProvider};
+
+/// Returns the duration since the unix epoch, from the time provider if one got set with `set_time_provider()`.
 #[cfg(feature = "instant")]
 pub fn unix_timestamp_now() -> core::time::Duration {
+    #[cfg(feature = "std")]
+    if let Some(time_provider) = time::time_provider() {
+        return time_provider.now();
+    }
+    system_unix_timestamp_now()
+}
+
+#[cfg(feature = "instant")]
+fn system_unix_timestamp_now() -> core::time::Duration {
     instant::SystemTime::now()
         .duration_since(instant::SystemTime::UNIX_EPOCH)
         .expect("time went backwards")
@@ -24,3 +24,52 @@ pub mod string {
         String::deserialize(deserializer)?.parse().map_err(de::Error::custom)
     }
 }
+
+pub mod option_string {
+    use alloc::string::String;
+    use core::{fmt::Display, str::FromStr};
+
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<T, S>(value: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: Display,
+        S: Serializer,
+    {
+        match value {
+            Some(value) => serializer.collect_str(value),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        T: FromStr,
+        T::Err: Display,
+        D: Deserializer<'de>,
+    {
+        Option::<String>::deserialize(deserializer)?
+            .map(|string| string.parse().map_err(de::Error::custom))
+            .transpose()
+    }
+}
+
+pub mod prefix_hex_bytes {
+    use alloc::{string::String, vec::Vec};
+
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S>(value: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&prefix_hex::encode(value))
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        prefix_hex::decode(String::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
@@ -0,0 +1,34 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use alloc::sync::Arc;
+use core::time::Duration;
+use std::sync::RwLock;
+
+/// A source of the current time, used by [`unix_timestamp_now()`](super::unix_timestamp_now) and therefore for
+/// timelocks, expirations and the time check before sending transactions.
+pub trait TimeProvider: Send + Sync {
+    /// Returns the duration since the unix epoch.
+    fn now(&self) -> Duration;
+}
+
+/// A [`TimeProvider`] using the system time, the default.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemTimeProvider;
+
+impl TimeProvider for SystemTimeProvider {
+    fn now(&self) -> Duration {
+        super::system_unix_timestamp_now()
+    }
+}
+
+static TIME_PROVIDER: RwLock<Option<Arc<dyn TimeProvider>>> = RwLock::new(None);
+
+/// Sets the [`TimeProvider`] used for the whole process, `None` resets it to the system time.
+pub fn set_time_provider(time_provider: Option<Arc<dyn TimeProvider>>) {
+    *TIME_PROVIDER.write().unwrap_or_else(|e| e.into_inner()) = time_provider;
+}
+
+pub(crate) fn time_provider() -> Option<Arc<dyn TimeProvider>> {
+    TIME_PROVIDER.read().unwrap_or_else(|e| e.into_inner()).clone()
+}
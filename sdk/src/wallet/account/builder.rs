@@ -9,7 +9,11 @@ use crate::{
     client::secret::{SecretManage, SecretManager},
     types::block::address::{Address, Bech32Address, Hrp},
     wallet::{
-        account::{types::AccountAddress, Account, AccountDetails},
+        account::{
+            operations::{address_discovery::GapLimitOptions, syncing::SyncOptions},
+            types::{AccountAddress, AccountId},
+            Account, AccountDetails,
+        },
         Error, Wallet,
     },
 };
@@ -19,6 +23,11 @@ pub struct AccountBuilder {
     addresses: Option<Vec<AccountAddress>>,
     alias: Option<String>,
     bech32_hrp: Option<Hrp>,
+    initial_addresses: u32,
+    initial_sync: Option<SyncOptions>,
+    default_sync_options: Option<SyncOptions>,
+    address_discovery: Option<GapLimitOptions>,
+    watch_only: bool,
     wallet: Wallet,
 }
 
@@ -29,6 +38,11 @@ impl AccountBuilder {
             addresses: None,
             alias: None,
             bech32_hrp: None,
+            initial_addresses: 1,
+            initial_sync: None,
+            default_sync_options: None,
+            address_discovery: None,
+            watch_only: false,
             wallet,
         }
     }
@@ -40,6 +54,31 @@ impl AccountBuilder {
         self
     }
 
+    /// Create a watch-only account for the addresses, which doesn't use the secret manager of the wallet, so it can
+    /// be used with a [`PlaceholderSecretManager`](crate::client::secret::placeholder::PlaceholderSecretManager).
+    /// Syncing, balances and the transaction history work like for other accounts and transactions can be prepared
+    /// for an offline signer, but generating addresses and signing return [`Error::WatchOnlyAccount`].
+    /// Extended public keys aren't supported as source, because Ed25519 addresses are derived with hardened SLIP-10
+    /// derivation only, which requires the private key.
+    pub fn with_watch_only_addresses(mut self, addresses: impl IntoIterator<Item = Bech32Address>) -> Self {
+        self.addresses = Some(
+            addresses
+                .into_iter()
+                .enumerate()
+                .map(|(index, address)| AccountAddress {
+                    address,
+                    key_index: index as u32,
+                    internal: false,
+                    used: false,
+                    label: None,
+                    metadata: Default::default(),
+                })
+                .collect(),
+        );
+        self.watch_only = true;
+        self
+    }
+
     /// Set the alias
     pub fn with_alias(mut self, alias: impl Into<Option<String>>) -> Self {
         self.alias = alias.into();
@@ -52,9 +91,36 @@ impl AccountBuilder {
         self
     }
 
+    /// Set the amount of public addresses the account should have after creation, the default is 1
+    pub fn with_initial_addresses(mut self, amount: u32) -> Self {
+        self.initial_addresses = amount;
+        self
+    }
+
+    /// Sync the account with the given options after creation
+    pub fn with_initial_sync(mut self, options: impl Into<Option<SyncOptions>>) -> Self {
+        self.initial_sync = options.into();
+        self
+    }
+
+    /// Set the default sync options of the account
+    pub fn with_default_sync_options(mut self, options: impl Into<Option<SyncOptions>>) -> Self {
+        self.default_sync_options = options.into();
+        self
+    }
+
+    /// Discover the used addresses of the account after creation with a gap limit scan, see
+    /// [`Account::discover_addresses()`]. Needed to recover accounts that were used with other software.
+    pub fn with_address_discovery(mut self, options: impl Into<Option<GapLimitOptions>>) -> Self {
+        self.address_discovery = options.into();
+        self
+    }
+
     /// Build the Account and add it to the accounts from Wallet
     /// Also generates the first address of the account and if it's not the first account, the address for the first
     /// account will also be generated and compared, so no accounts get generated with different seeds
+    /// If set, the address discovery, the additional initial addresses, the initial sync and the default sync options
+    /// are applied before the account is added. If one of them fails, its error is returned and no account is created.
     pub async fn finish(&mut self) -> crate::wallet::Result<Account> {
         let mut accounts = self.wallet.accounts.write().await;
         let account_index = accounts.len() as u32;
@@ -84,13 +150,33 @@ impl AccountBuilder {
             }
         }
 
+        // Watch-only addresses can't be checked against the secret manager, so at least make sure they're for the
+        // connected network
+        if self.watch_only {
+            let addresses = self.addresses.as_deref().unwrap_or_default();
+            if addresses.is_empty() {
+                return Err(Error::WatchOnlyAccount(account_alias, "be created without addresses"));
+            }
+            for address in addresses {
+                self.wallet.client().bech32_hrp_matches(address.address.hrp()).await?;
+            }
+        }
+
         // If addresses are provided we will use them directly without the additional checks, because then we assume
         // that it's for offline signing and the secretManager can't be used
         let addresses = match &self.addresses {
             Some(addresses) => addresses.clone(),
             None => {
                 let mut bech32_hrp = self.bech32_hrp;
-                if let Some(first_account) = accounts.first() {
+                // Watch-only accounts aren't derived from the seed, so they can't be used for the comparison
+                let mut first_account = None;
+                for account in accounts.iter() {
+                    if !account.details().await.watch_only {
+                        first_account.replace(account);
+                        break;
+                    }
+                }
+                if let Some(first_account) = first_account {
                     let first_account_coin_type = *first_account.details().await.coin_type();
                     // Generate the first address of the first account and compare it to the stored address from the
                     // first account to prevent having multiple accounts created with different
@@ -135,6 +221,8 @@ impl AccountBuilder {
                     key_index: 0,
                     internal: false,
                     used: false,
+                    label: None,
+                    metadata: Default::default(),
                 };
 
                 vec![first_public_account_address]
@@ -143,8 +231,10 @@ impl AccountBuilder {
 
         let account = AccountDetails {
             index: account_index,
+            account_id: AccountId::generate()?,
             coin_type,
             alias: account_alias,
+            alias_history: Vec::new(),
             public_addresses: addresses,
             internal_addresses: Vec::new(),
             addresses_with_unspent_outputs: Vec::new(),
@@ -156,15 +246,63 @@ impl AccountBuilder {
             incoming_transactions: HashMap::new(),
             inaccessible_incoming_transactions: HashSet::new(),
             native_token_foundries: HashMap::new(),
+            spending_allowances: HashMap::new(),
+            address_rotation: false,
+            retired_addresses: Vec::new(),
+            token_policy: None,
+            dust_policy: None,
+            alert_thresholds: None,
+            rate_limit: None,
+            transaction_sequencing: false,
+            next_sequence_number: 0,
+            watch_only: self.watch_only,
+            primary_alias: None,
+            auto_revote: false,
         };
 
         let account = Account::new(account, self.wallet.inner.clone()).await?;
+        // The accounts stay locked during the setup, so no other account can get the same index
+        if let Err(err) = self.setup(&account).await {
+            // The setup can already have stored the account
+            #[cfg(feature = "storage")]
+            if let Err(remove_err) = self
+                .wallet
+                .storage_manager
+                .write()
+                .await
+                .remove_account(account_index)
+                .await
+            {
+                log::warn!("[ACCOUNT BUILDER] failed to remove the account {account_index}: {remove_err}");
+            }
+            return Err(err);
+        }
         #[cfg(feature = "storage")]
         account.save(None).await?;
         accounts.push(account.clone());
 
         Ok(account)
     }
+
+    // Applies the optional settings to the new account, which isn't added to the wallet yet
+    async fn setup(&self, account: &Account) -> crate::wallet::Result<()> {
+        if let Some(options) = self.address_discovery {
+            account.discover_addresses(options).await?;
+        }
+        let address_count = account.details().await.public_addresses().len() as u32;
+        if !self.watch_only && self.initial_addresses > address_count {
+            account
+                .generate_addresses(self.initial_addresses - address_count, None)
+                .await?;
+        }
+        if let Some(options) = self.initial_sync.clone() {
+            account.sync(Some(options)).await?;
+        }
+        if let Some(options) = self.default_sync_options.clone() {
+            account.set_default_sync_options(options).await?;
+        }
+        Ok(())
+    }
 }
 
 /// Generate the first public address of an account
@@ -11,6 +11,24 @@ pub(crate) const DEFAULT_LEDGER_OUTPUT_CONSOLIDATION_THRESHOLD: usize = 15;
 /// Amount of API request that can be sent in parallel during syncing
 pub(crate) const PARALLEL_REQUESTS_AMOUNT: usize = 500;
 
+/// Amount of accounts that are synced in parallel by `Wallet::sync_all_accounts()`
+pub(crate) const DEFAULT_SYNC_ALL_CONCURRENCY: usize = 10;
+
+/// How long the network parameters are cached by an account before they're fetched again
+pub(crate) const NETWORK_PARAMS_CACHE_TTL: std::time::Duration = std::time::Duration::from_secs(60);
+
+/// Maximum amount of NFTs that are sent in a single transaction by `Account::send_nfts()`, so the NFT inputs leave
+/// room for inputs that cover the storage deposit of the remainder
+pub(crate) const MAX_NFTS_PER_TRANSACTION: usize = 64;
+
+/// Maximum amount of outputs that are added to the lineage of a traced output
+pub(crate) const MAX_OUTPUT_TRACE_ENTRIES: usize = 1000;
+
+/// Seconds a cached participation event status is used before it's fetched again from the event nodes, statuses of
+/// ended events are never fetched again
+#[cfg(feature = "wallet-participation")]
+pub(crate) const PARTICIPATION_EVENT_STATUS_REFRESH_INTERVAL: u32 = 60;
+
 /// ms before an account actually syncs with the network, before it just returns the previous syncing result
 /// this is done to prevent unnecessary simultaneous synchronizations
 pub(crate) const MIN_SYNC_INTERVAL: u128 = 5;
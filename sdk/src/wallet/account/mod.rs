@@ -23,37 +23,71 @@ use getset::{Getters, Setters};
 use serde::{Deserialize, Serialize};
 use tokio::sync::{Mutex, RwLock};
 
-#[cfg(feature = "participation")]
-pub use self::operations::participation::{AccountParticipationOverview, ParticipationEventWithNodes};
-use self::types::{
-    address::{AccountAddress, AddressWithUnspentOutputs},
-    AccountBalance, OutputData, Transaction,
+#[cfg(feature = "storage")]
+pub use self::operations::audit_log::{AuditLogEntry, AuditLogFilter};
+#[cfg(feature = "wallet-consolidation")]
+pub use self::operations::output_consolidation::ConsolidationParams;
+#[cfg(feature = "wallet-participation")]
+pub use self::operations::participation::{
+    AccountParticipationOverview, CachedParticipationEventStatus, ParticipationEventWithNodes,
 };
+#[cfg(not(target_family = "wasm"))]
+pub use self::operations::retry::RetryTransactionHandle;
 pub use self::{
     operations::{
+        address_clustering::{AddressCluster, AddressClusteringReport},
+        address_discovery::GapLimitOptions,
+        address_rotation::RetiredAddress,
+        address_verification::AddressDerivationProof,
+        alert_thresholds::AlertThresholds,
+        allowance::{AllowanceUsage, SpendingAllowance},
+        dust_policy::DustPolicy,
+        internal_address_pool::InternalAddressPoolStatus,
+        legacy_funds::LegacyAddressFunds,
+        network_params::NetworkParams,
+        nft_metadata::{Irc27Attribute, Irc27Metadata, NftMetadata},
         output_claiming::OutputsToClaim,
+        output_trace::{OutputLineage, OutputLineageEntry},
+        rate_limit::TransactionRateLimit,
+        retry::RetryProgress,
         syncing::{
             options::{AccountSyncOptions, AliasSyncOptions, NftSyncOptions},
             SyncOptions,
         },
+        token_policy::TokenPolicy,
+        token_registry::{Irc30Metadata, TokenRegistry},
         transaction::{
             high_level::{
-                create_alias::{CreateAliasParams, CreateAliasParamsDto},
+                burning_melting::can_burn::BurnBlocker,
+                create_alias::{CreateAliasParams, CreateAliasParamsDto, CreateAliasTransactionDto},
                 minting::{
                     mint_native_token::{
                         MintNativeTokenParams, MintNativeTokenParamsDto, MintTokenTransactionDto,
                         PreparedMintTokenTransactionDto,
                     },
-                    mint_nfts::{MintNftParams, MintNftParamsDto},
+                    mint_nfts::{MintNftParams, MintNftParamsDto, MintNftTransactionDto},
                 },
+                sweep::SweepOptions,
             },
             prepare_output::{
                 Assets, Features, OutputParams, OutputParamsDto, ReturnStrategy, StorageDeposit, Unlocks,
             },
-            RemainderValueStrategy, TransactionOptions, TransactionOptionsDto,
+            OutputEstimate, RemainderSplit, RemainderValueStrategy, TransactionEstimate, TransactionOptions,
+            TransactionOptionsDto,
         },
+        transaction_export::{
+            AddressDelta, ExportedTransaction, NativeTokenDelta, TransactionExportFormat, TransactionExportRange,
+        },
+        utxo_health::{AmountRange, NativeTokenFragmentation, UtxoHealthReport, UtxoRecommendation},
+    },
+    types::{AccountId, AliasChange, OutputDataDto},
+};
+use self::{
+    operations::{syncing::cache::SyncCache, transaction::PreparedTransactionContext},
+    types::{
+        address::{AccountAddress, AddressWithUnspentOutputs},
+        AccountBalance, OutputData, Transaction,
     },
-    types::OutputDataDto,
 };
 use super::wallet::WalletInner;
 use crate::{
@@ -61,6 +95,7 @@ use crate::{
     types::{
         api::core::response::OutputWithMetadataResponse,
         block::{
+            address::Address,
             output::{AliasId, FoundryId, FoundryOutput, NftId, Output, OutputId, TokenId},
             payload::{
                 transaction::{TransactionEssence, TransactionId},
@@ -69,7 +104,11 @@ use crate::{
             BlockId,
         },
     },
-    wallet::{account::types::InclusionState, Result},
+    wallet::{account::types::InclusionState, Error, Result},
+};
+#[cfg(feature = "wallet-participation")]
+use crate::{
+    types::api::plugins::participation::types::ParticipationEventId, wallet::account::types::ParticipationRewards,
 };
 
 /// Options to filter outputs
@@ -88,6 +127,17 @@ pub struct FilterOptions {
     pub foundry_ids: Option<HashSet<FoundryId>>,
     /// Return all nft outputs matching these IDs.
     pub nft_ids: Option<HashSet<NftId>>,
+    /// Filter all outputs by their spent status.
+    pub is_spent: Option<bool>,
+    /// Filter all outputs where the spent milestone timestamp is below the specified timestamp, unspent outputs are
+    /// filtered too.
+    pub lower_bound_spent_timestamp: Option<u32>,
+    /// Filter all outputs where the spent milestone timestamp is above the specified timestamp, unspent outputs are
+    /// filtered too.
+    pub upper_bound_spent_timestamp: Option<u32>,
+    /// Filter all outputs by whether they were claimed by the account, so they had additional unlock conditions and
+    /// were spent in a transaction of the account.
+    pub claimed: Option<bool>,
 }
 
 /// Details of an account.
@@ -97,10 +147,16 @@ pub struct FilterOptions {
 pub struct AccountDetails {
     /// The account index
     index: u32,
+    /// The stable account id, which doesn't change when the account is renamed
+    #[serde(default)]
+    pub(crate) account_id: AccountId,
     /// The coin type
     coin_type: u32,
     /// The account alias.
     alias: String,
+    /// Previous aliases of the account, oldest first
+    #[serde(default)]
+    alias_history: Vec<AliasChange>,
     /// Public addresses
     pub(crate) public_addresses: Vec<AccountAddress>,
     /// Internal addresses
@@ -122,10 +178,10 @@ pub struct AccountDetails {
     /// Sent transactions
     // stored separated from the account for performance and only the transaction id here? where to add the network id?
     // transactions: HashSet<TransactionId>,
-    transactions: HashMap<TransactionId, types::Transaction>,
+    pub(crate) transactions: HashMap<TransactionId, types::Transaction>,
     /// Pending transactions
     // Maybe pending transactions even additionally separated?
-    pending_transactions: HashSet<TransactionId>,
+    pub(crate) pending_transactions: HashSet<TransactionId>,
     /// Transaction payloads for received outputs with inputs when not pruned before syncing, can be used to determine
     /// the sender address(es)
     incoming_transactions: HashMap<TransactionId, Transaction>,
@@ -137,6 +193,43 @@ pub struct AccountDetails {
     /// Foundries for native tokens in outputs
     #[serde(default)]
     native_token_foundries: HashMap<FoundryId, FoundryOutput>,
+    /// Spending allowances for automated spenders, by id
+    #[serde(default)]
+    pub(crate) spending_allowances: HashMap<String, SpendingAllowance>,
+    /// Whether receive addresses are retired after they received funds, see [`Account::set_address_rotation()`]
+    #[serde(default)]
+    pub(crate) address_rotation: bool,
+    /// Receive addresses retired by the address rotation, oldest first
+    #[serde(default)]
+    pub(crate) retired_addresses: Vec<RetiredAddress>,
+    /// Restricts which native tokens are sent and claimed, see [`Account::set_token_policy()`]
+    #[serde(default)]
+    pub(crate) token_policy: Option<TokenPolicy>,
+    /// Prevents accumulating micro outputs, see [`Account::set_dust_policy()`]
+    #[serde(default)]
+    pub(crate) dust_policy: Option<DustPolicy>,
+    /// Thresholds for alerts about large balance changes, see [`Account::set_alert_thresholds()`]
+    #[serde(default)]
+    pub(crate) alert_thresholds: Option<AlertThresholds>,
+    /// Limits how many transactions are sent, see [`Account::set_rate_limit()`]
+    #[serde(default)]
+    pub(crate) rate_limit: Option<TransactionRateLimit>,
+    /// Whether sent transactions get a sequence number, see [`Account::set_transaction_sequencing()`]
+    #[serde(default)]
+    pub(crate) transaction_sequencing: bool,
+    /// The sequence number of the next sent transaction
+    #[serde(default)]
+    pub(crate) next_sequence_number: u64,
+    /// Whether the account only watches imported addresses, so it can't generate addresses or sign, see
+    /// [`AccountBuilder::with_watch_only_addresses()`](crate::wallet::account::builder::AccountBuilder::with_watch_only_addresses)
+    #[serde(default)]
+    pub(crate) watch_only: bool,
+    /// The alias whose address holds the funds of the account, see [`Account::set_primary_alias()`]
+    #[serde(default)]
+    pub(crate) primary_alias: Option<AliasId>,
+    /// Whether the participations are re-applied when the voting power changes, see `Account::set_auto_revote()`
+    #[serde(default)]
+    pub(crate) auto_revote: bool,
 }
 
 /// A thread guard over an account, so we can lock the account during operations.
@@ -144,6 +237,8 @@ pub struct AccountDetails {
 pub struct Account {
     inner: Arc<AccountInner>,
     pub(crate) wallet: Arc<WalletInner>,
+    // node data shared with other accounts that are synced at the same time, see `Wallet::sync_all()`
+    pub(crate) sync_cache: Option<Arc<SyncCache>>,
 }
 
 #[derive(Debug)]
@@ -153,7 +248,21 @@ pub struct AccountInner {
     // if the last synced time was < `MIN_SYNC_INTERVAL` second ago, we don't sync, but only calculate the balance
     // again, because sending transactions can change that
     pub(crate) last_synced: Mutex<u128>,
+    // base coin total balance after the last sync, which the balance drop alert compares against
+    #[cfg(feature = "events")]
+    pub(crate) last_synced_balance: Mutex<Option<u64>>,
     pub(crate) default_sync_options: Mutex<SyncOptions>,
+    // options of prepared transactions that are used once they're submitted, by the hash of the transaction essence
+    pub(crate) prepared_transactions: Mutex<HashMap<[u8; 32], PreparedTransactionContext>>,
+    // remainder addresses that were handed out to prepared transactions, so concurrent transactions get different ones
+    pub(crate) reserved_remainder_addresses: Mutex<HashSet<Address>>,
+    // network parameters with the time they were fetched, see `Account::network_params()`
+    pub(crate) network_params: Mutex<Option<(instant::Instant, NetworkParams)>>,
+    // held while a transaction is submitted and stored if a rate limit is set, so concurrent transactions are counted
+    pub(crate) rate_limit_lock: Mutex<()>,
+    // staking rewards per event from the last sync, see `Account::sync_participation_rewards()`
+    #[cfg(feature = "wallet-participation")]
+    pub(crate) participation_rewards: Mutex<HashMap<ParticipationEventId, ParticipationRewards>>,
 }
 
 // impl Deref so we can use `account.details()` instead of `account.details.read()`
@@ -184,8 +293,17 @@ impl Account {
             inner: Arc::new(AccountInner {
                 details: RwLock::new(details),
                 last_synced: Default::default(),
+                #[cfg(feature = "events")]
+                last_synced_balance: Default::default(),
                 default_sync_options: Mutex::new(default_sync_options),
+                prepared_transactions: Default::default(),
+                reserved_remainder_addresses: Default::default(),
+                network_params: Default::default(),
+                rate_limit_lock: Default::default(),
+                #[cfg(feature = "wallet-participation")]
+                participation_rewards: Default::default(),
             }),
+            sync_cache: None,
         })
     }
 
@@ -237,8 +355,13 @@ impl Account {
     }
 
     #[cfg(feature = "events")]
-    pub(crate) async fn emit(&self, account_index: u32, wallet_event: super::events::types::WalletEvent) {
-        self.wallet.emit(account_index, wallet_event).await
+    pub(crate) async fn emit(
+        &self,
+        account_index: u32,
+        wallet_event: super::events::types::WalletEvent,
+        origin: super::events::types::EventOrigin,
+    ) {
+        self.wallet.emit(account_index, wallet_event, origin).await
     }
 }
 
@@ -283,92 +406,46 @@ impl AccountInner {
         Ok(all_addresses.to_vec())
     }
 
+    /// Returns the first address of the account with the given label
+    pub async fn find_address_by_label(&self, label: &str) -> Option<AccountAddress> {
+        let account_details = self.details().await;
+        account_details
+            .public_addresses()
+            .iter()
+            .chain(account_details.internal_addresses())
+            .find(|address| address.label.as_deref() == Some(label))
+            .cloned()
+    }
+
     /// Returns all public addresses of the account
     pub(crate) async fn public_addresses(&self) -> Vec<AccountAddress> {
         self.details().await.public_addresses().to_vec()
     }
 
+    /// Returns an error for operations that need the secret manager, if the account is watch-only
+    pub(crate) async fn check_not_watch_only(&self, operation: &'static str) -> Result<()> {
+        let account_details = self.details().await;
+        if account_details.watch_only {
+            return Err(Error::WatchOnlyAccount(account_details.alias.clone(), operation));
+        }
+        Ok(())
+    }
+
     /// Returns only addresses of the account with balance
     pub async fn addresses_with_unspent_outputs(&self) -> Result<Vec<AddressWithUnspentOutputs>> {
         Ok(self.details().await.addresses_with_unspent_outputs().to_vec())
     }
 
-    fn filter_outputs<'a>(
-        &self,
-        outputs: impl Iterator<Item = &'a OutputData>,
-        filter: impl Into<Option<FilterOptions>>,
-    ) -> Result<Vec<OutputData>> {
-        let filter = filter.into();
-
-        if let Some(filter) = filter {
-            let mut filtered_outputs = Vec::new();
-
-            for output in outputs {
-                match &output.output {
-                    Output::Alias(alias) => {
-                        if let Some(alias_ids) = &filter.alias_ids {
-                            let alias_id = alias.alias_id_non_null(&output.output_id);
-                            if alias_ids.contains(&alias_id) {
-                                filtered_outputs.push(output.clone());
-                                continue;
-                            }
-                        }
-                    }
-                    Output::Foundry(foundry) => {
-                        if let Some(foundry_ids) = &filter.foundry_ids {
-                            let foundry_id = foundry.id();
-                            if foundry_ids.contains(&foundry_id) {
-                                filtered_outputs.push(output.clone());
-                                continue;
-                            }
-                        }
-                    }
-                    Output::Nft(nft) => {
-                        if let Some(nft_ids) = &filter.nft_ids {
-                            let nft_id = nft.nft_id_non_null(&output.output_id);
-                            if nft_ids.contains(&nft_id) {
-                                filtered_outputs.push(output.clone());
-                                continue;
-                            }
-                        }
-                    }
-                    _ => {}
-                }
-
-                if let Some(lower_bound_booked_timestamp) = filter.lower_bound_booked_timestamp {
-                    if output.metadata.milestone_timestamp_booked() < lower_bound_booked_timestamp {
-                        continue;
-                    }
-                }
-                if let Some(upper_bound_booked_timestamp) = filter.upper_bound_booked_timestamp {
-                    if output.metadata.milestone_timestamp_booked() > upper_bound_booked_timestamp {
-                        continue;
-                    }
-                }
-
-                if let Some(output_types) = &filter.output_types {
-                    if !output_types.contains(&output.output.kind()) {
-                        continue;
-                    }
-                }
-
-                filtered_outputs.push(output.clone());
-            }
-
-            Ok(filtered_outputs)
-        } else {
-            Ok(outputs.cloned().collect())
-        }
-    }
-
     /// Returns outputs of the account
     pub async fn outputs(&self, filter: impl Into<Option<FilterOptions>> + Send) -> Result<Vec<OutputData>> {
-        self.filter_outputs(self.details().await.outputs.values(), filter)
+        let account_details = self.details().await;
+        filter_outputs(&account_details, account_details.outputs.values(), filter)
     }
 
     /// Returns unspent outputs of the account
     pub async fn unspent_outputs(&self, filter: impl Into<Option<FilterOptions>> + Send) -> Result<Vec<OutputData>> {
-        self.filter_outputs(self.details().await.unspent_outputs.values(), filter)
+        let account_details = self.details().await;
+        filter_outputs(&account_details, account_details.unspent_outputs.values(), filter)
     }
 
     /// Gets the unspent alias output matching the given ID.
@@ -426,6 +503,31 @@ impl AccountInner {
     }
 }
 
+#[cfg(feature = "testkit")]
+impl AccountDetails {
+    /// Adds an unspent output of the given account address, used by the [`Testkit`](crate::wallet::testkit::Testkit)
+    /// to credit outputs without syncing.
+    pub(crate) fn add_unspent_output(&mut self, account_address: &AccountAddress, output_data: OutputData) {
+        let output_id = output_data.output_id;
+        if let Some(address_with_unspent_outputs) = self
+            .addresses_with_unspent_outputs
+            .iter_mut()
+            .find(|a| a.address == account_address.address)
+        {
+            address_with_unspent_outputs.output_ids.push(output_id);
+        } else {
+            self.addresses_with_unspent_outputs.push(AddressWithUnspentOutputs {
+                address: account_address.address,
+                key_index: account_address.key_index,
+                internal: account_address.internal,
+                output_ids: vec![output_id],
+            });
+        }
+        self.outputs.insert(output_id, output_data.clone());
+        self.unspent_outputs.insert(output_id, output_data);
+    }
+}
+
 pub(crate) fn build_transaction_from_payload_and_inputs(
     tx_id: TransactionId,
     tx_payload: TransactionPayload,
@@ -446,10 +548,117 @@ pub(crate) fn build_transaction_from_payload_and_inputs(
         network_id: tx_essence.network_id(),
         incoming: true,
         note: None,
+        confirmation_depth: None,
         inputs,
+        conflict_reason: None,
+        sequence_number: None,
     })
 }
 
+fn filter_outputs<'a>(
+    account_details: &AccountDetails,
+    outputs: impl Iterator<Item = &'a OutputData>,
+    filter: impl Into<Option<FilterOptions>>,
+) -> Result<Vec<OutputData>> {
+    let filter = filter.into();
+
+    if let Some(filter) = filter {
+        let mut filtered_outputs = Vec::new();
+
+        for output in outputs {
+            if let Some(is_spent) = filter.is_spent {
+                if output.is_spent != is_spent {
+                    continue;
+                }
+            }
+            if let Some(lower_bound_spent_timestamp) = filter.lower_bound_spent_timestamp {
+                match output.metadata.milestone_timestamp_spent() {
+                    Some(timestamp) if timestamp >= lower_bound_spent_timestamp => {}
+                    _ => continue,
+                }
+            }
+            if let Some(upper_bound_spent_timestamp) = filter.upper_bound_spent_timestamp {
+                match output.metadata.milestone_timestamp_spent() {
+                    Some(timestamp) if timestamp <= upper_bound_spent_timestamp => {}
+                    _ => continue,
+                }
+            }
+            if let Some(claimed) = filter.claimed {
+                if is_claimed(account_details, output) != claimed {
+                    continue;
+                }
+            }
+
+            match &output.output {
+                Output::Alias(alias) => {
+                    if let Some(alias_ids) = &filter.alias_ids {
+                        let alias_id = alias.alias_id_non_null(&output.output_id);
+                        if alias_ids.contains(&alias_id) {
+                            filtered_outputs.push(output.clone());
+                            continue;
+                        }
+                    }
+                }
+                Output::Foundry(foundry) => {
+                    if let Some(foundry_ids) = &filter.foundry_ids {
+                        let foundry_id = foundry.id();
+                        if foundry_ids.contains(&foundry_id) {
+                            filtered_outputs.push(output.clone());
+                            continue;
+                        }
+                    }
+                }
+                Output::Nft(nft) => {
+                    if let Some(nft_ids) = &filter.nft_ids {
+                        let nft_id = nft.nft_id_non_null(&output.output_id);
+                        if nft_ids.contains(&nft_id) {
+                            filtered_outputs.push(output.clone());
+                            continue;
+                        }
+                    }
+                }
+                _ => {}
+            }
+
+            if let Some(lower_bound_booked_timestamp) = filter.lower_bound_booked_timestamp {
+                if output.metadata.milestone_timestamp_booked() < lower_bound_booked_timestamp {
+                    continue;
+                }
+            }
+            if let Some(upper_bound_booked_timestamp) = filter.upper_bound_booked_timestamp {
+                if output.metadata.milestone_timestamp_booked() > upper_bound_booked_timestamp {
+                    continue;
+                }
+            }
+
+            if let Some(output_types) = &filter.output_types {
+                if !output_types.contains(&output.output.kind()) {
+                    continue;
+                }
+            }
+
+            filtered_outputs.push(output.clone());
+        }
+
+        Ok(filtered_outputs)
+    } else {
+        Ok(outputs.cloned().collect())
+    }
+}
+
+// An output was claimed by the account if it needed to be claimed, because it has additional unlock conditions, and
+// was spent in a transaction of the account
+fn is_claimed(account_details: &AccountDetails, output: &OutputData) -> bool {
+    (output.output.is_basic() || output.output.is_nft())
+        && output
+            .output
+            .unlock_conditions()
+            .map_or(false, |unlock_conditions| unlock_conditions.len() > 1)
+        && output.metadata.transaction_id_spent().map_or(false, |transaction_id| {
+            account_details.transactions.contains_key(transaction_id)
+        })
+}
+
 #[test]
 fn serialize() {
     use crate::types::block::{
@@ -520,7 +729,10 @@ fn serialize() {
         inclusion_state: InclusionState::Pending,
         incoming: false,
         note: None,
+        confirmation_depth: None,
         inputs: Vec::new(),
+        conflict_reason: None,
+        sequence_number: None,
     };
 
     let mut incoming_transactions = HashMap::new();
@@ -531,8 +743,10 @@ fn serialize() {
 
     let account = AccountDetails {
         index: 0,
+        account_id: AccountId::default(),
         coin_type: 4218,
         alias: "0".to_string(),
+        alias_history: Vec::new(),
         public_addresses: Vec::new(),
         internal_addresses: Vec::new(),
         addresses_with_unspent_outputs: Vec::new(),
@@ -544,6 +758,18 @@ fn serialize() {
         incoming_transactions,
         inaccessible_incoming_transactions: HashSet::new(),
         native_token_foundries: HashMap::new(),
+        spending_allowances: HashMap::new(),
+        address_rotation: false,
+        retired_addresses: Vec::new(),
+        token_policy: None,
+        dust_policy: None,
+        alert_thresholds: None,
+        rate_limit: None,
+        transaction_sequencing: false,
+        next_sequence_number: 0,
+        watch_only: false,
+        primary_alias: None,
+        auto_revote: false,
     };
 
     serde_json::from_str::<AccountDetails>(&serde_json::to_string(&account).unwrap()).unwrap();
@@ -558,8 +784,10 @@ impl AccountDetails {
     pub(crate) fn mock() -> Self {
         Self {
             index: 0,
+            account_id: AccountId::default(),
             coin_type: 4218,
             alias: "Alice".to_string(),
+            alias_history: Vec::new(),
             public_addresses: vec![AccountAddress {
                 address: crate::types::block::address::Bech32Address::from_str(
                     "rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy",
@@ -568,6 +796,8 @@ impl AccountDetails {
                 key_index: 0,
                 internal: false,
                 used: false,
+                label: None,
+                metadata: Default::default(),
             }],
             internal_addresses: Vec::new(),
             addresses_with_unspent_outputs: Vec::new(),
@@ -579,6 +809,150 @@ impl AccountDetails {
             incoming_transactions: HashMap::new(),
             inaccessible_incoming_transactions: HashSet::new(),
             native_token_foundries: HashMap::new(),
+            spending_allowances: HashMap::new(),
+            address_rotation: false,
+            retired_addresses: Vec::new(),
+            token_policy: None,
+            dust_policy: None,
+            alert_thresholds: None,
+            rate_limit: None,
+            transaction_sequencing: false,
+            next_sequence_number: 0,
+            watch_only: false,
+            primary_alias: None,
+            auto_revote: false,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::block::{
+        address::{Address, Ed25519Address},
+        output::{
+            unlock_condition::{AddressUnlockCondition, StorageDepositReturnUnlockCondition},
+            BasicOutputBuilder, OutputMetadata,
+        },
+    };
+
+    const TOKEN_SUPPLY: u64 = 1_813_620_509_061_365;
+
+    fn output_data(index: u16, spent: Option<(u32, TransactionId)>, storage_deposit_return: bool) -> OutputData {
+        let output_id = OutputId::new(TransactionId::new([1; TransactionId::LENGTH]), index).unwrap();
+        let address = Address::Ed25519(Ed25519Address::new([1; Ed25519Address::LENGTH]));
+        let mut output_builder =
+            BasicOutputBuilder::new_with_amount(1_000_000).add_unlock_condition(AddressUnlockCondition::new(address));
+        if storage_deposit_return {
+            output_builder = output_builder
+                .add_unlock_condition(StorageDepositReturnUnlockCondition::new(address, 50_000, TOKEN_SUPPLY).unwrap());
+        }
+        OutputData {
+            output_id,
+            metadata: OutputMetadata::new(
+                BlockId::new([0; BlockId::LENGTH]),
+                output_id,
+                spent.is_some(),
+                spent.map(|_| 1),
+                spent.map(|(timestamp, _)| timestamp),
+                spent.map(|(_, transaction_id)| transaction_id),
+                0,
+                0,
+                0,
+            ),
+            output: output_builder.finish_output(TOKEN_SUPPLY).unwrap(),
+            is_spent: spent.is_some(),
+            address,
+            network_id: 0,
+            remainder: false,
+            chain: None,
+        }
+    }
+
+    fn filtered_indexes(account_details: &AccountDetails, filter: FilterOptions) -> Vec<u16> {
+        let mut indexes = filter_outputs(account_details, account_details.outputs.values(), filter)
+            .unwrap()
+            .iter()
+            .map(|output_data| output_data.output_id.index())
+            .collect::<Vec<_>>();
+        indexes.sort();
+        indexes
+    }
+
+    #[test]
+    fn filter_outputs_by_spent_status() {
+        let mut account_details = AccountDetails::mock();
+        let transaction = Transaction::mock(vec![output_data(0, None, false).output]);
+        let other_transaction_id = TransactionId::new([2; TransactionId::LENGTH]);
+        for output_data in [
+            output_data(0, None, false),
+            // Spent by someone else
+            output_data(1, Some((100, other_transaction_id)), true),
+            // Claimed by the account
+            output_data(2, Some((200, transaction.transaction_id)), true),
+            // Spent by the account, but it didn't need to be claimed
+            output_data(3, Some((300, transaction.transaction_id)), false),
+        ] {
+            account_details.outputs.insert(output_data.output_id, output_data);
+        }
+        account_details
+            .transactions
+            .insert(transaction.transaction_id, transaction);
+
+        let filter = |filter: FilterOptions| filtered_indexes(&account_details, filter);
+        assert_eq!(filter(FilterOptions::default()), vec![0, 1, 2, 3]);
+        assert_eq!(
+            filter(FilterOptions {
+                is_spent: Some(false),
+                ..Default::default()
+            }),
+            vec![0]
+        );
+        assert_eq!(
+            filter(FilterOptions {
+                is_spent: Some(true),
+                ..Default::default()
+            }),
+            vec![1, 2, 3]
+        );
+
+        // Unspent outputs have no spent timestamp, so they're filtered by the bounds
+        assert_eq!(
+            filter(FilterOptions {
+                lower_bound_spent_timestamp: Some(200),
+                ..Default::default()
+            }),
+            vec![2, 3]
+        );
+        assert_eq!(
+            filter(FilterOptions {
+                upper_bound_spent_timestamp: Some(200),
+                ..Default::default()
+            }),
+            vec![1, 2]
+        );
+        assert_eq!(
+            filter(FilterOptions {
+                lower_bound_spent_timestamp: Some(150),
+                upper_bound_spent_timestamp: Some(250),
+                ..Default::default()
+            }),
+            vec![2]
+        );
+
+        assert_eq!(
+            filter(FilterOptions {
+                claimed: Some(true),
+                ..Default::default()
+            }),
+            vec![2]
+        );
+        assert_eq!(
+            filter(FilterOptions {
+                claimed: Some(false),
+                ..Default::default()
+            }),
+            vec![0, 1, 3]
+        );
+    }
+}
@@ -0,0 +1,305 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    types::block::{
+        address::{Address, Bech32Address, Hrp},
+        payload::transaction::TransactionId,
+    },
+    wallet::account::{types::InclusionState, Account, AccountDetails},
+};
+
+/// A report about which addresses of an account are linked on-chain, returned from
+/// [`Account::address_clustering()`]. Addresses that are spent together in a transaction can be assumed to belong to
+/// the same owner by anyone observing the ledger.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressClusteringReport {
+    /// Groups of addresses that were spent together, directly or through other addresses of the group, largest
+    /// first.
+    pub clusters: Vec<AddressCluster>,
+    /// Addresses that were never spent together with another address of the account.
+    pub unlinked_addresses: Vec<Bech32Address>,
+    /// Amount of sent transactions that were analyzed.
+    pub transaction_count: usize,
+}
+
+/// Addresses of an account that are linked by common-input spending.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressCluster {
+    /// The linked addresses.
+    pub addresses: Vec<Bech32Address>,
+    /// The transactions that spent inputs of multiple addresses of the cluster.
+    pub transaction_ids: Vec<TransactionId>,
+}
+
+impl Account {
+    /// Reports which addresses of the account were linked on-chain by spending their outputs in the same
+    /// transaction. Only uses the locally stored confirmed transactions, inputs whose output isn't known anymore are
+    /// ignored.
+    pub async fn address_clustering(&self) -> crate::wallet::Result<AddressClusteringReport> {
+        log::debug!("[ADDRESS_CLUSTERING] address_clustering");
+        let bech32_hrp = self.client().get_bech32_hrp().await?;
+        Ok(address_clustering_report(&*self.details().await, bech32_hrp))
+    }
+}
+
+fn address_clustering_report(account_details: &AccountDetails, bech32_hrp: Hrp) -> AddressClusteringReport {
+    let account_addresses = account_details
+        .public_addresses()
+        .iter()
+        .chain(account_details.internal_addresses())
+        .map(|address| (*address.address.inner(), address.address))
+        .collect::<HashMap<Address, Bech32Address>>();
+
+    let mut clusters: Vec<(HashSet<Address>, Vec<TransactionId>)> = Vec::new();
+    let mut transaction_count = 0;
+
+    // Pending or conflicting transactions didn't link anything on-chain
+    for transaction in account_details
+        .transactions()
+        .values()
+        .filter(|tx| !tx.incoming && tx.inclusion_state == InclusionState::Confirmed)
+    {
+        transaction_count += 1;
+
+        let input_addresses = transaction
+            .normalized(bech32_hrp)
+            .inputs
+            .into_iter()
+            .filter_map(|input| {
+                input.address.map(|address| *address.inner()).or_else(|| {
+                    account_details
+                        .outputs()
+                        .get(&input.output_id)
+                        .map(|output_data| output_data.address)
+                })
+            })
+            .filter(|address| account_addresses.contains_key(address))
+            .collect::<HashSet<_>>();
+        if input_addresses.len() < 2 {
+            continue;
+        }
+
+        // Merge all clusters that share an address with the inputs
+        let mut cluster = (input_addresses, vec![transaction.transaction_id]);
+        let mut index = 0;
+        while index < clusters.len() {
+            if clusters[index].0.is_disjoint(&cluster.0) {
+                index += 1;
+            } else {
+                let (addresses, transaction_ids) = clusters.swap_remove(index);
+                cluster.0.extend(addresses);
+                cluster.1.extend(transaction_ids);
+            }
+        }
+        clusters.push(cluster);
+    }
+
+    let linked_addresses = clusters
+        .iter()
+        .flat_map(|(addresses, _)| addresses)
+        .collect::<HashSet<_>>();
+    let mut unlinked_addresses = account_addresses
+        .iter()
+        .filter(|(address, _)| !linked_addresses.contains(address))
+        .map(|(_, bech32_address)| *bech32_address)
+        .collect::<Vec<_>>();
+    unlinked_addresses.sort_by_key(ToString::to_string);
+
+    let mut clusters = clusters
+        .into_iter()
+        .map(|(addresses, mut transaction_ids)| {
+            let mut addresses = addresses
+                .iter()
+                .map(|address| account_addresses[address])
+                .collect::<Vec<_>>();
+            addresses.sort_by_key(ToString::to_string);
+            transaction_ids.sort();
+            AddressCluster {
+                addresses,
+                transaction_ids,
+            }
+        })
+        .collect::<Vec<_>>();
+    clusters.sort_by(|a, b| b.addresses.len().cmp(&a.addresses.len()));
+
+    AddressClusteringReport {
+        clusters,
+        unlinked_addresses,
+        transaction_count,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        types::block::{
+            address::Ed25519Address,
+            input::{Input, UtxoInput},
+            output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder, OutputId, OutputMetadata},
+            payload::transaction::{RegularTransactionEssence, TransactionEssence, TransactionPayload},
+            protocol::ProtocolParameters,
+            signature::{Ed25519Signature, Signature},
+            unlock::{ReferenceUnlock, SignatureUnlock, Unlock, Unlocks},
+            BlockId,
+        },
+        wallet::account::types::{AccountAddress, OutputData, Transaction},
+    };
+
+    const TOKEN_SUPPLY: u64 = 1_813_620_509_061_365;
+
+    fn output_data(output_id: OutputId, address: Address) -> OutputData {
+        OutputData {
+            output_id,
+            metadata: OutputMetadata::new(
+                BlockId::new([0; BlockId::LENGTH]),
+                output_id,
+                true,
+                None,
+                None,
+                None,
+                0,
+                0,
+                0,
+            ),
+            output: BasicOutputBuilder::new_with_amount(1_000_000)
+                .add_unlock_condition(AddressUnlockCondition::new(address))
+                .finish_output(TOKEN_SUPPLY)
+                .unwrap(),
+            is_spent: true,
+            address,
+            network_id: 0,
+            remainder: false,
+            chain: None,
+        }
+    }
+
+    // A transaction that spends the given outputs
+    fn transaction(inputs: &[OutputId], inclusion_state: InclusionState, incoming: bool) -> Transaction {
+        let protocol_parameters = ProtocolParameters::default();
+        let output = BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(Address::Ed25519(Ed25519Address::new(
+                [9; Ed25519Address::LENGTH],
+            ))))
+            .finish_output(TOKEN_SUPPLY)
+            .unwrap();
+        let essence = RegularTransactionEssence::builder(protocol_parameters.network_id(), [0; 32].into())
+            .with_inputs(
+                inputs
+                    .iter()
+                    .map(|output_id| Input::Utxo(UtxoInput::from(*output_id)))
+                    .collect::<Vec<_>>(),
+            )
+            .with_outputs(vec![output.clone()])
+            .finish(&protocol_parameters)
+            .unwrap();
+        let unlocks = std::iter::once(Unlock::Signature(SignatureUnlock::from(Signature::Ed25519(
+            Ed25519Signature::new(
+                [0; Ed25519Signature::PUBLIC_KEY_LENGTH],
+                [0; Ed25519Signature::SIGNATURE_LENGTH],
+            ),
+        ))))
+        .chain((1..inputs.len()).map(|_| Unlock::Reference(ReferenceUnlock::new(0).unwrap())))
+        .collect::<Vec<_>>();
+        let payload =
+            TransactionPayload::new(TransactionEssence::Regular(essence), Unlocks::new(unlocks).unwrap()).unwrap();
+
+        let mut transaction = Transaction::mock(vec![output]);
+        transaction.transaction_id = payload.id();
+        transaction.payload = payload;
+        transaction.inclusion_state = inclusion_state;
+        transaction.incoming = incoming;
+        transaction
+    }
+
+    #[test]
+    fn clusters_confirmed_sent_transactions() {
+        let mut account_details = AccountDetails::mock();
+        let bech32_hrp = account_details.public_addresses[0].address.hrp;
+        let mut addresses = vec![*account_details.public_addresses[0].address.inner()];
+        for key_index in 1..4u8 {
+            let address = Address::Ed25519(Ed25519Address::new([key_index; Ed25519Address::LENGTH]));
+            addresses.push(address);
+            account_details.public_addresses.push(AccountAddress {
+                address: Bech32Address::new(bech32_hrp, address),
+                key_index: key_index as u32,
+                internal: false,
+                used: true,
+                label: None,
+                metadata: Default::default(),
+            });
+        }
+
+        // Two outputs on the second address, one on each other address
+        let output_ids = (0..5)
+            .map(|index| OutputId::new(TransactionId::new([1; TransactionId::LENGTH]), index).unwrap())
+            .collect::<Vec<_>>();
+        for (output_id, address) in output_ids.iter().zip([0, 1, 2, 3, 1]) {
+            account_details
+                .outputs
+                .insert(*output_id, output_data(*output_id, addresses[address]));
+        }
+
+        let linked = transaction(&[output_ids[0], output_ids[1]], InclusionState::Confirmed, false);
+        // Links the third address to the cluster through the second address
+        let extended = transaction(&[output_ids[4], output_ids[2]], InclusionState::Confirmed, false);
+        // Not on-chain or not sent by the account, so nothing gets linked
+        let pending = transaction(&[output_ids[3], output_ids[0]], InclusionState::Pending, false);
+        let conflicting = transaction(&[output_ids[3], output_ids[1]], InclusionState::Conflicting, false);
+        let incoming = transaction(&[output_ids[3], output_ids[2]], InclusionState::Confirmed, true);
+        for transaction in [&linked, &extended, &pending, &conflicting, &incoming] {
+            account_details
+                .transactions
+                .insert(transaction.transaction_id, transaction.clone());
+        }
+
+        let report = address_clustering_report(&account_details, bech32_hrp);
+        assert_eq!(report.transaction_count, 2);
+
+        let mut cluster_addresses = addresses[..3]
+            .iter()
+            .map(|address| Bech32Address::new(bech32_hrp, *address))
+            .collect::<Vec<_>>();
+        cluster_addresses.sort_by_key(ToString::to_string);
+        let mut transaction_ids = vec![linked.transaction_id, extended.transaction_id];
+        transaction_ids.sort();
+        assert_eq!(
+            report.clusters,
+            vec![AddressCluster {
+                addresses: cluster_addresses,
+                transaction_ids,
+            }]
+        );
+        assert_eq!(
+            report.unlinked_addresses,
+            vec![Bech32Address::new(bech32_hrp, addresses[3])]
+        );
+    }
+
+    #[test]
+    fn single_input_transactions_dont_link() {
+        let mut account_details = AccountDetails::mock();
+        let bech32_hrp = account_details.public_addresses[0].address.hrp;
+        let address = account_details.public_addresses[0].address;
+        let output_id = OutputId::new(TransactionId::new([1; TransactionId::LENGTH]), 0).unwrap();
+        account_details
+            .outputs
+            .insert(output_id, output_data(output_id, *address.inner()));
+        let transaction = transaction(&[output_id], InclusionState::Confirmed, false);
+        account_details
+            .transactions
+            .insert(transaction.transaction_id, transaction);
+
+        let report = address_clustering_report(&account_details, bech32_hrp);
+        assert_eq!(report.transaction_count, 1);
+        assert!(report.clusters.is_empty());
+        assert_eq!(report.unlinked_addresses, vec![address]);
+    }
+}
@@ -0,0 +1,250 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{client::secret::GenerateAddressOptions, wallet::account::Account};
+
+/// Options for the gap limit based address discovery of
+/// [`AccountBuilder::with_address_discovery()`](crate::wallet::account::AccountBuilder::with_address_discovery).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GapLimitOptions {
+    /// Amount of unused addresses in a row after which the discovery stops.
+    pub gap_limit: u32,
+    /// The highest key index that gets checked, even if the gap limit isn't reached.
+    pub max_index: u32,
+    /// Whether internal addresses are discovered too.
+    pub internal: bool,
+}
+
+// The BIP-44 gap limit for public and internal addresses
+impl Default for GapLimitOptions {
+    fn default() -> Self {
+        Self {
+            gap_limit: 20,
+            max_index: 1000,
+            internal: true,
+        }
+    }
+}
+
+impl Account {
+    /// Checks the addresses of the account for outputs, starting at key index 0, until `gap_limit` unused addresses in
+    /// a row or `max_index` are reached. Addresses up to the last used one are kept, so an account that was used with
+    /// other software gets all its addresses back. An address counts as used if the account knows a spent or unspent
+    /// output of it, or if the node has outputs on it.
+    pub async fn discover_addresses(&self, options: GapLimitOptions) -> crate::wallet::Result<()> {
+        log::debug!("[discover_addresses] {options:?}");
+        self.discover_address_chain(options, false).await?;
+        if options.internal {
+            self.discover_address_chain(options, true).await?;
+        }
+        Ok(())
+    }
+
+    async fn discover_address_chain(&self, options: GapLimitOptions, internal: bool) -> crate::wallet::Result<()> {
+        let sync_options = self.default_sync_options().await;
+        let generate_options = GenerateAddressOptions {
+            internal,
+            ..Default::default()
+        };
+        let (mut addresses, used_addresses) = {
+            let account_details = self.details().await;
+            let addresses = if internal {
+                account_details.internal_addresses.clone()
+            } else {
+                account_details.public_addresses.clone()
+            };
+            // The indexer only returns unspent outputs, so the transaction history of the account is checked too
+            let used_addresses = account_details
+                .outputs()
+                .values()
+                .map(|output_data| output_data.address)
+                .chain(
+                    addresses
+                        .iter()
+                        .filter(|address| address.used)
+                        .map(|address| *address.address.inner()),
+                )
+                .collect::<HashSet<_>>();
+            (addresses, used_addresses)
+        };
+
+        let mut gap_scan = GapScan::new(options);
+        while let Some(index) = gap_scan.next_index() {
+            // Derive the addresses that are needed to reach the gap limit at once, they're only stored if used
+            let address_count = addresses.len() as u32;
+            let required_address_count = gap_scan.required_address_count();
+            if required_address_count > address_count {
+                addresses.extend(
+                    self.derive_addresses(address_count..required_address_count, generate_options)
+                        .await?,
+                );
+            }
+
+            let address = *addresses[index as usize].address.inner();
+            let used = used_addresses.contains(&address)
+                || !self
+                    .get_output_ids_for_address(address, &sync_options)
+                    .await?
+                    .is_empty();
+            gap_scan.record(used);
+        }
+        log::debug!(
+            "[discover_addresses] highest used index {:?}, internal: {internal}",
+            gap_scan.highest_used_index
+        );
+
+        let Some(highest_used_index) = gap_scan.highest_used_index else {
+            return Ok(());
+        };
+        // Only the missing addresses up to the last used one are added, so addresses that were generated in the
+        // meantime are neither duplicated nor removed
+        let mut account_details = self.details_mut().await;
+        let address_chain = if internal {
+            &mut account_details.internal_addresses
+        } else {
+            &mut account_details.public_addresses
+        };
+        let address_chain_len = address_chain.len() as u32;
+        address_chain.extend(
+            addresses
+                .into_iter()
+                .filter(|address| address.key_index >= address_chain_len && address.key_index <= highest_used_index),
+        );
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+
+        Ok(())
+    }
+}
+
+/// Walks the key indexes of an address chain until the gap limit or the max index is reached.
+#[derive(Debug)]
+struct GapScan {
+    options: GapLimitOptions,
+    // `None` after the last possible key index was checked
+    index: Option<u32>,
+    unused_addresses_in_row: u32,
+    highest_used_index: Option<u32>,
+}
+
+impl GapScan {
+    fn new(options: GapLimitOptions) -> Self {
+        Self {
+            options,
+            index: Some(0),
+            unused_addresses_in_row: 0,
+            highest_used_index: None,
+        }
+    }
+
+    /// The key index that needs to be checked next, `None` if the scan is done.
+    fn next_index(&self) -> Option<u32> {
+        self.index
+            .filter(|index| self.unused_addresses_in_row < self.options.gap_limit && *index <= self.options.max_index)
+    }
+
+    /// The amount of addresses that is needed to reach the gap limit if all remaining addresses are unused.
+    fn required_address_count(&self) -> u32 {
+        let index = self.index.unwrap_or(u32::MAX);
+        index
+            .saturating_add(self.options.gap_limit - self.unused_addresses_in_row)
+            .min(self.options.max_index.saturating_add(1))
+    }
+
+    fn record(&mut self, used: bool) {
+        if let Some(index) = self.index {
+            if used {
+                self.unused_addresses_in_row = 0;
+                self.highest_used_index.replace(index);
+            } else {
+                self.unused_addresses_in_row += 1;
+            }
+            self.index = index.checked_add(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scan(options: GapLimitOptions, used_indexes: &[u32]) -> (Vec<u32>, GapScan) {
+        let mut gap_scan = GapScan::new(options);
+        let mut checked_indexes = Vec::new();
+        while let Some(index) = gap_scan.next_index() {
+            checked_indexes.push(index);
+            gap_scan.record(used_indexes.contains(&index));
+        }
+        (checked_indexes, gap_scan)
+    }
+
+    #[test]
+    fn gap_scan_stops_at_gap_limit() {
+        let options = GapLimitOptions {
+            gap_limit: 3,
+            max_index: 100,
+            internal: false,
+        };
+
+        let (checked_indexes, gap_scan) = scan(options, &[]);
+        assert_eq!(checked_indexes, vec![0, 1, 2]);
+        assert_eq!(gap_scan.highest_used_index, None);
+
+        // A used address resets the gap
+        let (checked_indexes, gap_scan) = scan(options, &[1, 4]);
+        assert_eq!(checked_indexes, (0..=7).collect::<Vec<_>>());
+        assert_eq!(gap_scan.highest_used_index, Some(4));
+    }
+
+    #[test]
+    fn gap_scan_required_address_count() {
+        let options = GapLimitOptions {
+            gap_limit: 3,
+            max_index: 4,
+            internal: false,
+        };
+        let mut gap_scan = GapScan::new(options);
+        assert_eq!(gap_scan.required_address_count(), 3);
+
+        gap_scan.record(false);
+        assert_eq!(gap_scan.required_address_count(), 3);
+        gap_scan.record(true);
+        // Limited by the max index
+        assert_eq!(gap_scan.required_address_count(), 5);
+    }
+
+    #[test]
+    fn gap_scan_stops_at_max_index() {
+        let options = GapLimitOptions {
+            gap_limit: 20,
+            max_index: 2,
+            internal: false,
+        };
+        let (checked_indexes, gap_scan) = scan(options, &[0, 1, 2]);
+        assert_eq!(checked_indexes, vec![0, 1, 2]);
+        assert_eq!(gap_scan.highest_used_index, Some(2));
+    }
+
+    #[test]
+    fn gap_scan_doesnt_overflow() {
+        let options = GapLimitOptions {
+            gap_limit: u32::MAX,
+            max_index: u32::MAX,
+            internal: false,
+        };
+        let mut gap_scan = GapScan::new(options);
+        gap_scan.index = Some(u32::MAX - 1);
+        assert_eq!(gap_scan.required_address_count(), u32::MAX);
+
+        gap_scan.record(true);
+        assert_eq!(gap_scan.next_index(), Some(u32::MAX));
+        gap_scan.record(true);
+        assert_eq!(gap_scan.next_index(), None);
+        assert_eq!(gap_scan.highest_used_index, Some(u32::MAX));
+    }
+}
@@ -1,16 +1,19 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::ops::Range;
+
 #[cfg(all(feature = "events", any(feature = "ledger_nano", feature = "ledger_nano")))]
-use crate::wallet::events::types::{AddressData, WalletEvent};
+use crate::wallet::events::types::{AddressData, EventOrigin, WalletEvent};
 use crate::{
     client::secret::{GenerateAddressOptions, SecretManage, SecretManager},
-    types::block::address::Bech32Address,
+    types::block::address::{Address, Bech32Address},
     wallet::account::{types::address::AccountAddress, Account},
 };
 
 impl Account {
-    /// Generate addresses and stores them in the account
+    /// Generate addresses and stores them in the account. Large amounts are derived in parallel with mnemonic and
+    /// Stronghold secret managers and stored with a single storage update.
     /// ```ignore
     /// let public_addresses = account.generate_addresses(2, None).await?;
     /// // internal addresses are used for remainder outputs, if the RemainderValueStrategy for transactions is set to ChangeAddress
@@ -38,24 +41,48 @@ impl Account {
             return Ok(vec![]);
         }
 
-        let account_details = self.details().await;
-
         // get the highest index for the public or internal addresses
-        let highest_current_index_plus_one = if options.internal {
-            account_details.internal_addresses.len() as u32
-        } else {
-            account_details.public_addresses.len() as u32
+        let highest_current_index_plus_one = {
+            let account_details = self.details().await;
+            if options.internal {
+                account_details.internal_addresses.len() as u32
+            } else {
+                account_details.public_addresses.len() as u32
+            }
         };
 
+        let generate_addresses = self
+            .derive_addresses(
+                highest_current_index_plus_one..highest_current_index_plus_one + amount,
+                options,
+            )
+            .await?;
+
+        self.update_account_addresses(options.internal, generate_addresses.clone())
+            .await?;
+
+        Ok(generate_addresses)
+    }
+
+    /// Derives the addresses of the key index range without storing them in the account.
+    pub(crate) async fn derive_addresses(
+        &self,
+        address_range: Range<u32>,
+        options: GenerateAddressOptions,
+    ) -> crate::wallet::Result<Vec<AccountAddress>> {
+        self.check_not_watch_only("generate addresses").await?;
+
+        let account_details = self.details().await;
+
         // get bech32_hrp
         let bech32_hrp = {
             match account_details.public_addresses.first() {
                 Some(address) => address.address.hrp,
-                None => self.client().get_bech32_hrp().await?,
+                None => self.network_params().await?.bech32_hrp,
             }
         };
 
-        let address_range = highest_current_index_plus_one..highest_current_index_plus_one + amount;
+        let first_key_index = address_range.start;
 
         let addresses = match &*self.wallet.secret_manager.read().await {
             #[cfg(feature = "ledger_nano")]
@@ -90,6 +117,7 @@ impl Account {
                                 WalletEvent::LedgerAddressGeneration(AddressData {
                                     address: address[0].to_bech32(bech32_hrp),
                                 }),
+                                EventOrigin::new("generate_addresses"),
                             )
                             .await;
                         }
@@ -142,32 +170,63 @@ impl Account {
 
         drop(account_details);
 
-        let generate_addresses: Vec<AccountAddress> = addresses
+        Ok(addresses
             .into_iter()
             .enumerate()
             .map(|(index, address)| AccountAddress {
                 address: Bech32Address::new(bech32_hrp, address),
-                key_index: highest_current_index_plus_one + index as u32,
+                key_index: first_key_index + index as u32,
                 internal: options.internal,
                 used: false,
+                label: None,
+                metadata: Default::default(),
             })
-            .collect();
+            .collect())
+    }
 
-        self.update_account_addresses(options.internal, generate_addresses.clone())
-            .await?;
+    /// Returns an unused internal address from the pool or generates a new one and stores it in the account, internal
+    /// addresses are used for remainder outputs. The address is reserved until it's released or receives an output, so
+    /// transactions that are prepared at the same time get different addresses.
+    pub(crate) async fn generate_remainder_address(&self) -> crate::wallet::Result<AccountAddress> {
+        // Held until the address is reserved, so concurrent calls can't get the same address
+        let mut reserved_addresses = self.reserved_remainder_addresses.lock().await;
+        let unused_address = {
+            let account_details = self.details().await;
+            // Addresses that received an output don't need to be reserved anymore
+            reserved_addresses.retain(|reserved_address| {
+                account_details
+                    .internal_addresses
+                    .iter()
+                    .any(|address| address.address.inner() == reserved_address && !address.used)
+            });
+            account_details
+                .unused_internal_addresses(&reserved_addresses)
+                .first()
+                .map(|address| (*address).clone())
+        };
 
-        Ok(generate_addresses)
+        let address = match unused_address {
+            Some(address) => {
+                log::debug!(
+                    "[ADDRESS GENERATION] using unused internal address {}",
+                    address.key_index
+                );
+                address
+            }
+            None => self
+                .generate_addresses(1, Some(GenerateAddressOptions::internal()))
+                .await?
+                .first()
+                .ok_or(crate::wallet::Error::FailedToGetRemainder)?
+                .clone(),
+        };
+        reserved_addresses.insert(*address.address.inner());
+
+        Ok(address)
     }
 
-    /// Generate an internal address and store in the account, internal addresses are used for remainder outputs
-    pub(crate) async fn generate_remainder_address(&self) -> crate::wallet::Result<AccountAddress> {
-        let result = self
-            .generate_addresses(1, Some(GenerateAddressOptions::internal()))
-            .await?
-            .first()
-            .ok_or(crate::wallet::Error::FailedToGetRemainder)?
-            .clone();
-
-        Ok(result)
+    /// Releases a remainder address from [`Account::generate_remainder_address()`] that isn't used by a transaction.
+    pub(crate) async fn release_remainder_address(&self, address: &Address) {
+        self.reserved_remainder_addresses.lock().await.remove(address);
     }
 }
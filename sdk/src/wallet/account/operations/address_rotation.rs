@@ -0,0 +1,118 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    types::block::address::Bech32Address,
+    wallet::{
+        account::{types::address::AccountAddress, Account, AccountDetails},
+        Result,
+    },
+};
+
+/// A receive address that got retired by the address rotation, because it received funds.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RetiredAddress {
+    /// The retired address.
+    pub address: Bech32Address,
+    /// The address key index.
+    pub key_index: u32,
+    /// Unix timestamp in seconds of the sync in which the address was found to have received funds.
+    pub retired_at: u32,
+}
+
+impl Account {
+    /// Enables or disables the address rotation. With the address rotation enabled, a receive address is retired after
+    /// it received funds and [`Account::current_receive_address()`] only returns the newest unused address, so
+    /// addresses aren't reused.
+    pub async fn set_address_rotation(&self, enabled: bool) -> Result<()> {
+        let mut account_details = self.details_mut().await;
+        account_details.address_rotation = enabled;
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+        Ok(())
+    }
+
+    /// Returns the address that should be given out to receive funds. Without the address rotation, this is always the
+    /// first public address. With the address rotation, it's the newest public address if it's unused, otherwise a new
+    /// address gets generated.
+    pub async fn current_receive_address(&self) -> Result<AccountAddress> {
+        let newest_address = {
+            let account_details = self.details().await;
+            let public_addresses = account_details.public_addresses();
+            if *account_details.address_rotation() {
+                public_addresses.last().filter(|address| !address.used).cloned()
+            } else {
+                public_addresses.first().cloned()
+            }
+        };
+
+        match newest_address {
+            Some(address) => Ok(address),
+            None => {
+                log::debug!("[current_receive_address] generating a new receive address");
+                Ok(self.generate_addresses(1, None).await?.remove(0))
+            }
+        }
+    }
+
+    /// Returns the receive addresses that got retired by the address rotation, oldest first.
+    pub async fn address_rotation_history(&self) -> Vec<RetiredAddress> {
+        self.details().await.retired_addresses().clone()
+    }
+}
+
+/// Marks the public address at `position` as used. With the address rotation enabled, the address gets retired the
+/// first time it received funds.
+pub(crate) fn mark_public_address_used(account_details: &mut AccountDetails, position: usize, retired_at: u32) {
+    let account_address = &mut account_details.public_addresses[position];
+    if account_details.address_rotation && !account_address.used {
+        account_details.retired_addresses.push(RetiredAddress {
+            address: account_address.address,
+            key_index: account_address.key_index,
+            retired_at,
+        });
+    }
+    account_address.used = true;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retire_used_addresses() {
+        // Without the address rotation, addresses are only marked as used
+        let mut account_details = AccountDetails::mock();
+        mark_public_address_used(&mut account_details, 0, 1_700_000_000);
+        assert!(account_details.public_addresses[0].used);
+        assert!(account_details.retired_addresses.is_empty());
+
+        let mut account_details = AccountDetails::mock();
+        account_details.address_rotation = true;
+        let mut second_address = account_details.public_addresses[0].clone();
+        second_address.key_index = 1;
+        account_details.public_addresses.push(second_address);
+
+        mark_public_address_used(&mut account_details, 0, 1_700_000_000);
+        let retired_address = RetiredAddress {
+            address: account_details.public_addresses[0].address,
+            key_index: 0,
+            retired_at: 1_700_000_000,
+        };
+        assert_eq!(account_details.retired_addresses, vec![retired_address.clone()]);
+
+        // An address is only retired the first time it received funds
+        mark_public_address_used(&mut account_details, 0, 1_700_000_100);
+        assert_eq!(account_details.retired_addresses, vec![retired_address.clone()]);
+
+        mark_public_address_used(&mut account_details, 1, 1_700_000_200);
+        assert_eq!(account_details.retired_addresses.len(), 2);
+        assert_eq!(account_details.retired_addresses[0], retired_address);
+        assert_eq!(account_details.retired_addresses[1].key_index, 1);
+        assert_eq!(account_details.retired_addresses[1].retired_at, 1_700_000_200);
+        assert!(account_details.public_addresses.iter().all(|address| address.used));
+    }
+}
@@ -0,0 +1,105 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crypto::keys::slip10::Chain;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{
+        constants::HD_WALLET_TYPE,
+        secret::{GenerateAddressOptions, SecretManage, SecretManager},
+    },
+    types::block::address::Bech32Address,
+    wallet::account::Account,
+};
+
+/// The derivation information of an address and the result of verifying it with the secret manager, returned from
+/// [`Account::verify_address_on_device()`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressDerivationProof {
+    /// The verified address.
+    pub address: Bech32Address,
+    /// The full BIP32 path of the address, like `m/44'/4218'/0'/0'/0'`.
+    pub bip32_path: String,
+    /// The address key index.
+    pub key_index: u32,
+    /// Determines if the address is a public or an internal (change) address.
+    pub internal: bool,
+    /// The hex encoded Ed25519 public key of the address. Not available for Ledger Nano devices, as they don't expose
+    /// public keys.
+    pub public_key: Option<String>,
+    /// Whether the address was shown on a hardware device, so the user could compare it with the one in the UI.
+    pub shown_on_device: bool,
+    /// Whether the address derived again by the secret manager matches the stored address.
+    pub verified: bool,
+}
+
+impl Account {
+    /// Derives an address of the account again with the secret manager and compares it with the stored one. With a
+    /// Ledger Nano the address gets shown on the device, so the user can compare it with the address displayed in the
+    /// UI before sharing it as receive address.
+    pub async fn verify_address_on_device(
+        &self,
+        address: Bech32Address,
+    ) -> crate::wallet::Result<AddressDerivationProof> {
+        log::debug!("[verify_address_on_device] {address}");
+        self.check_not_watch_only("verify addresses").await?;
+        let account_details = self.details().await;
+        let account_address = account_details
+            .public_addresses()
+            .iter()
+            .chain(account_details.internal_addresses())
+            .find(|a| a.address == address)
+            .cloned()
+            .ok_or(crate::wallet::Error::AddressNotFoundInAccount(address))?;
+        let coin_type = account_details.coin_type;
+        let account_index = account_details.index;
+        drop(account_details);
+
+        let key_index = account_address.key_index;
+        let internal = account_address.internal;
+        let bip32_path = format!(
+            "m/{HD_WALLET_TYPE}'/{coin_type}'/{account_index}'/{}'/{key_index}'",
+            internal as u32
+        );
+        let options = GenerateAddressOptions {
+            internal,
+            ledger_nano_prompt: true,
+        };
+
+        let secret_manager = self.wallet.secret_manager.read().await;
+        let derived_address = secret_manager
+            .generate_addresses(coin_type, account_index, key_index..key_index + 1, Some(options))
+            .await?[0];
+        let verified = derived_address == *account_address.address.inner();
+
+        let chain = Chain::from_u32_hardened(vec![
+            HD_WALLET_TYPE,
+            coin_type,
+            account_index,
+            internal as u32,
+            key_index,
+        ]);
+        let (public_key, shown_on_device) = match &*secret_manager {
+            #[cfg(feature = "stronghold")]
+            SecretManager::Stronghold(stronghold) => (Some(stronghold.derive_ed25519_public_key(&chain).await?), false),
+            #[cfg(feature = "ledger_nano")]
+            SecretManager::LedgerNano(_) => (None, true),
+            SecretManager::Mnemonic(mnemonic) => (Some(mnemonic.derive_ed25519_public_key(&chain)?), false),
+            // Placeholders can't generate addresses, so this isn't reached
+            SecretManager::Placeholder(_) => (None, false),
+        };
+        let public_key = public_key.map(prefix_hex::encode);
+
+        Ok(AddressDerivationProof {
+            address: account_address.address,
+            bip32_path,
+            key_index,
+            internal,
+            public_key,
+            shown_on_device,
+            verified,
+        })
+    }
+}
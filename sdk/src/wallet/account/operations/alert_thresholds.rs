@@ -0,0 +1,213 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+use crate::wallet::{account::Account, Error, Result};
+#[cfg(feature = "events")]
+use crate::wallet::{
+    account::{types::OutputData, AccountDetails},
+    events::types::{BalanceDropAlert, ReceivedAmountAlert},
+};
+
+/// Thresholds for large balance changes of an account. A `WalletEvent::ThresholdAlert` is emitted while syncing when
+/// one is reached, so large balance changes can be monitored without processing every output event.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AlertThresholds {
+    /// Alert when a received output holds at least this amount of the base coin. Remainders and other outputs of
+    /// transactions sent by the account are ignored.
+    #[serde(default, with = "crate::utils::serde::option_string")]
+    pub received_amount: Option<u64>,
+    /// Alert when the total base coin balance drops by at least this percentage, from 1 to 100, compared to the
+    /// previous sync.
+    #[serde(default)]
+    pub balance_drop_percentage: Option<u8>,
+}
+
+impl AlertThresholds {
+    /// Returns an alert if a new output is a received output that reaches the received amount threshold.
+    #[cfg(feature = "events")]
+    pub(crate) fn received_amount_alert(
+        &self,
+        account_details: &AccountDetails,
+        output_data: &OutputData,
+    ) -> Option<ReceivedAmountAlert> {
+        let threshold = self.received_amount?;
+        let amount = output_data.output.amount();
+
+        (amount >= threshold
+            && !output_data.remainder
+            && !account_details
+                .transactions
+                .contains_key(output_data.output_id.transaction_id()))
+        .then_some(ReceivedAmountAlert {
+            output_id: output_data.output_id,
+            amount,
+            threshold,
+        })
+    }
+
+    /// Returns an alert if the balance dropped by at least the balance drop percentage.
+    #[cfg(feature = "events")]
+    pub(crate) fn balance_drop_alert(&self, previous_balance: u64, balance: u64) -> Option<BalanceDropAlert> {
+        let threshold = self.balance_drop_percentage?;
+        if previous_balance == 0 || balance >= previous_balance {
+            return None;
+        }
+
+        let percentage = ((previous_balance - balance) as u128 * 100 / previous_balance as u128) as u8;
+        (percentage >= threshold).then_some(BalanceDropAlert {
+            previous_balance,
+            balance,
+            percentage,
+            threshold,
+        })
+    }
+}
+
+impl Account {
+    /// Sets the thresholds for large balance changes of the account, `None` removes them.
+    pub async fn set_alert_thresholds(&self, alert_thresholds: Option<AlertThresholds>) -> Result<()> {
+        if let Some(percentage) = alert_thresholds.and_then(|thresholds| thresholds.balance_drop_percentage) {
+            if !(1..=100).contains(&percentage) {
+                return Err(Error::InvalidBalanceDropPercentage(percentage));
+            }
+        }
+
+        let mut account_details = self.details_mut().await;
+        account_details.alert_thresholds = alert_thresholds;
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+        Ok(())
+    }
+
+    /// Returns the thresholds for large balance changes of the account.
+    pub async fn alert_thresholds(&self) -> Option<AlertThresholds> {
+        self.details().await.alert_thresholds
+    }
+}
+
+#[cfg(all(test, feature = "events"))]
+mod tests {
+    use super::*;
+    use crate::{
+        types::block::{
+            address::{Address, Ed25519Address},
+            output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder, Output, OutputId, OutputMetadata},
+            payload::transaction::TransactionId,
+            BlockId,
+        },
+        wallet::account::types::Transaction,
+    };
+
+    const TOKEN_SUPPLY: u64 = 1_813_620_509_061_365;
+
+    fn basic_output(amount: u64) -> Output {
+        BasicOutputBuilder::new_with_amount(amount)
+            .add_unlock_condition(AddressUnlockCondition::new(Address::Ed25519(Ed25519Address::new(
+                [1; Ed25519Address::LENGTH],
+            ))))
+            .finish_output(TOKEN_SUPPLY)
+            .unwrap()
+    }
+
+    fn output_data(output_id: OutputId, amount: u64, remainder: bool) -> OutputData {
+        OutputData {
+            output_id,
+            metadata: OutputMetadata::new(
+                BlockId::new([0; BlockId::LENGTH]),
+                output_id,
+                false,
+                None,
+                None,
+                None,
+                0,
+                0,
+                0,
+            ),
+            output: basic_output(amount),
+            is_spent: false,
+            address: Address::Ed25519(Ed25519Address::new([1; Ed25519Address::LENGTH])),
+            network_id: 0,
+            remainder,
+            chain: None,
+        }
+    }
+
+    #[test]
+    fn received_amount_alert() {
+        let alert_thresholds = AlertThresholds {
+            received_amount: Some(1_000_000),
+            balance_drop_percentage: None,
+        };
+        let mut account_details = AccountDetails::mock();
+        let received_output_id = OutputId::new(TransactionId::new([1; TransactionId::LENGTH]), 0).unwrap();
+
+        assert_eq!(
+            alert_thresholds
+                .received_amount_alert(&account_details, &output_data(received_output_id, 1_000_000, false)),
+            Some(ReceivedAmountAlert {
+                output_id: received_output_id,
+                amount: 1_000_000,
+                threshold: 1_000_000,
+            })
+        );
+        // Below the threshold
+        assert_eq!(
+            alert_thresholds.received_amount_alert(&account_details, &output_data(received_output_id, 999_999, false)),
+            None
+        );
+        // Remainders aren't received
+        assert_eq!(
+            alert_thresholds.received_amount_alert(&account_details, &output_data(received_output_id, 1_000_000, true)),
+            None
+        );
+        // Neither are other outputs of transactions sent by the account
+        let transaction = Transaction::mock(vec![basic_output(1_000_000)]);
+        let sent_output_id = OutputId::new(transaction.transaction_id, 0).unwrap();
+        account_details
+            .transactions
+            .insert(transaction.transaction_id, transaction);
+        assert_eq!(
+            alert_thresholds.received_amount_alert(&account_details, &output_data(sent_output_id, 1_000_000, false)),
+            None
+        );
+        // No alert without a threshold
+        assert_eq!(
+            AlertThresholds::default()
+                .received_amount_alert(&account_details, &output_data(received_output_id, 1_000_000, false)),
+            None
+        );
+    }
+
+    #[test]
+    fn balance_drop_alert() {
+        let alert_thresholds = AlertThresholds {
+            received_amount: None,
+            balance_drop_percentage: Some(50),
+        };
+
+        assert_eq!(
+            alert_thresholds.balance_drop_alert(1_000_000, 500_000),
+            Some(BalanceDropAlert {
+                previous_balance: 1_000_000,
+                balance: 500_000,
+                percentage: 50,
+                threshold: 50,
+            })
+        );
+        // The percentage is rounded down
+        assert_eq!(alert_thresholds.balance_drop_alert(1_000_000, 500_001), None);
+        assert_eq!(
+            alert_thresholds
+                .balance_drop_alert(u64::MAX, 0)
+                .map(|alert| alert.percentage),
+            Some(100)
+        );
+        // Increases and an empty previous balance don't alert
+        assert_eq!(alert_thresholds.balance_drop_alert(500_000, 1_000_000), None);
+        assert_eq!(alert_thresholds.balance_drop_alert(0, 0), None);
+        assert_eq!(AlertThresholds::default().balance_drop_alert(1_000_000, 0), None);
+    }
+}
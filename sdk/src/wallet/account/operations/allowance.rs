@@ -0,0 +1,339 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use primitive_types::U256;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    types::block::{
+        output::{NativeToken, Output, TokenId},
+        payload::transaction::TransactionId,
+    },
+    wallet::{
+        account::{Account, AccountDetails},
+        Error, Result,
+    },
+};
+
+/// A spending allowance of an account. Automated spenders reference it with
+/// [`TransactionOptions::allowance`](crate::wallet::account::TransactionOptions::allowance) when sending
+/// transactions, so the amount they can spend from a shared account is limited.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpendingAllowance {
+    /// The identifier that spenders reference.
+    pub id: String,
+    /// The maximum total amount of the base coin that can be spent with this allowance.
+    #[serde(with = "crate::utils::serde::string")]
+    pub max_total: u64,
+    /// The amount that was already spent with this allowance.
+    #[serde(default, with = "crate::utils::serde::string")]
+    pub spent: u64,
+    /// The maximum total amounts of native tokens that can be spent with this allowance. Other native tokens can't be
+    /// sent with it.
+    #[serde(default)]
+    pub max_native_tokens: HashMap<TokenId, U256>,
+    /// The amounts of native tokens that were already spent with this allowance.
+    #[serde(default)]
+    pub spent_native_tokens: HashMap<TokenId, U256>,
+    /// Unix timestamp in seconds after which the allowance can't be used anymore.
+    pub expiry: Option<u32>,
+    /// The recorded usage of the allowance.
+    #[serde(default)]
+    pub usage: Vec<AllowanceUsage>,
+}
+
+impl SpendingAllowance {
+    /// Creates a new unused allowance.
+    pub fn new(id: impl Into<String>, max_total: u64, expiry: Option<u32>) -> Self {
+        Self {
+            id: id.into(),
+            max_total,
+            spent: 0,
+            max_native_tokens: HashMap::new(),
+            spent_native_tokens: HashMap::new(),
+            expiry,
+            usage: Vec::new(),
+        }
+    }
+
+    /// Sets the maximum total amount of a native token that can be spent with this allowance.
+    pub fn with_max_native_token(mut self, token_id: TokenId, max_total: U256) -> Self {
+        self.max_native_tokens.insert(token_id, max_total);
+        self
+    }
+
+    /// Returns the amount that can still be spent with the allowance.
+    pub fn remaining(&self) -> u64 {
+        self.max_total.saturating_sub(self.spent)
+    }
+
+    /// Returns the amount of a native token that can still be spent with the allowance.
+    pub fn remaining_native_token(&self, token_id: &TokenId) -> U256 {
+        let max_total = self.max_native_tokens.get(token_id).copied().unwrap_or_default();
+        max_total.saturating_sub(self.spent_native_tokens.get(token_id).copied().unwrap_or_default())
+    }
+
+    // Checks that the allowance isn't expired and covers the usage.
+    fn check(&self, usage: &AllowanceUsage, current_time: u32) -> Result<()> {
+        if self.expiry.map_or(false, |expiry| current_time >= expiry) {
+            return Err(Error::AllowanceExpired(self.id.clone()));
+        }
+        if self.remaining() < usage.amount {
+            return Err(Error::AllowanceExceeded {
+                id: self.id.clone(),
+                remaining: self.remaining(),
+                required: usage.amount,
+            });
+        }
+        for native_token in &usage.native_tokens {
+            let remaining = self.remaining_native_token(native_token.token_id());
+            if remaining < native_token.amount() {
+                return Err(Error::AllowanceNativeTokenExceeded {
+                    id: self.id.clone(),
+                    token_id: *native_token.token_id(),
+                    remaining,
+                    required: native_token.amount(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    // Records the usage.
+    fn charge(&mut self, usage: AllowanceUsage) {
+        self.spent += usage.amount;
+        for native_token in &usage.native_tokens {
+            *self.spent_native_tokens.entry(*native_token.token_id()).or_default() += native_token.amount();
+        }
+        self.usage.push(usage);
+    }
+
+    // Removes the usage of a transaction that didn't get confirmed, returns whether there was one.
+    fn refund(&mut self, transaction_id: &TransactionId) -> bool {
+        let Some(position) = self
+            .usage
+            .iter()
+            .position(|usage| usage.transaction_id.as_ref() == Some(transaction_id))
+        else {
+            return false;
+        };
+        let usage = self.usage.remove(position);
+        self.spent = self.spent.saturating_sub(usage.amount);
+        for native_token in &usage.native_tokens {
+            if let Some(spent) = self.spent_native_tokens.get_mut(native_token.token_id()) {
+                *spent = spent.saturating_sub(native_token.amount());
+            }
+        }
+        true
+    }
+}
+
+/// A recorded use of a [`SpendingAllowance`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AllowanceUsage {
+    /// The amount of the base coin in the outputs of the transaction, without the storage deposits that are returned
+    /// to the account.
+    #[serde(with = "crate::utils::serde::string")]
+    pub amount: u64,
+    /// The native tokens in the outputs of the transaction.
+    #[serde(default)]
+    pub native_tokens: Vec<NativeToken>,
+    /// Unix timestamp in seconds of when the transaction was submitted.
+    pub timestamp: u32,
+    /// The transaction that used the allowance.
+    #[serde(default)]
+    pub transaction_id: Option<TransactionId>,
+}
+
+impl Account {
+    /// Adds a spending allowance, replacing an existing one with the same id.
+    pub async fn set_spending_allowance(&self, allowance: SpendingAllowance) -> Result<()> {
+        let mut account_details = self.details_mut().await;
+        account_details
+            .spending_allowances
+            .insert(allowance.id.clone(), allowance);
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+        Ok(())
+    }
+
+    /// Removes a spending allowance, returns it if it existed.
+    pub async fn remove_spending_allowance(&self, id: &str) -> Result<Option<SpendingAllowance>> {
+        let mut account_details = self.details_mut().await;
+        let allowance = account_details.spending_allowances.remove(id);
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+        Ok(allowance)
+    }
+
+    /// Returns the spending allowances of the account.
+    pub async fn spending_allowances(&self) -> Vec<SpendingAllowance> {
+        self.details().await.spending_allowances.values().cloned().collect()
+    }
+
+    /// Returns the usage of the allowance by a transaction with the outputs, if the allowance isn't expired and covers
+    /// it. Nothing is recorded before the transaction is submitted.
+    pub(crate) async fn check_spending_allowance(&self, id: &str, outputs: &[Output]) -> Result<AllowanceUsage> {
+        let current_time = self.client().get_time_checked().await?;
+        let account_details = self.details().await;
+        let allowance = account_details
+            .spending_allowances
+            .get(id)
+            .ok_or_else(|| Error::AllowanceNotFound(id.to_string()))?;
+
+        let mut amount = 0;
+        let mut native_tokens = HashMap::<TokenId, U256>::new();
+        for output in outputs {
+            amount += output.amount();
+            // Storage deposits that are returned to the account aren't spent
+            if let Some(sdr) = output
+                .unlock_conditions()
+                .and_then(|unlock_conditions| unlock_conditions.storage_deposit_return())
+            {
+                if account_details
+                    .public_addresses
+                    .iter()
+                    .chain(account_details.internal_addresses.iter())
+                    .any(|address| address.address.inner == *sdr.return_address())
+                {
+                    amount -= sdr.amount();
+                }
+            }
+            for native_token in output
+                .native_tokens()
+                .into_iter()
+                .flat_map(|native_tokens| native_tokens.iter())
+            {
+                *native_tokens.entry(*native_token.token_id()).or_default() += native_token.amount();
+            }
+        }
+        let usage = AllowanceUsage {
+            amount,
+            native_tokens: native_tokens
+                .into_iter()
+                .map(|(token_id, amount)| NativeToken::new(token_id, amount))
+                .collect::<std::result::Result<_, _>>()?,
+            timestamp: current_time,
+            transaction_id: None,
+        };
+        allowance.check(&usage, current_time)?;
+
+        Ok(usage)
+    }
+
+    /// Records the usage of an allowance by a transaction that is submitted. The allowance is checked again, because
+    /// other transactions could have used it since the transaction was prepared.
+    pub(crate) async fn charge_spending_allowance(
+        &self,
+        id: &str,
+        mut usage: AllowanceUsage,
+        transaction_id: TransactionId,
+    ) -> Result<()> {
+        let current_time = self.client().get_time_checked().await?;
+        let mut account_details = self.details_mut().await;
+        let allowance = account_details
+            .spending_allowances
+            .get_mut(id)
+            .ok_or_else(|| Error::AllowanceNotFound(id.to_string()))?;
+
+        allowance.check(&usage, current_time)?;
+        usage.timestamp = current_time;
+        usage.transaction_id = Some(transaction_id);
+        allowance.charge(usage);
+        Ok(())
+    }
+}
+
+impl AccountDetails {
+    // Refunds the usage of the allowances by a transaction that conflicted.
+    pub(crate) fn refund_spending_allowances(&mut self, transaction_id: &TransactionId) {
+        for allowance in self.spending_allowances.values_mut() {
+            if allowance.refund(transaction_id) {
+                log::debug!(
+                    "[TRANSACTION] refunded spending allowance {} for {transaction_id}",
+                    allowance.id
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn usage(amount: u64, native_tokens: Vec<NativeToken>, transaction_id: u8) -> AllowanceUsage {
+        AllowanceUsage {
+            amount,
+            native_tokens,
+            timestamp: 0,
+            transaction_id: Some(TransactionId::new([transaction_id; TransactionId::LENGTH])),
+        }
+    }
+
+    #[test]
+    fn exceeded_allowance() {
+        let token_id = TokenId::new([1; TokenId::LENGTH]);
+        let mut allowance =
+            SpendingAllowance::new("bot", 1_000_000, Some(100)).with_max_native_token(token_id, U256::from(50));
+
+        allowance.charge(usage(600_000, Vec::new(), 1));
+        assert!(allowance.check(&usage(400_000, Vec::new(), 2), 0).is_ok());
+        assert!(matches!(
+            allowance.check(&usage(400_001, Vec::new(), 2), 0),
+            Err(Error::AllowanceExceeded {
+                remaining: 400_000,
+                required: 400_001,
+                ..
+            })
+        ));
+        assert!(matches!(
+            allowance.check(&usage(0, Vec::new(), 2), 100),
+            Err(Error::AllowanceExpired(_))
+        ));
+
+        // Native tokens are limited separately, ones without a limit can't be spent
+        let native_token = NativeToken::new(token_id, U256::from(51)).unwrap();
+        assert!(matches!(
+            allowance.check(&usage(0, vec![native_token], 2), 0),
+            Err(Error::AllowanceNativeTokenExceeded { .. })
+        ));
+        let native_token = NativeToken::new(token_id, U256::from(50)).unwrap();
+        assert!(allowance.check(&usage(0, vec![native_token], 2), 0).is_ok());
+        let other_token = NativeToken::new(TokenId::new([2; TokenId::LENGTH]), U256::from(1)).unwrap();
+        assert!(matches!(
+            allowance.check(&usage(0, vec![other_token], 2), 0),
+            Err(Error::AllowanceNativeTokenExceeded { .. })
+        ));
+    }
+
+    #[test]
+    fn refund() {
+        let token_id = TokenId::new([1; TokenId::LENGTH]);
+        let mut allowance =
+            SpendingAllowance::new("bot", 1_000_000, None).with_max_native_token(token_id, U256::from(50));
+        let native_token = NativeToken::new(token_id, U256::from(20)).unwrap();
+
+        allowance.charge(usage(600_000, vec![native_token], 1));
+        allowance.charge(usage(300_000, Vec::new(), 2));
+        assert_eq!(allowance.remaining(), 100_000);
+        assert_eq!(allowance.remaining_native_token(&token_id), U256::from(30));
+
+        let mut account_details = AccountDetails::mock();
+        account_details
+            .spending_allowances
+            .insert(allowance.id.clone(), allowance);
+        account_details.refund_spending_allowances(&TransactionId::new([1; TransactionId::LENGTH]));
+        // Refunding again has no effect
+        account_details.refund_spending_allowances(&TransactionId::new([1; TransactionId::LENGTH]));
+
+        let allowance = &account_details.spending_allowances["bot"];
+        assert_eq!(allowance.remaining(), 700_000);
+        assert_eq!(allowance.remaining_native_token(&token_id), U256::from(50));
+        assert_eq!(allowance.usage, vec![usage(300_000, Vec::new(), 2)]);
+    }
+}
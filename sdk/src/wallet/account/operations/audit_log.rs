@@ -0,0 +1,179 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{future::Future, sync::atomic::Ordering};
+
+use crypto::hashes::{blake2b::Blake2b256, Digest};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    types::block::payload::transaction::TransactionId,
+    utils::unix_timestamp_now,
+    wallet::{account::Account, wallet::WalletInner},
+};
+
+tokio::task_local! {
+    // Set while the message interface records a method, so the transactions it sends aren't recorded a second time
+    static AUDITED_METHOD: ();
+}
+
+/// Runs the future of an account method which records its own audit log entry.
+pub(crate) async fn audited_method<F: Future>(future: F) -> F::Output {
+    AUDITED_METHOD.scope((), future).await
+}
+
+/// A recorded invocation of a mutating account method. Transactions submitted through the Rust API are recorded as
+/// `submitAndStoreTransaction`, the message interface records all mutating methods with their name. The log keeps the
+/// latest 10000 entries of each account.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditLogEntry {
+    /// The name of the called method.
+    pub method: String,
+    /// Blake2b-256 hash of the JSON serialized method parameters, so the entry doesn't contain secrets or large
+    /// payloads.
+    pub params_hash: String,
+    /// The id of the transaction which was sent by the method, if any.
+    pub transaction_id: Option<TransactionId>,
+    /// The error message if the method failed.
+    pub error: Option<String>,
+    /// A context provided by the caller, like an operator or request id.
+    pub context: Option<String>,
+    /// Unix timestamp in milliseconds of when the method was called.
+    pub timestamp: u128,
+}
+
+impl AuditLogEntry {
+    /// Creates a new entry for the method with the given parameters, timestamped with the current time.
+    pub fn new<P: Serialize>(method: impl Into<String>, params: &P) -> crate::wallet::Result<Self> {
+        let params_hash = prefix_hex::encode(Blake2b256::digest(serde_json::to_vec(params)?).as_slice());
+
+        Ok(Self {
+            method: method.into(),
+            params_hash,
+            transaction_id: None,
+            error: None,
+            context: None,
+            timestamp: unix_timestamp_now().as_millis(),
+        })
+    }
+
+    /// Sets the id of the transaction sent by the method.
+    pub fn with_transaction_id(mut self, transaction_id: impl Into<Option<TransactionId>>) -> Self {
+        self.transaction_id = transaction_id.into();
+        self
+    }
+
+    /// Sets the error message of a failed method.
+    pub fn with_error(mut self, error: impl Into<Option<String>>) -> Self {
+        self.error = error.into();
+        self
+    }
+
+    /// Sets the caller supplied context.
+    pub fn with_context(mut self, context: impl Into<Option<String>>) -> Self {
+        self.context = context.into();
+        self
+    }
+}
+
+/// Filter for querying the audit log, all conditions need to match.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditLogFilter {
+    /// Only return entries of this method.
+    pub method: Option<String>,
+    /// Only return entries with this transaction id.
+    pub transaction_id: Option<TransactionId>,
+    /// Only return entries with this context.
+    pub context: Option<String>,
+    /// Only return entries recorded at or after this unix timestamp in milliseconds.
+    pub since: Option<u128>,
+    /// Only return entries recorded before this unix timestamp in milliseconds.
+    pub until: Option<u128>,
+}
+
+impl AuditLogFilter {
+    fn matches(&self, entry: &AuditLogEntry) -> bool {
+        self.method.as_ref().map_or(true, |method| method == &entry.method)
+            && self
+                .transaction_id
+                .map_or(true, |transaction_id| Some(transaction_id) == entry.transaction_id)
+            && self
+                .context
+                .as_ref()
+                .map_or(true, |context| Some(context) == entry.context.as_ref())
+            && self.since.map_or(true, |since| entry.timestamp >= since)
+            && self.until.map_or(true, |until| entry.timestamp < until)
+    }
+}
+
+impl WalletInner {
+    /// Enables or disables recording of mutating account methods into the audit log. Disabled by default.
+    pub fn set_audit_log_enabled(&self, enabled: bool) {
+        self.audit_log_enabled.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Returns whether mutating account methods are recorded into the audit log.
+    pub fn is_audit_log_enabled(&self) -> bool {
+        self.audit_log_enabled.load(Ordering::Relaxed)
+    }
+}
+
+impl Account {
+    /// Appends an entry to the audit log of the account. Entries can't be modified afterwards, only the oldest ones are
+    /// dropped once the log is full.
+    pub async fn record_audit_log_entry(&self, entry: AuditLogEntry) -> crate::wallet::Result<()> {
+        let account_index = self.details().await.index;
+
+        self.wallet
+            .storage_manager
+            .write()
+            .await
+            .append_audit_log_entry(account_index, entry)
+            .await
+    }
+
+    // Records a submitted transaction if the audit log is enabled and the transaction isn't sent by a method which is
+    // already recorded. Errors are only logged, since the transaction has been submitted already.
+    pub(crate) async fn record_submitted_transaction(&self, transaction_id: TransactionId) {
+        if !self.wallet.is_audit_log_enabled() || AUDITED_METHOD.try_with(|_| ()).is_ok() {
+            return;
+        }
+
+        let entry = match AuditLogEntry::new("submitAndStoreTransaction", &transaction_id) {
+            Ok(entry) => entry.with_transaction_id(transaction_id),
+            Err(error) => {
+                log::error!("failed to create audit log entry: {error}");
+                return;
+            }
+        };
+        if let Err(error) = self.record_audit_log_entry(entry).await {
+            log::error!("failed to record audit log entry: {error}");
+        }
+    }
+
+    /// Returns the audit log entries of the account matching the filter, oldest first.
+    /// ```ignore
+    /// let entries = account
+    ///     .audit_log(AuditLogFilter {
+    ///         method: Some("sendAmount".to_string()),
+    ///         ..Default::default()
+    ///     })
+    ///     .await?;
+    /// ```
+    pub async fn audit_log(&self, filter: AuditLogFilter) -> crate::wallet::Result<Vec<AuditLogEntry>> {
+        log::debug!("[audit_log]");
+        let account_index = self.details().await.index;
+
+        let entries = self
+            .wallet
+            .storage_manager
+            .read()
+            .await
+            .get_audit_log(account_index)
+            .await?;
+
+        Ok(entries.into_iter().filter(|entry| filter.matches(entry)).collect())
+    }
+}
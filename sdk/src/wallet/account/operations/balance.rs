@@ -17,9 +17,10 @@ impl Account {
     pub async fn balance(&self) -> crate::wallet::Result<AccountBalance> {
         log::debug!("[BALANCE] get balance");
         let mut account_balance = AccountBalance::default();
-        #[cfg(feature = "participation")]
+        #[cfg(feature = "wallet-participation")]
         {
             account_balance.base_coin.voting_power = self.get_voting_power().await?;
+            account_balance.participation_rewards = self.participation_rewards.lock().await.clone();
         }
 
         let unlockable_outputs_with_multiple_unlock_conditions = self
@@ -29,7 +30,7 @@ impl Account {
         let account_addresses = self.addresses().await?;
 
         let network_id = self.client().get_network_id().await?;
-        let rent_structure = self.client().get_rent_structure().await?;
+        let rent_structure = self.network_params().await?.rent_structure;
 
         let local_time = self.client().get_time_checked().await?;
 
@@ -140,28 +141,30 @@ impl Account {
                                 local_time,
                             );
 
-                            if output_can_be_unlocked_now_and_in_future {
-                                // If output has a StorageDepositReturnUnlockCondition, the amount of it should be
-                                // subtracted, because this part needs to be sent back
-                                let amount = output
-                                    .unlock_conditions()
-                                    .and_then(|u| u.storage_deposit_return())
-                                    .map_or_else(
-                                        || output.amount(),
-                                        |sdr| {
-                                            if account_addresses
-                                                .iter()
-                                                .any(|a| a.address.inner == *sdr.return_address())
-                                            {
-                                                // sending to ourself, we get the full amount
-                                                output.amount()
-                                            } else {
-                                                // Sending to someone else
-                                                output.amount() - sdr.amount()
-                                            }
-                                        },
-                                    );
+                            // If output has a StorageDepositReturnUnlockCondition, the amount of it should be
+                            // subtracted, because this part needs to be sent back
+                            let amount = output
+                                .unlock_conditions()
+                                .and_then(|u| u.storage_deposit_return())
+                                .map_or_else(
+                                    || output.amount(),
+                                    |sdr| {
+                                        if account_addresses
+                                            .iter()
+                                            .any(|a| a.address.inner == *sdr.return_address())
+                                        {
+                                            // sending to ourself, we get the full amount
+                                            output.amount()
+                                        } else {
+                                            // Sending to someone else
+                                            output.amount() - sdr.amount()
+                                        }
+                                    },
+                                );
+
+                            account_balance.claimable += amount;
 
+                            if output_can_be_unlocked_now_and_in_future {
                                 // add nft_id for nft outputs
                                 if let Output::Nft(output) = &output {
                                     let nft_id = output.nft_id_non_null(output_id);
@@ -273,11 +276,11 @@ impl Account {
             })
         }
 
-        #[cfg(not(feature = "participation"))]
+        #[cfg(not(feature = "wallet-participation"))]
         {
             account_balance.base_coin.available = account_balance.base_coin.total.saturating_sub(locked_amount);
         }
-        #[cfg(feature = "participation")]
+        #[cfg(feature = "wallet-participation")]
         {
             account_balance.base_coin.available = account_balance
                 .base_coin
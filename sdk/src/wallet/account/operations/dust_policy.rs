@@ -0,0 +1,222 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "wallet-consolidation")]
+use crate::wallet::{
+    account::{types::Transaction, ConsolidationParams},
+    Error,
+};
+use crate::wallet::{
+    account::{Account, AccountDetails},
+    Result,
+};
+
+/// A policy that prevents an account from accumulating micro outputs, basic outputs with a small amount. Incoming
+/// outputs that need to be claimed are not returned as claimable by
+/// [`Account::get_unlockable_outputs_with_additional_unlock_conditions()`] if they would add too little or too many
+/// micro outputs, so they return to the sender once they expire. [`Account::consolidate_outputs()`] takes the smallest
+/// outputs first when a policy is set.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DustPolicy {
+    /// Basic outputs with a smaller amount are micro outputs.
+    #[serde(with = "crate::utils::serde::string")]
+    pub micro_amount: u64,
+    /// Incoming outputs that need to be claimed are declined if the amount they add is smaller.
+    #[serde(with = "crate::utils::serde::string")]
+    pub decline_below: u64,
+    /// Incoming micro outputs that need to be claimed are declined once the account holds this amount of micro
+    /// outputs, including the ones that are returned as claimable.
+    pub max_micro_outputs: usize,
+    /// [`Account::consolidate_micro_outputs()`] consolidates the outputs if the account holds more micro outputs,
+    /// `None` disables it. Requires the `wallet-consolidation` feature.
+    #[serde(default)]
+    pub consolidate_above: Option<usize>,
+}
+
+impl DustPolicy {
+    /// Returns the amount of unspent micro outputs of the account, without the ones that still need to be claimed.
+    pub(crate) fn micro_output_count(&self, account_details: &AccountDetails) -> usize {
+        account_details
+            .unspent_outputs
+            .values()
+            .filter(|output_data| {
+                output_data.output.is_basic()
+                    && output_data.output.amount() < self.micro_amount
+                    && output_data
+                        .output
+                        .unlock_conditions()
+                        .map_or(false, |unlock_conditions| unlock_conditions.len() == 1)
+            })
+            .count()
+    }
+
+    /// Returns whether an incoming output that adds the claimable amount should be declined.
+    pub(crate) fn declines(&self, claimable_amount: u64, micro_output_count: usize) -> bool {
+        claimable_amount < self.decline_below
+            || (claimable_amount < self.micro_amount && micro_output_count >= self.max_micro_outputs)
+    }
+}
+
+impl Account {
+    /// Sets the dust policy of the account, `None` removes it. Setting `consolidate_above` without the
+    /// `wallet-consolidation` feature returns an error.
+    pub async fn set_dust_policy(&self, dust_policy: Option<DustPolicy>) -> Result<()> {
+        #[cfg(not(feature = "wallet-consolidation"))]
+        if dust_policy.map_or(false, |dust_policy| dust_policy.consolidate_above.is_some()) {
+            return Err(crate::wallet::Error::FeatureNotEnabled("wallet-consolidation"));
+        }
+
+        let mut account_details = self.details_mut().await;
+        account_details.dust_policy = dust_policy;
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+        Ok(())
+    }
+
+    /// Returns the dust policy of the account.
+    pub async fn dust_policy(&self) -> Option<DustPolicy> {
+        self.details().await.dust_policy
+    }
+
+    /// Consolidates the outputs if the account holds more micro outputs than `consolidate_above` of the dust policy
+    /// allows. Returns `None` if no consolidation was needed or possible. This isn't done while syncing, it needs to be
+    /// called explicitly.
+    #[cfg(feature = "wallet-consolidation")]
+    pub async fn consolidate_micro_outputs(&self) -> Result<Option<Transaction>> {
+        let micro_output_count = {
+            let account_details = self.details().await;
+            let Some(dust_policy) = &account_details.dust_policy else {
+                return Ok(None);
+            };
+            let Some(consolidate_above) = dust_policy.consolidate_above else {
+                return Ok(None);
+            };
+            let micro_output_count = dust_policy.micro_output_count(&account_details);
+            if micro_output_count <= consolidate_above {
+                return Ok(None);
+            }
+            micro_output_count
+        };
+
+        log::debug!("[DUST_POLICY] consolidating {micro_output_count} micro outputs");
+        match self
+            .consolidate_outputs(ConsolidationParams {
+                force: true,
+                ..Default::default()
+            })
+            .await
+        {
+            Ok(transaction) => Ok(Some(transaction)),
+            // The micro outputs can't be consolidated yet, for example because they're locked
+            Err(Error::NoOutputsToConsolidate { .. }) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        types::block::{
+            address::{Address, Ed25519Address},
+            output::{
+                unlock_condition::{AddressUnlockCondition, StorageDepositReturnUnlockCondition},
+                BasicOutputBuilder, Output, OutputId, OutputMetadata,
+            },
+            payload::transaction::TransactionId,
+            BlockId,
+        },
+        wallet::account::types::OutputData,
+    };
+
+    const TOKEN_SUPPLY: u64 = 1_813_620_509_061_365;
+
+    fn dust_policy() -> DustPolicy {
+        DustPolicy {
+            micro_amount: 1_000_000,
+            decline_below: 10_000,
+            max_micro_outputs: 2,
+            consolidate_above: None,
+        }
+    }
+
+    fn add_output(account_details: &mut AccountDetails, index: u16, output: Output) {
+        let output_id = OutputId::new(TransactionId::new([1; TransactionId::LENGTH]), index).unwrap();
+        let output_data = OutputData {
+            output_id,
+            metadata: OutputMetadata::new(
+                BlockId::new([0; BlockId::LENGTH]),
+                output_id,
+                false,
+                None,
+                None,
+                None,
+                0,
+                0,
+                0,
+            ),
+            output,
+            is_spent: false,
+            address: Address::Ed25519(Ed25519Address::new([0; Ed25519Address::LENGTH])),
+            network_id: 0,
+            remainder: false,
+            chain: None,
+        };
+        account_details.unspent_outputs.insert(output_id, output_data);
+    }
+
+    #[test]
+    fn declines() {
+        let dust_policy = dust_policy();
+
+        // Too small, independent of the micro outputs
+        assert!(dust_policy.declines(9_999, 0));
+        assert!(!dust_policy.declines(10_000, 0));
+        // Micro outputs are declined once the limit is reached
+        assert!(!dust_policy.declines(999_999, 1));
+        assert!(dust_policy.declines(999_999, 2));
+        // Other outputs are always accepted
+        assert!(!dust_policy.declines(1_000_000, 2));
+    }
+
+    #[test]
+    fn micro_output_count() {
+        let address = Address::Ed25519(Ed25519Address::new([0; Ed25519Address::LENGTH]));
+        let basic_output = |amount: u64| {
+            BasicOutputBuilder::new_with_amount(amount).add_unlock_condition(AddressUnlockCondition::new(address))
+        };
+        let mut account_details = AccountDetails::mock();
+
+        add_output(
+            &mut account_details,
+            0,
+            basic_output(999_999).finish_output(TOKEN_SUPPLY).unwrap(),
+        );
+        add_output(
+            &mut account_details,
+            1,
+            basic_output(500_000).finish_output(TOKEN_SUPPLY).unwrap(),
+        );
+        // Not a micro output
+        add_output(
+            &mut account_details,
+            2,
+            basic_output(1_000_000).finish_output(TOKEN_SUPPLY).unwrap(),
+        );
+        // Still needs to be claimed
+        add_output(
+            &mut account_details,
+            3,
+            basic_output(500_000)
+                .add_unlock_condition(StorageDepositReturnUnlockCondition::new(address, 50_000, TOKEN_SUPPLY).unwrap())
+                .finish_output(TOKEN_SUPPLY)
+                .unwrap(),
+        );
+
+        assert_eq!(dust_policy().micro_output_count(&account_details), 2);
+    }
+}
@@ -0,0 +1,99 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::secret::GenerateAddressOptions,
+    types::block::{address::Address, payload::transaction::TransactionEssence},
+    wallet::account::{types::address::AccountAddress, Account, AccountDetails},
+};
+
+/// The status of the pool of unused internal addresses of an account, which are used for remainder outputs. The pool
+/// is filled while syncing, see [`SyncOptions::min_unused_internal_addresses`](crate::wallet::account::SyncOptions).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InternalAddressPoolStatus {
+    /// Amount of generated internal addresses.
+    pub total: usize,
+    /// Amount of internal addresses that never received an output and aren't the remainder address of a pending or
+    /// prepared transaction, so they can be used for the next remainders.
+    pub unused: usize,
+    /// The amount of unused internal addresses that is generated while syncing with the default sync options.
+    pub min_unused: u32,
+}
+
+impl AccountDetails {
+    /// Returns the internal addresses that never received an output, aren't the remainder address of a pending
+    /// transaction and aren't reserved for a prepared one, ordered by their key index.
+    pub(crate) fn unused_internal_addresses(&self, reserved_addresses: &HashSet<Address>) -> Vec<&AccountAddress> {
+        let pending_addresses = self
+            .pending_transactions
+            .iter()
+            .filter_map(|transaction_id| self.transactions.get(transaction_id))
+            .flat_map(|transaction| {
+                let TransactionEssence::Regular(essence) = transaction.payload.essence();
+                essence.outputs().iter().filter_map(|output| {
+                    output
+                        .unlock_conditions()
+                        .and_then(|unlock_conditions| unlock_conditions.address())
+                        .map(|address_unlock_condition| *address_unlock_condition.address())
+                })
+            })
+            .collect::<HashSet<Address>>();
+
+        let mut unused_addresses = self
+            .internal_addresses
+            .iter()
+            .filter(|address| {
+                !address.used
+                    && !pending_addresses.contains(address.address.inner())
+                    && !reserved_addresses.contains(address.address.inner())
+            })
+            .collect::<Vec<_>>();
+        unused_addresses.sort_by_key(|address| address.key_index);
+        unused_addresses
+    }
+}
+
+impl Account {
+    /// Returns the status of the pool of unused internal addresses.
+    pub async fn internal_address_pool_status(&self) -> InternalAddressPoolStatus {
+        let min_unused = self.default_sync_options().await.min_unused_internal_addresses;
+        let reserved_addresses = self.reserved_remainder_addresses.lock().await;
+        let account_details = self.details().await;
+
+        InternalAddressPoolStatus {
+            total: account_details.internal_addresses.len(),
+            unused: account_details.unused_internal_addresses(&reserved_addresses).len(),
+            min_unused,
+        }
+    }
+
+    /// Generates internal addresses until the account has at least `min_unused` unused internal addresses.
+    pub(crate) async fn fill_internal_address_pool(&self, min_unused: u32) -> crate::wallet::Result<()> {
+        let reserved_addresses = self.reserved_remainder_addresses.lock().await;
+        let account_details = self.details().await;
+        // Watch-only accounts can't generate addresses, they only have the imported ones
+        if account_details.watch_only {
+            return Ok(());
+        }
+        let unused = account_details.unused_internal_addresses(&reserved_addresses).len() as u32;
+        drop(account_details);
+        drop(reserved_addresses);
+        if unused >= min_unused {
+            return Ok(());
+        }
+
+        log::debug!(
+            "[INTERNAL_ADDRESS_POOL] {unused} unused internal addresses, generating {}",
+            min_unused - unused
+        );
+        self.generate_addresses(min_unused - unused, Some(GenerateAddressOptions::internal()))
+            .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,379 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crypto::keys::slip10::Chain;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{
+        api::{input_selection::Selected, PreparedTransactionData},
+        constants::HD_WALLET_TYPE,
+        node_api::indexer::query_parameters::QueryParameter,
+        secret::{types::InputSigningData, GenerateAddressOptions, SecretManage},
+    },
+    types::block::{
+        address::Bech32Address,
+        input::INPUT_COUNT_MAX,
+        output::{
+            unlock_condition::AddressUnlockCondition, BasicOutputBuilder, NativeTokens, NativeTokensBuilder, OutputId,
+            OutputWithMetadata,
+        },
+    },
+    wallet::{
+        account::{
+            operations::output_claiming::get_new_native_token_count, types::Transaction, Account, TransactionOptions,
+        },
+        Result, Wallet,
+    },
+};
+
+/// An address derived with a legacy coin type that holds funds, returned from [`Account::find_legacy_funds()`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LegacyAddressFunds {
+    /// The address, encoded with the bech32 HRP of the current network.
+    pub address: Bech32Address,
+    /// The coin type the address was derived with.
+    pub coin_type: u32,
+    /// The index of the account the address was derived for.
+    pub account_index: u32,
+    /// Whether the address is an internal address.
+    pub internal: bool,
+    /// The address key index.
+    pub key_index: u32,
+    /// The ids of the unspent basic outputs on the address that can be swept.
+    pub output_ids: Vec<OutputId>,
+    /// The summed amount of the outputs.
+    #[serde(with = "crate::utils::serde::string")]
+    pub amount: u64,
+}
+
+impl Account {
+    /// Searches for funds on addresses derived with a legacy coin type for the index of this account, for example
+    /// [`IOTA_COIN_TYPE`](crate::client::constants::IOTA_COIN_TYPE) which Chrysalis wallets used, when the wallet
+    /// now uses another coin type. Public and internal addresses are searched until `address_gap_limit` addresses in
+    /// a row have no outputs. Only basic outputs without unlock conditions besides the address are returned, as only
+    /// those can be swept.
+    pub async fn find_legacy_funds(
+        &self,
+        legacy_coin_type: u32,
+        address_gap_limit: u32,
+    ) -> Result<Vec<LegacyAddressFunds>> {
+        log::debug!("[find_legacy_funds] coin type {legacy_coin_type}");
+        self.check_not_watch_only("derive legacy addresses").await?;
+        let account_index = *self.details().await.index();
+        let bech32_hrp = self.network_params().await?.bech32_hrp;
+
+        let mut legacy_funds = Vec::new();
+        for internal in [false, true] {
+            let mut start_index = 0;
+            let mut addresses_without_outputs = 0;
+            while addresses_without_outputs < address_gap_limit {
+                let options = GenerateAddressOptions {
+                    internal,
+                    ..Default::default()
+                };
+                let addresses = self
+                    .wallet
+                    .secret_manager
+                    .read()
+                    .await
+                    .generate_addresses(
+                        legacy_coin_type,
+                        account_index,
+                        start_index..start_index + address_gap_limit,
+                        Some(options),
+                    )
+                    .await?;
+
+                for (key_index, address) in (start_index..).zip(addresses) {
+                    let address = Bech32Address::new(bech32_hrp, address);
+                    let output_ids = self
+                        .client()
+                        .basic_output_ids(vec![
+                            QueryParameter::Address(address),
+                            QueryParameter::HasExpiration(false),
+                            QueryParameter::HasStorageDepositReturn(false),
+                            QueryParameter::HasTimelock(false),
+                        ])
+                        .await?
+                        .items;
+
+                    if output_ids.is_empty() {
+                        addresses_without_outputs += 1;
+                        if addresses_without_outputs == address_gap_limit {
+                            break;
+                        }
+                        continue;
+                    }
+                    addresses_without_outputs = 0;
+
+                    let amount = self
+                        .client()
+                        .get_outputs(output_ids.clone())
+                        .await?
+                        .iter()
+                        .map(|output| output.output().amount())
+                        .sum();
+                    legacy_funds.push(LegacyAddressFunds {
+                        address,
+                        coin_type: legacy_coin_type,
+                        account_index,
+                        internal,
+                        key_index,
+                        output_ids,
+                        amount,
+                    });
+                }
+                start_index += address_gap_limit;
+            }
+        }
+
+        log::debug!("[find_legacy_funds] found funds on {} addresses", legacy_funds.len());
+        Ok(legacy_funds)
+    }
+
+    /// Sweeps the outputs found with [`Account::find_legacy_funds()`] into one output on the current receive address
+    /// of the account. A single transaction has at most 128 inputs, if there are more outputs, the remaining ones
+    /// can be swept by searching and sweeping again after the transaction got confirmed.
+    pub async fn sweep_legacy_funds(
+        &self,
+        legacy_funds: &[LegacyAddressFunds],
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> Result<Transaction> {
+        let options = options.into();
+        let prepared_transaction = self.prepare_sweep_legacy_funds(legacy_funds, options).await?;
+        self.sign_and_submit_transaction(prepared_transaction).await
+    }
+
+    /// Function to prepare the transaction for [`Account::sweep_legacy_funds()`].
+    pub async fn prepare_sweep_legacy_funds(
+        &self,
+        legacy_funds: &[LegacyAddressFunds],
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> Result<PreparedTransactionData> {
+        log::debug!("[prepare_sweep_legacy_funds]");
+        let token_supply = self.network_params().await?.token_supply;
+
+        let mut legacy_inputs = LegacyInputs::default();
+        'addresses: for address_funds in legacy_funds {
+            for output in self.client().get_outputs(address_funds.output_ids.clone()).await? {
+                if legacy_inputs.is_full() {
+                    log::debug!("[prepare_sweep_legacy_funds] reached the max amount of inputs");
+                    break 'addresses;
+                }
+                legacy_inputs.add(address_funds, output)?;
+            }
+        }
+
+        if legacy_inputs.inputs.is_empty() {
+            return Err(crate::wallet::Error::CustomInput(
+                "no unspent legacy outputs to sweep".to_string(),
+            ));
+        }
+
+        let receive_address = self.current_receive_address().await?;
+        let sweep_output = BasicOutputBuilder::new_with_amount(legacy_inputs.amount)
+            .add_unlock_condition(AddressUnlockCondition::new(*receive_address.address().inner()))
+            .with_native_tokens(legacy_inputs.native_tokens.finish()?)
+            .finish_output(token_supply)?;
+
+        self.build_transaction_essence(
+            Selected {
+                inputs: legacy_inputs.inputs,
+                outputs: vec![sweep_output],
+                remainder: None,
+            },
+            options,
+        )
+        .await
+    }
+}
+
+/// The inputs collected by [`Account::prepare_sweep_legacy_funds()`].
+#[derive(Default)]
+struct LegacyInputs {
+    inputs: Vec<InputSigningData>,
+    amount: u64,
+    native_tokens: NativeTokensBuilder,
+}
+
+impl LegacyInputs {
+    fn is_full(&self) -> bool {
+        self.inputs.len() >= INPUT_COUNT_MAX as usize
+    }
+
+    /// Adds an output of a legacy address as input, unless it got spent since it was found or its native tokens would
+    /// exceed the max native tokens count.
+    fn add(&mut self, address_funds: &LegacyAddressFunds, output: OutputWithMetadata) -> Result<()> {
+        if output.metadata().is_spent() {
+            return Ok(());
+        }
+        if let Some(native_tokens) = output.output().native_tokens() {
+            if get_new_native_token_count(&self.native_tokens, native_tokens)? > NativeTokens::COUNT_MAX.into() {
+                log::debug!("[prepare_sweep_legacy_funds] skipping output to not exceed the max native tokens count");
+                return Ok(());
+            }
+            self.native_tokens.add_native_tokens(native_tokens.clone())?;
+        }
+        self.amount += output.output().amount();
+
+        self.inputs.push(InputSigningData {
+            output: output.output().clone(),
+            output_metadata: output.into_metadata(),
+            chain: Some(Chain::from_u32_hardened(vec![
+                HD_WALLET_TYPE,
+                address_funds.coin_type,
+                address_funds.account_index,
+                address_funds.internal as u32,
+                address_funds.key_index,
+            ])),
+        });
+        Ok(())
+    }
+}
+
+impl Wallet {
+    /// Searches all accounts that aren't watch-only for funds on addresses derived with a legacy coin type, see
+    /// [`Account::find_legacy_funds()`]. To also find accounts that only have legacy funds, use
+    /// [`Wallet::recover_legacy_funds()`].
+    pub async fn find_legacy_funds(
+        &self,
+        legacy_coin_type: u32,
+        address_gap_limit: u32,
+    ) -> Result<Vec<LegacyAddressFunds>> {
+        let mut legacy_funds = Vec::new();
+        for account in self.accounts.read().await.iter() {
+            if *account.details().await.watch_only() {
+                continue;
+            }
+            legacy_funds.extend(account.find_legacy_funds(legacy_coin_type, address_gap_limit).await?);
+        }
+        Ok(legacy_funds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use primitive_types::U256;
+
+    use super::*;
+    use crate::{
+        client::constants::IOTA_COIN_TYPE,
+        types::block::{
+            output::{NativeToken, OutputMetadata, TokenId},
+            payload::transaction::TransactionId,
+            BlockId,
+        },
+    };
+
+    const TOKEN_SUPPLY: u64 = 1_813_620_509_061_365;
+
+    fn legacy_address_funds() -> LegacyAddressFunds {
+        LegacyAddressFunds {
+            address: Bech32Address::try_from_str("rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy")
+                .unwrap(),
+            coin_type: IOTA_COIN_TYPE,
+            account_index: 1,
+            internal: true,
+            key_index: 3,
+            output_ids: Vec::new(),
+            amount: 0,
+        }
+    }
+
+    fn output(
+        address_funds: &LegacyAddressFunds,
+        index: u16,
+        amount: u64,
+        is_spent: bool,
+        token: Option<u8>,
+    ) -> OutputWithMetadata {
+        // Transactions have at most 128 outputs
+        let output_id = OutputId::new(
+            TransactionId::new([(index / 100) as u8; TransactionId::LENGTH]),
+            index % 100,
+        )
+        .unwrap();
+        let mut output_builder = BasicOutputBuilder::new_with_amount(amount)
+            .add_unlock_condition(AddressUnlockCondition::new(*address_funds.address.inner()));
+        if let Some(token) = token {
+            output_builder = output_builder
+                .add_native_token(NativeToken::new(TokenId::new([token; TokenId::LENGTH]), U256::from(10)).unwrap());
+        }
+        OutputWithMetadata::new(
+            output_builder.finish_output(TOKEN_SUPPLY).unwrap(),
+            OutputMetadata::new(
+                BlockId::new([0; BlockId::LENGTH]),
+                output_id,
+                is_spent,
+                None,
+                None,
+                None,
+                0,
+                0,
+                0,
+            ),
+        )
+    }
+
+    #[test]
+    fn legacy_inputs() {
+        let address_funds = legacy_address_funds();
+        let mut legacy_inputs = LegacyInputs::default();
+
+        legacy_inputs
+            .add(&address_funds, output(&address_funds, 0, 1_000_000, false, None))
+            .unwrap();
+        // Outputs that got spent since they were found are skipped
+        legacy_inputs
+            .add(&address_funds, output(&address_funds, 1, 2_000_000, true, None))
+            .unwrap();
+
+        assert_eq!(legacy_inputs.inputs.len(), 1);
+        assert_eq!(legacy_inputs.amount, 1_000_000);
+        // The inputs are signed with the legacy coin type
+        assert_eq!(
+            legacy_inputs.inputs[0].chain,
+            Some(Chain::from_u32_hardened(vec![HD_WALLET_TYPE, IOTA_COIN_TYPE, 1, 1, 3]))
+        );
+        assert!(!legacy_inputs.is_full());
+
+        for index in 2..=INPUT_COUNT_MAX {
+            legacy_inputs
+                .add(&address_funds, output(&address_funds, index, 1_000_000, false, None))
+                .unwrap();
+        }
+        assert!(legacy_inputs.is_full());
+        assert_eq!(legacy_inputs.amount, INPUT_COUNT_MAX as u64 * 1_000_000);
+    }
+
+    #[test]
+    fn legacy_inputs_native_tokens_count() {
+        let address_funds = legacy_address_funds();
+        let mut legacy_inputs = LegacyInputs::default();
+
+        for token in 0..NativeTokens::COUNT_MAX {
+            legacy_inputs
+                .add(
+                    &address_funds,
+                    output(&address_funds, token.into(), 1_000_000, false, Some(token)),
+                )
+                .unwrap();
+        }
+        // An output with another native token would exceed the max native tokens count
+        legacy_inputs
+            .add(
+                &address_funds,
+                output(&address_funds, 100, 1_000_000, false, Some(NativeTokens::COUNT_MAX)),
+            )
+            .unwrap();
+        // Native tokens that are already part of the inputs can still be added
+        legacy_inputs
+            .add(&address_funds, output(&address_funds, 101, 1_000_000, false, Some(0)))
+            .unwrap();
+
+        assert_eq!(legacy_inputs.inputs.len(), NativeTokens::COUNT_MAX as usize + 1);
+        assert_eq!(legacy_inputs.native_tokens.len(), NativeTokens::COUNT_MAX as usize);
+    }
+}
@@ -1,26 +1,68 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+/// The module for the report of addresses that are linked on-chain
+pub(crate) mod address_clustering;
+/// The module for the gap limit based address discovery
+pub(crate) mod address_discovery;
 /// The module for the address generation
 pub(crate) mod address_generation;
+/// The module for the rotation of receive addresses
+pub(crate) mod address_rotation;
+/// The module for the verification of addresses
+pub(crate) mod address_verification;
+/// The module for the alert thresholds of large balance changes
+pub(crate) mod alert_thresholds;
+/// The module for spending allowances
+pub(crate) mod allowance;
+/// The module for the audit log of account methods
+#[cfg(feature = "storage")]
+pub(crate) mod audit_log;
 /// The module to get the accounts balance
 pub(crate) mod balance;
+/// The module for the protection against micro outputs
+pub(crate) mod dust_policy;
 /// Helper functions
 pub(crate) mod helpers;
+/// The module for the pool of unused internal addresses
+pub(crate) mod internal_address_pool;
+/// The module to find and sweep funds on addresses derived with a legacy coin type
+pub(crate) mod legacy_funds;
+/// The module for the cached network parameters
+pub(crate) mod network_params;
+/// The module to decode the metadata of NFTs
+pub(crate) mod nft_metadata;
 /// The module for claiming of outputs with
 /// [`UnlockCondition`](crate::types::block::output::UnlockCondition)s that aren't only
 /// [`AddressUnlockCondition`](crate::types::block::output::unlock_condition::AddressUnlockCondition)
 pub(crate) mod output_claiming;
 /// The module for the output consolidation
+#[cfg(feature = "wallet-consolidation")]
 pub(crate) mod output_consolidation;
 /// The module to find additional addresses with unspent outputs
 pub(crate) mod output_finder;
+/// The module to trace where the funds of an output went
+pub(crate) mod output_trace;
 /// The module for participation
-#[cfg(feature = "participation")]
+#[cfg(feature = "wallet-participation")]
 pub(crate) mod participation;
+/// The module for the primary alias, which holds the funds of the account
+pub(crate) mod primary_alias;
+/// The module for the rate limit of sent transactions
+pub(crate) mod rate_limit;
 /// The module for retrying blocks or transactions
 pub(crate) mod retry;
 /// The module for synchronization of an account
 pub(crate) mod syncing;
+/// The module for the native token policy
+pub(crate) mod token_policy;
+/// The module to resolve the IRC30 metadata of native tokens
+pub(crate) mod token_registry;
 /// The module for transactions
 pub(crate) mod transaction;
+/// The module to export the transaction history
+pub(crate) mod transaction_export;
+/// The module for the sequence numbers of sent transactions
+pub(crate) mod transaction_sequence;
+/// The module for the health report of the unspent outputs
+pub(crate) mod utxo_health;
@@ -0,0 +1,116 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use instant::Instant;
+
+use crate::{
+    types::block::{address::Hrp, output::RentStructure},
+    wallet::account::{constants::NETWORK_PARAMS_CACHE_TTL, Account},
+};
+
+/// Network parameters that are needed in almost every prepare path, cached by the account.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NetworkParams {
+    /// The bech32 human readable part of the network.
+    pub bech32_hrp: Hrp,
+    /// The token supply of the network.
+    pub token_supply: u64,
+    /// The rent structure of the network.
+    pub rent_structure: RentStructure,
+}
+
+impl Account {
+    /// Returns the network parameters, from the cache if they were fetched less than a minute ago, otherwise they get
+    /// refreshed.
+    pub async fn network_params(&self) -> crate::wallet::Result<NetworkParams> {
+        if let Some((timestamp, network_params)) = *self.network_params.lock().await {
+            if timestamp.elapsed() < NETWORK_PARAMS_CACHE_TTL {
+                return Ok(network_params);
+            }
+        }
+        self.refresh_network_params().await
+    }
+
+    /// Fetches the network parameters from the client and updates the cache, for example after the client options
+    /// changed or a protocol parameters update.
+    pub async fn refresh_network_params(&self) -> crate::wallet::Result<NetworkParams> {
+        log::debug!("[refresh_network_params]");
+        let protocol_parameters = self.client().get_protocol_parameters().await?;
+        let network_params = NetworkParams {
+            bech32_hrp: *protocol_parameters.bech32_hrp(),
+            token_supply: protocol_parameters.token_supply(),
+            rent_structure: *protocol_parameters.rent_structure(),
+        };
+        self.network_params
+            .lock()
+            .await
+            .replace((Instant::now(), network_params));
+        Ok(network_params)
+    }
+
+    /// Removes the cached network parameters, so they get fetched again when needed.
+    pub async fn invalidate_network_params(&self) {
+        self.network_params.lock().await.take();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::{
+            constants::SHIMMER_COIN_TYPE,
+            secret::{mnemonic::MnemonicSecretManager, SecretManager},
+            Client,
+        },
+        types::block::protocol::ProtocolParameters,
+        wallet::{ClientOptions, Wallet},
+    };
+
+    #[tokio::test]
+    async fn cached_until_expired() {
+        let storage_path = "test-storage/network_params_cached_until_expired";
+        std::fs::remove_dir_all(storage_path).ok();
+
+        // The offline client has the default protocol parameters, without a node
+        #[allow(unused_mut)]
+        let mut wallet_builder = Wallet::builder()
+            .with_secret_manager(SecretManager::Mnemonic(
+                MnemonicSecretManager::try_from_mnemonic(&Client::generate_mnemonic().unwrap()).unwrap(),
+            ))
+            .with_client_options(ClientOptions::new().with_offline_mode(ProtocolParameters::default()))
+            .with_coin_type(SHIMMER_COIN_TYPE);
+        #[cfg(feature = "storage")]
+        {
+            wallet_builder = wallet_builder.with_storage_path(storage_path);
+        }
+        let wallet = wallet_builder.finish().await.unwrap();
+        let account = wallet.create_account().finish().await.unwrap();
+        let network_params = account.refresh_network_params().await.unwrap();
+        assert_eq!(
+            network_params.token_supply,
+            ProtocolParameters::default().token_supply()
+        );
+
+        // Different from the parameters of the client, so they can only come from the cache
+        let cached = NetworkParams {
+            token_supply: network_params.token_supply - 1,
+            ..network_params
+        };
+        account.network_params.lock().await.replace((Instant::now(), cached));
+        assert_eq!(account.network_params().await.unwrap(), cached);
+        assert_eq!(account.network_params().await.unwrap(), cached);
+
+        // An expired entry is refreshed
+        let fetched_at = Instant::now() - NETWORK_PARAMS_CACHE_TTL - std::time::Duration::from_secs(1);
+        account.network_params.lock().await.replace((fetched_at, cached));
+        assert_eq!(account.network_params().await.unwrap(), network_params);
+        let (refreshed_at, refreshed) = account.network_params.lock().await.unwrap();
+        assert!(refreshed_at > fetched_at);
+        assert_eq!(refreshed, network_params);
+
+        drop(account);
+        drop(wallet);
+        std::fs::remove_dir_all(storage_path).ok();
+    }
+}
@@ -0,0 +1,99 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    types::block::output::{NftId, NftOutput, Output},
+    wallet::{account::Account, Error, Result},
+};
+
+/// IRC27 metadata of an NFT, stored in the immutable metadata feature of the NFT output.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Irc27Metadata {
+    standard: String,
+    /// The version of the IRC27 standard
+    pub version: String,
+    /// The MIME type of the NFT file, like `image/png`
+    #[serde(rename = "type")]
+    pub media_type: String,
+    /// The URI of the NFT file
+    pub uri: String,
+    /// The name of the NFT
+    pub name: String,
+    /// The name of the collection the NFT belongs to
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub collection_name: Option<String>,
+    /// The royalties of the NFT, the Bech32 addresses that receive a share of a sale, with their share from 0 to 1
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub royalties: BTreeMap<String, f64>,
+    /// The name of the issuer of the NFT
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub issuer_name: Option<String>,
+    /// A description of the NFT
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The attributes of the NFT
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attributes: Vec<Irc27Attribute>,
+}
+
+impl Irc27Metadata {
+    /// Parses the immutable metadata of an NFT, if it follows the IRC27 standard.
+    pub fn from_nft_metadata(metadata: &[u8]) -> Option<Self> {
+        serde_json::from_slice::<Self>(metadata)
+            .ok()
+            .filter(|metadata| metadata.standard == "IRC27")
+    }
+}
+
+/// An attribute of an NFT with IRC27 metadata.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Irc27Attribute {
+    /// The name of the attribute
+    pub trait_type: String,
+    /// The value of the attribute
+    pub value: serde_json::Value,
+}
+
+/// The decoded immutable metadata of an NFT, returned from [`Account::nft_metadata()`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "data", rename_all = "camelCase")]
+pub enum NftMetadata {
+    /// Metadata following the IRC27 standard
+    Irc27(Irc27Metadata),
+    /// Metadata that doesn't follow the IRC27 standard, hex encoded when serialized
+    Raw(#[serde(with = "crate::utils::serde::prefix_hex_bytes")] Vec<u8>),
+}
+
+impl NftMetadata {
+    /// Decodes the immutable metadata feature of an NFT output, `None` if it has none.
+    pub fn from_nft_output(nft_output: &NftOutput) -> Option<Self> {
+        let metadata = nft_output.immutable_features().metadata()?.data();
+
+        Some(Irc27Metadata::from_nft_metadata(metadata).map_or_else(|| Self::Raw(metadata.to_vec()), Self::Irc27))
+    }
+}
+
+impl Account {
+    /// Returns the decoded immutable metadata of an NFT owned by the account, `None` if it has no metadata feature.
+    pub async fn nft_metadata(&self, nft_id: NftId) -> Result<Option<NftMetadata>> {
+        let account_details = self.details().await;
+
+        let nft_output = account_details
+            .unspent_outputs()
+            .values()
+            .find_map(|output_data| match &output_data.output {
+                Output::Nft(nft_output) if nft_output.nft_id_non_null(&output_data.output_id) == nft_id => {
+                    Some(nft_output)
+                }
+                _ => None,
+            })
+            .ok_or(Error::NftNotFoundInUnspentOutputs)?;
+
+        Ok(NftMetadata::from_nft_output(nft_output))
+    }
+}
@@ -6,7 +6,7 @@ use std::collections::{HashMap, HashSet};
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    client::api::input_selection::minimum_storage_deposit_basic_output,
+    client::api::{input_selection::minimum_storage_deposit_basic_output, PreparedTransactionData},
     types::block::{
         address::Address,
         output::{
@@ -45,6 +45,10 @@ impl Account {
         let account_details = self.details().await;
 
         let local_time = self.client().get_time_checked().await?;
+        let mut micro_output_count = account_details
+            .dust_policy
+            .map(|dust_policy| dust_policy.micro_output_count(&account_details))
+            .unwrap_or_default();
 
         // Get outputs for the claim
         let mut output_ids_to_claim: HashSet<OutputId> = HashSet::new();
@@ -53,6 +57,12 @@ impl Account {
             .iter()
             .filter(|(_, o)| o.output.is_basic() || o.output.is_nft())
         {
+            // Outputs with native tokens that aren't allowed by the token policy need to be claimed explicitly
+            if let Some(token_policy) = &account_details.token_policy {
+                if token_policy.first_restricted_token(&output_data.output).is_some() {
+                    continue;
+                }
+            }
             // Don't use outputs that are locked for other transactions
             if !account_details.locked_outputs.contains(output_id) && account_details.outputs.contains_key(output_id) {
                 if let Some(unlock_conditions) = output_data.output.unlock_conditions() {
@@ -72,6 +82,23 @@ impl Account {
                             None,
                         )?
                     {
+                        // Outputs that would add dust to the account are declined, so they return to the sender
+                        let mut adds_micro_output = false;
+                        if let Some(dust_policy) = &account_details.dust_policy {
+                            let mut claimable_amount = output_data.output.amount();
+                            if !unlock_conditions.is_expired(local_time) {
+                                claimable_amount = claimable_amount.saturating_sub(
+                                    unlock_conditions
+                                        .storage_deposit_return()
+                                        .map(|s| s.amount())
+                                        .unwrap_or_default(),
+                                );
+                            }
+                            if dust_policy.declines(claimable_amount, micro_output_count) {
+                                continue;
+                            }
+                            adds_micro_output = claimable_amount < dust_policy.micro_amount;
+                        }
                         match outputs_to_claim {
                             OutputsToClaim::MicroTransactions => {
                                 if let Some(sdr) = unlock_conditions.storage_deposit_return() {
@@ -111,6 +138,10 @@ impl Account {
                                 output_ids_to_claim.insert(output_data.output_id);
                             }
                         }
+                        // The claimed micro outputs count towards the limit for the following ones
+                        if adds_micro_output && output_ids_to_claim.contains(&output_data.output_id) {
+                            micro_output_count += 1;
+                        }
                     }
                 }
             }
@@ -126,14 +157,14 @@ impl Account {
     /// additional inputs
     pub async fn get_basic_outputs_for_additional_inputs(&self) -> crate::wallet::Result<Vec<OutputData>> {
         log::debug!("[OUTPUT_CLAIMING] get_basic_outputs_for_additional_inputs");
-        #[cfg(feature = "participation")]
+        #[cfg(feature = "wallet-participation")]
         let voting_output = self.get_voting_output().await?;
         let account_details = self.details().await;
 
         // Get basic outputs only with AddressUnlockCondition and no other unlock condition
         let mut basic_outputs: Vec<OutputData> = Vec::new();
         for (output_id, output_data) in &account_details.unspent_outputs {
-            #[cfg(feature = "participation")]
+            #[cfg(feature = "wallet-participation")]
             if let Some(ref voting_output) = voting_output {
                 // Remove voting output from inputs, because we don't want to spent it to claim something else.
                 if output_data.output_id == voting_output.output_id {
@@ -161,21 +192,41 @@ impl Account {
     /// from [`Account::get_unlockable_outputs_with_additional_unlock_conditions()`].
     pub async fn claim_outputs(&self, output_ids_to_claim: Vec<OutputId>) -> crate::wallet::Result<Transaction> {
         log::debug!("[OUTPUT_CLAIMING] claim_outputs");
+        let prepared_transaction = self.prepare_claim_outputs(output_ids_to_claim).await?;
+        let claim_tx = self.sign_and_submit_transaction(prepared_transaction).await?;
+
+        log::debug!(
+            "[OUTPUT_CLAIMING] Claiming transaction created: block_id: {:?} tx_id: {:?}",
+            claim_tx.block_id,
+            claim_tx.transaction_id
+        );
+        Ok(claim_tx)
+    }
+
+    /// Function to prepare the transaction for
+    /// [Account.claim_outputs()](crate::account::Account.claim_outputs)
+    pub async fn prepare_claim_outputs(
+        &self,
+        output_ids_to_claim: Vec<OutputId>,
+    ) -> crate::wallet::Result<PreparedTransactionData> {
+        log::debug!("[OUTPUT_CLAIMING] prepare_claim_outputs");
         let basic_outputs = self.get_basic_outputs_for_additional_inputs().await?;
-        self.claim_outputs_internal(output_ids_to_claim, basic_outputs).await
+        self.prepare_claim_outputs_internal(output_ids_to_claim, basic_outputs)
+            .await
     }
 
-    /// Try to claim basic outputs that have additional unlock conditions to their [AddressUnlockCondition].
-    pub(crate) async fn claim_outputs_internal(
+    /// Prepare claiming basic outputs that have additional unlock conditions to their [AddressUnlockCondition].
+    pub(crate) async fn prepare_claim_outputs_internal(
         &self,
         output_ids_to_claim: Vec<OutputId>,
         mut possible_additional_inputs: Vec<OutputData>,
-    ) -> crate::wallet::Result<Transaction> {
-        log::debug!("[OUTPUT_CLAIMING] claim_outputs_internal");
+    ) -> crate::wallet::Result<PreparedTransactionData> {
+        log::debug!("[OUTPUT_CLAIMING] prepare_claim_outputs_internal");
 
         let current_time = self.client().get_time_checked().await?;
-        let rent_structure = self.client().get_rent_structure().await?;
-        let token_supply = self.client().get_token_supply().await?;
+        let network_params = self.network_params().await?;
+        let rent_structure = network_params.rent_structure;
+        let token_supply = network_params.token_supply;
 
         let account_details = self.details().await;
 
@@ -347,29 +398,21 @@ impl Account {
             })?;
         }
 
-        let claim_tx = self
-            .finish_transaction(
-                outputs_to_send,
-                Some(TransactionOptions {
-                    custom_inputs: Some(
-                        outputs_to_claim
-                            .iter()
-                            .map(|o| o.output_id)
-                            // add additional inputs
-                            .chain(additional_inputs)
-                            .collect::<Vec<OutputId>>(),
-                    ),
-                    ..Default::default()
-                }),
-            )
-            .await?;
-
-        log::debug!(
-            "[OUTPUT_CLAIMING] Claiming transaction created: block_id: {:?} tx_id: {:?}",
-            claim_tx.block_id,
-            claim_tx.transaction_id
-        );
-        Ok(claim_tx)
+        self.prepare_transaction(
+            outputs_to_send,
+            Some(TransactionOptions {
+                custom_inputs: Some(
+                    outputs_to_claim
+                        .iter()
+                        .map(|o| o.output_id)
+                        // add additional inputs
+                        .chain(additional_inputs)
+                        .collect::<Vec<OutputId>>(),
+                ),
+                ..Default::default()
+            }),
+        )
+        .await
     }
 }
 
@@ -1,14 +1,18 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use serde::{Deserialize, Serialize};
+
 #[cfg(feature = "ledger_nano")]
 use crate::client::secret::SecretManager;
 use crate::{
     client::api::PreparedTransactionData,
     types::block::{
+        address::{Address, Bech32Address},
         input::INPUT_COUNT_MAX,
         output::{
-            unlock_condition::AddressUnlockCondition, BasicOutputBuilder, NativeTokens, NativeTokensBuilder, Output,
+            unlock_condition::AddressUnlockCondition, BasicOutputBuilder, NativeToken, NativeTokens,
+            NativeTokensBuilder, Output, RentStructure, OUTPUT_COUNT_MAX,
         },
     },
 };
@@ -24,57 +28,46 @@ const MIN_OUTPUT_SIZE_IN_ESSENCE: usize = 46;
 
 #[cfg(feature = "ledger_nano")]
 use crate::wallet::account::constants::DEFAULT_LEDGER_OUTPUT_CONSOLIDATION_THRESHOLD;
+#[cfg(feature = "events")]
+use crate::wallet::events::types::{ConsolidationProgress, EventOrigin, TransactionProgressEvent, WalletEvent};
 use crate::wallet::{
     account::{
         constants::DEFAULT_OUTPUT_CONSOLIDATION_THRESHOLD,
-        operations::{helpers::time::can_output_be_unlocked_now, output_claiming::get_new_native_token_count},
-        types::{OutputData, Transaction},
-        Account, AddressWithUnspentOutputs, TransactionOptions,
+        operations::{output_claiming::get_new_native_token_count, utxo_health::should_consolidate_output},
+        types::Transaction,
+        Account, SyncOptions, TransactionOptions,
     },
-    Result,
+    Error, Result,
 };
 
-impl Account {
-    fn should_consolidate_output(
-        &self,
-        output_data: &OutputData,
-        current_time: u32,
-        account_addresses: &[AddressWithUnspentOutputs],
-    ) -> Result<bool> {
-        Ok(if let Output::Basic(basic_output) = &output_data.output {
-            let unlock_conditions = basic_output.unlock_conditions();
-
-            let is_time_locked = unlock_conditions.is_time_locked(current_time);
-            if is_time_locked {
-                // If the output is timelocked, then it cannot be consolidated.
-                return Ok(false);
-            }
-
-            let has_storage_deposit_return = unlock_conditions.storage_deposit_return().is_some();
-            let has_expiration = unlock_conditions.expiration().is_some();
-            let is_expired = unlock_conditions.is_expired(current_time);
-            if has_storage_deposit_return && (!has_expiration || !is_expired) {
-                // If the output has not expired and must return a storage deposit, then it cannot be consolidated.
-                return Ok(false);
-            }
-
-            can_output_be_unlocked_now(account_addresses, &[], output_data, current_time, None)?
-        } else {
-            false
-        })
-    }
+/// Parameters for the consolidation of outputs.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConsolidationParams {
+    /// Consolidate even if the amount of outputs is below the threshold.
+    #[serde(default)]
+    pub force: bool,
+    /// Minimum amount of outputs that need to be available for a consolidation, defaults to
+    /// [`DEFAULT_OUTPUT_CONSOLIDATION_THRESHOLD`] or the ledger nano default.
+    #[serde(default)]
+    pub output_consolidation_threshold: Option<usize>,
+    /// The address the consolidated outputs are sent to, defaults to the address of the first consolidated output.
+    #[serde(default)]
+    pub target_address: Option<Bech32Address>,
+    /// Create a separate output for every native token, so outputs with different native tokens can be consolidated
+    /// together instead of being skipped once [`NativeTokens::COUNT_MAX`] is reached. The base coin that isn't
+    /// needed for the storage deposits of the native token outputs goes to the first one. If the base coin doesn't
+    /// cover an output per native token, the native tokens are packed into as few outputs as possible.
+    #[serde(default)]
+    pub split_native_tokens: bool,
+}
 
+impl Account {
     /// Consolidate basic outputs with only an [AddressUnlockCondition] from an account by sending them to an own
     /// address again if the output amount is >= the output_consolidation_threshold. When `force` is set to `true`, the
     /// threshold is ignored. Only consolidates the amount of outputs that fit into a single transaction.
-    pub async fn consolidate_outputs(
-        &self,
-        force: bool,
-        output_consolidation_threshold: Option<usize>,
-    ) -> Result<Transaction> {
-        let prepared_transaction = self
-            .prepare_consolidate_outputs(force, output_consolidation_threshold)
-            .await?;
+    pub async fn consolidate_outputs(&self, params: ConsolidationParams) -> Result<Transaction> {
+        let prepared_transaction = self.prepare_consolidate_outputs(params).await?;
         let consolidation_tx = self.sign_and_submit_transaction(prepared_transaction).await?;
 
         log::debug!(
@@ -86,24 +79,99 @@ impl Account {
         Ok(consolidation_tx)
     }
 
+    /// Consolidates all outputs that [`Account::consolidate_outputs()`] would consolidate, also if they don't fit into
+    /// a single transaction. The transactions are sent one after another, each one also consolidates the output of the
+    /// previous one once it's included, so in the end a single output is left. The threshold only applies to the first
+    /// transaction. Emits a `TransactionProgressEvent::ConsolidationProgress` for every sent transaction. If it fails
+    /// after transactions were sent, their ids are returned in an [`Error::ConsolidationInterrupted`].
+    pub async fn consolidate_all_outputs(&self, params: ConsolidationParams) -> Result<Vec<Transaction>> {
+        let mut transactions: Vec<Transaction> = Vec::new();
+
+        loop {
+            let prepared_transaction = match self
+                .prepare_consolidate_outputs(ConsolidationParams {
+                    force: params.force || !transactions.is_empty(),
+                    ..params.clone()
+                })
+                .await
+            {
+                Ok(prepared_transaction) => prepared_transaction,
+                Err(Error::NoOutputsToConsolidate { .. }) if !transactions.is_empty() => break,
+                Err(err) => return Err(consolidation_interrupted(&transactions, err)),
+            };
+            // Only the output of the previous transaction is left
+            if !transactions.is_empty() && prepared_transaction.inputs_data.len() < 2 {
+                self.discard_prepared_transaction(&prepared_transaction)
+                    .await
+                    .map_err(|err| consolidation_interrupted(&transactions, err))?;
+                break;
+            }
+
+            let consolidated_outputs = prepared_transaction.inputs_data.len();
+            let transaction = self
+                .sign_and_submit_transaction(prepared_transaction)
+                .await
+                .map_err(|err| consolidation_interrupted(&transactions, err))?;
+            log::debug!(
+                "[OUTPUT_CONSOLIDATION] consolidation transaction {} created: tx_id: {:?}, consolidated outputs: {}",
+                transactions.len() + 1,
+                transaction.transaction_id,
+                consolidated_outputs
+            );
+
+            #[cfg(feature = "events")]
+            {
+                let account_index = self.details().await.index;
+                self.emit(
+                    account_index,
+                    WalletEvent::TransactionProgress(TransactionProgressEvent::ConsolidationProgress(
+                        ConsolidationProgress {
+                            transaction_number: transactions.len() + 1,
+                            transaction_id: transaction.transaction_id,
+                            consolidated_outputs,
+                        },
+                    )),
+                    EventOrigin::new("consolidate_all_outputs").with_transaction_id(transaction.transaction_id),
+                )
+                .await;
+            }
+
+            let transaction_id = transaction.transaction_id;
+            transactions.push(transaction);
+
+            // The output of the transaction can only be consolidated again once it's known
+            self.retry_transaction_until_included(&transaction_id, None, None)
+                .await
+                .map_err(|err| consolidation_interrupted(&transactions, err))?;
+            self.sync(Some(SyncOptions {
+                force_syncing: true,
+                ..self.default_sync_options().await
+            }))
+            .await
+            .map_err(|err| consolidation_interrupted(&transactions, err))?;
+        }
+
+        Ok(transactions)
+    }
+
     /// Function to prepare the transaction for
     /// [Account.consolidate_outputs()](crate::account::Account.consolidate_outputs)
-    pub async fn prepare_consolidate_outputs(
-        &self,
-        force: bool,
-        output_consolidation_threshold: Option<usize>,
-    ) -> Result<PreparedTransactionData> {
+    pub async fn prepare_consolidate_outputs(&self, params: ConsolidationParams) -> Result<PreparedTransactionData> {
         log::debug!("[OUTPUT_CONSOLIDATION] prepare consolidating outputs if needed");
-        #[cfg(feature = "participation")]
+        if let Some(target_address) = &params.target_address {
+            self.client().bech32_hrp_matches(target_address.hrp()).await?;
+        }
+        #[cfg(feature = "wallet-participation")]
         let voting_output = self.get_voting_output().await?;
         let current_time = self.client().get_time_checked().await?;
-        let token_supply = self.client().get_token_supply().await?;
+        let network_params = self.network_params().await?;
+        let token_supply = network_params.token_supply;
         let mut outputs_to_consolidate = Vec::new();
         let account_details = self.details().await;
         let account_addresses = &account_details.addresses_with_unspent_outputs[..];
 
         for (output_id, output_data) in account_details.unspent_outputs() {
-            #[cfg(feature = "participation")]
+            #[cfg(feature = "wallet-participation")]
             if let Some(ref voting_output) = voting_output {
                 // Remove voting output from inputs, because we want to keep its features and not consolidate it.
                 if output_data.output_id == voting_output.output_id {
@@ -111,16 +179,20 @@ impl Account {
                 }
             }
             let is_locked_output = account_details.locked_outputs.contains(output_id);
-            let should_consolidate_output =
-                self.should_consolidate_output(output_data, current_time, account_addresses)?;
+            let should_consolidate_output = should_consolidate_output(output_data, current_time, account_addresses)?;
             if !is_locked_output && should_consolidate_output {
                 outputs_to_consolidate.push(output_data.clone());
             }
         }
 
+        // Consolidate the micro outputs first, if there are more than fit into a transaction
+        if account_details.dust_policy.is_some() {
+            outputs_to_consolidate.sort_by_key(|output_data| output_data.output.amount());
+        }
+
         drop(account_details);
 
-        let output_consolidation_threshold = output_consolidation_threshold.unwrap_or({
+        let output_consolidation_threshold = params.output_consolidation_threshold.unwrap_or({
             match &*self.wallet.secret_manager.read().await {
                 #[cfg(feature = "ledger_nano")]
                 SecretManager::LedgerNano(_) => DEFAULT_LEDGER_OUTPUT_CONSOLIDATION_THRESHOLD,
@@ -130,7 +202,7 @@ impl Account {
 
         // only consolidate if the unlocked outputs are >= output_consolidation_threshold
         if outputs_to_consolidate.is_empty()
-            || (!force && outputs_to_consolidate.len() < output_consolidation_threshold)
+            || (!params.force && outputs_to_consolidate.len() < output_consolidation_threshold)
         {
             log::debug!(
                 "[OUTPUT_CONSOLIDATION] no consolidation needed, available_outputs: {}, consolidation_threshold: {}",
@@ -169,11 +241,17 @@ impl Account {
         let mut total_amount = 0;
         let mut custom_inputs = Vec::with_capacity(max_inputs.into());
         let mut total_native_tokens = NativeTokensBuilder::new();
+        // With split native tokens every native token gets its own output, next to the one for the base coin
+        let max_native_tokens: u16 = if params.split_native_tokens {
+            OUTPUT_COUNT_MAX - 1
+        } else {
+            NativeTokens::COUNT_MAX.into()
+        };
 
         for output_data in outputs_to_consolidate.iter().take(max_inputs.into()) {
             if let Some(native_tokens) = output_data.output.native_tokens() {
                 // Skip output if the max native tokens count would be exceeded
-                if get_new_native_token_count(&total_native_tokens, native_tokens)? > NativeTokens::COUNT_MAX.into() {
+                if get_new_native_token_count(&total_native_tokens, native_tokens)? > max_native_tokens.into() {
                     log::debug!("[OUTPUT_CONSOLIDATION] skipping output to not exceed the max native tokens count");
                     continue;
                 }
@@ -184,18 +262,204 @@ impl Account {
             custom_inputs.push(output_data.output_id);
         }
 
-        let consolidation_output = vec![
-            BasicOutputBuilder::new_with_amount(total_amount)
-                .add_unlock_condition(AddressUnlockCondition::new(outputs_to_consolidate[0].address))
-                .with_native_tokens(total_native_tokens.finish()?)
-                .finish_output(token_supply)?,
-        ];
+        let target_address = params
+            .target_address
+            .map_or(outputs_to_consolidate[0].address, |address| *address.inner());
+
+        let consolidation_outputs = consolidation_outputs(
+            total_amount,
+            total_native_tokens.finish_vec()?,
+            target_address,
+            params.split_native_tokens,
+            network_params.rent_structure,
+            token_supply,
+        )?;
 
         let options = Some(TransactionOptions {
             custom_inputs: Some(custom_inputs),
             ..Default::default()
         });
 
-        self.prepare_transaction(consolidation_output, options).await
+        self.prepare_transaction(consolidation_outputs, options).await
+    }
+}
+
+// Builds the outputs of a consolidation transaction. With `split_native_tokens` every native token gets its own output,
+// unless the base coin doesn't cover their storage deposits, then they're packed into as few outputs as possible.
+fn consolidation_outputs(
+    total_amount: u64,
+    native_tokens: Vec<NativeToken>,
+    target_address: Address,
+    split_native_tokens: bool,
+    rent_structure: RentStructure,
+    token_supply: u64,
+) -> Result<Vec<Output>> {
+    if !split_native_tokens || native_tokens.is_empty() {
+        return Ok(vec![BasicOutputBuilder::new_with_amount(total_amount)
+            .add_unlock_condition(AddressUnlockCondition::new(target_address))
+            .with_native_tokens(native_tokens)
+            .finish_output(token_supply)?]);
+    }
+
+    let mut outputs = Vec::new();
+    for native_tokens_per_output in [1, NativeTokens::COUNT_MAX.into()] {
+        outputs.clear();
+        for native_tokens in native_tokens.chunks(native_tokens_per_output) {
+            outputs.push(
+                BasicOutputBuilder::new_with_minimum_storage_deposit(rent_structure)
+                    .add_unlock_condition(AddressUnlockCondition::new(target_address))
+                    .with_native_tokens(native_tokens.iter().cloned())
+                    .finish_output(token_supply)?,
+            );
+        }
+        if outputs.iter().map(Output::amount).sum::<u64>() <= total_amount {
+            break;
+        }
+    }
+
+    let storage_deposits = outputs.iter().map(Output::amount).sum::<u64>();
+    let remaining_amount = total_amount
+        .checked_sub(storage_deposits)
+        .ok_or(Error::InsufficientFunds {
+            available: total_amount,
+            required: storage_deposits,
+        })?;
+
+    // The remaining base coin goes to the first output, so no output below the storage deposit is created
+    if let Output::Basic(first_output) = &outputs[0] {
+        outputs[0] = BasicOutputBuilder::from(first_output)
+            .with_amount(first_output.amount() + remaining_amount)
+            .finish_output(token_supply)?;
+    }
+
+    Ok(outputs)
+}
+
+// Keeps the ids of the transactions that were already sent by `Account::consolidate_all_outputs()`
+fn consolidation_interrupted(transactions: &[Transaction], error: Error) -> Error {
+    if transactions.is_empty() {
+        return error;
+    }
+    Error::ConsolidationInterrupted {
+        transaction_ids: transactions
+            .iter()
+            .map(|transaction| transaction.transaction_id)
+            .collect(),
+        error: Box::new(error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use primitive_types::U256;
+
+    use super::*;
+    use crate::types::block::{
+        address::Ed25519Address,
+        output::{Rent, TokenId},
+    };
+
+    const TOKEN_SUPPLY: u64 = 1_813_620_509_061_365;
+
+    fn address() -> Address {
+        Address::Ed25519(Ed25519Address::new([1; Ed25519Address::LENGTH]))
+    }
+
+    fn native_tokens(count: u8) -> Vec<NativeToken> {
+        (0..count)
+            .map(|byte| NativeToken::new(TokenId::new([byte; TokenId::LENGTH]), U256::from(10)).unwrap())
+            .collect()
+    }
+
+    fn storage_deposit(native_tokens: &[NativeToken]) -> u64 {
+        BasicOutputBuilder::new_with_minimum_storage_deposit(RentStructure::default())
+            .add_unlock_condition(AddressUnlockCondition::new(address()))
+            .with_native_tokens(native_tokens.iter().cloned())
+            .finish_output(TOKEN_SUPPLY)
+            .unwrap()
+            .amount()
+    }
+
+    #[test]
+    fn consolidation_outputs_single_output() {
+        let outputs = consolidation_outputs(
+            1_000_000,
+            native_tokens(3),
+            address(),
+            false,
+            RentStructure::default(),
+            TOKEN_SUPPLY,
+        )
+        .unwrap();
+
+        assert_eq!(outputs.len(), 1);
+        assert_eq!(outputs[0].amount(), 1_000_000);
+        assert_eq!(outputs[0].native_tokens().unwrap().len(), 3);
+        assert_eq!(
+            outputs[0].unlock_conditions().unwrap().address().unwrap().address(),
+            &address()
+        );
+    }
+
+    #[test]
+    fn consolidation_outputs_split_native_tokens() {
+        let outputs = consolidation_outputs(
+            1_000_000,
+            native_tokens(3),
+            address(),
+            true,
+            RentStructure::default(),
+            TOKEN_SUPPLY,
+        )
+        .unwrap();
+
+        assert_eq!(outputs.len(), 3);
+        assert!(outputs.iter().all(|output| output.native_tokens().unwrap().len() == 1));
+        assert_eq!(outputs.iter().map(Output::amount).sum::<u64>(), 1_000_000);
+        // The remaining base coin goes to the first output
+        assert_eq!(outputs[1].amount(), storage_deposit(&native_tokens(1)));
+        assert!(outputs
+            .iter()
+            .all(|output| output.amount() >= output.rent_cost(&RentStructure::default())));
+    }
+
+    #[test]
+    fn consolidation_outputs_packs_native_tokens_without_enough_base_coin() {
+        let native_tokens = native_tokens(3);
+        // Enough for a single output with all native tokens, but not for one per native token
+        let total_amount = storage_deposit(&native_tokens);
+        assert!(total_amount < 3 * storage_deposit(&native_tokens[..1]));
+
+        let outputs = consolidation_outputs(
+            total_amount,
+            native_tokens,
+            address(),
+            true,
+            RentStructure::default(),
+            TOKEN_SUPPLY,
+        )
+        .unwrap();
+
+        assert_eq!(outputs.len(), 1);
+        assert_eq!(outputs[0].amount(), total_amount);
+        assert_eq!(outputs[0].native_tokens().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn consolidation_outputs_insufficient_funds() {
+        let native_tokens = native_tokens(3);
+        let required = storage_deposit(&native_tokens);
+
+        assert!(matches!(
+            consolidation_outputs(
+                required - 1,
+                native_tokens,
+                address(),
+                true,
+                RentStructure::default(),
+                TOKEN_SUPPLY,
+            ),
+            Err(Error::InsufficientFunds { available, required: r }) if available == required - 1 && r == required
+        ));
     }
 }
@@ -0,0 +1,149 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{HashSet, VecDeque};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    types::block::{
+        address::{Address, Bech32Address},
+        output::{Output, OutputId},
+        payload::{
+            transaction::{TransactionEssence, TransactionId},
+            Payload,
+        },
+    },
+    wallet::{
+        account::{constants::MAX_OUTPUT_TRACE_ENTRIES, types::normalized::owner_address, Account},
+        Result,
+    },
+};
+
+/// The lineage of an output, returned from [`Account::trace_output()`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutputLineage {
+    /// The id of the traced output.
+    pub output_id: OutputId,
+    /// The traced output and the outputs created from it, in the order they were found.
+    pub entries: Vec<OutputLineageEntry>,
+    /// Whether the trace stopped early, because the maximum amount of entries was reached.
+    pub truncated: bool,
+}
+
+/// An output in an [`OutputLineage`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutputLineageEntry {
+    /// The id of the output.
+    pub output_id: OutputId,
+    /// The id of the output whose spending transaction created this output, `None` for the traced output.
+    pub parent: Option<OutputId>,
+    /// The amount of the output.
+    #[serde(with = "crate::utils::serde::string")]
+    pub amount: u64,
+    /// The address that owns the output.
+    pub address: Option<Bech32Address>,
+    /// Whether the output belongs to an address of the account. Only outputs of the account are traced further.
+    pub owned: bool,
+    /// The id of the transaction that spent the output, `None` if it's unspent.
+    pub spent_in: Option<TransactionId>,
+}
+
+impl Account {
+    /// Follows the spend chain of an output to find out where its funds went. For every output the transaction that
+    /// spent it is looked up in the stored transactions or requested from the node, and the outputs it created are
+    /// added to the lineage. Outputs that don't belong to the account are added, but not traced further.
+    pub async fn trace_output(&self, output_id: OutputId) -> Result<OutputLineage> {
+        log::debug!("[trace_output] {output_id}");
+        let bech32_hrp = self.network_params().await?.bech32_hrp;
+        let account_addresses = self
+            .addresses()
+            .await?
+            .into_iter()
+            .map(|address| *address.address.inner())
+            .collect::<HashSet<Address>>();
+
+        let mut entries = Vec::new();
+        let mut visited = HashSet::new();
+        let mut queue = VecDeque::from([(output_id, None)]);
+        let mut truncated = false;
+
+        while let Some((output_id, parent)) = queue.pop_front() {
+            if !visited.insert(output_id) {
+                continue;
+            }
+            if entries.len() >= MAX_OUTPUT_TRACE_ENTRIES {
+                truncated = true;
+                break;
+            }
+
+            let (output, spent_in) = self.output_with_spending_transaction(&output_id).await?;
+            let address = owner_address(&output);
+            let owned = address.map_or(false, |address| account_addresses.contains(&address));
+
+            if let Some(transaction_id) = spent_in.filter(|_| owned) {
+                for created_output_id in self.created_output_ids(&transaction_id).await? {
+                    queue.push_back((created_output_id, Some(output_id)));
+                }
+            }
+
+            entries.push(OutputLineageEntry {
+                output_id,
+                parent,
+                amount: output.amount(),
+                address: address.map(|address| Bech32Address::new(bech32_hrp, address)),
+                owned,
+                spent_in,
+            });
+        }
+
+        Ok(OutputLineage {
+            output_id,
+            entries,
+            truncated,
+        })
+    }
+
+    // Returns the output and the id of the transaction that spent it, stored data is preferred, but the metadata of
+    // spent outputs might be from before they got spent
+    async fn output_with_spending_transaction(&self, output_id: &OutputId) -> Result<(Output, Option<TransactionId>)> {
+        if let Some(output_data) = self.details().await.outputs().get(output_id) {
+            if !output_data.is_spent {
+                return Ok((output_data.output.clone(), None));
+            }
+            if let Some(transaction_id) = output_data.metadata.transaction_id_spent() {
+                return Ok((output_data.output.clone(), Some(*transaction_id)));
+            }
+        }
+
+        let output_with_metadata = self.client().get_output(output_id).await?;
+        let spent_in = output_with_metadata.metadata().transaction_id_spent().copied();
+        Ok((output_with_metadata.into_output(), spent_in))
+    }
+
+    // Returns the ids of the outputs created by a transaction, from the stored transactions or the included block
+    async fn created_output_ids(&self, transaction_id: &TransactionId) -> Result<Vec<OutputId>> {
+        let stored_essence = {
+            let account_details = self.details().await;
+            account_details
+                .transactions()
+                .get(transaction_id)
+                .or_else(|| account_details.incoming_transactions().get(transaction_id))
+                .map(|transaction| transaction.payload.essence().clone())
+        };
+        let essence = match stored_essence {
+            Some(essence) => essence,
+            None => match self.client().get_included_block(transaction_id).await?.payload() {
+                Some(Payload::Transaction(transaction_payload)) => transaction_payload.essence().clone(),
+                _ => return Ok(Vec::new()),
+            },
+        };
+        let TransactionEssence::Regular(essence) = essence;
+
+        Ok((0..essence.outputs().len())
+            .filter_map(|index| OutputId::new(*transaction_id, index as u16).ok())
+            .collect())
+    }
+}
@@ -8,9 +8,12 @@ use crate::{
     types::api::plugins::participation::types::{
         ParticipationEventId, ParticipationEventStatus, ParticipationEventType,
     },
+    utils::unix_timestamp_now,
     wallet::account::{
-        operations::participation::ParticipationEventWithNodes,
-        types::participation::ParticipationEventRegistrationOptions, Account,
+        constants::PARTICIPATION_EVENT_STATUS_REFRESH_INTERVAL,
+        operations::participation::{CachedParticipationEventStatus, ParticipationEventWithNodes},
+        types::participation::ParticipationEventRegistrationOptions,
+        Account,
     },
 };
 
@@ -126,11 +129,143 @@ impl Account {
         Ok(client.events(event_type).await?.event_ids)
     }
 
-    /// Retrieves the latest status of a given participation event.
+    /// Retrieves the latest status of a given participation event, see
+    /// [`Account::get_cached_participation_event_status()`].
     pub async fn get_participation_event_status(
         &self,
         id: &ParticipationEventId,
+    ) -> crate::wallet::Result<ParticipationEventStatus> {
+        Ok(self.get_cached_participation_event_status(id).await?.status)
+    }
+
+    /// Retrieves the latest status of a given participation event together with the time it was fetched. The status is
+    /// cached in storage and only fetched again from the event nodes once it's older than the refresh interval,
+    /// statuses of ended events are never fetched again. If the event nodes can't be reached, the cached status is
+    /// returned and marked as stale.
+    pub async fn get_cached_participation_event_status(
+        &self,
+        id: &ParticipationEventId,
+    ) -> crate::wallet::Result<CachedParticipationEventStatus> {
+        let account_index = self.details().await.index;
+        let cached_status = self
+            .wallet
+            .storage_manager
+            .read()
+            .await
+            .get_cached_participation_event_statuses(account_index)
+            .await?
+            .remove(id);
+        let time_now = unix_timestamp_now().as_secs() as u32;
+
+        if let Some(cached_status) = &cached_status {
+            if is_cached_status_current(cached_status, time_now) {
+                return Ok(cached_status.clone());
+            }
+        }
+
+        match self.fetch_participation_event_status(id).await {
+            Ok(status) => {
+                let status = CachedParticipationEventStatus {
+                    status,
+                    fetched_at: time_now,
+                    stale: false,
+                };
+                self.wallet
+                    .storage_manager
+                    .read()
+                    .await
+                    .set_cached_participation_event_status(account_index, *id, status.clone())
+                    .await?;
+                Ok(status)
+            }
+            Err(e) => {
+                log::debug!("[get_cached_participation_event_status] fetching the status of {id} failed: {e}");
+                stale_status_or_error(cached_status, e)
+            }
+        }
+    }
+
+    async fn fetch_participation_event_status(
+        &self,
+        id: &ParticipationEventId,
     ) -> crate::wallet::Result<ParticipationEventStatus> {
         Ok(self.get_client_for_event(id).await?.event_status(id, None).await?)
     }
 }
+
+/// Returns whether a cached status can still be used at `time_now`, without fetching it again.
+fn is_cached_status_current(cached_status: &CachedParticipationEventStatus, time_now: u32) -> bool {
+    cached_status.status.status() == "ended"
+        || time_now.saturating_sub(cached_status.fetched_at) < PARTICIPATION_EVENT_STATUS_REFRESH_INTERVAL
+}
+
+/// Falls back to the cached status, marked as stale, if the status couldn't be fetched.
+fn stale_status_or_error(
+    cached_status: Option<CachedParticipationEventStatus>,
+    error: crate::wallet::Error,
+) -> crate::wallet::Result<CachedParticipationEventStatus> {
+    match cached_status {
+        Some(cached_status) => Ok(CachedParticipationEventStatus {
+            stale: true,
+            ..cached_status
+        }),
+        None => Err(error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cached_status(status: &str, fetched_at: u32) -> CachedParticipationEventStatus {
+        CachedParticipationEventStatus {
+            status: serde_json::from_value(serde_json::json!({
+                "milestoneIndex": 42,
+                "status": status,
+                "checksum": "",
+            }))
+            .unwrap(),
+            fetched_at,
+            stale: false,
+        }
+    }
+
+    #[test]
+    fn cached_status_refresh() {
+        let fetched_at = 1_700_000_000;
+        let holding = cached_status("holding", fetched_at);
+        assert!(is_cached_status_current(&holding, fetched_at));
+        assert!(is_cached_status_current(
+            &holding,
+            fetched_at + PARTICIPATION_EVENT_STATUS_REFRESH_INTERVAL - 1
+        ));
+        assert!(!is_cached_status_current(
+            &holding,
+            fetched_at + PARTICIPATION_EVENT_STATUS_REFRESH_INTERVAL
+        ));
+        // A clock that went backwards doesn't make the status outdated
+        assert!(is_cached_status_current(&holding, fetched_at - 1));
+
+        // Ended events don't change anymore
+        let ended = cached_status("ended", fetched_at);
+        assert!(is_cached_status_current(&ended, u32::MAX));
+    }
+
+    #[test]
+    fn stale_cached_status() {
+        let status = stale_status_or_error(
+            Some(cached_status("holding", 1_700_000_000)),
+            crate::wallet::Error::Voting("unreachable".to_string()),
+        )
+        .unwrap();
+        assert!(status.stale);
+        assert_eq!(status.fetched_at, 1_700_000_000);
+        assert_eq!(status.status.status(), "holding");
+
+        // Without a cached status, the error is returned
+        assert!(matches!(
+            stale_status_or_error(None, crate::wallet::Error::Voting("unreachable".to_string())),
+            Err(crate::wallet::Error::Voting(_))
+        ));
+    }
+}
@@ -9,6 +9,7 @@
 // If the user has designated funds to vote with, the resulting output MUST NOT be used for input selection.
 
 pub(crate) mod event;
+pub(crate) mod rewards;
 pub(crate) mod voting;
 pub(crate) mod voting_power;
 
@@ -21,7 +22,10 @@ use crate::{
     types::{
         api::plugins::participation::{
             responses::TrackedParticipation,
-            types::{ParticipationEventData, ParticipationEventId, Participations, PARTICIPATION_TAG},
+            types::{
+                ParticipationEventData, ParticipationEventId, ParticipationEventStatus, Participations,
+                PARTICIPATION_TAG,
+            },
         },
         block::output::{unlock_condition::UnlockCondition, Output, OutputId},
     },
@@ -36,6 +40,22 @@ use crate::{
 pub struct AccountParticipationOverview {
     /// Output participations for events.
     pub participations: HashMap<ParticipationEventId, HashMap<OutputId, TrackedParticipation>>,
+    /// Whether the overview was restored from the cache, because the event nodes couldn't be reached.
+    #[serde(default)]
+    pub stale: bool,
+}
+
+/// The status of a participation event together with the time it was fetched from the event nodes.
+#[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CachedParticipationEventStatus {
+    /// The status of the event.
+    pub status: ParticipationEventStatus,
+    /// Unix timestamp in seconds when the status was fetched.
+    pub fetched_at: u32,
+    /// Whether the status is older than the refresh interval, because the event nodes couldn't be reached.
+    #[serde(default)]
+    pub stale: bool,
 }
 
 /// A participation event with the provided client nodes.
@@ -51,12 +71,66 @@ pub struct ParticipationEventWithNodes {
 
 impl Account {
     /// Calculates the voting overview of an account. If event_ids are provided, only return outputs and tracked
-    /// participations for them.
+    /// participations for them. If the event nodes can't be reached, the last overview of all events is restored from
+    /// the cache and marked as stale.
     pub async fn get_participation_overview(
         &self,
         event_ids: Option<Vec<ParticipationEventId>>,
+    ) -> Result<AccountParticipationOverview> {
+        self.get_participation_overview_with_clients(event_ids, &mut HashMap::new())
+            .await
+    }
+
+    // Like `get_participation_overview()`, but reuses the clients for the events, so the overviews of multiple
+    // accounts don't build a client per account and event
+    pub(crate) async fn get_participation_overview_with_clients(
+        &self,
+        event_ids: Option<Vec<ParticipationEventId>>,
+        event_clients: &mut HashMap<ParticipationEventId, Client>,
     ) -> Result<AccountParticipationOverview> {
         log::debug!("[get_participation_overview]");
+        let account_index = self.details().await.index;
+
+        match self
+            .fetch_participation_overview(event_ids.as_ref(), event_clients)
+            .await
+        {
+            Ok(overview) => {
+                // Only the complete overview is cached, so it can be filtered for any events later
+                if event_ids.is_none() {
+                    self.wallet
+                        .storage_manager
+                        .read()
+                        .await
+                        .set_cached_participation_overview(account_index, &overview)
+                        .await?;
+                }
+                Ok(overview)
+            }
+            Err(e) => {
+                let cached_overview = self
+                    .wallet
+                    .storage_manager
+                    .read()
+                    .await
+                    .get_cached_participation_overview(account_index)
+                    .await?;
+                match cached_overview {
+                    Some(overview) => {
+                        log::debug!("[get_participation_overview] using stale overview: {e}");
+                        Ok(stale_overview(overview, event_ids.as_deref()))
+                    }
+                    None => Err(e),
+                }
+            }
+        }
+    }
+
+    async fn fetch_participation_overview(
+        &self,
+        event_ids: Option<&Vec<ParticipationEventId>>,
+        event_clients: &mut HashMap<ParticipationEventId, Client>,
+    ) -> Result<AccountParticipationOverview> {
         // TODO: Could use the address endpoint in the future when https://github.com/iotaledger/inx-participation/issues/50 is done.
 
         let mut spent_cached_outputs = self
@@ -91,7 +165,7 @@ impl Account {
             if let Ok(participations) = Participations::from_bytes(&mut metadata.data()) {
                 for participation in participations.participations {
                     // Skip events that aren't in `event_ids` if not None
-                    if let Some(event_ids) = event_ids.as_ref() {
+                    if let Some(event_ids) = event_ids {
                         if !event_ids.contains(&participation.event_id) {
                             continue;
                         }
@@ -117,7 +191,7 @@ impl Account {
         for (output_id, output_status_response) in &spent_cached_outputs {
             for (event_id, participation) in &output_status_response.participations {
                 // Skip events that aren't in `event_ids` if not None
-                if let Some(event_ids) = event_ids.as_ref() {
+                if let Some(event_ids) = event_ids {
                     if !event_ids.contains(event_id) {
                         continue;
                     }
@@ -138,7 +212,10 @@ impl Account {
                 "[get_participation_overview] requesting {} outputs for event {event_id}",
                 output_ids.len()
             );
-            let event_client = self.get_client_for_event(&event_id).await?;
+            let event_client = match event_clients.entry(event_id) {
+                Entry::Occupied(entry) => entry.get().clone(),
+                Entry::Vacant(entry) => entry.insert(self.get_client_for_event(&event_id).await?).clone(),
+            };
 
             for output_id_chunk in output_ids.chunks(100).map(|x| x.to_vec()) {
                 let mut tasks = Vec::new();
@@ -181,7 +258,7 @@ impl Account {
                             }
                             for (event_id, participation) in status.participations {
                                 // Skip events that aren't in `event_ids` if not None
-                                if let Some(event_ids) = event_ids.as_ref() {
+                                if let Some(event_ids) = event_ids {
                                     if !event_ids.contains(&event_id) {
                                         continue;
                                     }
@@ -217,7 +294,10 @@ impl Account {
                 .await?;
         }
 
-        Ok(AccountParticipationOverview { participations })
+        Ok(AccountParticipationOverview {
+            participations,
+            stale: false,
+        })
     }
 
     /// Returns the voting output ("PARTICIPATION" tag).
@@ -314,6 +394,20 @@ fn is_valid_participation_output(output: &Output) -> bool {
     }
 }
 
+/// Marks a cached overview as stale and only keeps the participations of `event_ids`, if provided.
+fn stale_overview(
+    mut overview: AccountParticipationOverview,
+    event_ids: Option<&[ParticipationEventId]>,
+) -> AccountParticipationOverview {
+    if let Some(event_ids) = event_ids {
+        overview
+            .participations
+            .retain(|event_id, _| event_ids.contains(event_id));
+    }
+    overview.stale = true;
+    overview
+}
+
 #[cfg(test)]
 impl ParticipationEventWithNodes {
     pub fn mock() -> Self {
@@ -324,3 +418,26 @@ impl ParticipationEventWithNodes {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stale_cached_overview() {
+        let event_ids = [ParticipationEventId::new([1; 32]), ParticipationEventId::new([2; 32])];
+        let overview = AccountParticipationOverview {
+            participations: event_ids.iter().map(|event_id| (*event_id, HashMap::new())).collect(),
+            stale: false,
+        };
+
+        let stale = stale_overview(overview.clone(), None);
+        assert!(stale.stale);
+        assert_eq!(stale.participations.len(), 2);
+
+        // Only the requested events are restored from the complete cached overview
+        let stale = stale_overview(overview, Some(&event_ids[1..]));
+        assert!(stale.stale);
+        assert_eq!(stale.participations.keys().collect::<Vec<_>>(), vec![&event_ids[1]]);
+    }
+}
@@ -0,0 +1,183 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{hash_map::Entry, HashMap, HashSet};
+
+use super::is_valid_participation_output;
+use crate::{
+    types::{
+        api::plugins::participation::types::{AddressStakingStatus, ParticipationEventId, ParticipationEventPayload},
+        block::address::{Address, Bech32Address, Hrp},
+    },
+    wallet::{
+        account::{types::ParticipationRewards, Account},
+        task,
+    },
+};
+
+impl Account {
+    /// Requests the staking rewards of the addresses that hold unspent voting outputs from the nodes of the staking
+    /// events and keeps them in memory for the [`AccountBalance`](crate::wallet::account::types::AccountBalance). The
+    /// rewards of the addresses are summed up per event. If the nodes of an event can't be reached, the rewards of the
+    /// previous sync are kept for it.
+    pub(crate) async fn sync_participation_rewards(&self) -> crate::wallet::Result<()> {
+        log::debug!("[SYNC] sync_participation_rewards");
+        let addresses = self
+            .details()
+            .await
+            .unspent_outputs
+            .values()
+            .filter(|output_data| is_valid_participation_output(&output_data.output))
+            .map(|output_data| output_data.address)
+            .collect::<HashSet<_>>();
+
+        let mut rewards = HashMap::new();
+        if addresses.is_empty() {
+            *self.participation_rewards.lock().await = rewards;
+            return Ok(());
+        }
+
+        let bech32_hrp = self.client().get_bech32_hrp().await?;
+        let staking_event_ids = self
+            .get_participation_events()
+            .await?
+            .into_values()
+            .filter(|event| matches!(event.data.payload(), ParticipationEventPayload::StakingEventPayload(_)))
+            .map(|event| event.id)
+            .collect::<Vec<_>>();
+
+        let previous_rewards = self.participation_rewards.lock().await.clone();
+        for event_id in staking_event_ids {
+            match self
+                .request_event_staking_statuses(&event_id, bech32_hrp, &addresses)
+                .await
+            {
+                Ok(staking_statuses) => add_event_rewards(&mut rewards, &event_id, &staking_statuses),
+                Err(err) => {
+                    log::warn!("[SYNC] requesting participation rewards for event {event_id} failed: {err}");
+                    if let Some(event_rewards) = previous_rewards.get(&event_id) {
+                        rewards.insert(event_id, event_rewards.clone());
+                    }
+                }
+            }
+        }
+
+        *self.participation_rewards.lock().await = rewards;
+        Ok(())
+    }
+
+    // Requests the staking status of all addresses concurrently from the nodes of the event.
+    async fn request_event_staking_statuses(
+        &self,
+        event_id: &ParticipationEventId,
+        bech32_hrp: Hrp,
+        addresses: &HashSet<Address>,
+    ) -> crate::wallet::Result<Vec<AddressStakingStatus>> {
+        let event_client = self.get_client_for_event(event_id).await?;
+        let addresses = addresses.iter().copied().collect::<Vec<_>>();
+
+        let mut staking_statuses = Vec::with_capacity(addresses.len());
+        for addresses_chunk in addresses.chunks(100) {
+            let tasks = addresses_chunk.iter().map(|address| {
+                let event_client = event_client.clone();
+                let address = Bech32Address::new(bech32_hrp, *address);
+                async move { task::spawn(async move { event_client.address_staking_status(address).await }).await }
+            });
+            for result in futures::future::try_join_all(tasks).await? {
+                staking_statuses.push(result?);
+            }
+        }
+
+        Ok(staking_statuses)
+    }
+}
+
+// Sums up the rewards of the addresses for an event. The node returns the rewards of all staking events it tracks, so
+// only the ones for the requested event are added.
+fn add_event_rewards(
+    rewards: &mut HashMap<ParticipationEventId, ParticipationRewards>,
+    event_id: &ParticipationEventId,
+    staking_statuses: &[AddressStakingStatus],
+) {
+    let event_id_string = event_id.to_string();
+    for staking_status in staking_statuses {
+        let Some(status) = staking_status.rewards.get(&event_id_string) else {
+            continue;
+        };
+        let address_rewards = ParticipationRewards {
+            amount: status.amount,
+            symbol: status.symbol.clone(),
+            minimum_reached: status.minimum_reached,
+            milestone_index: staking_status.milestone_index,
+        };
+        match rewards.entry(*event_id) {
+            Entry::Occupied(mut entry) => *entry.get_mut() += address_rewards,
+            Entry::Vacant(entry) => {
+                entry.insert(address_rewards);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::api::plugins::participation::types::StakingStatus;
+
+    fn staking_status(rewards: Vec<(ParticipationEventId, u64, bool)>, milestone_index: u32) -> AddressStakingStatus {
+        AddressStakingStatus {
+            rewards: rewards
+                .into_iter()
+                .map(|(event_id, amount, minimum_reached)| {
+                    (
+                        event_id.to_string(),
+                        StakingStatus {
+                            amount,
+                            symbol: "SMR".to_string(),
+                            minimum_reached,
+                        },
+                    )
+                })
+                .collect(),
+            milestone_index,
+        }
+    }
+
+    #[test]
+    fn event_rewards_are_summed_up() {
+        let event_id = ParticipationEventId::new([1; 32]);
+        let other_event_id = ParticipationEventId::new([2; 32]);
+        let mut rewards = HashMap::new();
+
+        add_event_rewards(
+            &mut rewards,
+            &event_id,
+            &[
+                staking_status(vec![(event_id, 10, false), (other_event_id, 1_000, true)], 5),
+                staking_status(vec![(event_id, 20, true)], 7),
+                staking_status(vec![(other_event_id, 1_000, true)], 9),
+            ],
+        );
+
+        assert_eq!(rewards.len(), 1);
+        assert_eq!(
+            rewards[&event_id],
+            ParticipationRewards {
+                amount: 30,
+                symbol: "SMR".to_string(),
+                minimum_reached: true,
+                milestone_index: 7,
+            }
+        );
+    }
+
+    #[test]
+    fn event_without_rewards_is_skipped() {
+        let event_id = ParticipationEventId::new([1; 32]);
+        let mut rewards = HashMap::new();
+
+        add_event_rewards(&mut rewards, &event_id, &[staking_status(Vec::new(), 5)]);
+
+        assert!(rewards.is_empty());
+    }
+}
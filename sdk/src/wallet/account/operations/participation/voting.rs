@@ -99,7 +99,7 @@ impl Account {
                 Feature::Tag(TagFeature::new(PARTICIPATION_TAG)?),
                 Feature::Metadata(MetadataFeature::new(participation_bytes.clone())?),
             ])
-            .finish_output(self.client().get_token_supply().await?)?;
+            .finish_output(self.network_params().await?.token_supply)?;
 
         self.prepare_transaction(
             vec![new_output],
@@ -175,7 +175,7 @@ impl Account {
                 Feature::Tag(TagFeature::new(PARTICIPATION_TAG)?),
                 Feature::Metadata(MetadataFeature::new(participation_bytes.clone())?),
             ])
-            .finish_output(self.client().get_token_supply().await?)?;
+            .finish_output(self.network_params().await?.token_supply)?;
 
         self.prepare_transaction(
             vec![new_output],
@@ -1,6 +1,8 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::cmp::Ordering;
+
 use crate::{
     client::api::PreparedTransactionData,
     types::{
@@ -40,6 +42,11 @@ impl Account {
     /// cached event information, checks event milestones in there against latest network milestone).
     /// Prioritizes consuming outputs that are designated for voting but don't have any metadata (only possible if user
     /// increases voting power then increases again immediately after).
+    ///
+    /// With [`Account::set_auto_revote()`], the participations of the replaced voting output are re-applied in the
+    /// same transaction, so calling `Vote` again isn't needed, and the re-applied events are noted in the transaction,
+    /// also when it's only prepared. Participations that were withdrawn before, by removing the whole voting power or
+    /// stopping to participate, are never re-applied.
     pub async fn increase_voting_power(&self, amount: u64) -> Result<Transaction> {
         let prepared = self.prepare_increase_voting_power(amount).await?;
         self.sign_and_submit_transaction(prepared).await
@@ -48,7 +55,7 @@ impl Account {
     /// Function to prepare the transaction for
     /// [Account.increase_voting_power()](crate::account::Account.increase_voting_power)
     pub async fn prepare_increase_voting_power(&self, amount: u64) -> Result<PreparedTransactionData> {
-        let token_supply = self.client().get_token_supply().await?;
+        let token_supply = self.network_params().await?.token_supply;
 
         let (new_output, tx_options) = match self.get_voting_output().await? {
             Some(current_output_data) => {
@@ -56,7 +63,7 @@ impl Account {
 
                 // TODO checked addition
 
-                let (new_output, tagged_data_payload) = self
+                let (new_output, tagged_data_payload, note) = self
                     .new_voting_output_and_tagged_data(output, output.amount() + amount, token_supply)
                     .await?;
 
@@ -66,6 +73,7 @@ impl Account {
                         // Use the previous voting output and additionally other for the additional amount.
                         mandatory_inputs: Some(vec![current_output_data.output_id]),
                         tagged_data_payload: Some(tagged_data_payload),
+                        note,
                         ..Default::default()
                     }),
                 )
@@ -98,6 +106,9 @@ impl Account {
     /// milestones in there against latest network milestone).
     /// Prioritizes consuming outputs that are designated for voting but don't have any metadata (only possible if user
     /// increases voting power then decreases immediately after).
+    ///
+    /// With [`Account::set_auto_revote()`], the participations are re-applied like for
+    /// [`Account::increase_voting_power()`], unless the whole voting power is removed.
     pub async fn decrease_voting_power(&self, amount: u64) -> Result<Transaction> {
         let prepared = self.prepare_decrease_voting_power(amount).await?;
         self.sign_and_submit_transaction(prepared).await
@@ -106,7 +117,7 @@ impl Account {
     /// Function to prepare the transaction for
     /// [Account.decrease_voting_power()](crate::account::Account.decrease_voting_power)
     pub async fn prepare_decrease_voting_power(&self, amount: u64) -> Result<PreparedTransactionData> {
-        let token_supply = self.client().get_token_supply().await?;
+        let token_supply = self.network_params().await?.token_supply;
         let current_output_data = self
             .get_voting_output()
             .await?
@@ -115,20 +126,21 @@ impl Account {
 
         // TODO what is amount > output.amount() ?
         // If the amount to decrease is the amount of the output, then we just remove the features.
-        let (new_output, tagged_data_payload) = if amount == output.amount() {
+        let (new_output, tagged_data_payload, note) = if amount == output.amount() {
             (
                 BasicOutputBuilder::from(output)
                     .clear_features()
                     .finish_output(token_supply)?,
                 None,
+                None,
             )
         } else {
             // TODO checked subtraction
-            let (new_output, tagged_data_payload) = self
+            let (new_output, tagged_data_payload, note) = self
                 .new_voting_output_and_tagged_data(output, output.amount() - amount, token_supply)
                 .await?;
 
-            (new_output, Some(tagged_data_payload))
+            (new_output, Some(tagged_data_payload), note)
         };
 
         self.prepare_transaction(
@@ -138,20 +150,71 @@ impl Account {
                 // the remainder to reach the minimum required storage deposit.
                 mandatory_inputs: Some(vec![current_output_data.output_id]),
                 tagged_data_payload,
+                note,
                 ..Default::default()
             }),
         )
         .await
     }
 
+    /// Sets an account's "voting power" to the given target amount, by increasing or decreasing it by the difference to
+    /// the current voting power. The participations of the voting output are kept, like with
+    /// [`Account::increase_voting_power()`] and [`Account::decrease_voting_power()`].
+    ///
+    /// Returns `None` if the voting power already equals the target amount.
+    pub async fn set_voting_power(&self, target_amount: u64) -> Result<Option<Transaction>> {
+        match self.prepare_set_voting_power(target_amount).await? {
+            Some(prepared) => Ok(Some(self.sign_and_submit_transaction(prepared).await?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Function to prepare the transaction for
+    /// [Account.set_voting_power()](crate::account::Account.set_voting_power)
+    pub async fn prepare_set_voting_power(&self, target_amount: u64) -> Result<Option<PreparedTransactionData>> {
+        let current_amount = self.get_voting_power().await?;
+        log::debug!("[prepare_set_voting_power] current: {current_amount}, target: {target_amount}");
+
+        match target_amount.cmp(&current_amount) {
+            Ordering::Greater => Ok(Some(
+                self.prepare_increase_voting_power(target_amount - current_amount)
+                    .await?,
+            )),
+            Ordering::Less => Ok(Some(
+                self.prepare_decrease_voting_power(current_amount - target_amount)
+                    .await?,
+            )),
+            Ordering::Equal => Ok(None),
+        }
+    }
+
+    /// Sets whether the participations are re-applied automatically when the voting power changes. The participations
+    /// of the replaced voting output, without ended events, are added to the new voting output in the same transaction
+    /// and noted in it.
+    pub async fn set_auto_revote(&self, auto_revote: bool) -> Result<()> {
+        let mut account_details = self.details_mut().await;
+        account_details.auto_revote = auto_revote;
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+        Ok(())
+    }
+
+    /// Returns whether the participations are re-applied automatically when the voting power changes.
+    pub async fn auto_revote(&self) -> bool {
+        self.details().await.auto_revote
+    }
+
+    // Also returns the auto revote note for the transaction, if the participations of the replaced output are
+    // re-applied
     async fn new_voting_output_and_tagged_data(
         &self,
         output: &BasicOutput,
         amount: u64,
         token_supply: u64,
-    ) -> Result<(Output, TaggedDataPayload)> {
+    ) -> Result<(Output, TaggedDataPayload, Option<String>)> {
         let mut output_builder = BasicOutputBuilder::from(output).with_amount(amount);
         let mut participation_bytes = output.features().metadata().map(|m| m.data()).unwrap_or(&[]);
+        let mut note = None;
 
         let participation_bytes = if let Ok(mut participations) = Participations::from_bytes(&mut participation_bytes) {
             // Remove ended participations.
@@ -161,6 +224,10 @@ impl Account {
 
             output_builder = output_builder.replace_feature(MetadataFeature::new(participation_bytes.clone())?);
 
+            if self.details().await.auto_revote {
+                note = revote_note(&participations);
+            }
+
             participation_bytes
         } else {
             // TODO participation bytes are incorrect, should we really just ignore?
@@ -170,6 +237,56 @@ impl Account {
         Ok((
             output_builder.finish_output(token_supply)?,
             TaggedDataPayload::new(PARTICIPATION_TAG.as_bytes().to_vec(), participation_bytes.to_vec())?,
+            note,
         ))
     }
 }
+
+// The note of a transaction that re-applied participations, `None` if there are none
+fn revote_note(participations: &Participations) -> Option<String> {
+    if participations.participations.is_empty() {
+        return None;
+    }
+    let event_ids = participations
+        .participations
+        .iter()
+        .map(|participation| participation.event_id.to_string())
+        .collect::<Vec<_>>();
+
+    Some(format!(
+        "auto revote: re-applied participations for {}",
+        event_ids.join(", ")
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::api::plugins::participation::types::{Participation, ParticipationEventId};
+
+    #[test]
+    fn auto_revote_note() {
+        assert_eq!(revote_note(&Participations { participations: vec![] }), None);
+
+        let participations = Participations {
+            participations: vec![
+                Participation {
+                    event_id: ParticipationEventId::new([1; 32]),
+                    answers: vec![0],
+                },
+                Participation {
+                    event_id: ParticipationEventId::new([2; 32]),
+                    answers: vec![1],
+                },
+            ],
+        };
+        assert_eq!(
+            revote_note(&participations),
+            Some(format!(
+                "auto revote: re-applied participations for {}, {}",
+                ParticipationEventId::new([1; 32]),
+                ParticipationEventId::new([2; 32])
+            ))
+        );
+    }
+}
@@ -0,0 +1,102 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    types::block::{
+        address::{Address, AliasAddress, Bech32Address},
+        output::{AliasId, Output},
+    },
+    wallet::{
+        account::{Account, AccountDetails},
+        Error, Result,
+    },
+};
+
+impl AccountDetails {
+    // Returns the state controller address of the alias, if the alias output is an unspent output of the account and
+    // its state controller is one of the account addresses
+    pub(crate) fn alias_state_controller(&self, alias_id: AliasId) -> Option<Address> {
+        self.unspent_outputs
+            .values()
+            .find_map(|output_data| match &output_data.output {
+                Output::Alias(alias_output) if alias_output.alias_id_non_null(&output_data.output_id) == alias_id => {
+                    let state_controller = *alias_output.state_controller_address();
+                    self.public_addresses
+                        .iter()
+                        .chain(self.internal_addresses.iter())
+                        .any(|address| address.address.inner == state_controller)
+                        .then_some(state_controller)
+                }
+                _ => None,
+            })
+    }
+
+    /// Returns the address of the primary alias, if one is set and the account still controls its alias output. The
+    /// alias output can have been transitioned to another state controller or destroyed since it was set.
+    pub(crate) fn primary_alias_address(&self) -> Option<Address> {
+        let alias_id = self.primary_alias?;
+        if self.alias_state_controller(alias_id).is_none() {
+            log::warn!("[primary_alias] alias {alias_id} isn't controlled by the account anymore, it's ignored");
+            return None;
+        }
+        Some(Address::Alias(AliasAddress::from(alias_id)))
+    }
+}
+
+impl Account {
+    /// Sets the primary alias of the account, so the funds of the account are held under the alias address, like for
+    /// a treasury controlled by an organization. The alias output must be an unspent output of the account, with one
+    /// of the account addresses as state controller. `None` removes it.
+    ///
+    /// With a primary alias, syncing also requests the basic and NFT outputs of the alias address, independent of the
+    /// [`AliasSyncOptions`](crate::wallet::account::AliasSyncOptions) and `sync_only_most_basic_outputs`. The
+    /// remainder of transactions with the
+    /// [`RemainderValueStrategy::ReuseAddress`](crate::wallet::account::RemainderValueStrategy::ReuseAddress) strategy,
+    /// which is the default, is sent to the alias address instead of an address of the inputs. Spending outputs of
+    /// the alias address transitions the alias output in the same transaction, which is done by the input
+    /// selection.
+    ///
+    /// The primary alias is checked again whenever it's used, if the account doesn't control the alias output anymore,
+    /// it's ignored until it's set again.
+    pub async fn set_primary_alias(&self, alias_id: Option<AliasId>) -> Result<()> {
+        let mut account_details = self.details_mut().await;
+
+        if let Some(alias_id) = alias_id {
+            if account_details.alias_state_controller(alias_id).is_none() {
+                return Err(Error::AliasNotControlled(alias_id));
+            }
+        }
+
+        account_details.primary_alias = alias_id;
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+        Ok(())
+    }
+
+    /// Returns the primary alias of the account.
+    pub async fn primary_alias(&self) -> Option<AliasId> {
+        self.details().await.primary_alias
+    }
+
+    /// Returns the address that receives the funds of the account, the address of the primary alias if one is set and
+    /// still controlled by the account, otherwise the first public address.
+    pub async fn primary_address(&self) -> Result<Bech32Address> {
+        let (first_address, alias_address) = {
+            let account_details = self.details().await;
+            (
+                account_details.public_addresses.first().map(|address| address.address),
+                account_details.primary_alias_address(),
+            )
+        };
+
+        match (alias_address, first_address) {
+            (Some(alias_address), Some(first_address)) => Ok(Bech32Address::new(first_address.hrp, alias_address)),
+            (Some(alias_address), None) => Ok(Bech32Address::new(
+                self.network_params().await?.bech32_hrp,
+                alias_address,
+            )),
+            (None, Some(first_address)) => Ok(first_address),
+            (None, None) => Err(Error::MissingParameter("public address")),
+        }
+    }
+}
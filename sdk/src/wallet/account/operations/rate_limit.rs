@@ -0,0 +1,189 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::MutexGuard;
+
+use crate::wallet::{
+    account::{Account, AccountDetails},
+    Error, Result,
+};
+
+const MINUTE_MS: u128 = 60 * 1000;
+const HOUR_MS: u128 = 60 * MINUTE_MS;
+
+/// Limits how many transactions an account sends, so a misbehaving automation can't flood the nodes. The limits are
+/// checked before a transaction is prepared and signed and enforced when it's submitted, counting the transactions sent
+/// in the last minute and hour.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionRateLimit {
+    /// Maximum amount of transactions sent within any minute.
+    #[serde(default)]
+    pub max_per_minute: Option<u32>,
+    /// Maximum amount of transactions sent within any hour.
+    #[serde(default)]
+    pub max_per_hour: Option<u32>,
+    /// Wait until the transaction can be sent instead of failing with [`Error::RateLimitExceeded`].
+    #[serde(default)]
+    pub queue: bool,
+}
+
+impl TransactionRateLimit {
+    /// Returns the limit that is reached with the timestamps of the sent transactions, its window and the milliseconds
+    /// until another transaction can be sent, if any.
+    pub(crate) fn exceeded(
+        &self,
+        sent_timestamps: impl IntoIterator<Item = u128>,
+        now: u128,
+    ) -> Option<(u32, u128, u128)> {
+        let mut sent_timestamps = sent_timestamps
+            .into_iter()
+            .filter(|timestamp| timestamp + HOUR_MS > now)
+            .collect::<Vec<_>>();
+        // Newest first
+        sent_timestamps.sort_unstable_by(|a, b| b.cmp(a));
+
+        [(self.max_per_minute, MINUTE_MS), (self.max_per_hour, HOUR_MS)]
+            .into_iter()
+            .filter_map(|(max, window)| {
+                let max = max?;
+                let sent = sent_timestamps
+                    .iter()
+                    .take_while(|timestamp| **timestamp + window > now)
+                    .count();
+                (sent >= max as usize).then(|| {
+                    // Another transaction can be sent once the oldest transaction that counts towards the limit
+                    // leaves the window
+                    let retry_after = match max.checked_sub(1) {
+                        Some(index) => (sent_timestamps[index as usize] + window).saturating_sub(now),
+                        None => window,
+                    };
+                    (max, window, retry_after)
+                })
+            })
+            .max_by_key(|(_, _, retry_after)| *retry_after)
+    }
+}
+
+impl Account {
+    /// Sets the rate limit for sending transactions of the account, `None` removes it.
+    pub async fn set_rate_limit(&self, rate_limit: Option<TransactionRateLimit>) -> Result<()> {
+        let mut account_details = self.details_mut().await;
+        account_details.rate_limit = rate_limit;
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+        Ok(())
+    }
+
+    /// Returns the rate limit for sending transactions of the account.
+    pub async fn rate_limit(&self) -> Option<TransactionRateLimit> {
+        self.details().await.rate_limit
+    }
+
+    /// Waits until a transaction can be sent or fails if the rate limit is reached and shouldn't be queued. Called
+    /// before a transaction is prepared and signed, so no work is done for a transaction that can't be sent.
+    pub(crate) async fn check_rate_limit(&self) -> Result<()> {
+        self.enforce_rate_limit().await.map(|_| ())
+    }
+
+    /// Waits until a transaction can be sent or fails if the rate limit is reached and shouldn't be queued. The
+    /// returned guard needs to be held until the transaction is stored, so concurrent transactions are counted.
+    pub(crate) async fn enforce_rate_limit(&self) -> Result<Option<MutexGuard<'_, ()>>> {
+        if self.details().await.rate_limit.is_none() {
+            return Ok(None);
+        }
+
+        loop {
+            let guard = self.rate_limit_lock.lock().await;
+            let exceeded = {
+                let account_details = self.details().await;
+                account_details.rate_limit.and_then(|rate_limit| {
+                    rate_limit
+                        .exceeded(
+                            sent_timestamps(&account_details),
+                            crate::utils::unix_timestamp_now().as_millis(),
+                        )
+                        .map(|exceeded| (rate_limit.queue, exceeded))
+                })
+            };
+
+            match exceeded {
+                None => return Ok(Some(guard)),
+                // A limit of 0 never allows sending, so there is nothing to wait for
+                Some((queue, (max, window, retry_after))) if !queue || max == 0 => {
+                    return Err(Error::RateLimitExceeded {
+                        max,
+                        window_secs: (window / 1000) as u64,
+                        retry_after_ms: retry_after as u64,
+                    });
+                }
+                Some((_, (_, _, retry_after))) => {
+                    // Other transactions can be sent and stored while waiting, they're counted in the next round
+                    drop(guard);
+                    log::debug!("[RATE_LIMIT] waiting {retry_after}ms to send the transaction");
+                    let duration = std::time::Duration::from_millis(retry_after as u64);
+
+                    #[cfg(target_family = "wasm")]
+                    gloo_timers::future::TimeoutFuture::new(duration.as_millis() as u32).await;
+
+                    #[cfg(not(target_family = "wasm"))]
+                    tokio::time::sleep(duration).await;
+                }
+            }
+        }
+    }
+}
+
+// Returns the timestamps of the transactions sent by the account.
+fn sent_timestamps(account_details: &AccountDetails) -> impl Iterator<Item = u128> + '_ {
+    account_details
+        .transactions
+        .values()
+        .filter(|transaction| !transaction.incoming)
+        .map(|transaction| transaction.timestamp)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exceeded() {
+        let rate_limit = TransactionRateLimit {
+            max_per_minute: Some(2),
+            max_per_hour: Some(3),
+            queue: false,
+        };
+        let now = 10 * HOUR_MS;
+
+        assert_eq!(rate_limit.exceeded([], now), None);
+        // Transactions older than the windows aren't counted
+        assert_eq!(rate_limit.exceeded([now - HOUR_MS, now - 2 * MINUTE_MS], now), None);
+        // The per minute limit is reached until the older transaction leaves the window
+        assert_eq!(
+            rate_limit.exceeded([now - 20_000, now - 10_000], now),
+            Some((2, MINUTE_MS, 40_000))
+        );
+        // The per hour limit is reached, the longest wait is returned
+        assert_eq!(
+            rate_limit.exceeded([now - 30 * MINUTE_MS, now - 20_000, now - 10_000], now),
+            Some((3, HOUR_MS, 30 * MINUTE_MS))
+        );
+        assert_eq!(
+            rate_limit.exceeded([now - 30 * MINUTE_MS, now - 20 * MINUTE_MS, now - 10_000], now),
+            Some((3, HOUR_MS, 30 * MINUTE_MS))
+        );
+    }
+
+    #[test]
+    fn exceeded_zero_limit() {
+        let rate_limit = TransactionRateLimit {
+            max_per_minute: Some(0),
+            max_per_hour: None,
+            queue: true,
+        };
+
+        assert_eq!(rate_limit.exceeded([], HOUR_MS), Some((0, MINUTE_MS, MINUTE_MS)));
+    }
+}
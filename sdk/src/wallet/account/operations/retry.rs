@@ -1,6 +1,11 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::time::Duration;
+
+use serde::Serialize;
+use tokio::sync::watch;
+
 use crate::{
     types::{
         api::core::dto::LedgerInclusionStateDto,
@@ -9,11 +14,13 @@ use crate::{
             Block, BlockId,
         },
     },
-    wallet::account::{types::InclusionState, Account},
+    wallet::account::{operations::syncing::transactions::inclusion_state_at_depth, types::InclusionState, Account},
 };
 
 const DEFAULT_RETRY_UNTIL_INCLUDED_INTERVAL: u64 = 1;
 const DEFAULT_RETRY_UNTIL_INCLUDED_MAX_AMOUNT: u64 = 40;
+// How long it may take per milestone of the confirmation depth until an included transaction is confirmed
+const CONFIRMATION_DEPTH_TIMEOUT_PER_MILESTONE: Duration = Duration::from_secs(60);
 
 impl Account {
     /// Retries (promotes or reattaches) a block for provided block id until it's included (referenced by a
@@ -32,18 +39,71 @@ impl Account {
     }
 
     /// Retries (promotes or reattaches) a transaction sent from the account for a provided transaction id until it's
-    /// included (referenced by a milestone). Returns the included block id.
+    /// included (referenced by a milestone). If the transaction has a confirmation depth, it then waits until enough
+    /// further milestones got confirmed, which isn't limited by `max_attempts`, but times out after a minute per
+    /// milestone. Returns the included block id.
     pub async fn retry_transaction_until_included(
         &self,
         transaction_id: &TransactionId,
         interval: Option<u64>,
         max_attempts: Option<u64>,
+    ) -> crate::wallet::Result<BlockId> {
+        let (progress, _) = watch::channel(RetryProgress::default());
+        // The sender is kept alive, but never used, so the retrying can't be cancelled
+        let (_cancel, cancelled) = watch::channel(false);
+        self.retry_transaction_until_included_inner(transaction_id, interval, max_attempts, &progress, cancelled)
+            .await
+    }
+
+    /// Like [`Account::retry_transaction_until_included()`], but runs the retrying in a background task and returns
+    /// a [`RetryTransactionHandle`] right away, which can be used to observe the progress or to cancel the retrying.
+    #[cfg(not(target_family = "wasm"))]
+    pub fn retry_transaction_until_included_with_handle(
+        &self,
+        transaction_id: TransactionId,
+        interval: Option<u64>,
+        max_attempts: Option<u64>,
+    ) -> RetryTransactionHandle {
+        let (progress_sender, progress) = watch::channel(RetryProgress::default());
+        let (cancel, cancelled) = watch::channel(false);
+
+        let account = self.clone();
+        let task = tokio::task::spawn(async move {
+            account
+                .retry_transaction_until_included_inner(
+                    &transaction_id,
+                    interval,
+                    max_attempts,
+                    &progress_sender,
+                    cancelled,
+                )
+                .await
+        });
+
+        RetryTransactionHandle {
+            transaction_id,
+            progress,
+            cancel,
+            task,
+        }
+    }
+
+    async fn retry_transaction_until_included_inner(
+        &self,
+        transaction_id: &TransactionId,
+        interval: Option<u64>,
+        max_attempts: Option<u64>,
+        progress: &watch::Sender<RetryProgress>,
+        mut cancelled: watch::Receiver<bool>,
     ) -> crate::wallet::Result<BlockId> {
         log::debug!("[retry_transaction_until_included]");
+        check_cancelled(transaction_id, &cancelled)?;
 
         let transaction = self.details().await.transactions.get(transaction_id).cloned();
 
         if let Some(transaction) = transaction {
+            progress.send_modify(|progress| progress.inclusion_state = transaction.inclusion_state);
+
             if transaction.inclusion_state == InclusionState::Confirmed {
                 return transaction
                     .block_id
@@ -70,18 +130,19 @@ impl Account {
                     .id(),
             };
 
+            let confirmation_depth = transaction
+                .confirmation_depth
+                .unwrap_or_else(|| self.wallet.default_confirmation_depth());
             // Attachments of the Block to check inclusion state
             let mut block_ids = vec![block_id];
-            for _ in 0..max_attempts.unwrap_or(DEFAULT_RETRY_UNTIL_INCLUDED_MAX_AMOUNT) {
-                let duration =
-                    std::time::Duration::from_secs(interval.unwrap_or(DEFAULT_RETRY_UNTIL_INCLUDED_INTERVAL));
-
-                #[cfg(target_family = "wasm")]
-                gloo_timers::future::TimeoutFuture::new(duration.as_millis() as u32).await;
+            progress.send_modify(|progress| progress.block_ids = block_ids.clone());
 
-                #[cfg(not(target_family = "wasm"))]
-                tokio::time::sleep(duration).await;
+            let duration = Duration::from_secs(interval.unwrap_or(DEFAULT_RETRY_UNTIL_INCLUDED_INTERVAL));
+            for attempt in 1..=max_attempts.unwrap_or(DEFAULT_RETRY_UNTIL_INCLUDED_MAX_AMOUNT) {
+                sleep_unless_cancelled(transaction_id, duration, &mut cancelled).await?;
 
+                // The included attachment and the index of the milestone that referenced it
+                let mut included: Option<(BlockId, Option<u32>)> = None;
                 // Check inclusion state for each attachment
                 let block_ids_len = block_ids.len();
                 let mut conflicting = false;
@@ -90,10 +151,11 @@ impl Account {
                     if let Some(inclusion_state) = block_metadata.ledger_inclusion_state {
                         match inclusion_state {
                             LedgerInclusionStateDto::Included | LedgerInclusionStateDto::NoTransaction => {
-                                return Ok(*block_id_);
+                                included = Some((*block_id_, block_metadata.referenced_by_milestone_index));
+                                break;
                             }
-                            // only set it as conflicting here and don't return, because another reattached block could
-                            // have the included transaction
+                            // only set it as conflicting here and don't return, because another reattached block
+                            // could have the included transaction
                             LedgerInclusionStateDto::Conflicting => conflicting = true,
                         };
                     }
@@ -102,6 +164,7 @@ impl Account {
                         if block_metadata.should_promote.unwrap_or(false) {
                             // Safe to unwrap since we iterate over it
                             self.client().promote_unchecked(block_ids.last().unwrap()).await?;
+                            progress.send_modify(|progress| progress.promotions += 1);
                         } else if block_metadata.should_reattach.unwrap_or(false) {
                             let reattached_block = self
                                 .client()
@@ -109,14 +172,44 @@ impl Account {
                                 .finish_block(Some(Payload::Transaction(Box::new(transaction.payload.clone()))))
                                 .await?;
                             block_ids.push(reattached_block.id());
+                            progress.send_modify(|progress| progress.block_ids.push(reattached_block.id()));
                         }
                     }
                 }
-                // After we checked all our reattached blocks, check if the transaction got reattached in another block
-                // and confirmed
-                if conflicting {
-                    let included_block = self.client().get_included_block(transaction_id).await?;
-                    return Ok(included_block.id());
+                progress.send_modify(|progress| {
+                    progress.attempts = attempt;
+                    if conflicting {
+                        progress.inclusion_state = InclusionState::Conflicting;
+                    }
+                });
+                // After we checked all our reattached blocks, check if the transaction got reattached in another
+                // block and confirmed
+                if conflicting && included.is_none() {
+                    let included_block_id = self.client().get_included_block(transaction_id).await?.id();
+                    let milestone_index = match confirmation_depth {
+                        0 => None,
+                        _ => {
+                            self.client()
+                                .get_block_metadata(&included_block_id)
+                                .await?
+                                .referenced_by_milestone_index
+                        }
+                    };
+                    included = Some((included_block_id, milestone_index));
+                }
+
+                if let Some((included_block_id, milestone_index)) = included {
+                    return self
+                        .wait_for_confirmation_depth(
+                            transaction_id,
+                            included_block_id,
+                            milestone_index,
+                            confirmation_depth,
+                            duration,
+                            progress,
+                            &mut cancelled,
+                        )
+                        .await;
                 }
             }
             Err(crate::client::Error::TangleInclusion(block_id.to_string()).into())
@@ -124,4 +217,212 @@ impl Account {
             Err(crate::wallet::Error::TransactionNotFound(*transaction_id))
         }
     }
+
+    // Waits until enough milestones confirmed an included transaction, with a timeout depending on the confirmation
+    // depth
+    #[allow(clippy::too_many_arguments)]
+    async fn wait_for_confirmation_depth(
+        &self,
+        transaction_id: &TransactionId,
+        included_block_id: BlockId,
+        milestone_index: Option<u32>,
+        confirmation_depth: u32,
+        interval: Duration,
+        progress: &watch::Sender<RetryProgress>,
+        cancelled: &mut watch::Receiver<bool>,
+    ) -> crate::wallet::Result<BlockId> {
+        let timeout = CONFIRMATION_DEPTH_TIMEOUT_PER_MILESTONE * confirmation_depth;
+        let start_time = instant::Instant::now();
+        loop {
+            let confirmed_milestone_index = match confirmation_depth {
+                0 => None,
+                _ => Some(self.confirmed_milestone_index().await?),
+            };
+            let inclusion_state =
+                inclusion_state_at_depth(milestone_index, confirmation_depth, confirmed_milestone_index);
+            progress.send_modify(|progress| progress.inclusion_state = inclusion_state);
+            if inclusion_state == InclusionState::Confirmed {
+                return Ok(included_block_id);
+            }
+            if start_time.elapsed() >= timeout {
+                return Err(crate::client::Error::TangleInclusion(format!(
+                    "transaction id: {transaction_id} not confirmed at depth {confirmation_depth} within {timeout:?}"
+                ))
+                .into());
+            }
+
+            sleep_unless_cancelled(transaction_id, interval, cancelled).await?;
+        }
+    }
+}
+
+// Returns an error if the retrying was cancelled
+fn check_cancelled(transaction_id: &TransactionId, cancelled: &watch::Receiver<bool>) -> crate::wallet::Result<()> {
+    if *cancelled.borrow() {
+        log::debug!("[retry_transaction_until_included] cancelled");
+        return Err(crate::wallet::Error::RetryCancelled(*transaction_id));
+    }
+    Ok(())
+}
+
+// Sleeps for the interval, but stops right away if the retrying gets cancelled before or during the sleep
+async fn sleep_unless_cancelled(
+    transaction_id: &TransactionId,
+    interval: Duration,
+    cancelled: &mut watch::Receiver<bool>,
+) -> crate::wallet::Result<()> {
+    check_cancelled(transaction_id, cancelled)?;
+
+    #[cfg(target_family = "wasm")]
+    let sleep = gloo_timers::future::TimeoutFuture::new(interval.as_millis() as u32);
+
+    #[cfg(not(target_family = "wasm"))]
+    let sleep = tokio::time::sleep(interval);
+
+    let cancellation = async {
+        while !*cancelled.borrow_and_update() {
+            if cancelled.changed().await.is_err() {
+                // The handle got dropped, so the retrying can't be cancelled anymore
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+
+    tokio::select! {
+        _ = sleep => {}
+        _ = cancellation => {}
+    }
+
+    check_cancelled(transaction_id, cancelled)
+}
+
+/// The progress of retrying a transaction until it's included.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RetryProgress {
+    /// The amount of inclusion checks that were made so far.
+    pub attempts: u64,
+    /// The ids of the blocks the transaction was attached to, the first one is the original attachment.
+    pub block_ids: Vec<BlockId>,
+    /// The amount of times the latest attachment was promoted.
+    pub promotions: u64,
+    /// The last known inclusion state of the transaction.
+    pub inclusion_state: InclusionState,
+}
+
+impl Default for RetryProgress {
+    fn default() -> Self {
+        Self {
+            attempts: 0,
+            block_ids: Vec::new(),
+            promotions: 0,
+            inclusion_state: InclusionState::Pending,
+        }
+    }
+}
+
+/// A handle to a transaction that is retried in the background until it's included, created with
+/// [`Account::retry_transaction_until_included_with_handle()`].
+#[cfg(not(target_family = "wasm"))]
+#[derive(Debug)]
+pub struct RetryTransactionHandle {
+    transaction_id: TransactionId,
+    progress: watch::Receiver<RetryProgress>,
+    cancel: watch::Sender<bool>,
+    task: tokio::task::JoinHandle<crate::wallet::Result<BlockId>>,
+}
+
+#[cfg(not(target_family = "wasm"))]
+impl RetryTransactionHandle {
+    /// Returns the id of the transaction that is retried.
+    pub fn transaction_id(&self) -> &TransactionId {
+        &self.transaction_id
+    }
+
+    /// Returns the current progress.
+    pub fn progress(&self) -> RetryProgress {
+        self.progress.borrow().clone()
+    }
+
+    /// Waits until the progress changed and returns it, returns `None` if the retrying finished.
+    pub async fn progress_changed(&mut self) -> Option<RetryProgress> {
+        self.progress.changed().await.ok()?;
+        Some(self.progress())
+    }
+
+    /// Cancels the retrying, it stops right away if it's waiting for the next inclusion check, otherwise before it.
+    pub fn cancel(&self) {
+        self.cancel.send_replace(true);
+    }
+
+    /// Returns whether the retrying finished, either because the transaction got included, it failed or it was
+    /// cancelled.
+    pub fn is_finished(&self) -> bool {
+        self.task.is_finished()
+    }
+
+    /// Waits until the retrying finished and returns the included block id.
+    /// Returns [`Error::RetryCancelled`](crate::wallet::Error::RetryCancelled) if it was cancelled.
+    pub async fn wait(self) -> crate::wallet::Result<BlockId> {
+        self.task.await?
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Long enough to fail the tests by timing out if the sleep isn't interrupted
+    const LONG_INTERVAL: Duration = Duration::from_secs(600);
+
+    #[tokio::test]
+    async fn sleep_cancelled_before() {
+        let transaction_id = TransactionId::new([1; TransactionId::LENGTH]);
+        let (cancel, mut cancelled) = watch::channel(false);
+        cancel.send_replace(true);
+
+        let result = tokio::time::timeout(
+            Duration::from_secs(5),
+            sleep_unless_cancelled(&transaction_id, LONG_INTERVAL, &mut cancelled),
+        )
+        .await
+        .expect("sleep wasn't skipped");
+        assert!(matches!(result, Err(crate::wallet::Error::RetryCancelled(id)) if id == transaction_id));
+    }
+
+    #[tokio::test]
+    async fn sleep_cancelled_during() {
+        let transaction_id = TransactionId::new([1; TransactionId::LENGTH]);
+        let (cancel, mut cancelled) = watch::channel(false);
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            cancel.send_replace(true);
+            // Keep the sender alive, so only the cancellation can end the sleep
+            tokio::time::sleep(LONG_INTERVAL).await;
+        });
+
+        let result = tokio::time::timeout(
+            Duration::from_secs(5),
+            sleep_unless_cancelled(&transaction_id, LONG_INTERVAL, &mut cancelled),
+        )
+        .await
+        .expect("sleep wasn't interrupted");
+        assert!(matches!(result, Err(crate::wallet::Error::RetryCancelled(id)) if id == transaction_id));
+    }
+
+    #[tokio::test]
+    async fn sleep_not_cancelled() {
+        let transaction_id = TransactionId::new([1; TransactionId::LENGTH]);
+        let (cancel, mut cancelled) = watch::channel(false);
+        sleep_unless_cancelled(&transaction_id, Duration::from_millis(10), &mut cancelled)
+            .await
+            .unwrap();
+
+        // Dropping the sender doesn't cancel the retrying
+        drop(cancel);
+        sleep_unless_cancelled(&transaction_id, Duration::from_millis(10), &mut cancelled)
+            .await
+            .unwrap();
+        assert!(check_cancelled(&transaction_id, &cancelled).is_ok());
+    }
 }
@@ -105,7 +105,7 @@ impl Account {
         // Get alias outputs, so we can then get the foundry outputs with the alias addresses
         let alias_outputs_with_meta = self.get_outputs(alias_output_ids.iter().copied().collect()).await?;
 
-        let bech32_hrp = self.client().get_bech32_hrp().await?;
+        let bech32_hrp = self.network_params().await?.bech32_hrp;
 
         let mut tasks = vec![];
 
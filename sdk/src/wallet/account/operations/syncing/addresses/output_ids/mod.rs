@@ -31,7 +31,7 @@ impl Account {
         address: Address,
         sync_options: &SyncOptions,
     ) -> crate::wallet::Result<Vec<OutputId>> {
-        let bech32_address = Bech32Address::new(self.client().get_bech32_hrp().await?, address);
+        let bech32_address = Bech32Address::new(self.network_params().await?.bech32_hrp, address);
 
         if sync_options.sync_only_most_basic_outputs {
             let output_ids = self
@@ -40,6 +40,11 @@ impl Account {
             return Ok(output_ids);
         }
 
+        // The funds of the account are held under the address of the primary alias, so its basic and NFT outputs are
+        // always synced
+        let is_primary_alias =
+            address.is_alias() && self.details().await.primary_alias_address().as_ref() == Some(&address);
+
         #[cfg(target_family = "wasm")]
         let mut results = vec![];
 
@@ -48,7 +53,7 @@ impl Account {
 
         if (address.is_ed25519() && sync_options.account.basic_outputs)
             || (address.is_nft() && sync_options.nft.basic_outputs)
-            || (address.is_alias() && sync_options.alias.basic_outputs)
+            || (address.is_alias() && (sync_options.alias.basic_outputs || is_primary_alias))
         {
             // basic outputs
             #[cfg(target_family = "wasm")]
@@ -78,7 +83,7 @@ impl Account {
 
         if (address.is_ed25519() && sync_options.account.nft_outputs)
             || (address.is_nft() && sync_options.nft.nft_outputs)
-            || (address.is_alias() && sync_options.alias.nft_outputs)
+            || (address.is_alias() && (sync_options.alias.nft_outputs || is_primary_alias))
         {
             // nfts
             #[cfg(target_family = "wasm")]
@@ -157,15 +162,26 @@ impl Account {
         log::debug!("[SYNC] start get_output_ids_for_addresses");
         let address_output_ids_start_time = Instant::now();
 
-        let mut addresses_with_outputs = Vec::new();
-        // spent outputs or alias/nft/foundries that don't get synced anymore, because of other sync options
-        let mut spent_or_not_anymore_synced_outputs = Vec::new();
+        // Output ids that were queried for all accounts together don't need to be requested again
+        let mut results = Vec::new();
+        let mut addresses_to_request = Vec::new();
+        for address in addresses_with_unspent_outputs {
+            let prefetched_output_ids = match &self.sync_cache {
+                Some(sync_cache) => sync_cache.take_output_ids(&address.address.inner).await,
+                None => None,
+            };
+            match prefetched_output_ids {
+                Some(output_ids) => results.push((address, output_ids)),
+                None => addresses_to_request.push(address),
+            }
+        }
+
         // We split the addresses into chunks so we don't get timeouts if we have thousands
-        for addresses_chunk in &mut addresses_with_unspent_outputs
+        for addresses_chunk in &mut addresses_to_request
             .chunks(PARALLEL_REQUESTS_AMOUNT)
             .map(|x: &[AddressWithUnspentOutputs]| x.to_vec())
         {
-            let results;
+            let chunk_results;
             #[cfg(target_family = "wasm")]
             {
                 let mut tasks = Vec::new();
@@ -173,7 +189,7 @@ impl Account {
                     let output_ids = self.get_output_ids_for_address(address.address.inner, &options).await?;
                     tasks.push(crate::wallet::Result::Ok((address, output_ids)));
                 }
-                results = tasks;
+                chunk_results = tasks;
             }
 
             #[cfg(not(target_family = "wasm"))]
@@ -193,27 +209,33 @@ impl Account {
                     });
                 }
 
-                results = futures::future::try_join_all(tasks).await?;
+                chunk_results = futures::future::try_join_all(tasks).await?;
             }
 
-            for res in results {
-                let (mut address, output_ids): (AddressWithUnspentOutputs, Vec<OutputId>) = res?;
-                // only return addresses with outputs
-                if !output_ids.is_empty() {
-                    // outputs we had before, but now not anymore, got spent or are alias/nft/foundries that don't get
-                    // synced anymore because of other sync options
-                    for output_id in address.output_ids {
-                        if !output_ids.contains(&output_id) {
-                            spent_or_not_anymore_synced_outputs.push(output_id);
-                        }
+            for res in chunk_results {
+                results.push(res?);
+            }
+        }
+
+        let mut addresses_with_outputs = Vec::new();
+        // spent outputs or alias/nft/foundries that don't get synced anymore, because of other sync options
+        let mut spent_or_not_anymore_synced_outputs = Vec::new();
+        for (mut address, output_ids) in results {
+            // only return addresses with outputs
+            if !output_ids.is_empty() {
+                // outputs we had before, but now not anymore, got spent or are alias/nft/foundries that don't get
+                // synced anymore because of other sync options
+                for output_id in address.output_ids {
+                    if !output_ids.contains(&output_id) {
+                        spent_or_not_anymore_synced_outputs.push(output_id);
                     }
-                    address.output_ids = output_ids;
-                    addresses_with_outputs.push(address);
-                } else {
-                    // outputs we had before, but now not anymore, got spent or are alias/nft/foundries that don't get
-                    // synced anymore because of other sync options
-                    spent_or_not_anymore_synced_outputs.extend(address.output_ids.into_iter());
                 }
+                address.output_ids = output_ids;
+                addresses_with_outputs.push(address);
+            } else {
+                // outputs we had before, but now not anymore, got spent or are alias/nft/foundries that don't get
+                // synced anymore because of other sync options
+                spent_or_not_anymore_synced_outputs.extend(address.output_ids.into_iter());
             }
         }
 
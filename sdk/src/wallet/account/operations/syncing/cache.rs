@@ -0,0 +1,211 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{collections::HashMap, sync::Arc};
+
+use tokio::sync::{Mutex, OnceCell};
+
+use crate::{
+    types::block::{
+        address::Address,
+        output::{FoundryId, FoundryOutput, OutputId, OutputWithMetadata},
+        payload::transaction::TransactionId,
+    },
+    wallet::account::{
+        constants::{MIN_SYNC_INTERVAL, PARALLEL_REQUESTS_AMOUNT},
+        operations::syncing::SyncOptions,
+        types::Transaction,
+        Account,
+    },
+};
+
+/// Node data that is shared between the accounts synced with [`Wallet::sync_all()`](crate::wallet::Wallet::sync_all),
+/// so it's only requested once, no matter how many accounts need it.
+#[derive(Debug, Default)]
+pub(crate) struct SyncCache {
+    pub(crate) confirmed_milestone_index: OnceCell<u32>,
+    // Output ids of the account addresses, queried from the indexer for all accounts at once before syncing
+    pub(crate) output_ids: Mutex<HashMap<Address, Vec<OutputId>>>,
+    pub(crate) outputs: Mutex<HashMap<OutputId, OutputWithMetadata>>,
+    // `None` if the foundry doesn't exist
+    pub(crate) foundries: Mutex<HashMap<FoundryId, Option<FoundryOutput>>>,
+    // `None` if the transaction isn't accessible anymore
+    pub(crate) incoming_transactions: Mutex<HashMap<TransactionId, Option<Transaction>>>,
+}
+
+impl SyncCache {
+    /// Queries the indexer for the output ids of the addresses of all accounts together, in chunks of
+    /// [`PARALLEL_REQUESTS_AMOUNT`], instead of account by account. Accounts that won't be synced because they were
+    /// synced just now are skipped. Failed queries aren't cached, so the account sync requests them again.
+    pub(crate) async fn prefetch_output_ids(&self, accounts: &[Account], options: Option<&SyncOptions>) {
+        let time_now = crate::utils::unix_timestamp_now().as_millis();
+        let mut requests = Vec::new();
+        for account in accounts {
+            let sync_options = match options {
+                Some(options) => options.clone(),
+                None => account.default_sync_options().await,
+            };
+            if !sync_options.force_syncing && time_now - *account.last_synced.lock().await < MIN_SYNC_INTERVAL {
+                continue;
+            }
+            match account.get_addresses_to_sync(&sync_options).await {
+                Ok(addresses) => requests.extend(
+                    addresses
+                        .into_iter()
+                        .map(|address| (account, address.address.inner, sync_options.clone())),
+                ),
+                Err(e) => log::debug!("[SYNC] couldn't get the addresses to prefetch: {e}"),
+            }
+        }
+
+        for requests_chunk in requests.chunks(PARALLEL_REQUESTS_AMOUNT) {
+            let results = futures::future::join_all(requests_chunk.iter().map(
+                |(account, address, sync_options)| async move {
+                    (
+                        *address,
+                        account.get_output_ids_for_address(*address, sync_options).await,
+                    )
+                },
+            ))
+            .await;
+
+            let mut cached_output_ids = self.output_ids.lock().await;
+            for (address, result) in results {
+                match result {
+                    Ok(output_ids) => {
+                        cached_output_ids.insert(address, output_ids);
+                    }
+                    Err(e) => log::debug!("[SYNC] couldn't prefetch the output ids of {address:?}: {e}"),
+                }
+            }
+        }
+    }
+
+    // Returns the prefetched output ids of the address, they're only used once so syncing the account again, because
+    // of a confirmed transaction, queries the indexer for the new state
+    pub(crate) async fn take_output_ids(&self, address: &Address) -> Option<Vec<OutputId>> {
+        self.output_ids.lock().await.remove(address)
+    }
+}
+
+impl Account {
+    /// Returns a handle to the same account that uses the shared sync cache for its node requests.
+    pub(crate) fn with_sync_cache(&self, sync_cache: Arc<SyncCache>) -> Self {
+        Self {
+            sync_cache: Some(sync_cache),
+            ..self.clone()
+        }
+    }
+
+    // Requests outputs from the node, outputs that were already requested for another account are taken from the sync
+    // cache
+    pub(crate) async fn request_outputs(
+        &self,
+        output_ids: Vec<OutputId>,
+    ) -> crate::wallet::Result<Vec<OutputWithMetadata>> {
+        let Some(sync_cache) = &self.sync_cache else {
+            return Ok(self.client().get_outputs(output_ids).await?);
+        };
+
+        let (mut outputs, uncached_output_ids) = split_cached_outputs(&*sync_cache.outputs.lock().await, output_ids);
+
+        if !uncached_output_ids.is_empty() {
+            let requested_outputs = self.client().get_outputs(uncached_output_ids).await?;
+            sync_cache.outputs.lock().await.extend(
+                requested_outputs
+                    .iter()
+                    .map(|output| (*output.metadata().output_id(), output.clone())),
+            );
+            outputs.extend(requested_outputs);
+        }
+
+        Ok(outputs)
+    }
+}
+
+// Splits the output ids into the cached outputs and the ids of the outputs that need to be requested
+fn split_cached_outputs(
+    cached_outputs: &HashMap<OutputId, OutputWithMetadata>,
+    output_ids: Vec<OutputId>,
+) -> (Vec<OutputWithMetadata>, Vec<OutputId>) {
+    let mut outputs = Vec::new();
+    let mut uncached_output_ids = Vec::new();
+    for output_id in output_ids {
+        match cached_outputs.get(&output_id) {
+            Some(output) => outputs.push(output.clone()),
+            None => uncached_output_ids.push(output_id),
+        }
+    }
+    (outputs, uncached_output_ids)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::block::{
+        address::Ed25519Address,
+        output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder, OutputMetadata},
+        BlockId,
+    };
+
+    const TOKEN_SUPPLY: u64 = 1_813_620_509_061_365;
+
+    fn output_with_metadata(output_id: OutputId) -> OutputWithMetadata {
+        OutputWithMetadata::new(
+            BasicOutputBuilder::new_with_amount(1_000_000)
+                .add_unlock_condition(AddressUnlockCondition::new(Address::Ed25519(Ed25519Address::new(
+                    [1; Ed25519Address::LENGTH],
+                ))))
+                .finish_output(TOKEN_SUPPLY)
+                .unwrap(),
+            OutputMetadata::new(
+                BlockId::new([0; BlockId::LENGTH]),
+                output_id,
+                false,
+                None,
+                None,
+                None,
+                0,
+                0,
+                0,
+            ),
+        )
+    }
+
+    #[test]
+    fn split_outputs_into_cached_and_uncached() {
+        let output_ids = (0..3)
+            .map(|index| OutputId::new(TransactionId::new([1; TransactionId::LENGTH]), index).unwrap())
+            .collect::<Vec<_>>();
+        let cached_outputs = HashMap::from([(output_ids[1], output_with_metadata(output_ids[1]))]);
+
+        let (outputs, uncached_output_ids) = split_cached_outputs(&cached_outputs, output_ids.clone());
+        assert_eq!(
+            outputs
+                .iter()
+                .map(|output| *output.metadata().output_id())
+                .collect::<Vec<_>>(),
+            vec![output_ids[1]]
+        );
+        assert_eq!(uncached_output_ids, vec![output_ids[0], output_ids[2]]);
+
+        let (outputs, uncached_output_ids) = split_cached_outputs(&HashMap::new(), output_ids.clone());
+        assert!(outputs.is_empty());
+        assert_eq!(uncached_output_ids, output_ids);
+    }
+
+    #[tokio::test]
+    async fn prefetched_output_ids_are_used_once() {
+        let sync_cache = SyncCache::default();
+        let address = Address::Ed25519(Ed25519Address::new([1; Ed25519Address::LENGTH]));
+        let other_address = Address::Ed25519(Ed25519Address::new([2; Ed25519Address::LENGTH]));
+        let output_ids = vec![OutputId::new(TransactionId::new([1; TransactionId::LENGTH]), 0).unwrap()];
+        sync_cache.output_ids.lock().await.insert(address, output_ids.clone());
+        // An address without outputs is cached too, so it isn't queried again
+        sync_cache.output_ids.lock().await.insert(other_address, Vec::new());
+
+        assert_eq!(sync_cache.take_output_ids(&address).await, Some(output_ids));
+        assert_eq!(sync_cache.take_output_ids(&address).await, None);
+        assert_eq!(sync_cache.take_output_ids(&other_address).await, Some(Vec::new()));
+    }
+}
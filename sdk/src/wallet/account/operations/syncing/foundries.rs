@@ -1,7 +1,7 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use crate::{
     types::block::output::{FoundryId, Output},
@@ -16,6 +16,10 @@ impl Account {
         log::debug!("[SYNC] request_and_store_foundry_outputs");
 
         let mut foundries = self.details().await.native_token_foundries().clone();
+        let cached_foundries = match &self.sync_cache {
+            Some(sync_cache) => sync_cache.foundries.lock().await.clone(),
+            None => HashMap::new(),
+        };
         let mut tasks = Vec::new();
 
         for foundry_id in foundry_ids {
@@ -23,27 +27,42 @@ impl Account {
             if foundries.contains_key(&foundry_id) {
                 continue;
             }
+            // Don't request foundries again that were already requested for another account
+            if let Some(cached_foundry) = cached_foundries.get(&foundry_id) {
+                if let Some(foundry) = cached_foundry {
+                    foundries.insert(foundry_id, foundry.clone());
+                }
+                continue;
+            }
 
             let client = self.client().clone();
             tasks.push(async move {
                 task::spawn(async move {
                     match client.foundry_output_id(foundry_id).await {
-                        Ok(output_id) => Ok(Some(client.get_output(&output_id).await?)),
-                        Err(crate::client::Error::NoOutput(_)) => Ok(None),
+                        Ok(output_id) => match client.get_output(&output_id).await?.into_output() {
+                            Output::Foundry(foundry) => Ok((foundry_id, Some(foundry))),
+                            _ => Ok((foundry_id, None)),
+                        },
+                        Err(crate::client::Error::NoOutput(_)) => Ok((foundry_id, None)),
                         Err(e) => Err(crate::wallet::Error::Client(e.into())),
                     }
                 })
                 .await
             });
         }
-        let results = futures::future::try_join_all(tasks).await?;
+        let results = futures::future::try_join_all(tasks)
+            .await?
+            .into_iter()
+            .collect::<crate::wallet::Result<Vec<_>>>()?;
+
+        if let Some(sync_cache) = &self.sync_cache {
+            sync_cache.foundries.lock().await.extend(results.iter().cloned());
+        }
 
         // Update account with new foundries.
-        for result in results {
-            if let Some(foundry_output_with_metadata) = result? {
-                if let Output::Foundry(foundry) = foundry_output_with_metadata.output() {
-                    foundries.insert(foundry.id(), foundry.to_owned());
-                }
+        for (foundry_id, foundry) in results {
+            if let Some(foundry) = foundry {
+                foundries.insert(foundry_id, foundry);
             }
         }
 
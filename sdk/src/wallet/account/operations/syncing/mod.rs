@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub(crate) mod addresses;
+pub(crate) mod cache;
 pub(crate) mod foundries;
 pub(crate) mod options;
 pub(crate) mod outputs;
@@ -10,6 +11,11 @@ pub(crate) mod transactions;
 use std::collections::{HashMap, HashSet};
 
 pub use self::options::SyncOptions;
+#[cfg(feature = "events")]
+use crate::wallet::{
+    account::AccountDetails,
+    events::types::{EventOrigin, SyncCompletedEvent, ThresholdAlertEvent, WalletEvent},
+};
 use crate::{
     types::block::{
         address::{Address, AliasAddress, NftAddress},
@@ -67,6 +73,27 @@ impl Account {
             return self.balance().await;
         }
 
+        #[cfg(feature = "events")]
+        let alert_thresholds = self.details().await.alert_thresholds;
+        // The balance is only calculated before the first sync, afterwards the one of the previous sync is used
+        #[cfg(feature = "events")]
+        let balance_before = match (
+            alert_thresholds.and_then(|thresholds| thresholds.balance_drop_percentage),
+            *self.last_synced_balance.lock().await,
+        ) {
+            (Some(_), Some(last_synced_balance)) => Some(last_synced_balance),
+            (Some(_), None) => Some(self.balance().await?.base_coin.total),
+            (None, _) => None,
+        };
+        #[cfg(feature = "events")]
+        let (outputs_before, unspent_outputs_before) = {
+            let account_details = self.details().await;
+            (
+                account_details.outputs.keys().copied().collect::<HashSet<_>>(),
+                account_details.unspent_outputs.keys().copied().collect::<HashSet<_>>(),
+            )
+        };
+
         self.sync_internal(&options).await?;
 
         // Sync transactions after updating account with outputs, so we can use them to check the transaction
@@ -80,11 +107,72 @@ impl Account {
             }
         };
 
+        // Generating addresses doesn't change the synced data, so syncing doesn't fail if it isn't possible, for
+        // example because the Ledger Nano is locked
+        if let Err(err) = self
+            .fill_internal_address_pool(options.min_unused_internal_addresses)
+            .await
+        {
+            log::warn!("[SYNC] generating unused internal addresses failed: {err}");
+        }
+
+        // The rewards are only informational, so syncing doesn't fail if the node has no participation plugin
+        #[cfg(feature = "wallet-participation")]
+        if let Err(err) = self.sync_participation_rewards().await {
+            log::warn!("[SYNC] requesting participation rewards failed: {err}");
+        }
+
         let account_balance = self.balance().await?;
         // Update last_synced mutex
         let time_now = crate::utils::unix_timestamp_now().as_millis();
         *last_synced = time_now;
         log::debug!("[SYNC] finished syncing in {:.2?}", syc_start_time.elapsed());
+
+        #[cfg(feature = "events")]
+        {
+            *self.last_synced_balance.lock().await = Some(account_balance.base_coin.total);
+            // The statistics are only informational, so syncing doesn't fail if the node info can't be requested
+            let ledger_index = match self.client().get_info().await {
+                Ok(info) => Some(info.node_info.status.confirmed_milestone.index),
+                Err(err) => {
+                    log::warn!("[SYNC] requesting the ledger index failed: {err}");
+                    None
+                }
+            };
+            let account_details = self.details().await;
+            let (new_outputs, spent_outputs) =
+                output_deltas(&account_details, &outputs_before, &unspent_outputs_before);
+            let event = SyncCompletedEvent {
+                account_index: account_details.index,
+                new_outputs,
+                spent_outputs,
+                duration: syc_start_time.elapsed().as_millis() as u64,
+                ledger_index,
+            };
+            drop(account_details);
+            let account_index = event.account_index;
+            self.emit(
+                account_index,
+                WalletEvent::SyncCompleted(event),
+                EventOrigin::new("sync"),
+            )
+            .await;
+
+            if let Some(alert) = alert_thresholds
+                .zip(balance_before)
+                .and_then(|(alert_thresholds, balance_before)| {
+                    alert_thresholds.balance_drop_alert(balance_before, account_balance.base_coin.total)
+                })
+            {
+                self.emit(
+                    account_index,
+                    WalletEvent::ThresholdAlert(ThresholdAlertEvent::BalanceDrop(alert)),
+                    EventOrigin::new("sync"),
+                )
+                .await;
+            }
+        }
+
         Ok(account_balance)
     }
 
@@ -163,6 +251,7 @@ impl Account {
         let mut new_alias_and_nft_addresses = HashMap::new();
         let (mut spent_or_not_synced_output_ids, mut addresses_with_unspent_outputs, mut outputs_data) =
             (Vec::new(), Vec::new(), Vec::new());
+        let mut primary_alias_address = None;
 
         loop {
             let new_outputs_data = if new_alias_and_nft_addresses.is_empty() {
@@ -177,9 +266,25 @@ impl Account {
                     .await?;
                 addresses_with_unspent_outputs = addresses_with_unspent_outputs_inner;
                 outputs_data.extend(outputs_data_inner.clone().into_iter());
+                // Alias outputs aren't requested when only the most basic outputs are synced, but the funds of the
+                // account are held under the address of the primary alias, so it's synced anyway
+                if options.sync_only_most_basic_outputs {
+                    let account_details = self.details().await;
+                    if let Some(alias_id) = account_details.primary_alias {
+                        if let Some(state_controller) = account_details.alias_state_controller(alias_id) {
+                            if addresses_with_unspent_outputs
+                                .iter()
+                                .any(|address| address.address.inner == state_controller)
+                            {
+                                primary_alias_address =
+                                    Some((Address::Alias(AliasAddress::from(alias_id)), state_controller));
+                            }
+                        }
+                    }
+                }
                 outputs_data_inner
             } else {
-                let bech32_hrp = self.client().get_bech32_hrp().await?;
+                let bech32_hrp = self.network_params().await?.bech32_hrp;
                 let mut new_outputs_data = Vec::new();
                 for (alias_or_nft_address, ed25519_address) in new_alias_and_nft_addresses {
                     let output_ids = self.get_output_ids_for_address(alias_or_nft_address, options).await?;
@@ -223,6 +328,9 @@ impl Account {
                     _ => {}
                 }
             }
+            if let Some((alias_address, state_controller)) = primary_alias_address.take() {
+                new_alias_and_nft_addresses.insert(alias_address, state_controller);
+            }
 
             log::debug!("[SYNC] new_alias_and_nft_addresses: {new_alias_and_nft_addresses:?}");
             if new_alias_and_nft_addresses.is_empty() {
@@ -244,3 +352,117 @@ impl Account {
         ))
     }
 }
+
+// Counts the outputs that got added to the account and the unspent outputs that got spent since the given snapshot.
+// Outputs are compared by their ids, so pruned outputs aren't counted as new and unspent outputs that got removed
+// because they're not known by the node anymore are counted as spent.
+#[cfg(feature = "events")]
+fn output_deltas(
+    account_details: &AccountDetails,
+    outputs_before: &HashSet<OutputId>,
+    unspent_outputs_before: &HashSet<OutputId>,
+) -> (usize, usize) {
+    let new_outputs = account_details
+        .outputs
+        .keys()
+        .filter(|output_id| !outputs_before.contains(output_id))
+        .count();
+    let spent_outputs = unspent_outputs_before
+        .iter()
+        .filter(|output_id| !account_details.unspent_outputs.contains_key(output_id))
+        .count();
+    (new_outputs, spent_outputs)
+}
+
+#[cfg(all(test, feature = "events"))]
+mod tests {
+    use super::*;
+    use crate::{
+        types::block::{
+            address::Ed25519Address,
+            output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder, OutputMetadata},
+            payload::transaction::TransactionId,
+            BlockId,
+        },
+        wallet::account::types::OutputData,
+    };
+
+    const TOKEN_SUPPLY: u64 = 1_813_620_509_061_365;
+
+    fn output_data(output_id: OutputId, is_spent: bool) -> OutputData {
+        OutputData {
+            output_id,
+            metadata: OutputMetadata::new(
+                BlockId::new([0; BlockId::LENGTH]),
+                output_id,
+                is_spent,
+                None,
+                None,
+                None,
+                0,
+                0,
+                0,
+            ),
+            output: BasicOutputBuilder::new_with_amount(1_000_000)
+                .add_unlock_condition(AddressUnlockCondition::new(Address::Ed25519(Ed25519Address::new(
+                    [1; Ed25519Address::LENGTH],
+                ))))
+                .finish_output(TOKEN_SUPPLY)
+                .unwrap(),
+            is_spent,
+            address: Address::Ed25519(Ed25519Address::new([1; Ed25519Address::LENGTH])),
+            network_id: 0,
+            remainder: false,
+            chain: None,
+        }
+    }
+
+    #[test]
+    fn sync_output_deltas() {
+        let output_ids = (0..4)
+            .map(|index| OutputId::new(TransactionId::new([1; TransactionId::LENGTH]), index).unwrap())
+            .collect::<Vec<_>>();
+        let mut account_details = AccountDetails::mock();
+        // An old spent output and three unspent outputs before syncing
+        account_details
+            .outputs
+            .insert(output_ids[0], output_data(output_ids[0], true));
+        for output_id in &output_ids[1..] {
+            account_details
+                .outputs
+                .insert(*output_id, output_data(*output_id, false));
+            account_details
+                .unspent_outputs
+                .insert(*output_id, output_data(*output_id, false));
+        }
+        let outputs_before = account_details.outputs.keys().copied().collect::<HashSet<_>>();
+        let unspent_outputs_before = account_details.unspent_outputs.keys().copied().collect::<HashSet<_>>();
+
+        assert_eq!(
+            output_deltas(&account_details, &outputs_before, &unspent_outputs_before),
+            (0, 0)
+        );
+
+        // The old spent output got pruned, one output got spent, one removed because the node doesn't know it anymore
+        // and a new one was received
+        account_details.outputs.remove(&output_ids[0]);
+        account_details
+            .outputs
+            .insert(output_ids[1], output_data(output_ids[1], true));
+        account_details.unspent_outputs.remove(&output_ids[1]);
+        account_details.outputs.remove(&output_ids[2]);
+        account_details.unspent_outputs.remove(&output_ids[2]);
+        let new_output_id = OutputId::new(TransactionId::new([2; TransactionId::LENGTH]), 0).unwrap();
+        account_details
+            .outputs
+            .insert(new_output_id, output_data(new_output_id, false));
+        account_details
+            .unspent_outputs
+            .insert(new_output_id, output_data(new_output_id, false));
+
+        assert_eq!(
+            output_deltas(&account_details, &outputs_before, &unspent_outputs_before),
+            (1, 2)
+        );
+    }
+}
@@ -11,6 +11,7 @@ const DEFAULT_SYNC_INCOMING_TRANSACTIONS: bool = false;
 const DEFAULT_SYNC_ONLY_MOST_BASIC_OUTPUTS: bool = false;
 const DEFAULT_SYNC_PENDING_TRANSACTIONS: bool = true;
 const DEFAULT_SYNC_NATIVE_TOKEN_FOUNDRIES: bool = false;
+const DEFAULT_MIN_UNUSED_INTERNAL_ADDRESSES: u32 = 0;
 
 /// The synchronization options
 #[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
@@ -56,6 +57,11 @@ pub struct SyncOptions {
     /// Sync native token foundries, so their metadata can be returned in the balance.
     #[serde(default = "default_sync_native_token_foundries")]
     pub sync_native_token_foundries: bool,
+    /// Generate internal addresses while syncing until the account has at least this amount of unused internal
+    /// addresses, so no address needs to be generated for remainders when preparing a transaction, which can be slow
+    /// with a Ledger Nano.
+    #[serde(default = "default_min_unused_internal_addresses")]
+    pub min_unused_internal_addresses: u32,
 }
 
 fn default_address_start_index() -> u32 {
@@ -82,6 +88,10 @@ fn default_sync_native_token_foundries() -> bool {
     DEFAULT_SYNC_NATIVE_TOKEN_FOUNDRIES
 }
 
+fn default_min_unused_internal_addresses() -> u32 {
+    DEFAULT_MIN_UNUSED_INTERNAL_ADDRESSES
+}
+
 impl Default for SyncOptions {
     fn default() -> Self {
         Self {
@@ -95,6 +105,7 @@ impl Default for SyncOptions {
             nft: NftSyncOptions::default(),
             sync_only_most_basic_outputs: default_sync_only_most_basic_outputs(),
             sync_native_token_foundries: default_sync_native_token_foundries(),
+            min_unused_internal_addresses: default_min_unused_internal_addresses(),
             force_syncing: default_force_syncing(),
         }
     }
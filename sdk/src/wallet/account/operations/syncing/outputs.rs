@@ -104,7 +104,7 @@ impl Account {
         drop(account_details);
 
         if !unknown_outputs.is_empty() {
-            outputs.extend(self.client().get_outputs(unknown_outputs).await?);
+            outputs.extend(self.request_outputs(unknown_outputs).await?);
         }
 
         log::debug!(
@@ -127,6 +127,7 @@ impl Account {
         // Limit parallel requests to 100, to avoid timeouts
         for transaction_ids_chunk in transaction_ids.chunks(100).map(|x: &[TransactionId]| x.to_vec()) {
             let mut tasks = Vec::new();
+            let mut results = Vec::new();
             let account_details = self.details().await;
 
             for transaction_id in transaction_ids_chunk {
@@ -139,6 +140,13 @@ impl Account {
                 {
                     continue;
                 }
+                // Don't request transactions again that were already requested for another account
+                if let Some(sync_cache) = &self.sync_cache {
+                    if let Some(transaction) = sync_cache.incoming_transactions.lock().await.get(&transaction_id) {
+                        results.push((transaction_id, transaction.clone()));
+                        continue;
+                    }
+                }
 
                 let client = self.client().clone();
                 tasks.push(async move {
@@ -176,11 +184,23 @@ impl Account {
 
             drop(account_details);
 
-            let results = futures::future::try_join_all(tasks).await?;
+            let requested_results = futures::future::try_join_all(tasks)
+                .await?
+                .into_iter()
+                .collect::<crate::wallet::Result<Vec<_>>>()?;
+            if let Some(sync_cache) = &self.sync_cache {
+                sync_cache
+                    .incoming_transactions
+                    .lock()
+                    .await
+                    .extend(requested_results.iter().cloned());
+            }
+            results.extend(requested_results);
+
             // Update account with new transactions
             let mut account_details = self.details_mut().await;
-            for res in results {
-                match res? {
+            for result in results {
+                match result {
                     (transaction_id, Some(transaction)) => {
                         account_details
                             .incoming_transactions
@@ -1,12 +1,10 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::str::FromStr;
-
 use crate::{
-    types::{
-        api::core::dto::LedgerInclusionStateDto,
-        block::{input::Input, output::OutputId, payload::transaction::TransactionEssence, BlockId},
+    client::api::ConfirmationStatus,
+    types::block::{
+        input::Input, output::OutputId, payload::transaction::TransactionEssence, semantic::ConflictReason, BlockId,
     },
     utils::unix_timestamp_now,
     wallet::account::{
@@ -39,6 +37,19 @@ impl Account {
 
         let network_id = self.client().get_network_id().await?;
 
+        // Only request the confirmed milestone index if a transaction needs to be followed by further milestones
+        let default_confirmation_depth = self.wallet.default_confirmation_depth();
+        let confirmed_milestone_index = if account_details
+            .pending_transactions
+            .iter()
+            .filter_map(|transaction_id| account_details.transactions.get(transaction_id))
+            .any(|transaction| transaction.confirmation_depth.unwrap_or(default_confirmation_depth) > 0)
+        {
+            Some(self.confirmed_milestone_index().await?)
+        } else {
+            None
+        };
+
         let mut updated_transactions = Vec::new();
         let mut spent_output_ids = Vec::new();
         // Inputs from conflicting transactions that are unspent, but should be removed from the locked outputs so they
@@ -48,7 +59,7 @@ impl Account {
 
         for transaction_id in &account_details.pending_transactions {
             log::debug!("[SYNC] sync pending transaction {transaction_id}");
-            let transaction = account_details
+            let mut transaction = account_details
                 .transactions
                 .get(transaction_id)
                 // panic during development to easier detect if something is wrong, should be handled different later
@@ -67,17 +78,24 @@ impl Account {
                 .keys()
                 .find(|o| o.transaction_id() == transaction_id);
 
+            let confirmation_depth = transaction.confirmation_depth.unwrap_or(default_confirmation_depth);
+
             if let Some(transaction_output) = transaction_output {
                 // Save to unwrap, we just got the output
                 let confirmed_output_data = account_details.outputs.get(transaction_output).expect("output exists");
+                let inclusion_state = inclusion_state_at_depth(
+                    Some(confirmed_output_data.metadata.milestone_index_booked()),
+                    confirmation_depth,
+                    confirmed_milestone_index,
+                );
                 log::debug!(
-                    "[SYNC] confirmed transaction {transaction_id} in block {}",
+                    "[SYNC] {inclusion_state:?} transaction {transaction_id} in block {}",
                     confirmed_output_data.metadata.block_id()
                 );
                 updated_transaction_and_outputs(
                     transaction,
                     Some(*confirmed_output_data.metadata.block_id()),
-                    InclusionState::Confirmed,
+                    inclusion_state,
                     &mut updated_transactions,
                     &mut spent_output_ids,
                 );
@@ -98,76 +116,39 @@ impl Account {
             }
 
             if let Some(block_id) = transaction.block_id {
-                match self.client().get_block_metadata(&block_id).await {
-                    Ok(metadata) => {
-                        if let Some(inclusion_state) = metadata.ledger_inclusion_state {
-                            match inclusion_state {
-                                LedgerInclusionStateDto::Included => {
-                                    log::debug!(
-                                        "[SYNC] confirmed transaction {transaction_id} in block {}",
-                                        metadata.block_id
-                                    );
-                                    confirmed_unknown_output = true;
-                                    updated_transaction_and_outputs(
-                                        transaction,
-                                        Some(BlockId::from_str(&metadata.block_id)?),
-                                        InclusionState::Confirmed,
-                                        &mut updated_transactions,
-                                        &mut spent_output_ids,
-                                    );
-                                }
-                                LedgerInclusionStateDto::Conflicting => {
-                                    // try to get the included block, because maybe only this attachment is
-                                    // conflicting because it got confirmed in another block
-                                    if let Ok(included_block) =
-                                        self.client().get_included_block(&transaction.payload.id()).await
-                                    {
-                                        confirmed_unknown_output = true;
-                                        updated_transaction_and_outputs(
-                                            transaction,
-                                            Some(included_block.id()),
-                                            // block metadata was Conflicting, but it's confirmed in another attachment
-                                            InclusionState::Confirmed,
-                                            &mut updated_transactions,
-                                            &mut spent_output_ids,
-                                        );
-                                    } else {
-                                        log::debug!("[SYNC] conflicting transaction {transaction_id}");
-                                        updated_transaction_and_outputs(
-                                            transaction,
-                                            None,
-                                            InclusionState::Conflicting,
-                                            &mut updated_transactions,
-                                            &mut spent_output_ids,
-                                        );
-                                    }
-                                }
-                                LedgerInclusionStateDto::NoTransaction => {
-                                    unreachable!(
-                                        "We should only get the metadata for blocks with a transaction payload"
-                                    )
-                                }
-                            }
-                        } else {
-                            // no need to reattach if one input got spent
-                            if input_got_spent {
-                                process_transaction_with_unknown_state(
-                                    &account_details,
-                                    transaction,
-                                    &mut updated_transactions,
-                                    &mut output_ids_to_unlock,
-                                )?;
-                            } else {
-                                let time_now = unix_timestamp_now().as_millis();
-                                // Reattach if older than 30 seconds
-                                if transaction.timestamp + 30000 < time_now {
-                                    // only reattach if inputs are still unspent
-                                    transactions_to_reattach.push(transaction);
-                                }
-                            }
-                        }
+                match self
+                    .client()
+                    .confirmation_status(&transaction.payload.id(), Some(&block_id))
+                    .await?
+                {
+                    ConfirmationStatus::Included {
+                        block_id,
+                        milestone_index,
+                    } => {
+                        let inclusion_state =
+                            inclusion_state_at_depth(milestone_index, confirmation_depth, confirmed_milestone_index);
+                        log::debug!("[SYNC] {inclusion_state:?} transaction {transaction_id} in block {block_id}");
+                        confirmed_unknown_output = true;
+                        updated_transaction_and_outputs(
+                            transaction,
+                            Some(block_id),
+                            inclusion_state,
+                            &mut updated_transactions,
+                            &mut spent_output_ids,
+                        );
                     }
-                    Err(crate::client::Error::Node(crate::client::node_api::error::Error::NotFound(_))) => {
+                    ConfirmationStatus::Conflicting { reason, .. } => {
+                        log::debug!("[SYNC] conflicting transaction {transaction_id}: {reason:?}");
+                        transaction.conflict_reason = reason;
+                        updated_transaction_and_outputs(
+                            transaction,
+                            None,
+                            InclusionState::Conflicting,
+                            &mut updated_transactions,
+                            &mut spent_output_ids,
+                        );
+                    }
+                    ConfirmationStatus::Pending => {
                         // no need to reattach if one input got spent
                         if input_got_spent {
                             process_transaction_with_unknown_state(
@@ -185,7 +166,6 @@ impl Account {
                             }
                         }
                     }
-                    Err(e) => return Err(e.into()),
                 }
             } else {
                 // transaction wasn't submitted yet, so we have to send it again
@@ -212,6 +192,43 @@ impl Account {
 
         Ok(confirmed_unknown_output)
     }
+
+    /// Returns the index of the latest confirmed milestone.
+    pub(crate) async fn confirmed_milestone_index(&self) -> crate::wallet::Result<u32> {
+        match &self.sync_cache {
+            Some(sync_cache) => sync_cache
+                .confirmed_milestone_index
+                .get_or_try_init(|| self.request_confirmed_milestone_index())
+                .await
+                .copied(),
+            None => self.request_confirmed_milestone_index().await,
+        }
+    }
+
+    async fn request_confirmed_milestone_index(&self) -> crate::wallet::Result<u32> {
+        let node_info = self.client().get_info().await?.node_info;
+        Ok(node_info.status.confirmed_milestone.index)
+    }
+}
+
+/// Returns the inclusion state of a transaction that got referenced by the milestone with `milestone_index`. It's only
+/// confirmed once `confirmation_depth` further milestones got confirmed.
+pub(crate) fn inclusion_state_at_depth(
+    milestone_index: Option<u32>,
+    confirmation_depth: u32,
+    confirmed_milestone_index: Option<u32>,
+) -> InclusionState {
+    if confirmation_depth == 0 {
+        return InclusionState::Confirmed;
+    }
+    match (milestone_index, confirmed_milestone_index) {
+        (Some(milestone_index), Some(confirmed_milestone_index))
+            if confirmed_milestone_index >= milestone_index.saturating_add(confirmation_depth) =>
+        {
+            InclusionState::Confirmed
+        }
+        _ => InclusionState::Included,
+    }
 }
 
 // Set the outputs as spent so they will not be used as input again
@@ -263,7 +280,113 @@ fn process_transaction_with_unknown_state(
     } else {
         log::debug!("[SYNC] conflicting transaction {}", transaction.transaction_id);
         transaction.inclusion_state = InclusionState::Conflicting;
+        // Inputs got spent by another transaction
+        transaction.conflict_reason = Some(ConflictReason::InputUtxoAlreadySpent);
     }
     updated_transactions.push(transaction);
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        types::block::{
+            address::{Address, Ed25519Address},
+            output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder, Output, OutputMetadata},
+            payload::transaction::TransactionId,
+        },
+        wallet::account::types::OutputData,
+    };
+
+    const TOKEN_SUPPLY: u64 = 1_813_620_509_061_365;
+
+    fn basic_output() -> Output {
+        let address = Address::Ed25519(Ed25519Address::new([1; Ed25519Address::LENGTH]));
+        BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .finish_output(TOKEN_SUPPLY)
+            .unwrap()
+    }
+
+    // Adds the output that `Transaction::mock()` uses as input to the account
+    fn account_with_input(is_spent: bool) -> AccountDetails {
+        let mut account_details = AccountDetails::mock();
+        let output_id = OutputId::new(TransactionId::new([0; TransactionId::LENGTH]), 0).unwrap();
+        let output = basic_output();
+        account_details.outputs.insert(
+            output_id,
+            OutputData {
+                output_id,
+                metadata: OutputMetadata::new(
+                    BlockId::new([0; BlockId::LENGTH]),
+                    output_id,
+                    is_spent,
+                    None,
+                    None,
+                    None,
+                    0,
+                    0,
+                    0,
+                ),
+                address: *output.as_basic().address(),
+                output,
+                is_spent,
+                network_id: 0,
+                remainder: false,
+                chain: None,
+            },
+        );
+        account_details
+    }
+
+    #[test]
+    fn conflict_reason_of_transaction_with_unknown_state() {
+        // The input is still unspent, so the transaction conflicts and the input gets unlocked
+        let account_details = account_with_input(false);
+        let (mut updated_transactions, mut output_ids_to_unlock) = (Vec::new(), Vec::new());
+        process_transaction_with_unknown_state(
+            &account_details,
+            Transaction::mock(vec![basic_output()]),
+            &mut updated_transactions,
+            &mut output_ids_to_unlock,
+        )
+        .unwrap();
+        assert_eq!(updated_transactions[0].inclusion_state, InclusionState::Conflicting);
+        assert_eq!(
+            updated_transactions[0].conflict_reason,
+            Some(ConflictReason::InputUtxoAlreadySpent)
+        );
+        assert_eq!(output_ids_to_unlock.len(), 1);
+
+        // All inputs got spent, so the transaction could have been confirmed and only the state is unknown
+        let account_details = account_with_input(true);
+        let (mut updated_transactions, mut output_ids_to_unlock) = (Vec::new(), Vec::new());
+        process_transaction_with_unknown_state(
+            &account_details,
+            Transaction::mock(vec![basic_output()]),
+            &mut updated_transactions,
+            &mut output_ids_to_unlock,
+        )
+        .unwrap();
+        assert_eq!(updated_transactions[0].inclusion_state, InclusionState::UnknownPruned);
+        assert_eq!(updated_transactions[0].conflict_reason, None);
+        assert!(output_ids_to_unlock.is_empty());
+    }
+
+    #[test]
+    fn inclusion_state_with_confirmation_depth() {
+        assert_eq!(inclusion_state_at_depth(None, 0, None), InclusionState::Confirmed);
+        assert_eq!(inclusion_state_at_depth(Some(10), 0, None), InclusionState::Confirmed);
+        assert_eq!(inclusion_state_at_depth(Some(10), 3, None), InclusionState::Included);
+        assert_eq!(inclusion_state_at_depth(None, 3, Some(20)), InclusionState::Included);
+        assert_eq!(
+            inclusion_state_at_depth(Some(10), 3, Some(12)),
+            InclusionState::Included
+        );
+        assert_eq!(
+            inclusion_state_at_depth(Some(10), 3, Some(13)),
+            InclusionState::Confirmed
+        );
+    }
+}
@@ -0,0 +1,174 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::api::input_selection::Selected,
+    types::block::output::{Output, TokenId},
+    wallet::{
+        account::{Account, AccountDetails},
+        Error, Result,
+    },
+};
+
+/// A policy restricting which native tokens an account sends and claims automatically. Transactions that send other
+/// native tokens to addresses outside of the account are rejected, unless
+/// [`TransactionOptions::allow_restricted_tokens`](crate::wallet::account::TransactionOptions::allow_restricted_tokens)
+/// is set, and outputs with other native tokens are not returned as claimable by
+/// [`Account::get_unlockable_outputs_with_additional_unlock_conditions()`], but can still be claimed explicitly.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenPolicy {
+    /// The native tokens that are allowed.
+    pub allowed_token_ids: HashSet<TokenId>,
+}
+
+impl TokenPolicy {
+    /// Creates a new policy that allows the given native tokens.
+    pub fn new(allowed_token_ids: impl IntoIterator<Item = TokenId>) -> Self {
+        Self {
+            allowed_token_ids: allowed_token_ids.into_iter().collect(),
+        }
+    }
+
+    /// Returns whether the native token is allowed.
+    pub fn is_allowed(&self, token_id: &TokenId) -> bool {
+        self.allowed_token_ids.contains(token_id)
+    }
+
+    /// Returns the first native token of the output that isn't allowed.
+    pub(crate) fn first_restricted_token(&self, output: &Output) -> Option<TokenId> {
+        output
+            .native_tokens()?
+            .iter()
+            .map(|native_token| *native_token.token_id())
+            .find(|token_id| !self.is_allowed(token_id))
+    }
+}
+
+impl Account {
+    /// Sets the token policy of the account, `None` removes it, so all native tokens are allowed.
+    pub async fn set_token_policy(&self, token_policy: Option<TokenPolicy>) -> Result<()> {
+        let mut account_details = self.details_mut().await;
+        account_details.token_policy = token_policy;
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+        Ok(())
+    }
+
+    /// Returns the token policy of the account.
+    pub async fn token_policy(&self) -> Option<TokenPolicy> {
+        self.details().await.token_policy.clone()
+    }
+}
+
+/// Verifies that the outputs of the selection don't send native tokens that aren't allowed by the token policy of the
+/// account to addresses outside of the account.
+pub(crate) fn verify_token_policy(account_details: &AccountDetails, selected: &Selected) -> Result<()> {
+    let Some(token_policy) = &account_details.token_policy else {
+        return Ok(());
+    };
+
+    for output in &selected.outputs {
+        let Some(token_id) = token_policy.first_restricted_token(output) else {
+            continue;
+        };
+        let own_address = output
+            .unlock_conditions()
+            .and_then(|unlock_conditions| unlock_conditions.address())
+            .map_or(false, |unlock_condition| {
+                account_details
+                    .public_addresses
+                    .iter()
+                    .chain(account_details.internal_addresses.iter())
+                    .any(|account_address| account_address.address.inner() == unlock_condition.address())
+            });
+        if !own_address {
+            return Err(Error::TokenNotAllowed(token_id));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use primitive_types::U256;
+
+    use super::*;
+    use crate::types::block::{
+        address::{Address, Ed25519Address},
+        output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder, NativeToken},
+    };
+
+    const TOKEN_SUPPLY: u64 = 1_813_620_509_061_365;
+
+    fn output(address: Address, token_ids: &[TokenId]) -> Output {
+        let mut builder =
+            BasicOutputBuilder::new_with_amount(1_000_000).add_unlock_condition(AddressUnlockCondition::new(address));
+        for token_id in token_ids {
+            builder = builder.add_native_token(NativeToken::new(*token_id, U256::from(10)).unwrap());
+        }
+        builder.finish_output(TOKEN_SUPPLY).unwrap()
+    }
+
+    fn selected(outputs: Vec<Output>) -> Selected {
+        Selected {
+            inputs: Vec::new(),
+            outputs,
+            remainder: None,
+        }
+    }
+
+    #[test]
+    fn first_restricted_token() {
+        let allowed = TokenId::new([1; TokenId::LENGTH]);
+        let restricted = TokenId::new([2; TokenId::LENGTH]);
+        let address = Address::Ed25519(Ed25519Address::new([1; Ed25519Address::LENGTH]));
+        let token_policy = TokenPolicy::new([allowed]);
+
+        assert_eq!(token_policy.first_restricted_token(&output(address, &[])), None);
+        assert_eq!(token_policy.first_restricted_token(&output(address, &[allowed])), None);
+        assert_eq!(
+            token_policy.first_restricted_token(&output(address, &[allowed, restricted])),
+            Some(restricted)
+        );
+    }
+
+    #[test]
+    fn verify_token_policy_outputs() {
+        let allowed = TokenId::new([1; TokenId::LENGTH]);
+        let restricted = TokenId::new([2; TokenId::LENGTH]);
+        let mut account_details = AccountDetails::mock();
+        let own_address = account_details.public_addresses[0].address.inner;
+        let other_address = Address::Ed25519(Ed25519Address::new([1; Ed25519Address::LENGTH]));
+
+        // Without a policy all native tokens are allowed
+        verify_token_policy(&account_details, &selected(vec![output(other_address, &[restricted])])).unwrap();
+
+        account_details.token_policy = Some(TokenPolicy::new([allowed]));
+        verify_token_policy(&account_details, &selected(vec![output(other_address, &[allowed])])).unwrap();
+        // Restricted tokens can stay in the account, for example in the remainder
+        verify_token_policy(
+            &account_details,
+            &selected(vec![
+                output(other_address, &[allowed]),
+                output(own_address, &[allowed, restricted]),
+            ]),
+        )
+        .unwrap();
+        assert!(matches!(
+            verify_token_policy(
+                &account_details,
+                &selected(vec![
+                    output(own_address, &[restricted]),
+                    output(other_address, &[restricted]),
+                ]),
+            ),
+            Err(Error::TokenNotAllowed(token_id)) if token_id == restricted
+        ));
+    }
+}
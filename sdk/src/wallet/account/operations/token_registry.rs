@@ -0,0 +1,127 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    types::block::output::{FoundryId, FoundryOutput, TokenId},
+    wallet::{account::Account, Result},
+};
+
+/// IRC30 metadata of a native token, stored in the immutable metadata feature of the foundry that minted it.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Irc30Metadata {
+    standard: String,
+    /// The name of the token
+    #[serde(default)]
+    pub name: String,
+    /// The symbol of the token
+    pub symbol: String,
+    /// The number of decimals of the token amounts
+    pub decimals: u8,
+    /// A description of the token
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// A URL pointing to a website of the token
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// A URL pointing to the logo of the token
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub logo_url: Option<String>,
+    /// The logo of the token as SVG
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub logo: Option<String>,
+}
+
+impl Irc30Metadata {
+    /// Parses the foundry metadata of a native token, if it follows the IRC30 standard.
+    pub fn from_foundry_metadata(metadata: &[u8]) -> Option<Self> {
+        serde_json::from_slice::<Self>(metadata)
+            .ok()
+            .filter(|metadata| metadata.standard == "IRC30")
+    }
+
+    /// Parses the IRC30 metadata from the immutable metadata feature of a foundry.
+    pub fn from_foundry(foundry: &FoundryOutput) -> Option<Self> {
+        Self::from_foundry_metadata(foundry.immutable_features().metadata()?.data())
+    }
+}
+
+/// Resolves native token ids to the IRC30 metadata of their foundries, returned from [`Account::token_registry()`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct TokenRegistry {
+    metadata: HashMap<TokenId, Irc30Metadata>,
+}
+
+impl TokenRegistry {
+    /// Creates a [`TokenRegistry`] from foundries, foundries without IRC30 metadata are ignored.
+    pub fn new<'a>(foundries: impl IntoIterator<Item = &'a FoundryOutput>) -> Self {
+        Self {
+            metadata: foundries
+                .into_iter()
+                .filter_map(|foundry| {
+                    Irc30Metadata::from_foundry(foundry).map(|metadata| (foundry.token_id(), metadata))
+                })
+                .collect(),
+        }
+    }
+
+    /// Returns the IRC30 metadata of a native token.
+    pub fn get(&self, token_id: &TokenId) -> Option<&Irc30Metadata> {
+        self.metadata.get(token_id)
+    }
+
+    /// Returns the symbol of a native token.
+    pub fn symbol(&self, token_id: &TokenId) -> Option<&str> {
+        self.get(token_id).map(|metadata| metadata.symbol.as_str())
+    }
+
+    /// Returns the decimals of a native token.
+    pub fn decimals(&self, token_id: &TokenId) -> Option<u8> {
+        self.get(token_id).map(|metadata| metadata.decimals)
+    }
+
+    /// Returns the decimals of all native tokens in the registry.
+    pub fn all_decimals(&self) -> HashMap<TokenId, u8> {
+        self.metadata
+            .iter()
+            .map(|(token_id, metadata)| (*token_id, metadata.decimals))
+            .collect()
+    }
+
+    /// Returns an iterator over the native tokens in the registry and their IRC30 metadata.
+    pub fn iter(&self) -> impl Iterator<Item = (&TokenId, &Irc30Metadata)> {
+        self.metadata.iter()
+    }
+}
+
+impl Account {
+    /// Returns a [`TokenRegistry`] with the IRC30 metadata of the native tokens whose foundries are known to the
+    /// account.
+    pub async fn token_registry(&self) -> TokenRegistry {
+        TokenRegistry::new(self.details().await.native_token_foundries.values())
+    }
+
+    /// Returns the IRC30 metadata of a native token, `None` if its foundry doesn't exist or has no IRC30 metadata. The
+    /// foundry is requested from the node if it isn't known to the account yet and cached in the account.
+    pub async fn native_token_metadata(&self, token_id: TokenId) -> Result<Option<Irc30Metadata>> {
+        let foundry_id = FoundryId::from(token_id);
+
+        if !self.details().await.native_token_foundries.contains_key(&foundry_id) {
+            self.request_and_store_foundry_outputs(HashSet::from([foundry_id]))
+                .await?;
+            #[cfg(feature = "storage")]
+            self.save(None).await?;
+        }
+
+        Ok(self
+            .details()
+            .await
+            .native_token_foundries
+            .get(&foundry_id)
+            .and_then(Irc30Metadata::from_foundry))
+    }
+}
@@ -0,0 +1,134 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    types::block::output::{Output, Rent},
+    wallet::account::{
+        operations::transaction::{RemainderValueStrategy, TransactionOptions},
+        Account,
+    },
+};
+
+/// An estimate of a transaction, see [`Account::estimate_transaction()`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionEstimate {
+    /// The estimates of the provided outputs, in the same order.
+    pub outputs: Vec<OutputEstimate>,
+    /// Amount of the inputs that would be selected, which is consumed by the transaction.
+    #[serde(with = "crate::utils::serde::string")]
+    pub inputs_amount: u64,
+    /// Amount of inputs that would be selected.
+    pub input_count: usize,
+    /// Amount of the remainder output, if one is needed.
+    #[serde(default, with = "crate::utils::serde::option_string")]
+    pub remainder_amount: Option<u64>,
+    /// Storage deposit that would additionally be locked, the storage deposit of the outputs minus the one of the
+    /// inputs.
+    #[serde(with = "crate::utils::serde::string")]
+    pub storage_deposit: u64,
+    /// If any output needs to be claimed by its recipient, because it has a storage deposit return or an expiration
+    /// unlock condition.
+    pub requires_claiming: bool,
+}
+
+/// An estimate of an output of a transaction.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutputEstimate {
+    /// Amount of the output.
+    #[serde(with = "crate::utils::serde::string")]
+    pub amount: u64,
+    /// Storage deposit the output requires.
+    #[serde(with = "crate::utils::serde::string")]
+    pub required_storage_deposit: u64,
+    /// Amount that needs to be returned to the sender, if the output has a storage deposit return unlock condition.
+    #[serde(default, with = "crate::utils::serde::option_string")]
+    pub storage_deposit_return: Option<u64>,
+    /// Unix timestamp after which the output returns to the sender, if it has an expiration unlock condition.
+    pub expiration: Option<u32>,
+    /// Unix timestamp until which the output is locked, if it has a timelock unlock condition.
+    pub timelock: Option<u32>,
+}
+
+impl Account {
+    /// Estimates a transaction with the outputs and options, to show the amounts and storage deposits before the
+    /// transaction is sent. Runs the input selection, but without locking the inputs or any other changes to the
+    /// account, so the actually selected inputs of a later transaction may differ.
+    pub async fn estimate_transaction(
+        &self,
+        outputs: Vec<Output>,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<TransactionEstimate> {
+        log::debug!("[TRANSACTION] estimate_transaction");
+        let options = options.into();
+        let rent_structure = self.network_params().await?.rent_structure;
+
+        let output_estimates = outputs
+            .iter()
+            .map(|output| {
+                let unlock_conditions = output.unlock_conditions();
+                OutputEstimate {
+                    amount: output.amount(),
+                    required_storage_deposit: output.rent_cost(&rent_structure),
+                    storage_deposit_return: unlock_conditions
+                        .and_then(|unlock_conditions| unlock_conditions.storage_deposit_return())
+                        .map(|storage_deposit_return| storage_deposit_return.amount()),
+                    expiration: unlock_conditions
+                        .and_then(|unlock_conditions| unlock_conditions.expiration())
+                        .map(|expiration| expiration.timestamp()),
+                    timelock: unlock_conditions
+                        .and_then(|unlock_conditions| unlock_conditions.timelock())
+                        .map(|timelock| timelock.timestamp()),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // A change address isn't generated for the estimate, the remainder amount doesn't depend on the address
+        let remainder_address = options
+            .as_ref()
+            .and_then(|options| match &options.remainder_value_strategy {
+                RemainderValueStrategy::CustomAddress(address) => Some(address.address().inner),
+                RemainderValueStrategy::Split { addresses, .. } => addresses.first().map(|address| *address.inner()),
+                RemainderValueStrategy::ReuseAddress | RemainderValueStrategy::ChangeAddress => None,
+            });
+
+        let selected = self
+            .select_inputs(
+                outputs,
+                options
+                    .as_ref()
+                    .and_then(|options| options.custom_inputs.as_ref())
+                    .map(|inputs| HashSet::from_iter(inputs.clone())),
+                options
+                    .as_ref()
+                    .and_then(|options| options.mandatory_inputs.as_ref())
+                    .map(|inputs| HashSet::from_iter(inputs.clone())),
+                remainder_address,
+                options.as_ref().and_then(|options| options.burn.as_ref()),
+                options
+                    .as_ref()
+                    .map(|options| options.input_selection_strategy)
+                    .unwrap_or_default(),
+                false,
+            )
+            .await?;
+
+        let storage_deposit = selected.additional_storage_deposit(&rent_structure);
+
+        Ok(TransactionEstimate {
+            requires_claiming: output_estimates.iter().any(|output_estimate| {
+                output_estimate.storage_deposit_return.is_some() || output_estimate.expiration.is_some()
+            }),
+            outputs: output_estimates,
+            inputs_amount: selected.inputs.iter().map(|input| input.output.amount()).sum(),
+            input_count: selected.inputs.len(),
+            remainder_amount: selected.remainder.as_ref().map(|remainder| remainder.output.amount()),
+            storage_deposit,
+        })
+    }
+}
@@ -0,0 +1,359 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use primitive_types::U256;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    types::block::output::{AliasId, AliasTransition, ChainId, FoundryId, Output, TokenScheme},
+    wallet::account::{operations::helpers::time::can_output_be_unlocked_now, Account, AccountDetails},
+};
+
+/// A reason why a chain output can't be burned or destroyed at the moment.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum BurnBlocker {
+    /// The chain output isn't an unspent output of the account.
+    NotOwned,
+    /// The chain output is already used as input for a pending transaction.
+    Locked,
+    /// The output is timelocked until the given unix timestamp.
+    #[serde(rename_all = "camelCase")]
+    Timelocked { unix_time: u32 },
+    /// The output has an expiration unlock condition which currently prevents the account from unlocking it.
+    #[serde(rename_all = "camelCase")]
+    ExpirationLocked { unix_time: u32 },
+    /// The output can't be unlocked by an address of the account.
+    NotUnlockable,
+    /// The foundry has a circulating supply, which needs to be melted first.
+    #[serde(rename_all = "camelCase")]
+    FoundryHasCirculatingSupply { circulating_supply: U256 },
+    /// The alias which controls the foundry isn't available in the account.
+    #[serde(rename_all = "camelCase")]
+    ControllingAliasNotOwned { alias_id: AliasId },
+    /// The alias controls foundries, which need to be destroyed first.
+    #[serde(rename_all = "camelCase")]
+    AliasControlsFoundries { foundry_ids: Vec<FoundryId> },
+}
+
+impl core::fmt::Display for BurnBlocker {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::NotOwned => write!(f, "the output isn't owned by the account"),
+            Self::Locked => write!(f, "the output is used in a pending transaction"),
+            Self::Timelocked { unix_time } => write!(f, "the output is timelocked until {unix_time}"),
+            Self::ExpirationLocked { unix_time } => {
+                write!(
+                    f,
+                    "the output can't be unlocked because of its expiration at {unix_time}"
+                )
+            }
+            Self::NotUnlockable => write!(f, "the output can't be unlocked by the account"),
+            Self::FoundryHasCirculatingSupply { circulating_supply } => {
+                write!(f, "the foundry has a circulating supply of {circulating_supply}")
+            }
+            Self::ControllingAliasNotOwned { alias_id } => {
+                write!(f, "the controlling alias {alias_id} isn't owned by the account")
+            }
+            Self::AliasControlsFoundries { foundry_ids } => {
+                write!(f, "the alias controls {} foundries", foundry_ids.len())
+            }
+        }
+    }
+}
+
+impl Account {
+    /// Checks if an alias, foundry or nft output can be burned, based on the current account state. Returns the list
+    /// of reasons preventing it, an empty list means the output can be burned.
+    /// ```ignore
+    /// let blockers = account.can_burn(nft_id).await?;
+    /// for blocker in &blockers {
+    ///     println!("Can't burn the nft: {blocker}");
+    /// }
+    /// ```
+    pub async fn can_burn(&self, chain_id: impl Into<ChainId> + Send) -> crate::wallet::Result<Vec<BurnBlocker>> {
+        log::debug!("[can_burn]");
+        let chain_id = chain_id.into();
+        let current_time = self.client().get_time_checked().await?;
+
+        burn_blockers(&*self.details().await, chain_id, current_time)
+    }
+}
+
+// Returns the reasons why the chain output can't be burned at the current time
+fn burn_blockers(
+    account_details: &AccountDetails,
+    chain_id: ChainId,
+    current_time: u32,
+) -> crate::wallet::Result<Vec<BurnBlocker>> {
+    let Some(output_data) = account_details.unspent_outputs().values().find(|output_data| {
+        output_data
+            .output
+            .chain_id()
+            .map(|output_chain_id| output_chain_id.or_from_output_id(&output_data.output_id))
+            == Some(chain_id)
+    }) else {
+        return Ok(vec![BurnBlocker::NotOwned]);
+    };
+
+    let mut blockers = Vec::new();
+
+    if account_details.locked_outputs.contains(&output_data.output_id) {
+        blockers.push(BurnBlocker::Locked);
+    }
+
+    match &output_data.output {
+        Output::Foundry(foundry_output) => {
+            let TokenScheme::Simple(token_scheme) = foundry_output.token_scheme();
+            if !token_scheme.circulating_supply().is_zero() {
+                blockers.push(BurnBlocker::FoundryHasCirculatingSupply {
+                    circulating_supply: token_scheme.circulating_supply(),
+                });
+            }
+
+            // The foundry is unlocked by its alias, so the alias needs to be available
+            let alias_id = *foundry_output.alias_address().alias_id();
+            let alias_owned = account_details.unspent_outputs().values().any(|output_data| {
+                output_data
+                    .output
+                    .chain_id()
+                    .map(|output_chain_id| output_chain_id.or_from_output_id(&output_data.output_id))
+                    == Some(ChainId::Alias(alias_id))
+            });
+            if !alias_owned {
+                blockers.push(BurnBlocker::ControllingAliasNotOwned { alias_id });
+            }
+        }
+        Output::Alias(alias_output) => {
+            let alias_id = alias_output.alias_id_non_null(&output_data.output_id);
+            let foundry_ids = account_details
+                .unspent_outputs()
+                .values()
+                .filter_map(|output_data| match &output_data.output {
+                    Output::Foundry(foundry_output) if *foundry_output.alias_address().alias_id() == alias_id => {
+                        Some(foundry_output.id())
+                    }
+                    _ => None,
+                })
+                .collect::<Vec<_>>();
+            if !foundry_ids.is_empty() {
+                blockers.push(BurnBlocker::AliasControlsFoundries { foundry_ids });
+            }
+        }
+        _ => {}
+    }
+
+    if !output_data.output.is_foundry() {
+        let unlock_conditions = output_data.output.unlock_conditions();
+
+        let timelock = unlock_conditions
+            .and_then(|u| u.timelock())
+            .filter(|timelock| timelock.timestamp() > current_time);
+
+        if let Some(timelock) = timelock {
+            blockers.push(BurnBlocker::Timelocked {
+                unix_time: timelock.timestamp(),
+            });
+        } else if !can_output_be_unlocked_now(
+            &account_details.addresses_with_unspent_outputs,
+            &[],
+            output_data,
+            current_time,
+            // Destroying an alias requires a governance transition
+            output_data.output.is_alias().then_some(AliasTransition::Governance),
+        )? {
+            match unlock_conditions.and_then(|u| u.expiration()) {
+                Some(expiration) => blockers.push(BurnBlocker::ExpirationLocked {
+                    unix_time: expiration.timestamp(),
+                }),
+                None => blockers.push(BurnBlocker::NotUnlockable),
+            }
+        }
+    }
+
+    Ok(blockers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        types::block::{
+            address::{Address, AliasAddress, Ed25519Address},
+            output::{
+                unlock_condition::{
+                    AddressUnlockCondition, ExpirationUnlockCondition, GovernorAddressUnlockCondition,
+                    ImmutableAliasAddressUnlockCondition, StateControllerAddressUnlockCondition,
+                    TimelockUnlockCondition,
+                },
+                AliasOutputBuilder, FoundryOutputBuilder, NftId, NftOutputBuilder, OutputId, OutputMetadata,
+                SimpleTokenScheme, UnlockCondition,
+            },
+            payload::transaction::TransactionId,
+            BlockId,
+        },
+        wallet::account::types::{AddressWithUnspentOutputs, OutputData},
+    };
+
+    const TOKEN_SUPPLY: u64 = 1_813_620_509_061_365;
+    const CURRENT_TIME: u32 = 1_700_000_000;
+
+    fn account_details() -> AccountDetails {
+        let mut account_details = AccountDetails::mock();
+        account_details
+            .addresses_with_unspent_outputs
+            .push(AddressWithUnspentOutputs {
+                address: account_details.public_addresses[0].address,
+                key_index: 0,
+                internal: false,
+                output_ids: Vec::new(),
+            });
+        account_details
+    }
+
+    fn account_address(account_details: &AccountDetails) -> Address {
+        *account_details.public_addresses[0].address.inner()
+    }
+
+    fn add_output(account_details: &mut AccountDetails, index: u16, output: Output) -> OutputId {
+        let output_id = OutputId::new(TransactionId::new([1; TransactionId::LENGTH]), index).unwrap();
+        let output_data = OutputData {
+            output_id,
+            metadata: OutputMetadata::new(
+                BlockId::new([0; BlockId::LENGTH]),
+                output_id,
+                false,
+                None,
+                None,
+                None,
+                0,
+                0,
+                0,
+            ),
+            output,
+            is_spent: false,
+            address: account_address(account_details),
+            network_id: 0,
+            remainder: false,
+            chain: None,
+        };
+        account_details.unspent_outputs.insert(output_id, output_data);
+        output_id
+    }
+
+    fn nft_output(address: Address, unlock_condition: Option<UnlockCondition>) -> Output {
+        let mut output_builder = NftOutputBuilder::new_with_amount(1_000_000, NftId::null())
+            .add_unlock_condition(AddressUnlockCondition::new(address));
+        if let Some(unlock_condition) = unlock_condition {
+            output_builder = output_builder.add_unlock_condition(unlock_condition);
+        }
+        output_builder.finish_output(TOKEN_SUPPLY).unwrap()
+    }
+
+    #[test]
+    fn nft_burn_blockers() {
+        let mut account_details = account_details();
+        let address = account_address(&account_details);
+        let other_address = Address::Ed25519(Ed25519Address::new([1; Ed25519Address::LENGTH]));
+        let blockers = |account_details: &AccountDetails, output_id: &OutputId| {
+            burn_blockers(account_details, ChainId::Nft(NftId::from(output_id)), CURRENT_TIME).unwrap()
+        };
+
+        let owned = add_output(&mut account_details, 0, nft_output(address, None));
+        assert_eq!(blockers(&account_details, &owned), []);
+
+        account_details.locked_outputs.insert(owned);
+        assert_eq!(blockers(&account_details, &owned), [BurnBlocker::Locked]);
+
+        let timelocked = add_output(
+            &mut account_details,
+            1,
+            nft_output(
+                address,
+                Some(TimelockUnlockCondition::new(CURRENT_TIME + 1).unwrap().into()),
+            ),
+        );
+        assert_eq!(
+            blockers(&account_details, &timelocked),
+            [BurnBlocker::Timelocked {
+                unix_time: CURRENT_TIME + 1
+            }]
+        );
+
+        // The expiration returned the NFT to the sender
+        let expired = add_output(
+            &mut account_details,
+            2,
+            nft_output(
+                address,
+                Some(
+                    ExpirationUnlockCondition::new(other_address, CURRENT_TIME - 1)
+                        .unwrap()
+                        .into(),
+                ),
+            ),
+        );
+        assert_eq!(
+            blockers(&account_details, &expired),
+            [BurnBlocker::ExpirationLocked {
+                unix_time: CURRENT_TIME - 1
+            }]
+        );
+
+        let foreign = add_output(&mut account_details, 3, nft_output(other_address, None));
+        assert_eq!(blockers(&account_details, &foreign), [BurnBlocker::NotUnlockable]);
+
+        let unknown = OutputId::new(TransactionId::new([2; TransactionId::LENGTH]), 0).unwrap();
+        assert_eq!(blockers(&account_details, &unknown), [BurnBlocker::NotOwned]);
+    }
+
+    #[test]
+    fn alias_and_foundry_burn_blockers() {
+        let mut account_details = account_details();
+        let address = account_address(&account_details);
+        let alias_output_id = add_output(
+            &mut account_details,
+            0,
+            AliasOutputBuilder::new_with_amount(1_000_000, AliasId::null())
+                .add_unlock_condition(StateControllerAddressUnlockCondition::new(address))
+                .add_unlock_condition(GovernorAddressUnlockCondition::new(address))
+                .finish_output(TOKEN_SUPPLY)
+                .unwrap(),
+        );
+        let alias_id = AliasId::from(&alias_output_id);
+        let foundry_output = FoundryOutputBuilder::new_with_amount(
+            1_000_000,
+            1,
+            TokenScheme::Simple(SimpleTokenScheme::new(U256::from(10), U256::from(4), U256::from(100)).unwrap()),
+        )
+        .add_unlock_condition(ImmutableAliasAddressUnlockCondition::new(AliasAddress::new(alias_id)))
+        .finish(TOKEN_SUPPLY)
+        .unwrap();
+        let foundry_id = foundry_output.id();
+        add_output(&mut account_details, 1, Output::Foundry(foundry_output));
+
+        assert_eq!(
+            burn_blockers(&account_details, ChainId::Alias(alias_id), CURRENT_TIME).unwrap(),
+            [BurnBlocker::AliasControlsFoundries {
+                foundry_ids: vec![foundry_id]
+            }]
+        );
+        assert_eq!(
+            burn_blockers(&account_details, ChainId::Foundry(foundry_id), CURRENT_TIME).unwrap(),
+            [BurnBlocker::FoundryHasCirculatingSupply {
+                circulating_supply: U256::from(6)
+            }]
+        );
+
+        account_details.unspent_outputs.remove(&alias_output_id);
+        assert_eq!(
+            burn_blockers(&account_details, ChainId::Foundry(foundry_id), CURRENT_TIME).unwrap(),
+            [
+                BurnBlocker::FoundryHasCirculatingSupply {
+                    circulating_supply: U256::from(6)
+                },
+                BurnBlocker::ControllingAliasNotOwned { alias_id }
+            ]
+        );
+    }
+}
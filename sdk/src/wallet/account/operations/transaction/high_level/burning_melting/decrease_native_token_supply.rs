@@ -5,9 +5,7 @@ use primitive_types::U256;
 
 use crate::{
     client::api::PreparedTransactionData,
-    types::block::output::{
-        AliasId, AliasOutputBuilder, FoundryId, FoundryOutputBuilder, Output, SimpleTokenScheme, TokenId, TokenScheme,
-    },
+    types::block::output::{AliasId, FoundryId, FoundryTransitionBuilder, Output, TokenId},
     wallet::{
         account::{operations::transaction::Transaction, types::OutputData, Account, TransactionOptions},
         Error,
@@ -42,7 +40,7 @@ impl Account {
 
         let foundry_id = FoundryId::from(token_id);
         let alias_id = *foundry_id.alias_address().alias_id();
-        let token_supply = self.client().get_token_supply().await?;
+        let token_supply = self.network_params().await?.token_supply;
 
         let (existing_alias_output_data, existing_foundry_output) = self
             .find_alias_and_foundry_output_data(alias_id, foundry_id)
@@ -52,24 +50,15 @@ impl Account {
                 _ => unreachable!("We already checked it's a foundry output"),
             })?;
 
-        if let Output::Alias(alias_output) = &existing_alias_output_data.output {
-            // Create the new alias output with updated amount and state_index
-            let alias_output = AliasOutputBuilder::from(alias_output)
-                .with_alias_id(alias_id)
-                .with_state_index(alias_output.state_index() + 1)
-                .finish_output(token_supply)?;
-
-            let TokenScheme::Simple(token_scheme) = existing_foundry_output.token_scheme();
-            let outputs = vec![
+        if let Output::Alias(alias_output) = existing_alias_output_data.output {
+            // Create the new alias output with updated state_index and the new foundry output with melted native tokens
+            let outputs = FoundryTransitionBuilder::new(
                 alias_output,
-                FoundryOutputBuilder::from(&existing_foundry_output)
-                    .with_token_scheme(TokenScheme::Simple(SimpleTokenScheme::new(
-                        token_scheme.minted_tokens(),
-                        token_scheme.melted_tokens() + melt_amount,
-                        token_scheme.maximum_supply(),
-                    )?))
-                    .finish_output(token_supply)?,
-            ];
+                &existing_alias_output_data.output_id,
+                existing_foundry_output,
+            )
+            .with_melt_amount(melt_amount)
+            .finish_outputs(token_supply)?;
             // Input selection will detect that we're melting native tokens and add the required inputs if available
             self.prepare_transaction(outputs, options).await
         } else {
@@ -9,6 +9,7 @@ use crate::{
     },
 };
 
+pub(crate) mod can_burn;
 pub(crate) mod decrease_native_token_supply;
 
 impl Account {
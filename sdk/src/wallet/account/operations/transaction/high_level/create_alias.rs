@@ -10,11 +10,15 @@ use crate::{
         output::{
             feature::MetadataFeature,
             unlock_condition::{GovernorAddressUnlockCondition, StateControllerAddressUnlockCondition},
-            AliasId, AliasOutputBuilder, Output,
+            AliasId, AliasOutputBuilder, Output, OutputId,
         },
+        payload::transaction::TransactionEssence,
         Error,
     },
-    wallet::account::{types::Transaction, Account, OutputData, TransactionOptions},
+    wallet::account::{
+        types::{Transaction, TransactionDto},
+        Account, OutputData, TransactionOptions,
+    },
 };
 
 /// Params `create_alias_output()`
@@ -73,6 +77,45 @@ impl TryFrom<&CreateAliasParamsDto> for CreateAliasParams {
     }
 }
 
+/// The result of a transaction creating an alias output
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateAliasTransaction {
+    pub alias_id: AliasId,
+    pub transaction: Transaction,
+}
+
+/// Dto for CreateAliasTransaction
+#[derive(Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateAliasTransactionDto {
+    pub alias_id: AliasId,
+    pub transaction: TransactionDto,
+}
+
+impl From<&CreateAliasTransaction> for CreateAliasTransactionDto {
+    fn from(value: &CreateAliasTransaction) -> Self {
+        Self {
+            alias_id: value.alias_id,
+            transaction: TransactionDto::from(&value.transaction),
+        }
+    }
+}
+
+/// Computes the id of the alias created by a transaction, which is the alias output with a null alias id.
+pub(crate) fn created_alias_id(transaction: &Transaction) -> crate::wallet::Result<AliasId> {
+    let TransactionEssence::Regular(essence) = transaction.payload.essence();
+    let index = essence
+        .outputs()
+        .iter()
+        .position(|output| matches!(output, Output::Alias(alias_output) if alias_output.alias_id().is_null()))
+        .ok_or_else(|| {
+            crate::wallet::Error::InvalidOutputKind("the transaction doesn't create an alias output".to_string())
+        })?;
+
+    Ok(AliasId::from(&OutputId::new(transaction.transaction_id, index as u16)?))
+}
+
 impl Account {
     /// Function to create an alias output.
     /// ```ignore
@@ -83,20 +126,26 @@ impl Account {
     ///     state_metadata: Some(b"some alias state metadata".to_vec()),
     /// };
     ///
-    /// let transaction = account.create_alias_output(params, None).await?;
+    /// let alias_transaction = account.create_alias_output(params, None).await?;
     /// println!(
     ///     "Transaction sent: {}/transaction/{}",
     ///     std::env::var("EXPLORER_URL").unwrap(),
-    ///     transaction.transaction_id
+    ///     alias_transaction.transaction.transaction_id
     /// );
+    /// println!("Created alias: {}", alias_transaction.alias_id);
     /// ```
     pub async fn create_alias_output(
         &self,
         params: Option<CreateAliasParams>,
         options: impl Into<Option<TransactionOptions>> + Send,
-    ) -> crate::wallet::Result<Transaction> {
+    ) -> crate::wallet::Result<CreateAliasTransaction> {
         let prepared_transaction = self.prepare_create_alias_output(params, options).await?;
-        self.sign_and_submit_transaction(prepared_transaction).await
+        let transaction = self.sign_and_submit_transaction(prepared_transaction).await?;
+
+        Ok(CreateAliasTransaction {
+            alias_id: created_alias_id(&transaction)?,
+            transaction,
+        })
     }
 
     /// Function to prepare the transaction for
@@ -107,8 +156,9 @@ impl Account {
         options: impl Into<Option<TransactionOptions>> + Send,
     ) -> crate::wallet::Result<PreparedTransactionData> {
         log::debug!("[TRANSACTION] prepare_create_alias_output");
-        let rent_structure = self.client().get_rent_structure().await?;
-        let token_supply = self.client().get_token_supply().await?;
+        let network_params = self.network_params().await?;
+        let rent_structure = network_params.rent_structure;
+        let token_supply = network_params.token_supply;
 
         let controller_address = match params.as_ref().and_then(|options| options.address.as_ref()) {
             Some(bech32_address) => {
@@ -4,7 +4,10 @@
 use primitive_types::U256;
 
 use crate::{
-    types::block::output::{AliasOutputBuilder, FoundryOutputBuilder, Output, SimpleTokenScheme, TokenId, TokenScheme},
+    types::block::{
+        output::{FoundryTransitionBuilder, Output, TokenId},
+        Error as BlockError,
+    },
     wallet::{
         account::{
             operations::transaction::high_level::minting::mint_native_token::{
@@ -58,7 +61,7 @@ impl Account {
         log::debug!("[TRANSACTION] increase_native_token_supply");
 
         let account_details = self.details().await;
-        let token_supply = self.client().get_token_supply().await?;
+        let token_supply = self.network_params().await?.token_supply;
         let existing_foundry_output = account_details.unspent_outputs().values().find(|output_data| {
             if let Output::Foundry(output) = &output_data.output {
                 TokenId::new(*output.id()) == token_id
@@ -72,15 +75,6 @@ impl Account {
             .clone();
 
         let existing_alias_output = if let Output::Foundry(foundry_output) = &existing_foundry_output.output {
-            let TokenScheme::Simple(token_scheme) = foundry_output.token_scheme();
-            // Check if we can mint the provided amount without exceeding the maximum_supply
-            if token_scheme.maximum_supply() - token_scheme.circulating_supply() < mint_amount {
-                return Err(Error::MintingFailed(format!(
-                    "minting additional {mint_amount} tokens would exceed the maximum supply: {}",
-                    token_scheme.maximum_supply()
-                )));
-            }
-
             // Get the alias output that controls the foundry output
             let existing_alias_output = account_details.unspent_outputs().values().find(|output_data| {
                 if let Output::Alias(output) = &output_data.output {
@@ -109,28 +103,18 @@ impl Account {
             unreachable!("We checked if it's an foundry output before")
         };
 
-        // Create the next alias output with the same data, just updated state_index
-        let new_alias_output_builder =
-            AliasOutputBuilder::from(&alias_output).with_state_index(alias_output.state_index() + 1);
-
-        // Create next foundry output with minted native tokens
-
-        let TokenScheme::Simple(token_scheme) = foundry_output.token_scheme();
-
-        let updated_token_scheme = TokenScheme::Simple(SimpleTokenScheme::new(
-            token_scheme.minted_tokens() + mint_amount,
-            token_scheme.melted_tokens(),
-            token_scheme.maximum_supply(),
-        )?);
-
-        let new_foundry_output_builder =
-            FoundryOutputBuilder::from(&foundry_output).with_token_scheme(updated_token_scheme);
-
-        let outputs = vec![
-            new_alias_output_builder.finish_output(token_supply)?,
-            new_foundry_output_builder.finish_output(token_supply)?,
-            // Native Tokens will be added automatically in the remainder output in try_select_inputs()
-        ];
+        // Create the next alias output with an updated state_index and the next foundry output with minted native
+        // tokens, native tokens will be added automatically in the remainder output in try_select_inputs()
+        let outputs = FoundryTransitionBuilder::new(alias_output, &existing_alias_output.output_id, foundry_output)
+            .with_mint_amount(mint_amount)
+            .finish_outputs(token_supply)
+            .map_err(|error| match error {
+                // Check if we can mint the provided amount without exceeding the maximum_supply
+                BlockError::InvalidFoundryOutputSupply { max, .. } => Error::MintingFailed(format!(
+                    "minting additional {mint_amount} tokens would exceed the maximum supply: {max}"
+                )),
+                error => error.into(),
+            })?;
 
         self.prepare_transaction(outputs, options)
             .await
@@ -158,8 +158,9 @@ impl Account {
         options: impl Into<Option<TransactionOptions>> + Send,
     ) -> crate::wallet::Result<PreparedMintTokenTransaction> {
         log::debug!("[TRANSACTION] mint_native_token");
-        let rent_structure = self.client().get_rent_structure().await?;
-        let token_supply = self.client().get_token_supply().await?;
+        let network_params = self.network_params().await?;
+        let rent_structure = network_params.rent_structure;
+        let token_supply = network_params.token_supply;
 
         let (alias_id, alias_output) = self
             .get_alias_output(params.alias_id)
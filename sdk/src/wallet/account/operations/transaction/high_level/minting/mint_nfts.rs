@@ -10,12 +10,16 @@ use crate::{
         output::{
             feature::{IssuerFeature, MetadataFeature, SenderFeature, TagFeature},
             unlock_condition::AddressUnlockCondition,
-            NftId, NftOutputBuilder,
+            NftId, NftOutputBuilder, Output, OutputId,
         },
+        payload::transaction::TransactionEssence,
         Error as BlockError,
     },
     wallet::{
-        account::{operations::transaction::Transaction, Account, TransactionOptions},
+        account::{
+            types::{Transaction, TransactionDto},
+            Account, TransactionOptions,
+        },
         Error as WalletError,
     },
 };
@@ -84,6 +88,49 @@ impl TryFrom<&MintNftParamsDto> for MintNftParams {
     }
 }
 
+/// The result of a minting nfts transaction
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MintNftTransaction {
+    /// The ids of the minted nfts, in the order of the params
+    pub nft_ids: Vec<NftId>,
+    pub transaction: Transaction,
+}
+
+/// Dto for MintNftTransaction
+#[derive(Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MintNftTransactionDto {
+    pub nft_ids: Vec<NftId>,
+    pub transaction: TransactionDto,
+}
+
+impl From<&MintNftTransaction> for MintNftTransactionDto {
+    fn from(value: &MintNftTransaction) -> Self {
+        Self {
+            nft_ids: value.nft_ids.clone(),
+            transaction: TransactionDto::from(&value.transaction),
+        }
+    }
+}
+
+/// Computes the ids of the nfts created by a transaction, which are the nft outputs with a null nft id.
+pub(crate) fn minted_nft_ids(transaction: &Transaction) -> crate::wallet::Result<Vec<NftId>> {
+    let TransactionEssence::Regular(essence) = transaction.payload.essence();
+    let mut nft_ids = Vec::new();
+
+    for (index, output) in essence.outputs().iter().enumerate() {
+        if let Output::Nft(nft_output) = output {
+            if nft_output.nft_id().is_null() {
+                let output_id = OutputId::new(transaction.transaction_id, index as u16)?;
+                nft_ids.push(NftId::from(&output_id));
+            }
+        }
+    }
+
+    Ok(nft_ids)
+}
+
 impl Account {
     /// Function to mint nfts.
     /// Calls [Account.send()](crate::account::Account.send) internally, the options can define the
@@ -103,20 +150,26 @@ impl Account {
     ///     immutable_metadata: Some(b"some immutable nft metadata".to_vec()),
     /// }];
     ///
-    /// let transaction = account.mint_nfts(params, None).await?;
+    /// let mint_transaction = account.mint_nfts(params, None).await?;
     /// println!(
     ///     "Transaction sent: {}/transaction/{}",
     ///     std::env::var("EXPLORER_URL").unwrap(),
-    ///     transaction.transaction_id,
+    ///     mint_transaction.transaction.transaction_id,
     /// );
+    /// println!("Minted nfts: {:?}", mint_transaction.nft_ids);
     /// ```
     pub async fn mint_nfts(
         &self,
         params: Vec<MintNftParams>,
         options: impl Into<Option<TransactionOptions>> + Send,
-    ) -> crate::wallet::Result<Transaction> {
+    ) -> crate::wallet::Result<MintNftTransaction> {
         let prepared_transaction = self.prepare_mint_nfts(params, options).await?;
-        self.sign_and_submit_transaction(prepared_transaction).await
+        let transaction = self.sign_and_submit_transaction(prepared_transaction).await?;
+
+        Ok(MintNftTransaction {
+            nft_ids: minted_nft_ids(&transaction)?,
+            transaction,
+        })
     }
 
     /// Function to prepare the transaction for
@@ -127,8 +180,9 @@ impl Account {
         options: impl Into<Option<TransactionOptions>> + Send,
     ) -> crate::wallet::Result<PreparedTransactionData> {
         log::debug!("[TRANSACTION] prepare_mint_nfts");
-        let rent_structure = self.client().get_rent_structure().await?;
-        let token_supply = self.client().get_token_supply().await?;
+        let network_params = self.network_params().await?;
+        let rent_structure = network_params.rent_structure;
+        let token_supply = network_params.token_supply;
         let account_addresses = self.addresses().await?;
         let mut outputs = Vec::new();
 
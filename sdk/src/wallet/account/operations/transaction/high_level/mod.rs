@@ -6,5 +6,7 @@ pub(crate) mod create_alias;
 pub(crate) mod minimum_storage_deposit;
 pub(crate) mod minting;
 pub(crate) mod send_amount;
+pub(crate) mod send_assets;
 pub(crate) mod send_native_tokens;
 pub(crate) mod send_nft;
+pub(crate) mod sweep;
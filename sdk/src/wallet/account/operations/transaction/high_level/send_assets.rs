@@ -0,0 +1,186 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+
+use primitive_types::U256;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::api::PreparedTransactionData,
+    types::block::{
+        address::Bech32Address,
+        output::{NativeToken, NativeTokensBuilder, NftId, NftOutputBuilder, Output, TokenId},
+    },
+    wallet::{
+        account::{
+            operations::transaction::{
+                prepare_output::{Assets, OutputParams},
+                Transaction,
+            },
+            Account, TransactionOptions,
+        },
+        Error,
+    },
+};
+
+/// Assets to send to an address with `send_assets()`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssetTransfer {
+    /// Bech32 encoded address
+    pub address: Bech32Address,
+    /// Amount of base coins. If an NFT is sent to the address, the amount is added to the amount of its output. If
+    /// the amount is below the required storage deposit, the missing storage deposit gets returned to the sender.
+    #[serde(default, with = "crate::utils::serde::string")]
+    pub amount: u64,
+    /// Native tokens
+    #[serde(default)]
+    pub native_tokens: Vec<(TokenId, U256)>,
+    /// Nft id
+    #[serde(default)]
+    pub nft_id: Option<NftId>,
+}
+
+// The assets of all transfers to the same address
+struct MergedTransfer {
+    address: Bech32Address,
+    amount: u64,
+    native_tokens: NativeTokensBuilder,
+    nft_ids: Vec<NftId>,
+}
+
+impl Account {
+    /// Function to send base coins, native tokens and NFTs to multiple addresses in a single transaction, so either
+    /// all or none of the transfers happen. Transfers to the same address are merged and base coins and native tokens
+    /// are added to the output of the first NFT sent to an address, so only the minimal number of outputs is created.
+    /// Calls [Account.send()](crate::wallet::account::Account.send) internally, the options can define the
+    /// RemainderValueStrategy or custom inputs.
+    /// ```ignore
+    /// let transfers = vec![AssetTransfer {
+    ///     address: Bech32Address::try_from_str("rms1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluaw60xu")?,
+    ///     amount: 1_000_000,
+    ///     native_tokens: vec![(
+    ///         TokenId::from_str("08e68f7616cd4948efebc6a77c4f93aed770ac53860100000000000000000000000000000000")?,
+    ///         U256::from(50),
+    ///     )],
+    ///     nft_id: Some(NftId::from_str("0x04f9b54d488d2e83a6c90db08ae4b39651bbba8a")?),
+    /// }];
+    ///
+    /// let transaction = account.send_assets(transfers, None).await?;
+    /// ```
+    pub async fn send_assets(
+        &self,
+        transfers: Vec<AssetTransfer>,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<Transaction> {
+        let prepared_transaction = self.prepare_send_assets(transfers, options).await?;
+        self.sign_and_submit_transaction(prepared_transaction).await
+    }
+
+    /// Function to prepare the transaction for
+    /// [Account.send_assets()](crate::wallet::account::Account.send_assets)
+    pub async fn prepare_send_assets(
+        &self,
+        transfers: Vec<AssetTransfer>,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<PreparedTransactionData> {
+        log::debug!("[TRANSACTION] prepare_send_assets");
+        let options = options.into();
+        let network_params = self.network_params().await?;
+        let rent_structure = network_params.rent_structure;
+        let token_supply = network_params.token_supply;
+        let unspent_outputs = self.unspent_outputs(None).await?;
+
+        // Merge the transfers to the same address, so their assets can share outputs
+        let mut merged_transfers: Vec<MergedTransfer> = Vec::new();
+        let mut nft_ids = HashSet::new();
+        for transfer in transfers {
+            self.client().bech32_hrp_matches(transfer.address.hrp()).await?;
+
+            if let Some(nft_id) = transfer.nft_id {
+                if !nft_ids.insert(nft_id) {
+                    return Err(Error::DuplicateNftTransfer(nft_id));
+                }
+            }
+
+            let index = match merged_transfers
+                .iter()
+                .position(|merged_transfer| merged_transfer.address == transfer.address)
+            {
+                Some(index) => index,
+                None => {
+                    merged_transfers.push(MergedTransfer {
+                        address: transfer.address,
+                        amount: 0,
+                        native_tokens: NativeTokensBuilder::new(),
+                        nft_ids: Vec::new(),
+                    });
+                    merged_transfers.len() - 1
+                }
+            };
+            let merged_transfer = &mut merged_transfers[index];
+            merged_transfer.amount += transfer.amount;
+            for (token_id, amount) in transfer.native_tokens {
+                merged_transfer
+                    .native_tokens
+                    .add_native_token(NativeToken::new(token_id, amount)?)?;
+            }
+            merged_transfer.nft_ids.extend(transfer.nft_id);
+        }
+
+        let mut outputs = Vec::new();
+        for merged_transfer in merged_transfers {
+            let mut nft_outputs = Vec::new();
+            for nft_id in merged_transfer.nft_ids {
+                nft_outputs.push(
+                    self.nft_transfer_output(&unspent_outputs, nft_id, merged_transfer.address, token_supply)
+                        .await?,
+                );
+            }
+
+            if merged_transfer.amount > 0 || !merged_transfer.native_tokens.is_empty() {
+                if let Some(Output::Nft(nft_output)) = nft_outputs.first() {
+                    // Add the base coins and native tokens to the output of the first NFT, so no additional output is
+                    // needed
+                    let mut native_tokens = NativeTokensBuilder::from(nft_output.native_tokens().clone());
+                    native_tokens.merge(merged_transfer.native_tokens)?;
+                    let nft_output_builder =
+                        NftOutputBuilder::from(nft_output).with_native_tokens(native_tokens.finish_vec()?);
+                    // The sender covers the storage deposit the additional native tokens might require
+                    let minimum_amount = nft_output_builder
+                        .clone()
+                        .with_minimum_storage_deposit(rent_structure)
+                        .finish(token_supply)?
+                        .amount();
+                    let output = nft_output_builder
+                        .with_amount((nft_output.amount() + merged_transfer.amount).max(minimum_amount))
+                        .finish_output(token_supply)?;
+                    nft_outputs[0] = output;
+                } else {
+                    outputs.push(
+                        self.prepare_output(
+                            OutputParams {
+                                recipient_address: merged_transfer.address,
+                                amount: merged_transfer.amount,
+                                assets: Some(Assets {
+                                    native_tokens: Some(merged_transfer.native_tokens.finish_vec()?),
+                                    nft_id: None,
+                                }),
+                                features: None,
+                                unlocks: None,
+                                storage_deposit: None,
+                            },
+                            options.clone(),
+                        )
+                        .await?,
+                    );
+                }
+            }
+
+            outputs.extend(nft_outputs);
+        }
+
+        self.prepare_transaction(outputs, options).await
+    }
+}
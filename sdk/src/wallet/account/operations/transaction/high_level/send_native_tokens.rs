@@ -1,6 +1,8 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::{collections::HashSet, str::FromStr};
+
 use primitive_types::U256;
 use serde::{Deserialize, Serialize};
 
@@ -21,7 +23,8 @@ use crate::{
             operations::transaction::{
                 high_level::minimum_storage_deposit::minimum_storage_deposit_basic_native_tokens, Transaction,
             },
-            Account, TransactionOptions,
+            types::parse_native_token_amount,
+            Account, Irc30Metadata, TransactionOptions,
         },
         Error, Result,
     },
@@ -43,6 +46,32 @@ pub struct SendNativeTokensParams {
     pub expiration: Option<u32>,
 }
 
+/// Params for `send_decimal_native_tokens()`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SendDecimalNativeTokensParams {
+    /// Bech32 encoded address
+    pub address: Bech32Address,
+    /// Native tokens with decimal amounts, referenced by token id or IRC30 symbol
+    pub native_tokens: Vec<DecimalNativeToken>,
+    /// Bech32 encoded address return address, to which the storage deposit will be returned. Default will use the
+    /// first address of the account
+    pub return_address: Option<Bech32Address>,
+    /// Expiration in seconds, after which the output will be available for the sender again, if not spent by the
+    /// receiver before. Default is 1 day
+    pub expiration: Option<u32>,
+}
+
+/// A native token amount as decimal string, resolved with the IRC30 metadata of the foundry of the token
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DecimalNativeToken {
+    /// Token id or IRC30 symbol of the native token
+    pub token: String,
+    /// Decimal amount, e.g. `"1.5"`
+    pub amount: String,
+}
+
 impl Account {
     /// Function to send native tokens in basic outputs with a [StorageDepositReturnUnlockCondition] and
     /// [ExpirationUnlockCondition], so the storage deposit gets back to the sender and also that the sender gets access
@@ -75,6 +104,98 @@ impl Account {
         self.sign_and_submit_transaction(prepared_transaction).await
     }
 
+    /// Sends native tokens like [`Account::send_native_tokens()`], with decimal amounts of tokens that are referenced
+    /// by token id or IRC30 symbol. A symbol is only resolved among the native tokens the account holds, so a foreign
+    /// token can't take the symbol of an own one.
+    /// ```ignore
+    /// let params = vec![SendDecimalNativeTokensParams {
+    ///     address: Bech32Address::try_from_str("rms1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluaw60xu")?,
+    ///     native_tokens: vec![DecimalNativeToken {
+    ///         token: "TST".to_string(),
+    ///         amount: "1.5".to_string(),
+    ///     }],
+    ///     return_address: None,
+    ///     expiration: None,
+    /// }];
+    ///
+    /// let tx = account.send_decimal_native_tokens(params, None).await?;
+    /// ```
+    pub async fn send_decimal_native_tokens(
+        &self,
+        params: Vec<SendDecimalNativeTokensParams>,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<Transaction> {
+        let prepared_transaction = self.prepare_send_decimal_native_tokens(params, options).await?;
+        self.sign_and_submit_transaction(prepared_transaction).await
+    }
+
+    /// Function to prepare the transaction for
+    /// [Account.send_decimal_native_tokens()](crate::account::Account.send_decimal_native_tokens)
+    pub async fn prepare_send_decimal_native_tokens(
+        &self,
+        params: Vec<SendDecimalNativeTokensParams>,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<PreparedTransactionData> {
+        log::debug!("[TRANSACTION] prepare_send_decimal_native_tokens");
+        let mut held_tokens = None;
+
+        let mut native_tokens_params = Vec::with_capacity(params.len());
+        for SendDecimalNativeTokensParams {
+            address,
+            native_tokens,
+            return_address,
+            expiration,
+        } in params
+        {
+            let mut resolved_native_tokens = Vec::with_capacity(native_tokens.len());
+            for native_token in &native_tokens {
+                let (token_id, metadata) = match TokenId::from_str(&native_token.token) {
+                    Ok(token_id) => (
+                        token_id,
+                        self.native_token_metadata(token_id)
+                            .await?
+                            .ok_or_else(|| Error::UnknownToken(native_token.token.clone()))?,
+                    ),
+                    Err(_) => {
+                        if held_tokens.is_none() {
+                            held_tokens = Some(self.held_token_metadata().await?);
+                        }
+                        token_by_symbol(held_tokens.as_deref().unwrap_or_default(), &native_token.token)?
+                    }
+                };
+                resolved_native_tokens.push((token_id, decimal_native_token_amount(native_token, &metadata)?));
+            }
+
+            native_tokens_params.push(SendNativeTokensParams {
+                address,
+                native_tokens: resolved_native_tokens,
+                return_address,
+                expiration,
+            });
+        }
+
+        self.prepare_send_native_tokens(native_tokens_params, options).await
+    }
+
+    // Returns the IRC30 metadata of the native tokens in the unspent outputs of the account
+    async fn held_token_metadata(&self) -> Result<Vec<(TokenId, Irc30Metadata)>> {
+        let token_ids = self
+            .unspent_outputs(None)
+            .await?
+            .iter()
+            .filter_map(|output_data| output_data.output.native_tokens())
+            .flat_map(|native_tokens| native_tokens.iter().map(|native_token| *native_token.token_id()))
+            .collect::<HashSet<_>>();
+
+        let mut tokens = Vec::new();
+        for token_id in token_ids {
+            if let Some(metadata) = self.native_token_metadata(token_id).await? {
+                tokens.push((token_id, metadata));
+            }
+        }
+        Ok(tokens)
+    }
+
     /// Function to prepare the transaction for
     /// [Account.send_native_tokens()](crate::account::Account.send_native_tokens)
     pub async fn prepare_send_native_tokens(
@@ -83,8 +204,9 @@ impl Account {
         options: impl Into<Option<TransactionOptions>> + Send,
     ) -> crate::wallet::Result<PreparedTransactionData> {
         log::debug!("[TRANSACTION] prepare_send_native_tokens");
-        let rent_structure = self.client().get_rent_structure().await?;
-        let token_supply = self.client().get_token_supply().await?;
+        let network_params = self.network_params().await?;
+        let rent_structure = network_params.rent_structure;
+        let token_supply = network_params.token_supply;
 
         let account_addresses = self.addresses().await?;
         let default_return_address = account_addresses.first().ok_or(Error::FailedToGetRemainder)?;
@@ -153,3 +275,84 @@ impl Account {
         self.prepare_transaction(outputs, options).await
     }
 }
+
+// Returns the token with the IRC30 symbol, an error if no or multiple tokens have it
+fn token_by_symbol(tokens: &[(TokenId, Irc30Metadata)], symbol: &str) -> Result<(TokenId, Irc30Metadata)> {
+    let matches = tokens
+        .iter()
+        .filter(|(_, metadata)| metadata.symbol == symbol)
+        .collect::<Vec<_>>();
+    match matches[..] {
+        [] => Err(Error::UnknownToken(symbol.to_string())),
+        [token] => Ok(token.clone()),
+        _ => Err(Error::AmbiguousTokenSymbol {
+            symbol: symbol.to_string(),
+            token_ids: matches.iter().map(|(token_id, _)| *token_id).collect(),
+        }),
+    }
+}
+
+// Parses the decimal amount of a native token with the decimals of its IRC30 metadata
+fn decimal_native_token_amount(native_token: &DecimalNativeToken, metadata: &Irc30Metadata) -> Result<U256> {
+    parse_native_token_amount(&native_token.amount, metadata.decimals).ok_or_else(|| Error::InvalidDecimalAmount {
+        amount: native_token.amount.clone(),
+        decimals: metadata.decimals,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn token(byte: u8, symbol: &str, decimals: u8) -> (TokenId, Irc30Metadata) {
+        let metadata = serde_json::json!({
+            "standard": "IRC30",
+            "name": symbol,
+            "symbol": symbol,
+            "decimals": decimals,
+        });
+        (
+            TokenId::from([byte; TokenId::LENGTH]),
+            Irc30Metadata::from_foundry_metadata(metadata.to_string().as_bytes()).unwrap(),
+        )
+    }
+
+    fn decimal_native_token(token: &str, amount: &str) -> DecimalNativeToken {
+        DecimalNativeToken {
+            token: token.to_string(),
+            amount: amount.to_string(),
+        }
+    }
+
+    #[test]
+    fn resolve_token_by_symbol() {
+        let tokens = [token(1, "TST", 2), token(2, "FOO", 0), token(3, "FOO", 6)];
+
+        assert_eq!(token_by_symbol(&tokens, "TST").unwrap(), tokens[0]);
+        assert!(matches!(
+            token_by_symbol(&tokens, "BAR"),
+            Err(Error::UnknownToken(symbol)) if symbol == "BAR"
+        ));
+        assert!(matches!(
+            token_by_symbol(&tokens, "FOO"),
+            Err(Error::AmbiguousTokenSymbol { symbol, token_ids })
+                if symbol == "FOO" && token_ids == [tokens[1].0, tokens[2].0]
+        ));
+        // Symbols are case sensitive
+        assert!(matches!(token_by_symbol(&tokens, "tst"), Err(Error::UnknownToken(_))));
+    }
+
+    #[test]
+    fn resolve_decimal_native_token_amount() {
+        let (_, metadata) = token(1, "TST", 2);
+
+        assert_eq!(
+            decimal_native_token_amount(&decimal_native_token("TST", "1.5"), &metadata).unwrap(),
+            U256::from(150)
+        );
+        assert!(matches!(
+            decimal_native_token_amount(&decimal_native_token("TST", "1.505"), &metadata),
+            Err(Error::InvalidDecimalAmount { amount, decimals: 2 }) if amount == "1.505"
+        ));
+    }
+}
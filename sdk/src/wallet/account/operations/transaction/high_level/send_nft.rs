@@ -9,8 +9,12 @@ use crate::{
     types::block::{
         address::Bech32Address,
         output::{unlock_condition::AddressUnlockCondition, NftId, NftOutputBuilder, Output},
+        payload::transaction::TransactionId,
+    },
+    wallet::account::{
+        constants::MAX_NFTS_PER_TRANSACTION, operations::transaction::Transaction, types::OutputData, Account,
+        TransactionOptions,
     },
-    wallet::account::{operations::transaction::Transaction, Account, TransactionOptions},
 };
 
 /// Params for `send_nft()`
@@ -23,6 +27,17 @@ pub struct SendNftParams {
     pub nft_id: NftId,
 }
 
+/// The result of sending a single NFT with [`Account::send_nfts()`].
+#[derive(Debug)]
+pub struct NftSendResult {
+    /// The id of the NFT.
+    pub nft_id: NftId,
+    /// The address the NFT was sent to.
+    pub address: Bech32Address,
+    /// The id of the transaction that sent the NFT, or the error that made sending it fail.
+    pub result: crate::wallet::Result<TransactionId>,
+}
+
 impl Account {
     /// Function to send native tokens in basic outputs with a
     /// [`StorageDepositReturnUnlockCondition`](crate::types::block::output::unlock_condition::StorageDepositReturnUnlockCondition) and
@@ -64,33 +79,115 @@ impl Account {
         log::debug!("[TRANSACTION] prepare_send_nft");
 
         let unspent_outputs = self.unspent_outputs(None).await?;
-        let token_supply = self.client().get_token_supply().await?;
+        let token_supply = self.network_params().await?.token_supply;
 
         let mut outputs = Vec::new();
 
         for SendNftParams { address, nft_id } in params {
-            self.client().bech32_hrp_matches(address.hrp()).await?;
-
-            // Find nft output from the inputs
-            if let Some(nft_output_data) = unspent_outputs.iter().find(|o| {
-                if let Output::Nft(nft_output) = &o.output {
-                    nft_id == nft_output.nft_id_non_null(&o.output_id)
-                } else {
-                    false
+            outputs.push(
+                self.nft_transfer_output(&unspent_outputs, nft_id, address, token_supply)
+                    .await?,
+            );
+        }
+
+        self.prepare_transaction(outputs, options).await
+    }
+
+    /// Sends many NFTs, each to its own address. The current output of every NFT is resolved and a transfer output
+    /// keeping its features and immutable features is built. The NFTs are split over multiple transactions if they
+    /// don't fit into a single one. If a transaction fails, its NFTs are split in halves that are sent separately,
+    /// until the failing NFTs are found. Returns a result per NFT, in the order of `nfts`.
+    pub async fn send_nfts(
+        &self,
+        nfts: Vec<(NftId, Bech32Address)>,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<Vec<NftSendResult>> {
+        log::debug!("[TRANSACTION] send_nfts");
+        let options = options.into();
+        let unspent_outputs = self.unspent_outputs(None).await?;
+        let token_supply = self.network_params().await?.token_supply;
+
+        // The results of NFTs for which no transfer output could be built are known already, the others are set once
+        // their transaction is sent
+        let mut results = Vec::with_capacity(nfts.len());
+        let mut sendable = Vec::new();
+        for (index, (nft_id, address)) in nfts.iter().enumerate() {
+            match self
+                .nft_transfer_output(&unspent_outputs, *nft_id, *address, token_supply)
+                .await
+            {
+                Ok(output) => {
+                    sendable.push((index, output));
+                    results.push(None);
                 }
-            }) {
-                if let Output::Nft(nft_output) = &nft_output_data.output {
-                    // Set the nft id and new address unlock condition
-                    let nft_builder = NftOutputBuilder::from(nft_output)
-                        .with_nft_id(nft_id)
-                        .with_unlock_conditions(vec![AddressUnlockCondition::new(address)]);
-                    outputs.push(nft_builder.finish_output(token_supply)?);
+                Err(e) => results.push(Some(Err(e))),
+            }
+        }
+
+        // The chunks are taken from the end, so they're sent in the order of `nfts`
+        let mut chunks = sendable
+            .chunks(MAX_NFTS_PER_TRANSACTION)
+            .rev()
+            .map(<[_]>::to_vec)
+            .collect::<Vec<_>>();
+        while let Some(mut chunk) = chunks.pop() {
+            let outputs = chunk.iter().map(|(_, output)| output.clone()).collect::<Vec<_>>();
+            match self.send(outputs, options.clone()).await {
+                Ok(transaction) => {
+                    for (index, _) in chunk {
+                        results[index] = Some(Ok(transaction.transaction_id));
+                    }
                 }
-            } else {
-                return Err(crate::wallet::Error::NftNotFoundInUnspentOutputs);
-            };
+                Err(e) if chunk.len() > 1 => {
+                    log::debug!("[TRANSACTION] send_nfts sending {} NFTs failed: {e}", chunk.len());
+                    let second_half = chunk.split_off(chunk.len() / 2);
+                    chunks.push(second_half);
+                    chunks.push(chunk);
+                }
+                Err(e) => results[chunk[0].0] = Some(Err(e)),
+            }
         }
 
-        self.prepare_transaction(outputs, options).await
+        Ok(nfts
+            .into_iter()
+            .zip(results)
+            .map(|((nft_id, address), result)| NftSendResult {
+                nft_id,
+                address,
+                // PANIC: every NFT is either not sendable or part of a chunk, so all results are set.
+                result: result.unwrap(),
+            })
+            .collect())
+    }
+
+    // Builds the output that transfers an NFT to the address, keeping its features and immutable features
+    pub(crate) async fn nft_transfer_output(
+        &self,
+        unspent_outputs: &[OutputData],
+        nft_id: NftId,
+        address: Bech32Address,
+        token_supply: u64,
+    ) -> crate::wallet::Result<Output> {
+        self.client().bech32_hrp_matches(address.hrp()).await?;
+
+        // Find nft output from the inputs
+        let nft_output_data = unspent_outputs.iter().find(|o| {
+            if let Output::Nft(nft_output) = &o.output {
+                nft_id == nft_output.nft_id_non_null(&o.output_id)
+            } else {
+                false
+            }
+        });
+
+        match nft_output_data.map(|output_data| &output_data.output) {
+            Some(Output::Nft(nft_output)) => {
+                // Set the nft id and new address unlock condition
+                let nft_builder = NftOutputBuilder::from(nft_output)
+                    .with_nft_id(nft_id)
+                    .with_unlock_conditions(vec![AddressUnlockCondition::new(address)]);
+                Ok(nft_builder.finish_output(token_supply)?)
+            }
+            _ => Err(crate::wallet::Error::NftNotFoundInUnspentOutputs),
+        }
     }
 }
@@ -0,0 +1,377 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::api::PreparedTransactionData,
+    types::block::{
+        address::Bech32Address,
+        input::INPUT_COUNT_MAX,
+        output::{
+            unlock_condition::{
+                AddressUnlockCondition, GovernorAddressUnlockCondition, StateControllerAddressUnlockCondition,
+                UnlockCondition,
+            },
+            AliasOutputBuilder, AliasTransition, BasicOutputBuilder, NativeTokens, NativeTokensBuilder,
+            NftOutputBuilder, Output, OutputId, OUTPUT_COUNT_MAX,
+        },
+    },
+    wallet::account::{
+        operations::{
+            helpers::time::{can_output_be_unlocked_forever_from_now_on, can_output_be_unlocked_now},
+            output_claiming::get_new_native_token_count,
+            transaction::Transaction,
+        },
+        Account, TransactionOptions,
+    },
+};
+
+/// Options for [`Account::sweep()`].
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SweepOptions {
+    /// Also send the NFTs of the account.
+    #[serde(default)]
+    pub nfts: bool,
+    /// Also transfer the aliases the account is the governor of, the new address becomes their state controller and
+    /// governor. The amount and native tokens held by an alias stay in it.
+    #[serde(default)]
+    pub aliases: bool,
+    /// Options for the transactions, the custom inputs are replaced by the swept outputs.
+    #[serde(default)]
+    pub transaction_options: Option<TransactionOptions>,
+}
+
+impl Account {
+    /// Sends the entire balance of the account to an address. All basic outputs that can be unlocked by the account
+    /// are merged into one output per transaction, NFTs and aliases are transferred if enabled in the options. Outputs
+    /// that can't be unlocked yet or must return a storage deposit are skipped. If the outputs don't fit into a
+    /// single transaction, multiple transactions are sent, each with its own inputs. Returns no transactions if
+    /// there is nothing to sweep.
+    /// ```ignore
+    /// let transactions = account
+    ///     .sweep(
+    ///         Bech32Address::try_from_str("rms1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluaw60xu")?,
+    ///         SweepOptions {
+    ///             nfts: true,
+    ///             ..Default::default()
+    ///         },
+    ///     )
+    ///     .await?;
+    /// ```
+    pub async fn sweep(
+        &self,
+        address: Bech32Address,
+        options: SweepOptions,
+    ) -> crate::wallet::Result<Vec<Transaction>> {
+        log::debug!("[TRANSACTION] sweep");
+        let mut transactions = Vec::new();
+        for (inputs, outputs) in self.sweep_transactions(address, &options).await? {
+            let prepared_transaction = self
+                .prepare_transaction(outputs, sweep_transaction_options(&options, inputs))
+                .await?;
+            transactions.push(self.sign_and_submit_transaction(prepared_transaction).await?);
+        }
+        Ok(transactions)
+    }
+
+    /// Function to prepare the transactions for [`Account::sweep()`].
+    pub async fn prepare_sweep(
+        &self,
+        address: Bech32Address,
+        options: SweepOptions,
+    ) -> crate::wallet::Result<Vec<PreparedTransactionData>> {
+        log::debug!("[TRANSACTION] prepare_sweep");
+        let mut prepared_transactions = Vec::new();
+        for (inputs, outputs) in self.sweep_transactions(address, &options).await? {
+            prepared_transactions.push(
+                self.prepare_transaction(outputs, sweep_transaction_options(&options, inputs))
+                    .await?,
+            );
+        }
+        Ok(prepared_transactions)
+    }
+
+    // Collects the outputs to sweep and splits them into the inputs and outputs of the transactions
+    async fn sweep_transactions(
+        &self,
+        address: Bech32Address,
+        options: &SweepOptions,
+    ) -> crate::wallet::Result<Vec<(Vec<OutputId>, Vec<Output>)>> {
+        self.client().bech32_hrp_matches(address.hrp()).await?;
+        let current_time = self.client().get_time_checked().await?;
+        let token_supply = self.network_params().await?.token_supply;
+
+        let mut chain_outputs = Vec::new();
+        let mut basic_outputs = Vec::new();
+        {
+            let account_details = self.details().await;
+            let account_addresses = &account_details.addresses_with_unspent_outputs[..];
+
+            for (output_id, output_data) in account_details.unspent_outputs() {
+                let output = &output_data.output;
+                // Outputs whose storage deposit must be returned can't be swept
+                let returns_storage_deposit = output.unlock_conditions().map_or(false, |unlock_conditions| {
+                    unlock_conditions.storage_deposit_return().is_some() && unlock_conditions.expiration().is_none()
+                });
+                if account_details.locked_outputs.contains(output_id)
+                    || returns_storage_deposit
+                    || !can_output_be_unlocked_forever_from_now_on(account_addresses, output, current_time)
+                {
+                    continue;
+                }
+
+                match output {
+                    Output::Basic(_)
+                        if can_output_be_unlocked_now(account_addresses, &[], output_data, current_time, None)? =>
+                    {
+                        basic_outputs.push((*output_id, output.clone()));
+                    }
+                    Output::Nft(nft_output)
+                        if options.nfts
+                            && can_output_be_unlocked_now(account_addresses, &[], output_data, current_time, None)? =>
+                    {
+                        let output = NftOutputBuilder::from(nft_output)
+                            .with_nft_id(nft_output.nft_id_non_null(output_id))
+                            .with_unlock_conditions([AddressUnlockCondition::new(address)])
+                            .finish_output(token_supply)?;
+                        chain_outputs.push((*output_id, output));
+                    }
+                    Output::Alias(alias_output)
+                        if options.aliases
+                            && can_output_be_unlocked_now(
+                                account_addresses,
+                                &[],
+                                output_data,
+                                current_time,
+                                Some(AliasTransition::Governance),
+                            )? =>
+                    {
+                        let output = AliasOutputBuilder::from(alias_output)
+                            .with_alias_id(alias_output.alias_id_non_null(output_id))
+                            .with_unlock_conditions([
+                                UnlockCondition::from(StateControllerAddressUnlockCondition::new(address)),
+                                UnlockCondition::from(GovernorAddressUnlockCondition::new(address)),
+                            ])
+                            .finish_output(token_supply)?;
+                        chain_outputs.push((*output_id, output));
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let batches = sweep_batches(chain_outputs, basic_outputs, address, token_supply)?;
+        log::debug!("[TRANSACTION] sweeping with {} transactions", batches.len());
+        Ok(batches)
+    }
+}
+
+// Splits the outputs to sweep into the inputs and outputs of the transactions. The chain outputs are transferred as
+// they are, the basic outputs of a transaction are merged into one output, as long as their native tokens fit into it.
+fn sweep_batches(
+    chain_outputs: Vec<(OutputId, Output)>,
+    basic_outputs: Vec<(OutputId, Output)>,
+    address: Bech32Address,
+    token_supply: u64,
+) -> crate::wallet::Result<Vec<(Vec<OutputId>, Vec<Output>)>> {
+    let mut batches = Vec::new();
+    let mut inputs = Vec::new();
+    let mut outputs = Vec::new();
+
+    for (output_id, output) in chain_outputs {
+        // Leave room for the output that merges the basic outputs
+        if inputs.len() == INPUT_COUNT_MAX as usize || outputs.len() + 1 == OUTPUT_COUNT_MAX as usize {
+            batches.push((std::mem::take(&mut inputs), std::mem::take(&mut outputs)));
+        }
+        inputs.push(output_id);
+        outputs.push(output);
+    }
+
+    let mut amount = 0;
+    let mut native_tokens = NativeTokensBuilder::new();
+    for (output_id, output) in basic_outputs {
+        let exceeds_native_tokens = match output.native_tokens() {
+            Some(output_native_tokens) => {
+                get_new_native_token_count(&native_tokens, output_native_tokens)? > NativeTokens::COUNT_MAX.into()
+            }
+            None => false,
+        };
+        if inputs.len() == INPUT_COUNT_MAX as usize || exceeds_native_tokens {
+            if amount > 0 {
+                outputs.push(sweep_basic_output(
+                    address,
+                    amount,
+                    std::mem::take(&mut native_tokens),
+                    token_supply,
+                )?);
+                amount = 0;
+            }
+            batches.push((std::mem::take(&mut inputs), std::mem::take(&mut outputs)));
+        }
+        if let Some(output_native_tokens) = output.native_tokens() {
+            native_tokens.add_native_tokens(output_native_tokens.clone())?;
+        }
+        amount += output.amount();
+        inputs.push(output_id);
+    }
+    if amount > 0 {
+        outputs.push(sweep_basic_output(address, amount, native_tokens, token_supply)?);
+    }
+    if !inputs.is_empty() {
+        batches.push((inputs, outputs));
+    }
+
+    Ok(batches)
+}
+
+// Builds the output that merges the swept basic outputs of a transaction
+fn sweep_basic_output(
+    address: Bech32Address,
+    amount: u64,
+    native_tokens: NativeTokensBuilder,
+    token_supply: u64,
+) -> crate::wallet::Result<Output> {
+    Ok(BasicOutputBuilder::new_with_amount(amount)
+        .add_unlock_condition(AddressUnlockCondition::new(address))
+        .with_native_tokens(native_tokens.finish()?)
+        .finish_output(token_supply)?)
+}
+
+// Uses only the swept outputs as inputs of a transaction
+fn sweep_transaction_options(options: &SweepOptions, inputs: Vec<OutputId>) -> TransactionOptions {
+    TransactionOptions {
+        custom_inputs: Some(inputs),
+        mandatory_inputs: None,
+        ..options.transaction_options.clone().unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use primitive_types::U256;
+
+    use super::*;
+    use crate::types::block::{
+        address::{Address, Ed25519Address},
+        output::{NativeToken, NftId, TokenId},
+        payload::transaction::TransactionId,
+    };
+
+    const TOKEN_SUPPLY: u64 = 1_813_620_509_061_365;
+
+    fn address() -> Bech32Address {
+        Address::Ed25519(Ed25519Address::new([0; Ed25519Address::LENGTH])).to_bech32_unchecked("rms")
+    }
+
+    fn output_id(index: u16) -> OutputId {
+        // Output indexes only go up to 127
+        OutputId::new(
+            TransactionId::new([(index / 128) as u8; TransactionId::LENGTH]),
+            index % 128,
+        )
+        .unwrap()
+    }
+
+    fn basic_output(index: u16, native_tokens: &[u8]) -> (OutputId, Output) {
+        let output = BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(address()))
+            .with_native_tokens(
+                native_tokens
+                    .iter()
+                    .map(|token| NativeToken::new(TokenId::new([*token; TokenId::LENGTH]), U256::from(1)).unwrap()),
+            )
+            .finish_output(TOKEN_SUPPLY)
+            .unwrap();
+        (output_id(index), output)
+    }
+
+    fn nft_output(index: u16) -> (OutputId, Output) {
+        let output = NftOutputBuilder::new_with_amount(1_000_000, NftId::new([index as u8; NftId::LENGTH]))
+            .add_unlock_condition(AddressUnlockCondition::new(address()))
+            .finish_output(TOKEN_SUPPLY)
+            .unwrap();
+        (output_id(index), output)
+    }
+
+    #[test]
+    fn sweep_batches_merge_basic_outputs() {
+        let chain_outputs = (0..2).map(nft_output).collect::<Vec<_>>();
+        let basic_outputs = (2..5).map(|index| basic_output(index, &[1])).collect::<Vec<_>>();
+
+        let batches = sweep_batches(chain_outputs.clone(), basic_outputs, address(), TOKEN_SUPPLY).unwrap();
+
+        assert_eq!(batches.len(), 1);
+        let (inputs, outputs) = &batches[0];
+        assert_eq!(inputs, &(0..5).map(output_id).collect::<Vec<_>>());
+        assert_eq!(outputs.len(), 3);
+        assert_eq!(outputs[..2], [chain_outputs[0].1.clone(), chain_outputs[1].1.clone()]);
+        assert_eq!(outputs[2].amount(), 3_000_000);
+        assert_eq!(
+            outputs[2].native_tokens().unwrap().first().unwrap().amount(),
+            U256::from(3)
+        );
+    }
+
+    #[test]
+    fn sweep_batches_input_count() {
+        let basic_outputs = (0..INPUT_COUNT_MAX + 2)
+            .map(|index| basic_output(index, &[]))
+            .collect::<Vec<_>>();
+
+        let batches = sweep_batches(Vec::new(), basic_outputs, address(), TOKEN_SUPPLY).unwrap();
+
+        assert_eq!(
+            batches
+                .iter()
+                .map(|(inputs, outputs)| (inputs.len(), outputs.len()))
+                .collect::<Vec<_>>(),
+            [(INPUT_COUNT_MAX as usize, 1), (2, 1)]
+        );
+        assert_eq!(batches[0].1[0].amount(), INPUT_COUNT_MAX as u64 * 1_000_000);
+        assert_eq!(batches[1].1[0].amount(), 2_000_000);
+    }
+
+    #[test]
+    fn sweep_batches_output_count() {
+        let chain_outputs = (0..OUTPUT_COUNT_MAX).map(nft_output).collect::<Vec<_>>();
+        let basic_outputs = vec![basic_output(OUTPUT_COUNT_MAX, &[])];
+
+        let batches = sweep_batches(chain_outputs, basic_outputs, address(), TOKEN_SUPPLY).unwrap();
+
+        // The first transaction leaves room for a merged output, the basic output is merged in the second one
+        assert_eq!(
+            batches
+                .iter()
+                .map(|(inputs, outputs)| (inputs.len(), outputs.len()))
+                .collect::<Vec<_>>(),
+            [(OUTPUT_COUNT_MAX as usize - 1, OUTPUT_COUNT_MAX as usize - 1), (2, 2)]
+        );
+    }
+
+    #[test]
+    fn sweep_batches_native_token_count() {
+        let basic_outputs = (0..NativeTokens::COUNT_MAX as u16 + 1)
+            .map(|index| basic_output(index, &[index as u8]))
+            .collect::<Vec<_>>();
+
+        let batches = sweep_batches(Vec::new(), basic_outputs, address(), TOKEN_SUPPLY).unwrap();
+
+        assert_eq!(batches.len(), 2);
+        assert_eq!(batches[0].0.len(), NativeTokens::COUNT_MAX as usize);
+        assert_eq!(
+            batches[0].1[0].native_tokens().unwrap().len(),
+            NativeTokens::COUNT_MAX as usize
+        );
+        assert_eq!(batches[1].0, [output_id(NativeTokens::COUNT_MAX as u16)]);
+        assert_eq!(batches[1].1[0].native_tokens().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn sweep_batches_nothing_to_sweep() {
+        assert!(sweep_batches(Vec::new(), Vec::new(), address(), TOKEN_SUPPLY)
+            .unwrap()
+            .is_empty());
+    }
+}
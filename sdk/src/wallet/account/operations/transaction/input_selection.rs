@@ -4,15 +4,15 @@
 use std::collections::{hash_map::Values, HashSet};
 
 #[cfg(feature = "events")]
-use crate::wallet::events::types::{TransactionProgressEvent, WalletEvent};
+use crate::wallet::events::types::{EventOrigin, TransactionProgressEvent, WalletEvent};
 use crate::{
     client::{
-        api::input_selection::{Burn, InputSelection, Selected},
+        api::input_selection::{Burn, InputSelection, InputSelectionStrategy, Selected},
         secret::types::InputSigningData,
     },
     types::block::{
         address::Address,
-        output::{AliasTransition, Output, OutputId},
+        output::{AliasTransition, ChainId, NativeToken, NativeTokensBuilder, Output, OutputId, TokenScheme},
     },
     wallet::account::{
         operations::helpers::time::can_output_be_unlocked_forever_from_now_on, Account, AccountDetails, OutputData,
@@ -20,7 +20,9 @@ use crate::{
 };
 
 impl Account {
-    /// Selects inputs for a transaction and locks them in the account, so they don't get used again
+    /// Selects inputs for a transaction and locks them in the account if `lock_inputs` is set, so they don't get used
+    /// again
+    #[allow(clippy::too_many_arguments)]
     pub(crate) async fn select_inputs(
         &self,
         outputs: Vec<Output>,
@@ -28,21 +30,26 @@ impl Account {
         mandatory_inputs: Option<HashSet<OutputId>>,
         remainder_address: Option<Address>,
         burn: Option<&Burn>,
+        strategy: InputSelectionStrategy,
+        lock_inputs: bool,
     ) -> crate::wallet::Result<Selected> {
         log::debug!("[TRANSACTION] select_inputs");
         // Voting output needs to be requested before to prevent a deadlock
-        #[cfg(feature = "participation")]
+        #[cfg(feature = "wallet-participation")]
         let voting_output = self.get_voting_output().await?;
         // lock so the same inputs can't be selected in multiple transactions
         let mut account_details = self.details_mut().await;
         let protocol_parameters = self.client().get_protocol_parameters().await?;
 
         #[cfg(feature = "events")]
-        self.emit(
-            account_details.index,
-            WalletEvent::TransactionProgress(TransactionProgressEvent::SelectingInputs),
-        )
-        .await;
+        if lock_inputs {
+            self.emit(
+                account_details.index,
+                WalletEvent::TransactionProgress(TransactionProgressEvent::SelectingInputs),
+                EventOrigin::new("select_inputs"),
+            )
+            .await;
+        }
 
         let current_time = self.client().get_time_checked().await?;
         #[allow(unused_mut)]
@@ -56,7 +63,7 @@ impl Account {
             .collect();
 
         // Prevent consuming the voting output if not actually wanted
-        #[cfg(feature = "participation")]
+        #[cfg(feature = "wallet-participation")]
         if let Some(voting_output) = &voting_output {
             let required = mandatory_inputs.as_ref().map_or(false, |mandatory_inputs| {
                 mandatory_inputs.contains(&voting_output.output_id)
@@ -97,7 +104,9 @@ impl Account {
                 protocol_parameters.clone(),
             )
             .required_inputs(custom_inputs)
-            .forbidden_inputs(forbidden_inputs);
+            .forbidden_inputs(forbidden_inputs)
+            .strategy(strategy)
+            .timestamp(current_time);
 
             if let Some(address) = remainder_address {
                 input_selection = input_selection.remainder_address(address);
@@ -110,8 +119,10 @@ impl Account {
             let selected_transaction_data = input_selection.select()?;
 
             // lock outputs so they don't get used by another transaction
-            for output in &selected_transaction_data.inputs {
-                account_details.locked_outputs.insert(*output.output_id());
+            if lock_inputs {
+                for output in &selected_transaction_data.inputs {
+                    account_details.locked_outputs.insert(*output.output_id());
+                }
             }
 
             return Ok(selected_transaction_data);
@@ -132,7 +143,9 @@ impl Account {
                 protocol_parameters.clone(),
             )
             .required_inputs(mandatory_inputs)
-            .forbidden_inputs(forbidden_inputs);
+            .forbidden_inputs(forbidden_inputs)
+            .strategy(strategy)
+            .timestamp(current_time);
 
             if let Some(address) = remainder_address {
                 input_selection = input_selection.remainder_address(address);
@@ -145,13 +158,10 @@ impl Account {
             let selected_transaction_data = input_selection.select()?;
 
             // lock outputs so they don't get used by another transaction
-            for output in &selected_transaction_data.inputs {
-                account_details.locked_outputs.insert(*output.output_id());
-            }
-
-            // lock outputs so they don't get used by another transaction
-            for output in &selected_transaction_data.inputs {
-                account_details.locked_outputs.insert(*output.output_id());
+            if lock_inputs {
+                for output in &selected_transaction_data.inputs {
+                    account_details.locked_outputs.insert(*output.output_id());
+                }
             }
 
             return Ok(selected_transaction_data);
@@ -163,7 +173,9 @@ impl Account {
             addresses,
             protocol_parameters.clone(),
         )
-        .forbidden_inputs(forbidden_inputs);
+        .forbidden_inputs(forbidden_inputs)
+        .strategy(strategy)
+        .timestamp(current_time);
 
         if let Some(address) = remainder_address {
             input_selection = input_selection.remainder_address(address);
@@ -191,9 +203,11 @@ impl Account {
         };
 
         // lock outputs so they don't get used by another transaction
-        for output in &selected_transaction_data.inputs {
-            log::debug!("[TRANSACTION] locking: {}", output.output_id());
-            account_details.locked_outputs.insert(*output.output_id());
+        if lock_inputs {
+            for output in &selected_transaction_data.inputs {
+                log::debug!("[TRANSACTION] locking: {}", output.output_id());
+                account_details.locked_outputs.insert(*output.output_id());
+            }
         }
 
         Ok(selected_transaction_data)
@@ -219,7 +233,7 @@ impl Account {
 /// | [Address, StorageDepositReturn, ...]                | no                |
 /// | [Address, StorageDepositReturn, expired Expiration] | yes               |
 #[allow(clippy::too_many_arguments)]
-fn filter_inputs(
+pub(crate) fn filter_inputs(
     account: &AccountDetails,
     available_outputs: Values<'_, OutputId, OutputData>,
     current_time: u32,
@@ -263,6 +277,88 @@ fn filter_inputs(
     Ok(available_outputs_signing_data)
 }
 
+/// Verifies that the selected inputs and outputs don't burn native tokens or destroy chain outputs, unless it's allowed
+/// by the transaction options or explicitly requested with a [`Burn`].
+pub(crate) fn verify_burn_capabilities(
+    selected: &Selected,
+    burn: Option<&Burn>,
+    allow_burning_tokens: bool,
+    allow_destroying_chains: bool,
+) -> crate::wallet::Result<()> {
+    if !allow_destroying_chains {
+        for input in &selected.inputs {
+            if let Some(chain_id) = input.output.chain_id() {
+                let chain_id = chain_id.or_from_output_id(input.output_id());
+                let transitioned = selected
+                    .outputs
+                    .iter()
+                    .any(|output| output.chain_id() == Some(chain_id));
+                let requested = burn.map_or(false, |burn| match chain_id {
+                    ChainId::Alias(alias_id) => burn.aliases().contains(&alias_id),
+                    ChainId::Foundry(foundry_id) => burn.foundries().contains(&foundry_id),
+                    ChainId::Nft(nft_id) => burn.nfts().contains(&nft_id),
+                });
+
+                if !transitioned && !requested {
+                    return Err(crate::wallet::Error::ChainDestructionNotAllowed(chain_id));
+                }
+            }
+        }
+    }
+
+    if !allow_burning_tokens {
+        let mut input_native_tokens = NativeTokensBuilder::new();
+        let mut output_native_tokens = NativeTokensBuilder::new();
+        // Tokens that get melted by a foundry transition aren't burned
+        let mut melted_native_tokens = NativeTokensBuilder::new();
+
+        for input in &selected.inputs {
+            if let Some(native_tokens) = input.output.native_tokens() {
+                input_native_tokens.add_native_tokens(native_tokens.clone())?;
+            }
+        }
+        for output in &selected.outputs {
+            if let Some(native_tokens) = output.native_tokens() {
+                output_native_tokens.add_native_tokens(native_tokens.clone())?;
+            }
+            if let Output::Foundry(foundry_output) = output {
+                let input_foundry = selected.inputs.iter().find_map(|input| match &input.output {
+                    Output::Foundry(input_foundry) if input_foundry.id() == foundry_output.id() => Some(input_foundry),
+                    _ => None,
+                });
+                if let Some(input_foundry) = input_foundry {
+                    let TokenScheme::Simple(input_token_scheme) = input_foundry.token_scheme();
+                    let TokenScheme::Simple(output_token_scheme) = foundry_output.token_scheme();
+                    let melted = output_token_scheme
+                        .melted_tokens()
+                        .saturating_sub(input_token_scheme.melted_tokens());
+                    if !melted.is_zero() {
+                        melted_native_tokens.add_native_token(NativeToken::new(foundry_output.token_id(), melted)?)?;
+                    }
+                }
+            }
+        }
+
+        for (token_id, input_amount) in input_native_tokens.iter() {
+            let output_amount = output_native_tokens.get(token_id).copied().unwrap_or_default()
+                + melted_native_tokens.get(token_id).copied().unwrap_or_default();
+
+            if *input_amount > output_amount {
+                let requested = burn.map_or(false, |burn| burn.native_tokens().contains_key(token_id));
+
+                if !requested {
+                    return Err(crate::wallet::Error::NativeTokenBurningNotAllowed {
+                        token_id: *token_id,
+                        amount: *input_amount - output_amount,
+                    });
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
 // Returns if alias transition is a state transition with the provided outputs for a given input.
 pub(crate) fn alias_state_transition(
     output_data: &OutputData,
@@ -303,3 +399,119 @@ pub(crate) fn alias_state_transition(
         None
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        types::block::{
+            address::Ed25519Address,
+            output::{
+                unlock_condition::AddressUnlockCondition, BasicOutputBuilder, NftId, NftOutputBuilder, OutputMetadata,
+                TokenId,
+            },
+            payload::transaction::TransactionId,
+            BlockId,
+        },
+        U256,
+    };
+
+    const TOKEN_SUPPLY: u64 = 1_813_620_509_061_365;
+
+    fn address() -> Address {
+        Address::Ed25519(Ed25519Address::new([0; Ed25519Address::LENGTH]))
+    }
+
+    fn input(index: u16, output: Output) -> InputSigningData {
+        let output_id = OutputId::new(TransactionId::new([1; TransactionId::LENGTH]), index).unwrap();
+        InputSigningData {
+            output,
+            output_metadata: OutputMetadata::new(
+                BlockId::new([0; BlockId::LENGTH]),
+                output_id,
+                false,
+                None,
+                None,
+                None,
+                0,
+                0,
+                0,
+            ),
+            chain: None,
+        }
+    }
+
+    fn basic_output(native_token: Option<NativeToken>) -> Output {
+        BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(address()))
+            .with_native_tokens(native_token)
+            .finish_output(TOKEN_SUPPLY)
+            .unwrap()
+    }
+
+    fn nft_output(nft_id: NftId) -> Output {
+        NftOutputBuilder::new_with_amount(1_000_000, nft_id)
+            .add_unlock_condition(AddressUnlockCondition::new(address()))
+            .finish_output(TOKEN_SUPPLY)
+            .unwrap()
+    }
+
+    #[test]
+    fn burning_native_tokens() {
+        let token_id = TokenId::from([1; TokenId::LENGTH]);
+        let native_token = |amount: u64| NativeToken::new(token_id, U256::from(amount)).unwrap();
+        let selected = Selected {
+            inputs: vec![input(0, basic_output(Some(native_token(10))))],
+            outputs: vec![basic_output(Some(native_token(4)))],
+            remainder: None,
+        };
+
+        assert!(matches!(
+            verify_burn_capabilities(&selected, None, false, false),
+            Err(crate::wallet::Error::NativeTokenBurningNotAllowed { token_id: burned_token_id, amount })
+                if burned_token_id == token_id && amount == U256::from(6)
+        ));
+        assert!(verify_burn_capabilities(&selected, None, true, false).is_ok());
+        let burn = Burn::new().add_native_token(token_id, 6);
+        assert!(verify_burn_capabilities(&selected, Some(&burn), false, false).is_ok());
+        // Burning another token doesn't allow it
+        let burn = Burn::new().add_native_token(TokenId::from([2; TokenId::LENGTH]), 6);
+        assert!(verify_burn_capabilities(&selected, Some(&burn), false, false).is_err());
+
+        // Tokens that are kept aren't burned
+        let selected = Selected {
+            inputs: vec![input(0, basic_output(Some(native_token(10))))],
+            outputs: vec![basic_output(Some(native_token(4))), basic_output(Some(native_token(6)))],
+            remainder: None,
+        };
+        assert!(verify_burn_capabilities(&selected, None, false, false).is_ok());
+    }
+
+    #[test]
+    fn destroying_chains() {
+        let input = input(0, nft_output(NftId::null()));
+        let nft_id = NftId::from(input.output_id());
+        let selected = Selected {
+            inputs: vec![input.clone()],
+            outputs: vec![basic_output(None)],
+            remainder: None,
+        };
+
+        assert!(matches!(
+            verify_burn_capabilities(&selected, None, false, false),
+            Err(crate::wallet::Error::ChainDestructionNotAllowed(ChainId::Nft(destroyed_nft_id)))
+                if destroyed_nft_id == nft_id
+        ));
+        assert!(verify_burn_capabilities(&selected, None, false, true).is_ok());
+        let burn = Burn::new().add_nft(nft_id);
+        assert!(verify_burn_capabilities(&selected, Some(&burn), false, false).is_ok());
+
+        // A transitioned NFT isn't destroyed
+        let selected = Selected {
+            inputs: vec![input],
+            outputs: vec![nft_output(nft_id)],
+            remainder: None,
+        };
+        assert!(verify_burn_capabilities(&selected, None, false, false).is_ok());
+    }
+}
@@ -2,35 +2,44 @@
 // SPDX-License-Identifier: Apache-2.0
 
 mod build_transaction;
+mod estimate;
 pub(crate) mod high_level;
-mod input_selection;
+pub(crate) mod input_selection;
 mod options;
 pub(crate) mod prepare_output;
 mod prepare_transaction;
+mod remainder_split;
 mod sign_transaction;
 pub(crate) mod submit_transaction;
 
-pub use self::options::{RemainderValueStrategy, TransactionOptions, TransactionOptionsDto};
+use std::collections::{HashMap, HashSet};
+
+pub use self::{
+    estimate::{OutputEstimate, TransactionEstimate},
+    options::{RemainderSplit, RemainderValueStrategy, TransactionOptions, TransactionOptionsDto},
+};
 use crate::{
     client::{
         api::{verify_semantic, PreparedTransactionData, SignedTransactionData},
         secret::types::InputSigningData,
-        Error,
+        Client, Error,
     },
     types::{
         api::core::response::OutputWithMetadataResponse,
         block::{
+            address::Address,
             output::{
                 dto::{OutputDto, OutputMetadataDto},
-                Output,
+                Output, OutputId,
             },
             payload::transaction::TransactionPayload,
             semantic::ConflictReason,
+            BlockId,
         },
     },
     wallet::account::{
         types::{InclusionState, Transaction},
-        Account,
+        Account, AllowanceUsage,
     },
 };
 
@@ -88,6 +97,7 @@ impl Account {
     ) -> crate::wallet::Result<Transaction> {
         log::debug!("[TRANSACTION] finish_transaction");
 
+        self.check_rate_limit().await?;
         let prepared_transaction_data = self.prepare_transaction(outputs, options).await?;
 
         self.sign_and_submit_transaction(prepared_transaction_data).await
@@ -100,11 +110,17 @@ impl Account {
     ) -> crate::wallet::Result<Transaction> {
         log::debug!("[TRANSACTION] sign_and_submit_transaction");
 
+        if let Err(err) = self.check_rate_limit().await {
+            // unlock outputs so they are available for a new transaction
+            self.discard_prepared_transaction(&prepared_transaction_data).await?;
+            return Err(err);
+        }
+
         let signed_transaction_data = match self.sign_transaction_essence(&prepared_transaction_data).await {
             Ok(res) => res,
             Err(err) => {
                 // unlock outputs so they are available for a new transaction
-                self.unlock_inputs(&prepared_transaction_data.inputs_data).await?;
+                self.discard_prepared_transaction(&prepared_transaction_data).await?;
                 return Err(err);
             }
         };
@@ -122,65 +138,83 @@ impl Account {
             signed_transaction_data.transaction_payload.id()
         );
 
-        // Validate transaction before sending and storing it
-        let local_time = self.client().get_time_checked().await?;
-
-        let conflict = verify_semantic(
-            &signed_transaction_data.inputs_data,
-            &signed_transaction_data.transaction_payload,
-            local_time,
-        )?;
+        // Held until the transaction is stored, so it's counted for the next transactions
+        let _rate_limit_guard = match self.enforce_rate_limit().await {
+            Ok(guard) => guard,
+            Err(err) => {
+                // unlock outputs so they are available for a new transaction
+                self.unlock_inputs(&signed_transaction_data.inputs_data).await?;
+                return Err(err);
+            }
+        };
 
-        if conflict != ConflictReason::None {
-            log::debug!(
-                "[TRANSACTION] conflict: {conflict:?} for {:?}",
-                signed_transaction_data.transaction_payload
-            );
+        // Validate transaction before sending and storing it
+        if let Err(err) = verify_signed_transaction(self.client(), &signed_transaction_data).await {
             // unlock outputs so they are available for a new transaction
             self.unlock_inputs(&signed_transaction_data.inputs_data).await?;
-            return Err(Error::TransactionSemantic(conflict).into());
+            return Err(err);
         }
 
-        // Ignore errors from sending, we will try to send it again during [`sync_pending_transactions`]
-        let block_id = match self
-            .submit_transaction_payload(signed_transaction_data.transaction_payload.clone())
-            .await
-        {
-            Ok(block_id) => Some(block_id),
-            Err(err) => {
-                log::error!("Failed to submit_transaction_payload {}", err);
-                None
+        let transaction_id = signed_transaction_data.transaction_payload.id();
+        let context = self
+            .take_prepared_transaction_context(&signed_transaction_data.transaction_payload.essence().hash())
+            .await;
+        if let Some((allowance, usage)) = context.allowance_usage {
+            if let Err(err) = self.charge_spending_allowance(&allowance, usage, transaction_id).await {
+                // unlock outputs so they are available for a new transaction
+                self.unlock_inputs(&signed_transaction_data.inputs_data).await?;
+                return Err(err);
             }
-        };
+        }
 
-        let transaction_id = signed_transaction_data.transaction_payload.id();
+        let block_id = self
+            .try_submit_transaction_payload(signed_transaction_data.transaction_payload.clone())
+            .await;
 
         // store transaction payload to account (with db feature also store the account to the db)
         let network_id = self.client().get_network_id().await?;
 
-        let inputs = signed_transaction_data
-            .inputs_data
-            .into_iter()
-            .map(|input| OutputWithMetadataResponse {
-                metadata: OutputMetadataDto::from(&input.output_metadata),
-                output: OutputDto::from(&input.output),
-            })
-            .collect();
-
-        let transaction = Transaction {
-            transaction_id,
-            payload: signed_transaction_data.transaction_payload,
-            block_id,
-            network_id,
-            timestamp: crate::utils::unix_timestamp_now().as_millis(),
-            inclusion_state: InclusionState::Pending,
-            incoming: false,
-            note: None,
-            inputs,
-        };
+        let transaction = self
+            .store_transaction(pending_transaction(
+                signed_transaction_data,
+                block_id,
+                network_id,
+                context.note,
+                context.confirmation_depth,
+            ))
+            .await?;
+        #[cfg(feature = "stronghold")]
+        self.wallet.auto_backup_transaction_sent();
+
+        Ok(transaction)
+    }
+
+    /// Submits the transaction payload and returns the block id, errors are ignored, because the transaction is sent
+    /// again during [`sync_pending_transactions`](Account::sync_pending_transactions).
+    pub(crate) async fn try_submit_transaction_payload(
+        &self,
+        transaction_payload: TransactionPayload,
+    ) -> Option<BlockId> {
+        match self.submit_transaction_payload(transaction_payload).await {
+            Ok(block_id) => Some(block_id),
+            Err(err) => {
+                log::error!("Failed to submit_transaction_payload {}", err);
+                None
+            }
+        }
+    }
 
+    /// Stores a sent transaction in the account and returns it, with the next sequence number of the account if the
+    /// transaction sequencing is enabled.
+    pub(crate) async fn store_transaction(&self, mut transaction: Transaction) -> crate::wallet::Result<Transaction> {
+        let transaction_id = transaction.transaction_id;
         let mut account_details = self.details_mut().await;
 
+        if account_details.transaction_sequencing {
+            transaction.sequence_number = Some(account_details.next_sequence_number);
+            account_details.next_sequence_number += 1;
+        }
+
         account_details.transactions.insert(transaction_id, transaction.clone());
         account_details.pending_transactions.insert(transaction_id);
         #[cfg(feature = "storage")]
@@ -188,12 +222,78 @@ impl Account {
             log::debug!("[TRANSACTION] storing account {}", account_details.index());
             self.save(Some(&account_details)).await?;
         }
+        drop(account_details);
+        #[cfg(feature = "storage")]
+        self.record_submitted_transaction(transaction_id).await;
 
         Ok(transaction)
     }
 
+    /// Keeps the options of a prepared transaction that are used once it's submitted. Contexts of prepared
+    /// transactions whose inputs got unlocked without discarding them are removed, because their inputs can be used by
+    /// other transactions.
+    pub(crate) async fn set_prepared_transaction_context(
+        &self,
+        prepared_transaction_data: &PreparedTransactionData,
+        mut context: PreparedTransactionContext,
+    ) {
+        let stale_contexts = {
+            let account_details = self.details().await;
+            let mut prepared_transactions = self.prepared_transactions.lock().await;
+            let stale_contexts = stale_prepared_transactions(&prepared_transactions, &account_details.locked_outputs)
+                .iter()
+                .filter_map(|essence_hash| prepared_transactions.remove(essence_hash))
+                .collect::<Vec<_>>();
+
+            if context != PreparedTransactionContext::default() {
+                context.inputs = prepared_transaction_data
+                    .inputs_data
+                    .iter()
+                    .map(|input| *input.output_id())
+                    .collect();
+                prepared_transactions.insert(prepared_transaction_data.essence.hash(), context);
+            }
+            stale_contexts
+        };
+
+        for stale_context in stale_contexts {
+            if let Some(address) = &stale_context.remainder_address {
+                self.release_remainder_address(address).await;
+            }
+        }
+    }
+
+    /// Removes and returns the options of a prepared transaction by the hash of its essence.
+    pub(crate) async fn take_prepared_transaction_context(
+        &self,
+        essence_hash: &[u8; 32],
+    ) -> PreparedTransactionContext {
+        let context = self
+            .prepared_transactions
+            .lock()
+            .await
+            .remove(essence_hash)
+            .unwrap_or_default();
+        // A submitted transaction keeps its remainder address from being used as long as it's pending
+        if let Some(address) = &context.remainder_address {
+            self.release_remainder_address(address).await;
+        }
+        context
+    }
+
+    /// Releases the inputs of a prepared transaction that won't be signed and submitted, so they can be used by other
+    /// transactions again.
+    pub(crate) async fn discard_prepared_transaction(
+        &self,
+        prepared_transaction_data: &PreparedTransactionData,
+    ) -> crate::wallet::Result<()> {
+        self.take_prepared_transaction_context(&prepared_transaction_data.essence.hash())
+            .await;
+        self.unlock_inputs(&prepared_transaction_data.inputs_data).await
+    }
+
     // unlock outputs
-    async fn unlock_inputs(&self, inputs: &[InputSigningData]) -> crate::wallet::Result<()> {
+    pub(crate) async fn unlock_inputs(&self, inputs: &[InputSigningData]) -> crate::wallet::Result<()> {
         let mut account_details = self.details_mut().await;
         for input_signing_data in inputs {
             let output_id = input_signing_data.output_id();
@@ -206,3 +306,125 @@ impl Account {
         Ok(())
     }
 }
+
+/// Options of a prepared transaction that are only used once it's submitted.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct PreparedTransactionContext {
+    /// The note that is stored with the transaction.
+    pub(crate) note: Option<String>,
+    /// The spending allowance that is charged and its usage.
+    pub(crate) allowance_usage: Option<(String, AllowanceUsage)>,
+    /// The reserved remainder address, which is released once the transaction is submitted or discarded.
+    pub(crate) remainder_address: Option<Address>,
+    /// The confirmation depth that is stored with the transaction.
+    pub(crate) confirmation_depth: Option<u32>,
+    /// The inputs of the prepared transaction, set by `Account::set_prepared_transaction_context()`. The context is
+    /// removed once none of them is locked anymore.
+    pub(crate) inputs: Vec<OutputId>,
+}
+
+// Returns the essence hashes of the prepared transactions whose inputs aren't locked anymore
+fn stale_prepared_transactions(
+    prepared_transactions: &HashMap<[u8; 32], PreparedTransactionContext>,
+    locked_outputs: &HashSet<OutputId>,
+) -> Vec<[u8; 32]> {
+    prepared_transactions
+        .iter()
+        .filter(|(_, context)| {
+            !context
+                .inputs
+                .iter()
+                .any(|output_id| locked_outputs.contains(output_id))
+        })
+        .map(|(essence_hash, _)| *essence_hash)
+        .collect()
+}
+
+/// Validates a signed transaction before it's submitted.
+pub(crate) async fn verify_signed_transaction(
+    client: &Client,
+    signed_transaction_data: &SignedTransactionData,
+) -> crate::wallet::Result<()> {
+    let local_time = client.get_time_checked().await?;
+
+    let conflict = verify_semantic(
+        &signed_transaction_data.inputs_data,
+        &signed_transaction_data.transaction_payload,
+        local_time,
+    )?;
+
+    if conflict != ConflictReason::None {
+        log::debug!(
+            "[TRANSACTION] conflict: {conflict:?} for {:?}",
+            signed_transaction_data.transaction_payload
+        );
+        return Err(Error::TransactionSemantic(conflict).into());
+    }
+    Ok(())
+}
+
+/// Returns the pending transaction that is stored for a signed and submitted transaction.
+pub(crate) fn pending_transaction(
+    signed_transaction_data: SignedTransactionData,
+    block_id: Option<BlockId>,
+    network_id: u64,
+    note: Option<String>,
+    confirmation_depth: Option<u32>,
+) -> Transaction {
+    Transaction {
+        transaction_id: signed_transaction_data.transaction_payload.id(),
+        payload: signed_transaction_data.transaction_payload,
+        block_id,
+        network_id,
+        timestamp: crate::utils::unix_timestamp_now().as_millis(),
+        inclusion_state: InclusionState::Pending,
+        incoming: false,
+        note,
+        confirmation_depth,
+        inputs: signed_transaction_data
+            .inputs_data
+            .into_iter()
+            .map(|input| OutputWithMetadataResponse {
+                metadata: OutputMetadataDto::from(&input.output_metadata),
+                output: OutputDto::from(&input.output),
+            })
+            .collect(),
+        conflict_reason: None,
+        sequence_number: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::block::payload::transaction::TransactionId;
+
+    fn output_id(index: u16) -> OutputId {
+        OutputId::new(TransactionId::new([1; TransactionId::LENGTH]), index).unwrap()
+    }
+
+    #[test]
+    fn stale_prepared_transaction_contexts() {
+        let context = |inputs: Vec<OutputId>| PreparedTransactionContext {
+            note: Some("note".to_string()),
+            inputs,
+            ..Default::default()
+        };
+        let prepared_transactions = HashMap::from([
+            ([1; 32], context(vec![output_id(0), output_id(1)])),
+            ([2; 32], context(vec![output_id(2)])),
+            ([3; 32], context(vec![output_id(3)])),
+        ]);
+        // The first transaction still has a locked input, the inputs of the second one got unlocked
+        let locked_outputs = HashSet::from([output_id(1), output_id(3)]);
+
+        assert_eq!(
+            stale_prepared_transactions(&prepared_transactions, &locked_outputs),
+            vec![[2; 32]]
+        );
+        assert_eq!(
+            stale_prepared_transactions(&prepared_transactions, &HashSet::new()).len(),
+            3
+        );
+    }
+}
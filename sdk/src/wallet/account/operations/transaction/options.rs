@@ -4,8 +4,9 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    client::api::input_selection::{Burn, BurnDto},
+    client::api::input_selection::{Burn, BurnDto, InputSelectionStrategy},
     types::block::{
+        address::Bech32Address,
         output::OutputId,
         payload::{dto::TaggedDataPayloadDto, tagged_data::TaggedDataPayload},
         Error,
@@ -19,6 +20,9 @@ use crate::{
 pub struct TransactionOptions {
     #[serde(default)]
     pub remainder_value_strategy: RemainderValueStrategy,
+    /// Splits the remainder into multiple outputs, so that following transactions can spend them in parallel.
+    #[serde(default)]
+    pub remainder_split: Option<RemainderSplit>,
     #[serde(default)]
     pub tagged_data_payload: Option<TaggedDataPayload>,
     // If custom inputs are provided only they are used. If also other additional inputs should be used,
@@ -27,10 +31,39 @@ pub struct TransactionOptions {
     pub custom_inputs: Option<Vec<OutputId>>,
     #[serde(default)]
     pub mandatory_inputs: Option<Vec<OutputId>>,
+    /// The order in which inputs are selected to cover the amount of the outputs.
+    #[serde(default)]
+    pub input_selection_strategy: InputSelectionStrategy,
     pub burn: Option<Burn>,
     pub note: Option<String>,
     #[serde(default)]
     pub allow_micro_amount: bool,
+    /// Allows the transaction to burn native tokens that aren't listed in `burn`, for example when sweeping outputs.
+    #[serde(default)]
+    pub allow_burning_tokens: bool,
+    /// Allows the transaction to destroy alias, foundry and nft outputs that aren't listed in `burn`.
+    #[serde(default)]
+    pub allow_destroying_chains: bool,
+    /// Allows sending to addresses which aren't in the [`AddressBook`](crate::wallet::AddressBook), if its
+    /// verification is enabled.
+    #[serde(default)]
+    pub force: bool,
+    /// The id of the [`SpendingAllowance`](crate::wallet::account::SpendingAllowance) the amount of the outputs is
+    /// counted against.
+    #[serde(default)]
+    pub allowance: Option<String>,
+    /// Allows sending native tokens that aren't allowed by the [`TokenPolicy`](crate::wallet::account::TokenPolicy)
+    /// of the account.
+    #[serde(default)]
+    pub allow_restricted_tokens: bool,
+    /// Amount of milestones that need to be confirmed after the one that included the transaction, before it's
+    /// considered confirmed. Overrides the default of the wallet.
+    #[serde(default)]
+    pub confirmation_depth: Option<u32>,
+    /// The maximum amount the transaction may additionally lock as storage deposit, which is the storage deposit of
+    /// the created outputs minus the one of the consumed inputs.
+    #[serde(default, with = "crate::utils::serde::option_string")]
+    pub max_storage_deposit: Option<u64>,
 }
 
 impl TransactionOptions {
@@ -38,6 +71,7 @@ impl TransactionOptions {
     pub fn try_from_dto(value: &TransactionOptionsDto) -> Result<Self, Error> {
         Ok(Self {
             remainder_value_strategy: value.remainder_value_strategy.clone(),
+            remainder_split: value.remainder_split.clone(),
             tagged_data_payload: value
                 .tagged_data_payload
                 .as_ref()
@@ -45,9 +79,17 @@ impl TransactionOptions {
                 .transpose()?,
             custom_inputs: value.custom_inputs.clone(),
             mandatory_inputs: value.mandatory_inputs.clone(),
+            input_selection_strategy: value.input_selection_strategy,
             burn: value.burn.as_ref().map(Burn::try_from).transpose()?,
             note: value.note.clone(),
             allow_micro_amount: value.allow_micro_amount,
+            allow_burning_tokens: value.allow_burning_tokens,
+            allow_destroying_chains: value.allow_destroying_chains,
+            force: value.force,
+            allowance: value.allowance.clone(),
+            allow_restricted_tokens: value.allow_restricted_tokens,
+            confirmation_depth: value.confirmation_depth,
+            max_storage_deposit: value.max_storage_deposit,
         })
     }
 }
@@ -59,6 +101,8 @@ pub struct TransactionOptionsDto {
     #[serde(default)]
     pub remainder_value_strategy: RemainderValueStrategy,
     #[serde(default)]
+    pub remainder_split: Option<RemainderSplit>,
+    #[serde(default)]
     pub tagged_data_payload: Option<TaggedDataPayloadDto>,
     // If custom inputs are provided only they are used. If also other additional inputs should be used,
     // `mandatory_inputs` should be used instead.
@@ -66,10 +110,26 @@ pub struct TransactionOptionsDto {
     pub custom_inputs: Option<Vec<OutputId>>,
     #[serde(default)]
     pub mandatory_inputs: Option<Vec<OutputId>>,
+    #[serde(default)]
+    pub input_selection_strategy: InputSelectionStrategy,
     pub burn: Option<BurnDto>,
     pub note: Option<String>,
     #[serde(default)]
     pub allow_micro_amount: bool,
+    #[serde(default)]
+    pub allow_burning_tokens: bool,
+    #[serde(default)]
+    pub allow_destroying_chains: bool,
+    #[serde(default)]
+    pub force: bool,
+    #[serde(default)]
+    pub allowance: Option<String>,
+    #[serde(default)]
+    pub allow_restricted_tokens: bool,
+    #[serde(default)]
+    pub confirmation_depth: Option<u32>,
+    #[serde(default, with = "crate::utils::serde::option_string")]
+    pub max_storage_deposit: Option<u64>,
 }
 
 #[allow(clippy::enum_variant_names)]
@@ -77,12 +137,19 @@ pub struct TransactionOptionsDto {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(tag = "strategy", content = "value")]
 pub enum RemainderValueStrategy {
-    /// Keep the remainder value on the source address.
+    /// Keep the remainder value on the source address. For accounts with a primary alias, the remainder is sent to the
+    /// address of the alias, see [`Account::set_primary_alias()`](crate::wallet::Account::set_primary_alias).
     ReuseAddress,
     /// Move the remainder value to a change address.
     ChangeAddress,
     /// Move the remainder value to any specified address.
     CustomAddress(AccountAddress),
+    /// Split the remainder value across multiple addresses, proportional to their weights. Native tokens stay on the
+    /// first address, as do shares that wouldn't cover their storage deposit.
+    Split {
+        addresses: Vec<Bech32Address>,
+        weights: Vec<u64>,
+    },
 }
 
 impl Default for RemainderValueStrategy {
@@ -90,3 +157,13 @@ impl Default for RemainderValueStrategy {
         Self::ReuseAddress
     }
 }
+
+/// How the remainder value gets split into multiple outputs.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "strategy", content = "value")]
+pub enum RemainderSplit {
+    /// Split the remainder into the given number of outputs with roughly equal amounts.
+    EqualParts(u16),
+    /// Split the remainder into outputs with the given amounts, larger ones first. The rest stays in a single output.
+    Denominations(Vec<u64>),
+}
@@ -37,7 +37,7 @@ impl Account {
     ) -> crate::wallet::Result<Output> {
         log::debug!("[OUTPUT] prepare_output {params:?}");
         let transaction_options = transaction_options.into();
-        let token_supply = self.client().get_token_supply().await?;
+        let token_supply = self.network_params().await?.token_supply;
 
         self.client().bech32_hrp_matches(params.recipient_address.hrp()).await?;
 
@@ -46,7 +46,7 @@ impl Account {
                 return self.prepare_nft_output(params, transaction_options, nft_id).await;
             }
         }
-        let rent_structure = self.client().get_rent_structure().await?;
+        let rent_structure = self.network_params().await?.rent_structure;
 
         // We start building with minimum storage deposit, so we know the minimum required amount and can later replace
         // it, if needed
@@ -189,8 +189,9 @@ impl Account {
 
         let transaction_options = transaction_options.into();
 
-        let token_supply = self.client().get_token_supply().await?;
-        let rent_structure = self.client().get_rent_structure().await?;
+        let network_params = self.network_params().await?;
+        let token_supply = network_params.token_supply;
+        let rent_structure = network_params.rent_structure;
         let unspent_nft_outputs = self
             .unspent_outputs(Some(FilterOptions {
                 output_types: Some(vec![NftOutput::KIND]),
@@ -366,10 +367,14 @@ impl Account {
                         None
                     }
                     RemainderValueStrategy::ChangeAddress => {
+                        // Stays reserved until the storage deposit is returned to it
                         let remainder_address = self.generate_remainder_address().await?;
                         Some(remainder_address.address().inner)
                     }
                     RemainderValueStrategy::CustomAddress(address) => Some(address.address().inner),
+                    RemainderValueStrategy::Split { addresses, .. } => {
+                        addresses.first().map(|address| *address.inner())
+                    }
                 }
             }
             None => None,
@@ -3,19 +3,30 @@
 
 use std::collections::HashSet;
 
+use crypto::keys::slip10::Chain;
 use instant::Instant;
 use packable::bounded::TryIntoBoundedU16Error;
 
+#[cfg(feature = "ledger_nano")]
+use crate::client::secret::SecretManager;
 #[cfg(feature = "events")]
-use crate::wallet::events::types::{AddressData, TransactionProgressEvent, WalletEvent};
+use crate::wallet::events::types::{AddressData, EventOrigin, TransactionProgressEvent, WalletEvent};
 use crate::{
-    client::api::PreparedTransactionData,
+    client::{api::PreparedTransactionData, constants::HD_WALLET_TYPE},
     types::block::{
+        address::Address,
         input::INPUT_COUNT_RANGE,
         output::{Output, OUTPUT_COUNT_RANGE},
     },
     wallet::account::{
-        operations::transaction::{RemainderValueStrategy, TransactionOptions},
+        operations::{
+            token_policy::verify_token_policy,
+            transaction::{
+                input_selection::verify_burn_capabilities,
+                remainder_split::{split_remainder, split_remainder_to_addresses, verify_remainder_split},
+                PreparedTransactionContext, RemainderValueStrategy, TransactionOptions,
+            },
+        },
         Account,
     },
 };
@@ -30,8 +41,9 @@ impl Account {
         log::debug!("[TRANSACTION] prepare_transaction");
         let options = options.into();
         let prepare_transaction_start_time = Instant::now();
-        let rent_structure = self.client().get_rent_structure().await?;
-        let token_supply = self.client().get_token_supply().await?;
+        let network_params = self.network_params().await?;
+        let rent_structure = network_params.rent_structure;
+        let token_supply = network_params.token_supply;
 
         // Check if the outputs have enough amount to cover the storage deposit
         for output in &outputs {
@@ -39,6 +51,16 @@ impl Account {
         }
 
         let is_burn_present = options.as_ref().map(|options| options.burn.is_some()).unwrap_or(false);
+        // The allowance is only charged once the transaction is submitted
+        let allowance_usage = match options.as_ref().and_then(|options| options.allowance.as_ref()) {
+            Some(allowance) => Some((
+                allowance.clone(),
+                self.check_spending_allowance(allowance, &outputs).await?,
+            )),
+            None => None,
+        };
+        let confirmation_depth = options.as_ref().and_then(|options| options.confirmation_depth);
+        let note = options.as_ref().and_then(|options| options.note.clone());
 
         // Validate the number of outputs. The validation shouldn't be performed if [`Burn`] is present.
         // The outputs will be generated by the input selection algorithm (ISA).
@@ -66,12 +88,17 @@ impl Account {
             }
         }
 
+        // The chain of a remainder on an address of the account that isn't found by the input selection
+        let mut remainder_chain = None;
+        // The change address that is reserved for this transaction
+        let mut reserved_remainder_address = None;
         let remainder_address = match &options {
             Some(options) => {
                 match &options.remainder_value_strategy {
                     RemainderValueStrategy::ReuseAddress => {
-                        // select_inputs will select an address from the inputs if it's none
-                        None
+                        // select_inputs will select an address from the inputs if it's none, the funds of an account
+                        // with a primary alias stay under the alias address, as documented on the strategy
+                        self.details().await.primary_alias_address()
                     }
                     RemainderValueStrategy::ChangeAddress => {
                         let remainder_address = self.generate_remainder_address().await?;
@@ -85,18 +112,102 @@ impl Account {
                                         address: remainder_address.address,
                                     }),
                                 ),
+                                EventOrigin::new("prepare_transaction"),
                             )
                             .await;
                         }
-                        Some(remainder_address.address().inner)
+                        reserved_remainder_address = Some(remainder_address.address().inner);
+                        reserved_remainder_address
                     }
                     RemainderValueStrategy::CustomAddress(address) => Some(address.address().inner),
+                    RemainderValueStrategy::Split { addresses, weights } => {
+                        verify_remainder_split(addresses, weights)?;
+                        for address in addresses {
+                            self.client().bech32_hrp_matches(address.hrp()).await?;
+                        }
+                        remainder_chain = {
+                            let account_details = self.details().await;
+                            account_details
+                                .public_addresses
+                                .iter()
+                                .chain(account_details.internal_addresses.iter())
+                                .find(|account_address| account_address.address.inner() == addresses[0].inner())
+                                .map(|account_address| {
+                                    Chain::from_u32_hardened(vec![
+                                        HD_WALLET_TYPE,
+                                        account_details.coin_type,
+                                        account_details.index,
+                                        account_address.internal as u32,
+                                        account_address.key_index,
+                                    ])
+                                })
+                        };
+                        // The Ledger Nano only accepts a remainder that it can derive
+                        #[cfg(feature = "ledger_nano")]
+                        if remainder_chain.is_none()
+                            && matches!(&*self.wallet.secret_manager.read().await, SecretManager::LedgerNano(_))
+                        {
+                            return Err(crate::wallet::Error::InvalidRemainderSplit(
+                                "the first address has to be an address of the account".to_string(),
+                            ));
+                        }
+                        // The remainder is created on the first address and split after the input selection
+                        Some(*addresses[0].inner())
+                    }
                 }
             }
-            None => None,
+            None => self.details().await.primary_alias_address(),
         };
 
-        let selected_transaction_data = self
+        let prepared_transaction_data = match self
+            .select_and_build_transaction(outputs, options, remainder_address, remainder_chain)
+            .await
+        {
+            Ok(prepared_transaction_data) => prepared_transaction_data,
+            Err(err) => {
+                // release the change address so it's used for the next transaction
+                if let Some(address) = &reserved_remainder_address {
+                    self.release_remainder_address(address).await;
+                }
+                return Err(err);
+            }
+        };
+
+        self.set_prepared_transaction_context(
+            &prepared_transaction_data,
+            PreparedTransactionContext {
+                note,
+                allowance_usage,
+                remainder_address: reserved_remainder_address,
+                confirmation_depth,
+                ..Default::default()
+            },
+        )
+        .await;
+
+        log::debug!(
+            "[TRANSACTION] finished prepare_transaction in {:.2?}",
+            prepare_transaction_start_time.elapsed()
+        );
+        Ok(prepared_transaction_data)
+    }
+
+    // Selects the inputs, adjusts the remainder and builds the transaction essence, the selected inputs are unlocked if
+    // it fails
+    async fn select_and_build_transaction(
+        &self,
+        outputs: Vec<Output>,
+        options: Option<TransactionOptions>,
+        remainder_address: Option<Address>,
+        remainder_chain: Option<Chain>,
+    ) -> crate::wallet::Result<PreparedTransactionData> {
+        let network_params = self.network_params().await?;
+        let rent_structure = network_params.rent_structure;
+        let token_supply = network_params.token_supply;
+
+        // The splits tell the remainder apart from provided outputs that are equal to it
+        let provided_outputs = outputs.clone();
+        let mut selected_transaction_data = self
             .select_inputs(
                 outputs,
                 options
@@ -109,25 +220,102 @@ impl Account {
                     .map(|inputs| HashSet::from_iter(inputs.clone())),
                 remainder_address,
                 options.as_ref().and_then(|options| options.burn.as_ref()),
+                options
+                    .as_ref()
+                    .map(|options| options.input_selection_strategy)
+                    .unwrap_or_default(),
+                true,
             )
             .await?;
+        if let (Some(remainder), Some(chain)) = (selected_transaction_data.remainder.as_mut(), remainder_chain) {
+            remainder.chain = Some(chain);
+        }
 
-        let prepared_transaction_data = match self
+        if let Err(err) = verify_burn_capabilities(
+            &selected_transaction_data,
+            options.as_ref().and_then(|options| options.burn.as_ref()),
+            options.as_ref().map_or(false, |options| options.allow_burning_tokens),
+            options.as_ref().map_or(false, |options| options.allow_destroying_chains),
+        ) {
+            // unlock outputs so they are available for a new transaction
+            self.unlock_inputs(&selected_transaction_data.inputs).await?;
+            return Err(err);
+        }
+
+        if !options
+            .as_ref()
+            .map_or(false, |options| options.allow_restricted_tokens)
+        {
+            let verified = verify_token_policy(&*self.details().await, &selected_transaction_data);
+            if let Err(err) = verified {
+                // unlock outputs so they are available for a new transaction
+                self.unlock_inputs(&selected_transaction_data.inputs).await?;
+                return Err(err);
+            }
+        }
+
+        if let Some(RemainderValueStrategy::Split { addresses, weights }) =
+            options.as_ref().map(|options| &options.remainder_value_strategy)
+        {
+            if let Err(err) = split_remainder_to_addresses(
+                &mut selected_transaction_data,
+                &provided_outputs,
+                addresses,
+                weights,
+                rent_structure,
+                token_supply,
+            ) {
+                // unlock outputs so they are available for a new transaction
+                self.unlock_inputs(&selected_transaction_data.inputs).await?;
+                return Err(err);
+            }
+        }
+
+        if let Some(remainder_split) = options.as_ref().and_then(|options| options.remainder_split.as_ref()) {
+            if let Err(err) = split_remainder(
+                &mut selected_transaction_data,
+                &provided_outputs,
+                remainder_split,
+                rent_structure,
+                token_supply,
+            ) {
+                // unlock outputs so they are available for a new transaction
+                self.unlock_inputs(&selected_transaction_data.inputs).await?;
+                return Err(err);
+            }
+        }
+
+        if let Some(max_storage_deposit) = options.as_ref().and_then(|options| options.max_storage_deposit) {
+            let required = selected_transaction_data.additional_storage_deposit(&rent_structure);
+            if required > max_storage_deposit {
+                // unlock outputs so they are available for a new transaction
+                self.unlock_inputs(&selected_transaction_data.inputs).await?;
+                return Err(crate::wallet::Error::StorageDepositBudgetExceeded {
+                    max: max_storage_deposit,
+                    required,
+                });
+            }
+        }
+
+        // Verified after the input selection, so remainders and split outputs are verified too
+        if !options.as_ref().map_or(false, |options| options.force) {
+            if let Err(err) = self.verify_destinations(&selected_transaction_data.outputs).await {
+                // unlock outputs so they are available for a new transaction
+                self.unlock_inputs(&selected_transaction_data.inputs).await?;
+                return Err(err);
+            }
+        }
+
+        match self
             .build_transaction_essence(selected_transaction_data.clone(), options)
             .await
         {
-            Ok(res) => res,
+            Ok(prepared_transaction_data) => Ok(prepared_transaction_data),
             Err(err) => {
                 // unlock outputs so they are available for a new transaction
                 self.unlock_inputs(&selected_transaction_data.inputs).await?;
-                return Err(err);
+                Err(err)
             }
-        };
-
-        log::debug!(
-            "[TRANSACTION] finished prepare_transaction in {:.2?}",
-            prepare_transaction_start_time.elapsed()
-        );
-        Ok(prepared_transaction_data)
+        }
     }
 }
@@ -0,0 +1,469 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    client::api::input_selection::Selected,
+    types::block::{
+        address::Bech32Address,
+        output::{
+            unlock_condition::AddressUnlockCondition, BasicOutputBuilder, Output, Rent, RentStructure, OUTPUT_COUNT_MAX,
+        },
+    },
+    wallet::{account::operations::transaction::RemainderSplit, Error},
+};
+
+// Returns the index of the remainder in the selected outputs. The input selection appends it after the provided outputs
+// and the chain transitions, which aren't basic outputs, so it follows the provided outputs that are equal to it.
+fn remainder_position(selected_outputs: &[Output], provided_outputs: &[Output], remainder: &Output) -> Option<usize> {
+    let equal_provided_outputs = provided_outputs.iter().filter(|output| *output == remainder).count();
+    selected_outputs
+        .iter()
+        .enumerate()
+        .filter(|(_, output)| *output == remainder)
+        .nth(equal_provided_outputs)
+        .map(|(position, _)| position)
+}
+
+/// Splits the remainder output of the selected transaction data into multiple basic outputs to the same address. The
+/// original remainder output keeps the native tokens and the value that isn't moved to the new outputs. Outputs that
+/// wouldn't cover their storage deposit or exceed the maximum output count aren't created.
+pub(crate) fn split_remainder(
+    selected: &mut Selected,
+    provided_outputs: &[Output],
+    split: &RemainderSplit,
+    rent_structure: RentStructure,
+    token_supply: u64,
+) -> crate::wallet::Result<()> {
+    let remainder = match selected.remainder.as_mut() {
+        Some(remainder) => remainder,
+        None => return Ok(()),
+    };
+    let basic_output = match &remainder.output {
+        Output::Basic(basic_output) => basic_output.clone(),
+        _ => return Ok(()),
+    };
+    let Some(position) = remainder_position(&selected.outputs, provided_outputs, &remainder.output) else {
+        return Ok(());
+    };
+
+    let total = basic_output.amount();
+    let remainder_min_amount = remainder.output.rent_cost(&rent_structure);
+    let split_output_min_amount = Output::Basic(
+        BasicOutputBuilder::from(&basic_output)
+            .with_native_tokens([])
+            .finish(token_supply)?,
+    )
+    .rent_cost(&rent_structure);
+    let available_outputs = (OUTPUT_COUNT_MAX as usize).saturating_sub(selected.outputs.len());
+
+    let mut split_amounts = Vec::new();
+    match split {
+        RemainderSplit::EqualParts(parts) => {
+            let mut parts = (*parts as usize).clamp(1, available_outputs + 1) as u64;
+            while parts > 1 {
+                let part = total / parts;
+                if part >= split_output_min_amount && total - part * (parts - 1) >= remainder_min_amount {
+                    break;
+                }
+                parts -= 1;
+            }
+            split_amounts = vec![total / parts; parts as usize - 1];
+        }
+        RemainderSplit::Denominations(denominations) => {
+            let mut denominations = denominations.clone();
+            denominations.sort_unstable_by(|a, b| b.cmp(a));
+            let mut rest = total;
+            for denomination in denominations {
+                while split_amounts.len() < available_outputs
+                    && denomination >= split_output_min_amount
+                    && rest.saturating_sub(denomination) >= remainder_min_amount
+                {
+                    split_amounts.push(denomination);
+                    rest -= denomination;
+                }
+            }
+        }
+    }
+
+    if split_amounts.is_empty() {
+        return Ok(());
+    }
+
+    let remainder_output = BasicOutputBuilder::from(&basic_output)
+        .with_amount(total - split_amounts.iter().sum::<u64>())
+        .finish_output(token_supply)?;
+    let split_outputs = split_amounts
+        .into_iter()
+        .map(|amount| {
+            BasicOutputBuilder::from(&basic_output)
+                .with_native_tokens([])
+                .with_amount(amount)
+                .finish_output(token_supply)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    log::debug!("[TRANSACTION] split remainder into {} outputs", split_outputs.len() + 1);
+    selected.outputs[position] = remainder_output.clone();
+    selected.outputs.splice(position + 1..position + 1, split_outputs);
+    remainder.output = remainder_output;
+
+    Ok(())
+}
+
+/// Verifies that the addresses and weights of a
+/// [`RemainderValueStrategy::Split`](crate::wallet::account::RemainderValueStrategy::Split) can be used.
+pub(crate) fn verify_remainder_split(addresses: &[Bech32Address], weights: &[u64]) -> crate::wallet::Result<()> {
+    if addresses.is_empty() {
+        return Err(Error::InvalidRemainderSplit("no addresses provided".to_string()));
+    }
+    if addresses.len() != weights.len() {
+        return Err(Error::InvalidRemainderSplit(format!(
+            "{} addresses, but {} weights provided",
+            addresses.len(),
+            weights.len()
+        )));
+    }
+    if weights.iter().all(|weight| *weight == 0) {
+        return Err(Error::InvalidRemainderSplit("all weights are 0".to_string()));
+    }
+    Ok(())
+}
+
+/// Splits the remainder output of the selected transaction data across the given addresses, proportional to their
+/// weights. The remainder output is expected to be on the first address, it keeps the native tokens and the value of
+/// the shares that wouldn't cover their storage deposit or exceed the maximum output count.
+pub(crate) fn split_remainder_to_addresses(
+    selected: &mut Selected,
+    provided_outputs: &[Output],
+    addresses: &[Bech32Address],
+    weights: &[u64],
+    rent_structure: RentStructure,
+    token_supply: u64,
+) -> crate::wallet::Result<()> {
+    verify_remainder_split(addresses, weights)?;
+
+    let remainder = match selected.remainder.as_mut() {
+        Some(remainder) => remainder,
+        None => return Ok(()),
+    };
+    let basic_output = match &remainder.output {
+        Output::Basic(basic_output) => basic_output.clone(),
+        _ => return Ok(()),
+    };
+    let Some(position) = remainder_position(&selected.outputs, provided_outputs, &remainder.output) else {
+        return Ok(());
+    };
+
+    let total = basic_output.amount();
+    let total_weight = weights.iter().map(|weight| *weight as u128).sum::<u128>();
+    let remainder_min_amount = remainder.output.rent_cost(&rent_structure);
+    let available_outputs = (OUTPUT_COUNT_MAX as usize).saturating_sub(selected.outputs.len());
+
+    let mut rest = total;
+    let mut split_outputs = Vec::new();
+    for (address, weight) in addresses.iter().zip(weights).skip(1) {
+        let share = (total as u128 * *weight as u128 / total_weight) as u64;
+        let min_amount = BasicOutputBuilder::new_with_minimum_storage_deposit(rent_structure)
+            .add_unlock_condition(AddressUnlockCondition::new(*address))
+            .finish_output(token_supply)?
+            .amount();
+        if split_outputs.len() < available_outputs
+            && share >= min_amount
+            && rest.saturating_sub(share) >= remainder_min_amount
+        {
+            split_outputs.push(
+                BasicOutputBuilder::new_with_amount(share)
+                    .add_unlock_condition(AddressUnlockCondition::new(*address))
+                    .finish_output(token_supply)?,
+            );
+            rest -= share;
+        }
+    }
+
+    if split_outputs.is_empty() {
+        return Ok(());
+    }
+
+    let remainder_output = BasicOutputBuilder::from(&basic_output)
+        .with_amount(rest)
+        .finish_output(token_supply)?;
+
+    log::debug!(
+        "[TRANSACTION] split remainder across {} addresses",
+        split_outputs.len() + 1
+    );
+    selected.outputs[position] = remainder_output.clone();
+    selected.outputs.splice(position + 1..position + 1, split_outputs);
+    remainder.output = remainder_output;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        client::api::RemainderData,
+        types::block::{
+            address::{Address, Ed25519Address},
+            output::{NativeToken, TokenId},
+        },
+        U256,
+    };
+
+    const TOKEN_SUPPLY: u64 = 1_813_620_509_061_365;
+
+    fn address(byte: u8) -> Address {
+        Address::Ed25519(Ed25519Address::new([byte; Ed25519Address::LENGTH]))
+    }
+
+    fn basic_output(amount: u64, address: Address) -> Output {
+        BasicOutputBuilder::new_with_amount(amount)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .finish_output(TOKEN_SUPPLY)
+            .unwrap()
+    }
+
+    fn selected(provided_outputs: &[Output], remainder: Output) -> Selected {
+        let mut outputs = provided_outputs.to_vec();
+        outputs.push(remainder.clone());
+        Selected {
+            inputs: Vec::new(),
+            outputs,
+            remainder: Some(RemainderData {
+                output: remainder,
+                chain: None,
+                address: address(1),
+            }),
+        }
+    }
+
+    #[test]
+    fn split_remainder_equal_parts() {
+        let provided_outputs = [basic_output(1_000_000, address(3))];
+        let mut selected = selected(&provided_outputs, basic_output(3_000_000, address(1)));
+
+        split_remainder(
+            &mut selected,
+            &provided_outputs,
+            &RemainderSplit::EqualParts(3),
+            RentStructure::default(),
+            TOKEN_SUPPLY,
+        )
+        .unwrap();
+
+        assert_eq!(
+            selected.outputs,
+            [
+                basic_output(1_000_000, address(3)),
+                basic_output(1_000_000, address(1)),
+                basic_output(1_000_000, address(1)),
+                basic_output(1_000_000, address(1)),
+            ]
+        );
+        assert_eq!(selected.remainder.unwrap().output, basic_output(1_000_000, address(1)));
+    }
+
+    #[test]
+    fn split_remainder_equal_parts_covering_storage_deposit() {
+        let rent_structure = RentStructure::default();
+        let min_amount = basic_output(1_000_000, address(1)).rent_cost(&rent_structure);
+        let mut selected = selected(&[], basic_output(2 * min_amount + 1, address(1)));
+
+        // Only two parts cover their storage deposit
+        split_remainder(
+            &mut selected,
+            &[],
+            &RemainderSplit::EqualParts(4),
+            rent_structure,
+            TOKEN_SUPPLY,
+        )
+        .unwrap();
+
+        assert_eq!(
+            selected.outputs,
+            [
+                basic_output(min_amount + 1, address(1)),
+                basic_output(min_amount, address(1))
+            ]
+        );
+    }
+
+    #[test]
+    fn split_remainder_denominations() {
+        let mut selected = selected(&[], basic_output(3_500_000, address(1)));
+
+        split_remainder(
+            &mut selected,
+            &[],
+            &RemainderSplit::Denominations(vec![500_000, 1_000_000]),
+            RentStructure::default(),
+            TOKEN_SUPPLY,
+        )
+        .unwrap();
+
+        // The remainder keeps enough for its own storage deposit
+        assert_eq!(
+            selected.outputs,
+            [
+                basic_output(500_000, address(1)),
+                basic_output(1_000_000, address(1)),
+                basic_output(1_000_000, address(1)),
+                basic_output(1_000_000, address(1)),
+            ]
+        );
+    }
+
+    #[test]
+    fn split_remainder_keeps_native_tokens() {
+        let native_token = NativeToken::new(TokenId::from([1; TokenId::LENGTH]), U256::from(10)).unwrap();
+        let remainder = |amount| {
+            BasicOutputBuilder::new_with_amount(amount)
+                .add_unlock_condition(AddressUnlockCondition::new(address(1)))
+                .add_native_token(native_token)
+                .finish_output(TOKEN_SUPPLY)
+                .unwrap()
+        };
+        let mut selected = selected(&[], remainder(2_000_000));
+
+        split_remainder(
+            &mut selected,
+            &[],
+            &RemainderSplit::EqualParts(2),
+            RentStructure::default(),
+            TOKEN_SUPPLY,
+        )
+        .unwrap();
+
+        assert_eq!(
+            selected.outputs,
+            [remainder(1_000_000), basic_output(1_000_000, address(1))]
+        );
+        assert_eq!(selected.remainder.unwrap().output, remainder(1_000_000));
+    }
+
+    #[test]
+    fn split_remainder_output_count_max() {
+        let provided_outputs = vec![basic_output(1_000_000, address(3)); OUTPUT_COUNT_MAX as usize - 2];
+        let mut selected = selected(&provided_outputs, basic_output(10_000_000, address(1)));
+
+        split_remainder(
+            &mut selected,
+            &provided_outputs,
+            &RemainderSplit::EqualParts(10),
+            RentStructure::default(),
+            TOKEN_SUPPLY,
+        )
+        .unwrap();
+
+        assert_eq!(selected.outputs.len(), OUTPUT_COUNT_MAX as usize);
+        assert_eq!(
+            selected.outputs[OUTPUT_COUNT_MAX as usize - 2..],
+            [basic_output(5_000_000, address(1)), basic_output(5_000_000, address(1))]
+        );
+    }
+
+    #[test]
+    fn split_remainder_to_addresses_by_weight() {
+        let provided_outputs = [basic_output(1_000_000, address(3))];
+        let mut selected = selected(&provided_outputs, basic_output(3_000_000, address(1)));
+        let addresses = [
+            address(1).to_bech32_unchecked("rms"),
+            address(2).to_bech32_unchecked("rms"),
+        ];
+
+        split_remainder_to_addresses(
+            &mut selected,
+            &provided_outputs,
+            &addresses,
+            &[1, 2],
+            RentStructure::default(),
+            TOKEN_SUPPLY,
+        )
+        .unwrap();
+
+        assert_eq!(
+            selected.outputs,
+            [
+                basic_output(1_000_000, address(3)),
+                basic_output(1_000_000, address(1)),
+                basic_output(2_000_000, address(2)),
+            ]
+        );
+        assert_eq!(selected.remainder.unwrap().output, basic_output(1_000_000, address(1)));
+    }
+
+    #[test]
+    fn split_remainder_to_addresses_keeps_small_shares() {
+        let mut selected = selected(&[], basic_output(3_000_000, address(1)));
+        let addresses = [
+            address(1).to_bech32_unchecked("rms"),
+            address(2).to_bech32_unchecked("rms"),
+        ];
+
+        // The share of the second address wouldn't cover its storage deposit
+        split_remainder_to_addresses(
+            &mut selected,
+            &[],
+            &addresses,
+            &[1_000, 1],
+            RentStructure::default(),
+            TOKEN_SUPPLY,
+        )
+        .unwrap();
+
+        assert_eq!(selected.outputs, [basic_output(3_000_000, address(1))]);
+    }
+
+    #[test]
+    fn split_remainder_to_addresses_after_equal_provided_output() {
+        let provided_outputs = [basic_output(3_000_000, address(1))];
+        let mut selected = selected(&provided_outputs, basic_output(3_000_000, address(1)));
+        let addresses = [
+            address(1).to_bech32_unchecked("rms"),
+            address(2).to_bech32_unchecked("rms"),
+        ];
+
+        split_remainder_to_addresses(
+            &mut selected,
+            &provided_outputs,
+            &addresses,
+            &[1, 2],
+            RentStructure::default(),
+            TOKEN_SUPPLY,
+        )
+        .unwrap();
+
+        // The provided output stays as it is
+        assert_eq!(
+            selected.outputs,
+            [
+                basic_output(3_000_000, address(1)),
+                basic_output(1_000_000, address(1)),
+                basic_output(2_000_000, address(2)),
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_remainder_split() {
+        let addresses = [
+            address(1).to_bech32_unchecked("rms"),
+            address(2).to_bech32_unchecked("rms"),
+        ];
+
+        assert!(verify_remainder_split(&addresses, &[1, 2]).is_ok());
+        assert!(matches!(
+            verify_remainder_split(&[], &[]),
+            Err(Error::InvalidRemainderSplit(_))
+        ));
+        assert!(matches!(
+            verify_remainder_split(&addresses, &[1]),
+            Err(Error::InvalidRemainderSplit(_))
+        ));
+        assert!(matches!(
+            verify_remainder_split(&addresses, &[0, 0]),
+            Err(Error::InvalidRemainderSplit(_))
+        ));
+    }
+}
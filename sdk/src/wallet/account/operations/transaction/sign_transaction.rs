@@ -4,11 +4,11 @@
 #[cfg(all(feature = "events", feature = "ledger_nano"))]
 use {
     crate::client::api::PreparedTransactionDataDto, crate::client::secret::ledger_nano::needs_blind_signing,
-    crate::client::secret::SecretManager,
+    crate::client::secret::SecretManager, crate::wallet::events::types::TransactionPreview,
 };
 
 #[cfg(feature = "events")]
-use crate::wallet::events::types::{TransactionProgressEvent, WalletEvent};
+use crate::wallet::events::types::{EventOrigin, TransactionProgressEvent, WalletEvent};
 use crate::{
     client::{
         api::{transaction::validate_transaction_payload_length, PreparedTransactionData, SignedTransactionData},
@@ -25,23 +25,19 @@ impl Account {
     ) -> crate::wallet::Result<SignedTransactionData> {
         log::debug!("[TRANSACTION] sign_transaction_essence");
         log::debug!("[TRANSACTION] prepared_transaction_data {prepared_transaction_data:?}");
-        #[cfg(feature = "events")]
-        self.emit(
-            self.details().await.index,
-            WalletEvent::TransactionProgress(TransactionProgressEvent::SigningTransaction),
-        )
-        .await;
-
+        self.check_not_watch_only("sign transactions").await?;
         #[cfg(all(feature = "events", feature = "ledger_nano"))]
         if let SecretManager::LedgerNano(ledger) = &*self.wallet.secret_manager.read().await {
             let ledger_nano_status = ledger.get_ledger_nano_status().await;
             if let Some(buffer_size) = ledger_nano_status.buffer_size() {
-                if needs_blind_signing(prepared_transaction_data, buffer_size) {
+                let blind_signing = needs_blind_signing(prepared_transaction_data, buffer_size);
+                if blind_signing {
                     self.emit(
                         self.details().await.index,
                         WalletEvent::TransactionProgress(TransactionProgressEvent::PreparedTransactionEssenceHash(
                             prefix_hex::encode(prepared_transaction_data.essence.hash()),
                         )),
+                        EventOrigin::new("sign_transaction_essence"),
                     )
                     .await;
                 } else {
@@ -50,18 +46,41 @@ impl Account {
                         WalletEvent::TransactionProgress(TransactionProgressEvent::PreparedTransaction(Box::new(
                             PreparedTransactionDataDto::from(prepared_transaction_data),
                         ))),
+                        EventOrigin::new("sign_transaction_essence"),
                     )
                     .await;
                 }
+                // So UIs can show what the device displays, without deriving it from the prepared transaction
+                self.emit(
+                    self.details().await.index,
+                    WalletEvent::TransactionProgress(TransactionProgressEvent::TransactionPreview(Box::new(
+                        TransactionPreview::new(
+                            prepared_transaction_data,
+                            self.client().get_bech32_hrp().await?,
+                            blind_signing,
+                        ),
+                    ))),
+                    EventOrigin::new("sign_transaction_essence"),
+                )
+                .await;
             }
         }
 
+        #[cfg(feature = "events")]
+        self.emit(
+            self.details().await.index,
+            WalletEvent::TransactionProgress(TransactionProgressEvent::SigningTransaction),
+            EventOrigin::new("sign_transaction_essence"),
+        )
+        .await;
+
+        let current_time = self.client().local_time().await;
         let unlocks = match self
             .wallet
             .secret_manager
             .read()
             .await
-            .sign_transaction_essence(prepared_transaction_data, None)
+            .sign_transaction_essence(prepared_transaction_data, Some(current_time))
             .await
         {
             Ok(res) => res,
@@ -2,7 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 #[cfg(feature = "events")]
-use crate::wallet::events::types::{TransactionProgressEvent, WalletEvent};
+use crate::wallet::events::types::{EventOrigin, TransactionProgressEvent, WalletEvent};
 use crate::{
     types::block::{payload::Payload, BlockId},
     wallet::account::{operations::transaction::TransactionPayload, Account},
@@ -17,6 +17,8 @@ impl Account {
         log::debug!("[TRANSACTION] send_payload");
         #[cfg(feature = "events")]
         let account_index = self.details().await.index;
+        #[cfg(feature = "events")]
+        let transaction_id = transaction_payload.id();
 
         let local_pow = self.client().get_local_pow().await;
         if local_pow {
@@ -25,6 +27,7 @@ impl Account {
             self.emit(
                 account_index,
                 WalletEvent::TransactionProgress(TransactionProgressEvent::PerformingPow),
+                EventOrigin::new("submit_transaction_payload").with_transaction_id(transaction_id),
             )
             .await;
         }
@@ -37,6 +40,7 @@ impl Account {
         self.emit(
             account_index,
             WalletEvent::TransactionProgress(TransactionProgressEvent::Broadcasting),
+            EventOrigin::new("submit_transaction_payload").with_transaction_id(transaction_id),
         )
         .await;
         let block_id = self.client().post_block(&block).await?;
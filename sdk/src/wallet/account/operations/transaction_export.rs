@@ -0,0 +1,483 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{BTreeMap, HashMap};
+
+use primitive_types::U256;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    types::block::{
+        address::{Address, Bech32Address},
+        output::{Output, Rent, RentStructure, TokenId},
+        payload::transaction::{TransactionEssence, TransactionId},
+        BlockId,
+    },
+    wallet::{
+        account::{
+            types::{normalized::owner_address, InclusionState, Transaction},
+            Account,
+        },
+        Result,
+    },
+};
+
+/// The format of an export created with [`Account::export_transactions()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TransactionExportFormat {
+    /// One row per balance change of a transaction, with a header row.
+    Csv,
+    /// A list of [`ExportedTransaction`]s.
+    Json,
+}
+
+/// The time range of an export created with [`Account::export_transactions()`], based on the creation time of the
+/// transactions.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionExportRange {
+    /// Only export transactions created at or after this unix timestamp in milliseconds.
+    pub since: Option<u128>,
+    /// Only export transactions created before this unix timestamp in milliseconds.
+    pub until: Option<u128>,
+}
+
+impl TransactionExportRange {
+    fn contains(&self, timestamp: u128) -> bool {
+        self.since.map_or(true, |since| timestamp >= since) && self.until.map_or(true, |until| timestamp < until)
+    }
+}
+
+/// A transaction with the balance changes it caused for the account.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportedTransaction {
+    /// The id of the transaction.
+    pub transaction_id: TransactionId,
+    /// The id of the block that contains the transaction, if it was sent.
+    pub block_id: Option<BlockId>,
+    /// Unix timestamp in milliseconds of when the transaction was created.
+    pub timestamp: u128,
+    /// The inclusion state of the transaction, either confirmed or pending.
+    pub inclusion_state: InclusionState,
+    /// Whether the transaction was sent by someone else.
+    pub incoming: bool,
+    /// The note of the transaction.
+    pub note: Option<String>,
+    /// The change of the base coin amount per address of the account.
+    pub address_deltas: Vec<AddressDelta>,
+    /// The change of the amount that is locked as storage deposit in outputs of the account.
+    #[serde(with = "crate::utils::serde::string")]
+    pub storage_deposit_delta: i64,
+    /// The native tokens that were moved from or to the account.
+    pub native_token_deltas: Vec<NativeTokenDelta>,
+}
+
+/// The change of the base coin amount of an address in an [`ExportedTransaction`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressDelta {
+    /// The address of the account.
+    pub address: Bech32Address,
+    /// The amount the address received, negative if it sent more than it received.
+    #[serde(with = "crate::utils::serde::string")]
+    pub amount: i64,
+}
+
+/// A native token movement in an [`ExportedTransaction`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NativeTokenDelta {
+    /// The id of the native token.
+    pub token_id: TokenId,
+    /// The amount that was added to outputs of the account.
+    pub received: U256,
+    /// The amount that was removed from outputs of the account.
+    pub sent: U256,
+}
+
+impl Account {
+    /// Exports the confirmed and pending transactions of the account created within the range, oldest first. Every
+    /// transaction includes the base coin changes per address, the change of the storage deposit and the native token
+    /// movements of the account. Inputs that were already pruned from the node when the transaction was stored can't
+    /// be taken into account.
+    /// ```ignore
+    /// let csv = account
+    ///     .export_transactions(TransactionExportRange::default(), TransactionExportFormat::Csv)
+    ///     .await?;
+    /// ```
+    pub async fn export_transactions(
+        &self,
+        range: TransactionExportRange,
+        format: TransactionExportFormat,
+    ) -> Result<String> {
+        log::debug!("[export_transactions]");
+        let transactions = self.exported_transactions(range).await?;
+
+        Ok(match format {
+            TransactionExportFormat::Csv => to_csv(&transactions),
+            TransactionExportFormat::Json => serde_json::to_string(&transactions)?,
+        })
+    }
+
+    async fn exported_transactions(&self, range: TransactionExportRange) -> Result<Vec<ExportedTransaction>> {
+        let network_params = self.network_params().await?;
+        let account_details = self.details().await;
+        let account_addresses = account_details
+            .public_addresses
+            .iter()
+            .chain(account_details.internal_addresses.iter())
+            .map(|account_address| (*account_address.address.inner(), account_address.address))
+            .collect::<HashMap<Address, Bech32Address>>();
+
+        let transactions = account_details.transactions.values().chain(
+            account_details
+                .incoming_transactions
+                .values()
+                .filter(|transaction| !account_details.transactions.contains_key(&transaction.transaction_id)),
+        );
+
+        let mut exported_transactions = Vec::new();
+        for transaction in transactions.filter(|transaction| is_exported(transaction, range)) {
+            exported_transactions.push(export_transaction(
+                transaction,
+                &account_addresses,
+                network_params.token_supply,
+                &network_params.rent_structure,
+            )?);
+        }
+        exported_transactions.sort_by_key(|transaction| transaction.timestamp);
+
+        Ok(exported_transactions)
+    }
+}
+
+// Returns whether the transaction is in the range and wasn't rejected by the ledger
+fn is_exported(transaction: &Transaction, range: TransactionExportRange) -> bool {
+    matches!(
+        transaction.inclusion_state,
+        InclusionState::Pending | InclusionState::Included | InclusionState::Confirmed
+    ) && range.contains(transaction.timestamp)
+}
+
+// Computes the base coin changes per address, the storage deposit change and the native token movements of the account
+// in a transaction
+fn export_transaction(
+    transaction: &Transaction,
+    account_addresses: &HashMap<Address, Bech32Address>,
+    token_supply: u64,
+    rent_structure: &RentStructure,
+) -> Result<ExportedTransaction> {
+    let inputs = transaction
+        .inputs
+        .iter()
+        .map(|input| Output::try_from_dto(&input.output, token_supply))
+        .collect::<core::result::Result<Vec<_>, _>>()?;
+    let TransactionEssence::Regular(essence) = transaction.payload.essence();
+
+    let mut address_deltas: Vec<AddressDelta> = Vec::new();
+    let mut storage_deposit_delta = 0i64;
+    let mut native_token_deltas = BTreeMap::new();
+    let outputs = inputs
+        .iter()
+        .map(|output| (output, false))
+        .chain(essence.outputs().iter().map(|output| (output, true)));
+
+    for (output, received) in outputs {
+        let Some(address) = owner_address(output).and_then(|address| account_addresses.get(&address)) else {
+            continue;
+        };
+        let sign = if received { 1 } else { -1 };
+
+        match address_deltas.iter_mut().find(|delta| delta.address == *address) {
+            Some(delta) => delta.amount += sign * output.amount() as i64,
+            None => address_deltas.push(AddressDelta {
+                address: *address,
+                amount: sign * output.amount() as i64,
+            }),
+        }
+        storage_deposit_delta += sign * output.rent_cost(rent_structure) as i64;
+
+        for native_token in output
+            .native_tokens()
+            .into_iter()
+            .flat_map(|native_tokens| native_tokens.iter())
+        {
+            let token_id = *native_token.token_id();
+            let delta = native_token_deltas.entry(token_id).or_insert_with(|| NativeTokenDelta {
+                token_id,
+                received: U256::zero(),
+                sent: U256::zero(),
+            });
+            if received {
+                delta.received += native_token.amount();
+            } else {
+                delta.sent += native_token.amount();
+            }
+        }
+    }
+
+    Ok(ExportedTransaction {
+        transaction_id: transaction.transaction_id,
+        block_id: transaction.block_id,
+        timestamp: transaction.timestamp,
+        inclusion_state: transaction.inclusion_state,
+        incoming: transaction.incoming,
+        note: transaction.note.clone(),
+        address_deltas,
+        storage_deposit_delta,
+        native_token_deltas: native_token_deltas.into_values().collect(),
+    })
+}
+
+const CSV_HEADER: &str = "transactionId,blockId,timestamp,inclusionState,incoming,note,asset,address,delta";
+
+// One row per balance change, transactions without any change for the account get a single row without asset
+fn to_csv(transactions: &[ExportedTransaction]) -> String {
+    let mut csv = String::from(CSV_HEADER);
+    csv.push('\n');
+
+    for transaction in transactions {
+        let mut changes = transaction
+            .address_deltas
+            .iter()
+            .map(|delta| {
+                (
+                    "baseCoin".to_string(),
+                    delta.address.to_string(),
+                    delta.amount.to_string(),
+                )
+            })
+            .collect::<Vec<_>>();
+        if transaction.storage_deposit_delta != 0 {
+            changes.push((
+                "storageDeposit".to_string(),
+                String::new(),
+                transaction.storage_deposit_delta.to_string(),
+            ));
+        }
+        for delta in &transaction.native_token_deltas {
+            let amount = if delta.received >= delta.sent {
+                (delta.received - delta.sent).to_string()
+            } else {
+                format!("-{}", delta.sent - delta.received)
+            };
+            changes.push((delta.token_id.to_string(), String::new(), amount));
+        }
+        if changes.is_empty() {
+            changes.push((String::new(), String::new(), String::new()));
+        }
+
+        for (asset, address, delta) in changes {
+            let row = [
+                transaction.transaction_id.to_string(),
+                transaction
+                    .block_id
+                    .map(|block_id| block_id.to_string())
+                    .unwrap_or_default(),
+                transaction.timestamp.to_string(),
+                format!("{:?}", transaction.inclusion_state),
+                transaction.incoming.to_string(),
+                csv_field(transaction.note.as_deref().unwrap_or_default()),
+                asset,
+                address,
+                delta,
+            ];
+            csv.push_str(&row.join(","));
+            csv.push('\n');
+        }
+    }
+
+    csv
+}
+
+// Quotes a field if it contains characters that would break the row
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{
+        api::core::response::OutputWithMetadataResponse,
+        block::{
+            address::Ed25519Address,
+            output::{
+                unlock_condition::AddressUnlockCondition, BasicOutputBuilder, NativeToken, OutputId, OutputMetadata,
+                OutputWithMetadata,
+            },
+        },
+    };
+
+    const TOKEN_SUPPLY: u64 = 1_813_620_509_061_365;
+
+    fn basic_output(amount: u64, address: Address, native_token: Option<(TokenId, u64)>) -> Output {
+        let mut builder =
+            BasicOutputBuilder::new_with_amount(amount).add_unlock_condition(AddressUnlockCondition::new(address));
+        if let Some((token_id, amount)) = native_token {
+            builder = builder.add_native_token(NativeToken::new(token_id, U256::from(amount)).unwrap());
+        }
+        builder.finish_output(TOKEN_SUPPLY).unwrap()
+    }
+
+    fn input(output: Output) -> OutputWithMetadataResponse {
+        let output_id = OutputId::new(TransactionId::new([0; TransactionId::LENGTH]), 0).unwrap();
+        OutputWithMetadataResponse::from(OutputWithMetadata::new(
+            output,
+            OutputMetadata::new(
+                BlockId::new([0; BlockId::LENGTH]),
+                output_id,
+                true,
+                None,
+                None,
+                None,
+                0,
+                0,
+                0,
+            ),
+        ))
+    }
+
+    #[test]
+    fn export_transaction_deltas() {
+        let rent_structure = RentStructure::default();
+        let account_address =
+            Bech32Address::try_from_str("rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy").unwrap();
+        let account_addresses = HashMap::from([(account_address.inner, account_address)]);
+        let other_address = Address::Ed25519(Ed25519Address::new([1; Ed25519Address::LENGTH]));
+        let token_id = TokenId::new([1; TokenId::LENGTH]);
+
+        // Sent: the input of the account is split into an output to another address and the remainder
+        let input_output = basic_output(10_000_000, account_address.inner, Some((token_id, 100)));
+        let remainder = basic_output(7_000_000, account_address.inner, Some((token_id, 70)));
+        let mut transaction = Transaction::mock(vec![
+            basic_output(3_000_000, other_address, Some((token_id, 30))),
+            remainder.clone(),
+        ]);
+        transaction.inputs = vec![input(input_output.clone())];
+
+        let exported = export_transaction(&transaction, &account_addresses, TOKEN_SUPPLY, &rent_structure).unwrap();
+        assert_eq!(
+            exported.address_deltas,
+            vec![AddressDelta {
+                address: account_address,
+                amount: -3_000_000,
+            }]
+        );
+        assert_eq!(
+            exported.storage_deposit_delta,
+            remainder.rent_cost(&rent_structure) as i64 - input_output.rent_cost(&rent_structure) as i64
+        );
+        assert_eq!(
+            exported.native_token_deltas,
+            vec![NativeTokenDelta {
+                token_id,
+                received: U256::from(70),
+                sent: U256::from(100),
+            }]
+        );
+
+        // Received: the inputs of someone else aren't known
+        let received = basic_output(1_000_000, account_address.inner, None);
+        let mut transaction = Transaction::mock(vec![received.clone(), basic_output(2_000_000, other_address, None)]);
+        transaction.incoming = true;
+
+        let exported = export_transaction(&transaction, &account_addresses, TOKEN_SUPPLY, &rent_structure).unwrap();
+        assert!(exported.incoming);
+        assert_eq!(
+            exported.address_deltas,
+            vec![AddressDelta {
+                address: account_address,
+                amount: 1_000_000,
+            }]
+        );
+        assert_eq!(
+            exported.storage_deposit_delta,
+            received.rent_cost(&rent_structure) as i64
+        );
+        assert!(exported.native_token_deltas.is_empty());
+    }
+
+    #[test]
+    fn exported_inclusion_states() {
+        let address = Address::Ed25519(Ed25519Address::new([0; Ed25519Address::LENGTH]));
+        let mut transaction = Transaction::mock(vec![basic_output(1_000_000, address, None)]);
+        transaction.timestamp = 1_000;
+        let range = TransactionExportRange::default();
+
+        for (inclusion_state, exported) in [
+            (InclusionState::Pending, true),
+            (InclusionState::Included, true),
+            (InclusionState::Confirmed, true),
+            (InclusionState::Conflicting, false),
+            (InclusionState::UnknownPruned, false),
+        ] {
+            transaction.inclusion_state = inclusion_state;
+            assert_eq!(is_exported(&transaction, range), exported, "{inclusion_state:?}");
+        }
+
+        transaction.inclusion_state = InclusionState::Included;
+        let range = TransactionExportRange {
+            since: Some(2_000),
+            until: None,
+        };
+        assert!(!is_exported(&transaction, range));
+    }
+
+    #[test]
+    fn csv_rows_and_quoting() {
+        let address =
+            Bech32Address::try_from_str("rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy").unwrap();
+        let token_id = TokenId::new([1; TokenId::LENGTH]);
+        let transaction_id = TransactionId::new([1; TransactionId::LENGTH]);
+        let exported = |note: Option<&str>, changes: bool| ExportedTransaction {
+            transaction_id,
+            block_id: None,
+            timestamp: 1_000,
+            inclusion_state: InclusionState::Confirmed,
+            incoming: false,
+            note: note.map(ToString::to_string),
+            address_deltas: if changes {
+                vec![AddressDelta { address, amount: -5 }]
+            } else {
+                Vec::new()
+            },
+            storage_deposit_delta: if changes { -2 } else { 0 },
+            native_token_deltas: if changes {
+                vec![NativeTokenDelta {
+                    token_id,
+                    received: U256::from(70),
+                    sent: U256::from(100),
+                }]
+            } else {
+                Vec::new()
+            },
+        };
+
+        let csv = to_csv(&[exported(Some("say \"hi\", bye"), true), exported(None, false)]);
+        let prefix = format!("{transaction_id},,1000,Confirmed,false");
+        assert_eq!(
+            csv.lines().collect::<Vec<_>>(),
+            vec![
+                CSV_HEADER.to_string(),
+                format!("{prefix},\"say \"\"hi\"\", bye\",baseCoin,{address},-5"),
+                format!("{prefix},\"say \"\"hi\"\", bye\",storageDeposit,,-2"),
+                format!("{prefix},\"say \"\"hi\"\", bye\",{token_id},,-30"),
+                format!("{prefix},,,,"),
+            ]
+        );
+    }
+
+    #[test]
+    fn csv_field_quoting() {
+        assert_eq!(csv_field("plain note"), "plain note");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("line\nbreak"), "\"line\nbreak\"");
+        assert_eq!(csv_field("\"quoted\""), "\"\"\"quoted\"\"\"");
+    }
+}
@@ -0,0 +1,194 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    client::api::ConfirmationStatus,
+    types::block::payload::transaction::TransactionId,
+    wallet::{
+        account::{
+            operations::syncing::transactions::inclusion_state_at_depth,
+            types::{InclusionState, Transaction},
+            Account,
+        },
+        Error, Result,
+    },
+};
+
+const DEFAULT_WAIT_FOR_SEQUENCE_INTERVAL: u64 = 1;
+const DEFAULT_WAIT_FOR_SEQUENCE_MAX_ATTEMPTS: u64 = 40;
+
+impl Account {
+    /// Enables or disables the transaction sequencing. While it's enabled, every sent transaction gets the next
+    /// sequence number of the account, so they can be processed in the order they were sent.
+    pub async fn set_transaction_sequencing(&self, enabled: bool) -> Result<()> {
+        let mut account_details = self.details_mut().await;
+        account_details.transaction_sequencing = enabled;
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+        Ok(())
+    }
+
+    /// Returns whether sent transactions get a sequence number.
+    pub async fn transaction_sequencing(&self) -> bool {
+        self.details().await.transaction_sequencing
+    }
+
+    /// Returns the sequence number of the last transaction sent by the account with the transaction sequencing
+    /// enabled, if any.
+    pub async fn last_sequence_number(&self) -> Option<u64> {
+        self.details().await.next_sequence_number.checked_sub(1)
+    }
+
+    /// Returns the transaction that was sent with the sequence number.
+    pub async fn get_transaction_by_sequence_number(&self, sequence_number: u64) -> Option<Transaction> {
+        self.details()
+            .await
+            .transactions
+            .values()
+            .find(|transaction| transaction.sequence_number == Some(sequence_number))
+            .cloned()
+    }
+
+    /// Waits until all transactions sent by the account up to and including the sequence number are confirmed. The
+    /// transactions are checked in the order they were sent, every `interval` seconds, without reissuing them, which
+    /// is left to the syncing. An error is returned for the first one that conflicts, got pruned before its
+    /// confirmation could be checked or isn't confirmed within `max_attempts`.
+    pub async fn wait_for_sequence_confirmed(
+        &self,
+        sequence_number: u64,
+        interval: Option<u64>,
+        max_attempts: Option<u64>,
+    ) -> Result<()> {
+        log::debug!("[wait_for_sequence_confirmed] {sequence_number}");
+        let mut transaction_ids = {
+            let account_details = self.details().await;
+            if sequence_number >= account_details.next_sequence_number {
+                return Err(Error::UnknownSequenceNumber(sequence_number));
+            }
+
+            account_details
+                .transactions
+                .values()
+                .filter_map(|transaction| {
+                    transaction
+                        .sequence_number
+                        .filter(|transaction_sequence_number| *transaction_sequence_number <= sequence_number)
+                        .map(|transaction_sequence_number| (transaction_sequence_number, transaction.transaction_id))
+                })
+                .collect::<Vec<_>>()
+        };
+        transaction_ids.sort_unstable();
+
+        let interval = std::time::Duration::from_secs(interval.unwrap_or(DEFAULT_WAIT_FOR_SEQUENCE_INTERVAL));
+        let max_attempts = max_attempts.unwrap_or(DEFAULT_WAIT_FOR_SEQUENCE_MAX_ATTEMPTS);
+        for (_, transaction_id) in transaction_ids {
+            let mut attempt = 1;
+            while !self.is_sequence_transaction_confirmed(&transaction_id).await? {
+                if attempt >= max_attempts {
+                    return Err(crate::client::Error::TangleInclusion(format!(
+                        "transaction id: {transaction_id} not confirmed after {max_attempts} attempts"
+                    ))
+                    .into());
+                }
+                attempt += 1;
+
+                #[cfg(target_family = "wasm")]
+                gloo_timers::future::TimeoutFuture::new(interval.as_millis() as u32).await;
+
+                #[cfg(not(target_family = "wasm"))]
+                tokio::time::sleep(interval).await;
+            }
+        }
+
+        Ok(())
+    }
+
+    // Checks whether a transaction is confirmed, with the stored inclusion state or by requesting its confirmation
+    // status if it was submitted already.
+    async fn is_sequence_transaction_confirmed(&self, transaction_id: &TransactionId) -> Result<bool> {
+        let transaction = self
+            .details()
+            .await
+            .transactions
+            .get(transaction_id)
+            .cloned()
+            .ok_or(Error::TransactionNotFound(*transaction_id))?;
+
+        if let Some(confirmed) = sequence_transaction_confirmed(transaction_id, transaction.inclusion_state)? {
+            return Ok(confirmed);
+        }
+        // The transaction is sent again by the syncing
+        let Some(block_id) = transaction.block_id else {
+            return Ok(false);
+        };
+
+        let inclusion_state = match self
+            .client()
+            .confirmation_status(transaction_id, Some(&block_id))
+            .await?
+        {
+            ConfirmationStatus::Pending => InclusionState::Pending,
+            ConfirmationStatus::Conflicting { .. } => InclusionState::Conflicting,
+            ConfirmationStatus::Included { milestone_index, .. } => {
+                let confirmation_depth = transaction
+                    .confirmation_depth
+                    .unwrap_or_else(|| self.wallet.default_confirmation_depth());
+                let confirmed_milestone_index = match confirmation_depth {
+                    0 => None,
+                    _ => Some(self.confirmed_milestone_index().await?),
+                };
+                inclusion_state_at_depth(milestone_index, confirmation_depth, confirmed_milestone_index)
+            }
+        };
+
+        Ok(sequence_transaction_confirmed(transaction_id, inclusion_state)?.unwrap_or(false))
+    }
+}
+
+// Returns whether a transaction with the inclusion state is confirmed, `None` if it's still unknown, or an error if it
+// can't be confirmed anymore. A pruned transaction may have been confirmed, but that can't be verified.
+fn sequence_transaction_confirmed(
+    transaction_id: &TransactionId,
+    inclusion_state: InclusionState,
+) -> Result<Option<bool>> {
+    match inclusion_state {
+        InclusionState::Confirmed => Ok(Some(true)),
+        InclusionState::Pending | InclusionState::Included => Ok(None),
+        InclusionState::Conflicting | InclusionState::UnknownPruned => Err(crate::client::Error::TangleInclusion(
+            format!("transaction id: {transaction_id} inclusion state: {inclusion_state:?}"),
+        )
+        .into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sequence_transaction_inclusion_states() {
+        let transaction_id = TransactionId::new([1; TransactionId::LENGTH]);
+
+        assert_eq!(
+            sequence_transaction_confirmed(&transaction_id, InclusionState::Confirmed).unwrap(),
+            Some(true)
+        );
+        assert_eq!(
+            sequence_transaction_confirmed(&transaction_id, InclusionState::Pending).unwrap(),
+            None
+        );
+        assert_eq!(
+            sequence_transaction_confirmed(&transaction_id, InclusionState::Included).unwrap(),
+            None
+        );
+        // A pruned transaction isn't treated as confirmed
+        assert!(matches!(
+            sequence_transaction_confirmed(&transaction_id, InclusionState::UnknownPruned),
+            Err(Error::Client(_))
+        ));
+        assert!(matches!(
+            sequence_transaction_confirmed(&transaction_id, InclusionState::Conflicting),
+            Err(Error::Client(_))
+        ));
+    }
+}
@@ -0,0 +1,471 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "ledger_nano")]
+use crate::{client::secret::SecretManager, wallet::account::constants::DEFAULT_LEDGER_OUTPUT_CONSOLIDATION_THRESHOLD};
+use crate::{
+    types::block::{
+        input::INPUT_COUNT_MAX,
+        output::{Output, Rent, RentStructure, TokenId},
+    },
+    wallet::account::{
+        constants::DEFAULT_OUTPUT_CONSOLIDATION_THRESHOLD, operations::helpers::time::can_output_be_unlocked_now,
+        types::OutputData, Account, AccountDetails, AddressWithUnspentOutputs,
+    },
+};
+
+/// Upper bounds of the amount ranges in the size distribution.
+const AMOUNT_RANGE_BOUNDS: [u64; 5] = [1_000_000, 10_000_000, 100_000_000, 1_000_000_000, u64::MAX];
+/// Basic outputs with an amount below this multiple of their storage deposit are counted as dust.
+const DUST_STORAGE_DEPOSIT_FACTOR: u64 = 2;
+/// Amount of dust outputs at which a consolidation gets recommended, independent of the consolidation threshold.
+const DUST_CONSOLIDATION_THRESHOLD: usize = 10;
+/// Amount of free outputs that allows sending a few transactions in parallel.
+const RECOMMENDED_FREE_OUTPUTS: u16 = 3;
+
+/// A report about the unspent outputs of an account, returned from [`Account::utxo_health()`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UtxoHealthReport {
+    /// Amount of unspent outputs.
+    pub output_count: usize,
+    /// Amount of unspent outputs that are used as inputs of pending transactions.
+    pub locked_output_count: usize,
+    /// Amount of unspent outputs that aren't locked and can be unlocked now.
+    pub free_output_count: usize,
+    /// Amount of basic outputs that hold barely more than their storage deposit.
+    pub dust_output_count: usize,
+    /// Amount of unspent outputs per amount range.
+    pub size_distribution: Vec<AmountRange>,
+    /// Native tokens that are spread over multiple outputs, most fragmented first.
+    pub fragmented_native_tokens: Vec<NativeTokenFragmentation>,
+    /// Recommended maintenance actions.
+    pub recommendations: Vec<UtxoRecommendation>,
+}
+
+/// Amount of outputs with an amount in a range.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AmountRange {
+    /// Inclusive lower bound of the range.
+    #[serde(with = "crate::utils::serde::string")]
+    pub min: u64,
+    /// Exclusive upper bound of the range.
+    #[serde(with = "crate::utils::serde::string")]
+    pub max: u64,
+    /// Amount of outputs with an amount in the range.
+    pub output_count: usize,
+}
+
+/// Amount of outputs holding a native token.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NativeTokenFragmentation {
+    /// The id of the native token.
+    pub token_id: TokenId,
+    /// Amount of outputs holding the native token.
+    pub output_count: usize,
+}
+
+/// A recommended maintenance action for the unspent outputs of an account.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum UtxoRecommendation {
+    /// Consolidate outputs with [`Account::consolidate_outputs()`].
+    #[serde(rename_all = "camelCase")]
+    Consolidate {
+        /// Amount of outputs that can be consolidated.
+        output_count: usize,
+        /// Estimated amount of transactions needed to consolidate all outputs.
+        transactions_needed: usize,
+    },
+    /// Split a large output with
+    /// [`RemainderSplit`](crate::wallet::account::RemainderSplit), so multiple transactions can be sent in parallel.
+    #[serde(rename_all = "camelCase")]
+    Split {
+        /// Amount of outputs to split into.
+        outputs: u16,
+        /// Estimated amount of transactions needed to split the output.
+        transactions_needed: usize,
+    },
+}
+
+impl Account {
+    /// Analyzes the unspent outputs of the account and recommends consolidating or splitting outputs. Only uses the
+    /// locally stored outputs, so the account should be synced before.
+    pub async fn utxo_health(&self) -> crate::wallet::Result<UtxoHealthReport> {
+        log::debug!("[UTXO_HEALTH] utxo_health");
+        let rent_structure = self.network_params().await?.rent_structure;
+        let current_time = self.client().get_time_checked().await?;
+        let consolidation_threshold = match &*self.wallet.secret_manager.read().await {
+            #[cfg(feature = "ledger_nano")]
+            SecretManager::LedgerNano(_) => DEFAULT_LEDGER_OUTPUT_CONSOLIDATION_THRESHOLD,
+            _ => DEFAULT_OUTPUT_CONSOLIDATION_THRESHOLD,
+        };
+
+        utxo_health_report(
+            &*self.details().await,
+            rent_structure,
+            current_time,
+            consolidation_threshold,
+        )
+    }
+}
+
+/// Creates the report of [`Account::utxo_health()`] from the stored outputs of an account.
+fn utxo_health_report(
+    account_details: &AccountDetails,
+    rent_structure: RentStructure,
+    current_time: u32,
+    consolidation_threshold: usize,
+) -> crate::wallet::Result<UtxoHealthReport> {
+    let account_addresses = &account_details.addresses_with_unspent_outputs[..];
+
+    let mut size_distribution = AMOUNT_RANGE_BOUNDS
+        .iter()
+        .scan(0, |min, max| {
+            let range = AmountRange {
+                min: *min,
+                max: *max,
+                output_count: 0,
+            };
+            *min = *max;
+            Some(range)
+        })
+        .collect::<Vec<_>>();
+    let mut native_token_output_counts = HashMap::<TokenId, usize>::new();
+    let mut locked_output_count = 0;
+    let mut free_output_count = 0;
+    let mut dust_output_count = 0;
+    let mut consolidatable_output_count = 0;
+    let mut largest_free_basic_output: Option<&Output> = None;
+
+    for (output_id, output_data) in account_details.unspent_outputs() {
+        let output = &output_data.output;
+        let amount = output.amount();
+
+        if let Some(range) = size_distribution.iter_mut().find(|range| amount < range.max) {
+            range.output_count += 1;
+        }
+        if let Some(native_tokens) = output.native_tokens() {
+            for native_token in native_tokens.iter() {
+                *native_token_output_counts.entry(*native_token.token_id()).or_default() += 1;
+            }
+        }
+
+        let is_basic = output.is_basic();
+        if is_basic && amount < DUST_STORAGE_DEPOSIT_FACTOR * output.rent_cost(&rent_structure) {
+            dust_output_count += 1;
+        }
+
+        if account_details.locked_outputs.contains(output_id) {
+            locked_output_count += 1;
+            continue;
+        }
+        if can_output_be_unlocked_now(account_addresses, &[], output_data, current_time, None)? {
+            free_output_count += 1;
+            if is_basic && largest_free_basic_output.map_or(true, |largest| largest.amount() < amount) {
+                largest_free_basic_output.replace(output);
+            }
+        }
+        if should_consolidate_output(output_data, current_time, account_addresses)? {
+            consolidatable_output_count += 1;
+        }
+    }
+
+    let mut fragmented_native_tokens = native_token_output_counts
+        .into_iter()
+        .filter(|(_, output_count)| *output_count > 1)
+        .map(|(token_id, output_count)| NativeTokenFragmentation { token_id, output_count })
+        .collect::<Vec<_>>();
+    fragmented_native_tokens.sort_by(|a, b| b.output_count.cmp(&a.output_count));
+
+    let mut recommendations = Vec::new();
+    if consolidatable_output_count > 1
+        && (consolidatable_output_count >= consolidation_threshold || dust_output_count >= DUST_CONSOLIDATION_THRESHOLD)
+    {
+        recommendations.push(UtxoRecommendation::Consolidate {
+            output_count: consolidatable_output_count,
+            transactions_needed: (consolidatable_output_count + INPUT_COUNT_MAX as usize - 1)
+                / INPUT_COUNT_MAX as usize,
+        });
+    } else if free_output_count < RECOMMENDED_FREE_OUTPUTS as usize {
+        if let Some(output) = largest_free_basic_output {
+            if output.amount() >= RECOMMENDED_FREE_OUTPUTS as u64 * output.rent_cost(&rent_structure) {
+                recommendations.push(UtxoRecommendation::Split {
+                    outputs: RECOMMENDED_FREE_OUTPUTS,
+                    transactions_needed: 1,
+                });
+            }
+        }
+    }
+
+    Ok(UtxoHealthReport {
+        output_count: account_details.unspent_outputs().len(),
+        locked_output_count,
+        free_output_count,
+        dust_output_count,
+        size_distribution,
+        fragmented_native_tokens,
+        recommendations,
+    })
+}
+
+/// Returns whether the output is a basic output that can be unlocked now without returning a storage deposit, which
+/// is what the output consolidation consolidates.
+pub(crate) fn should_consolidate_output(
+    output_data: &OutputData,
+    current_time: u32,
+    account_addresses: &[AddressWithUnspentOutputs],
+) -> crate::wallet::Result<bool> {
+    Ok(if let Output::Basic(basic_output) = &output_data.output {
+        let unlock_conditions = basic_output.unlock_conditions();
+
+        let is_time_locked = unlock_conditions.is_time_locked(current_time);
+        if is_time_locked {
+            // If the output is timelocked, then it cannot be consolidated.
+            return Ok(false);
+        }
+
+        let has_storage_deposit_return = unlock_conditions.storage_deposit_return().is_some();
+        let has_expiration = unlock_conditions.expiration().is_some();
+        let is_expired = unlock_conditions.is_expired(current_time);
+        if has_storage_deposit_return && (!has_expiration || !is_expired) {
+            // If the output has not expired and must return a storage deposit, then it cannot be consolidated.
+            return Ok(false);
+        }
+
+        can_output_be_unlocked_now(account_addresses, &[], output_data, current_time, None)?
+    } else {
+        false
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::block::{
+        output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder, OutputId, OutputMetadata},
+        payload::transaction::TransactionId,
+        BlockId,
+    };
+
+    const TOKEN_SUPPLY: u64 = 1_813_620_509_061_365;
+    const CURRENT_TIME: u32 = 1_700_000_000;
+
+    fn add_output(account_details: &mut AccountDetails, index: u16, amount: u64) -> OutputId {
+        let account_address = account_details.public_addresses[0].clone();
+        // Output indexes are bounded, so the outputs are spread over multiple transactions
+        let output_id = OutputId::new(
+            TransactionId::new([(index / 100) as u8 + 1; TransactionId::LENGTH]),
+            index % 100,
+        )
+        .unwrap();
+        let output_data = OutputData {
+            output_id,
+            metadata: OutputMetadata::new(
+                BlockId::new([0; BlockId::LENGTH]),
+                output_id,
+                false,
+                None,
+                None,
+                None,
+                0,
+                0,
+                0,
+            ),
+            output: BasicOutputBuilder::new_with_amount(amount)
+                .add_unlock_condition(AddressUnlockCondition::new(*account_address.address.inner()))
+                .finish_output(TOKEN_SUPPLY)
+                .unwrap(),
+            is_spent: false,
+            address: *account_address.address.inner(),
+            network_id: 0,
+            remainder: false,
+            chain: None,
+        };
+        match account_details.addresses_with_unspent_outputs.first_mut() {
+            Some(address) => address.output_ids.push(output_id),
+            None => account_details
+                .addresses_with_unspent_outputs
+                .push(AddressWithUnspentOutputs {
+                    address: account_address.address,
+                    key_index: account_address.key_index,
+                    internal: account_address.internal,
+                    output_ids: vec![output_id],
+                }),
+        }
+        account_details.unspent_outputs.insert(output_id, output_data);
+        output_id
+    }
+
+    // The storage deposit of the outputs created by `add_output()`
+    fn storage_deposit() -> u64 {
+        BasicOutputBuilder::new_with_minimum_storage_deposit(RentStructure::default())
+            .add_unlock_condition(AddressUnlockCondition::new(
+                *AccountDetails::mock().public_addresses[0].address.inner(),
+            ))
+            .finish_output(TOKEN_SUPPLY)
+            .unwrap()
+            .amount()
+    }
+
+    fn report(account_details: &AccountDetails, consolidation_threshold: usize) -> UtxoHealthReport {
+        utxo_health_report(
+            account_details,
+            RentStructure::default(),
+            CURRENT_TIME,
+            consolidation_threshold,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn size_distribution() {
+        let mut account_details = AccountDetails::mock();
+        for (index, amount) in [999_999, 1_000_000, 50_000_000, 99_999_999, 2_000_000_000]
+            .into_iter()
+            .enumerate()
+        {
+            add_output(&mut account_details, index as u16, amount);
+        }
+
+        let report = report(&account_details, DEFAULT_OUTPUT_CONSOLIDATION_THRESHOLD);
+        assert_eq!(report.output_count, 5);
+        assert_eq!(report.free_output_count, 5);
+        assert_eq!(
+            report.size_distribution,
+            vec![
+                AmountRange {
+                    min: 0,
+                    max: 1_000_000,
+                    output_count: 1,
+                },
+                AmountRange {
+                    min: 1_000_000,
+                    max: 10_000_000,
+                    output_count: 1,
+                },
+                AmountRange {
+                    min: 10_000_000,
+                    max: 100_000_000,
+                    output_count: 2,
+                },
+                AmountRange {
+                    min: 100_000_000,
+                    max: 1_000_000_000,
+                    output_count: 0,
+                },
+                AmountRange {
+                    min: 1_000_000_000,
+                    max: u64::MAX,
+                    output_count: 1,
+                },
+            ]
+        );
+        // Enough free outputs and too few to consolidate
+        assert!(report.recommendations.is_empty());
+    }
+
+    #[test]
+    fn dust_and_locked_outputs() {
+        let storage_deposit = storage_deposit();
+        let mut account_details = AccountDetails::mock();
+        add_output(&mut account_details, 0, storage_deposit);
+        add_output(
+            &mut account_details,
+            1,
+            DUST_STORAGE_DEPOSIT_FACTOR * storage_deposit - 1,
+        );
+        add_output(&mut account_details, 2, DUST_STORAGE_DEPOSIT_FACTOR * storage_deposit);
+        let locked_output = add_output(&mut account_details, 3, storage_deposit);
+        account_details.locked_outputs.insert(locked_output);
+
+        let report = report(&account_details, DEFAULT_OUTPUT_CONSOLIDATION_THRESHOLD);
+        assert_eq!(report.output_count, 4);
+        // Locked outputs are still counted as dust, but aren't free
+        assert_eq!(report.dust_output_count, 3);
+        assert_eq!(report.locked_output_count, 1);
+        assert_eq!(report.free_output_count, 3);
+    }
+
+    #[test]
+    fn recommends_consolidation() {
+        let mut account_details = AccountDetails::mock();
+        for index in 0..3 {
+            add_output(&mut account_details, index, 1_000_000);
+        }
+        // Below the threshold
+        assert!(report(&account_details, 4).recommendations.is_empty());
+        assert_eq!(
+            report(&account_details, 3).recommendations,
+            vec![UtxoRecommendation::Consolidate {
+                output_count: 3,
+                transactions_needed: 1,
+            }]
+        );
+
+        // More outputs than fit into a single transaction
+        for index in 3..INPUT_COUNT_MAX + 1 {
+            add_output(&mut account_details, index, 1_000_000);
+        }
+        assert_eq!(
+            report(&account_details, 3).recommendations,
+            vec![UtxoRecommendation::Consolidate {
+                output_count: INPUT_COUNT_MAX as usize + 1,
+                transactions_needed: 2,
+            }]
+        );
+    }
+
+    #[test]
+    fn recommends_consolidation_of_dust() {
+        let mut account_details = AccountDetails::mock();
+        for index in 0..DUST_CONSOLIDATION_THRESHOLD as u16 {
+            add_output(&mut account_details, index, storage_deposit());
+        }
+
+        // Recommended independent of the consolidation threshold
+        let report = report(&account_details, usize::MAX);
+        assert_eq!(report.dust_output_count, DUST_CONSOLIDATION_THRESHOLD);
+        assert_eq!(
+            report.recommendations,
+            vec![UtxoRecommendation::Consolidate {
+                output_count: DUST_CONSOLIDATION_THRESHOLD,
+                transactions_needed: 1,
+            }]
+        );
+    }
+
+    #[test]
+    fn recommends_split() {
+        let mut account_details = AccountDetails::mock();
+        add_output(&mut account_details, 0, 1_000_000_000);
+
+        assert_eq!(
+            report(&account_details, DEFAULT_OUTPUT_CONSOLIDATION_THRESHOLD).recommendations,
+            vec![UtxoRecommendation::Split {
+                outputs: RECOMMENDED_FREE_OUTPUTS,
+                transactions_needed: 1,
+            }]
+        );
+    }
+
+    #[test]
+    fn no_split_without_enough_amount() {
+        let mut account_details = AccountDetails::mock();
+        add_output(
+            &mut account_details,
+            0,
+            RECOMMENDED_FREE_OUTPUTS as u64 * storage_deposit() - 1,
+        );
+
+        let report = report(&account_details, DEFAULT_OUTPUT_CONSOLIDATION_THRESHOLD);
+        assert_eq!(report.free_output_count, 1);
+        assert!(report.recommendations.is_empty());
+    }
+}
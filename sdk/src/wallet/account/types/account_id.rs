@@ -0,0 +1,91 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use core::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+/// A stable identifier of an account, a random UUID (version 4) that doesn't change when the account is renamed.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct AccountId([u8; 16]);
+
+impl AccountId {
+    /// The length of the hyphenated string representation.
+    const STR_LENGTH: usize = 36;
+
+    /// Generates a new random account id.
+    pub(crate) fn generate() -> crate::wallet::Result<Self> {
+        let mut bytes = [0u8; 16];
+        crypto::utils::rand::fill(&mut bytes)?;
+        // Set the version (4) and the variant (RFC 4122)
+        bytes[6] = (bytes[6] & 0x0f) | 0x40;
+        bytes[8] = (bytes[8] & 0x3f) | 0x80;
+        Ok(Self(bytes))
+    }
+
+    /// Returns whether the id wasn't assigned yet, which is the case for accounts created before ids existed.
+    pub(crate) fn is_nil(&self) -> bool {
+        self.0 == [0u8; 16]
+    }
+}
+
+impl fmt::Display for AccountId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, byte) in self.0.iter().enumerate() {
+            if matches!(i, 4 | 6 | 8 | 10) {
+                write!(f, "-")?;
+            }
+            write!(f, "{byte:02x}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for AccountId {
+    type Err = crate::wallet::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || crate::wallet::Error::InvalidAccountId(s.to_string());
+
+        if s.len() != Self::STR_LENGTH || [8, 13, 18, 23].iter().any(|i| s.as_bytes()[*i] != b'-') {
+            return Err(invalid());
+        }
+
+        let mut bytes = [0u8; 16];
+        hex::decode_to_slice(s.replace('-', ""), &mut bytes).map_err(|_| invalid())?;
+        Ok(Self(bytes))
+    }
+}
+
+impl TryFrom<String> for AccountId {
+    type Error = crate::wallet::Error;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::from_str(&value)
+    }
+}
+
+impl From<AccountId> for String {
+    fn from(value: AccountId) -> Self {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn account_id_string() {
+        let account_id = AccountId::generate().unwrap();
+        let account_id_str = account_id.to_string();
+        assert_eq!(account_id_str.len(), AccountId::STR_LENGTH);
+        assert_eq!(&account_id_str[14..15], "4");
+        assert_eq!(AccountId::from_str(&account_id_str).unwrap(), account_id);
+
+        assert!(AccountId::from_str("0").is_err());
+        assert!(AccountId::from_str("01234567x89ab-cdef-0123-456789abcdef").is_err());
+        assert!(AccountId::default().is_nil());
+    }
+}
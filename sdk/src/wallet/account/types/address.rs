@@ -1,7 +1,7 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::hash::Hash;
+use std::{collections::BTreeMap, hash::Hash};
 
 use getset::{Getters, Setters};
 use serde::{Deserialize, Serialize};
@@ -24,6 +24,12 @@ pub struct AccountAddress {
     // do we want this field? Could be useful if we don't store spent output ids and because of that wouldn't know if
     // an address was used or not just by looking at it
     pub(crate) used: bool,
+    /// A label to identify the address, like the id of the user it was given to.
+    #[serde(default)]
+    pub(crate) label: Option<String>,
+    /// Arbitrary metadata of the address.
+    #[serde(default)]
+    pub(crate) metadata: BTreeMap<String, String>,
 }
 
 impl AccountAddress {
@@ -7,7 +7,12 @@ use getset::{CopyGetters, Getters};
 use primitive_types::U256;
 use serde::{Deserialize, Serialize};
 
-use crate::types::block::output::{feature::MetadataFeature, AliasId, FoundryId, NftId, OutputId, TokenId};
+#[cfg(feature = "wallet-participation")]
+use crate::types::api::plugins::participation::types::ParticipationEventId;
+use crate::{
+    types::block::output::{feature::MetadataFeature, AliasId, FoundryId, NftId, OutputId, TokenId},
+    wallet::account::TokenRegistry,
+};
 
 /// The balance of an account, returned from [`crate::wallet::account::Account::sync()`] and
 /// [`crate::wallet::account::Account::balance()`].
@@ -19,6 +24,11 @@ pub struct AccountBalance {
     pub(crate) base_coin: BaseCoinBalance,
     /// Current required storage deposit amount
     pub(crate) required_storage_deposit: RequiredStorageDeposit,
+    /// Amount of the base coin in outputs with additional unlock conditions that can be claimed now, without the
+    /// amount that needs to be returned because of a
+    /// [`StorageDepositReturnUnlockCondition`](crate::types::block::output::unlock_condition::StorageDepositReturnUnlockCondition)
+    #[serde(with = "crate::utils::serde::string")]
+    pub(crate) claimable: u64,
     /// Native tokens
     pub(crate) native_tokens: Vec<NativeTokensBalance>,
     /// Nfts
@@ -32,12 +42,17 @@ pub struct AccountBalance {
     /// [`ExpirationUnlockCondition`](crate::types::block::output::unlock_condition::ExpirationUnlockCondition) this
     /// can change at any time
     pub(crate) potentially_locked_outputs: HashMap<OutputId, bool>,
+    /// Accumulated staking rewards per event, from the participation plugin of the node during the last sync
+    #[cfg(feature = "wallet-participation")]
+    #[serde(default)]
+    pub(crate) participation_rewards: HashMap<ParticipationEventId, ParticipationRewards>,
 }
 
 impl std::ops::AddAssign for AccountBalance {
     fn add_assign(&mut self, rhs: Self) {
         self.base_coin += rhs.base_coin;
         self.required_storage_deposit += rhs.required_storage_deposit;
+        self.claimable += rhs.claimable;
 
         for rhs_native_token_balance in rhs.native_tokens.into_iter() {
             if let Some(total_native_token_balance) = self
@@ -54,6 +69,15 @@ impl std::ops::AddAssign for AccountBalance {
         self.nfts.extend(rhs.nfts.into_iter());
         self.aliases.extend(rhs.aliases.into_iter());
         self.foundries.extend(rhs.foundries.into_iter());
+        #[cfg(feature = "wallet-participation")]
+        for (event_id, rhs_rewards) in rhs.participation_rewards {
+            match self.participation_rewards.get_mut(&event_id) {
+                Some(rewards) => *rewards += rhs_rewards,
+                None => {
+                    self.participation_rewards.insert(event_id, rhs_rewards);
+                }
+            }
+        }
     }
 }
 
@@ -66,6 +90,8 @@ pub struct AccountBalanceDto {
     pub base_coin: BaseCoinBalance,
     /// Current required storage deposit amount
     pub required_storage_deposit: RequiredStorageDeposit,
+    /// Amount of the base coin that can be claimed now
+    pub claimable: String,
     /// Native tokens
     pub native_tokens: Vec<NativeTokensBalanceDto>,
     /// Nfts
@@ -79,6 +105,10 @@ pub struct AccountBalanceDto {
     /// [`ExpirationUnlockCondition`](crate::types::block::output::unlock_condition::ExpirationUnlockCondition) this
     /// can change at any time
     pub potentially_locked_outputs: HashMap<OutputId, bool>,
+    /// Accumulated staking rewards per event
+    #[cfg(feature = "wallet-participation")]
+    #[serde(default)]
+    pub participation_rewards: HashMap<ParticipationEventId, ParticipationRewards>,
 }
 
 impl From<&AccountBalance> for AccountBalanceDto {
@@ -86,6 +116,7 @@ impl From<&AccountBalance> for AccountBalanceDto {
         Self {
             base_coin: value.base_coin.clone(),
             required_storage_deposit: value.required_storage_deposit.clone(),
+            claimable: value.claimable.to_string(),
             native_tokens: value
                 .native_tokens
                 .iter()
@@ -95,7 +126,33 @@ impl From<&AccountBalance> for AccountBalanceDto {
             aliases: value.aliases.clone(),
             foundries: value.foundries.clone(),
             potentially_locked_outputs: value.potentially_locked_outputs.clone(),
+            #[cfg(feature = "wallet-participation")]
+            participation_rewards: value.participation_rewards.clone(),
+        }
+    }
+}
+
+impl AccountBalanceDto {
+    /// Sets the formatted amounts of the native tokens for which the decimals are known.
+    pub fn with_formatted_native_token_amounts(mut self, decimals: &HashMap<TokenId, u8>) -> Self {
+        for native_token in &mut self.native_tokens {
+            if let Some(decimals) = decimals.get(&native_token.token_id) {
+                native_token.formatted_total = Some(format_native_token_amount(native_token.total, *decimals));
+                native_token.formatted_available = Some(format_native_token_amount(native_token.available, *decimals));
+            }
+        }
+        self
+    }
+
+    /// Sets the symbols, decimals and formatted amounts of the native tokens that have IRC30 metadata in the registry.
+    pub fn with_native_token_metadata(mut self, token_registry: &TokenRegistry) -> Self {
+        for native_token in &mut self.native_tokens {
+            if let Some(metadata) = token_registry.get(&native_token.token_id) {
+                native_token.symbol = Some(metadata.symbol.clone());
+                native_token.decimals = Some(metadata.decimals);
+            }
         }
+        self.with_formatted_native_token_amounts(&token_registry.all_decimals())
     }
 }
 
@@ -111,7 +168,7 @@ pub struct BaseCoinBalance {
     #[serde(with = "crate::utils::serde::string")]
     pub(crate) available: u64,
     /// Voting power
-    #[cfg(feature = "participation")]
+    #[cfg(feature = "wallet-participation")]
     #[serde(with = "crate::utils::serde::string")]
     pub(crate) voting_power: u64,
 }
@@ -120,13 +177,42 @@ impl std::ops::AddAssign for BaseCoinBalance {
     fn add_assign(&mut self, rhs: Self) {
         self.total += rhs.total;
         self.available += rhs.available;
-        #[cfg(feature = "participation")]
+        #[cfg(feature = "wallet-participation")]
         {
             self.voting_power += rhs.voting_power;
         }
     }
 }
 
+/// Staking rewards of an event for [`AccountBalance`]
+#[cfg(feature = "wallet-participation")]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, Getters, CopyGetters)]
+#[serde(rename_all = "camelCase")]
+pub struct ParticipationRewards {
+    /// Accumulated rewards of all addresses of the account
+    #[serde(with = "crate::utils::serde::string")]
+    #[getset(get_copy = "pub")]
+    pub(crate) amount: u64,
+    /// Symbol of the rewards
+    #[getset(get = "pub")]
+    pub(crate) symbol: String,
+    /// Whether the minimum rewards of the event are reached by at least one address
+    #[getset(get_copy = "pub")]
+    pub(crate) minimum_reached: bool,
+    /// Milestone index the rewards were calculated for
+    #[getset(get_copy = "pub")]
+    pub(crate) milestone_index: u32,
+}
+
+#[cfg(feature = "wallet-participation")]
+impl std::ops::AddAssign for ParticipationRewards {
+    fn add_assign(&mut self, rhs: Self) {
+        self.amount += rhs.amount;
+        self.minimum_reached |= rhs.minimum_reached;
+        self.milestone_index = self.milestone_index.max(rhs.milestone_index);
+    }
+}
+
 #[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize, CopyGetters)]
 #[getset(get_copy = "pub")]
 pub struct RequiredStorageDeposit {
@@ -200,6 +286,18 @@ pub struct NativeTokensBalanceDto {
     pub total: U256,
     /// Balance that can currently be spent
     pub available: U256,
+    /// Total amount as decimal string, if the decimals of the token are known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub formatted_total: Option<String>,
+    /// Available amount as decimal string, if the decimals of the token are known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub formatted_available: Option<String>,
+    /// Symbol from the IRC30 metadata of the token, if known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub symbol: Option<String>,
+    /// Decimals from the IRC30 metadata of the token, if known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub decimals: Option<u8>,
 }
 
 impl From<&NativeTokensBalance> for NativeTokensBalanceDto {
@@ -209,10 +307,51 @@ impl From<&NativeTokensBalance> for NativeTokensBalanceDto {
             metadata: value.metadata.as_ref().map(|m| prefix_hex::encode(m.data())),
             total: value.total,
             available: value.available,
+            formatted_total: None,
+            formatted_available: None,
+            symbol: None,
+            decimals: None,
         }
     }
 }
 
+/// Formats a raw native token amount as decimal string with the given decimals, e.g. `1234500` with 4 decimals as
+/// `123.45`.
+pub fn format_native_token_amount(amount: U256, decimals: u8) -> String {
+    let digits = amount.to_string();
+    if decimals == 0 {
+        return digits;
+    }
+    let decimals = decimals as usize;
+    let digits = format!("{digits:0>width$}", width = decimals + 1);
+    let (integer, fraction) = digits.split_at(digits.len() - decimals);
+    let fraction = fraction.trim_end_matches('0');
+
+    if fraction.is_empty() {
+        integer.to_string()
+    } else {
+        format!("{integer}.{fraction}")
+    }
+}
+
+/// Parses a decimal string like `123.45` as raw native token amount with the given decimals, returns `None` if the
+/// amount is invalid or has more fractional digits than decimals.
+pub fn parse_native_token_amount(amount: &str, decimals: u8) -> Option<U256> {
+    let (integer, fraction) = amount.split_once('.').unwrap_or((amount, ""));
+    let is_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+
+    if integer.is_empty()
+        || amount.ends_with('.')
+        || !is_digits(integer)
+        || !is_digits(fraction)
+        || fraction.len() > decimals as usize
+    {
+        return None;
+    }
+
+    U256::from_dec_str(&format!("{integer}{fraction:0<width$}", width = decimals as usize)).ok()
+}
+
 #[cfg(feature = "rand")]
 impl AccountBalance {
     pub fn rand_mock() -> Self {
@@ -267,7 +406,7 @@ impl AccountBalance {
             base_coin: BaseCoinBalance {
                 total,
                 available: total / 2,
-                #[cfg(feature = "participation")]
+                #[cfg(feature = "wallet-participation")]
                 voting_power: total / 4,
             },
             required_storage_deposit: RequiredStorageDeposit {
@@ -276,6 +415,7 @@ impl AccountBalance {
                 foundry: total / 4,
                 nft: total / 2,
             },
+            claimable: total / 32,
             native_tokens,
             aliases,
             foundries,
@@ -284,3 +424,48 @@ impl AccountBalance {
         }
     }
 }
+
+#[cfg(all(test, feature = "wallet-participation"))]
+mod tests {
+    use super::*;
+
+    fn rewards(amount: u64, minimum_reached: bool, milestone_index: u32) -> ParticipationRewards {
+        ParticipationRewards {
+            amount,
+            symbol: "SMR".to_string(),
+            minimum_reached,
+            milestone_index,
+        }
+    }
+
+    #[test]
+    fn add_participation_rewards() {
+        let mut total = rewards(10, false, 7);
+        total += rewards(5, true, 3);
+
+        assert_eq!(total, rewards(15, true, 7));
+    }
+
+    #[test]
+    fn add_account_balance_participation_rewards() {
+        let event_id = ParticipationEventId::new([1; 32]);
+        let other_event_id = ParticipationEventId::new([2; 32]);
+        let mut total = AccountBalance {
+            participation_rewards: HashMap::from([(event_id, rewards(10, false, 5))]),
+            ..Default::default()
+        };
+
+        total += AccountBalance {
+            participation_rewards: HashMap::from([
+                (event_id, rewards(20, false, 6)),
+                (other_event_id, rewards(1, true, 4)),
+            ]),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            total.participation_rewards,
+            HashMap::from([(event_id, rewards(30, false, 6)), (other_event_id, rewards(1, true, 4))])
+        );
+    }
+}
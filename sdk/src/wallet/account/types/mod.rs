@@ -1,22 +1,32 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+/// The stable account identifier
+pub(crate) mod account_id;
 /// Address types used in the account
 pub(crate) mod address;
 pub(crate) mod balance;
-#[cfg(feature = "participation")]
+pub(crate) mod normalized;
+#[cfg(feature = "wallet-participation")]
 pub mod participation;
 
-use std::str::FromStr;
+use std::{collections::HashMap, str::FromStr};
 
 use crypto::keys::slip10::Chain;
+use primitive_types::U256;
 use serde::{Deserialize, Deserializer, Serialize};
 
+#[cfg(feature = "wallet-participation")]
+pub use self::balance::ParticipationRewards;
 pub use self::{
+    account_id::AccountId,
     address::{AccountAddress, AddressWithUnspentOutputs},
     balance::{
-        AccountBalance, AccountBalanceDto, BaseCoinBalance, NativeTokensBalance, NativeTokensBalanceDto,
-        RequiredStorageDeposit,
+        format_native_token_amount, parse_native_token_amount, AccountBalance, AccountBalanceDto, BaseCoinBalance,
+        NativeTokensBalance, NativeTokensBalanceDto, RequiredStorageDeposit,
+    },
+    normalized::{
+        CompactOutputData, NormalizedInput, NormalizedOutput, NormalizedTransaction, UnlockConditionsSummary,
     },
 };
 use crate::{
@@ -27,9 +37,13 @@ use crate::{
             address::{dto::AddressDto, Address},
             output::{
                 dto::{OutputDto, OutputMetadataDto},
-                AliasTransition, Output, OutputId, OutputMetadata,
+                AliasTransition, Output, OutputId, OutputMetadata, TokenId,
+            },
+            payload::transaction::{
+                dto::{TransactionEssenceDto, TransactionPayloadDto},
+                TransactionId, TransactionPayload,
             },
-            payload::transaction::{dto::TransactionPayloadDto, TransactionId, TransactionPayload},
+            semantic::ConflictReason,
             BlockId,
         },
     },
@@ -150,11 +164,67 @@ pub struct Transaction {
     // set if the transaction was created by the wallet or if it was sent by someone else and is incoming
     pub incoming: bool,
     pub note: Option<String>,
+    /// Amount of milestones that need to be confirmed after the one that included the transaction, before it's
+    /// considered confirmed. The wallet default is used if not set.
+    #[serde(default)]
+    pub confirmation_depth: Option<u32>,
     /// Outputs that are used as input in the transaction. May not be all, because some may have already been deleted
     /// from the node.
     // serde(default) is needed so it doesn't break with old dbs
     #[serde(default)]
     pub inputs: Vec<OutputWithMetadataResponse>,
+    /// The reason why the transaction conflicts with the ledger state, if it's conflicting and the reason is known.
+    #[serde(default)]
+    pub conflict_reason: Option<ConflictReason>,
+    /// Position of the transaction among the transactions sent by the account, starting at 0. Only set for
+    /// transactions sent while the transaction sequencing of the account is enabled.
+    #[serde(default)]
+    pub sequence_number: Option<u64>,
+}
+
+#[cfg(test)]
+impl Transaction {
+    /// Returns a mock of a pending transaction sent by the account, which spends a single input and creates the
+    /// given outputs. The signature is a dummy, so it's only useful where the transaction isn't validated.
+    pub(crate) fn mock(outputs: Vec<Output>) -> Self {
+        use crate::types::block::{
+            input::{Input, UtxoInput},
+            payload::transaction::{RegularTransactionEssence, TransactionEssence},
+            protocol::ProtocolParameters,
+            signature::{Ed25519Signature, Signature},
+            unlock::{SignatureUnlock, Unlock, Unlocks},
+        };
+
+        let protocol_parameters = ProtocolParameters::default();
+        let essence = RegularTransactionEssence::builder(protocol_parameters.network_id(), [0; 32].into())
+            .with_inputs(vec![Input::Utxo(
+                UtxoInput::new(TransactionId::new([0; TransactionId::LENGTH]), 0).unwrap(),
+            )])
+            .with_outputs(outputs)
+            .finish(&protocol_parameters)
+            .unwrap();
+        let signature = Signature::Ed25519(Ed25519Signature::new(
+            [0; Ed25519Signature::PUBLIC_KEY_LENGTH],
+            [0; Ed25519Signature::SIGNATURE_LENGTH],
+        ));
+        let unlocks = Unlocks::new(vec![Unlock::Signature(SignatureUnlock::from(signature))]).unwrap();
+        let payload = TransactionPayload::new(TransactionEssence::Regular(essence), unlocks).unwrap();
+
+        Self {
+            transaction_id: payload.id(),
+            payload,
+            block_id: None,
+            inclusion_state: InclusionState::Pending,
+            timestamp: 0,
+            network_id: protocol_parameters.network_id(),
+            incoming: false,
+            note: None,
+            confirmation_depth: None,
+            inputs: Vec::new(),
+            conflict_reason: None,
+            sequence_number: None,
+        }
+    }
 }
 
 /// Dto for a transaction with metadata
@@ -175,7 +245,22 @@ pub struct TransactionDto {
     /// If the transaction was created by the wallet or if it was sent by someone else and is incoming
     pub incoming: bool,
     pub note: Option<String>,
+    /// Amount of milestones after the including one, before the transaction is considered confirmed
+    #[serde(default)]
+    pub confirmation_depth: Option<u32>,
     pub inputs: Vec<OutputWithMetadataResponse>,
+    /// The reason why the transaction conflicts with the ledger state, if it's conflicting and the reason is known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conflict_reason: Option<ConflictReason>,
+    /// Position of the transaction among the transactions sent by the account
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sequence_number: Option<u64>,
+    /// Amounts of the native tokens in the outputs as decimal strings, for the tokens of which the decimals are known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub formatted_native_token_amounts: Option<HashMap<TokenId, String>>,
+    /// The inputs and outputs of the transaction with resolved addresses
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub normalized: Option<NormalizedTransaction>,
 }
 
 impl From<&Transaction> for TransactionDto {
@@ -189,8 +274,48 @@ impl From<&Transaction> for TransactionDto {
             network_id: value.network_id.to_string(),
             incoming: value.incoming,
             note: value.note.clone(),
+            confirmation_depth: value.confirmation_depth,
             inputs: value.inputs.clone(),
+            conflict_reason: value.conflict_reason,
+            sequence_number: value.sequence_number,
+            formatted_native_token_amounts: None,
+            normalized: None,
+        }
+    }
+}
+
+impl TransactionDto {
+    /// Sets the formatted amounts of the native tokens in the outputs for which the decimals are known.
+    pub fn with_formatted_native_token_amounts(mut self, decimals: &HashMap<TokenId, u8>) -> Self {
+        let TransactionEssenceDto::Regular(essence) = &self.payload.essence;
+        let mut amounts = HashMap::<TokenId, U256>::new();
+
+        for output in &essence.outputs {
+            let native_tokens = match output {
+                OutputDto::Basic(output) => &output.native_tokens,
+                OutputDto::Alias(output) => &output.native_tokens,
+                OutputDto::Foundry(output) => &output.native_tokens,
+                OutputDto::Nft(output) => &output.native_tokens,
+                OutputDto::Treasury(_) => continue,
+            };
+            for native_token in native_tokens {
+                let amount = amounts.entry(native_token.token_id).or_default();
+                *amount = amount.saturating_add(native_token.amount);
+            }
+        }
+
+        let formatted_amounts = amounts
+            .into_iter()
+            .filter_map(|(token_id, amount)| {
+                decimals
+                    .get(&token_id)
+                    .map(|decimals| (token_id, format_native_token_amount(amount, *decimals)))
+            })
+            .collect::<HashMap<_, _>>();
+        if !formatted_amounts.is_empty() {
+            self.formatted_native_token_amounts.replace(formatted_amounts);
         }
+        self
     }
 }
 
@@ -198,6 +323,9 @@ impl From<&Transaction> for TransactionDto {
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 pub enum InclusionState {
     Pending,
+    /// Referenced by a milestone, but not yet followed by enough confirmed milestones to reach the confirmation depth
+    /// of the transaction.
+    Included,
     Confirmed,
     Conflicting,
     UnknownPruned,
@@ -242,6 +370,18 @@ pub enum AccountIdentifier {
     Alias(String),
     /// An index identifier.
     Index(u32),
+    /// The stable account id as identifier.
+    Id(AccountId),
+}
+
+impl core::fmt::Display for AccountIdentifier {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Alias(alias) => alias.fmt(f),
+            Self::Index(index) => index.fmt(f),
+            Self::Id(id) => id.fmt(f),
+        }
+    }
 }
 
 // Custom deserialize because the index could also be encoded as String
@@ -272,7 +412,13 @@ impl<'de> Deserialize<'de> for AccountIdentifier {
 // When the identifier is a string.
 impl From<&str> for AccountIdentifier {
     fn from(value: &str) -> Self {
-        u32::from_str(value).map_or_else(|_| Self::Alias(value.to_string()), Self::Index)
+        if let Ok(index) = u32::from_str(value) {
+            Self::Index(index)
+        } else if let Ok(account_id) = AccountId::from_str(value) {
+            Self::Id(account_id)
+        } else {
+            Self::Alias(value.to_string())
+        }
     }
 }
 
@@ -294,3 +440,19 @@ impl From<u32> for AccountIdentifier {
         Self::Index(value)
     }
 }
+
+impl From<AccountId> for AccountIdentifier {
+    fn from(value: AccountId) -> Self {
+        Self::Id(value)
+    }
+}
+
+/// A previous alias of an account, recorded when it got renamed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AliasChange {
+    /// The alias before the rename.
+    pub alias: String,
+    /// Unix timestamp in seconds of the rename.
+    pub renamed_at: u32,
+}
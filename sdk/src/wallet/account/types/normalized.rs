@@ -0,0 +1,232 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+use super::{OutputData, OutputKind, Transaction};
+use crate::types::block::{
+    address::{Address, Bech32Address, Hrp},
+    input::Input,
+    output::{AliasId, FoundryId, NativeToken, NftId, Output, OutputId},
+    payload::transaction::TransactionEssence,
+};
+
+/// The inputs and outputs of a [`Transaction`] with resolved addresses, returned from [`Transaction::normalized()`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NormalizedTransaction {
+    pub inputs: Vec<NormalizedInput>,
+    pub outputs: Vec<NormalizedOutput>,
+}
+
+/// An input of a [`NormalizedTransaction`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NormalizedInput {
+    /// The id of the consumed output.
+    pub output_id: OutputId,
+    /// The kind of the consumed output, if it's known.
+    pub kind: Option<OutputKind>,
+    /// The address that owns the consumed output, if it's known.
+    pub address: Option<Bech32Address>,
+    /// The amount of the consumed output, if it's known.
+    #[serde(default, with = "crate::utils::serde::option_string")]
+    pub amount: Option<u64>,
+    /// The native tokens of the consumed output, if it's known.
+    #[serde(default)]
+    pub native_tokens: Vec<NativeToken>,
+}
+
+/// An output of a [`NormalizedTransaction`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NormalizedOutput {
+    /// The id of the created output.
+    pub output_id: OutputId,
+    /// The kind of the created output.
+    pub kind: OutputKind,
+    /// The address that owns the created output.
+    pub address: Option<Bech32Address>,
+    /// The amount of the created output.
+    #[serde(with = "crate::utils::serde::string")]
+    pub amount: u64,
+    /// The native tokens of the created output.
+    #[serde(default)]
+    pub native_tokens: Vec<NativeToken>,
+    /// The unlock conditions of the created output, besides the owning address.
+    pub unlock_conditions: UnlockConditionsSummary,
+}
+
+/// The unlock conditions of a [`NormalizedOutput`] that restrict how the owning address can unlock it.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnlockConditionsSummary {
+    /// The address to which the storage deposit needs to be returned.
+    pub storage_deposit_return_address: Option<Bech32Address>,
+    /// The amount of the storage deposit that needs to be returned.
+    #[serde(default, with = "crate::utils::serde::option_string")]
+    pub storage_deposit_return_amount: Option<u64>,
+    /// Unix timestamp in seconds before which the output can't be unlocked.
+    pub timelock: Option<u32>,
+    /// The address that can unlock the output after the expiration.
+    pub expiration_return_address: Option<Bech32Address>,
+    /// Unix timestamp in seconds after which only the expiration return address can unlock the output.
+    pub expiration: Option<u32>,
+}
+
+/// A summary of an [`OutputData`] without the full output, returned from [`OutputData::compact()`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CompactOutputData {
+    /// The id of the output.
+    pub output_id: OutputId,
+    /// The kind of the output.
+    pub kind: OutputKind,
+    /// The address that owns the output.
+    pub address: Option<Bech32Address>,
+    /// The amount of the output.
+    #[serde(with = "crate::utils::serde::string")]
+    pub amount: u64,
+    /// The native tokens of the output.
+    #[serde(default)]
+    pub native_tokens: Vec<NativeToken>,
+    /// The id of the alias, for alias outputs.
+    pub alias_id: Option<AliasId>,
+    /// The id of the foundry, for foundry outputs.
+    pub foundry_id: Option<FoundryId>,
+    /// The id of the NFT, for NFT outputs.
+    pub nft_id: Option<NftId>,
+    /// The unlock conditions of the output, besides the owning address.
+    pub unlock_conditions: UnlockConditionsSummary,
+    /// If the output is spent.
+    pub is_spent: bool,
+    /// If the output is a remainder.
+    pub remainder: bool,
+}
+
+impl OutputData {
+    /// Returns a summary of the output, with addresses encoded with the given bech32 HRP.
+    pub fn compact(&self, bech32_hrp: Hrp) -> CompactOutputData {
+        let output = &self.output;
+        CompactOutputData {
+            output_id: self.output_id,
+            kind: output_kind(output),
+            address: owner_address(output).map(|address| Bech32Address::new(bech32_hrp, address)),
+            amount: output.amount(),
+            native_tokens: native_tokens(output),
+            alias_id: match output {
+                Output::Alias(alias) => Some(alias.alias_id_non_null(&self.output_id)),
+                _ => None,
+            },
+            foundry_id: match output {
+                Output::Foundry(foundry) => Some(foundry.id()),
+                _ => None,
+            },
+            nft_id: match output {
+                Output::Nft(nft) => Some(nft.nft_id_non_null(&self.output_id)),
+                _ => None,
+            },
+            unlock_conditions: unlock_conditions_summary(output, bech32_hrp),
+            is_spent: self.is_spent,
+            remainder: self.remainder,
+        }
+    }
+}
+
+impl Transaction {
+    /// Returns the inputs and outputs of the transaction with addresses encoded with the given bech32 HRP. Inputs
+    /// for which the consumed output isn't stored only have their output id set.
+    pub fn normalized(&self, bech32_hrp: Hrp) -> NormalizedTransaction {
+        let TransactionEssence::Regular(essence) = self.payload.essence();
+
+        let inputs = essence
+            .inputs()
+            .iter()
+            .filter_map(|input| match input {
+                Input::Utxo(input) => Some(*input.output_id()),
+                Input::Treasury(_) => None,
+            })
+            .map(|output_id| {
+                let output = self
+                    .inputs
+                    .iter()
+                    .find(|input| input.metadata.output_id().map_or(false, |id| id == output_id))
+                    .and_then(|input| Output::try_from_dto_unverified(&input.output).ok());
+                NormalizedInput {
+                    output_id,
+                    kind: output.as_ref().map(output_kind),
+                    address: output
+                        .as_ref()
+                        .and_then(owner_address)
+                        .map(|address| Bech32Address::new(bech32_hrp, address)),
+                    amount: output.as_ref().map(Output::amount),
+                    native_tokens: output.as_ref().map(native_tokens).unwrap_or_default(),
+                }
+            })
+            .collect();
+
+        let outputs = essence
+            .outputs()
+            .iter()
+            .enumerate()
+            .filter_map(|(index, output)| {
+                let output_id = OutputId::new(self.transaction_id, index as u16).ok()?;
+                Some(NormalizedOutput {
+                    output_id,
+                    kind: output_kind(output),
+                    address: owner_address(output).map(|address| Bech32Address::new(bech32_hrp, address)),
+                    amount: output.amount(),
+                    native_tokens: native_tokens(output),
+                    unlock_conditions: unlock_conditions_summary(output, bech32_hrp),
+                })
+            })
+            .collect();
+
+        NormalizedTransaction { inputs, outputs }
+    }
+}
+
+fn output_kind(output: &Output) -> OutputKind {
+    match output {
+        Output::Alias(_) => OutputKind::Alias,
+        Output::Basic(_) => OutputKind::Basic,
+        Output::Foundry(_) => OutputKind::Foundry,
+        Output::Nft(_) => OutputKind::Nft,
+        Output::Treasury(_) => OutputKind::Treasury,
+    }
+}
+
+// The address that owns the output, the state controller for alias outputs and the controlling alias for foundries
+pub(crate) fn owner_address(output: &Output) -> Option<Address> {
+    let unlock_conditions = output.unlock_conditions()?;
+    unlock_conditions
+        .address()
+        .map(|uc| *uc.address())
+        .or_else(|| unlock_conditions.state_controller_address().map(|uc| *uc.address()))
+        .or_else(|| unlock_conditions.immutable_alias_address().map(|uc| *uc.address()))
+}
+
+fn native_tokens(output: &Output) -> Vec<NativeToken> {
+    output
+        .native_tokens()
+        .map(|native_tokens| native_tokens.iter().copied().collect())
+        .unwrap_or_default()
+}
+
+fn unlock_conditions_summary(output: &Output, bech32_hrp: Hrp) -> UnlockConditionsSummary {
+    let Some(unlock_conditions) = output.unlock_conditions() else {
+        return UnlockConditionsSummary::default();
+    };
+    let storage_deposit_return = unlock_conditions.storage_deposit_return();
+    let expiration = unlock_conditions.expiration();
+
+    UnlockConditionsSummary {
+        storage_deposit_return_address: storage_deposit_return
+            .map(|sdr| Bech32Address::new(bech32_hrp, *sdr.return_address())),
+        storage_deposit_return_amount: storage_deposit_return.map(|sdr| sdr.amount()),
+        timelock: unlock_conditions.timelock().map(|timelock| timelock.timestamp()),
+        expiration_return_address: expiration
+            .map(|expiration| Bech32Address::new(bech32_hrp, *expiration.return_address())),
+        expiration: expiration.map(|expiration| expiration.timestamp()),
+    }
+}
@@ -1,13 +1,24 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
+#[cfg(any(feature = "mqtt", test))]
+use crypto::keys::slip10::Chain;
+
+#[cfg(any(feature = "mqtt", test))]
+use crate::{
+    client::constants::HD_WALLET_TYPE, types::block::output::OutputWithMetadata, wallet::account::AccountDetails,
+};
 use crate::{
-    types::block::output::{dto::OutputMetadataDto, OutputId},
+    types::block::{
+        address::Bech32Address,
+        output::{dto::OutputMetadataDto, OutputId},
+    },
+    utils::unix_timestamp_now,
     wallet::account::{
-        operations::syncing::options::SyncOptions,
-        types::{address::AddressWithUnspentOutputs, InclusionState, OutputData, Transaction},
+        operations::{address_rotation::mark_public_address_used, syncing::options::SyncOptions},
+        types::{address::AddressWithUnspentOutputs, AliasChange, InclusionState, OutputData, Transaction},
         Account, AccountAddress,
     },
 };
@@ -16,20 +27,73 @@ use crate::{
     types::{api::core::response::OutputWithMetadataResponse, block::payload::transaction::dto::TransactionPayloadDto},
     wallet::{
         account::types::OutputDataDto,
-        events::types::{NewOutputEvent, SpentOutputEvent, TransactionInclusionEvent, WalletEvent},
+        events::types::{
+            EventOrigin, NewOutputEvent, SpentOutputEvent, ThresholdAlertEvent, TransactionInclusionEvent, WalletEvent,
+        },
     },
 };
 
 impl Account {
-    /// Set the alias for the account
+    /// Set the alias for the account, the previous alias is added to the alias history. Use
+    /// [`Wallet::rename_account()`](crate::wallet::Wallet::rename_account) to check that no other account uses the
+    /// alias.
     pub async fn set_alias(&self, alias: &str) -> crate::wallet::Result<()> {
         let mut account_details = self.details_mut().await;
-        account_details.alias = alias.to_string();
+        if account_details.alias == alias {
+            return Ok(());
+        }
+        let previous_alias = core::mem::replace(&mut account_details.alias, alias.to_string());
+        account_details.alias_history.push(AliasChange {
+            alias: previous_alias,
+            renamed_at: unix_timestamp_now().as_secs() as u32,
+        });
         #[cfg(feature = "storage")]
         self.save(Some(&account_details)).await?;
         Ok(())
     }
 
+    /// Set or remove the label of an address of the account
+    pub async fn set_address_label(
+        &self,
+        address: &Bech32Address,
+        label: Option<String>,
+    ) -> crate::wallet::Result<AccountAddress> {
+        self.update_address(address, |account_address| account_address.label = label)
+            .await
+    }
+
+    /// Replace the metadata of an address of the account
+    pub async fn set_address_metadata(
+        &self,
+        address: &Bech32Address,
+        metadata: BTreeMap<String, String>,
+    ) -> crate::wallet::Result<AccountAddress> {
+        self.update_address(address, |account_address| account_address.metadata = metadata)
+            .await
+    }
+
+    async fn update_address(
+        &self,
+        address: &Bech32Address,
+        f: impl FnOnce(&mut AccountAddress) + Send,
+    ) -> crate::wallet::Result<AccountAddress> {
+        let mut account_details = self.details_mut().await;
+        let account_address = {
+            let account_details = &mut *account_details;
+            let account_address = account_details
+                .public_addresses
+                .iter_mut()
+                .chain(account_details.internal_addresses.iter_mut())
+                .find(|account_address| account_address.address == *address)
+                .ok_or(crate::wallet::Error::AddressNotFoundInAccount(*address))?;
+            f(account_address);
+            account_address.clone()
+        };
+        #[cfg(feature = "storage")]
+        self.save(Some(&account_details)).await?;
+        Ok(account_address)
+    }
+
     /// Update account with newly synced data and emit events for outputs
     pub(crate) async fn update_account(
         &self,
@@ -74,7 +138,7 @@ impl Account {
                     .map_err(|_| {
                         crate::wallet::Error::AddressNotFoundInAccount(address_with_unspent_outputs.address)
                     })?;
-                account_details.public_addresses[position].used = true;
+                mark_public_address_used(&mut account_details, position, unix_timestamp_now().as_secs() as u32);
             }
         }
 
@@ -120,11 +184,16 @@ impl Account {
                         output_data.is_spent = true;
                         #[cfg(feature = "events")]
                         {
+                            let mut origin = EventOrigin::new("sync");
+                            if let Some(transaction_id) = output_data.metadata.transaction_id_spent() {
+                                origin = origin.with_transaction_id(*transaction_id);
+                            }
                             self.emit(
                                 account_index,
                                 WalletEvent::SpentOutput(Box::new(SpentOutputEvent {
                                     output: OutputDataDto::from(&*output_data),
                                 })),
+                                origin,
                             )
                             .await;
                         }
@@ -159,8 +228,19 @@ impl Account {
                                     .collect()
                             }),
                         })),
+                        EventOrigin::new("sync").with_transaction_id(*output_data.output_id.transaction_id()),
                     )
                     .await;
+                    if let Some(alert) = account_details.alert_thresholds.and_then(|alert_thresholds| {
+                        alert_thresholds.received_amount_alert(&account_details, &output_data)
+                    }) {
+                        self.emit(
+                            account_index,
+                            WalletEvent::ThresholdAlert(ThresholdAlertEvent::ReceivedAmount(alert)),
+                            EventOrigin::new("sync").with_transaction_id(*output_data.output_id.transaction_id()),
+                        )
+                        .await;
+                    }
                 }
             };
             if !output_data.is_spent {
@@ -193,31 +273,43 @@ impl Account {
         let mut account_details = self.details_mut().await;
 
         for transaction in updated_transactions {
+            let transaction_id = transaction.payload.id();
+            let inclusion_state_changed = account_details
+                .transactions
+                .get(&transaction_id)
+                .map_or(true, |previous| previous.inclusion_state != transaction.inclusion_state);
             match transaction.inclusion_state {
-                InclusionState::Confirmed | InclusionState::Conflicting | InclusionState::UnknownPruned => {
-                    let transaction_id = transaction.payload.id();
+                InclusionState::Confirmed | InclusionState::UnknownPruned => {
                     account_details.pending_transactions.remove(&transaction_id);
-                    log::debug!(
-                        "[SYNC] inclusion_state of {transaction_id} changed to {:?}",
-                        transaction.inclusion_state
-                    );
-                    #[cfg(feature = "events")]
-                    {
-                        self.emit(
-                            account_details.index,
-                            WalletEvent::TransactionInclusion(TransactionInclusionEvent {
-                                transaction_id,
-                                inclusion_state: transaction.inclusion_state,
-                            }),
-                        )
-                        .await;
+                }
+                InclusionState::Conflicting => {
+                    account_details.pending_transactions.remove(&transaction_id);
+                    if inclusion_state_changed {
+                        account_details.refund_spending_allowances(&transaction_id);
                     }
                 }
-                _ => {}
+                // The transaction stays pending until its confirmation depth is reached
+                InclusionState::Included | InclusionState::Pending => {}
             }
-            account_details
-                .transactions
-                .insert(transaction.payload.id(), transaction.clone());
+            if inclusion_state_changed && transaction.inclusion_state != InclusionState::Pending {
+                log::debug!(
+                    "[SYNC] inclusion_state of {transaction_id} changed to {:?}",
+                    transaction.inclusion_state
+                );
+                #[cfg(feature = "events")]
+                {
+                    self.emit(
+                        account_details.index,
+                        WalletEvent::TransactionInclusion(TransactionInclusionEvent {
+                            transaction_id,
+                            inclusion_state: transaction.inclusion_state,
+                        }),
+                        EventOrigin::new("sync").with_transaction_id(transaction_id),
+                    )
+                    .await;
+                }
+            }
+            account_details.transactions.insert(transaction_id, transaction);
         }
 
         for output_to_unlock in &spent_output_ids {
@@ -276,7 +368,7 @@ impl Account {
     // Should only be called from the Wallet so all accounts are on the same state
     // Will update the addresses with a possible new Bech32 HRP and clear the inaccessible_incoming_transactions.
     pub(crate) async fn update_account_bech32_hrp(&mut self) -> crate::wallet::Result<()> {
-        let bech32_hrp = self.client().get_bech32_hrp().await?;
+        let bech32_hrp = self.network_params().await?.bech32_hrp;
         log::debug!("[UPDATE ACCOUNT WITH BECH32 HRP] new bech32_hrp: {}", bech32_hrp);
         let mut account_details = self.details_mut().await;
         for address in &mut account_details.addresses_with_unspent_outputs {
@@ -303,3 +395,210 @@ impl Account {
         Ok(())
     }
 }
+
+/// How an output received from the node changed the account.
+#[cfg(any(feature = "mqtt", test))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum NodeOutputUpdate {
+    /// The address of the output doesn't belong to the account.
+    UnknownAddress,
+    /// The output is already known in this state, or it's spent without having been known.
+    Unchanged,
+    /// The output was added as unspent output.
+    New(OutputData),
+    /// The known output was marked as spent.
+    Spent(OutputData),
+}
+
+#[cfg(any(feature = "mqtt", test))]
+impl AccountDetails {
+    /// Adds a new or marks a spent output received from the node for an address of the account, without syncing.
+    pub(crate) fn apply_node_output(
+        &mut self,
+        address: &Bech32Address,
+        output_with_metadata: &OutputWithMetadata,
+        network_id: u64,
+    ) -> NodeOutputUpdate {
+        let Some(account_address) = self
+            .public_addresses
+            .iter_mut()
+            .chain(self.internal_addresses.iter_mut())
+            .find(|account_address| account_address.address == *address)
+        else {
+            return NodeOutputUpdate::UnknownAddress;
+        };
+        account_address.used = true;
+        let (key_index, internal) = (account_address.key_index, account_address.internal);
+        let output_id = *output_with_metadata.metadata().output_id();
+
+        if output_with_metadata.metadata().is_spent() {
+            self.locked_outputs.remove(&output_id);
+            self.unspent_outputs.remove(&output_id);
+            if let Some(address_with_unspent_outputs) = self
+                .addresses_with_unspent_outputs
+                .iter_mut()
+                .find(|a| a.address == *address)
+            {
+                address_with_unspent_outputs.output_ids.retain(|id| *id != output_id);
+            }
+            return match self.outputs.get_mut(&output_id) {
+                Some(output_data) if !output_data.is_spent => {
+                    output_data.metadata = output_with_metadata.metadata().clone();
+                    output_data.is_spent = true;
+                    NodeOutputUpdate::Spent(output_data.clone())
+                }
+                _ => NodeOutputUpdate::Unchanged,
+            };
+        }
+
+        if self.outputs.contains_key(&output_id) {
+            return NodeOutputUpdate::Unchanged;
+        }
+        let remainder = self
+            .transactions
+            .get(output_id.transaction_id())
+            .map_or(false, |tx| !tx.incoming);
+        let output_data = OutputData {
+            output_id,
+            metadata: output_with_metadata.metadata().clone(),
+            output: output_with_metadata.output().clone(),
+            is_spent: false,
+            address: address.inner,
+            network_id,
+            remainder,
+            chain: Some(Chain::from_u32_hardened(vec![
+                HD_WALLET_TYPE,
+                self.coin_type,
+                self.index,
+                internal as u32,
+                key_index,
+            ])),
+        };
+
+        match self
+            .addresses_with_unspent_outputs
+            .iter_mut()
+            .find(|a| a.address == *address)
+        {
+            Some(address_with_unspent_outputs) => address_with_unspent_outputs.output_ids.push(output_id),
+            None => self.addresses_with_unspent_outputs.push(AddressWithUnspentOutputs {
+                address: *address,
+                key_index,
+                internal,
+                output_ids: vec![output_id],
+            }),
+        }
+        self.outputs.insert(output_id, output_data.clone());
+        self.unspent_outputs.insert(output_id, output_data.clone());
+
+        NodeOutputUpdate::New(output_data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::block::{
+        address::{Address, Ed25519Address},
+        output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder, OutputMetadata},
+        payload::transaction::TransactionId,
+        BlockId,
+    };
+
+    const TOKEN_SUPPLY: u64 = 1_813_620_509_061_365;
+
+    fn output_with_metadata(address: &Bech32Address, output_id: OutputId, spent: bool) -> OutputWithMetadata {
+        OutputWithMetadata::new(
+            BasicOutputBuilder::new_with_amount(1_000_000)
+                .add_unlock_condition(AddressUnlockCondition::new(address.inner))
+                .finish_output(TOKEN_SUPPLY)
+                .unwrap(),
+            OutputMetadata::new(
+                BlockId::new([0; BlockId::LENGTH]),
+                output_id,
+                spent,
+                spent.then_some(2),
+                spent.then_some(2),
+                spent.then(|| TransactionId::new([2; TransactionId::LENGTH])),
+                1,
+                1,
+                2,
+            ),
+        )
+    }
+
+    #[test]
+    fn apply_node_output() {
+        let mut account_details = AccountDetails::mock();
+        let address = account_details.public_addresses[0].address;
+        let output_id = OutputId::new(TransactionId::new([1; TransactionId::LENGTH]), 0).unwrap();
+
+        let NodeOutputUpdate::New(output_data) =
+            account_details.apply_node_output(&address, &output_with_metadata(&address, output_id, false), 1)
+        else {
+            panic!("the output should be new");
+        };
+        assert_eq!(output_data.output_id, output_id);
+        assert_eq!(output_data.network_id, 1);
+        assert!(!output_data.remainder);
+        assert!(account_details.public_addresses[0].used);
+        assert!(account_details.unspent_outputs.contains_key(&output_id));
+        assert_eq!(
+            account_details.addresses_with_unspent_outputs[0].output_ids,
+            vec![output_id]
+        );
+        // Receiving it again doesn't change anything
+        assert_eq!(
+            account_details.apply_node_output(&address, &output_with_metadata(&address, output_id, false), 1),
+            NodeOutputUpdate::Unchanged
+        );
+        assert_eq!(
+            account_details.addresses_with_unspent_outputs[0].output_ids,
+            vec![output_id]
+        );
+
+        let NodeOutputUpdate::Spent(output_data) =
+            account_details.apply_node_output(&address, &output_with_metadata(&address, output_id, true), 1)
+        else {
+            panic!("the output should be spent");
+        };
+        assert!(output_data.is_spent);
+        assert!(account_details.outputs[&output_id].is_spent);
+        assert!(account_details.unspent_outputs.is_empty());
+        assert!(account_details.addresses_with_unspent_outputs[0].output_ids.is_empty());
+        assert_eq!(
+            account_details.apply_node_output(&address, &output_with_metadata(&address, output_id, true), 1),
+            NodeOutputUpdate::Unchanged
+        );
+    }
+
+    #[test]
+    fn apply_node_output_of_other_address() {
+        let mut account_details = AccountDetails::mock();
+        let address = Bech32Address::new(
+            account_details.public_addresses[0].address.hrp,
+            Address::Ed25519(Ed25519Address::new([1; Ed25519Address::LENGTH])),
+        );
+        let output_id = OutputId::new(TransactionId::new([1; TransactionId::LENGTH]), 0).unwrap();
+
+        assert_eq!(
+            account_details.apply_node_output(&address, &output_with_metadata(&address, output_id, false), 1),
+            NodeOutputUpdate::UnknownAddress
+        );
+        assert!(account_details.outputs.is_empty());
+        assert!(!account_details.public_addresses[0].used);
+    }
+
+    #[test]
+    fn apply_unknown_spent_node_output() {
+        let mut account_details = AccountDetails::mock();
+        let address = account_details.public_addresses[0].address;
+        let output_id = OutputId::new(TransactionId::new([1; TransactionId::LENGTH]), 0).unwrap();
+
+        assert_eq!(
+            account_details.apply_node_output(&address, &output_with_metadata(&address, output_id, true), 1),
+            NodeOutputUpdate::Unchanged
+        );
+        assert!(account_details.outputs.is_empty());
+    }
+}
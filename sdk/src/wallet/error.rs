@@ -3,12 +3,17 @@
 
 use std::fmt::Debug;
 
+use primitive_types::U256;
 use serde::{
     ser::{SerializeMap, Serializer},
     Serialize,
 };
 
-use crate::types::block::{address::Bech32Address, payload::transaction::TransactionId};
+use crate::types::block::{
+    address::{Bech32Address, Hrp},
+    output::{AliasId, ChainId, NftId, TokenId},
+    payload::transaction::TransactionId,
+};
 
 /// The wallet error type.
 #[derive(Debug, thiserror::Error)]
@@ -22,45 +27,107 @@ pub enum Error {
     /// Address not found in account
     #[error("address {0} not found in account")]
     AddressNotFoundInAccount(Bech32Address),
+    /// Destination address isn't allowed by the address book
+    #[error("destination address {0} isn't in the address book or allow-list")]
+    AddressNotInAddressBook(Bech32Address),
+    /// The amount would exceed the remaining amount of a spending allowance
+    #[error("spending allowance {id} exceeded: remaining {remaining}, required {required}")]
+    AllowanceExceeded { id: String, remaining: u64, required: u64 },
+    /// The native token amount would exceed the remaining amount of a spending allowance
+    #[error("spending allowance {id} exceeded for token {token_id}: remaining {remaining}, required {required}")]
+    AllowanceNativeTokenExceeded {
+        id: String,
+        token_id: TokenId,
+        remaining: U256,
+        required: U256,
+    },
+    /// Spending allowance expired
+    #[error("spending allowance {0} expired")]
+    AllowanceExpired(String),
+    /// Spending allowance not found
+    #[error("spending allowance {0} not found")]
+    AllowanceNotFound(String),
+    /// The alias output isn't an unspent output of the account or isn't controlled by it
+    #[error("alias {0} isn't controlled by the account")]
+    AliasNotControlled(AliasId),
+    /// A token symbol matches multiple native tokens
+    #[error("token symbol {symbol} is ambiguous, it matches the tokens {token_ids:?}")]
+    AmbiguousTokenSymbol { symbol: String, token_ids: Vec<TokenId> },
     /// Errors during backup creation or restoring
     #[error("backup failed {0}")]
     Backup(&'static str),
+    /// A backed up account conflicts with the accounts of the wallet
+    #[error("can't restore account from backup: {0}")]
+    BackupConflict(String),
     /// Error from block crate.
     #[error("{0}")]
     Block(Box<crate::types::block::Error>),
     /// Burning or melting failed
     #[error("burning or melting failed: {0}")]
     BurningOrMeltingFailed(String),
+    /// A chain output would be destroyed, but it wasn't allowed
+    #[error("transaction would destroy chain {0}, but destroying chains isn't allowed")]
+    ChainDestructionNotAllowed(ChainId),
     /// Client error.
     #[error("`{0}`")]
     Client(Box<crate::client::Error>),
     /// Funds are spread over too many outputs
     #[error("funds are spread over too many outputs {output_count}/{output_count_max}, consolidation required")]
     ConsolidationRequired { output_count: usize, output_count_max: u16 },
+    /// Consolidating with multiple transactions failed after some of them were sent
+    #[error("consolidation failed after sending the transactions {transaction_ids:?}: {error}")]
+    ConsolidationInterrupted {
+        transaction_ids: Vec<TransactionId>,
+        error: Box<Self>,
+    },
     /// Crypto.rs error
     #[error("{0}")]
     Crypto(#[from] crypto::Error),
     /// Custom input error
     #[error("custom input error {0}")]
     CustomInput(String),
+    /// The account method can't be called as dry run
+    #[error("account method {0} doesn't support a dry run")]
+    DryRunNotSupported(String),
+    /// The same NFT is sent more than once
+    #[error("nft {0} is sent more than once")]
+    DuplicateNftTransfer(NftId),
     /// Failed to get remainder
     #[error("failed to get remainder address")]
     FailedToGetRemainder,
+    /// A setting requires a crate feature that isn't enabled
+    #[error("the `{0}` feature isn't enabled")]
+    FeatureNotEnabled(&'static str),
     /// Insufficient funds to send transaction.
     #[error("insufficient funds {available}/{required} available")]
     InsufficientFunds { available: u64, required: u64 },
+    /// Invalid account id
+    #[error("invalid account id: {0}")]
+    InvalidAccountId(String),
+    /// Invalid percentage for the balance drop alert threshold
+    #[error("invalid balance drop percentage {0}, needs to be from 1 to 100")]
+    InvalidBalanceDropPercentage(u8),
     /// Invalid coin type, all accounts need to have the same coin type
     #[error("invalid coin type for new account: {new_coin_type}, existing coin type is: {existing_coin_type}")]
     InvalidCoinType {
         new_coin_type: u32,
         existing_coin_type: u32,
     },
+    /// Invalid decimal amount of a native token
+    #[error("invalid decimal amount {amount} for a token with {decimals} decimals")]
+    InvalidDecimalAmount { amount: String, decimals: u8 },
     /// Invalid mnemonic error
     #[error("invalid mnemonic: {0}")]
     InvalidMnemonic(String),
+    /// Invalid transaction with inputs of multiple accounts
+    #[error("invalid multi-account transaction: {0}")]
+    InvalidMultiAccountTransaction(String),
     /// Invalid output kind.
     #[error("invalid output kind: {0}")]
     InvalidOutputKind(String),
+    /// Invalid remainder split
+    #[error("invalid remainder split: {0}")]
+    InvalidRemainderSplit(String),
     /// IO error. (storage, backup, restore)
     #[error("`{0}`")]
     Io(#[from] std::io::Error),
@@ -76,18 +143,27 @@ pub enum Error {
     /// Missing parameter.
     #[error("missing parameter: {0}")]
     MissingParameter(&'static str),
+    /// A placeholder of a transaction template has no parameter
+    #[error("missing parameter for the placeholder {{{0}}} of the transaction template")]
+    MissingTemplateParameter(String),
+    /// Native tokens would be burned, but it wasn't allowed
+    #[error("transaction would burn {amount} of native token {token_id}, but burning tokens isn't allowed")]
+    NativeTokenBurningNotAllowed { token_id: TokenId, amount: U256 },
+    /// The network of the new client options doesn't belong to the coin type of the existing accounts
+    #[error("bech32 hrp {bech32_hrp} of the network doesn't match the coin type {coin_type} of the existing accounts")]
+    NetworkMismatch { bech32_hrp: Hrp, coin_type: u32 },
     /// Nft not found in unspent outputs
     #[error("nft not found in unspent outputs")]
     NftNotFoundInUnspentOutputs,
     // TODO more precise error
     /// Voting error
-    #[cfg(feature = "participation")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
+    #[cfg(feature = "wallet-participation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "wallet-participation")))]
     #[error("voting error {0}")]
     Voting(String),
     /// Participation error
-    #[cfg(feature = "participation")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
+    #[cfg(feature = "wallet-participation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "wallet-participation")))]
     #[error("participation error {0}")]
     Participation(#[from] crate::types::api::plugins::participation::error::Error),
     /// No outputs available for consolidating
@@ -100,18 +176,67 @@ pub enum Error {
         /// The consolidation threshold.
         consolidation_threshold: usize,
     },
+    /// The rate limit for sending transactions of the account is reached
+    #[error("rate limit of {max} transactions per {window_secs} seconds reached, retry in {retry_after_ms}ms")]
+    RateLimitExceeded {
+        /// The maximum amount of transactions of the reached limit.
+        max: u32,
+        /// The time window of the reached limit.
+        window_secs: u64,
+        /// Milliseconds until another transaction can be sent.
+        retry_after_ms: u64,
+    },
+    /// Retrying a transaction was cancelled
+    #[error("retrying transaction {0} was cancelled")]
+    RetryCancelled(TransactionId),
+    /// The transaction would lock more storage deposit than allowed by its options
+    #[error("storage deposit budget exceeded: max {max}, required {required}")]
+    StorageDepositBudgetExceeded { max: u64, required: u64 },
     /// Storage access error.
     #[error("error accessing storage: {0}")]
     Storage(String),
+    /// The storage path is used by another wallet process
+    #[error("storage {path} is locked by process {pid}")]
+    StorageLocked {
+        /// The locked storage path.
+        path: String,
+        /// The id of the process holding the lock.
+        pid: u32,
+    },
     /// Can't use Wallet API because the storage is encrypted
     #[error("can't perform operation while storage is encrypted; use Wallet::set_storage_password to decrypt storage")]
     StorageIsEncrypted,
+    /// Testkit error
+    #[cfg(feature = "testkit")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "testkit")))]
+    #[error("testkit error: {0}")]
+    Testkit(String),
     /// Tokio task join error
     #[error("{0}")]
     TaskJoin(#[from] tokio::task::JoinError),
+    /// The token policy of the account doesn't allow sending the native token
+    #[error("native token {0} isn't allowed by the token policy of the account")]
+    TokenNotAllowed(TokenId),
     /// Transaction not found
     #[error("transaction {0} not found")]
     TransactionNotFound(TransactionId),
+    /// No transaction template with the name exists
+    #[error("transaction template {0} not found")]
+    TransactionTemplateNotFound(String),
+    /// No transaction with the sequence number was sent by the account
+    #[error("no transaction with sequence number {0} was sent")]
+    UnknownSequenceNumber(u64),
+    /// No native token with IRC30 metadata is known for the token id or symbol
+    #[error("no native token with IRC30 metadata found for {0}")]
+    UnknownToken(String),
+    /// The account is watch-only and has no secret manager to generate addresses or sign with
+    #[error("account {0} is watch-only and can't {1}")]
+    WatchOnlyAccount(String, &'static str),
+    /// Zip error
+    #[cfg(feature = "debug_bundle")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "debug_bundle")))]
+    #[error("`{0}`")]
+    Zip(#[from] zip::result::ZipError),
 }
 
 // Serialize type with Display error
@@ -167,6 +292,13 @@ impl From<crate::client::secret::ledger_nano::Error> for Error {
     }
 }
 
+#[cfg(feature = "mqtt")]
+impl From<crate::client::node_api::mqtt::Error> for Error {
+    fn from(error: crate::client::node_api::mqtt::Error) -> Self {
+        Self::Client(Box::new(crate::client::Error::Mqtt(error)))
+    }
+}
+
 #[cfg(feature = "rocksdb")]
 impl From<rocksdb::Error> for Error {
     fn from(error: rocksdb::Error) -> Self {
@@ -4,16 +4,50 @@
 pub mod types;
 
 use std::{
-    collections::HashMap,
+    collections::{HashMap, VecDeque},
     fmt::{Debug, Formatter, Result},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
 };
 
-use self::types::{Event, WalletEvent, WalletEventType};
+use futures::Stream;
+use tokio::sync::broadcast::{self, error::RecvError};
+
+use self::types::{Event, EventOrigin, NewOutputFilter, WalletEvent, WalletEventType};
+use crate::utils::unix_timestamp_now;
 
 type Handler<T> = Box<dyn Fn(&T) + Send + Sync + 'static>;
 
+/// A registered handler with the filter for new output events it was registered with.
+struct Listener {
+    handler: Handler<Event>,
+    new_output_filter: Option<NewOutputFilter>,
+}
+
+impl Listener {
+    fn new<F>(handler: F, new_output_filter: Option<NewOutputFilter>) -> Self
+    where
+        F: Fn(&Event) + 'static + Send + Sync,
+    {
+        Self {
+            handler: Box::new(handler),
+            new_output_filter,
+        }
+    }
+}
+
+/// Amount of emitted events that are kept for debugging.
+const RECENT_EVENTS_CAPACITY: usize = 100;
+/// Amount of events that are buffered for an event stream, before the oldest ones are dropped for it.
+const EVENT_STREAM_CAPACITY: usize = 1000;
+
 pub struct EventEmitter {
-    handlers: HashMap<WalletEventType, Vec<Handler<Event>>>,
+    handlers: HashMap<WalletEventType, Vec<Listener>>,
+    sequence_number: AtomicU64,
+    recent_events: Mutex<VecDeque<Event>>,
+    stream_sender: broadcast::Sender<Event>,
 }
 
 impl EventEmitter {
@@ -21,6 +55,9 @@ impl EventEmitter {
     pub fn new() -> Self {
         Self {
             handlers: HashMap::new(),
+            sequence_number: AtomicU64::new(0),
+            recent_events: Mutex::new(VecDeque::with_capacity(RECENT_EVENTS_CAPACITY)),
+            stream_sender: broadcast::channel(EVENT_STREAM_CAPACITY).0,
         }
     }
 
@@ -34,8 +71,15 @@ impl EventEmitter {
         if events.is_empty() {
             // we could use a crate like strum or a macro to iterate over all values, but not sure if it's worth it
             for event_type in &[
+                #[cfg(feature = "stronghold")]
+                WalletEventType::AutoBackup,
+                WalletEventType::ClientOptionsChanged,
+                WalletEventType::ClockSkew,
                 WalletEventType::NewOutput,
                 WalletEventType::SpentOutput,
+                WalletEventType::SyncCompleted,
+                WalletEventType::SyncSummary,
+                WalletEventType::ThresholdAlert,
                 WalletEventType::TransactionInclusion,
                 WalletEventType::TransactionProgress,
                 WalletEventType::ConsolidationRequired,
@@ -43,15 +87,49 @@ impl EventEmitter {
                 WalletEventType::LedgerAddressGeneration,
             ] {
                 let event_handlers = self.handlers.entry(*event_type).or_insert_with(Vec::new);
-                event_handlers.push(Box::new(handler.clone()));
+                event_handlers.push(Listener::new(handler.clone(), None));
             }
         }
         for event in events.into_iter() {
             let event_handlers = self.handlers.entry(event).or_insert_with(Vec::new);
-            event_handlers.push(Box::new(handler.clone()));
+            event_handlers.push(Listener::new(handler.clone(), None));
         }
     }
 
+    /// Registers function `handler` as a listener for `NewOutput` events that pass `filter`. The filter is evaluated
+    /// before the handler gets called, so it isn't invoked for outputs it isn't interested in.
+    pub fn on_new_output<F>(&mut self, filter: NewOutputFilter, handler: F)
+    where
+        F: Fn(&Event) + 'static + Send + Sync,
+    {
+        let event_handlers = self.handlers.entry(WalletEventType::NewOutput).or_insert_with(Vec::new);
+        event_handlers.push(Listener::new(handler, Some(filter)));
+    }
+
+    /// Returns a stream of the emitted events of the given `WalletEventType`s, an empty vec streams all events. Events
+    /// are only buffered while the stream exists, so dropping it unsubscribes. If the stream isn't polled fast enough
+    /// the oldest events are skipped.
+    pub fn stream(&self, events: Vec<WalletEventType>) -> impl Stream<Item = Event> + Send + 'static {
+        futures::stream::unfold(
+            (self.stream_sender.subscribe(), events),
+            |(mut receiver, events)| async move {
+                loop {
+                    match receiver.recv().await {
+                        Ok(event) => {
+                            if events.is_empty() || events.contains(&event_type(&event.event)) {
+                                return Some((event, (receiver, events)));
+                            }
+                        }
+                        Err(RecvError::Lagged(skipped)) => {
+                            log::warn!("[EVENTS] event stream lagged behind, skipped {skipped} events");
+                        }
+                        Err(RecvError::Closed) => return None,
+                    }
+                }
+            },
+        )
+    }
+
     /// Removes handlers for each given `WalletEventType`.
     /// If no `WalletEventType` is given, handlers will be removed for all event types.
     pub fn clear(&mut self, events: Vec<WalletEventType>) {
@@ -65,23 +143,73 @@ impl EventEmitter {
     }
 
     /// Invokes all listeners of `event`, passing a reference to `payload` as an
-    /// argument to each of them.
-    pub fn emit(&self, account_index: u32, event: WalletEvent) {
-        let event_type = match &event {
-            WalletEvent::NewOutput(_) => WalletEventType::NewOutput,
-            WalletEvent::SpentOutput(_) => WalletEventType::SpentOutput,
-            WalletEvent::TransactionInclusion(_) => WalletEventType::TransactionInclusion,
-            WalletEvent::TransactionProgress(_) => WalletEventType::TransactionProgress,
-            WalletEvent::ConsolidationRequired => WalletEventType::ConsolidationRequired,
-            #[cfg(feature = "ledger_nano")]
-            WalletEvent::LedgerAddressGeneration(_) => WalletEventType::LedgerAddressGeneration,
+    /// argument to each of them. The event has an empty [`EventOrigin`], use [`EventEmitter::emit_with_origin()`] to
+    /// set it.
+    pub fn emit(&self, account_index: impl Into<Option<u32>>, event: WalletEvent) {
+        self.emit_with_origin(account_index, event, EventOrigin::default())
+    }
+
+    /// Invokes all listeners of `event` like [`EventEmitter::emit()`], with the operation which emitted it. Every
+    /// emitted event gets the next sequence number, even if no listener is registered for it. Events of the whole
+    /// wallet are emitted without an account index.
+    pub fn emit_with_origin(&self, account_index: impl Into<Option<u32>>, event: WalletEvent, origin: EventOrigin) {
+        let event_type = event_type(&event);
+        let event = Event {
+            account_index: account_index.into(),
+            sequence_number: self.sequence_number.fetch_add(1, Ordering::SeqCst),
+            timestamp: unix_timestamp_now().as_millis(),
+            origin,
+            event,
         };
-        let event = Event { account_index, event };
-        if let Some(handlers) = self.handlers.get(&event_type) {
-            for handler in handlers {
-                handler(&event);
+        if let Some(listeners) = self.handlers.get(&event_type) {
+            for listener in listeners {
+                if let (Some(filter), WalletEvent::NewOutput(new_output_event)) =
+                    (&listener.new_output_filter, &event.event)
+                {
+                    if !filter.matches(new_output_event) {
+                        continue;
+                    }
+                }
+                (listener.handler)(&event);
             }
         }
+        // Sending only fails if there is no stream
+        if self.stream_sender.receiver_count() > 0 {
+            self.stream_sender.send(event.clone()).ok();
+        }
+        if let Ok(mut recent_events) = self.recent_events.lock() {
+            if recent_events.len() == RECENT_EVENTS_CAPACITY {
+                recent_events.pop_front();
+            }
+            recent_events.push_back(event);
+        }
+    }
+
+    /// Returns the most recently emitted events, oldest first.
+    pub fn recent_events(&self) -> Vec<Event> {
+        self.recent_events
+            .lock()
+            .map(|recent_events| recent_events.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+}
+
+fn event_type(event: &WalletEvent) -> WalletEventType {
+    match event {
+        #[cfg(feature = "stronghold")]
+        WalletEvent::AutoBackup(_) => WalletEventType::AutoBackup,
+        WalletEvent::ClientOptionsChanged(_) => WalletEventType::ClientOptionsChanged,
+        WalletEvent::ClockSkew(_) => WalletEventType::ClockSkew,
+        WalletEvent::NewOutput(_) => WalletEventType::NewOutput,
+        WalletEvent::SpentOutput(_) => WalletEventType::SpentOutput,
+        WalletEvent::SyncCompleted(_) => WalletEventType::SyncCompleted,
+        WalletEvent::SyncSummary(_) => WalletEventType::SyncSummary,
+        WalletEvent::ThresholdAlert(_) => WalletEventType::ThresholdAlert,
+        WalletEvent::TransactionInclusion(_) => WalletEventType::TransactionInclusion,
+        WalletEvent::TransactionProgress(_) => WalletEventType::TransactionProgress,
+        WalletEvent::ConsolidationRequired => WalletEventType::ConsolidationRequired,
+        #[cfg(feature = "ledger_nano")]
+        WalletEvent::LedgerAddressGeneration(_) => WalletEventType::LedgerAddressGeneration,
     }
 }
 
@@ -107,15 +235,66 @@ mod tests {
         str::FromStr,
         sync::{
             atomic::{AtomicUsize, Ordering},
-            Arc,
+            Arc, Mutex,
         },
     };
 
     use super::{
-        types::{TransactionInclusionEvent, TransactionProgressEvent, WalletEvent, WalletEventType},
+        types::{
+            AssetKind, EventOrigin, NewOutputEvent, NewOutputFilter, TransactionInclusionEvent,
+            TransactionProgressEvent, WalletEvent, WalletEventType,
+        },
         EventEmitter,
     };
-    use crate::{types::block::payload::transaction::TransactionId, wallet::account::types::InclusionState};
+    use crate::{
+        client::api::ClockSkew,
+        types::block::{
+            address::{dto::AddressDto, Address, Ed25519Address},
+            output::{
+                dto::{OutputDto, OutputMetadataDto},
+                unlock_condition::AddressUnlockCondition,
+                BasicOutputBuilder, OutputId,
+            },
+            payload::transaction::TransactionId,
+            protocol::protocol_parameters,
+        },
+        wallet::account::types::{InclusionState, OutputDataDto},
+    };
+
+    fn new_output_event(amount: u64, address: Address) -> WalletEvent {
+        let transaction_id =
+            TransactionId::from_str("0x2289d9981fb23cc5f4f6c2742685eeb480f8476089888aa886a18232bad81989").unwrap();
+        let output = BasicOutputBuilder::new_with_amount(amount)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .finish_output(protocol_parameters().token_supply())
+            .unwrap();
+
+        WalletEvent::NewOutput(Box::new(NewOutputEvent {
+            output: OutputDataDto {
+                output_id: OutputId::new(transaction_id, 0).unwrap(),
+                metadata: OutputMetadataDto {
+                    block_id: "0xaf6a5e6b1d1b0d1a4d0b5a1d9c7e3f0e6b1c2d3e4f5a6b7c8d9e0f1a2b3c4d5e".to_string(),
+                    transaction_id: transaction_id.to_string(),
+                    output_index: 0,
+                    is_spent: false,
+                    milestone_index_spent: None,
+                    milestone_timestamp_spent: None,
+                    transaction_id_spent: None,
+                    milestone_index_booked: 0,
+                    milestone_timestamp_booked: 0,
+                    ledger_index: 0,
+                },
+                output: OutputDto::from(&output),
+                is_spent: false,
+                address: AddressDto::from(&address),
+                network_id: "0".to_string(),
+                remainder: false,
+                chain: None,
+            },
+            transaction: None,
+            transaction_inputs: None,
+        }))
+    }
 
     #[test]
     fn events() {
@@ -202,4 +381,131 @@ mod tests {
         }
         assert_eq!(1_000_003, event_counter.load(Ordering::SeqCst));
     }
+
+    #[test]
+    fn event_sequence_numbers() {
+        let mut emitter = EventEmitter::new();
+        let events = Arc::new(Mutex::new(Vec::new()));
+
+        let events_clone = Arc::clone(&events);
+        emitter.on(vec![WalletEventType::TransactionInclusion], move |event| {
+            events_clone.lock().unwrap().push(event.clone());
+        });
+
+        let transaction_id =
+            TransactionId::from_str("0x2289d9981fb23cc5f4f6c2742685eeb480f8476089888aa886a18232bad81989").unwrap();
+        emitter.emit_with_origin(
+            1,
+            WalletEvent::TransactionInclusion(TransactionInclusionEvent {
+                transaction_id,
+                inclusion_state: InclusionState::Pending,
+            }),
+            EventOrigin::new("sync").with_transaction_id(transaction_id),
+        );
+        // Not listened to, but still consumes a sequence number
+        emitter.emit(1, WalletEvent::ConsolidationRequired);
+        emitter.emit_with_origin(
+            1,
+            WalletEvent::TransactionInclusion(TransactionInclusionEvent {
+                transaction_id,
+                inclusion_state: InclusionState::Confirmed,
+            }),
+            EventOrigin::new("sync").with_transaction_id(transaction_id),
+        );
+
+        let events = events.lock().unwrap();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].sequence_number, 0);
+        assert_eq!(events[1].sequence_number, 2);
+        assert!(events[0].timestamp <= events[1].timestamp);
+        assert_eq!(events[1].origin.operation, "sync");
+        assert_eq!(events[1].origin.transaction_id, Some(transaction_id));
+    }
+
+    #[test]
+    fn wallet_events_without_account_index() {
+        let emitter = EventEmitter::new();
+
+        emitter.emit(1, WalletEvent::ConsolidationRequired);
+        emitter.emit_with_origin(
+            None,
+            WalletEvent::ClockSkew(ClockSkew {
+                local_time: 1_000,
+                milestone_timestamp: 2_000,
+                round_trip_time: 10,
+                skew: 1_000,
+            }),
+            EventOrigin::new("check_clock_skew"),
+        );
+
+        let events = emitter.recent_events();
+        assert_eq!(events[0].account_index, Some(1));
+        assert_eq!(events[0].origin, EventOrigin::default());
+        assert_eq!(events[1].account_index, None);
+        assert_eq!(events[1].origin.operation, "check_clock_skew");
+    }
+
+    #[tokio::test]
+    async fn event_stream() {
+        use futures::StreamExt;
+
+        let emitter = EventEmitter::new();
+        // Events emitted before the stream is created aren't streamed
+        emitter.emit(0, WalletEvent::ConsolidationRequired);
+
+        let stream = emitter.stream(vec![WalletEventType::TransactionProgress]);
+        futures::pin_mut!(stream);
+
+        emitter.emit(0, WalletEvent::ConsolidationRequired);
+        emitter.emit(
+            1,
+            WalletEvent::TransactionProgress(TransactionProgressEvent::SelectingInputs),
+        );
+
+        let event = stream.next().await.unwrap();
+        assert_eq!(event.account_index, Some(1));
+        assert_eq!(event.sequence_number, 2);
+        assert!(matches!(
+            event.event,
+            WalletEvent::TransactionProgress(TransactionProgressEvent::SelectingInputs)
+        ));
+
+        drop(emitter);
+        assert!(stream.next().await.is_none());
+    }
+
+    #[test]
+    fn new_output_filter() {
+        let mut emitter = EventEmitter::new();
+        let address = Address::from(Ed25519Address::new([1; 32]));
+        let other_address = Address::from(Ed25519Address::new([2; 32]));
+
+        let unfiltered_counter = Arc::new(AtomicUsize::new(0));
+        let unfiltered_counter_clone = Arc::clone(&unfiltered_counter);
+        emitter.on(vec![WalletEventType::NewOutput], move |_event| {
+            unfiltered_counter_clone.fetch_add(1, Ordering::SeqCst);
+        });
+
+        let filtered_counter = Arc::new(AtomicUsize::new(0));
+        let filtered_counter_clone = Arc::clone(&filtered_counter);
+        emitter.on_new_output(
+            NewOutputFilter {
+                min_amount: 1_000_000,
+                asset_kinds: vec![AssetKind::BaseCoin],
+                address: Some(address.to_bech32_unchecked("rms")),
+            },
+            move |_event| {
+                filtered_counter_clone.fetch_add(1, Ordering::SeqCst);
+            },
+        );
+
+        // Dust output
+        emitter.emit(0, new_output_event(100_000, address));
+        // Output to another address
+        emitter.emit(0, new_output_event(2_000_000, other_address));
+        emitter.emit(0, new_output_event(2_000_000, address));
+
+        assert_eq!(3, unfiltered_counter.load(Ordering::SeqCst));
+        assert_eq!(1, filtered_counter.load(Ordering::SeqCst));
+    }
 }
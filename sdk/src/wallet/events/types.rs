@@ -4,12 +4,18 @@
 use getset::Getters;
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "ledger_nano")]
 use crate::{
-    client::api::PreparedTransactionDataDto,
+    client::api::PreparedTransactionData,
+    types::block::{output::NativeToken, payload::transaction::TransactionEssence},
+};
+use crate::{
+    client::api::{ClockSkew, PreparedTransactionDataDto},
     types::{
         api::core::response::OutputWithMetadataResponse,
         block::{
-            address::Bech32Address,
+            address::{Address, Bech32Address, Hrp},
+            output::{Output, OutputId},
             payload::transaction::{dto::TransactionPayloadDto, TransactionId},
         },
     },
@@ -18,32 +24,82 @@ use crate::{
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Event {
-    /// Associated account index.
-    pub account_index: u32,
+    /// Associated account index, `None` for events of the whole wallet.
+    pub account_index: Option<u32>,
+    /// Monotonic sequence number of the event, unique for the wallet instance.
+    pub sequence_number: u64,
+    /// Unix timestamp in milliseconds when the event was emitted.
+    pub timestamp: u128,
+    /// The operation which emitted the event.
+    pub origin: EventOrigin,
     /// The event
     pub event: WalletEvent,
 }
 
+/// The operation which emitted an event.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventOrigin {
+    /// Name of the method which emitted the event.
+    pub operation: String,
+    /// The transaction the event relates to, if any.
+    pub transaction_id: Option<TransactionId>,
+}
+
+impl EventOrigin {
+    /// Creates a new [`EventOrigin`] for the given method name.
+    pub fn new(operation: impl Into<String>) -> Self {
+        Self {
+            operation: operation.into(),
+            transaction_id: None,
+        }
+    }
+
+    /// Sets the transaction the event relates to.
+    pub fn with_transaction_id(mut self, transaction_id: TransactionId) -> Self {
+        self.transaction_id = Some(transaction_id);
+        self
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub enum WalletEvent {
+    #[cfg(feature = "stronghold")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
+    AutoBackup(AutoBackupEvent),
+    ClientOptionsChanged(ClientOptionsChangedEvent),
+    /// The local clock deviates from the time of the network.
+    ClockSkew(ClockSkew),
     ConsolidationRequired,
     #[cfg(feature = "ledger_nano")]
     #[cfg_attr(docsrs, doc(cfg(feature = "ledger_nano")))]
     LedgerAddressGeneration(AddressData),
     NewOutput(Box<NewOutputEvent>),
     SpentOutput(Box<SpentOutputEvent>),
+    SyncCompleted(SyncCompletedEvent),
+    SyncSummary(SyncSummaryEvent),
+    /// A threshold of the [`AlertThresholds`](crate::wallet::account::AlertThresholds) of the account was reached.
+    ThresholdAlert(ThresholdAlertEvent),
     TransactionInclusion(TransactionInclusionEvent),
     TransactionProgress(TransactionProgressEvent),
 }
 
 #[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum WalletEventType {
+    #[cfg(feature = "stronghold")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
+    AutoBackup,
+    ClientOptionsChanged,
+    ClockSkew,
     ConsolidationRequired,
     #[cfg(feature = "ledger_nano")]
     #[cfg_attr(docsrs, doc(cfg(feature = "ledger_nano")))]
     LedgerAddressGeneration,
     NewOutput,
     SpentOutput,
+    SyncCompleted,
+    SyncSummary,
+    ThresholdAlert,
     TransactionInclusion,
     TransactionProgress,
 }
@@ -53,11 +109,18 @@ impl TryFrom<&str> for WalletEventType {
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
         let event_type = match value {
+            #[cfg(feature = "stronghold")]
+            "AutoBackup" => Self::AutoBackup,
+            "ClientOptionsChanged" => Self::ClientOptionsChanged,
+            "ClockSkew" => Self::ClockSkew,
             "ConsolidationRequired" => Self::ConsolidationRequired,
             #[cfg(feature = "ledger_nano")]
             "LedgerAddressGeneration" => Self::LedgerAddressGeneration,
             "NewOutput" => Self::NewOutput,
             "SpentOutput" => Self::SpentOutput,
+            "SyncCompleted" => Self::SyncCompleted,
+            "SyncSummary" => Self::SyncSummary,
+            "ThresholdAlert" => Self::ThresholdAlert,
             "TransactionInclusion" => Self::TransactionInclusion,
             "TransactionProgress" => Self::TransactionProgress,
             _ => return Err(format!("invalid event type {value}")),
@@ -79,12 +142,188 @@ pub struct NewOutputEvent {
     pub transaction_inputs: Option<Vec<OutputWithMetadataResponse>>,
 }
 
+/// The kind of asset an output holds, used by [`NewOutputFilter`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum AssetKind {
+    /// A basic output without native tokens.
+    BaseCoin,
+    /// Any output with native tokens.
+    NativeToken,
+    /// An alias output.
+    Alias,
+    /// A foundry output.
+    Foundry,
+    /// An NFT output.
+    Nft,
+}
+
+/// Filter for [`WalletEvent::NewOutput`] events of a single listener, so it only gets notified about relevant
+/// outputs, for example no dust outputs.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewOutputFilter {
+    /// Minimum amount of the base coin the output needs to hold.
+    #[serde(default, with = "crate::utils::serde::string")]
+    pub min_amount: u64,
+    /// The output needs to hold one of these kinds of assets, all kinds match if empty.
+    #[serde(default)]
+    pub asset_kinds: Vec<AssetKind>,
+    /// The output needs to belong to this address.
+    #[serde(default)]
+    pub address: Option<Bech32Address>,
+}
+
+impl NewOutputFilter {
+    /// Returns whether a new output event passes the filter. Events with an output that can't be converted never
+    /// pass a filter.
+    pub fn matches(&self, event: &NewOutputEvent) -> bool {
+        let output = match Output::try_from_dto_unverified(&event.output.output) {
+            Ok(output) => output,
+            Err(_) => return false,
+        };
+
+        if output.amount() < self.min_amount {
+            return false;
+        }
+
+        if !self.asset_kinds.is_empty() {
+            let has_native_tokens = output
+                .native_tokens()
+                .map_or(false, |native_tokens| !native_tokens.is_empty());
+            let matches_kind = self.asset_kinds.iter().any(|asset_kind| match asset_kind {
+                AssetKind::BaseCoin => output.is_basic() && !has_native_tokens,
+                AssetKind::NativeToken => has_native_tokens,
+                AssetKind::Alias => output.is_alias(),
+                AssetKind::Foundry => output.is_foundry(),
+                AssetKind::Nft => output.is_nft(),
+            });
+            if !matches_kind {
+                return false;
+            }
+        }
+
+        if let Some(address) = &self.address {
+            match Address::try_from(&event.output.address) {
+                Ok(output_address) if &output_address == address.inner() => {}
+                _ => return false,
+            }
+        }
+
+        true
+    }
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct SpentOutputEvent {
     /// The spent output.
     pub output: OutputDataDto,
 }
 
+/// Statistics of a single [`Account::sync()`](crate::wallet::Account::sync), emitted after each sync that requested
+/// data from the node, also for the accounts synced by [`Wallet::sync_all()`](crate::wallet::Wallet::sync_all).
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncCompletedEvent {
+    /// Index of the synced account.
+    pub account_index: u32,
+    /// Number of outputs that got added to the account.
+    pub new_outputs: usize,
+    /// Number of unspent outputs that got spent or are no longer known by the node.
+    pub spent_outputs: usize,
+    /// How long syncing took, in milliseconds.
+    pub duration: u64,
+    /// The confirmed milestone index of the node after syncing, `None` if it couldn't be requested.
+    pub ledger_index: Option<u32>,
+}
+
+/// Summary of [`Wallet::sync_all()`](crate::wallet::Wallet::sync_all), emitted without an account index. The
+/// statistics of each synced account are emitted as separate [`SyncCompletedEvent`]s.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncSummaryEvent {
+    /// Indexes of the accounts that got synced.
+    pub synced_accounts: Vec<u32>,
+    /// Indexes of the accounts that failed to sync.
+    pub failed_accounts: Vec<u32>,
+    /// How long syncing all accounts took, in milliseconds.
+    pub duration: u64,
+}
+
+/// A threshold of the [`AlertThresholds`](crate::wallet::account::AlertThresholds) of an account that was reached.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub enum ThresholdAlertEvent {
+    /// A received output reached the received amount threshold.
+    ReceivedAmount(ReceivedAmountAlert),
+    /// The balance dropped by at least the balance drop percentage during a sync.
+    BalanceDrop(BalanceDropAlert),
+}
+
+/// A received output that reached the received amount threshold.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReceivedAmountAlert {
+    /// The id of the received output.
+    pub output_id: OutputId,
+    /// The amount of the base coin of the output.
+    #[serde(with = "crate::utils::serde::string")]
+    pub amount: u64,
+    /// The threshold that was reached.
+    #[serde(with = "crate::utils::serde::string")]
+    pub threshold: u64,
+}
+
+/// A drop of the total base coin balance since the previous sync that reached the balance drop percentage.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceDropAlert {
+    /// The total balance before the sync.
+    #[serde(with = "crate::utils::serde::string")]
+    pub previous_balance: u64,
+    /// The total balance after the sync.
+    #[serde(with = "crate::utils::serde::string")]
+    pub balance: u64,
+    /// The percentage the balance dropped by.
+    pub percentage: u8,
+    /// The threshold percentage that was reached.
+    pub threshold: u8,
+}
+
+/// The result of an automatic backup, see
+/// [`WalletBuilder::with_auto_backup()`](crate::wallet::WalletBuilder::with_auto_backup), emitted without an account
+/// index.
+#[cfg(feature = "stronghold")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AutoBackupEvent {
+    /// The path of the written backup.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<std::path::PathBuf>,
+    /// The error if the backup failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Describes what changed with [`Wallet::set_client_options()`](crate::wallet::Wallet::set_client_options), emitted
+/// without an account index.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClientOptionsChangedEvent {
+    /// The nodes changed, so the node pool got rebuilt.
+    pub nodes: bool,
+    /// The network info changed.
+    pub network_info: bool,
+    /// The MQTT broker options or the nodes changed, so the MQTT subscriptions got reconnected.
+    pub mqtt: bool,
+    /// The API or remote PoW timeout changed.
+    pub timeouts: bool,
+    /// The PoW worker count or the PoW provider changed.
+    pub pow: bool,
+    /// The new bech32 HRP, if the addresses of the accounts got updated to it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bech32_hrp: Option<Hrp>,
+}
+
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TransactionInclusionEvent {
@@ -102,12 +341,115 @@ pub enum TransactionProgressEvent {
     PreparedTransaction(Box<PreparedTransactionDataDto>),
     /// Prepared transaction essence hash hex encoded, required for blindsigning with a ledger nano
     PreparedTransactionEssenceHash(String),
+    /// Human-readable breakdown of the transaction that gets signed with a ledger nano, emitted before
+    /// [`TransactionProgressEvent::SigningTransaction`].
+    #[cfg(feature = "ledger_nano")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ledger_nano")))]
+    TransactionPreview(Box<TransactionPreview>),
     /// Signing the transaction.
     SigningTransaction,
     /// Performing PoW.
     PerformingPow,
     /// Broadcasting.
     Broadcasting,
+    /// A transaction of
+    /// [`Account::consolidate_all_outputs()`](crate::wallet::account::Account::consolidate_all_outputs)
+    /// was sent, the next one is prepared once it's included.
+    ConsolidationProgress(ConsolidationProgress),
+}
+
+/// The progress of consolidating outputs with multiple transactions.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConsolidationProgress {
+    /// The number of the sent consolidation transaction, starting at 1.
+    pub transaction_number: usize,
+    /// The id of the sent consolidation transaction.
+    pub transaction_id: TransactionId,
+    /// The amount of outputs consolidated by the transaction.
+    pub consolidated_outputs: usize,
+}
+
+/// A human-readable breakdown of a transaction, with the information a ledger nano displays.
+#[cfg(feature = "ledger_nano")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ledger_nano")))]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionPreview {
+    /// Whether the device can't display the outputs, so only the essence hash is shown for blind signing.
+    pub blind_signing: bool,
+    /// The outputs of the transaction, in the order of the essence.
+    pub outputs: Vec<OutputPreview>,
+}
+
+/// An output of a [`TransactionPreview`].
+#[cfg(feature = "ledger_nano")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ledger_nano")))]
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutputPreview {
+    /// The kind of the output: `basic`, `alias`, `foundry` or `nft`.
+    pub kind: String,
+    /// The address of the address unlock condition, not set for foundry outputs.
+    pub recipient: Option<Bech32Address>,
+    /// The amount of base coins.
+    #[serde(with = "crate::utils::serde::string")]
+    pub amount: u64,
+    /// The native tokens of the output.
+    pub native_tokens: Vec<NativeToken>,
+    /// The hex encoded data of the metadata feature.
+    pub metadata: Option<String>,
+    /// The hex encoded tag of the tag feature.
+    pub tag: Option<String>,
+    /// Whether the output is the remainder that goes back to the account.
+    pub remainder: bool,
+}
+
+#[cfg(feature = "ledger_nano")]
+impl TransactionPreview {
+    pub(crate) fn new(prepared_transaction_data: &PreparedTransactionData, hrp: Hrp, blind_signing: bool) -> Self {
+        let TransactionEssence::Regular(essence) = &prepared_transaction_data.essence;
+        let remainder = prepared_transaction_data
+            .remainder
+            .as_ref()
+            .map(|remainder| &remainder.output);
+
+        let outputs = essence
+            .outputs()
+            .iter()
+            .map(|output| {
+                let features = output.features();
+                OutputPreview {
+                    kind: match output {
+                        Output::Treasury(_) => "treasury",
+                        Output::Basic(_) => "basic",
+                        Output::Alias(_) => "alias",
+                        Output::Foundry(_) => "foundry",
+                        Output::Nft(_) => "nft",
+                    }
+                    .to_string(),
+                    recipient: output
+                        .unlock_conditions()
+                        .and_then(|unlock_conditions| unlock_conditions.address())
+                        .map(|unlock_condition| unlock_condition.address().to_bech32(hrp)),
+                    amount: output.amount(),
+                    native_tokens: output
+                        .native_tokens()
+                        .map(|native_tokens| native_tokens.iter().copied().collect())
+                        .unwrap_or_default(),
+                    metadata: features
+                        .and_then(|features| features.metadata())
+                        .map(|metadata| prefix_hex::encode(metadata.data())),
+                    tag: features
+                        .and_then(|features| features.tag())
+                        .map(|tag| prefix_hex::encode(tag.tag())),
+                    remainder: remainder == Some(output),
+                }
+            })
+            .collect();
+
+        Self { blind_signing, outputs }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
@@ -124,3 +466,84 @@ pub struct AddressData {
     #[getset(get = "pub")]
     pub address: Bech32Address,
 }
+
+#[cfg(all(test, feature = "ledger_nano"))]
+mod tests {
+    use super::*;
+    use crate::{
+        client::api::RemainderData,
+        types::block::{
+            address::Ed25519Address,
+            input::{Input, UtxoInput},
+            output::{
+                feature::{MetadataFeature, TagFeature},
+                unlock_condition::AddressUnlockCondition,
+                BasicOutputBuilder,
+            },
+            payload::transaction::RegularTransactionEssence,
+            protocol::ProtocolParameters,
+        },
+    };
+
+    #[test]
+    fn transaction_preview() {
+        let protocol_parameters = ProtocolParameters::default();
+        let token_supply = protocol_parameters.token_supply();
+        let recipient = Address::Ed25519(Ed25519Address::new([1; Ed25519Address::LENGTH]));
+        let remainder_address = Address::Ed25519(Ed25519Address::new([2; Ed25519Address::LENGTH]));
+
+        let output = BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(recipient))
+            .add_feature(MetadataFeature::new(vec![1, 2]).unwrap())
+            .add_feature(TagFeature::new(vec![3]).unwrap())
+            .finish_output(token_supply)
+            .unwrap();
+        let remainder = BasicOutputBuilder::new_with_amount(500_000)
+            .add_unlock_condition(AddressUnlockCondition::new(remainder_address))
+            .finish_output(token_supply)
+            .unwrap();
+        let essence = RegularTransactionEssence::builder(protocol_parameters.network_id(), [0; 32].into())
+            .with_inputs(vec![Input::Utxo(
+                UtxoInput::new(TransactionId::new([0; TransactionId::LENGTH]), 0).unwrap(),
+            )])
+            .with_outputs(vec![output, remainder.clone()])
+            .finish(&protocol_parameters)
+            .unwrap();
+        let prepared_transaction_data = PreparedTransactionData {
+            essence: TransactionEssence::Regular(essence),
+            inputs_data: Vec::new(),
+            remainder: Some(RemainderData {
+                output: remainder,
+                chain: None,
+                address: remainder_address,
+            }),
+        };
+
+        let hrp = Hrp::from_str_unchecked("rms");
+        let preview = TransactionPreview::new(&prepared_transaction_data, hrp, true);
+        assert!(preview.blind_signing);
+        assert_eq!(
+            preview.outputs,
+            vec![
+                OutputPreview {
+                    kind: "basic".to_string(),
+                    recipient: Some(recipient.to_bech32(hrp)),
+                    amount: 1_000_000,
+                    native_tokens: Vec::new(),
+                    metadata: Some("0x0102".to_string()),
+                    tag: Some("0x03".to_string()),
+                    remainder: false,
+                },
+                OutputPreview {
+                    kind: "basic".to_string(),
+                    recipient: Some(remainder_address.to_bech32(hrp)),
+                    amount: 500_000,
+                    native_tokens: Vec::new(),
+                    metadata: None,
+                    tag: None,
+                    remainder: true,
+                },
+            ]
+        );
+    }
+}
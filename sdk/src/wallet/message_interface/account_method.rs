@@ -1,18 +1,25 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::{BTreeMap, HashMap};
+
 use serde::{Deserialize, Serialize};
 
-#[cfg(feature = "participation")]
+#[cfg(feature = "wallet-participation")]
 use crate::wallet::account::types::participation::ParticipationEventRegistrationOptions;
-#[cfg(feature = "participation")]
+#[cfg(feature = "storage")]
+use crate::wallet::account::AuditLogFilter;
+#[cfg(feature = "wallet-participation")]
 use crate::{
     client::node_manager::node::Node,
     types::api::plugins::participation::types::{ParticipationEventId, ParticipationEventType},
 };
 use crate::{
     client::{
-        api::{PreparedTransactionDataDto, SignedTransactionDataDto},
+        api::{
+            input_selection::{Burn, BurnDto},
+            PreparedTransactionDataDto, SignedTransactionDataDto,
+        },
         secret::GenerateAddressOptions,
     },
     types::block::{
@@ -21,13 +28,14 @@ use crate::{
             dto::{NativeTokenDto, OutputDto, TokenSchemeDto},
             feature::dto::FeatureDto,
             unlock_condition::dto::UnlockConditionDto,
-            AliasId, FoundryId, NftId, OutputId, TokenId,
+            AliasId, ChainId, FoundryId, NftId, OutputId, TokenId,
         },
         payload::transaction::TransactionId,
     },
     wallet::{
         account::{
             operations::{
+                legacy_funds::LegacyAddressFunds,
                 output_claiming::OutputsToClaim,
                 syncing::SyncOptions,
                 transaction::{
@@ -39,9 +47,10 @@ use crate::{
                     TransactionOptionsDto,
                 },
             },
-            FilterOptions,
+            AlertThresholds, DustPolicy, FilterOptions, SpendingAllowance, TokenPolicy, TransactionExportFormat,
+            TransactionExportRange, TransactionRateLimit,
         },
-        SendAmountParams, SendNativeTokensParams, SendNftParams,
+        AssetTransfer, SendAmountParams, SendDecimalNativeTokensParams, SendNativeTokensParams, SendNftParams,
     },
     U256,
 };
@@ -125,15 +134,48 @@ pub enum AccountMethod {
         nft_id: NftId,
         options: Option<TransactionOptionsDto>,
     },
+    /// Check if an alias, foundry or nft output can be burned and return the reasons preventing it.
+    /// Expected response: [`BurnBlockers`](crate::wallet::message_interface::Response::BurnBlockers)
+    #[serde(rename_all = "camelCase")]
+    CanBurn { chain_id: ChainId },
     /// Consolidate outputs.
     /// Expected response: [`SentTransaction`](crate::wallet::message_interface::Response::SentTransaction)
+    #[cfg(feature = "wallet-consolidation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "wallet-consolidation")))]
     #[serde(rename_all = "camelCase")]
     ConsolidateOutputs {
         force: bool,
         output_consolidation_threshold: Option<usize>,
-    },
+        /// The address the consolidated outputs are sent to.
+        #[serde(default)]
+        target_address: Option<Bech32Address>,
+        /// Create a separate output for every native token.
+        #[serde(default)]
+        split_native_tokens: bool,
+    },
+    /// Consolidate all outputs with multiple transactions, waiting for the inclusion of each one.
+    /// Expected response: [`Transactions`](crate::wallet::message_interface::Response::Transactions)
+    #[cfg(feature = "wallet-consolidation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "wallet-consolidation")))]
+    #[serde(rename_all = "camelCase")]
+    ConsolidateAllOutputs {
+        force: bool,
+        output_consolidation_threshold: Option<usize>,
+        /// The address the consolidated outputs are sent to.
+        #[serde(default)]
+        target_address: Option<Bech32Address>,
+        /// Create a separate output for every native token.
+        #[serde(default)]
+        split_native_tokens: bool,
+    },
+    /// Consolidate the outputs if the account holds more micro outputs than its dust policy allows.
+    /// Expected response: [`Transaction`](crate::wallet::message_interface::Response::Transaction)
+    #[cfg(feature = "wallet-consolidation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "wallet-consolidation")))]
+    ConsolidateMicroOutputs,
     /// Create an alias output.
-    /// Expected response: [`SentTransaction`](crate::wallet::message_interface::Response::SentTransaction)
+    /// Expected response:
+    /// [`CreateAliasTransaction`](crate::wallet::message_interface::Response::CreateAliasTransaction)
     #[serde(rename_all = "camelCase")]
     CreateAliasOutput {
         params: Option<CreateAliasParamsDto>,
@@ -162,14 +204,38 @@ pub enum AccountMethod {
         amount: u32,
         options: Option<GenerateAddressOptions>,
     },
+    /// Get the audit log entries of the account matching the filter
+    /// Expected response: [`AuditLog`](crate::wallet::message_interface::Response::AuditLog)
+    #[cfg(feature = "storage")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
+    GetAuditLog { filter: AuditLogFilter },
     /// Get the [`OutputData`](crate::wallet::account::types::OutputData) of an output stored in the account
     /// Expected response: [`OutputData`](crate::wallet::message_interface::Response::OutputData)
     #[serde(rename_all = "camelCase")]
     GetOutput { output_id: OutputId },
+    /// Search for funds on addresses derived with a legacy coin type, like the one of Chrysalis wallets.
+    /// Expected response: [`LegacyAddressFunds`](crate::wallet::message_interface::Response::LegacyAddressFunds)
+    #[serde(rename_all = "camelCase")]
+    FindLegacyFunds {
+        legacy_coin_type: u32,
+        address_gap_limit: u32,
+    },
+    /// Follows the spend chain of an output to find out where its funds went
+    /// Expected response: [`OutputLineage`](crate::wallet::message_interface::Response::OutputLineage)
+    #[serde(rename_all = "camelCase")]
+    TraceOutput { output_id: OutputId },
     /// Get the [`Output`](crate::types::block::output::Output) that minted a native token by its TokenId
     /// Expected response: [`Output`](crate::wallet::message_interface::Response::Output)
     #[serde(rename_all = "camelCase")]
     GetFoundryOutput { token_id: TokenId },
+    /// Get the IRC30 metadata of a native token, the foundry is requested from the node if it isn't known yet
+    /// Expected response: [`NativeTokenMetadata`](crate::wallet::message_interface::Response::NativeTokenMetadata)
+    #[serde(rename_all = "camelCase")]
+    GetNativeTokenMetadata { token_id: TokenId },
+    /// Get the decoded immutable metadata of an NFT owned by the account
+    /// Expected response: [`NftMetadata`](crate::wallet::message_interface::Response::NftMetadata)
+    #[serde(rename_all = "camelCase")]
+    GetNftMetadata { nft_id: NftId },
     /// Get outputs with additional unlock conditions
     /// Expected response: [`OutputIds`](crate::wallet::message_interface::Response::OutputIds)
     #[serde(rename_all = "camelCase")]
@@ -187,18 +253,48 @@ pub enum AccountMethod {
     /// Expected response: [`Addresses`](crate::wallet::message_interface::Response::Addresses)
     /// List addresses.
     Addresses,
+    /// Returns the first address of the account with the given label.
+    /// Expected response: [`Address`](crate::wallet::message_interface::Response::Address)
+    FindAddressByLabel { label: String },
+    /// Returns the address that should be given out to receive funds, generates a new one if the address rotation is
+    /// enabled and the newest address is used.
+    /// Expected response: [`Address`](crate::wallet::message_interface::Response::Address)
+    CurrentReceiveAddress,
+    /// Enable or disable the rotation of receive addresses.
+    /// Expected response: [`Ok`](crate::wallet::message_interface::Response::Ok)
+    SetAddressRotation { enabled: bool },
+    /// Returns the receive addresses that got retired by the address rotation.
+    /// Expected response: [`RetiredAddresses`](crate::wallet::message_interface::Response::RetiredAddresses)
+    GetAddressRotationHistory,
+    /// Derives an address again with the secret manager and shows it on the device for Ledger Nano secret managers,
+    /// so it can be compared with the address displayed in the UI.
+    /// Expected response:
+    /// [`AddressDerivationProof`](crate::wallet::message_interface::Response::AddressDerivationProof)
+    VerifyAddressOnDevice { address: Bech32Address },
     /// Returns only addresses of the account with unspent outputs
     /// Expected response:
     /// [`AddressesWithUnspentOutputs`](crate::wallet::message_interface::Response::AddressesWithUnspentOutputs)
     AddressesWithUnspentOutputs,
     /// Returns all outputs of the account
-    /// Expected response: [`OutputsData`](crate::wallet::message_interface::Response::OutputsData)
+    /// Expected response: [`OutputsData`](crate::wallet::message_interface::Response::OutputsData), or
+    /// [`CompactOutputsData`](crate::wallet::message_interface::Response::CompactOutputsData) if `compact` is set
     #[serde(rename_all = "camelCase")]
-    Outputs { filter_options: Option<FilterOptions> },
+    Outputs {
+        filter_options: Option<FilterOptions>,
+        /// Only return a summary of every output instead of the full output.
+        #[serde(default)]
+        compact: bool,
+    },
     /// Returns all unspent outputs of the account
-    /// Expected response: [`OutputsData`](crate::wallet::message_interface::Response::OutputsData)
+    /// Expected response: [`OutputsData`](crate::wallet::message_interface::Response::OutputsData), or
+    /// [`CompactOutputsData`](crate::wallet::message_interface::Response::CompactOutputsData) if `compact` is set
     #[serde(rename_all = "camelCase")]
-    UnspentOutputs { filter_options: Option<FilterOptions> },
+    UnspentOutputs {
+        filter_options: Option<FilterOptions>,
+        /// Only return a summary of every output instead of the full output.
+        #[serde(default)]
+        compact: bool,
+    },
     /// Returns all incoming transactions of the account
     /// Expected response:
     /// [`Transactions`](crate::wallet::message_interface::Response::Transactions)
@@ -209,6 +305,12 @@ pub enum AccountMethod {
     /// Returns all pending transactions of the account
     /// Expected response: [`Transactions`](crate::wallet::message_interface::Response::Transactions)
     PendingTransactions,
+    /// Exports the confirmed and pending transactions of the account with their balance changes
+    /// Expected response: [`TransactionExport`](crate::wallet::message_interface::Response::TransactionExport)
+    ExportTransactions {
+        range: TransactionExportRange,
+        format: TransactionExportFormat,
+    },
     /// Melt native tokens. This happens with the foundry output which minted them, by increasing it's
     /// `melted_tokens` field.
     /// Expected response: [`SentTransaction`](crate::wallet::message_interface::Response::SentTransaction)
@@ -242,7 +344,7 @@ pub enum AccountMethod {
         options: Option<TransactionOptionsDto>,
     },
     /// Mint nft.
-    /// Expected response: [`SentTransaction`](crate::wallet::message_interface::Response::SentTransaction)
+    /// Expected response: [`MintNftTransaction`](crate::wallet::message_interface::Response::MintNftTransaction)
     #[serde(rename_all = "camelCase")]
     MintNfts {
         params: Vec<MintNftParamsDto>,
@@ -251,6 +353,37 @@ pub enum AccountMethod {
     /// Get account balance information.
     /// Expected response: [`Balance`](crate::wallet::message_interface::Response::Balance)
     GetBalance,
+    /// Analyze the unspent outputs and get maintenance recommendations.
+    /// Expected response: [`UtxoHealth`](crate::wallet::message_interface::Response::UtxoHealth)
+    GetUtxoHealth,
+    /// Report which addresses of the account were linked on-chain by spending their outputs together.
+    /// Expected response: [`AddressClustering`](crate::wallet::message_interface::Response::AddressClustering)
+    GetAddressClustering,
+    /// Get the spending allowances of the account.
+    /// Expected response: [`SpendingAllowances`](crate::wallet::message_interface::Response::SpendingAllowances)
+    GetSpendingAllowances,
+    /// Get the token policy of the account.
+    /// Expected response: [`TokenPolicy`](crate::wallet::message_interface::Response::TokenPolicy)
+    GetTokenPolicy,
+    /// Get the dust policy of the account.
+    /// Expected response: [`DustPolicy`](crate::wallet::message_interface::Response::DustPolicy)
+    GetDustPolicy,
+    /// Get the thresholds for alerts about large balance changes of the account.
+    /// Expected response: [`AlertThresholds`](crate::wallet::message_interface::Response::AlertThresholds)
+    GetAlertThresholds,
+    /// Get the status of the pool of unused internal addresses, which are used for remainder outputs.
+    /// Expected response:
+    /// [`InternalAddressPoolStatus`](crate::wallet::message_interface::Response::InternalAddressPoolStatus)
+    GetInternalAddressPoolStatus,
+    /// Get the rate limit for sending transactions of the account.
+    /// Expected response: [`RateLimit`](crate::wallet::message_interface::Response::RateLimit)
+    GetRateLimit,
+    /// Get the primary alias of the account, whose address holds its funds.
+    /// Expected response: [`PrimaryAlias`](crate::wallet::message_interface::Response::PrimaryAlias)
+    GetPrimaryAlias,
+    /// Get the address that receives the funds of the account, the address of the primary alias if one is set.
+    /// Expected response: [`Bech32Address`](crate::wallet::message_interface::Response::Bech32Address)
+    GetPrimaryAddress,
     /// Prepare an output.
     /// Expected response: [`Output`](crate::wallet::message_interface::Response::Output)
     #[serde(rename_all = "camelCase")]
@@ -258,12 +391,26 @@ pub enum AccountMethod {
         params: Box<OutputParamsDto>,
         transaction_options: Option<TransactionOptionsDto>,
     },
+    /// Estimate the amounts and storage deposits of a transaction, without locking any inputs.
+    /// Expected response: [`TransactionEstimate`](crate::wallet::message_interface::Response::TransactionEstimate)
+    EstimateTransaction {
+        outputs: Vec<OutputDto>,
+        options: Option<TransactionOptionsDto>,
+    },
     /// Prepare transaction.
     /// Expected response: [`PreparedTransaction`](crate::wallet::message_interface::Response::PreparedTransaction)
     PrepareTransaction {
         outputs: Vec<OutputDto>,
         options: Option<TransactionOptionsDto>,
     },
+    /// Prepare a transaction that sweeps funds found with
+    /// [`FindLegacyFunds`](crate::wallet::message_interface::AccountMethod::FindLegacyFunds) into the account.
+    /// Expected response: [`PreparedTransaction`](crate::wallet::message_interface::Response::PreparedTransaction)
+    #[serde(rename_all = "camelCase")]
+    PrepareSweepLegacyFunds {
+        legacy_funds: Vec<LegacyAddressFunds>,
+        options: Option<TransactionOptionsDto>,
+    },
     /// Prepare send amount.
     /// Expected response: [`PreparedTransaction`](crate::wallet::message_interface::Response::PreparedTransaction)
     #[serde(rename_all = "camelCase")]
@@ -271,6 +418,111 @@ pub enum AccountMethod {
         params: Vec<SendAmountParams>,
         options: Option<TransactionOptionsDto>,
     },
+    /// Prepare sending base coins, native tokens and nfts to multiple addresses in one transaction.
+    /// Expected response: [`PreparedTransaction`](crate::wallet::message_interface::Response::PreparedTransaction)
+    #[serde(rename_all = "camelCase")]
+    PrepareSendAssets {
+        transfers: Vec<AssetTransfer>,
+        options: Option<TransactionOptionsDto>,
+    },
+    /// Prepare sending the outputs of a transaction template.
+    /// Expected response: [`PreparedTransaction`](crate::wallet::message_interface::Response::PreparedTransaction)
+    PrepareSendFromTemplate {
+        name: String,
+        params: HashMap<String, String>,
+    },
+    /// Prepare burning native tokens, nfts, foundries and aliases. Covers the prepared counterparts of
+    /// [`BurnNativeToken`](AccountMethod::BurnNativeToken), [`BurnNft`](AccountMethod::BurnNft),
+    /// [`DestroyAlias`](AccountMethod::DestroyAlias) and [`DestroyFoundry`](AccountMethod::DestroyFoundry).
+    /// Expected response: [`PreparedTransaction`](crate::wallet::message_interface::Response::PreparedTransaction)
+    #[serde(rename_all = "camelCase")]
+    PrepareBurn {
+        burn: BurnDto,
+        options: Option<TransactionOptionsDto>,
+    },
+    /// Prepare claiming outputs.
+    /// Expected response: [`PreparedTransaction`](crate::wallet::message_interface::Response::PreparedTransaction)
+    #[serde(rename_all = "camelCase")]
+    PrepareClaimOutputs { output_ids_to_claim: Vec<OutputId> },
+    /// Prepare consolidating outputs.
+    /// Expected response: [`PreparedTransaction`](crate::wallet::message_interface::Response::PreparedTransaction)
+    #[cfg(feature = "wallet-consolidation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "wallet-consolidation")))]
+    #[serde(rename_all = "camelCase")]
+    PrepareConsolidateOutputs {
+        force: bool,
+        output_consolidation_threshold: Option<usize>,
+        /// The address the consolidated outputs are sent to.
+        #[serde(default)]
+        target_address: Option<Bech32Address>,
+        /// Create a separate output for every native token.
+        #[serde(default)]
+        split_native_tokens: bool,
+    },
+    /// Prepare creating an alias output.
+    /// Expected response: [`PreparedTransaction`](crate::wallet::message_interface::Response::PreparedTransaction)
+    #[serde(rename_all = "camelCase")]
+    PrepareCreateAliasOutput {
+        params: Option<CreateAliasParamsDto>,
+        options: Option<TransactionOptionsDto>,
+    },
+    /// Prepare melting native tokens.
+    /// Expected response: [`PreparedTransaction`](crate::wallet::message_interface::Response::PreparedTransaction)
+    #[serde(rename_all = "camelCase")]
+    PrepareDecreaseNativeTokenSupply {
+        /// Native token id
+        token_id: TokenId,
+        /// To be melted amount
+        melt_amount: U256,
+        options: Option<TransactionOptionsDto>,
+    },
+    /// Prepare minting more native tokens.
+    /// Expected response:
+    /// [`PreparedMintTokenTransaction`](crate::wallet::message_interface::Response::PreparedMintTokenTransaction)
+    #[serde(rename_all = "camelCase")]
+    PrepareIncreaseNativeTokenSupply {
+        /// Native token id
+        token_id: TokenId,
+        /// To be minted amount
+        mint_amount: U256,
+        options: Option<TransactionOptionsDto>,
+    },
+    /// Prepare minting a native token.
+    /// Expected response:
+    /// [`PreparedMintTokenTransaction`](crate::wallet::message_interface::Response::PreparedMintTokenTransaction)
+    #[serde(rename_all = "camelCase")]
+    PrepareMintNativeToken {
+        params: MintNativeTokenParamsDto,
+        options: Option<TransactionOptionsDto>,
+    },
+    /// Prepare minting nfts.
+    /// Expected response: [`PreparedTransaction`](crate::wallet::message_interface::Response::PreparedTransaction)
+    #[serde(rename_all = "camelCase")]
+    PrepareMintNfts {
+        params: Vec<MintNftParamsDto>,
+        options: Option<TransactionOptionsDto>,
+    },
+    /// Prepare sending native tokens.
+    /// Expected response: [`PreparedTransaction`](crate::wallet::message_interface::Response::PreparedTransaction)
+    #[serde(rename_all = "camelCase")]
+    PrepareSendNativeTokens {
+        params: Vec<SendNativeTokensParams>,
+        options: Option<TransactionOptionsDto>,
+    },
+    /// Prepare sending native tokens with decimal amounts.
+    /// Expected response: [`PreparedTransaction`](crate::wallet::message_interface::Response::PreparedTransaction)
+    #[serde(rename_all = "camelCase")]
+    PrepareSendDecimalNativeTokens {
+        params: Vec<SendDecimalNativeTokensParams>,
+        options: Option<TransactionOptionsDto>,
+    },
+    /// Prepare sending nfts.
+    /// Expected response: [`PreparedTransaction`](crate::wallet::message_interface::Response::PreparedTransaction)
+    #[serde(rename_all = "camelCase")]
+    PrepareSendNft {
+        params: Vec<SendNftParams>,
+        options: Option<TransactionOptionsDto>,
+    },
     /// Retries (promotes or reattaches) a transaction sent from the account for a provided transaction id until it's
     /// included (referenced by a milestone). Returns the included block id.
     /// Expected response: [`BlockId`](crate::wallet::message_interface::Response::BlockId)
@@ -283,6 +535,17 @@ pub enum AccountMethod {
         /// Maximum attempts
         max_attempts: Option<u64>,
     },
+    /// Waits until all transactions sent by the account up to and including the sequence number are confirmed.
+    /// Expected response: [`Ok`](crate::wallet::message_interface::Response::Ok)
+    #[serde(rename_all = "camelCase")]
+    WaitForSequenceConfirmed {
+        /// Sequence number of the last transaction to wait for
+        sequence_number: u64,
+        /// Interval
+        interval: Option<u64>,
+        /// Maximum attempts
+        max_attempts: Option<u64>,
+    },
     /// Sync the account by fetching new information from the nodes. Will also retry pending transactions
     /// if necessary. A custom default can be set using SetDefaultSyncOptions.
     /// Expected response: [`Balance`](crate::wallet::message_interface::Response::Balance)
@@ -297,6 +560,19 @@ pub enum AccountMethod {
         params: Vec<SendAmountParams>,
         options: Option<TransactionOptionsDto>,
     },
+    /// Send base coins, native tokens and nfts to multiple addresses in one transaction.
+    /// Expected response: [`SentTransaction`](crate::wallet::message_interface::Response::SentTransaction)
+    #[serde(rename_all = "camelCase")]
+    SendAssets {
+        transfers: Vec<AssetTransfer>,
+        options: Option<TransactionOptionsDto>,
+    },
+    /// Send the outputs of a transaction template, the placeholders are replaced by the params with the same name.
+    /// Expected response: [`SentTransaction`](crate::wallet::message_interface::Response::SentTransaction)
+    SendFromTemplate {
+        name: String,
+        params: HashMap<String, String>,
+    },
     /// Send native tokens.
     /// Expected response: [`SentTransaction`](crate::wallet::message_interface::Response::SentTransaction)
     #[serde(rename_all = "camelCase")]
@@ -304,6 +580,13 @@ pub enum AccountMethod {
         params: Vec<SendNativeTokensParams>,
         options: Option<TransactionOptionsDto>,
     },
+    /// Send native tokens with decimal amounts.
+    /// Expected response: [`SentTransaction`](crate::wallet::message_interface::Response::SentTransaction)
+    #[serde(rename_all = "camelCase")]
+    SendDecimalNativeTokens {
+        params: Vec<SendDecimalNativeTokensParams>,
+        options: Option<TransactionOptionsDto>,
+    },
     /// Send nft.
     /// Expected response: [`SentTransaction`](crate::wallet::message_interface::Response::SentTransaction)
     #[serde(rename_all = "camelCase")]
@@ -311,9 +594,61 @@ pub enum AccountMethod {
         params: Vec<SendNftParams>,
         options: Option<TransactionOptionsDto>,
     },
+    /// Send the entire balance of the account to an address, NFTs and aliases are only transferred if enabled.
+    /// Expected response: [`Transactions`](crate::wallet::message_interface::Response::Transactions)
+    #[serde(rename_all = "camelCase")]
+    Sweep {
+        address: Bech32Address,
+        #[serde(default)]
+        nfts: bool,
+        #[serde(default)]
+        aliases: bool,
+        options: Option<TransactionOptionsDto>,
+    },
     /// Set the alias of the account.
     /// Expected response: [`Ok`](crate::wallet::message_interface::Response::Ok)
     SetAlias { alias: String },
+    /// Set or remove the label of an address of the account.
+    /// Expected response: [`Address`](crate::wallet::message_interface::Response::Address)
+    SetAddressLabel {
+        address: Bech32Address,
+        label: Option<String>,
+    },
+    /// Replace the metadata of an address of the account.
+    /// Expected response: [`Address`](crate::wallet::message_interface::Response::Address)
+    SetAddressMetadata {
+        address: Bech32Address,
+        metadata: BTreeMap<String, String>,
+    },
+    /// Add a spending allowance, replacing an existing one with the same id.
+    /// Expected response: [`Ok`](crate::wallet::message_interface::Response::Ok)
+    SetSpendingAllowance { allowance: SpendingAllowance },
+    /// Remove a spending allowance.
+    /// Expected response: [`SpendingAllowance`](crate::wallet::message_interface::Response::SpendingAllowance)
+    RemoveSpendingAllowance { id: String },
+    /// Set the token policy of the account, `None` removes it.
+    /// Expected response: [`Ok`](crate::wallet::message_interface::Response::Ok)
+    #[serde(rename_all = "camelCase")]
+    SetTokenPolicy { token_policy: Option<TokenPolicy> },
+    /// Set the dust policy of the account, `None` removes it.
+    /// Expected response: [`Ok`](crate::wallet::message_interface::Response::Ok)
+    #[serde(rename_all = "camelCase")]
+    SetDustPolicy { dust_policy: Option<DustPolicy> },
+    /// Set the thresholds for alerts about large balance changes of the account, `None` removes them.
+    /// Expected response: [`Ok`](crate::wallet::message_interface::Response::Ok)
+    #[serde(rename_all = "camelCase")]
+    SetAlertThresholds { alert_thresholds: Option<AlertThresholds> },
+    /// Set the rate limit for sending transactions of the account, `None` removes it.
+    /// Expected response: [`Ok`](crate::wallet::message_interface::Response::Ok)
+    #[serde(rename_all = "camelCase")]
+    SetRateLimit { rate_limit: Option<TransactionRateLimit> },
+    /// Enable or disable the sequence numbers of sent transactions.
+    /// Expected response: [`Ok`](crate::wallet::message_interface::Response::Ok)
+    SetTransactionSequencing { enabled: bool },
+    /// Set the primary alias of the account, whose address holds its funds, `None` removes it.
+    /// Expected response: [`Ok`](crate::wallet::message_interface::Response::Ok)
+    #[serde(rename_all = "camelCase")]
+    SetPrimaryAlias { alias_id: Option<AliasId> },
     /// Set the fallback SyncOptions for account syncing.
     /// If storage is enabled, will persist during restarts.
     /// Expected response: [`Ok`](crate::Response::Ok)
@@ -342,8 +677,8 @@ pub enum AccountMethod {
     ClaimOutputs { output_ids_to_claim: Vec<OutputId> },
     /// Vote for a participation event.
     /// Expected response: [`SentTransaction`](crate::wallet::message_interface::Response::SentTransaction)
-    #[cfg(feature = "participation")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
+    #[cfg(feature = "wallet-participation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "wallet-participation")))]
     #[serde(rename_all = "camelCase")]
     Vote {
         event_id: Option<ParticipationEventId>,
@@ -351,16 +686,16 @@ pub enum AccountMethod {
     },
     /// Stop participating for an event.
     /// Expected response: [`SentTransaction`](crate::wallet::message_interface::Response::SentTransaction)
-    #[cfg(feature = "participation")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
+    #[cfg(feature = "wallet-participation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "wallet-participation")))]
     #[serde(rename_all = "camelCase")]
     StopParticipating { event_id: ParticipationEventId },
     /// Calculates a participation overview for an account. If event_ids are provided, only return outputs and tracked
     /// participations for them.
     /// Expected response:
     /// [`AccountParticipationOverview`](crate::wallet::message_interface::Response::AccountParticipationOverview)
-    #[cfg(feature = "participation")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
+    #[cfg(feature = "wallet-participation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "wallet-participation")))]
     #[serde(rename_all = "camelCase")]
     GetParticipationOverview {
         event_ids: Option<Vec<ParticipationEventId>>,
@@ -370,38 +705,86 @@ pub enum AccountMethod {
     /// This will stop voting in most cases (if there is a remainder output), but the voting data isn't lost and
     /// calling `Vote` without parameters will revote. Expected response:
     /// [`SentTransaction`](crate::wallet::message_interface::Response::SentTransaction)
-    #[cfg(feature = "participation")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
+    #[cfg(feature = "wallet-participation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "wallet-participation")))]
     IncreaseVotingPower { amount: String },
     /// Reduces an account's "voting power" by a given amount.
     /// This will stop voting, but the voting data isn't lost and calling `Vote` without parameters will revote.
     /// Expected response: [`SentTransaction`](crate::wallet::message_interface::Response::SentTransaction)
-    #[cfg(feature = "participation")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
+    #[cfg(feature = "wallet-participation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "wallet-participation")))]
     DecreaseVotingPower { amount: String },
+    /// Sets an account's "voting power" to the given amount, by increasing or decreasing it as needed.
+    /// Expected response: [`SentTransaction`](crate::wallet::message_interface::Response::SentTransaction), or
+    /// [`Ok`](crate::wallet::message_interface::Response::Ok) if the voting power already equals the amount
+    #[cfg(feature = "wallet-participation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "wallet-participation")))]
+    SetVotingPower { amount: String },
+    /// Set whether the participations are re-applied automatically when the voting power changes.
+    /// Expected response: [`Ok`](crate::wallet::message_interface::Response::Ok)
+    #[cfg(feature = "wallet-participation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "wallet-participation")))]
+    #[serde(rename_all = "camelCase")]
+    SetAutoRevote { auto_revote: bool },
+    /// Get whether the participations are re-applied automatically when the voting power changes.
+    /// Expected response: [`AutoRevote`](crate::wallet::message_interface::Response::AutoRevote)
+    #[cfg(feature = "wallet-participation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "wallet-participation")))]
+    GetAutoRevote,
+    /// Prepare voting for a participation event.
+    /// Expected response: [`PreparedTransaction`](crate::wallet::message_interface::Response::PreparedTransaction)
+    #[cfg(feature = "wallet-participation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "wallet-participation")))]
+    #[serde(rename_all = "camelCase")]
+    PrepareVote {
+        event_id: Option<ParticipationEventId>,
+        answers: Option<Vec<u8>>,
+    },
+    /// Prepare stopping participating for an event.
+    /// Expected response: [`PreparedTransaction`](crate::wallet::message_interface::Response::PreparedTransaction)
+    #[cfg(feature = "wallet-participation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "wallet-participation")))]
+    #[serde(rename_all = "camelCase")]
+    PrepareStopParticipating { event_id: ParticipationEventId },
+    /// Prepare increasing an account's "voting power".
+    /// Expected response: [`PreparedTransaction`](crate::wallet::message_interface::Response::PreparedTransaction)
+    #[cfg(feature = "wallet-participation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "wallet-participation")))]
+    PrepareIncreaseVotingPower { amount: String },
+    /// Prepare decreasing an account's "voting power".
+    /// Expected response: [`PreparedTransaction`](crate::wallet::message_interface::Response::PreparedTransaction)
+    #[cfg(feature = "wallet-participation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "wallet-participation")))]
+    PrepareDecreaseVotingPower { amount: String },
+    /// Prepare setting an account's "voting power" to the given amount.
+    /// Expected response: [`PreparedTransaction`](crate::wallet::message_interface::Response::PreparedTransaction), or
+    /// [`Ok`](crate::wallet::message_interface::Response::Ok) if the voting power already equals the amount
+    #[cfg(feature = "wallet-participation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "wallet-participation")))]
+    PrepareSetVotingPower { amount: String },
     /// Stores participation information locally and returns the event.
     ///
     /// This will NOT store the node url and auth inside the client options.
     /// Expected response: [`ParticipationEvents`](crate::wallet::message_interface::Response::ParticipationEvents)
-    #[cfg(feature = "participation")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
+    #[cfg(feature = "wallet-participation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "wallet-participation")))]
     RegisterParticipationEvents {
         options: ParticipationEventRegistrationOptions,
     },
     /// Removes a previously registered participation event from local storage.
     /// Expected response: [`Ok`](crate::wallet::message_interface::Response::Ok)
-    #[cfg(feature = "participation")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
+    #[cfg(feature = "wallet-participation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "wallet-participation")))]
     #[serde(rename_all = "camelCase")]
     DeregisterParticipationEvent { event_id: ParticipationEventId },
     /// Expected response: [`ParticipationEvent`](crate::wallet::message_interface::Response::ParticipationEvent)
-    #[cfg(feature = "participation")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
+    #[cfg(feature = "wallet-participation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "wallet-participation")))]
     #[serde(rename_all = "camelCase")]
     GetParticipationEvent { event_id: ParticipationEventId },
     /// Expected response: [`ParticipationEventIds`](crate::wallet::message_interface::Response::ParticipationEventIds)
-    #[cfg(feature = "participation")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
+    #[cfg(feature = "wallet-participation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "wallet-participation")))]
     #[serde(rename_all = "camelCase")]
     GetParticipationEventIds {
         node: Node,
@@ -409,14 +792,230 @@ pub enum AccountMethod {
     },
     /// Expected response:
     /// [`ParticipationEventStatus`](crate::wallet::message_interface::Response::ParticipationEventStatus)
-    #[cfg(feature = "participation")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
+    #[cfg(feature = "wallet-participation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "wallet-participation")))]
     #[serde(rename_all = "camelCase")]
     GetParticipationEventStatus { event_id: ParticipationEventId },
     /// Expected response: [`ParticipationEvents`](crate::wallet::message_interface::Response::ParticipationEvents)
-    #[cfg(feature = "participation")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
+    #[cfg(feature = "wallet-participation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "wallet-participation")))]
     GetParticipationEvents,
     /// Expected response: [`Faucet`](crate::wallet::message_interface::Response::Faucet)
     RequestFundsFromFaucet { url: String, address: Bech32Address },
 }
+
+impl AccountMethod {
+    /// Returns whether the method changes the account or sends a transaction and is therefore recorded in the audit
+    /// log. Every method is listed explicitly, since the read-only access of the RPC service relies on it.
+    pub fn is_mutating(&self) -> bool {
+        match self {
+            Self::BuildAliasOutput { .. }
+            | Self::BuildBasicOutput { .. }
+            | Self::BuildFoundryOutput { .. }
+            | Self::BuildNftOutput { .. }
+            | Self::CanBurn { .. }
+            | Self::GetOutput { .. }
+            | Self::TraceOutput { .. }
+            | Self::FindLegacyFunds { .. }
+            | Self::GetFoundryOutput { .. }
+            | Self::GetNativeTokenMetadata { .. }
+            | Self::GetNftMetadata { .. }
+            | Self::GetOutputsWithAdditionalUnlockConditions { .. }
+            | Self::GetTransaction { .. }
+            | Self::GetIncomingTransaction { .. }
+            | Self::Addresses
+            | Self::FindAddressByLabel { .. }
+            | Self::GetAddressRotationHistory
+            | Self::VerifyAddressOnDevice { .. }
+            | Self::AddressesWithUnspentOutputs
+            | Self::Outputs { .. }
+            | Self::UnspentOutputs { .. }
+            | Self::IncomingTransactions
+            | Self::Transactions
+            | Self::PendingTransactions
+            | Self::WaitForSequenceConfirmed { .. }
+            | Self::ExportTransactions { .. }
+            | Self::MinimumRequiredStorageDeposit { .. }
+            | Self::EstimateTransaction { .. }
+            | Self::GetBalance
+            | Self::GetUtxoHealth
+            | Self::GetAddressClustering
+            | Self::GetSpendingAllowances
+            | Self::GetTokenPolicy
+            | Self::GetDustPolicy
+            | Self::GetAlertThresholds
+            | Self::GetInternalAddressPoolStatus
+            | Self::GetRateLimit
+            | Self::GetPrimaryAlias
+            | Self::GetPrimaryAddress
+            | Self::PrepareOutput { .. }
+            | Self::PrepareTransaction { .. }
+            | Self::PrepareSendAmount { .. }
+            | Self::PrepareSendAssets { .. }
+            | Self::PrepareSendFromTemplate { .. }
+            | Self::PrepareSweepLegacyFunds { .. }
+            | Self::PrepareBurn { .. }
+            | Self::PrepareClaimOutputs { .. }
+            | Self::PrepareCreateAliasOutput { .. }
+            | Self::PrepareDecreaseNativeTokenSupply { .. }
+            | Self::PrepareIncreaseNativeTokenSupply { .. }
+            | Self::PrepareMintNativeToken { .. }
+            | Self::PrepareMintNfts { .. }
+            | Self::PrepareSendNativeTokens { .. }
+            | Self::PrepareSendDecimalNativeTokens { .. }
+            | Self::PrepareSendNft { .. }
+            // Syncing only mirrors the ledger state
+            | Self::SyncAccount { .. }
+            | Self::RequestFundsFromFaucet { .. } => false,
+            #[cfg(feature = "storage")]
+            Self::GetAuditLog { .. } => false,
+            #[cfg(feature = "wallet-consolidation")]
+            Self::PrepareConsolidateOutputs { .. } => false,
+            #[cfg(feature = "wallet-participation")]
+            Self::GetParticipationOverview { .. }
+            | Self::GetAutoRevote
+            | Self::GetParticipationEvent { .. }
+            | Self::GetParticipationEventIds { .. }
+            | Self::GetParticipationEventStatus { .. }
+            | Self::GetParticipationEvents
+            | Self::PrepareVote { .. }
+            | Self::PrepareStopParticipating { .. }
+            | Self::PrepareIncreaseVotingPower { .. }
+            | Self::PrepareDecreaseVotingPower { .. }
+            | Self::PrepareSetVotingPower { .. } => false,
+            Self::BurnNativeToken { .. }
+            | Self::BurnNft { .. }
+            | Self::CreateAliasOutput { .. }
+            | Self::DestroyAlias { .. }
+            | Self::DestroyFoundry { .. }
+            // Generating addresses adds them to the account
+            | Self::GenerateAddresses { .. }
+            | Self::CurrentReceiveAddress
+            | Self::SetAddressRotation { .. }
+            | Self::DecreaseNativeTokenSupply { .. }
+            | Self::IncreaseNativeTokenSupply { .. }
+            | Self::MintNativeToken { .. }
+            | Self::MintNfts { .. }
+            // Reissues blocks
+            | Self::RetryTransactionUntilIncluded { .. }
+            | Self::SendAmount { .. }
+            | Self::SendAssets { .. }
+            | Self::SendFromTemplate { .. }
+            | Self::SendNativeTokens { .. }
+            | Self::SendDecimalNativeTokens { .. }
+            | Self::SendNft { .. }
+            | Self::Sweep { .. }
+            | Self::SetAlias { .. }
+            | Self::SetAddressLabel { .. }
+            | Self::SetAddressMetadata { .. }
+            | Self::SetSpendingAllowance { .. }
+            | Self::RemoveSpendingAllowance { .. }
+            | Self::SetTokenPolicy { .. }
+            | Self::SetDustPolicy { .. }
+            | Self::SetAlertThresholds { .. }
+            | Self::SetRateLimit { .. }
+            | Self::SetTransactionSequencing { .. }
+            | Self::SetPrimaryAlias { .. }
+            | Self::SetDefaultSyncOptions { .. }
+            | Self::SendOutputs { .. }
+            // Uses the keys of the account
+            | Self::SignTransactionEssence { .. }
+            | Self::SubmitAndStoreTransaction { .. }
+            | Self::ClaimOutputs { .. } => true,
+            #[cfg(feature = "wallet-consolidation")]
+            Self::ConsolidateOutputs { .. }
+            | Self::ConsolidateAllOutputs { .. }
+            | Self::ConsolidateMicroOutputs => true,
+            #[cfg(feature = "wallet-participation")]
+            Self::Vote { .. }
+            | Self::StopParticipating { .. }
+            | Self::IncreaseVotingPower { .. }
+            | Self::DecreaseVotingPower { .. }
+            | Self::SetVotingPower { .. }
+            | Self::SetAutoRevote { .. }
+            | Self::RegisterParticipationEvents { .. }
+            | Self::DeregisterParticipationEvent { .. } => true,
+        }
+    }
+
+    /// Returns the method that only prepares the transaction of a mutating method, used for dry runs. Methods without
+    /// one are returned as error.
+    pub(crate) fn into_prepare_method(self) -> Result<Self, Self> {
+        Ok(match self {
+            Self::BurnNativeToken {
+                token_id,
+                burn_amount,
+                options,
+            } => Self::PrepareBurn {
+                burn: BurnDto::from(&Burn::new().add_native_token(token_id, burn_amount)),
+                options,
+            },
+            Self::BurnNft { nft_id, options } => Self::PrepareBurn {
+                burn: BurnDto::from(&Burn::from(nft_id)),
+                options,
+            },
+            #[cfg(feature = "wallet-consolidation")]
+            Self::ConsolidateOutputs {
+                force,
+                output_consolidation_threshold,
+                target_address,
+                split_native_tokens,
+            } => Self::PrepareConsolidateOutputs {
+                force,
+                output_consolidation_threshold,
+                target_address,
+                split_native_tokens,
+            },
+            Self::CreateAliasOutput { params, options } => Self::PrepareCreateAliasOutput { params, options },
+            Self::DestroyAlias { alias_id, options } => Self::PrepareBurn {
+                burn: BurnDto::from(&Burn::from(alias_id)),
+                options,
+            },
+            Self::DestroyFoundry { foundry_id, options } => Self::PrepareBurn {
+                burn: BurnDto::from(&Burn::from(foundry_id)),
+                options,
+            },
+            Self::DecreaseNativeTokenSupply {
+                token_id,
+                melt_amount,
+                options,
+            } => Self::PrepareDecreaseNativeTokenSupply {
+                token_id,
+                melt_amount,
+                options,
+            },
+            Self::IncreaseNativeTokenSupply {
+                token_id,
+                mint_amount,
+                options,
+            } => Self::PrepareIncreaseNativeTokenSupply {
+                token_id,
+                mint_amount,
+                options,
+            },
+            Self::MintNativeToken { params, options } => Self::PrepareMintNativeToken { params, options },
+            Self::MintNfts { params, options } => Self::PrepareMintNfts { params, options },
+            Self::SendAmount { params, options } => Self::PrepareSendAmount { params, options },
+            Self::SendAssets { transfers, options } => Self::PrepareSendAssets { transfers, options },
+            Self::SendFromTemplate { name, params } => Self::PrepareSendFromTemplate { name, params },
+            Self::ClaimOutputs { output_ids_to_claim } => Self::PrepareClaimOutputs { output_ids_to_claim },
+            Self::SendNativeTokens { params, options } => Self::PrepareSendNativeTokens { params, options },
+            Self::SendDecimalNativeTokens { params, options } => {
+                Self::PrepareSendDecimalNativeTokens { params, options }
+            }
+            Self::SendNft { params, options } => Self::PrepareSendNft { params, options },
+            Self::SendOutputs { outputs, options } => Self::PrepareTransaction { outputs, options },
+            #[cfg(feature = "wallet-participation")]
+            Self::Vote { event_id, answers } => Self::PrepareVote { event_id, answers },
+            #[cfg(feature = "wallet-participation")]
+            Self::StopParticipating { event_id } => Self::PrepareStopParticipating { event_id },
+            #[cfg(feature = "wallet-participation")]
+            Self::IncreaseVotingPower { amount } => Self::PrepareIncreaseVotingPower { amount },
+            #[cfg(feature = "wallet-participation")]
+            Self::DecreaseVotingPower { amount } => Self::PrepareDecreaseVotingPower { amount },
+            #[cfg(feature = "wallet-participation")]
+            Self::SetVotingPower { amount } => Self::PrepareSetVotingPower { amount },
+            method => return Err(method),
+        })
+    }
+}
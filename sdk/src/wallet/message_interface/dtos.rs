@@ -9,11 +9,11 @@ use serde::{Deserialize, Serialize};
 
 use crate::{
     types::block::{
-        output::{dto::FoundryOutputDto, FoundryId, OutputId},
+        output::{dto::FoundryOutputDto, AliasId, FoundryId, OutputId},
         payload::transaction::TransactionId,
     },
     wallet::account::{
-        types::{AccountAddress, AddressWithUnspentOutputs, TransactionDto},
+        types::{AccountAddress, AccountId, AddressWithUnspentOutputs, AliasChange, TransactionDto},
         AccountDetails, OutputDataDto,
     },
 };
@@ -24,10 +24,16 @@ use crate::{
 pub struct AccountDetailsDto {
     /// The account index
     pub index: u32,
+    /// The stable account id
+    #[serde(default)]
+    pub account_id: AccountId,
     /// The coin type
     pub coin_type: u32,
     /// The account alias.
     pub alias: String,
+    /// Previous aliases of the account
+    #[serde(default)]
+    pub alias_history: Vec<AliasChange>,
     /// Public addresses
     pub public_addresses: Vec<AccountAddress>,
     /// Internal addresses
@@ -49,14 +55,25 @@ pub struct AccountDetailsDto {
     /// Foundries for native tokens in outputs
     #[serde(default)]
     pub native_token_foundries: HashMap<FoundryId, FoundryOutputDto>,
+    /// Whether the account is watch-only
+    #[serde(default)]
+    pub watch_only: bool,
+    /// The alias whose address holds the funds of the account
+    #[serde(default)]
+    pub primary_alias: Option<AliasId>,
+    /// Whether the participations are re-applied when the voting power changes
+    #[serde(default)]
+    pub auto_revote: bool,
 }
 
 impl From<&AccountDetails> for AccountDetailsDto {
     fn from(value: &AccountDetails) -> Self {
         Self {
             index: *value.index(),
+            account_id: *value.account_id(),
             coin_type: *value.coin_type(),
             alias: value.alias().clone(),
+            alias_history: value.alias_history().clone(),
             public_addresses: value.public_addresses.clone(),
             internal_addresses: value.internal_addresses.clone(),
             addresses_with_unspent_outputs: value.addresses_with_unspent_outputs().clone(),
@@ -87,6 +104,27 @@ impl From<&AccountDetails> for AccountDetailsDto {
                 .iter()
                 .map(|(id, foundry)| (*id, FoundryOutputDto::from(foundry)))
                 .collect(),
+            watch_only: *value.watch_only(),
+            primary_alias: *value.primary_alias(),
+            auto_revote: *value.auto_revote(),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn account_details_dto_without_account_id() {
+        let mut account_details = AccountDetails::mock();
+        account_details.account_id = AccountId::generate().unwrap();
+        let mut json = serde_json::to_value(AccountDetailsDto::from(&account_details)).unwrap();
+        assert_eq!(json["accountId"], account_details.account_id.to_string());
+
+        // Data from before account ids existed
+        json.as_object_mut().unwrap().remove("accountId");
+        let dto = serde_json::from_value::<AccountDetailsDto>(json).unwrap();
+        assert!(dto.account_id.is_nil());
+    }
+}
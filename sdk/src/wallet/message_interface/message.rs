@@ -1,21 +1,33 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::fmt::{Debug, Formatter, Result};
-#[cfg(feature = "stronghold")]
-use std::path::PathBuf;
+use std::{
+    fmt::{Debug, Formatter, Result},
+    path::PathBuf,
+};
 
 use serde::{Deserialize, Serialize};
 
 use super::account_method::AccountMethod;
+#[cfg(feature = "wallet-participation")]
+use crate::types::api::plugins::participation::types::ParticipationEventId;
 #[cfg(feature = "events")]
 use crate::wallet::events::types::{WalletEvent, WalletEventType};
 use crate::{
-    client::{node_manager::node::NodeAuth, secret::GenerateAddressOptions},
-    types::block::address::{Bech32Address, Hrp},
+    client::{
+        api::{PreparedTransactionDataDto, SignedTransactionDataDto},
+        node_manager::node::NodeAuth,
+        secret::GenerateAddressOptions,
+    },
+    types::block::{
+        address::{Bech32Address, Hrp},
+        output::dto::OutputDto,
+        payload::transaction::dto::TransactionPayloadDto,
+        BlockDto,
+    },
     wallet::{
-        account::{operations::syncing::SyncOptions, types::AccountIdentifier},
-        ClientOptions,
+        account::{operations::syncing::SyncOptions, types::AccountIdentifier, TransactionOptionsDto},
+        AddressBook, ClientOptions, TransactionTemplate,
     },
     Url,
 };
@@ -32,6 +44,9 @@ pub enum Message {
         alias: Option<String>,
         /// The bech32 HRP.
         bech32_hrp: Option<Hrp>,
+        /// Addresses to create a watch-only account for.
+        #[serde(default)]
+        watch_only_addresses: Option<Vec<Bech32Address>>,
     },
     /// Read account.
     /// Expected response: [`Account`](crate::wallet::message_interface::Response::Account)
@@ -51,6 +66,15 @@ pub enum Message {
         account_id: AccountIdentifier,
         /// The account method to call.
         method: AccountMethod,
+        /// Context recorded in the audit log, like an operator or request id.
+        #[serde(default)]
+        audit_context: Option<String>,
+        /// Only prepare the transaction of a mutating method, without signing and submitting it. The response is the
+        /// one of the corresponding prepare method, like
+        /// [`PreparedTransaction`](crate::wallet::message_interface::Response::PreparedTransaction) or
+        /// [`PreparedMintTokenTransaction`](crate::wallet::message_interface::Response::PreparedMintTokenTransaction).
+        #[serde(default)]
+        dry_run: bool,
     },
     /// Backup storage. Password must be the current one, when Stronghold is used as SecretManager.
     /// Expected response: [`Ok`](crate::wallet::message_interface::Response::Ok)
@@ -62,6 +86,16 @@ pub enum Message {
         /// Stronghold file password.
         password: String,
     },
+    /// Backup the wallet data in a JSON file encrypted with the password, without the mnemonic or Stronghold seed.
+    /// Expected response: [`Ok`](crate::wallet::message_interface::Response::Ok)
+    #[cfg(feature = "encrypted_backup")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "encrypted_backup")))]
+    BackupToEncryptedJson {
+        /// The backup destination.
+        destination: PathBuf,
+        /// The password the backup is encrypted with.
+        password: String,
+    },
     /// Change the Stronghold password to another one and also re-encrypt the values in the loaded snapshot with it.
     /// Expected response: [`Ok`](crate::wallet::message_interface::Response::Ok)
     #[cfg(feature = "stronghold")]
@@ -123,9 +157,74 @@ pub enum Message {
         /// accounts will be restored.
         ignore_if_bech32_mismatch: Option<Hrp>,
     },
+    /// Restore a backup created with
+    /// [`BackupToEncryptedJson`](crate::wallet::message_interface::Message::BackupToEncryptedJson).
+    /// Replaces client_options, coin_type, accounts and the secret_manager if one was backed up. Returns an error if
+    /// accounts were already created.
+    /// Expected response: [`Ok`](crate::wallet::message_interface::Response::Ok)
+    #[cfg(feature = "encrypted_backup")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "encrypted_backup")))]
+    RestoreFromEncryptedJson {
+        /// The path to the encrypted backup.
+        source: PathBuf,
+        /// The password the backup is encrypted with.
+        password: String,
+    },
+    /// Restore a subset of the accounts of a Stronghold backup, adding them to the existing accounts instead of
+    /// replacing them. No account is restored if one conflicts with the accounts of the wallet.
+    /// Expected response: [`Accounts`](crate::wallet::message_interface::Response::Accounts)
+    #[cfg(feature = "stronghold")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
+    #[serde(rename_all = "camelCase")]
+    RestoreAccountsFromBackup {
+        /// The path to the backed up Stronghold.
+        source: PathBuf,
+        /// Stronghold file password.
+        password: String,
+        /// The accounts to restore, by their index, alias or id in the backup.
+        account_ids: Vec<AccountIdentifier>,
+    },
+    /// Import a Stronghold backup created by Firefly, importing the seed and recreating its accounts.
+    /// Returns an error if accounts were already created, if the Stronghold already contains a mnemonic or if its
+    /// password isn't the one of the backup.
+    /// Expected response: [`Accounts`](crate::wallet::message_interface::Response::Accounts)
+    #[cfg(feature = "stronghold")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
+    ImportFireflyBackup {
+        /// The path to the Firefly Stronghold backup.
+        source: PathBuf,
+        /// Stronghold file password.
+        password: String,
+    },
+    /// Opens a Stronghold backup and checks that it can be restored, without changing the wallet.
+    /// Expected response: [`BackupReport`](crate::wallet::message_interface::Response::BackupReport)
+    #[cfg(feature = "stronghold")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
+    VerifyBackup {
+        /// The path to the backed up Stronghold.
+        source: PathBuf,
+        /// Stronghold file password.
+        password: String,
+    },
     /// Removes the latest account (account with the largest account index).
     /// Expected response: [`Ok`](crate::wallet::message_interface::Response::Ok)
     RemoveLatestAccount,
+    /// Rename an account, fails if another account already uses the alias.
+    /// Expected response: [`Ok`](crate::wallet::message_interface::Response::Ok)
+    #[serde(rename_all = "camelCase")]
+    RenameAccount {
+        account_id: AccountIdentifier,
+        alias: String,
+    },
+    /// Send a single transaction with inputs of multiple accounts.
+    /// Expected response: [`SentTransaction`](crate::wallet::message_interface::Response::SentTransaction)
+    #[serde(rename_all = "camelCase")]
+    SendFromAccounts {
+        /// The accounts whose outputs can be used as inputs.
+        account_ids: Vec<AccountIdentifier>,
+        outputs: Vec<OutputDto>,
+        options: Option<TransactionOptionsDto>,
+    },
     /// Generates a new mnemonic.
     /// Expected response: [`GeneratedMnemonic`](crate::wallet::message_interface::Response::GeneratedMnemonic)
     GenerateMnemonic,
@@ -136,6 +235,61 @@ pub enum Message {
     /// Expected response: [`Ok`](crate::wallet::message_interface::Response::Ok)
     #[serde(rename_all = "camelCase")]
     SetClientOptions { client_options: Box<ClientOptions> },
+    /// Get the address book used to verify the destinations of outgoing transactions.
+    /// Expected response: [`AddressBook`](crate::wallet::message_interface::Response::AddressBook)
+    GetAddressBook,
+    /// Set the address book used to verify the destinations of outgoing transactions.
+    /// Expected response: [`Ok`](crate::wallet::message_interface::Response::Ok)
+    #[serde(rename_all = "camelCase")]
+    SetAddressBook { address_book: AddressBook },
+    /// Get the transaction templates by their name.
+    /// Expected response: [`TransactionTemplates`](crate::wallet::message_interface::Response::TransactionTemplates)
+    GetTransactionTemplates,
+    /// Add a transaction template, replacing an existing one with the same name.
+    /// Expected response: [`Ok`](crate::wallet::message_interface::Response::Ok)
+    SetTransactionTemplate {
+        name: String,
+        template: TransactionTemplate,
+    },
+    /// Remove a transaction template.
+    /// Expected response: [`Ok`](crate::wallet::message_interface::Response::Ok)
+    RemoveTransactionTemplate { name: String },
+    /// Enable or disable recording of mutating account methods into the audit log.
+    /// Expected response: [`Ok`](crate::wallet::message_interface::Response::Ok)
+    #[cfg(feature = "storage")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
+    SetAuditLogEnabled { enabled: bool },
+    /// Get the participation overview of all accounts, summed up per event.
+    /// Expected response:
+    /// [`WalletParticipationOverview`](crate::wallet::message_interface::Response::WalletParticipationOverview)
+    #[cfg(feature = "wallet-participation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "wallet-participation")))]
+    #[serde(rename_all = "camelCase")]
+    GetParticipationOverview {
+        event_ids: Option<Vec<ParticipationEventId>>,
+    },
+    /// Compact the storage to reclaim the space of removed and overwritten records.
+    /// Expected response: [`StorageCompaction`](crate::wallet::message_interface::Response::StorageCompaction)
+    #[cfg(feature = "storage")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
+    CompactStorage,
+    /// Set the interval in which the background syncing compacts the storage, `None` disables it.
+    /// Expected response: [`Ok`](crate::wallet::message_interface::Response::Ok)
+    #[cfg(feature = "storage")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
+    #[serde(rename_all = "camelCase")]
+    SetStorageCompactionInterval { interval_in_milliseconds: Option<u64> },
+    /// Enable or disable adding decimal formatted native token amounts to balance and transaction responses, for
+    /// tokens with IRC30 metadata.
+    /// Expected response: [`Ok`](crate::wallet::message_interface::Response::Ok)
+    SetFormatNativeTokenAmounts { enabled: bool },
+    /// Enable or disable adding the normalized inputs and outputs to transaction responses.
+    /// Expected response: [`Ok`](crate::wallet::message_interface::Response::Ok)
+    SetIncludeNormalizedTransactions { enabled: bool },
+    /// Set the amount of milestones that need to be confirmed after the one that included a transaction, before the
+    /// transaction is considered confirmed.
+    /// Expected response: [`Ok`](crate::wallet::message_interface::Response::Ok)
+    SetDefaultConfirmationDepth { depth: u32 },
     /// Generate an address without storing it
     /// Expected response: [`Bech32Address`](crate::wallet::message_interface::Response::Bech32Address)
     #[serde(rename_all = "camelCase")]
@@ -190,6 +344,15 @@ pub enum Message {
     /// Stop background syncing.
     /// Expected response: [`Ok`](crate::wallet::message_interface::Response::Ok)
     StopBackgroundSync,
+    /// Pause background syncing.
+    /// Expected response: [`BackgroundSyncStatus`](crate::wallet::message_interface::Response::BackgroundSyncStatus)
+    PauseBackgroundSync,
+    /// Resume paused background syncing.
+    /// Expected response: [`BackgroundSyncStatus`](crate::wallet::message_interface::Response::BackgroundSyncStatus)
+    ResumeBackgroundSync,
+    /// Get the status of background syncing.
+    /// Expected response: [`BackgroundSyncStatus`](crate::wallet::message_interface::Response::BackgroundSyncStatus)
+    GetBackgroundSyncStatus,
     /// Emits an event for testing if the event system is working
     /// Expected response: [`Ok`](crate::wallet::message_interface::Response::Ok)
     #[cfg(feature = "events")]
@@ -208,6 +371,42 @@ pub enum Message {
         /// Human readable part
         bech32_hrp: Option<Hrp>,
     },
+    /// Computes the block ID (Blake2b256 hash of the block bytes) of a block, without network access
+    /// Expected response: [`BlockId`](crate::wallet::message_interface::Response::BlockId)
+    BlockId { block: BlockDto },
+    /// Computes the transaction ID (Blake2b256 hash of the payload bytes) of a transaction payload, without network
+    /// access
+    /// Expected response: [`TransactionId`](crate::wallet::message_interface::Response::TransactionId)
+    TransactionId { payload: TransactionPayloadDto },
+    /// Computes the hash of the essence of a transaction payload, which is the message that gets signed
+    /// Expected response:
+    /// [`TransactionEssenceHash`](crate::wallet::message_interface::Response::TransactionEssenceHash)
+    HashTransactionEssence { payload: TransactionPayloadDto },
+    /// Writes a prepared transaction as versioned envelope with an integrity hash to a file, to be signed by an
+    /// offline signer. Not available through the RPC service.
+    /// Expected response: [`Ok`](crate::wallet::message_interface::Response::Ok)
+    #[serde(rename_all = "camelCase")]
+    WritePreparedTransactionToFile {
+        prepared_transaction_data: PreparedTransactionDataDto,
+        path: PathBuf,
+    },
+    /// Reads a prepared transaction from an envelope file and verifies its integrity. Not available through the RPC
+    /// service.
+    /// Expected response: [`PreparedTransaction`](crate::wallet::message_interface::Response::PreparedTransaction)
+    ReadPreparedTransactionFromFile { path: PathBuf },
+    /// Writes a signed transaction as versioned envelope with an integrity hash to a file, to be submitted by an
+    /// online wallet. Not available through the RPC service.
+    /// Expected response: [`Ok`](crate::wallet::message_interface::Response::Ok)
+    #[serde(rename_all = "camelCase")]
+    WriteSignedTransactionToFile {
+        signed_transaction_data: SignedTransactionDataDto,
+        path: PathBuf,
+    },
+    /// Reads a signed transaction from an envelope file and verifies its integrity. Not available through the RPC
+    /// service.
+    /// Expected response:
+    /// [`SignedTransactionData`](crate::wallet::message_interface::Response::SignedTransactionData)
+    ReadSignedTransactionFromFile { path: PathBuf },
     // Remove all listeners of this type. Empty vec clears all listeners
     /// Expected response: [`Ok`](crate::wallet::message_interface::Response::Ok)
     #[cfg(feature = "events")]
@@ -228,15 +427,25 @@ pub enum Message {
 impl Debug for Message {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         match self {
-            Self::CreateAccount { alias, bech32_hrp } => {
-                write!(f, "CreateAccount{{ alias: {alias:?}, bech32_hrp: {bech32_hrp:?} }}")
-            }
+            Self::CreateAccount {
+                alias,
+                bech32_hrp,
+                watch_only_addresses,
+            } => write!(
+                f,
+                "CreateAccount{{ alias: {alias:?}, bech32_hrp: {bech32_hrp:?}, watch_only_addresses: {watch_only_addresses:?} }}"
+            ),
             Self::GetAccountIndexes => write!(f, "GetAccountIndexes"),
             Self::GetAccount { account_id } => write!(f, "GetAccount{{ account_id: {account_id:?} }}"),
             Self::GetAccounts => write!(f, "GetAccounts"),
-            Self::CallAccountMethod { account_id, method } => write!(
+            Self::CallAccountMethod {
+                account_id,
+                method,
+                audit_context,
+                dry_run,
+            } => write!(
                 f,
-                "CallAccountMethod{{ account_id: {account_id:?}, method: {method:?} }}"
+                "CallAccountMethod{{ account_id: {account_id:?}, method: {method:?}, audit_context: {audit_context:?}, dry_run: {dry_run} }}"
             ),
             #[cfg(feature = "stronghold")]
             Self::ChangeStrongholdPassword {
@@ -255,6 +464,11 @@ impl Debug for Message {
                 destination,
                 password: _,
             } => write!(f, "Backup{{ destination: {destination:?} }}"),
+            #[cfg(feature = "encrypted_backup")]
+            Self::BackupToEncryptedJson {
+                destination,
+                password: _,
+            } => write!(f, "BackupToEncryptedJson{{ destination: {destination:?} }}"),
             Self::RecoverAccounts {
                 account_start_index,
                 account_gap_limit,
@@ -265,6 +479,17 @@ impl Debug for Message {
                 "RecoverAccounts{{ account_start_index: {account_start_index:?}, account_gap_limit: {account_gap_limit:?}, address_gap_limit: {address_gap_limit:?}, sync_options: {sync_options:?} }}"
             ),
             Self::RemoveLatestAccount => write!(f, "RemoveLatestAccount"),
+            Self::RenameAccount { account_id, alias } => {
+                write!(f, "RenameAccount{{ account_id: {account_id:?}, alias: {alias:?} }}")
+            }
+            Self::SendFromAccounts {
+                account_ids,
+                outputs,
+                options,
+            } => write!(
+                f,
+                "SendFromAccounts{{ account_ids: {account_ids:?}, outputs: {outputs:?}, options: {options:?} }}"
+            ),
             #[cfg(feature = "stronghold")]
             Self::RestoreBackup {
                 source,
@@ -275,11 +500,61 @@ impl Debug for Message {
                 f,
                 "RestoreBackup{{ source: {source:?}, password: <ommited>, ignore_if_coin_type_mismatch: {ignore_if_coin_type_mismatch:?}, ignore_if_bech32_mismatch: {ignore_if_bech32_mismatch:?} }}"
             ),
+            #[cfg(feature = "encrypted_backup")]
+            Self::RestoreFromEncryptedJson { source, password: _ } => {
+                write!(f, "RestoreFromEncryptedJson{{ source: {source:?}, password: <omitted> }}")
+            }
+            #[cfg(feature = "stronghold")]
+            Self::RestoreAccountsFromBackup {
+                source,
+                password: _,
+                account_ids,
+            } => write!(
+                f,
+                "RestoreAccountsFromBackup{{ source: {source:?}, password: <omitted>, account_ids: {account_ids:?} }}"
+            ),
+            #[cfg(feature = "stronghold")]
+            Self::ImportFireflyBackup { source, password: _ } => {
+                write!(f, "ImportFireflyBackup{{ source: {source:?}, password: <omitted> }}")
+            }
+            #[cfg(feature = "stronghold")]
+            Self::VerifyBackup { source, password: _ } => {
+                write!(f, "VerifyBackup{{ source: {source:?}, password: <omitted> }}")
+            }
             Self::GenerateMnemonic => write!(f, "GenerateMnemonic"),
             Self::VerifyMnemonic { mnemonic: _ } => write!(f, "VerifyMnemonic{{ mnemonic: <omitted> }}"),
             Self::SetClientOptions { client_options } => {
                 write!(f, "SetClientOptions{{ client_options: {client_options:?} }}")
             }
+            Self::GetAddressBook => write!(f, "GetAddressBook"),
+            Self::SetAddressBook { address_book } => write!(f, "SetAddressBook{{ address_book: {address_book:?} }}"),
+            Self::GetTransactionTemplates => write!(f, "GetTransactionTemplates"),
+            Self::SetTransactionTemplate { name, template } => {
+                write!(f, "SetTransactionTemplate{{ name: {name:?}, template: {template:?} }}")
+            }
+            Self::RemoveTransactionTemplate { name } => write!(f, "RemoveTransactionTemplate{{ name: {name:?} }}"),
+            #[cfg(feature = "storage")]
+            Self::SetAuditLogEnabled { enabled } => write!(f, "SetAuditLogEnabled{{ enabled: {enabled:?} }}"),
+            #[cfg(feature = "wallet-participation")]
+            Self::GetParticipationOverview { event_ids } => {
+                write!(f, "GetParticipationOverview{{ event_ids: {event_ids:?} }}")
+            }
+            #[cfg(feature = "storage")]
+            Self::CompactStorage => write!(f, "CompactStorage"),
+            #[cfg(feature = "storage")]
+            Self::SetStorageCompactionInterval {
+                interval_in_milliseconds,
+            } => write!(
+                f,
+                "SetStorageCompactionInterval{{ interval_in_milliseconds: {interval_in_milliseconds:?} }}"
+            ),
+            Self::SetFormatNativeTokenAmounts { enabled } => {
+                write!(f, "SetFormatNativeTokenAmounts{{ enabled: {enabled:?} }}")
+            }
+            Self::SetIncludeNormalizedTransactions { enabled } => {
+                write!(f, "SetIncludeNormalizedTransactions{{ enabled: {enabled:?} }}")
+            }
+            Self::SetDefaultConfirmationDepth { depth } => write!(f, "SetDefaultConfirmationDepth{{ depth: {depth:?} }}"),
             #[cfg(feature = "ledger_nano")]
             Self::GetLedgerNanoStatus => write!(f, "GetLedgerNanoStatus"),
             Self::GenerateAddress {
@@ -315,12 +590,38 @@ impl Debug for Message {
                 "StartBackgroundSync{{ options: {options:?}, interval: {interval_in_milliseconds:?} }}"
             ),
             Self::StopBackgroundSync => write!(f, "StopBackgroundSync"),
+            Self::PauseBackgroundSync => write!(f, "PauseBackgroundSync"),
+            Self::ResumeBackgroundSync => write!(f, "ResumeBackgroundSync"),
+            Self::GetBackgroundSyncStatus => write!(f, "GetBackgroundSyncStatus"),
             #[cfg(feature = "events")]
             Self::EmitTestEvent { event } => write!(f, "EmitTestEvent{{ event: {event:?} }}"),
             Self::Bech32ToHex { bech32_address } => write!(f, "Bech32ToHex{{ bech32_address: {bech32_address:?} }}"),
             Self::HexToBech32 { hex, bech32_hrp } => {
                 write!(f, "HexToBech32{{ hex: {hex:?}, bech32_hrp: {bech32_hrp:?} }}")
             }
+            Self::BlockId { block } => write!(f, "BlockId{{ block: {block:?} }}"),
+            Self::TransactionId { payload } => write!(f, "TransactionId{{ payload: {payload:?} }}"),
+            Self::HashTransactionEssence { payload } => write!(f, "HashTransactionEssence{{ payload: {payload:?} }}"),
+            Self::WritePreparedTransactionToFile {
+                prepared_transaction_data,
+                path,
+            } => write!(
+                f,
+                "WritePreparedTransactionToFile{{ prepared_transaction_data: {prepared_transaction_data:?}, path: {path:?} }}"
+            ),
+            Self::ReadPreparedTransactionFromFile { path } => {
+                write!(f, "ReadPreparedTransactionFromFile{{ path: {path:?} }}")
+            }
+            Self::WriteSignedTransactionToFile {
+                signed_transaction_data,
+                path,
+            } => write!(
+                f,
+                "WriteSignedTransactionToFile{{ signed_transaction_data: {signed_transaction_data:?}, path: {path:?} }}"
+            ),
+            Self::ReadSignedTransactionFromFile { path } => {
+                write!(f, "ReadSignedTransactionFromFile{{ path: {path:?} }}")
+            }
             #[cfg(feature = "events")]
             Self::ClearListeners { event_types } => write!(f, "ClearListeners{{ event_types: {event_types:?} }}"),
             Self::UpdateNodeAuth { url, auth: _ } => write!(f, "UpdateNodeAuth{{ url: {url}, auth: <omitted> }}"),
@@ -1,44 +1,53 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-#[cfg(feature = "participation")]
+#[cfg(feature = "wallet-participation")]
 use std::str::FromStr;
 use std::{
     any::Any,
     panic::{catch_unwind, AssertUnwindSafe},
+    sync::atomic::{AtomicBool, Ordering},
     time::Duration,
 };
 
 use backtrace::Backtrace;
 use futures::{Future, FutureExt};
-use primitive_types::U256;
 use zeroize::Zeroize;
 
+#[cfg(feature = "wallet-consolidation")]
+use crate::wallet::account::ConsolidationParams;
 #[cfg(feature = "events")]
-use crate::wallet::events::types::{Event, WalletEventType};
+use crate::wallet::events::types::{Event, NewOutputFilter, WalletEventType};
 use crate::{
     client::{
-        api::{PreparedTransactionData, PreparedTransactionDataDto, SignedTransactionData, SignedTransactionDataDto},
+        api::{
+            input_selection::Burn, PreparedTransactionData, PreparedTransactionDataDto, SignedTransactionData,
+            SignedTransactionDataDto,
+        },
         constants::SHIMMER_TESTNET_BECH32_HRP,
         request_funds_from_faucet, utils, Client, NodeInfoWrapper,
     },
     types::block::{
-        address::HrpLike,
+        address::{Bech32Address, HrpLike},
         output::{
             dto::{OutputBuilderAmountDto, OutputDto},
             AliasOutput, BasicOutput, FoundryOutput, NativeToken, NftOutput, Output, Rent,
         },
-        Error,
+        payload::{transaction::TransactionEssenceHash, TransactionPayload},
+        Block,
     },
     wallet::{
         account::{
             operations::transaction::{
-                high_level::{create_alias::CreateAliasParams, minting::mint_native_token::MintTokenTransactionDto},
+                high_level::{
+                    create_alias::{CreateAliasParams, CreateAliasTransactionDto},
+                    minting::{mint_native_token::MintTokenTransactionDto, mint_nfts::MintNftTransactionDto},
+                },
                 prepare_output::OutputParams,
-                TransactionOptions,
+                TransactionOptions, TransactionOptionsDto,
             },
-            types::{AccountBalanceDto, AccountIdentifier, TransactionDto},
-            OutputDataDto,
+            types::{AccountBalanceDto, AccountIdentifier, OutputData, TransactionDto},
+            Account, OutputDataDto, PreparedMintTokenTransactionDto, SweepOptions,
         },
         message_interface::{
             account_method::AccountMethod, dtos::AccountDetailsDto, message::Message, response::Response,
@@ -46,6 +55,48 @@ use crate::{
         MintNativeTokenParams, MintNftParams, Result, Wallet,
     },
 };
+#[cfg(feature = "storage")]
+use crate::{
+    types::block::payload::transaction::TransactionId,
+    wallet::account::{operations::audit_log::audited_method, AuditLogEntry},
+};
+
+// Returns the id of the transaction sent by an account method
+#[cfg(feature = "storage")]
+fn sent_transaction_id(response: &Response) -> Option<TransactionId> {
+    match response {
+        Response::SentTransaction(transaction)
+        | Response::MintTokenTransaction(MintTokenTransactionDto { transaction, .. })
+        | Response::MintNftTransaction(MintNftTransactionDto { transaction, .. })
+        | Response::CreateAliasTransaction(CreateAliasTransactionDto { transaction, .. }) => {
+            Some(transaction.transaction_id)
+        }
+        _ => None,
+    }
+}
+
+// Returns the outputs as `OutputsData`, or as `CompactOutputsData` if `compact` is set
+async fn outputs_response(account: &Account, outputs: Vec<OutputData>, compact: bool) -> Result<Response> {
+    if compact {
+        let bech32_hrp = account.network_params().await?.bech32_hrp;
+        Ok(Response::CompactOutputsData(
+            outputs.iter().map(|output| output.compact(bech32_hrp)).collect(),
+        ))
+    } else {
+        Ok(Response::OutputsData(outputs.iter().map(OutputDataDto::from).collect()))
+    }
+}
+
+// Converts the transaction options of an account method, shared by the submitting and the preparing variants
+fn transaction_options(options: Option<&TransactionOptionsDto>) -> Result<Option<TransactionOptions>> {
+    Ok(options.map(TransactionOptions::try_from_dto).transpose()?)
+}
+
+// Parses the voting power amount of a participation account method
+#[cfg(feature = "wallet-participation")]
+fn voting_power_amount(amount: &str) -> Result<u64> {
+    Ok(u64::from_str(amount).map_err(|_| crate::client::Error::InvalidAmount(amount.to_string()))?)
+}
 
 fn panic_to_response_message(panic: Box<dyn Any>) -> Response {
     let msg = panic.downcast_ref::<String>().map_or_else(
@@ -95,6 +146,10 @@ where
 /// The Wallet message handler.
 pub struct WalletMessageHandler {
     wallet: Wallet,
+    /// If decimal formatted native token amounts are added to responses.
+    format_native_token_amounts: AtomicBool,
+    /// If the normalized inputs and outputs are added to transaction responses.
+    include_normalized_transactions: AtomicBool,
 }
 
 impl WalletMessageHandler {
@@ -102,13 +157,19 @@ impl WalletMessageHandler {
     pub async fn new() -> Result<Self> {
         let instance = Self {
             wallet: Wallet::builder().finish().await?,
+            format_native_token_amounts: AtomicBool::new(false),
+            include_normalized_transactions: AtomicBool::new(false),
         };
         Ok(instance)
     }
 
     /// Creates a new instance of the message handler with the specified wallet.
     pub fn with_manager(wallet: Wallet) -> Self {
-        Self { wallet }
+        Self {
+            wallet,
+            format_native_token_amounts: AtomicBool::new(false),
+            include_normalized_transactions: AtomicBool::new(false),
+        }
     }
 
     /// Listen to wallet events, empty vec will listen to all events
@@ -121,13 +182,28 @@ impl WalletMessageHandler {
         self.wallet.listen(events, handler).await;
     }
 
+    /// Listen to new output events that pass `filter`
+    #[cfg(feature = "events")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "events")))]
+    pub async fn listen_new_outputs<F>(&self, filter: NewOutputFilter, handler: F)
+    where
+        F: Fn(&Event) + 'static + Send + Sync,
+    {
+        self.wallet.listen_new_outputs(filter, handler).await;
+    }
+
     /// Send a message.
     pub async fn send_message(&self, message: Message) -> Response {
         log::debug!("Message: {:?}", message);
 
         let response: Result<Response> = match message {
-            Message::CreateAccount { alias, bech32_hrp } => {
-                convert_async_panics(|| async { self.create_account(alias, bech32_hrp).await }).await
+            Message::CreateAccount {
+                alias,
+                bech32_hrp,
+                watch_only_addresses,
+            } => {
+                convert_async_panics(|| async { self.create_account(alias, bech32_hrp, watch_only_addresses).await })
+                    .await
             }
             Message::GetAccount { account_id } => {
                 convert_async_panics(|| async { self.get_account(&account_id).await }).await
@@ -144,8 +220,39 @@ impl WalletMessageHandler {
                 .await
             }
             Message::GetAccounts => convert_async_panics(|| async { self.get_accounts().await }).await,
-            Message::CallAccountMethod { account_id, method } => {
-                convert_async_panics(|| async { self.call_account_method(&account_id, method).await }).await
+            Message::CallAccountMethod {
+                account_id,
+                method,
+                audit_context,
+                dry_run,
+            } => {
+                convert_async_panics(|| async {
+                    // A dry run doesn't change the account, so it isn't recorded in the audit log
+                    if dry_run && method.is_mutating() {
+                        return self.call_account_method_dry_run(&account_id, method).await;
+                    }
+                    #[cfg(feature = "storage")]
+                    let response = if method.is_mutating() && self.wallet.is_audit_log_enabled() {
+                        self.call_account_method_audited(&account_id, method, audit_context)
+                            .await?
+                    } else {
+                        self.call_account_method(&account_id, method).await?
+                    };
+                    #[cfg(not(feature = "storage"))]
+                    let response = {
+                        let _ = audit_context;
+                        self.call_account_method(&account_id, method).await?
+                    };
+                    let mut response = response;
+                    if self.format_native_token_amounts.load(Ordering::Relaxed) {
+                        response = self.add_formatted_native_token_amounts(&account_id, response).await?;
+                    }
+                    if self.include_normalized_transactions.load(Ordering::Relaxed) {
+                        response = self.add_normalized_transactions(&account_id, response).await?;
+                    }
+                    Ok(response)
+                })
+                .await
             }
             #[cfg(feature = "stronghold")]
             Message::Backup { destination, password } => {
@@ -155,6 +262,14 @@ impl WalletMessageHandler {
                 })
                 .await
             }
+            #[cfg(feature = "encrypted_backup")]
+            Message::BackupToEncryptedJson { destination, password } => {
+                convert_async_panics(|| async {
+                    self.wallet.backup_to_encrypted_json(destination, password).await?;
+                    Ok(Response::Ok(()))
+                })
+                .await
+            }
             #[cfg(feature = "stronghold")]
             Message::ChangeStrongholdPassword {
                 mut current_password,
@@ -213,6 +328,35 @@ impl WalletMessageHandler {
                 })
                 .await
             }
+            Message::RenameAccount { account_id, alias } => {
+                convert_async_panics(|| async {
+                    self.wallet.rename_account(account_id, &alias).await?;
+                    Ok(Response::Ok(()))
+                })
+                .await
+            }
+            Message::SendFromAccounts {
+                account_ids,
+                outputs,
+                options,
+            } => {
+                convert_async_panics(|| async {
+                    let token_supply = self.wallet.client().get_token_supply().await?;
+                    let transaction = self
+                        .wallet
+                        .send_from_accounts(
+                            account_ids,
+                            outputs
+                                .iter()
+                                .map(|o| Ok(Output::try_from_dto(o, token_supply)?))
+                                .collect::<crate::wallet::Result<Vec<Output>>>()?,
+                            transaction_options(options.as_ref())?,
+                        )
+                        .await?;
+                    Ok(Response::SentTransaction(TransactionDto::from(&transaction)))
+                })
+                .await
+            }
             #[cfg(feature = "stronghold")]
             Message::RestoreBackup {
                 source,
@@ -233,6 +377,127 @@ impl WalletMessageHandler {
                 })
                 .await
             }
+            #[cfg(feature = "encrypted_backup")]
+            Message::RestoreFromEncryptedJson { source, password } => {
+                convert_async_panics(|| async {
+                    self.wallet.restore_from_encrypted_json(source, password).await?;
+                    Ok(Response::Ok(()))
+                })
+                .await
+            }
+            #[cfg(feature = "stronghold")]
+            Message::RestoreAccountsFromBackup {
+                source,
+                password,
+                account_ids,
+            } => {
+                convert_async_panics(|| async {
+                    let accounts = self
+                        .wallet
+                        .restore_accounts_from_backup(source, password, account_ids)
+                        .await?;
+                    let mut account_dtos = Vec::with_capacity(accounts.len());
+                    for account in accounts {
+                        account_dtos.push(AccountDetailsDto::from(&*account.details().await));
+                    }
+                    Ok(Response::Accounts(account_dtos))
+                })
+                .await
+            }
+            #[cfg(feature = "stronghold")]
+            Message::ImportFireflyBackup { source, password } => {
+                convert_async_panics(|| async {
+                    let accounts = self.wallet.import_firefly_backup(source, password).await?;
+                    let mut account_dtos = Vec::with_capacity(accounts.len());
+                    for account in accounts {
+                        account_dtos.push(AccountDetailsDto::from(&*account.details().await));
+                    }
+                    Ok(Response::Accounts(account_dtos))
+                })
+                .await
+            }
+            #[cfg(feature = "stronghold")]
+            Message::VerifyBackup { source, password } => {
+                convert_async_panics(|| async {
+                    Ok(Response::BackupReport(
+                        self.wallet.verify_backup(source, password).await?,
+                    ))
+                })
+                .await
+            }
+            Message::GetAddressBook => {
+                convert_async_panics(|| async { Ok(Response::AddressBook(self.wallet.address_book().await)) }).await
+            }
+            Message::SetAddressBook { address_book } => {
+                convert_async_panics(|| async {
+                    self.wallet.set_address_book(address_book).await?;
+                    Ok(Response::Ok(()))
+                })
+                .await
+            }
+            Message::GetTransactionTemplates => {
+                convert_async_panics(|| async {
+                    Ok(Response::TransactionTemplates(
+                        self.wallet.transaction_templates().await,
+                    ))
+                })
+                .await
+            }
+            Message::SetTransactionTemplate { name, template } => {
+                convert_async_panics(|| async {
+                    self.wallet.set_transaction_template(name, template).await?;
+                    Ok(Response::Ok(()))
+                })
+                .await
+            }
+            Message::RemoveTransactionTemplate { name } => {
+                convert_async_panics(|| async {
+                    self.wallet.remove_transaction_template(&name).await?;
+                    Ok(Response::Ok(()))
+                })
+                .await
+            }
+            #[cfg(feature = "storage")]
+            Message::SetAuditLogEnabled { enabled } => convert_panics(|| {
+                self.wallet.set_audit_log_enabled(enabled);
+                Ok(Response::Ok(()))
+            }),
+            #[cfg(feature = "wallet-participation")]
+            Message::GetParticipationOverview { event_ids } => {
+                convert_async_panics(|| async {
+                    let overview = self.wallet.get_participation_overview(event_ids).await?;
+                    Ok(Response::WalletParticipationOverview(overview))
+                })
+                .await
+            }
+            #[cfg(feature = "storage")]
+            Message::CompactStorage => {
+                convert_async_panics(|| async {
+                    let compaction = self.wallet.compact_storage().await?;
+                    Ok(Response::StorageCompaction(compaction))
+                })
+                .await
+            }
+            #[cfg(feature = "storage")]
+            Message::SetStorageCompactionInterval {
+                interval_in_milliseconds,
+            } => convert_panics(|| {
+                let interval = interval_in_milliseconds.map(Duration::from_millis);
+                self.wallet.set_storage_compaction_interval(interval);
+                Ok(Response::Ok(()))
+            }),
+            Message::SetFormatNativeTokenAmounts { enabled } => convert_panics(|| {
+                self.format_native_token_amounts.store(enabled, Ordering::Relaxed);
+                Ok(Response::Ok(()))
+            }),
+            Message::SetIncludeNormalizedTransactions { enabled } => convert_panics(|| {
+                self.include_normalized_transactions.store(enabled, Ordering::Relaxed);
+                Ok(Response::Ok(()))
+            }),
+            Message::SetDefaultConfirmationDepth { depth } => convert_panics(|| {
+                self.wallet.set_default_confirmation_depth(depth);
+                Ok(Response::Ok(()))
+            }),
             Message::GenerateMnemonic => {
                 convert_panics(|| self.wallet.generate_mnemonic().map(Response::GeneratedMnemonic))
             }
@@ -335,6 +600,17 @@ impl WalletMessageHandler {
                 })
                 .await
             }
+            Message::PauseBackgroundSync => {
+                self.wallet.pause_background_syncing();
+                Ok(Response::BackgroundSyncStatus(self.wallet.background_syncing_status()))
+            }
+            Message::ResumeBackgroundSync => {
+                self.wallet.resume_background_syncing();
+                Ok(Response::BackgroundSyncStatus(self.wallet.background_syncing_status()))
+            }
+            Message::GetBackgroundSyncStatus => {
+                Ok(Response::BackgroundSyncStatus(self.wallet.background_syncing_status()))
+            }
             #[cfg(feature = "events")]
             Message::EmitTestEvent { event } => {
                 convert_async_panics(|| async {
@@ -360,6 +636,69 @@ impl WalletMessageHandler {
                 })
                 .await
             }
+            Message::BlockId { block } => {
+                convert_panics(|| Ok(Response::BlockId(Block::try_from_dto_unverified(&block)?.id())))
+            }
+            Message::TransactionId { payload } => convert_panics(|| {
+                let payload = TransactionPayload::try_from_dto_unverified(&payload)?;
+                Ok(Response::TransactionId(payload.id()))
+            }),
+            Message::HashTransactionEssence { payload } => convert_panics(|| {
+                let payload = TransactionPayload::try_from_dto_unverified(&payload)?;
+                Ok(Response::TransactionEssenceHash(TransactionEssenceHash::new(
+                    payload.essence().hash(),
+                )))
+            }),
+            Message::WritePreparedTransactionToFile {
+                prepared_transaction_data,
+                path,
+            } => {
+                convert_async_panics(|| async {
+                    PreparedTransactionData::try_from_dto(
+                        &prepared_transaction_data,
+                        &self.wallet.client().get_protocol_parameters().await?,
+                    )?
+                    .to_file(path)?;
+                    Ok(Response::Ok(()))
+                })
+                .await
+            }
+            Message::ReadPreparedTransactionFromFile { path } => {
+                convert_async_panics(|| async {
+                    let prepared_transaction_data = PreparedTransactionData::from_file(
+                        path,
+                        &self.wallet.client().get_protocol_parameters().await?,
+                    )?;
+                    Ok(Response::PreparedTransaction(PreparedTransactionDataDto::from(
+                        &prepared_transaction_data,
+                    )))
+                })
+                .await
+            }
+            Message::WriteSignedTransactionToFile {
+                signed_transaction_data,
+                path,
+            } => {
+                convert_async_panics(|| async {
+                    SignedTransactionData::try_from_dto(
+                        &signed_transaction_data,
+                        &self.wallet.client().get_protocol_parameters().await?,
+                    )?
+                    .to_file(path)?;
+                    Ok(Response::Ok(()))
+                })
+                .await
+            }
+            Message::ReadSignedTransactionFromFile { path } => {
+                convert_async_panics(|| async {
+                    let signed_transaction_data =
+                        SignedTransactionData::from_file(path, &self.wallet.client().get_protocol_parameters().await?)?;
+                    Ok(Response::SignedTransactionData(SignedTransactionDataDto::from(
+                        &signed_transaction_data,
+                    )))
+                })
+                .await
+            }
             #[cfg(feature = "events")]
             Message::ClearListeners { event_types } => {
                 convert_async_panics(|| async {
@@ -387,6 +726,124 @@ impl WalletMessageHandler {
         response
     }
 
+    // Calls the account method and records it in the audit log of the account, also if it failed
+    #[cfg(feature = "storage")]
+    async fn call_account_method_audited(
+        &self,
+        account_id: &AccountIdentifier,
+        method: AccountMethod,
+        audit_context: Option<String>,
+    ) -> Result<Response> {
+        let account = self.wallet.get_account(account_id.clone()).await?;
+
+        // The method is serialized as `{ "name": .., "data": .. }`
+        let serialized_method = serde_json::to_value(&method)?;
+        let entry = AuditLogEntry::new(
+            serialized_method["name"].as_str().unwrap_or_default(),
+            &serialized_method["data"],
+        )?
+        .with_context(audit_context);
+
+        // Boxed, the future of the account method is too large for the stack in debug builds
+        let result = audited_method(Box::pin(self.call_account_method(account_id, method))).await;
+
+        let entry = match &result {
+            Ok(response) => entry.with_transaction_id(sent_transaction_id(response)),
+            Err(error) => entry.with_error(error.to_string()),
+        };
+        // Don't hide the result of the method, a transaction might already have been sent
+        if let Err(error) = account.record_audit_log_entry(entry).await {
+            log::error!("failed to record audit log entry: {error}");
+        }
+
+        result
+    }
+
+    // Calls the prepare method of a mutating account method instead, so the response is the same as for the prepare
+    // method. The inputs are released again, so the prepared transaction is only a preview.
+    async fn call_account_method_dry_run(
+        &self,
+        account_id: &AccountIdentifier,
+        method: AccountMethod,
+    ) -> Result<Response> {
+        let prepare_method = method.into_prepare_method().map_err(|method| {
+            // The method is serialized as `{ "name": .., "data": .. }`
+            let name = serde_json::to_value(&method)
+                .ok()
+                .and_then(|method| method["name"].as_str().map(ToString::to_string))
+                .unwrap_or_default();
+            crate::wallet::Error::DryRunNotSupported(name)
+        })?;
+        let response = self.call_account_method(account_id, prepare_method).await?;
+
+        let prepared_transaction = match &response {
+            Response::PreparedTransaction(prepared_transaction) => prepared_transaction,
+            Response::PreparedMintTokenTransaction(prepared_mint_token_transaction) => {
+                &prepared_mint_token_transaction.transaction
+            }
+            // Nothing was prepared, for example because the voting power is already at the target amount
+            _ => return Ok(response),
+        };
+        let account = self.wallet.get_account(account_id.clone()).await?;
+        let prepared_transaction = PreparedTransactionData::try_from_dto(
+            prepared_transaction,
+            &account.client().get_protocol_parameters().await?,
+        )?;
+        account.discard_prepared_transaction(&prepared_transaction).await?;
+
+        Ok(response)
+    }
+
+    // Adds the decimal formatted native token amounts to balance and transaction responses
+    async fn add_formatted_native_token_amounts(
+        &self,
+        account_id: &AccountIdentifier,
+        response: Response,
+    ) -> Result<Response> {
+        let account = self.wallet.get_account(account_id.clone()).await?;
+        let token_registry = account.token_registry().await;
+        let decimals = token_registry.all_decimals();
+        let format = |transaction: TransactionDto| transaction.with_formatted_native_token_amounts(&decimals);
+
+        Ok(match response {
+            Response::Balance(balance) => Response::Balance(balance.with_native_token_metadata(&token_registry)),
+            Response::Transaction(transaction) => Response::Transaction(transaction.map(|t| Box::new(format(*t)))),
+            Response::Transactions(transactions) => {
+                Response::Transactions(transactions.into_iter().map(format).collect())
+            }
+            Response::SentTransaction(transaction) => Response::SentTransaction(format(transaction)),
+            response => response,
+        })
+    }
+
+    // Adds the normalized inputs and outputs of stored transactions to transaction responses
+    async fn add_normalized_transactions(
+        &self,
+        account_id: &AccountIdentifier,
+        response: Response,
+    ) -> Result<Response> {
+        let account = self.wallet.get_account(account_id.clone()).await?;
+        let bech32_hrp = account.network_params().await?.bech32_hrp;
+        let account_details = account.details().await;
+        let normalize = |mut transaction: TransactionDto| {
+            transaction.normalized = account_details
+                .transactions()
+                .get(&transaction.transaction_id)
+                .or_else(|| account_details.incoming_transactions().get(&transaction.transaction_id))
+                .map(|transaction| transaction.normalized(bech32_hrp));
+            transaction
+        };
+
+        Ok(match response {
+            Response::Transaction(transaction) => Response::Transaction(transaction.map(|t| Box::new(normalize(*t)))),
+            Response::Transactions(transactions) => {
+                Response::Transactions(transactions.into_iter().map(normalize).collect())
+            }
+            Response::SentTransaction(transaction) => Response::SentTransaction(normalize(transaction)),
+            response => response,
+        })
+    }
+
     async fn call_account_method(&self, account_id: &AccountIdentifier, method: AccountMethod) -> Result<Response> {
         let account = self.wallet.get_account(account_id.clone()).await?;
 
@@ -500,7 +957,7 @@ impl WalletMessageHandler {
                     let transaction = account
                         .burn(
                             NativeToken::new(token_id, burn_amount)?,
-                            options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                            transaction_options(options.as_ref())?,
                         )
                         .await?;
                     Ok(Response::SentTransaction(TransactionDto::from(&transaction)))
@@ -509,64 +966,88 @@ impl WalletMessageHandler {
             }
             AccountMethod::BurnNft { nft_id, options } => {
                 convert_async_panics(|| async {
-                    let transaction = account
-                        .burn(
-                            nft_id,
-                            options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
-                        )
-                        .await?;
+                    let transaction = account.burn(nft_id, transaction_options(options.as_ref())?).await?;
                     Ok(Response::SentTransaction(TransactionDto::from(&transaction)))
                 })
                 .await
             }
+            #[cfg(feature = "wallet-consolidation")]
             AccountMethod::ConsolidateOutputs {
                 force,
                 output_consolidation_threshold,
+                target_address,
+                split_native_tokens,
             } => {
                 convert_async_panics(|| async {
                     let transaction = account
-                        .consolidate_outputs(force, output_consolidation_threshold)
+                        .consolidate_outputs(ConsolidationParams {
+                            force,
+                            output_consolidation_threshold,
+                            target_address,
+                            split_native_tokens,
+                        })
                         .await?;
                     Ok(Response::SentTransaction(TransactionDto::from(&transaction)))
                 })
                 .await
             }
+            #[cfg(feature = "wallet-consolidation")]
+            AccountMethod::ConsolidateAllOutputs {
+                force,
+                output_consolidation_threshold,
+                target_address,
+                split_native_tokens,
+            } => {
+                convert_async_panics(|| async {
+                    let transactions = account
+                        .consolidate_all_outputs(ConsolidationParams {
+                            force,
+                            output_consolidation_threshold,
+                            target_address,
+                            split_native_tokens,
+                        })
+                        .await?;
+                    Ok(Response::Transactions(
+                        transactions.iter().map(TransactionDto::from).collect(),
+                    ))
+                })
+                .await
+            }
+            #[cfg(feature = "wallet-consolidation")]
+            AccountMethod::ConsolidateMicroOutputs => {
+                convert_async_panics(|| async {
+                    let transaction = account.consolidate_micro_outputs().await?;
+                    Ok(Response::Transaction(
+                        transaction.as_ref().map(TransactionDto::from).map(Box::new),
+                    ))
+                })
+                .await
+            }
             AccountMethod::CreateAliasOutput { params, options } => {
                 convert_async_panics(|| async {
                     let params = params
                         .map(|options| CreateAliasParams::try_from(&options))
                         .transpose()?;
 
-                    let transaction = account
-                        .create_alias_output(
-                            params,
-                            options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
-                        )
+                    let alias_transaction = account
+                        .create_alias_output(params, transaction_options(options.as_ref())?)
                         .await?;
-                    Ok(Response::SentTransaction(TransactionDto::from(&transaction)))
+                    Ok(Response::CreateAliasTransaction(CreateAliasTransactionDto::from(
+                        &alias_transaction,
+                    )))
                 })
                 .await
             }
             AccountMethod::DestroyAlias { alias_id, options } => {
                 convert_async_panics(|| async {
-                    let transaction = account
-                        .burn(
-                            alias_id,
-                            options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
-                        )
-                        .await?;
+                    let transaction = account.burn(alias_id, transaction_options(options.as_ref())?).await?;
                     Ok(Response::SentTransaction(TransactionDto::from(&transaction)))
                 })
                 .await
             }
             AccountMethod::DestroyFoundry { foundry_id, options } => {
                 convert_async_panics(|| async {
-                    let transaction = account
-                        .burn(
-                            foundry_id,
-                            options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
-                        )
-                        .await?;
+                    let transaction = account.burn(foundry_id, transaction_options(options.as_ref())?).await?;
                     Ok(Response::SentTransaction(TransactionDto::from(&transaction)))
                 })
                 .await
@@ -581,16 +1062,31 @@ impl WalletMessageHandler {
                     .await?;
                 Ok(Response::OutputIds(output_ids))
             }
+            #[cfg(feature = "storage")]
+            AccountMethod::GetAuditLog { filter } => Ok(Response::AuditLog(account.audit_log(filter).await?)),
             AccountMethod::GetOutput { output_id } => {
                 let output_data = account.get_output(&output_id).await;
                 Ok(Response::OutputData(
                     output_data.as_ref().map(OutputDataDto::from).map(Box::new),
                 ))
             }
+            AccountMethod::FindLegacyFunds {
+                legacy_coin_type,
+                address_gap_limit,
+            } => Ok(Response::LegacyAddressFunds(
+                account.find_legacy_funds(legacy_coin_type, address_gap_limit).await?,
+            )),
+            AccountMethod::TraceOutput { output_id } => {
+                Ok(Response::OutputLineage(account.trace_output(output_id).await?))
+            }
             AccountMethod::GetFoundryOutput { token_id } => {
                 let output = account.get_foundry_output(token_id).await?;
                 Ok(Response::Output(OutputDto::from(&output)))
             }
+            AccountMethod::GetNativeTokenMetadata { token_id } => Ok(Response::NativeTokenMetadata(
+                account.native_token_metadata(token_id).await?,
+            )),
+            AccountMethod::GetNftMetadata { nft_id } => Ok(Response::NftMetadata(account.nft_metadata(nft_id).await?)),
             AccountMethod::GetTransaction { transaction_id } => {
                 let transaction = account.get_transaction(&transaction_id).await;
                 Ok(Response::Transaction(
@@ -613,17 +1109,39 @@ impl WalletMessageHandler {
                 let addresses = account.addresses().await?;
                 Ok(Response::Addresses(addresses))
             }
+            AccountMethod::FindAddressByLabel { label } => {
+                Ok(Response::Address(account.find_address_by_label(&label).await))
+            }
+            AccountMethod::CurrentReceiveAddress => {
+                Ok(Response::Address(Some(account.current_receive_address().await?)))
+            }
+            AccountMethod::SetAddressRotation { enabled } => {
+                account.set_address_rotation(enabled).await?;
+                Ok(Response::Ok(()))
+            }
+            AccountMethod::GetAddressRotationHistory => {
+                Ok(Response::RetiredAddresses(account.address_rotation_history().await))
+            }
+            AccountMethod::VerifyAddressOnDevice { address } => Ok(Response::AddressDerivationProof(
+                account.verify_address_on_device(address).await?,
+            )),
             AccountMethod::AddressesWithUnspentOutputs => {
                 let addresses = account.addresses_with_unspent_outputs().await?;
                 Ok(Response::AddressesWithUnspentOutputs(addresses))
             }
-            AccountMethod::Outputs { filter_options } => {
+            AccountMethod::Outputs {
+                filter_options,
+                compact,
+            } => {
                 let outputs = account.outputs(filter_options).await?;
-                Ok(Response::OutputsData(outputs.iter().map(OutputDataDto::from).collect()))
+                outputs_response(&account, outputs, compact).await
             }
-            AccountMethod::UnspentOutputs { filter_options } => {
+            AccountMethod::UnspentOutputs {
+                filter_options,
+                compact,
+            } => {
                 let outputs = account.unspent_outputs(filter_options).await?;
-                Ok(Response::OutputsData(outputs.iter().map(OutputDataDto::from).collect()))
+                outputs_response(&account, outputs, compact).await
             }
             AccountMethod::IncomingTransactions => {
                 let transactions = account.incoming_transactions().await;
@@ -643,6 +1161,9 @@ impl WalletMessageHandler {
                     transactions.iter().map(TransactionDto::from).collect(),
                 ))
             }
+            AccountMethod::ExportTransactions { range, format } => Ok(Response::TransactionExport(
+                account.export_transactions(range, format).await?,
+            )),
             AccountMethod::DecreaseNativeTokenSupply {
                 token_id,
                 melt_amount,
@@ -650,11 +1171,7 @@ impl WalletMessageHandler {
             } => {
                 convert_async_panics(|| async {
                     let transaction = account
-                        .decrease_native_token_supply(
-                            token_id,
-                            U256::try_from(&melt_amount).map_err(|_| Error::InvalidField("melt_amount"))?,
-                            options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
-                        )
+                        .decrease_native_token_supply(token_id, melt_amount, transaction_options(options.as_ref())?)
                         .await?;
                     Ok(Response::SentTransaction(TransactionDto::from(&transaction)))
                 })
@@ -667,11 +1184,7 @@ impl WalletMessageHandler {
             } => {
                 convert_async_panics(|| async {
                     let transaction = account
-                        .increase_native_token_supply(
-                            token_id,
-                            U256::try_from(&mint_amount).map_err(|_| Error::InvalidField("mint_amount"))?,
-                            options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
-                        )
+                        .increase_native_token_supply(token_id, mint_amount, transaction_options(options.as_ref())?)
                         .await?;
                     Ok(Response::MintTokenTransaction(MintTokenTransactionDto::from(
                         &transaction,
@@ -684,7 +1197,7 @@ impl WalletMessageHandler {
                     let transaction = account
                         .mint_native_token(
                             MintNativeTokenParams::try_from(&params)?,
-                            options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                            transaction_options(options.as_ref())?,
                         )
                         .await?;
                     Ok(Response::MintTokenTransaction(MintTokenTransactionDto::from(
@@ -708,20 +1221,37 @@ impl WalletMessageHandler {
             }
             AccountMethod::MintNfts { params, options } => {
                 convert_async_panics(|| async {
-                    let transaction = account
+                    let mint_transaction = account
                         .mint_nfts(
                             params
                                 .iter()
                                 .map(MintNftParams::try_from)
                                 .collect::<Result<Vec<MintNftParams>>>()?,
-                            options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                            transaction_options(options.as_ref())?,
                         )
                         .await?;
-                    Ok(Response::SentTransaction(TransactionDto::from(&transaction)))
+                    Ok(Response::MintNftTransaction(MintNftTransactionDto::from(
+                        &mint_transaction,
+                    )))
                 })
                 .await
             }
+            AccountMethod::CanBurn { chain_id } => Ok(Response::BurnBlockers(account.can_burn(chain_id).await?)),
             AccountMethod::GetBalance => Ok(Response::Balance(AccountBalanceDto::from(&account.balance().await?))),
+            AccountMethod::GetUtxoHealth => Ok(Response::UtxoHealth(account.utxo_health().await?)),
+            AccountMethod::GetAddressClustering => Ok(Response::AddressClustering(account.address_clustering().await?)),
+            AccountMethod::GetSpendingAllowances => {
+                Ok(Response::SpendingAllowances(account.spending_allowances().await))
+            }
+            AccountMethod::GetTokenPolicy => Ok(Response::TokenPolicy(account.token_policy().await)),
+            AccountMethod::GetDustPolicy => Ok(Response::DustPolicy(account.dust_policy().await)),
+            AccountMethod::GetAlertThresholds => Ok(Response::AlertThresholds(account.alert_thresholds().await)),
+            AccountMethod::GetInternalAddressPoolStatus => Ok(Response::InternalAddressPoolStatus(
+                account.internal_address_pool_status().await,
+            )),
+            AccountMethod::GetRateLimit => Ok(Response::RateLimit(account.rate_limit().await)),
+            AccountMethod::GetPrimaryAlias => Ok(Response::PrimaryAlias(account.primary_alias().await)),
+            AccountMethod::GetPrimaryAddress => Ok(Response::Bech32Address(account.primary_address().await?)),
             AccountMethod::PrepareOutput {
                 params: options,
                 transaction_options,
@@ -743,15 +1273,195 @@ impl WalletMessageHandler {
             AccountMethod::PrepareSendAmount { params, options } => {
                 convert_async_panics(|| async {
                     let data = account
-                        .prepare_send_amount(
-                            params,
-                            options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                        .prepare_send_amount(params, transaction_options(options.as_ref())?)
+                        .await?;
+                    Ok(Response::PreparedTransaction(PreparedTransactionDataDto::from(&data)))
+                })
+                .await
+            }
+            AccountMethod::PrepareSendAssets { transfers, options } => {
+                convert_async_panics(|| async {
+                    let data = account
+                        .prepare_send_assets(transfers, transaction_options(options.as_ref())?)
+                        .await?;
+                    Ok(Response::PreparedTransaction(PreparedTransactionDataDto::from(&data)))
+                })
+                .await
+            }
+            AccountMethod::PrepareBurn { burn, options } => {
+                convert_async_panics(|| async {
+                    let data = account
+                        .prepare_burn(Burn::try_from(&burn)?, transaction_options(options.as_ref())?)
+                        .await?;
+                    Ok(Response::PreparedTransaction(PreparedTransactionDataDto::from(&data)))
+                })
+                .await
+            }
+            AccountMethod::PrepareClaimOutputs { output_ids_to_claim } => {
+                convert_async_panics(|| async {
+                    let data = account.prepare_claim_outputs(output_ids_to_claim).await?;
+                    Ok(Response::PreparedTransaction(PreparedTransactionDataDto::from(&data)))
+                })
+                .await
+            }
+            #[cfg(feature = "wallet-consolidation")]
+            AccountMethod::PrepareConsolidateOutputs {
+                force,
+                output_consolidation_threshold,
+                target_address,
+                split_native_tokens,
+            } => {
+                convert_async_panics(|| async {
+                    let data = account
+                        .prepare_consolidate_outputs(ConsolidationParams {
+                            force,
+                            output_consolidation_threshold,
+                            target_address,
+                            split_native_tokens,
+                        })
+                        .await?;
+                    Ok(Response::PreparedTransaction(PreparedTransactionDataDto::from(&data)))
+                })
+                .await
+            }
+            AccountMethod::PrepareCreateAliasOutput { params, options } => {
+                convert_async_panics(|| async {
+                    let data = account
+                        .prepare_create_alias_output(
+                            params
+                                .map(|options| CreateAliasParams::try_from(&options))
+                                .transpose()?,
+                            transaction_options(options.as_ref())?,
+                        )
+                        .await?;
+                    Ok(Response::PreparedTransaction(PreparedTransactionDataDto::from(&data)))
+                })
+                .await
+            }
+            AccountMethod::PrepareDecreaseNativeTokenSupply {
+                token_id,
+                melt_amount,
+                options,
+            } => {
+                convert_async_panics(|| async {
+                    let data = account
+                        .prepare_decrease_native_token_supply(
+                            token_id,
+                            melt_amount,
+                            transaction_options(options.as_ref())?,
+                        )
+                        .await?;
+                    Ok(Response::PreparedTransaction(PreparedTransactionDataDto::from(&data)))
+                })
+                .await
+            }
+            AccountMethod::PrepareIncreaseNativeTokenSupply {
+                token_id,
+                mint_amount,
+                options,
+            } => {
+                convert_async_panics(|| async {
+                    let data = account
+                        .prepare_increase_native_token_supply(
+                            token_id,
+                            mint_amount,
+                            transaction_options(options.as_ref())?,
+                        )
+                        .await?;
+                    Ok(Response::PreparedMintTokenTransaction(
+                        PreparedMintTokenTransactionDto::from(&data),
+                    ))
+                })
+                .await
+            }
+            AccountMethod::PrepareMintNativeToken { params, options } => {
+                convert_async_panics(|| async {
+                    let data = account
+                        .prepare_mint_native_token(
+                            MintNativeTokenParams::try_from(&params)?,
+                            transaction_options(options.as_ref())?,
+                        )
+                        .await?;
+                    Ok(Response::PreparedMintTokenTransaction(
+                        PreparedMintTokenTransactionDto::from(&data),
+                    ))
+                })
+                .await
+            }
+            AccountMethod::PrepareMintNfts { params, options } => {
+                convert_async_panics(|| async {
+                    let data = account
+                        .prepare_mint_nfts(
+                            params
+                                .iter()
+                                .map(MintNftParams::try_from)
+                                .collect::<Result<Vec<MintNftParams>>>()?,
+                            transaction_options(options.as_ref())?,
                         )
                         .await?;
                     Ok(Response::PreparedTransaction(PreparedTransactionDataDto::from(&data)))
                 })
                 .await
             }
+            AccountMethod::PrepareSendNativeTokens { params, options } => {
+                convert_async_panics(|| async {
+                    let data = account
+                        .prepare_send_native_tokens(params, transaction_options(options.as_ref())?)
+                        .await?;
+                    Ok(Response::PreparedTransaction(PreparedTransactionDataDto::from(&data)))
+                })
+                .await
+            }
+            AccountMethod::PrepareSendDecimalNativeTokens { params, options } => {
+                convert_async_panics(|| async {
+                    let data = account
+                        .prepare_send_decimal_native_tokens(params, transaction_options(options.as_ref())?)
+                        .await?;
+                    Ok(Response::PreparedTransaction(PreparedTransactionDataDto::from(&data)))
+                })
+                .await
+            }
+            AccountMethod::PrepareSendNft { params, options } => {
+                convert_async_panics(|| async {
+                    let data = account
+                        .prepare_send_nft(params, transaction_options(options.as_ref())?)
+                        .await?;
+                    Ok(Response::PreparedTransaction(PreparedTransactionDataDto::from(&data)))
+                })
+                .await
+            }
+            AccountMethod::PrepareSendFromTemplate { name, params } => {
+                convert_async_panics(|| async {
+                    let data = account.prepare_send_from_template(&name, &params).await?;
+                    Ok(Response::PreparedTransaction(PreparedTransactionDataDto::from(&data)))
+                })
+                .await
+            }
+            AccountMethod::PrepareSweepLegacyFunds { legacy_funds, options } => {
+                convert_async_panics(|| async {
+                    let data = account
+                        .prepare_sweep_legacy_funds(&legacy_funds, transaction_options(options.as_ref())?)
+                        .await?;
+                    Ok(Response::PreparedTransaction(PreparedTransactionDataDto::from(&data)))
+                })
+                .await
+            }
+            AccountMethod::EstimateTransaction { outputs, options } => {
+                convert_async_panics(|| async {
+                    let token_supply = account.client().get_token_supply().await?;
+                    let estimate = account
+                        .estimate_transaction(
+                            outputs
+                                .iter()
+                                .map(|o| Ok(Output::try_from_dto(o, token_supply)?))
+                                .collect::<Result<Vec<Output>>>()?,
+                            transaction_options(options.as_ref())?,
+                        )
+                        .await?;
+                    Ok(Response::TransactionEstimate(estimate))
+                })
+                .await
+            }
             AccountMethod::PrepareTransaction { outputs, options } => {
                 convert_async_panics(|| async {
                     let token_supply = account.client().get_token_supply().await?;
@@ -761,7 +1471,7 @@ impl WalletMessageHandler {
                                 .iter()
                                 .map(|o| Ok(Output::try_from_dto(o, token_supply)?))
                                 .collect::<Result<Vec<Output>>>()?,
-                            options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                            transaction_options(options.as_ref())?,
                         )
                         .await?;
                     Ok(Response::PreparedTransaction(PreparedTransactionDataDto::from(&data)))
@@ -781,28 +1491,60 @@ impl WalletMessageHandler {
                 })
                 .await
             }
+            AccountMethod::WaitForSequenceConfirmed {
+                sequence_number,
+                interval,
+                max_attempts,
+            } => {
+                convert_async_panics(|| async {
+                    account
+                        .wait_for_sequence_confirmed(sequence_number, interval, max_attempts)
+                        .await?;
+                    Ok(Response::Ok(()))
+                })
+                .await
+            }
             AccountMethod::SyncAccount { options } => Ok(Response::Balance(AccountBalanceDto::from(
                 &account.sync(options).await?,
             ))),
             AccountMethod::SendAmount { params, options } => {
                 convert_async_panics(|| async {
                     let transaction = account
-                        .send_amount(
-                            params,
-                            options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
-                        )
+                        .send_amount(params, transaction_options(options.as_ref())?)
+                        .await?;
+                    Ok(Response::SentTransaction(TransactionDto::from(&transaction)))
+                })
+                .await
+            }
+            AccountMethod::SendAssets { transfers, options } => {
+                convert_async_panics(|| async {
+                    let transaction = account
+                        .send_assets(transfers, transaction_options(options.as_ref())?)
                         .await?;
                     Ok(Response::SentTransaction(TransactionDto::from(&transaction)))
                 })
                 .await
             }
+            AccountMethod::SendFromTemplate { name, params } => {
+                convert_async_panics(|| async {
+                    let transaction = account.send_from_template(&name, &params).await?;
+                    Ok(Response::SentTransaction(TransactionDto::from(&transaction)))
+                })
+                .await
+            }
             AccountMethod::SendNativeTokens { params, options } => {
                 convert_async_panics(|| async {
                     let transaction = account
-                        .send_native_tokens(
-                            params.clone(),
-                            options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
-                        )
+                        .send_native_tokens(params.clone(), transaction_options(options.as_ref())?)
+                        .await?;
+                    Ok(Response::SentTransaction(TransactionDto::from(&transaction)))
+                })
+                .await
+            }
+            AccountMethod::SendDecimalNativeTokens { params, options } => {
+                convert_async_panics(|| async {
+                    let transaction = account
+                        .send_decimal_native_tokens(params, transaction_options(options.as_ref())?)
                         .await?;
                     Ok(Response::SentTransaction(TransactionDto::from(&transaction)))
                 })
@@ -811,15 +1553,38 @@ impl WalletMessageHandler {
             AccountMethod::SendNft { params, options } => {
                 convert_async_panics(|| async {
                     let transaction = account
-                        .send_nft(
-                            params.clone(),
-                            options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
-                        )
+                        .send_nft(params.clone(), transaction_options(options.as_ref())?)
                         .await?;
                     Ok(Response::SentTransaction(TransactionDto::from(&transaction)))
                 })
                 .await
             }
+            AccountMethod::Sweep {
+                address,
+                nfts,
+                aliases,
+                options,
+            } => {
+                convert_async_panics(|| async {
+                    let transactions = account
+                        .sweep(
+                            address,
+                            SweepOptions {
+                                nfts,
+                                aliases,
+                                transaction_options: options
+                                    .as_ref()
+                                    .map(TransactionOptions::try_from_dto)
+                                    .transpose()?,
+                            },
+                        )
+                        .await?;
+                    Ok(Response::Transactions(
+                        transactions.iter().map(TransactionDto::from).collect(),
+                    ))
+                })
+                .await
+            }
             AccountMethod::SetAlias { alias } => {
                 convert_async_panics(|| async {
                     account.set_alias(&alias).await?;
@@ -827,6 +1592,43 @@ impl WalletMessageHandler {
                 })
                 .await
             }
+            AccountMethod::SetAddressLabel { address, label } => Ok(Response::Address(Some(
+                account.set_address_label(&address, label).await?,
+            ))),
+            AccountMethod::SetAddressMetadata { address, metadata } => Ok(Response::Address(Some(
+                account.set_address_metadata(&address, metadata).await?,
+            ))),
+            AccountMethod::SetSpendingAllowance { allowance } => {
+                account.set_spending_allowance(allowance).await?;
+                Ok(Response::Ok(()))
+            }
+            AccountMethod::SetTokenPolicy { token_policy } => {
+                account.set_token_policy(token_policy).await?;
+                Ok(Response::Ok(()))
+            }
+            AccountMethod::SetDustPolicy { dust_policy } => {
+                account.set_dust_policy(dust_policy).await?;
+                Ok(Response::Ok(()))
+            }
+            AccountMethod::SetAlertThresholds { alert_thresholds } => {
+                account.set_alert_thresholds(alert_thresholds).await?;
+                Ok(Response::Ok(()))
+            }
+            AccountMethod::SetRateLimit { rate_limit } => {
+                account.set_rate_limit(rate_limit).await?;
+                Ok(Response::Ok(()))
+            }
+            AccountMethod::SetTransactionSequencing { enabled } => {
+                account.set_transaction_sequencing(enabled).await?;
+                Ok(Response::Ok(()))
+            }
+            AccountMethod::SetPrimaryAlias { alias_id } => {
+                account.set_primary_alias(alias_id).await?;
+                Ok(Response::Ok(()))
+            }
+            AccountMethod::RemoveSpendingAllowance { id } => Ok(Response::SpendingAllowance(
+                account.remove_spending_allowance(&id).await?,
+            )),
             AccountMethod::SetDefaultSyncOptions { options } => {
                 convert_async_panics(|| async {
                     account.set_default_sync_options(options).await?;
@@ -843,7 +1645,7 @@ impl WalletMessageHandler {
                                 .iter()
                                 .map(|o| Ok(Output::try_from_dto(o, token_supply)?))
                                 .collect::<crate::wallet::Result<Vec<Output>>>()?,
-                            options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                            transaction_options(options.as_ref())?,
                         )
                         .await?;
                     Ok(Response::SentTransaction(TransactionDto::from(&transaction)))
@@ -886,7 +1688,7 @@ impl WalletMessageHandler {
                 })
                 .await
             }
-            #[cfg(feature = "participation")]
+            #[cfg(feature = "wallet-participation")]
             AccountMethod::Vote { event_id, answers } => {
                 convert_async_panics(|| async {
                     let transaction = account.vote(event_id, answers).await?;
@@ -894,7 +1696,7 @@ impl WalletMessageHandler {
                 })
                 .await
             }
-            #[cfg(feature = "participation")]
+            #[cfg(feature = "wallet-participation")]
             AccountMethod::StopParticipating { event_id } => {
                 convert_async_panics(|| async {
                     let transaction = account.stop_participating(event_id).await?;
@@ -902,7 +1704,7 @@ impl WalletMessageHandler {
                 })
                 .await
             }
-            #[cfg(feature = "participation")]
+            #[cfg(feature = "wallet-participation")]
             AccountMethod::GetParticipationOverview { event_ids } => {
                 convert_async_panics(|| async {
                     let overview = account.get_participation_overview(event_ids).await?;
@@ -910,31 +1712,86 @@ impl WalletMessageHandler {
                 })
                 .await
             }
-            #[cfg(feature = "participation")]
+            #[cfg(feature = "wallet-participation")]
             AccountMethod::IncreaseVotingPower { amount } => {
                 convert_async_panics(|| async {
-                    let transaction = account
-                        .increase_voting_power(
-                            u64::from_str(&amount).map_err(|_| crate::client::Error::InvalidAmount(amount.clone()))?,
-                        )
-                        .await?;
+                    let transaction = account.increase_voting_power(voting_power_amount(&amount)?).await?;
                     Ok(Response::SentTransaction(TransactionDto::from(&transaction)))
                 })
                 .await
             }
-            #[cfg(feature = "participation")]
+            #[cfg(feature = "wallet-participation")]
             AccountMethod::DecreaseVotingPower { amount } => {
                 convert_async_panics(|| async {
-                    let transaction = account
-                        .decrease_voting_power(
-                            u64::from_str(&amount).map_err(|_| crate::client::Error::InvalidAmount(amount.clone()))?,
-                        )
-                        .await?;
+                    let transaction = account.decrease_voting_power(voting_power_amount(&amount)?).await?;
                     Ok(Response::SentTransaction(TransactionDto::from(&transaction)))
                 })
                 .await
             }
-            #[cfg(feature = "participation")]
+            #[cfg(feature = "wallet-participation")]
+            AccountMethod::SetVotingPower { amount } => {
+                convert_async_panics(|| async {
+                    let transaction = account.set_voting_power(voting_power_amount(&amount)?).await?;
+                    Ok(transaction.map_or(Response::Ok(()), |transaction| {
+                        Response::SentTransaction(TransactionDto::from(&transaction))
+                    }))
+                })
+                .await
+            }
+            #[cfg(feature = "wallet-participation")]
+            AccountMethod::SetAutoRevote { auto_revote } => {
+                account.set_auto_revote(auto_revote).await?;
+                Ok(Response::Ok(()))
+            }
+            #[cfg(feature = "wallet-participation")]
+            AccountMethod::GetAutoRevote => Ok(Response::AutoRevote(account.auto_revote().await)),
+            #[cfg(feature = "wallet-participation")]
+            AccountMethod::PrepareVote { event_id, answers } => {
+                convert_async_panics(|| async {
+                    let data = account.prepare_vote(event_id, answers).await?;
+                    Ok(Response::PreparedTransaction(PreparedTransactionDataDto::from(&data)))
+                })
+                .await
+            }
+            #[cfg(feature = "wallet-participation")]
+            AccountMethod::PrepareStopParticipating { event_id } => {
+                convert_async_panics(|| async {
+                    let data = account.prepare_stop_participating(event_id).await?;
+                    Ok(Response::PreparedTransaction(PreparedTransactionDataDto::from(&data)))
+                })
+                .await
+            }
+            #[cfg(feature = "wallet-participation")]
+            AccountMethod::PrepareIncreaseVotingPower { amount } => {
+                convert_async_panics(|| async {
+                    let data = account
+                        .prepare_increase_voting_power(voting_power_amount(&amount)?)
+                        .await?;
+                    Ok(Response::PreparedTransaction(PreparedTransactionDataDto::from(&data)))
+                })
+                .await
+            }
+            #[cfg(feature = "wallet-participation")]
+            AccountMethod::PrepareDecreaseVotingPower { amount } => {
+                convert_async_panics(|| async {
+                    let data = account
+                        .prepare_decrease_voting_power(voting_power_amount(&amount)?)
+                        .await?;
+                    Ok(Response::PreparedTransaction(PreparedTransactionDataDto::from(&data)))
+                })
+                .await
+            }
+            #[cfg(feature = "wallet-participation")]
+            AccountMethod::PrepareSetVotingPower { amount } => {
+                convert_async_panics(|| async {
+                    let data = account.prepare_set_voting_power(voting_power_amount(&amount)?).await?;
+                    Ok(data.map_or(Response::Ok(()), |data| {
+                        Response::PreparedTransaction(PreparedTransactionDataDto::from(&data))
+                    }))
+                })
+                .await
+            }
+            #[cfg(feature = "wallet-participation")]
             AccountMethod::RegisterParticipationEvents { options } => {
                 convert_async_panics(|| async {
                     let events = account.register_participation_events(&options).await?;
@@ -942,7 +1799,7 @@ impl WalletMessageHandler {
                 })
                 .await
             }
-            #[cfg(feature = "participation")]
+            #[cfg(feature = "wallet-participation")]
             AccountMethod::DeregisterParticipationEvent { event_id } => {
                 convert_async_panics(|| async {
                     account.deregister_participation_event(&event_id).await?;
@@ -950,7 +1807,7 @@ impl WalletMessageHandler {
                 })
                 .await
             }
-            #[cfg(feature = "participation")]
+            #[cfg(feature = "wallet-participation")]
             AccountMethod::GetParticipationEvent { event_id } => {
                 convert_async_panics(|| async {
                     let event_and_nodes = account.get_participation_event(event_id).await?;
@@ -958,7 +1815,7 @@ impl WalletMessageHandler {
                 })
                 .await
             }
-            #[cfg(feature = "participation")]
+            #[cfg(feature = "wallet-participation")]
             AccountMethod::GetParticipationEventIds { node, event_type } => {
                 convert_async_panics(|| async {
                     let event_ids = account.get_participation_event_ids(&node, event_type).await?;
@@ -966,7 +1823,7 @@ impl WalletMessageHandler {
                 })
                 .await
             }
-            #[cfg(feature = "participation")]
+            #[cfg(feature = "wallet-participation")]
             AccountMethod::GetParticipationEventStatus { event_id } => {
                 convert_async_panics(|| async {
                     let event_status = account.get_participation_event_status(&event_id).await?;
@@ -974,7 +1831,7 @@ impl WalletMessageHandler {
                 })
                 .await
             }
-            #[cfg(feature = "participation")]
+            #[cfg(feature = "wallet-participation")]
             AccountMethod::GetParticipationEvents => {
                 convert_async_panics(|| async {
                     let events = account.get_participation_events().await?;
@@ -992,7 +1849,12 @@ impl WalletMessageHandler {
     }
 
     /// The create account message handler.
-    async fn create_account(&self, alias: Option<String>, bech32_hrp: Option<impl HrpLike>) -> Result<Response> {
+    async fn create_account(
+        &self,
+        alias: Option<String>,
+        bech32_hrp: Option<impl HrpLike>,
+        watch_only_addresses: Option<Vec<Bech32Address>>,
+    ) -> Result<Response> {
         let mut builder = self.wallet.create_account();
 
         if let Some(alias) = alias {
@@ -1003,6 +1865,10 @@ impl WalletMessageHandler {
             builder = builder.with_bech32_hrp(bech32_hrp.to_hrp()?);
         }
 
+        if let Some(addresses) = watch_only_addresses {
+            builder = builder.with_watch_only_addresses(addresses);
+        }
+
         match builder.finish().await {
             Ok(account) => {
                 let account = account.details().await;
@@ -1,18 +1,25 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::fmt::{Debug, Formatter, Result};
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Formatter, Result},
+};
 
 use serde::Serialize;
-#[cfg(feature = "participation")]
+#[cfg(feature = "wallet-participation")]
 use {
     crate::types::api::plugins::participation::types::{ParticipationEventId, ParticipationEventStatus},
     crate::wallet::account::operations::participation::{AccountParticipationOverview, ParticipationEventWithNodes},
-    std::collections::HashMap,
+    crate::wallet::WalletParticipationOverview,
 };
 
 #[cfg(feature = "ledger_nano")]
 use crate::client::secret::LedgerNanoStatus;
+#[cfg(feature = "stronghold")]
+use crate::wallet::BackupReport;
+#[cfg(feature = "storage")]
+use crate::wallet::{account::AuditLogEntry, StorageCompaction};
 use crate::{
     client::{
         api::{PreparedTransactionDataDto, SignedTransactionDataDto},
@@ -20,17 +27,28 @@ use crate::{
     },
     types::block::{
         address::Bech32Address,
-        output::{dto::OutputDto, OutputId},
+        output::{dto::OutputDto, AliasId, OutputId},
+        payload::transaction::{TransactionEssenceHash, TransactionId},
         BlockId,
     },
     wallet::{
         account::{
-            operations::transaction::high_level::minting::mint_native_token::MintTokenTransactionDto,
-            types::{address::AccountAddress, AccountBalanceDto, AddressWithUnspentOutputs, TransactionDto},
-            OutputDataDto,
+            operations::transaction::high_level::{
+                burning_melting::can_burn::BurnBlocker,
+                create_alias::CreateAliasTransactionDto,
+                minting::{mint_native_token::MintTokenTransactionDto, mint_nfts::MintNftTransactionDto},
+            },
+            types::{
+                address::AccountAddress, AccountBalanceDto, AddressWithUnspentOutputs, CompactOutputData,
+                TransactionDto,
+            },
+            AddressClusteringReport, AddressDerivationProof, AlertThresholds, DustPolicy, InternalAddressPoolStatus,
+            Irc30Metadata, LegacyAddressFunds, NftMetadata, OutputDataDto, OutputLineage,
+            PreparedMintTokenTransactionDto, RetiredAddress, SpendingAllowance, TokenPolicy, TransactionEstimate,
+            TransactionRateLimit, UtxoHealthReport,
         },
         message_interface::dtos::AccountDetailsDto,
-        Error,
+        AddressBook, BackgroundSyncStatus, Error, TransactionTemplate,
     },
 };
 
@@ -44,16 +62,43 @@ pub enum Response {
     Account(AccountDetailsDto),
     /// Response for [`GetAccountIndexes`](crate::wallet::message_interface::Message::GetAccountIndexes)
     AccountIndexes(Vec<u32>),
-    /// Response for [`GetAccounts`](crate::wallet::message_interface::Message::GetAccounts)
+    /// Response for
+    /// [`GetAccounts`](crate::wallet::message_interface::Message::GetAccounts),
+    /// [`ImportFireflyBackup`](crate::wallet::message_interface::Message::ImportFireflyBackup),
+    /// [`RestoreAccountsFromBackup`](crate::wallet::message_interface::Message::RestoreAccountsFromBackup)
     Accounts(Vec<AccountDetailsDto>),
+    /// Response for [`GetAddressBook`](crate::wallet::message_interface::Message::GetAddressBook)
+    AddressBook(AddressBook),
+    /// Response for
+    /// [`FindAddressByLabel`](crate::wallet::message_interface::AccountMethod::FindAddressByLabel),
+    /// [`CurrentReceiveAddress`](crate::wallet::message_interface::AccountMethod::CurrentReceiveAddress),
+    /// [`SetAddressLabel`](crate::wallet::message_interface::AccountMethod::SetAddressLabel),
+    /// [`SetAddressMetadata`](crate::wallet::message_interface::AccountMethod::SetAddressMetadata)
+    Address(Option<AccountAddress>),
+    /// Response for [`VerifyAddressOnDevice`](crate::wallet::message_interface::AccountMethod::VerifyAddressOnDevice)
+    AddressDerivationProof(AddressDerivationProof),
+    /// Response for
+    /// [`GetAddressRotationHistory`](crate::wallet::message_interface::AccountMethod::GetAddressRotationHistory)
+    RetiredAddresses(Vec<RetiredAddress>),
     /// Response for [`Addresses`](crate::wallet::message_interface::AccountMethod::Addresses)
     Addresses(Vec<AccountAddress>),
     /// Response for
     /// [`AddressesWithUnspentOutputs`](crate::wallet::message_interface::AccountMethod::AddressesWithUnspentOutputs)
     AddressesWithUnspentOutputs(Vec<AddressWithUnspentOutputs>),
+    /// Response for [`GetAuditLog`](crate::wallet::message_interface::AccountMethod::GetAuditLog)
+    #[cfg(feature = "storage")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
+    AuditLog(Vec<AuditLogEntry>),
+    /// Response for [`VerifyBackup`](crate::wallet::message_interface::Message::VerifyBackup)
+    #[cfg(feature = "stronghold")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
+    BackupReport(BackupReport),
     /// Response for
+    /// [`BlockId`](crate::wallet::message_interface::Message::BlockId),
     /// [`RetryTransactionUntilIncluded`](crate::wallet::message_interface::AccountMethod::RetryTransactionUntilIncluded)
     BlockId(BlockId),
+    /// Response for [`CanBurn`](crate::wallet::message_interface::AccountMethod::CanBurn)
+    BurnBlockers(Vec<BurnBlocker>),
     /// Response for
     /// [`BuildAliasOutput`](crate::wallet::message_interface::AccountMethod::BuildAliasOutput)
     /// [`BuildBasicOutput`](crate::wallet::message_interface::AccountMethod::BuildBasicOutput)
@@ -70,23 +115,60 @@ pub enum Response {
     OutputIds(Vec<OutputId>),
     /// Response for [`GetOutput`](crate::wallet::message_interface::AccountMethod::GetOutput)
     OutputData(Option<Box<OutputDataDto>>),
+    /// Response for [`TraceOutput`](crate::wallet::message_interface::AccountMethod::TraceOutput)
+    OutputLineage(OutputLineage),
+    /// Response for [`FindLegacyFunds`](crate::wallet::message_interface::AccountMethod::FindLegacyFunds)
+    LegacyAddressFunds(Vec<LegacyAddressFunds>),
     /// Response for
     /// [`Outputs`](crate::wallet::message_interface::AccountMethod::Outputs),
     /// [`UnspentOutputs`](crate::wallet::message_interface::AccountMethod::UnspentOutputs)
     OutputsData(Vec<OutputDataDto>),
     /// Response for
+    /// [`Outputs`](crate::wallet::message_interface::AccountMethod::Outputs),
+    /// [`UnspentOutputs`](crate::wallet::message_interface::AccountMethod::UnspentOutputs) with `compact` set
+    CompactOutputsData(Vec<CompactOutputData>),
+    /// Response for
+    /// [`PrepareBurn`](crate::wallet::message_interface::AccountMethod::PrepareBurn),
+    /// [`PrepareConsolidateOutputs`](crate::wallet::message_interface::AccountMethod::PrepareConsolidateOutputs),
+    /// [`PrepareCreateAliasOutput`](crate::wallet::message_interface::AccountMethod::PrepareCreateAliasOutput),
+    /// [`PrepareDecreaseNativeTokenSupply`](crate::wallet::message_interface::AccountMethod::PrepareDecreaseNativeTokenSupply),
+    /// [`PrepareDecreaseVotingPower`](crate::wallet::message_interface::AccountMethod::PrepareDecreaseVotingPower),
+    /// [`PrepareIncreaseVotingPower`](crate::wallet::message_interface::AccountMethod::PrepareIncreaseVotingPower),
+    /// [`PrepareMintNfts`](crate::wallet::message_interface::AccountMethod::PrepareMintNfts),
     /// [`PrepareSendAmount`](crate::wallet::message_interface::AccountMethod::PrepareSendAmount),
-    /// [`PrepareTransaction`](crate::wallet::message_interface::AccountMethod::PrepareTransaction)
+    /// [`PrepareSendNativeTokens`](crate::wallet::message_interface::AccountMethod::PrepareSendNativeTokens),
+    /// [`PrepareSendDecimalNativeTokens`](crate::wallet::message_interface::AccountMethod::PrepareSendDecimalNativeTokens),
+    /// [`PrepareSendNft`](crate::wallet::message_interface::AccountMethod::PrepareSendNft),
+    /// [`PrepareSetVotingPower`](crate::wallet::message_interface::AccountMethod::PrepareSetVotingPower),
+    /// [`PrepareStopParticipating`](crate::wallet::message_interface::AccountMethod::PrepareStopParticipating),
+    /// [`PrepareSweepLegacyFunds`](crate::wallet::message_interface::AccountMethod::PrepareSweepLegacyFunds),
+    /// [`PrepareTransaction`](crate::wallet::message_interface::AccountMethod::PrepareTransaction),
+    /// [`PrepareVote`](crate::wallet::message_interface::AccountMethod::PrepareVote)
     PreparedTransaction(PreparedTransactionDataDto),
     /// Response for
+    /// [`PrepareIncreaseNativeTokenSupply`](crate::wallet::message_interface::AccountMethod::PrepareIncreaseNativeTokenSupply),
+    /// [`PrepareMintNativeToken`](crate::wallet::message_interface::AccountMethod::PrepareMintNativeToken)
+    PreparedMintTokenTransaction(PreparedMintTokenTransactionDto),
+    /// Response for
     /// [`GetTransaction`](crate::wallet::message_interface::AccountMethod::GetTransaction),
-    /// [`GetIncomingTransaction`](crate::wallet::message_interface::AccountMethod::GetIncomingTransaction)
+    /// [`GetIncomingTransaction`](crate::wallet::message_interface::AccountMethod::GetIncomingTransaction),
+    /// [`ConsolidateMicroOutputs`](crate::wallet::message_interface::AccountMethod::ConsolidateMicroOutputs)
     Transaction(Option<Box<TransactionDto>>),
     /// Response for
     /// [`Transactions`](crate::wallet::message_interface::AccountMethod::Transactions),
     /// [`PendingTransactions`](crate::wallet::message_interface::AccountMethod::PendingTransactions),
-    /// [`IncomingTransactions`](crate::wallet::message_interface::AccountMethod::IncomingTransactions)
+    /// [`IncomingTransactions`](crate::wallet::message_interface::AccountMethod::IncomingTransactions),
+    /// [`Sweep`](crate::wallet::message_interface::AccountMethod::Sweep),
+    /// [`ConsolidateAllOutputs`](crate::wallet::message_interface::AccountMethod::ConsolidateAllOutputs)
     Transactions(Vec<TransactionDto>),
+    /// Response for [`ExportTransactions`](crate::wallet::message_interface::AccountMethod::ExportTransactions)
+    TransactionExport(String),
+    /// Response for [`TransactionId`](crate::wallet::message_interface::Message::TransactionId)
+    TransactionId(TransactionId),
+    /// Response for [`HashTransactionEssence`](crate::wallet::message_interface::Message::HashTransactionEssence)
+    TransactionEssenceHash(TransactionEssenceHash),
+    /// Response for [`GetTransactionTemplates`](crate::wallet::message_interface::Message::GetTransactionTemplates)
+    TransactionTemplates(HashMap<String, TransactionTemplate>),
     /// Response for
     /// [`SignTransactionEssence`](crate::wallet::message_interface::AccountMethod::SignTransactionEssence)
     /// [`SubmitAndStoreTransaction`](crate::wallet::message_interface::AccountMethod::SubmitAndStoreTransaction)
@@ -98,6 +180,36 @@ pub enum Response {
     /// [`GetBalance`](crate::wallet::message_interface::AccountMethod::GetBalance),
     /// [`SyncAccount`](crate::wallet::message_interface::AccountMethod::SyncAccount)
     Balance(AccountBalanceDto),
+    /// Response for [`GetUtxoHealth`](crate::wallet::message_interface::AccountMethod::GetUtxoHealth)
+    UtxoHealth(UtxoHealthReport),
+    /// Response for [`GetAddressClustering`](crate::wallet::message_interface::AccountMethod::GetAddressClustering)
+    AddressClustering(AddressClusteringReport),
+    /// Response for
+    /// [`RemoveSpendingAllowance`](crate::wallet::message_interface::AccountMethod::RemoveSpendingAllowance)
+    SpendingAllowance(Option<SpendingAllowance>),
+    /// Response for
+    /// [`GetSpendingAllowances`](crate::wallet::message_interface::AccountMethod::GetSpendingAllowances)
+    SpendingAllowances(Vec<SpendingAllowance>),
+    /// Response for [`GetTokenPolicy`](crate::wallet::message_interface::AccountMethod::GetTokenPolicy)
+    TokenPolicy(Option<TokenPolicy>),
+    /// Response for [`GetDustPolicy`](crate::wallet::message_interface::AccountMethod::GetDustPolicy)
+    DustPolicy(Option<DustPolicy>),
+    /// Response for [`GetAlertThresholds`](crate::wallet::message_interface::AccountMethod::GetAlertThresholds)
+    AlertThresholds(Option<AlertThresholds>),
+    /// Response for
+    /// [`GetInternalAddressPoolStatus`](crate::wallet::message_interface::AccountMethod::GetInternalAddressPoolStatus)
+    InternalAddressPoolStatus(InternalAddressPoolStatus),
+    /// Response for [`GetRateLimit`](crate::wallet::message_interface::AccountMethod::GetRateLimit)
+    RateLimit(Option<TransactionRateLimit>),
+    /// Response for [`GetPrimaryAlias`](crate::wallet::message_interface::AccountMethod::GetPrimaryAlias)
+    PrimaryAlias(Option<AliasId>),
+    /// Response for [`EstimateTransaction`](crate::wallet::message_interface::AccountMethod::EstimateTransaction)
+    TransactionEstimate(TransactionEstimate),
+    /// Response for
+    /// [`GetNativeTokenMetadata`](crate::wallet::message_interface::AccountMethod::GetNativeTokenMetadata)
+    NativeTokenMetadata(Option<Irc30Metadata>),
+    /// Response for [`GetNftMetadata`](crate::wallet::message_interface::AccountMethod::GetNftMetadata)
+    NftMetadata(Option<NftMetadata>),
     /// Response for
     /// [`GetLedgerNanoStatus`](crate::wallet::message_interface::Message::GetLedgerNanoStatus),
     #[cfg(feature = "ledger_nano")]
@@ -106,23 +218,39 @@ pub enum Response {
     /// Response for
     /// [`ConsolidateOutputs`](crate::wallet::message_interface::AccountMethod::ConsolidateOutputs)
     /// [`ClaimOutputs`](crate::wallet::message_interface::AccountMethod::ClaimOutputs)
-    /// [`CreateAliasOutput`](crate::wallet::message_interface::AccountMethod::CreateAliasOutput)
     /// [`SendAmount`](crate::wallet::message_interface::AccountMethod::SendAmount),
-    /// [`MintNfts`](crate::wallet::message_interface::AccountMethod::MintNfts),
     /// [`SendAmount`](crate::wallet::message_interface::AccountMethod::SendAmount),
     /// [`SendNativeTokens`](crate::wallet::message_interface::AccountMethod::SendNativeTokens),
+    /// [`SendDecimalNativeTokens`](crate::wallet::message_interface::AccountMethod::SendDecimalNativeTokens),
     /// [`SendNft`](crate::wallet::message_interface::AccountMethod::SendNft),
     /// [`SendOutputs`](crate::wallet::message_interface::AccountMethod::SendOutputs)
+    /// [`SendFromAccounts`](crate::wallet::message_interface::Message::SendFromAccounts)
     /// [`SubmitAndStoreTransaction`](crate::wallet::message_interface::AccountMethod::SubmitAndStoreTransaction)
     /// [`Vote`](crate::wallet::message_interface::AccountMethod::Vote)
     /// [`StopParticipating`](crate::wallet::message_interface::AccountMethod::StopParticipating)
     /// [`IncreaseVotingPower`](crate::wallet::message_interface::AccountMethod::IncreaseVotingPower)
     /// [`DecreaseVotingPower`](crate::wallet::message_interface::AccountMethod::DecreaseVotingPower)
+    /// [`SetVotingPower`](crate::wallet::message_interface::AccountMethod::SetVotingPower)
     SentTransaction(TransactionDto),
+    /// Response for [`CompactStorage`](crate::wallet::message_interface::Message::CompactStorage)
+    #[cfg(feature = "storage")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
+    StorageCompaction(StorageCompaction),
     /// Response for
     /// [`MintNativeToken`](crate::wallet::message_interface::AccountMethod::MintNativeToken),
     MintTokenTransaction(MintTokenTransactionDto),
     /// Response for
+    /// [`MintNfts`](crate::wallet::message_interface::AccountMethod::MintNfts),
+    MintNftTransaction(MintNftTransactionDto),
+    /// Response for
+    /// [`CreateAliasOutput`](crate::wallet::message_interface::AccountMethod::CreateAliasOutput),
+    CreateAliasTransaction(CreateAliasTransactionDto),
+    /// Response for
+    /// [`PauseBackgroundSync`](crate::wallet::message_interface::Message::PauseBackgroundSync),
+    /// [`ResumeBackgroundSync`](crate::wallet::message_interface::Message::ResumeBackgroundSync),
+    /// [`GetBackgroundSyncStatus`](crate::wallet::message_interface::Message::GetBackgroundSyncStatus)
+    BackgroundSyncStatus(BackgroundSyncStatus),
+    /// Response for
     /// [`IsStrongholdPasswordAvailable`](crate::wallet::message_interface::Message::IsStrongholdPasswordAvailable)
     StrongholdPasswordIsAvailable(bool),
     /// An error occurred.
@@ -135,51 +263,66 @@ pub enum Response {
     NodeInfo(NodeInfoWrapper),
     /// Response for
     /// [`GetParticipationEvent`](crate::wallet::message_interface::AccountMethod::GetParticipationEvent)
-    #[cfg(feature = "participation")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
+    #[cfg(feature = "wallet-participation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "wallet-participation")))]
     ParticipationEvent(Option<ParticipationEventWithNodes>),
     /// Response for
     /// [`GetParticipationEventIds`](crate::wallet::message_interface::AccountMethod::GetParticipationEventIds)
-    #[cfg(feature = "participation")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
+    #[cfg(feature = "wallet-participation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "wallet-participation")))]
     ParticipationEventIds(Vec<ParticipationEventId>),
     /// Response for
     /// [`GetParticipationEventStatus`](crate::wallet::message_interface::AccountMethod::GetParticipationEventStatus)
-    #[cfg(feature = "participation")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
+    #[cfg(feature = "wallet-participation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "wallet-participation")))]
     ParticipationEventStatus(ParticipationEventStatus),
     /// Response for
     /// [`GetParticipationEvents`](crate::wallet::message_interface::AccountMethod::GetParticipationEvents)
     /// [`RegisterParticipationEvent`](crate::wallet::message_interface::AccountMethod::RegisterParticipationEvents)
-    #[cfg(feature = "participation")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
+    #[cfg(feature = "wallet-participation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "wallet-participation")))]
     ParticipationEvents(HashMap<ParticipationEventId, ParticipationEventWithNodes>),
     /// Response for
     /// [`GetParticipationOverview`](crate::wallet::message_interface::AccountMethod::GetParticipationOverview)
-    #[cfg(feature = "participation")]
-    #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
+    #[cfg(feature = "wallet-participation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "wallet-participation")))]
     AccountParticipationOverview(AccountParticipationOverview),
+    /// Response for [`GetParticipationOverview`](crate::wallet::message_interface::Message::GetParticipationOverview)
+    #[cfg(feature = "wallet-participation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "wallet-participation")))]
+    WalletParticipationOverview(WalletParticipationOverview),
+    /// Response for [`GetAutoRevote`](crate::wallet::message_interface::AccountMethod::GetAutoRevote)
+    #[cfg(feature = "wallet-participation")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "wallet-participation")))]
+    AutoRevote(bool),
     /// Response for [`Bech32ToHex`](crate::wallet::message_interface::Message::Bech32ToHex)
     HexAddress(String),
     /// Response for [`HexToBech32`](crate::wallet::message_interface::Message::HexToBech32)
     /// Response for [`GenerateAddress`](crate::wallet::message_interface::Message::GenerateAddress)
+    /// Response for [`GetPrimaryAddress`](crate::wallet::message_interface::AccountMethod::GetPrimaryAddress)
     Bech32Address(Bech32Address),
     /// Response for
     /// [`RequestFundsFromFaucet`](crate::wallet::message_interface::AccountMethod::RequestFundsFromFaucet)
     Faucet(String),
     /// Response for
     /// [`Backup`](crate::wallet::message_interface::Message::Backup),
+    /// [`BackupToEncryptedJson`](crate::wallet::message_interface::Message::BackupToEncryptedJson),
     /// [`ClearStrongholdPassword`](crate::wallet::message_interface::Message::ClearStrongholdPassword),
     /// [`DeregisterParticipationEvent`](crate::wallet::message_interface::AccountMethod::DeregisterParticipationEvent),
     /// [`RestoreBackup`](crate::wallet::message_interface::Message::RestoreBackup),
+    /// [`RestoreFromEncryptedJson`](crate::wallet::message_interface::Message::RestoreFromEncryptedJson),
     /// [`VerifyMnemonic`](crate::wallet::message_interface::Message::VerifyMnemonic),
     /// [`SetClientOptions`](crate::wallet::message_interface::Message::SetClientOptions),
     /// [`SetStrongholdPassword`](crate::wallet::message_interface::Message::SetStrongholdPassword),
     /// [`SetStrongholdPasswordClearInterval`](crate::wallet::message_interface::Message::SetStrongholdPasswordClearInterval),
+    /// [`SetStorageCompactionInterval`](crate::wallet::message_interface::Message::SetStorageCompactionInterval),
     /// [`StoreMnemonic`](crate::wallet::message_interface::Message::StoreMnemonic),
     /// [`StartBackgroundSync`](crate::wallet::message_interface::Message::StartBackgroundSync),
     /// [`StopBackgroundSync`](crate::wallet::message_interface::Message::StopBackgroundSync),
     /// [`EmitTestEvent`](crate::wallet::message_interface::Message::EmitTestEvent),
+    /// [`SetVotingPower`](crate::wallet::message_interface::AccountMethod::SetVotingPower),
+    /// [`SetTransactionSequencing`](crate::wallet::message_interface::AccountMethod::SetTransactionSequencing),
+    /// [`WaitForSequenceConfirmed`](crate::wallet::message_interface::AccountMethod::WaitForSequenceConfirmed),
     Ok(()),
 }
 
@@ -190,30 +333,71 @@ impl Debug for Response {
             Self::Account(account) => write!(f, "Account({account:?})"),
             Self::AccountIndexes(account_indexes) => write!(f, "AccountIndexes({account_indexes:?})"),
             Self::Accounts(accounts) => write!(f, "Accounts({accounts:?})"),
+            Self::AddressBook(address_book) => write!(f, "AddressBook({address_book:?})"),
+            Self::Address(address) => write!(f, "Address({address:?})"),
+            Self::AddressDerivationProof(proof) => write!(f, "AddressDerivationProof({proof:?})"),
+            Self::RetiredAddresses(addresses) => write!(f, "RetiredAddresses({addresses:?})"),
             Self::Addresses(addresses) => write!(f, "Addresses({addresses:?})"),
             Self::AddressesWithUnspentOutputs(addresses) => {
                 write!(f, "AddressesWithUnspentOutputs({addresses:?})")
             }
+            #[cfg(feature = "storage")]
+            Self::AuditLog(entries) => write!(f, "AuditLog({entries:?})"),
+            #[cfg(feature = "storage")]
+            Self::StorageCompaction(compaction) => write!(f, "StorageCompaction({compaction:?})"),
+            #[cfg(feature = "stronghold")]
+            Self::BackupReport(report) => write!(f, "BackupReport({report:?})"),
             Self::BlockId(block_id) => write!(f, "BlockId({block_id:?})"),
+            Self::BurnBlockers(blockers) => write!(f, "BurnBlockers({blockers:?})"),
             Self::Output(output) => write!(f, "Output({output:?})"),
             Self::MinimumRequiredStorageDeposit(amount) => write!(f, "MinimumRequiredStorageDeposit({amount:?})"),
             Self::OutputIds(output_ids) => write!(f, "OutputIds({output_ids:?})"),
             Self::OutputData(output) => write!(f, "OutputData({output:?})"),
+            Self::OutputLineage(lineage) => write!(f, "OutputLineage({lineage:?})"),
+            Self::LegacyAddressFunds(legacy_funds) => write!(f, "LegacyAddressFunds({legacy_funds:?})"),
             Self::OutputsData(outputs) => write!(f, "OutputsData{outputs:?}"),
+            Self::CompactOutputsData(outputs) => write!(f, "CompactOutputsData{outputs:?}"),
             Self::PreparedTransaction(transaction_data) => {
                 write!(f, "PreparedTransaction({transaction_data:?})")
             }
+            Self::PreparedMintTokenTransaction(transaction_data) => {
+                write!(f, "PreparedMintTokenTransaction({transaction_data:?})")
+            }
             Self::Transaction(transaction) => write!(f, "Transaction({transaction:?})"),
             Self::Transactions(transactions) => write!(f, "Transactions({transactions:?})"),
+            Self::TransactionExport(export) => write!(f, "TransactionExport({export:?})"),
+            Self::TransactionId(transaction_id) => write!(f, "TransactionId({transaction_id:?})"),
+            Self::TransactionEssenceHash(hash) => write!(f, "TransactionEssenceHash({hash:?})"),
+            Self::TransactionTemplates(templates) => write!(f, "TransactionTemplates({templates:?})"),
             Self::SignedTransactionData(signed_transaction_data) => {
                 write!(f, "SignedTransactionData({signed_transaction_data:?})")
             }
             Self::GeneratedAddress(addresses) => write!(f, "GeneratedAddress({addresses:?})"),
             Self::Balance(balance) => write!(f, "Balance({balance:?})"),
+            Self::UtxoHealth(report) => write!(f, "UtxoHealth({report:?})"),
+            Self::AddressClustering(report) => write!(f, "AddressClustering({report:?})"),
+            Self::SpendingAllowance(allowance) => write!(f, "SpendingAllowance({allowance:?})"),
+            Self::SpendingAllowances(allowances) => write!(f, "SpendingAllowances({allowances:?})"),
+            Self::TokenPolicy(token_policy) => write!(f, "TokenPolicy({token_policy:?})"),
+            Self::DustPolicy(dust_policy) => write!(f, "DustPolicy({dust_policy:?})"),
+            Self::AlertThresholds(alert_thresholds) => write!(f, "AlertThresholds({alert_thresholds:?})"),
+            Self::InternalAddressPoolStatus(status) => write!(f, "InternalAddressPoolStatus({status:?})"),
+            Self::RateLimit(rate_limit) => write!(f, "RateLimit({rate_limit:?})"),
+            Self::PrimaryAlias(alias_id) => write!(f, "PrimaryAlias({alias_id:?})"),
+            Self::TransactionEstimate(estimate) => write!(f, "TransactionEstimate({estimate:?})"),
+            Self::NativeTokenMetadata(metadata) => write!(f, "NativeTokenMetadata({metadata:?})"),
+            Self::NftMetadata(metadata) => write!(f, "NftMetadata({metadata:?})"),
             Self::SentTransaction(transaction) => write!(f, "SentTransaction({transaction:?})"),
             Self::MintTokenTransaction(mint_transaction) => {
                 write!(f, "MintTokenTransaction({mint_transaction:?})")
             }
+            Self::MintNftTransaction(mint_transaction) => {
+                write!(f, "MintNftTransaction({mint_transaction:?})")
+            }
+            Self::CreateAliasTransaction(alias_transaction) => {
+                write!(f, "CreateAliasTransaction({alias_transaction:?})")
+            }
+            Self::BackgroundSyncStatus(status) => write!(f, "BackgroundSyncStatus({status:?})"),
             Self::StrongholdPasswordIsAvailable(is_available) => {
                 write!(f, "StrongholdPasswordIsAvailable({is_available:?})")
             }
@@ -226,18 +410,22 @@ impl Debug for Response {
             Self::HexAddress(hex_address) => write!(f, "Hex encoded address({hex_address:?})"),
             Self::Bech32Address(bech32_address) => write!(f, "Bech32 encoded address({bech32_address:?})"),
             Self::Ok(()) => write!(f, "Ok(())"),
-            #[cfg(feature = "participation")]
+            #[cfg(feature = "wallet-participation")]
             Self::ParticipationEvent(event) => write!(f, "ParticipationEvent({event:?})"),
-            #[cfg(feature = "participation")]
+            #[cfg(feature = "wallet-participation")]
             Self::ParticipationEventStatus(event_status) => write!(f, "ParticipationEventStatus({event_status:?})"),
-            #[cfg(feature = "participation")]
+            #[cfg(feature = "wallet-participation")]
             Self::ParticipationEvents(events) => write!(f, "ParticipationEvents({events:?})"),
-            #[cfg(feature = "participation")]
+            #[cfg(feature = "wallet-participation")]
             Self::ParticipationEventIds(event_ids) => write!(f, "ParticipationEventIds({event_ids:?})"),
-            #[cfg(feature = "participation")]
+            #[cfg(feature = "wallet-participation")]
             Self::AccountParticipationOverview(overview) => {
                 write!(f, "AccountParticipationOverview({overview:?})")
             }
+            #[cfg(feature = "wallet-participation")]
+            Self::WalletParticipationOverview(overview) => write!(f, "WalletParticipationOverview({overview:?})"),
+            #[cfg(feature = "wallet-participation")]
+            Self::AutoRevote(auto_revote) => write!(f, "AutoRevote({auto_revote})"),
             Self::Faucet(response) => write!(f, "Faucet({response:?})"),
         }
     }
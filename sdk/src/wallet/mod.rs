@@ -8,8 +8,8 @@
 pub mod account;
 /// The message passing interface for the library. A different way to call the wallet functions, useful for bindings to
 /// other languages.
-#[cfg(feature = "message_interface")]
-#[cfg_attr(docsrs, doc(cfg(feature = "message_interface")))]
+#[cfg(feature = "message-interface")]
+#[cfg_attr(docsrs, doc(cfg(feature = "message-interface")))]
 pub mod message_interface;
 #[cfg(any(feature = "stronghold", feature = "storage"))]
 pub(crate) mod migration;
@@ -26,25 +26,52 @@ pub mod error;
 #[cfg(feature = "events")]
 #[cfg_attr(docsrs, doc(cfg(feature = "events")))]
 pub mod events;
+/// The module for running the wallet as a long-running service
+#[cfg(all(feature = "service", not(target_family = "wasm")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "service")))]
+pub mod service;
 /// The storage module.
 #[cfg(feature = "storage")]
 #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
 pub mod storage;
 /// The module for spawning tasks on a thread
 pub(crate) mod task;
+/// The module with a deterministic test harness for the wallet
+#[cfg(all(feature = "testkit", not(target_family = "wasm")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "testkit")))]
+pub mod testkit;
 
+#[cfg(feature = "debug_bundle")]
+pub use self::wallet::operations::debug_bundle::DebugBundleOptions;
+#[cfg(feature = "wallet-participation")]
+pub use self::wallet::operations::participation::{ParticipationEventTotals, WalletParticipationOverview};
+#[cfg(feature = "storage")]
+pub use self::wallet::operations::storage_compaction::StorageCompaction;
+#[cfg(feature = "stronghold")]
+pub use self::wallet::operations::stronghold_backup::{
+    auto_backup::AutoBackupOptions, BackupAccount, BackupIssue, BackupReport, BackupSecretManagerKind,
+};
 pub use self::{
     account::{
         operations::transaction::high_level::{
             minting::{mint_native_token::MintNativeTokenParams, mint_nfts::MintNftParams},
             send_amount::SendAmountParams,
-            send_native_tokens::SendNativeTokensParams,
-            send_nft::SendNftParams,
+            send_assets::AssetTransfer,
+            send_native_tokens::{DecimalNativeToken, SendDecimalNativeTokensParams, SendNativeTokensParams},
+            send_nft::{NftSendResult, SendNftParams},
         },
         Account,
     },
     error::Error,
-    wallet::{Wallet, WalletBuilder},
+    wallet::{
+        operations::{
+            address_book::AddressBook,
+            background_syncing::BackgroundSyncStatus,
+            sync_all::AccountSyncResult,
+            transaction_templates::{TemplateOutput, TransactionTemplate},
+        },
+        Wallet, WalletBuilder,
+    },
 };
 
 /// The wallet Result type.
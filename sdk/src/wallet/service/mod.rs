@@ -0,0 +1,272 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! A long-running wallet service, assembled from the background syncing, the event listeners and the message
+//! interface.
+//!
+//! ```ignore
+//! let service = WalletService::builder(wallet)
+//!     .with_background_syncing(None, None)
+//!     .with_event_sink(vec![], |event| println!("{event:?}"))
+//!     .with_rpc(RpcOptions::new(auth_token).with_access(RpcAccess::Full))
+//!     .start()
+//!     .await?;
+//! service.run_until(tokio::signal::ctrl_c().map(|_| ())).await?;
+//! ```
+//!
+//! The JSON-RPC 2.0 server accepts `POST /` requests whose `method` is the `cmd` and whose `params` are the `payload`
+//! of a [`Message`](crate::wallet::message_interface::Message). `GET /health` returns the [`ServiceHealth`]. All
+//! requests need the auth token of the [`RpcOptions`] as `Authorization: Bearer <token>` header.
+
+mod rpc;
+
+use std::{
+    net::{Ipv4Addr, SocketAddr},
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use futures::{Future, StreamExt};
+use serde::{Deserialize, Serialize};
+use tokio::task::JoinHandle;
+
+use self::rpc::RpcServer;
+use crate::wallet::{
+    account::operations::syncing::SyncOptions,
+    events::types::{Event, WalletEventType},
+    message_interface::WalletMessageHandler,
+    BackgroundSyncStatus, Result, Wallet,
+};
+
+type EventSink = Arc<dyn Fn(&Event) + Send + Sync>;
+
+/// The port the JSON-RPC server listens on by default.
+pub const DEFAULT_RPC_PORT: u16 = 9000;
+
+/// The messages that can be sent to the JSON-RPC server.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RpcAccess {
+    /// Only messages that read the wallet state, like the balance or the transactions. Messages that change the
+    /// accounts, send transactions or reveal secrets are rejected.
+    #[default]
+    ReadOnly,
+    /// All messages.
+    Full,
+}
+
+/// Options for the JSON-RPC server of a [`WalletService`].
+#[derive(Debug, Clone)]
+pub struct RpcOptions {
+    address: SocketAddr,
+    auth_token: String,
+    access: RpcAccess,
+}
+
+impl RpcOptions {
+    /// Creates options for a read-only server on the loopback interface with the [`DEFAULT_RPC_PORT`]. Requests are
+    /// only accepted with the auth token.
+    pub fn new(auth_token: impl Into<String>) -> Self {
+        Self {
+            address: SocketAddr::from((Ipv4Addr::LOCALHOST, DEFAULT_RPC_PORT)),
+            auth_token: auth_token.into(),
+            access: RpcAccess::default(),
+        }
+    }
+
+    /// Sets the address the server is bound to, port 0 picks a free port. Other addresses than the loopback
+    /// interface make the wallet reachable from the network.
+    pub fn with_address(mut self, address: SocketAddr) -> Self {
+        self.address = address;
+        self
+    }
+
+    /// Sets the messages that can be sent to the server.
+    pub fn with_access(mut self, access: RpcAccess) -> Self {
+        self.access = access;
+        self
+    }
+}
+
+/// Builder for a [`WalletService`].
+pub struct WalletServiceBuilder {
+    wallet: Wallet,
+    background_syncing: Option<(Option<SyncOptions>, Option<Duration>)>,
+    event_sinks: Vec<(Vec<WalletEventType>, EventSink)>,
+    rpc_options: Option<RpcOptions>,
+}
+
+impl WalletServiceBuilder {
+    /// Creates a builder for a service of the wallet, without any subsystem enabled.
+    pub fn new(wallet: Wallet) -> Self {
+        Self {
+            wallet,
+            background_syncing: None,
+            event_sinks: Vec::new(),
+            rpc_options: None,
+        }
+    }
+
+    /// Syncs the accounts in the background while the service runs, see [`Wallet::start_background_syncing()`].
+    pub fn with_background_syncing(mut self, options: Option<SyncOptions>, interval: Option<Duration>) -> Self {
+        self.background_syncing.replace((options, interval));
+        self
+    }
+
+    /// Calls the sink for the wallet events while the service runs, an empty vec passes all events.
+    pub fn with_event_sink(
+        mut self,
+        events: Vec<WalletEventType>,
+        sink: impl Fn(&Event) + Send + Sync + 'static,
+    ) -> Self {
+        self.event_sinks.push((events, Arc::new(sink)));
+        self
+    }
+
+    /// Serves the JSON-RPC API and the health endpoint.
+    pub fn with_rpc(mut self, options: RpcOptions) -> Self {
+        self.rpc_options.replace(options);
+        self
+    }
+
+    /// Starts the enabled subsystems.
+    pub async fn start(self) -> Result<WalletService> {
+        log::debug!("[WalletService] starting");
+        // The sinks consume their own event streams, so they can be removed without touching other listeners
+        let mut event_sink_tasks = Vec::with_capacity(self.event_sinks.len());
+        for (events, sink) in self.event_sinks {
+            let mut events = Box::pin(self.wallet.event_stream(events).await);
+            event_sink_tasks.push(tokio::spawn(async move {
+                while let Some(event) = events.next().await {
+                    sink(&event);
+                }
+            }));
+        }
+
+        let background_syncing = self.background_syncing.is_some();
+        if let Some((options, interval)) = self.background_syncing {
+            self.wallet.start_background_syncing(options, interval).await?;
+        }
+
+        let health_probe = HealthProbe {
+            wallet: self.wallet,
+            background_syncing,
+            started_at: Instant::now(),
+        };
+        let rpc_server = match self.rpc_options {
+            Some(options) => Some(RpcServer::start(
+                options,
+                Arc::new(WalletMessageHandler::with_manager(health_probe.wallet.clone())),
+                health_probe.clone(),
+            )?),
+            None => None,
+        };
+
+        Ok(WalletService {
+            health_probe,
+            rpc_server,
+            event_sink_tasks,
+        })
+    }
+}
+
+/// A running wallet service, stopped with [`WalletService::shutdown()`].
+pub struct WalletService {
+    health_probe: HealthProbe,
+    rpc_server: Option<RpcServer>,
+    event_sink_tasks: Vec<JoinHandle<()>>,
+}
+
+impl WalletService {
+    /// Creates a builder for a service of the wallet.
+    pub fn builder(wallet: Wallet) -> WalletServiceBuilder {
+        WalletServiceBuilder::new(wallet)
+    }
+
+    /// Returns the wallet of the service.
+    pub fn wallet(&self) -> &Wallet {
+        &self.health_probe.wallet
+    }
+
+    /// Returns the address the JSON-RPC server is bound to.
+    pub fn rpc_address(&self) -> Option<SocketAddr> {
+        self.rpc_server.as_ref().map(RpcServer::address)
+    }
+
+    /// Returns the health of the service.
+    pub async fn health(&self) -> ServiceHealth {
+        self.health_probe.health().await
+    }
+
+    /// Runs the service until the signal completes, then shuts it down.
+    pub async fn run_until(self, signal: impl Future<Output = ()> + Send) -> Result<()> {
+        signal.await;
+        self.shutdown().await
+    }
+
+    /// Stops the JSON-RPC server and the background syncing and removes the event sinks of the service, other event
+    /// listeners of the wallet are kept. Requests that are already being processed are finished first.
+    pub async fn shutdown(self) -> Result<()> {
+        log::debug!("[WalletService] shutting down");
+        if let Some(rpc_server) = self.rpc_server {
+            rpc_server.stop().await;
+        }
+        let wallet = &self.health_probe.wallet;
+        if self.health_probe.background_syncing {
+            wallet.stop_background_syncing().await?;
+        }
+        for task in self.event_sink_tasks {
+            task.abort();
+        }
+        log::debug!("[WalletService] stopped");
+        Ok(())
+    }
+}
+
+/// The health of a [`WalletService`], returned by the health endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ServiceHealth {
+    /// Whether a healthy node is available and the background syncing runs, if it's enabled.
+    pub healthy: bool,
+    /// Seconds since the service was started.
+    pub uptime: u64,
+    /// The status of the background syncing.
+    pub background_syncing: BackgroundSyncStatus,
+    /// The amount of accounts of the wallet.
+    pub accounts: usize,
+    /// The amount of healthy nodes.
+    pub healthy_nodes: usize,
+}
+
+// The state that's needed to report the health of the service
+#[derive(Clone)]
+struct HealthProbe {
+    wallet: Wallet,
+    background_syncing: bool,
+    started_at: Instant,
+}
+
+impl HealthProbe {
+    async fn health(&self) -> ServiceHealth {
+        let healthy_nodes = self
+            .wallet
+            .client()
+            .node_manager
+            .read()
+            .await
+            .healthy_nodes
+            .read()
+            .map_or(0, |healthy_nodes| healthy_nodes.len());
+        let background_syncing = self.wallet.background_syncing_status();
+
+        ServiceHealth {
+            healthy: healthy_nodes > 0
+                && (!self.background_syncing || background_syncing != BackgroundSyncStatus::Stopped),
+            uptime: self.started_at.elapsed().as_secs(),
+            background_syncing,
+            accounts: self.wallet.accounts.read().await.len(),
+            healthy_nodes,
+        }
+    }
+}
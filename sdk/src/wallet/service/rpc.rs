@@ -0,0 +1,463 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    convert::Infallible,
+    net::{SocketAddr, TcpListener},
+    sync::Arc,
+};
+
+use hyper::{
+    body::HttpBody,
+    header::{AUTHORIZATION, CONTENT_TYPE},
+    service::{make_service_fn, service_fn},
+    Body, Method, Request, Server, StatusCode,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tokio::{
+    sync::{oneshot, Semaphore},
+    task::JoinHandle,
+};
+
+use super::{HealthProbe, RpcAccess, RpcOptions};
+use crate::wallet::{
+    message_interface::{Message, Response, WalletMessageHandler},
+    Error, Result,
+};
+
+// Requests with a larger body are rejected
+const MAX_BODY_SIZE: usize = 1024 * 1024;
+// Requests that exceed this amount of requests being processed at the same time are rejected
+const MAX_CONCURRENT_REQUESTS: usize = 32;
+
+// JSON-RPC 2.0 error codes
+const PARSE_ERROR: i32 = -32700;
+const INVALID_REQUEST: i32 = -32600;
+const METHOD_NOT_ALLOWED: i32 = -32601;
+const INVALID_PARAMS: i32 = -32602;
+const SERVER_ERROR: i32 = -32000;
+
+// The HTTP server for the JSON-RPC API and the health endpoint, the connections are handled as tasks on the runtime
+// that started the service.
+pub(super) struct RpcServer {
+    address: SocketAddr,
+    shutdown: oneshot::Sender<()>,
+    task: JoinHandle<()>,
+}
+
+impl RpcServer {
+    pub(super) fn start(
+        options: RpcOptions,
+        message_handler: Arc<WalletMessageHandler>,
+        health_probe: HealthProbe,
+    ) -> Result<Self> {
+        if options.auth_token.is_empty() {
+            return Err(Error::MissingParameter("auth token"));
+        }
+        let listener = TcpListener::bind(options.address)?;
+        listener.set_nonblocking(true)?;
+        let address = listener.local_addr()?;
+        let server = Server::from_tcp(listener).map_err(|e| Error::Io(std::io::Error::other(e)))?;
+        log::debug!("[WalletService] JSON-RPC server listening on {address}");
+
+        let context = Arc::new(RpcContext {
+            auth_token: options.auth_token,
+            access: options.access,
+            message_handler,
+            health_probe,
+            requests: Semaphore::new(MAX_CONCURRENT_REQUESTS),
+        });
+        let make_service = make_service_fn(move |_| {
+            let context = context.clone();
+            async move {
+                Ok::<_, Infallible>(service_fn(move |request| {
+                    let context = context.clone();
+                    async move { Ok::<_, Infallible>(handle_request(request, &context).await) }
+                }))
+            }
+        });
+
+        let (shutdown, shutdown_signal) = oneshot::channel();
+        let task = tokio::spawn(async move {
+            if let Err(err) = server
+                .serve(make_service)
+                .with_graceful_shutdown(async {
+                    shutdown_signal.await.ok();
+                })
+                .await
+            {
+                log::debug!("[WalletService] JSON-RPC server error: {err}");
+            }
+        });
+
+        Ok(Self {
+            address,
+            shutdown,
+            task,
+        })
+    }
+
+    pub(super) fn address(&self) -> SocketAddr {
+        self.address
+    }
+
+    // Waits until the requests that are being processed are answered
+    pub(super) async fn stop(self) {
+        self.shutdown.send(()).ok();
+        self.task.await.ok();
+        log::debug!("[WalletService] JSON-RPC server stopped");
+    }
+}
+
+struct RpcContext {
+    auth_token: String,
+    access: RpcAccess,
+    message_handler: Arc<WalletMessageHandler>,
+    health_probe: HealthProbe,
+    requests: Semaphore,
+}
+
+async fn handle_request(request: Request<Body>, context: &RpcContext) -> hyper::Response<Body> {
+    let Ok(_permit) = context.requests.try_acquire() else {
+        return http_response(StatusCode::SERVICE_UNAVAILABLE, Vec::new());
+    };
+    if !is_authorized(&request, &context.auth_token) {
+        return http_response(StatusCode::UNAUTHORIZED, Vec::new());
+    }
+
+    match (request.method(), request.uri().path()) {
+        (&Method::GET, "/health") => {
+            let health = context.health_probe.health().await;
+            let status = if health.healthy {
+                StatusCode::OK
+            } else {
+                StatusCode::SERVICE_UNAVAILABLE
+            };
+            http_response(status, serde_json::to_vec(&health).unwrap_or_default())
+        }
+        (&Method::POST, "/") => match read_body(request.into_body()).await {
+            Some(body) => http_response(
+                StatusCode::OK,
+                serde_json::to_vec(&handle_rpc_request(&body, context).await).unwrap_or_default(),
+            ),
+            None => http_response(StatusCode::PAYLOAD_TOO_LARGE, Vec::new()),
+        },
+        _ => http_response(StatusCode::NOT_FOUND, Vec::new()),
+    }
+}
+
+fn http_response(status: StatusCode, body: Vec<u8>) -> hyper::Response<Body> {
+    let mut response = hyper::Response::new(Body::from(body));
+    *response.status_mut() = status;
+    response
+        .headers_mut()
+        .insert(CONTENT_TYPE, "application/json".parse().expect("valid header value"));
+    response
+}
+
+// The token is expected as `Authorization: Bearer <token>`
+fn is_authorized(request: &Request<Body>, auth_token: &str) -> bool {
+    let Some(token) = request
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|value| value.as_bytes().strip_prefix(b"Bearer "))
+    else {
+        return false;
+    };
+
+    // Compare in constant time, so the token can't be guessed from the response time
+    token.len() == auth_token.len()
+        && token
+            .iter()
+            .zip(auth_token.as_bytes())
+            .fold(0, |difference, (a, b)| difference | (a ^ b))
+            == 0
+}
+
+// Returns `None` if the body is too large
+async fn read_body(mut body: Body) -> Option<Vec<u8>> {
+    if body.size_hint().lower() > MAX_BODY_SIZE as u64 {
+        return None;
+    }
+
+    let mut bytes = Vec::new();
+    while let Some(chunk) = body.data().await {
+        let chunk = chunk.ok()?;
+        if bytes.len() + chunk.len() > MAX_BODY_SIZE {
+            return None;
+        }
+        bytes.extend_from_slice(&chunk);
+    }
+
+    Some(bytes)
+}
+
+// Returns whether the message only reads the wallet state, without changing it, sending transactions or revealing
+// secrets
+fn is_read_only(message: &Message) -> bool {
+    match message {
+        Message::CallAccountMethod { method, .. } => !method.is_mutating(),
+        Message::GetNodeInfo { url, .. } => url.is_none(),
+        Message::GetAccount { .. }
+        | Message::GetAccountIndexes
+        | Message::GetAccounts
+        | Message::GetAddressBook
+        | Message::GetTransactionTemplates
+        | Message::GetBackgroundSyncStatus
+        | Message::Bech32ToHex { .. }
+        | Message::HexToBech32 { .. }
+        | Message::BlockId { .. }
+        | Message::TransactionId { .. }
+        | Message::HashTransactionEssence { .. } => true,
+        #[cfg(feature = "wallet-participation")]
+        Message::GetParticipationOverview { .. } => true,
+        _ => false,
+    }
+}
+
+// Returns whether the message reads or writes a file at a path chosen by the caller, which isn't allowed for remote
+// callers, independent of the access
+fn accesses_files(message: &Message) -> bool {
+    match message {
+        Message::WritePreparedTransactionToFile { .. }
+        | Message::ReadPreparedTransactionFromFile { .. }
+        | Message::WriteSignedTransactionToFile { .. }
+        | Message::ReadSignedTransactionFromFile { .. } => true,
+        #[cfg(feature = "stronghold")]
+        Message::Backup { .. }
+        | Message::RestoreBackup { .. }
+        | Message::RestoreAccountsFromBackup { .. }
+        | Message::ImportFireflyBackup { .. }
+        | Message::VerifyBackup { .. } => true,
+        #[cfg(feature = "encrypted_backup")]
+        Message::BackupToEncryptedJson { .. } | Message::RestoreFromEncryptedJson { .. } => true,
+        _ => false,
+    }
+}
+
+#[derive(Deserialize)]
+struct RpcRequest {
+    jsonrpc: String,
+    #[serde(default)]
+    id: Value,
+    method: String,
+    #[serde(default)]
+    params: Value,
+}
+
+#[derive(Serialize)]
+struct RpcResponse {
+    jsonrpc: &'static str,
+    id: Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    result: Option<Response>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<RpcError>,
+}
+
+#[derive(Serialize)]
+struct RpcError {
+    code: i32,
+    message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    data: Option<Response>,
+}
+
+impl RpcResponse {
+    fn error(id: Value, code: i32, message: String, data: Option<Response>) -> Self {
+        Self {
+            jsonrpc: "2.0",
+            id,
+            result: None,
+            error: Some(RpcError { code, message, data }),
+        }
+    }
+}
+
+// The method is the `cmd` and the params are the `payload` of a message
+async fn handle_rpc_request(body: &[u8], context: &RpcContext) -> RpcResponse {
+    let request = match serde_json::from_slice::<RpcRequest>(body) {
+        Ok(request) => request,
+        Err(err) => return RpcResponse::error(Value::Null, PARSE_ERROR, err.to_string(), None),
+    };
+    if request.jsonrpc != "2.0" {
+        return RpcResponse::error(
+            request.id,
+            INVALID_REQUEST,
+            "unsupported jsonrpc version".to_string(),
+            None,
+        );
+    }
+
+    let mut message = serde_json::Map::new();
+    message.insert("cmd".to_string(), Value::String(request.method));
+    if !request.params.is_null() {
+        message.insert("payload".to_string(), request.params);
+    }
+    let message = match serde_json::from_value::<Message>(Value::Object(message)) {
+        Ok(message) => message,
+        Err(err) => return RpcResponse::error(request.id, INVALID_PARAMS, err.to_string(), None),
+    };
+    if accesses_files(&message) {
+        return RpcResponse::error(
+            request.id,
+            METHOD_NOT_ALLOWED,
+            "method not available over RPC".to_string(),
+            None,
+        );
+    }
+    if context.access == RpcAccess::ReadOnly && !is_read_only(&message) {
+        return RpcResponse::error(
+            request.id,
+            METHOD_NOT_ALLOWED,
+            "method not allowed with read-only access".to_string(),
+            None,
+        );
+    }
+
+    let response = context.message_handler.send_message(message).await;
+    let error_message = match &response {
+        Response::Error(err) => Some(err.to_string()),
+        Response::Panic(panic) => Some(panic.clone()),
+        _ => None,
+    };
+
+    match error_message {
+        Some(error_message) => RpcResponse::error(request.id, SERVER_ERROR, error_message, Some(response)),
+        None => RpcResponse {
+            jsonrpc: "2.0",
+            id: request.id,
+            result: Some(response),
+            error: None,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(authorization: Option<&str>) -> Request<Body> {
+        let mut builder = Request::builder().method(Method::POST).uri("/");
+        if let Some(authorization) = authorization {
+            builder = builder.header(AUTHORIZATION, authorization);
+        }
+        builder.body(Body::empty()).unwrap()
+    }
+
+    #[test]
+    fn authorization() {
+        assert!(is_authorized(&request(Some("Bearer token")), "token"));
+        assert!(!is_authorized(&request(Some("Bearer other")), "token"));
+        assert!(!is_authorized(&request(Some("Bearer token2")), "token"));
+        assert!(!is_authorized(&request(Some("token")), "token"));
+        assert!(!is_authorized(&request(None), "token"));
+    }
+
+    #[tokio::test]
+    async fn body_size_limit() {
+        assert_eq!(read_body(Body::from(vec![1; 10])).await, Some(vec![1; 10]));
+        assert_eq!(read_body(Body::from(vec![0; MAX_BODY_SIZE + 1])).await, None);
+    }
+
+    #[test]
+    fn read_only_messages() {
+        assert!(is_read_only(&message(serde_json::json!({ "cmd": "getAccounts" }))));
+        assert!(is_read_only(&message(serde_json::json!({
+            "cmd": "callAccountMethod",
+            "payload": { "accountId": 0, "method": { "name": "getBalance" } }
+        }))));
+        assert!(!is_read_only(&message(serde_json::json!({
+            "cmd": "callAccountMethod",
+            "payload": { "accountId": 0, "method": { "name": "generateAddresses", "data": { "amount": 1 } } }
+        }))));
+        assert!(!is_read_only(&message(
+            serde_json::json!({ "cmd": "removeLatestAccount" })
+        )));
+        assert!(!is_read_only(&message(serde_json::json!({
+            "cmd": "getNodeInfo",
+            "payload": { "url": "http://localhost:14265" }
+        }))));
+    }
+
+    fn message(value: Value) -> Message {
+        serde_json::from_value::<Message>(value).unwrap()
+    }
+
+    #[test]
+    fn file_messages() {
+        assert!(accesses_files(&message(serde_json::json!({
+            "cmd": "readSignedTransactionFromFile",
+            "payload": { "path": "/etc/passwd" }
+        }))));
+        assert!(accesses_files(&message(serde_json::json!({
+            "cmd": "readPreparedTransactionFromFile",
+            "payload": { "path": "prepared.json" }
+        }))));
+        assert!(!accesses_files(&message(serde_json::json!({ "cmd": "getAccounts" }))));
+    }
+
+    #[cfg(feature = "stronghold")]
+    #[test]
+    fn backup_accesses_files() {
+        assert!(accesses_files(&message(serde_json::json!({
+            "cmd": "backup",
+            "payload": { "destination": "/tmp/backup.stronghold", "password": "password" }
+        }))));
+    }
+
+    #[cfg(feature = "stronghold")]
+    #[test]
+    fn restore_backup_accesses_files() {
+        assert!(accesses_files(&message(serde_json::json!({
+            "cmd": "restoreBackup",
+            "payload": { "source": "/tmp/backup.stronghold", "password": "password" }
+        }))));
+    }
+
+    #[cfg(feature = "stronghold")]
+    #[test]
+    fn restore_accounts_from_backup_accesses_files() {
+        assert!(accesses_files(&message(serde_json::json!({
+            "cmd": "restoreAccountsFromBackup",
+            "payload": { "source": "/tmp/backup.stronghold", "password": "password", "accountIds": [0] }
+        }))));
+    }
+
+    #[cfg(feature = "stronghold")]
+    #[test]
+    fn import_firefly_backup_accesses_files() {
+        assert!(accesses_files(&message(serde_json::json!({
+            "cmd": "importFireflyBackup",
+            "payload": { "source": "/tmp/firefly.stronghold", "password": "password" }
+        }))));
+    }
+
+    #[cfg(feature = "stronghold")]
+    #[test]
+    fn verify_backup_accesses_files() {
+        assert!(accesses_files(&message(serde_json::json!({
+            "cmd": "verifyBackup",
+            "payload": { "source": "/tmp/backup.stronghold", "password": "password" }
+        }))));
+    }
+
+    #[cfg(feature = "encrypted_backup")]
+    #[test]
+    fn backup_to_encrypted_json_accesses_files() {
+        assert!(accesses_files(&message(serde_json::json!({
+            "cmd": "backupToEncryptedJson",
+            "payload": { "destination": "/tmp/backup.json", "password": "password" }
+        }))));
+    }
+
+    #[cfg(feature = "encrypted_backup")]
+    #[test]
+    fn restore_from_encrypted_json_accesses_files() {
+        assert!(accesses_files(&message(serde_json::json!({
+            "cmd": "restoreFromEncryptedJson",
+            "payload": { "source": "/tmp/backup.json", "password": "password" }
+        }))));
+    }
+}
@@ -43,4 +43,22 @@ impl StorageAdapter for Memory {
         self.0.write().await.remove(key);
         Ok(())
     }
+
+    /// Releases the memory that isn't used by records anymore.
+    async fn compact(&self) -> crate::wallet::Result<()> {
+        self.0.write().await.shrink_to_fit();
+        Ok(())
+    }
+
+    /// Gets the size of the stored keys and records in bytes.
+    async fn size(&self) -> crate::wallet::Result<Option<u64>> {
+        Ok(Some(
+            self.0
+                .read()
+                .await
+                .iter()
+                .map(|(key, record)| (key.len() + record.len()) as u64)
+                .sum(),
+        ))
+    }
 }
@@ -28,4 +28,14 @@ pub trait StorageAdapter: std::fmt::Debug {
 
     /// Removes a record from the storage.
     async fn remove(&self, key: &str) -> crate::wallet::Result<()>;
+
+    /// Compacts the storage to reclaim the space of removed and overwritten records.
+    async fn compact(&self) -> crate::wallet::Result<()> {
+        Ok(())
+    }
+
+    /// Gets the size of the storage in bytes, if it's known.
+    async fn size(&self) -> crate::wallet::Result<Option<u64>> {
+        Ok(None)
+    }
 }
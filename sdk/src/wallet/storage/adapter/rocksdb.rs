@@ -1,7 +1,7 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::{collections::HashMap, path::Path, sync::Arc};
+use std::{collections::HashMap, fs, path::Path, sync::Arc};
 
 use rocksdb::{DBCompressionType, Options, WriteBatch, DB};
 use tokio::sync::Mutex;
@@ -29,6 +29,16 @@ impl RocksdbStorageAdapter {
             db: Arc::new(Mutex::new(db)),
         })
     }
+
+    /// Initialises the storage adapter for an existing database in read-only mode, writes will return an error.
+    pub fn new_read_only(path: impl AsRef<Path>) -> crate::wallet::Result<Self> {
+        let mut opts = Options::default();
+        opts.set_compression_type(DBCompressionType::Lz4);
+        let db = DB::open_for_read_only(&opts, path, false)?;
+        Ok(Self {
+            db: Arc::new(Mutex::new(db)),
+        })
+    }
 }
 
 #[async_trait::async_trait]
@@ -68,4 +78,32 @@ impl StorageAdapter for RocksdbStorageAdapter {
         self.db.lock().await.delete(key.as_bytes())?;
         Ok(())
     }
+
+    /// Flushes the memtable and compacts the whole key range.
+    async fn compact(&self) -> crate::wallet::Result<()> {
+        let db = self.db.clone().lock_owned().await;
+        // The compaction can take a while and would block the executor
+        tokio::task::spawn_blocking(move || {
+            db.flush()?;
+            db.compact_range::<&[u8], &[u8]>(None, None);
+            Ok(())
+        })
+        .await?
+    }
+
+    /// Gets the size of the files in the database folder in bytes.
+    async fn size(&self) -> crate::wallet::Result<Option<u64>> {
+        let path = self.db.lock().await.path().to_path_buf();
+        tokio::task::spawn_blocking(move || {
+            let mut size = 0;
+            for entry in fs::read_dir(path)? {
+                let metadata = entry?.metadata()?;
+                if metadata.is_file() {
+                    size += metadata.len();
+                }
+            }
+            Ok(Some(size))
+        })
+        .await?
+    }
 }
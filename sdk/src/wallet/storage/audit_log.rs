@@ -0,0 +1,95 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use super::manager::StorageManager;
+use crate::wallet::{
+    account::AuditLogEntry,
+    storage::constants::{AUDIT_LOG, MAX_AUDIT_LOG_ENTRIES},
+};
+
+impl StorageManager {
+    // Takes `&mut self`, so concurrent appends can't overwrite each other's entries
+    pub(crate) async fn append_audit_log_entry(
+        &mut self,
+        account_index: u32,
+        entry: AuditLogEntry,
+    ) -> crate::wallet::Result<()> {
+        log::debug!("append_audit_log_entry {}", entry.method);
+
+        let mut entries = self.get_audit_log(account_index).await?;
+        push_capped(&mut entries, entry, MAX_AUDIT_LOG_ENTRIES);
+
+        self.storage
+            .set(&format!("{AUDIT_LOG}{account_index}"), &entries)
+            .await?;
+
+        Ok(())
+    }
+
+    pub(crate) async fn get_audit_log(&self, account_index: u32) -> crate::wallet::Result<Vec<AuditLogEntry>> {
+        log::debug!("get_audit_log");
+
+        Ok(self
+            .storage
+            .get::<Vec<AuditLogEntry>>(&format!("{AUDIT_LOG}{account_index}"))
+            .await?
+            .unwrap_or_default())
+    }
+}
+
+// Appends the entry and drops the oldest entries above the maximum
+fn push_capped(entries: &mut Vec<AuditLogEntry>, entry: AuditLogEntry, max_entries: usize) {
+    entries.push(entry);
+    if entries.len() > max_entries {
+        entries.drain(..entries.len() - max_entries);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wallet::storage::adapter::memory::Memory;
+
+    fn entry(method: &str) -> AuditLogEntry {
+        AuditLogEntry::new(method, &()).unwrap()
+    }
+
+    #[test]
+    fn push_capped_drops_oldest() {
+        let mut entries = Vec::new();
+        for method in ["a", "b", "c", "d"] {
+            push_capped(&mut entries, entry(method), 3);
+        }
+
+        assert_eq!(
+            entries.iter().map(|entry| entry.method.as_str()).collect::<Vec<_>>(),
+            ["b", "c", "d"]
+        );
+    }
+
+    #[tokio::test]
+    async fn append_per_account() {
+        let mut storage_manager = StorageManager::new(Memory::default(), None).await.unwrap();
+
+        storage_manager
+            .append_audit_log_entry(0, entry("setAlias"))
+            .await
+            .unwrap();
+        storage_manager
+            .append_audit_log_entry(0, entry("sendAmount"))
+            .await
+            .unwrap();
+        storage_manager
+            .append_audit_log_entry(1, entry("sendNft"))
+            .await
+            .unwrap();
+
+        let entries = storage_manager.get_audit_log(0).await.unwrap();
+        assert_eq!(
+            entries.iter().map(|entry| entry.method.as_str()).collect::<Vec<_>>(),
+            ["setAlias", "sendAmount"]
+        );
+        assert_eq!(storage_manager.get_audit_log(1).await.unwrap().len(), 1);
+        assert!(storage_manager.get_audit_log(2).await.unwrap().is_empty());
+    }
+}
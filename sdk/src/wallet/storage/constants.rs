@@ -8,6 +8,10 @@ pub const DEFAULT_STORAGE_PATH: &str = "./storage";
 #[cfg(feature = "rocksdb")]
 pub(crate) const ROCKSDB_FOLDERNAME: &str = "walletdb";
 
+/// The lock file created in the storage path.
+#[cfg(feature = "rocksdb")]
+pub(crate) const STORAGE_LOCK_FILE_NAME: &str = "wallet.lock";
+
 pub const fn default_storage_path() -> &'static str {
     #[cfg(feature = "rocksdb")]
     return ROCKSDB_FOLDERNAME;
@@ -24,10 +28,21 @@ pub(crate) const ACCOUNT_INDEXATION_KEY: &str = "iota-wallet-account-";
 
 pub(crate) const ACCOUNT_SYNC_OPTIONS: &str = "sync-options";
 
+pub(crate) const ADDRESS_BOOK_KEY: &str = "address-book";
+pub(crate) const TRANSACTION_TEMPLATES_KEY: &str = "transaction-templates";
+
 pub(crate) const DATABASE_SCHEMA_VERSION: u8 = 1;
 pub(crate) const DATABASE_SCHEMA_VERSION_KEY: &str = "database-schema-version";
 
-#[cfg(feature = "participation")]
+#[cfg(feature = "wallet-participation")]
 pub(crate) const PARTICIPATION_EVENTS: &str = "participation-events";
-#[cfg(feature = "participation")]
+#[cfg(feature = "wallet-participation")]
 pub(crate) const PARTICIPATION_CACHED_OUTPUTS: &str = "participation-cached-outputs";
+#[cfg(feature = "wallet-participation")]
+pub(crate) const PARTICIPATION_CACHED_EVENT_STATUSES: &str = "participation-cached-event-statuses";
+#[cfg(feature = "wallet-participation")]
+pub(crate) const PARTICIPATION_CACHED_OVERVIEW: &str = "participation-cached-overview";
+
+pub(crate) const AUDIT_LOG: &str = "audit-log-";
+/// The maximum number of audit log entries kept per account, older entries are dropped.
+pub(crate) const MAX_AUDIT_LOG_ENTRIES: usize = 10_000;
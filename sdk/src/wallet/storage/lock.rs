@@ -0,0 +1,129 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{Read, Seek, SeekFrom, Write},
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use fs2::FileExt;
+use serde::{Deserialize, Serialize};
+
+use crate::wallet::{storage::constants::STORAGE_LOCK_FILE_NAME, Error, Result};
+
+/// The content of the lock file, only used to report which process holds the lock.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LockInfo {
+    /// The id of the process holding the lock.
+    pid: u32,
+    /// Unix timestamp in seconds at which the lock was acquired.
+    acquired_at: u64,
+}
+
+/// An advisory lock on a storage path, so only a single wallet process writes to it. The lock is held by the operating
+/// system on the open lock file, so it's released when it's dropped or the process exits, also if it crashed.
+#[derive(Debug)]
+pub(crate) struct StorageLock {
+    _file: File,
+}
+
+impl StorageLock {
+    /// Acquires the lock for the storage path.
+    pub(crate) fn acquire(storage_path: &Path) -> Result<Self> {
+        fs::create_dir_all(storage_path)?;
+        // The file is never removed, another process could otherwise lock a new file while the old one is still locked
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(storage_path.join(STORAGE_LOCK_FILE_NAME))?;
+
+        match file.try_lock_exclusive() {
+            Ok(()) => {}
+            Err(e) if e.kind() == fs2::lock_contended_error().kind() => {
+                return Err(Error::StorageLocked {
+                    path: storage_path.to_string_lossy().into(),
+                    pid: read_lock_info(&mut file).map_or(0, |lock_info| lock_info.pid),
+                });
+            }
+            Err(e) => return Err(e.into()),
+        }
+
+        let lock_info = LockInfo {
+            pid: std::process::id(),
+            acquired_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|duration| duration.as_secs())
+                .unwrap_or_default(),
+        };
+        file.set_len(0)?;
+        file.seek(SeekFrom::Start(0))?;
+        file.write_all(serde_json::to_string(&lock_info)?.as_bytes())?;
+        file.sync_data()?;
+
+        Ok(Self { _file: file })
+    }
+}
+
+// Returns `None` if the lock info is corrupted, for example if the process crashed while writing it
+fn read_lock_info(file: &mut File) -> Option<LockInfo> {
+    let mut content = String::new();
+    file.read_to_string(&mut content).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::*;
+
+    #[test]
+    fn lock_storage_path() {
+        let storage_path = PathBuf::from("test-storage/lock_storage_path");
+        let _ = fs::remove_dir_all(&storage_path);
+
+        let lock = StorageLock::acquire(&storage_path).unwrap();
+        match StorageLock::acquire(&storage_path) {
+            Err(Error::StorageLocked { pid, .. }) => assert_eq!(pid, std::process::id()),
+            other => panic!("expected Error::StorageLocked, got {other:?}"),
+        }
+
+        // The lock is released when it's dropped, the lock file is kept
+        drop(lock);
+        assert!(storage_path.join(STORAGE_LOCK_FILE_NAME).exists());
+        let lock = StorageLock::acquire(&storage_path).unwrap();
+        drop(lock);
+
+        fs::remove_dir_all(storage_path).unwrap();
+    }
+
+    #[test]
+    fn lock_file_without_lock() {
+        // A lock file left behind by another process doesn't lock the storage path, even if the pid in it is running
+        let storage_path = PathBuf::from("test-storage/lock_file_without_lock");
+        let _ = fs::remove_dir_all(&storage_path);
+        fs::create_dir_all(&storage_path).unwrap();
+        fs::write(
+            storage_path.join(STORAGE_LOCK_FILE_NAME),
+            serde_json::json!({ "pid": 1, "acquiredAt": 0 }).to_string(),
+        )
+        .unwrap();
+
+        let lock = StorageLock::acquire(&storage_path).unwrap();
+        let content = fs::read_to_string(storage_path.join(STORAGE_LOCK_FILE_NAME)).unwrap();
+        let lock_info = serde_json::from_str::<LockInfo>(&content).unwrap();
+        assert_eq!(lock_info.pid, std::process::id());
+        drop(lock);
+
+        // Corrupted lock files are overwritten as well
+        fs::write(storage_path.join(STORAGE_LOCK_FILE_NAME), "{").unwrap();
+        drop(StorageLock::acquire(&storage_path).unwrap());
+
+        fs::remove_dir_all(storage_path).unwrap();
+    }
+}
@@ -1,7 +1,7 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
 use serde::{Deserialize, Serialize};
 use tokio::sync::RwLock;
@@ -12,7 +12,7 @@ use crate::{
         account::{AccountDetails, SyncOptions},
         migration::migrate_storage,
         storage::{constants::*, Storage, StorageAdapter},
-        WalletBuilder,
+        AddressBook, TransactionTemplate, WalletBuilder,
     },
 };
 
@@ -198,6 +198,27 @@ impl StorageManager {
         let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_SYNC_OPTIONS}");
         self.storage.get(&key).await
     }
+
+    pub async fn set_address_book(&self, address_book: &AddressBook) -> crate::wallet::Result<()> {
+        self.storage.set(ADDRESS_BOOK_KEY, address_book).await
+    }
+
+    pub async fn get_address_book(&self) -> crate::wallet::Result<Option<AddressBook>> {
+        self.storage.get(ADDRESS_BOOK_KEY).await
+    }
+
+    pub async fn set_transaction_templates(
+        &self,
+        transaction_templates: &HashMap<String, TransactionTemplate>,
+    ) -> crate::wallet::Result<()> {
+        self.storage.set(TRANSACTION_TEMPLATES_KEY, transaction_templates).await
+    }
+
+    pub async fn get_transaction_templates(
+        &self,
+    ) -> crate::wallet::Result<Option<HashMap<String, TransactionTemplate>>> {
+        self.storage.get(TRANSACTION_TEMPLATES_KEY).await
+    }
 }
 
 #[cfg(test)]
@@ -3,13 +3,18 @@
 
 /// Storage adapter.
 pub mod adapter;
+/// Storage functions related to the audit log.
+mod audit_log;
 /// Storage constants.
 pub mod constants;
+/// Storage lock.
+#[cfg(feature = "rocksdb")]
+pub(crate) mod lock;
 /// Storage manager.
 pub mod manager;
 /// Storage functions related to participation.
-#[cfg(feature = "participation")]
-#[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
+#[cfg(feature = "wallet-participation")]
+#[cfg_attr(docsrs, doc(cfg(feature = "wallet-participation")))]
 mod participation;
 
 use std::collections::HashMap;
@@ -81,6 +86,14 @@ impl Storage {
     async fn remove(&self, key: &str) -> crate::wallet::Result<()> {
         self.inner.remove(key).await
     }
+
+    pub(crate) async fn compact(&self) -> crate::wallet::Result<()> {
+        self.inner.compact().await
+    }
+
+    pub(crate) async fn size(&self) -> crate::wallet::Result<Option<u64>> {
+        self.inner.size().await
+    }
 }
 
 impl Drop for Storage {
@@ -10,8 +10,13 @@ use crate::{
         block::output::OutputId,
     },
     wallet::{
-        account::operations::participation::ParticipationEventWithNodes,
-        storage::constants::{PARTICIPATION_CACHED_OUTPUTS, PARTICIPATION_EVENTS},
+        account::operations::participation::{
+            AccountParticipationOverview, CachedParticipationEventStatus, ParticipationEventWithNodes,
+        },
+        storage::constants::{
+            PARTICIPATION_CACHED_EVENT_STATUSES, PARTICIPATION_CACHED_OUTPUTS, PARTICIPATION_CACHED_OVERVIEW,
+            PARTICIPATION_EVENTS,
+        },
     },
 };
 
@@ -109,6 +114,65 @@ impl StorageManager {
             .await?
             .unwrap_or_default())
     }
+
+    pub(crate) async fn set_cached_participation_event_status(
+        &self,
+        account_index: u32,
+        id: ParticipationEventId,
+        status: CachedParticipationEventStatus,
+    ) -> crate::wallet::Result<()> {
+        log::debug!("set_cached_participation_event_status {id}");
+
+        let mut statuses = self.get_cached_participation_event_statuses(account_index).await?;
+        statuses.insert(id, status);
+
+        self.storage
+            .set(
+                &format!("{PARTICIPATION_CACHED_EVENT_STATUSES}{account_index}"),
+                &statuses,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub(crate) async fn get_cached_participation_event_statuses(
+        &self,
+        account_index: u32,
+    ) -> crate::wallet::Result<HashMap<ParticipationEventId, CachedParticipationEventStatus>> {
+        log::debug!("get_cached_participation_event_statuses");
+
+        Ok(self
+            .storage
+            .get(&format!("{PARTICIPATION_CACHED_EVENT_STATUSES}{account_index}"))
+            .await?
+            .unwrap_or_default())
+    }
+
+    pub(crate) async fn set_cached_participation_overview(
+        &self,
+        account_index: u32,
+        overview: &AccountParticipationOverview,
+    ) -> crate::wallet::Result<()> {
+        log::debug!("set_cached_participation_overview");
+
+        self.storage
+            .set(&format!("{PARTICIPATION_CACHED_OVERVIEW}{account_index}"), overview)
+            .await?;
+
+        Ok(())
+    }
+
+    pub(crate) async fn get_cached_participation_overview(
+        &self,
+        account_index: u32,
+    ) -> crate::wallet::Result<Option<AccountParticipationOverview>> {
+        log::debug!("get_cached_participation_overview");
+
+        self.storage
+            .get(&format!("{PARTICIPATION_CACHED_OVERVIEW}{account_index}"))
+            .await
+    }
 }
 
 #[cfg(test)]
@@ -169,4 +233,64 @@ mod tests {
             outputs_participation
         );
     }
+
+    #[tokio::test]
+    async fn set_get_cached_participation_event_status_and_overview() {
+        let storage_manager = StorageManager::new(Memory::default(), None).await.unwrap();
+        let event_id = ParticipationEventId::new([42; 32]);
+        let status = CachedParticipationEventStatus {
+            status: serde_json::from_value(serde_json::json!({
+                "milestoneIndex": 42,
+                "status": "holding",
+                "checksum": "",
+            }))
+            .unwrap(),
+            fetched_at: 1_700_000_000,
+            stale: false,
+        };
+
+        storage_manager
+            .set_cached_participation_event_status(0, event_id, status.clone())
+            .await
+            .unwrap();
+        assert_eq!(
+            storage_manager
+                .get_cached_participation_event_statuses(0)
+                .await
+                .unwrap()
+                .get(&event_id),
+            Some(&status)
+        );
+        // The cache is per account
+        assert!(
+            storage_manager
+                .get_cached_participation_event_statuses(1)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        assert!(
+            storage_manager
+                .get_cached_participation_overview(0)
+                .await
+                .unwrap()
+                .is_none()
+        );
+        let overview = AccountParticipationOverview {
+            participations: std::iter::once((event_id, HashMap::new())).collect(),
+            stale: false,
+        };
+        storage_manager
+            .set_cached_participation_overview(0, &overview)
+            .await
+            .unwrap();
+        let cached_overview = storage_manager
+            .get_cached_participation_overview(0)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(cached_overview.participations.contains_key(&event_id));
+        assert!(!cached_overview.stale);
+    }
 }
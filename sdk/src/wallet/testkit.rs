@@ -0,0 +1,199 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Deterministic test harness for the wallet.
+//!
+//! The [`Testkit`] replaces the node for the parts of the wallet which only depend on the local account state: outputs
+//! are credited to accounts directly and time only moves forward when a milestone is issued. This allows testing
+//! expiration and timelock scenarios with [`Account::balance()`](crate::wallet::Account::balance) or
+//! [`Account::can_burn()`](crate::wallet::Account::can_burn) in milliseconds. Accounts used with the testkit must not
+//! be synced, since syncing would replace the credited outputs with the ones known by the node.
+//!
+//! ```ignore
+//! let testkit = Testkit::new(&wallet, 1_680_000_000).await?;
+//! let output_id = testkit.fund(&account, 1_000_000).await?;
+//! testkit.advance_time(3600).await?;
+//! let balance = account.balance().await?;
+//! ```
+
+use crypto::keys::slip10::Chain;
+use tokio::sync::Mutex;
+
+use crate::{
+    client::{constants::HD_WALLET_TYPE, Client, TimeSource},
+    types::block::{
+        address::Address,
+        output::{
+            unlock_condition::{AddressUnlockCondition, UnlockCondition},
+            BasicOutputBuilder, Output, OutputId, OutputMetadata,
+        },
+        payload::transaction::TransactionId,
+        BlockId,
+    },
+    wallet::{account::types::OutputData, Account, Error, Result, Wallet},
+};
+
+/// A programmable ledger and clock for wallet tests.
+pub struct Testkit {
+    client: Client,
+    state: Mutex<TestkitState>,
+}
+
+#[derive(Debug)]
+struct TestkitState {
+    milestone_index: u32,
+    milestone_timestamp: u32,
+    transaction_count: u64,
+}
+
+impl Testkit {
+    /// Creates a new testkit for the wallet, issuing a first milestone at the given unix timestamp.
+    pub async fn new(wallet: &Wallet, timestamp: u32) -> Result<Self> {
+        let testkit = Self {
+            client: wallet.client().clone(),
+            state: Mutex::new(TestkitState {
+                milestone_index: 0,
+                milestone_timestamp: timestamp,
+                transaction_count: 0,
+            }),
+        };
+        testkit.issue_milestone(timestamp).await?;
+
+        Ok(testkit)
+    }
+
+    /// Returns the index of the latest milestone.
+    pub async fn milestone_index(&self) -> u32 {
+        self.state.lock().await.milestone_index
+    }
+
+    /// Returns the current time, which is the timestamp of the latest milestone.
+    pub async fn time(&self) -> u32 {
+        self.state.lock().await.milestone_timestamp
+    }
+
+    /// Issues a new milestone `seconds` after the latest one and moves the time forward.
+    pub async fn advance_time(&self, seconds: u32) -> Result<u32> {
+        let time = self.time().await;
+        let timestamp = time
+            .checked_add(seconds)
+            .ok_or_else(|| Error::Testkit(format!("advancing the time {time} by {seconds} seconds overflows")))?;
+        self.issue_milestone(timestamp).await
+    }
+
+    /// Issues a new milestone with the given timestamp, which can't be before the latest milestone timestamp. Returns
+    /// the index of the new milestone.
+    pub async fn issue_milestone(&self, timestamp: u32) -> Result<u32> {
+        let mut state = self.state.lock().await;
+
+        if timestamp < state.milestone_timestamp {
+            return Err(Error::Testkit(format!(
+                "milestone timestamp {timestamp} is before the latest milestone timestamp {}",
+                state.milestone_timestamp
+            )));
+        }
+
+        state.milestone_index += 1;
+        state.milestone_timestamp = timestamp;
+
+        self.client.network_info.write().await.latest_milestone_timestamp = Some(timestamp);
+        *self.client.time_source.write().await = TimeSource::Fixed { timestamp };
+
+        Ok(state.milestone_index)
+    }
+
+    /// Credits a basic output with the given amount to the first address of the account, like a faucet would.
+    pub async fn fund(&self, account: &Account, amount: u64) -> Result<OutputId> {
+        let address = account.addresses().await?[0].address;
+        let token_supply = self.client.get_token_supply().await?;
+        let output = BasicOutputBuilder::new_with_amount(amount)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .finish_output(token_supply)?;
+
+        self.add_output(account, output).await
+    }
+
+    /// Credits an arbitrary output to the account, booked in the latest milestone. The output is associated with the
+    /// first account address found in its address, expiration, state controller or governor unlock condition.
+    pub async fn add_output(&self, account: &Account, output: Output) -> Result<OutputId> {
+        let (output_id, metadata) = {
+            let mut state = self.state.lock().await;
+            state.transaction_count += 1;
+
+            let mut transaction_id = [0u8; TransactionId::LENGTH];
+            transaction_id[..8].copy_from_slice(&state.transaction_count.to_le_bytes());
+            let output_id = OutputId::new(TransactionId::new(transaction_id), 0)?;
+            let mut block_id = [0u8; BlockId::LENGTH];
+            block_id[..8].copy_from_slice(&state.transaction_count.to_le_bytes());
+
+            let metadata = OutputMetadata::new(
+                BlockId::new(block_id),
+                output_id,
+                false,
+                None,
+                None,
+                None,
+                state.milestone_index,
+                state.milestone_timestamp,
+                state.milestone_index,
+            );
+            (output_id, metadata)
+        };
+
+        let network_id = self.client.get_network_id().await?;
+        let related_addresses = related_addresses(&output);
+
+        let mut account_details = account.details_mut().await;
+        let account_address = account_details
+            .public_addresses
+            .iter()
+            .chain(account_details.internal_addresses.iter())
+            .find(|account_address| related_addresses.contains(&account_address.address.inner))
+            .cloned()
+            .ok_or_else(|| Error::Testkit(format!("output {output_id} isn't related to the account")))?;
+
+        let chain = Chain::from_u32_hardened(vec![
+            HD_WALLET_TYPE,
+            *account_details.coin_type(),
+            *account_details.index(),
+            account_address.internal as u32,
+            account_address.key_index,
+        ]);
+        let output_data = OutputData {
+            output_id,
+            metadata,
+            output,
+            is_spent: false,
+            address: account_address.address.inner,
+            network_id,
+            remainder: false,
+            chain: Some(chain),
+        };
+
+        account_details.add_unspent_output(&account_address, output_data);
+
+        #[cfg(feature = "storage")]
+        account.save(Some(&account_details)).await?;
+
+        Ok(output_id)
+    }
+}
+
+// Addresses which can own an output, depending on the time or the kind of transition.
+fn related_addresses(output: &Output) -> Vec<Address> {
+    output
+        .unlock_conditions()
+        .map(|unlock_conditions| {
+            unlock_conditions
+                .iter()
+                .filter_map(|unlock_condition| match unlock_condition {
+                    UnlockCondition::Address(uc) => Some(*uc.address()),
+                    UnlockCondition::Expiration(uc) => Some(*uc.return_address()),
+                    UnlockCondition::StateControllerAddress(uc) => Some(*uc.address()),
+                    UnlockCondition::GovernorAddress(uc) => Some(*uc.address()),
+                    _ => None,
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
@@ -6,7 +6,11 @@ use std::sync::{
     Arc,
 };
 #[cfg(feature = "storage")]
-use std::{collections::HashSet, path::PathBuf, sync::atomic::Ordering};
+use std::{
+    collections::HashSet,
+    path::PathBuf,
+    sync::atomic::{AtomicBool, AtomicU64, Ordering},
+};
 
 use futures::{future::try_join_all, FutureExt};
 use serde::{Deserialize, Serialize};
@@ -16,9 +20,13 @@ use tokio::sync::RwLock;
 use crate::wallet::events::EventEmitter;
 #[cfg(all(feature = "storage", not(feature = "rocksdb")))]
 use crate::wallet::storage::adapter::memory::Memory;
+#[cfg(feature = "rocksdb")]
+use crate::wallet::storage::{adapter::rocksdb::RocksdbStorageAdapter, lock::StorageLock};
+#[cfg(feature = "stronghold")]
+use crate::wallet::wallet::operations::stronghold_backup::auto_backup::{AutoBackup, AutoBackupOptions};
 #[cfg(feature = "storage")]
 use crate::wallet::{
-    account::AccountDetails,
+    account::{types::AccountId, AccountDetails},
     storage::{
         constants::default_storage_path,
         manager::{ManagerStorage, StorageManager},
@@ -38,6 +46,9 @@ pub struct WalletBuilder {
     storage_options: Option<StorageOptions>,
     #[serde(default, skip)]
     pub(crate) secret_manager: Option<Arc<RwLock<SecretManager>>>,
+    #[cfg(feature = "stronghold")]
+    #[serde(default, skip)]
+    auto_backup: Option<AutoBackupOptions>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -48,6 +59,9 @@ pub(crate) struct StorageOptions {
     pub(crate) storage_file_name: Option<String>,
     pub(crate) storage_encryption_key: Option<[u8; 32]>,
     pub(crate) manager_store: ManagerStorage,
+    // Only applies to the current process, so it's not stored
+    #[serde(skip)]
+    pub(crate) read_only: bool,
 }
 
 #[cfg(feature = "storage")]
@@ -58,6 +72,7 @@ impl Default for StorageOptions {
             storage_file_name: None,
             storage_encryption_key: None,
             manager_store: ManagerStorage::default(),
+            read_only: false,
         }
     }
 }
@@ -102,11 +117,29 @@ impl WalletBuilder {
     pub fn with_storage_path(mut self, path: &str) -> Self {
         self.storage_options = Some(StorageOptions {
             storage_path: path.into(),
-            ..Default::default()
+            ..self.storage_options.unwrap_or_default()
         });
         self
     }
 
+    /// Open an existing storage in read-only mode. The storage doesn't get locked, so it can be read while another
+    /// wallet process is using it, but all operations that write to the storage will fail.
+    #[cfg(feature = "rocksdb")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rocksdb")))]
+    pub fn with_storage_read_only(mut self, read_only: bool) -> Self {
+        self.storage_options.get_or_insert_with(Default::default).read_only = read_only;
+        self
+    }
+
+    /// Write Stronghold backups automatically with rotation, after the configured amount of sent transactions or in
+    /// the configured interval. Requires a Stronghold secret manager, the backups use its password.
+    #[cfg(feature = "stronghold")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
+    pub fn with_auto_backup(mut self, options: impl Into<Option<AutoBackupOptions>>) -> Self {
+        self.auto_backup = options.into();
+        self
+    }
+
     /// Builds the wallet
     #[allow(unreachable_code, unused_mut)]
     pub async fn finish(mut self) -> crate::wallet::Result<Wallet> {
@@ -128,9 +161,22 @@ impl WalletBuilder {
                 return Err(crate::wallet::Error::MissingParameter("secret_manager"));
             }
         }
-        #[cfg(all(feature = "rocksdb", feature = "storage"))]
-        let storage =
-            crate::wallet::storage::adapter::rocksdb::RocksdbStorageAdapter::new(storage_options.storage_path.clone())?;
+        #[cfg(feature = "rocksdb")]
+        let (storage, storage_lock) = if storage_options.read_only {
+            if !storage_options.storage_path.is_dir() {
+                return Err(crate::wallet::Error::Storage(format!(
+                    "can't open non-existent storage {} read-only",
+                    storage_options.storage_path.display()
+                )));
+            }
+            let storage = RocksdbStorageAdapter::new_read_only(storage_options.storage_path.clone())?;
+            (storage, None)
+        } else {
+            // Lock the storage before opening it, so another wallet process can't write to it at the same time
+            let storage_lock = StorageLock::acquire(&storage_options.storage_path)?;
+            let storage = RocksdbStorageAdapter::new(storage_options.storage_path.clone())?;
+            (storage, Some(storage_lock))
+        };
         #[cfg(all(not(feature = "rocksdb"), feature = "storage"))]
         let storage = Memory::default();
 
@@ -166,6 +212,20 @@ impl WalletBuilder {
             self.secret_manager.replace(secret_manager);
         }
 
+        #[cfg(feature = "stronghold")]
+        let auto_backup = match self.auto_backup.take() {
+            Some(options) => {
+                let secret_manager = self.secret_manager.as_ref().expect("secret_manager was set above");
+                if !matches!(*secret_manager.read().await, SecretManager::Stronghold(_)) {
+                    return Err(crate::wallet::Error::Backup(
+                        "automatic backups require a Stronghold secret manager",
+                    ));
+                }
+                Some(AutoBackup::new(options)?)
+            }
+            None => None,
+        };
+
         if self.coin_type.is_none() {
             let coin_type =
                 read_manager_builder
@@ -180,7 +240,9 @@ impl WalletBuilder {
 
         // Store wallet data in storage
         #[cfg(feature = "storage")]
-        storage_manager.save_wallet_data(&self).await?;
+        if !storage_options.read_only {
+            storage_manager.save_wallet_data(&self).await?;
+        }
 
         #[cfg(feature = "events")]
         let event_emitter = tokio::sync::RwLock::new(EventEmitter::new());
@@ -192,8 +254,21 @@ impl WalletBuilder {
         // here
         #[cfg(feature = "storage")]
         unlock_unused_inputs(&mut accounts)?;
+        #[cfg(feature = "storage")]
+        assign_missing_account_ids(&mut storage_manager, &mut accounts, storage_options.read_only).await?;
         #[cfg(not(feature = "storage"))]
         let accounts = Vec::new();
+
+        #[cfg(feature = "storage")]
+        let address_book = storage_manager.get_address_book().await?.unwrap_or_default();
+        #[cfg(not(feature = "storage"))]
+        let address_book = Default::default();
+
+        #[cfg(feature = "storage")]
+        let transaction_templates = storage_manager.get_transaction_templates().await?.unwrap_or_default();
+        #[cfg(not(feature = "storage"))]
+        let transaction_templates = Default::default();
+
         let wallet_inner = Arc::new(WalletInner {
             background_syncing_status: AtomicUsize::new(0),
             client: self
@@ -205,15 +280,30 @@ impl WalletBuilder {
             coin_type: AtomicU32::new(self.coin_type.ok_or(crate::wallet::Error::MissingParameter(
                 "coin_type (IOTA: 4218, Shimmer: 4219)",
             ))?),
+            default_confirmation_depth: AtomicU32::new(0),
             secret_manager: self
                 .secret_manager
                 .ok_or(crate::wallet::Error::MissingParameter("secret_manager"))?,
+            address_book: RwLock::new(address_book),
+            transaction_templates: RwLock::new(transaction_templates),
             #[cfg(feature = "events")]
             event_emitter,
             #[cfg(feature = "storage")]
             storage_options,
             #[cfg(feature = "storage")]
             storage_manager: tokio::sync::RwLock::new(storage_manager),
+            #[cfg(feature = "rocksdb")]
+            _storage_lock: storage_lock,
+            #[cfg(feature = "storage")]
+            audit_log_enabled: AtomicBool::new(false),
+            #[cfg(feature = "storage")]
+            storage_compaction_interval: AtomicU64::new(0),
+            #[cfg(feature = "mqtt")]
+            node_event_topics: RwLock::new(Vec::new()),
+            #[cfg(feature = "mqtt")]
+            node_events_fallback_syncing: std::sync::atomic::AtomicBool::new(false),
+            #[cfg(feature = "stronghold")]
+            auto_backup,
         });
 
         let mut accounts: Vec<Account> = try_join_all(
@@ -232,10 +322,14 @@ impl WalletBuilder {
             }
         }
 
-        Ok(Wallet {
+        let wallet = Wallet {
             inner: wallet_inner,
             accounts: Arc::new(RwLock::new(accounts)),
-        })
+        };
+        #[cfg(feature = "stronghold")]
+        wallet.spawn_auto_backup_task();
+
+        Ok(wallet)
     }
 
     #[cfg(feature = "storage")]
@@ -245,8 +339,29 @@ impl WalletBuilder {
             coin_type: Some(wallet.coin_type.load(Ordering::Relaxed)),
             storage_options: Some(wallet.storage_options.clone()),
             secret_manager: Some(wallet.secret_manager.clone()),
+            #[cfg(feature = "stronghold")]
+            auto_backup: wallet
+                .auto_backup
+                .as_ref()
+                .map(|auto_backup| auto_backup.options.clone()),
+        }
+    }
+}
+
+// Accounts created before account ids existed get one, which is stored so it doesn't change anymore
+#[cfg(feature = "storage")]
+async fn assign_missing_account_ids(
+    storage_manager: &mut StorageManager,
+    accounts: &mut [AccountDetails],
+    read_only: bool,
+) -> crate::wallet::Result<()> {
+    for account in accounts.iter_mut().filter(|account| account.account_id.is_nil()) {
+        account.account_id = AccountId::generate()?;
+        if !read_only {
+            storage_manager.save_account(account).await?;
         }
     }
+    Ok(())
 }
 
 // Check if any of the locked inputs is not used in a transaction and unlock them, so they get available for new
@@ -273,3 +388,36 @@ fn unlock_unused_inputs(accounts: &mut [AccountDetails]) -> crate::wallet::Resul
     }
     Ok(())
 }
+
+#[cfg(all(test, feature = "storage"))]
+mod tests {
+    use super::*;
+    use crate::wallet::storage::adapter::memory::Memory;
+
+    #[tokio::test]
+    async fn assign_missing_account_ids() {
+        let mut storage_manager = StorageManager::new(Memory::default(), None).await.unwrap();
+        let account_id = AccountId::generate().unwrap();
+        let mut account_with_id = AccountDetails::mock();
+        account_with_id.account_id = account_id;
+        let mut accounts = vec![AccountDetails::mock(), account_with_id];
+
+        // Read only storage isn't changed
+        super::assign_missing_account_ids(&mut storage_manager, &mut accounts.clone(), true)
+            .await
+            .unwrap();
+        assert!(storage_manager.get_accounts().await.unwrap().is_empty());
+
+        super::assign_missing_account_ids(&mut storage_manager, &mut accounts, false)
+            .await
+            .unwrap();
+        assert!(!accounts[0].account_id.is_nil());
+        // Existing ids are kept
+        assert_eq!(accounts[1].account_id, account_id);
+
+        // Only the account that got a new id is stored
+        let stored_accounts = storage_manager.get_accounts().await.unwrap();
+        assert_eq!(stored_accounts.len(), 1);
+        assert_eq!(stored_accounts[0].account_id, accounts[0].account_id);
+    }
+}
@@ -4,9 +4,14 @@
 pub(crate) mod builder;
 pub(crate) mod operations;
 
-use std::sync::{
-    atomic::{AtomicU32, AtomicUsize},
-    Arc,
+#[cfg(feature = "storage")]
+use std::sync::atomic::{AtomicBool, AtomicU64};
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU32, AtomicUsize, Ordering},
+        Arc,
+    },
 };
 
 use tokio::sync::RwLock;
@@ -16,14 +21,19 @@ use self::builder::StorageOptions;
 pub use self::builder::WalletBuilder;
 #[cfg(feature = "events")]
 use crate::wallet::events::{
-    types::{Event, WalletEventType},
+    types::{Event, NewOutputFilter, WalletEventType},
     EventEmitter,
 };
+#[cfg(feature = "rocksdb")]
+use crate::wallet::storage::lock::StorageLock;
 #[cfg(feature = "storage")]
 use crate::wallet::storage::manager::StorageManager;
 use crate::{
     client::{secret::SecretManager, verify_mnemonic, Client},
-    wallet::account::{builder::AccountBuilder, operations::syncing::SyncOptions, types::AccountBalance, Account},
+    wallet::{
+        account::{builder::AccountBuilder, operations::syncing::SyncOptions, types::AccountBalance, Account},
+        wallet::operations::{address_book::AddressBook, transaction_templates::TransactionTemplate},
+    },
 };
 
 /// The wallet, used to create and get accounts. One wallet can hold many accounts, but they should
@@ -58,17 +68,36 @@ impl Wallet {
 
 #[derive(Debug)]
 pub struct WalletInner {
-    // 0 = not running, 1 = running, 2 = stopping
+    // The discriminant of a `BackgroundSyncStatus`
     pub(crate) background_syncing_status: AtomicUsize,
     pub(crate) client: Client,
     pub(crate) coin_type: AtomicU32,
+    pub(crate) default_confirmation_depth: AtomicU32,
     pub(crate) secret_manager: Arc<RwLock<SecretManager>>,
+    pub(crate) address_book: RwLock<AddressBook>,
+    pub(crate) transaction_templates: RwLock<HashMap<String, TransactionTemplate>>,
     #[cfg(feature = "events")]
     pub(crate) event_emitter: tokio::sync::RwLock<EventEmitter>,
     #[cfg(feature = "storage")]
     pub(crate) storage_options: StorageOptions,
     #[cfg(feature = "storage")]
     pub(crate) storage_manager: tokio::sync::RwLock<StorageManager>,
+    // Held as long as the wallet is alive, `None` if the storage was opened read-only
+    #[cfg(feature = "rocksdb")]
+    pub(crate) _storage_lock: Option<StorageLock>,
+    #[cfg(feature = "storage")]
+    pub(crate) audit_log_enabled: AtomicBool,
+    // Interval in seconds in which the background syncing compacts the storage, 0 = disabled
+    #[cfg(feature = "storage")]
+    pub(crate) storage_compaction_interval: AtomicU64,
+    // The MQTT topics subscribed to by `listen_to_node_events()`, empty if not listening
+    #[cfg(feature = "mqtt")]
+    pub(crate) node_event_topics: RwLock<Vec<crate::client::node_api::mqtt::Topic>>,
+    // Whether `listen_to_node_events()` started the background syncing, because the broker disconnected
+    #[cfg(feature = "mqtt")]
+    pub(crate) node_events_fallback_syncing: std::sync::atomic::AtomicBool,
+    #[cfg(feature = "stronghold")]
+    pub(crate) auto_backup: Option<operations::stronghold_backup::auto_backup::AutoBackup>,
 }
 
 impl Wallet {
@@ -166,6 +195,34 @@ impl WalletInner {
         emitter.on(events, handler);
     }
 
+    /// Listen to new output events that pass `filter`, for example to not get notified about dust outputs
+    #[cfg(feature = "events")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "events")))]
+    pub async fn listen_new_outputs<F>(&self, filter: NewOutputFilter, handler: F)
+    where
+        F: Fn(&Event) + 'static + Send + Sync,
+    {
+        let mut emitter = self.event_emitter.write().await;
+        emitter.on_new_output(filter, handler);
+    }
+
+    /// Stream wallet events, empty vec will stream all events. An alternative to [`WalletInner::listen()`] for async
+    /// consumers, the subscription ends when the stream is dropped.
+    /// ```ignore
+    /// let mut events = wallet.event_stream(vec![WalletEventType::NewOutput]).await.boxed();
+    /// while let Some(event) = events.next().await {
+    ///     println!("{event:?}");
+    /// }
+    /// ```
+    #[cfg(feature = "events")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "events")))]
+    pub async fn event_stream(
+        &self,
+        events: Vec<WalletEventType>,
+    ) -> impl futures::Stream<Item = Event> + Send + 'static {
+        self.event_emitter.read().await.stream(events)
+    }
+
     /// Remove wallet event listeners, empty vec will remove all listeners
     #[cfg(feature = "events")]
     #[cfg_attr(docsrs, doc(cfg(feature = "events")))]
@@ -185,16 +242,40 @@ impl WalletInner {
         Ok(())
     }
 
+    /// Sets the amount of milestones that need to be confirmed after the one that included a transaction, before the
+    /// transaction is considered confirmed. Used for transactions that don't set their own depth, defaults to 0.
+    pub fn set_default_confirmation_depth(&self, depth: u32) {
+        self.default_confirmation_depth.store(depth, Ordering::Relaxed);
+    }
+
+    /// Returns the default confirmation depth of transactions.
+    pub fn default_confirmation_depth(&self) -> u32 {
+        self.default_confirmation_depth.load(Ordering::Relaxed)
+    }
+
     #[cfg(feature = "events")]
-    pub(crate) async fn emit(&self, account_index: u32, event: crate::wallet::events::types::WalletEvent) {
-        self.event_emitter.read().await.emit(account_index, event);
+    pub(crate) async fn emit(
+        &self,
+        account_index: impl Into<Option<u32>>,
+        event: crate::wallet::events::types::WalletEvent,
+        origin: crate::wallet::events::types::EventOrigin,
+    ) {
+        self.event_emitter
+            .read()
+            .await
+            .emit_with_origin(account_index, event, origin);
     }
 
     /// Helper function to test events. Emits a provided event with account index 0.
     #[cfg(feature = "events")]
     #[cfg_attr(docsrs, doc(cfg(feature = "events")))]
     pub async fn emit_test_event(&self, event: crate::wallet::events::types::WalletEvent) {
-        self.emit(0, event).await
+        self.emit(
+            0,
+            event,
+            crate::wallet::events::types::EventOrigin::new("emit_test_event"),
+        )
+        .await
     }
 }
 
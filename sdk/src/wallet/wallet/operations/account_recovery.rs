@@ -4,6 +4,7 @@
 use instant::Instant;
 
 use crate::wallet::{
+    account::LegacyAddressFunds,
     task,
     wallet::{SyncOptions, Wallet},
     Account,
@@ -31,9 +32,57 @@ impl Wallet {
         address_gap_limit: u32,
         sync_options: Option<SyncOptions>,
     ) -> crate::wallet::Result<Vec<Account>> {
+        self.recover(
+            account_start_index,
+            account_gap_limit,
+            address_gap_limit,
+            sync_options,
+            None,
+        )
+        .await?;
+        Ok(self.accounts.read().await.clone())
+    }
+
+    /// Find accounts with unspent outputs like [`Wallet::recover_accounts()`], but also searches each account for funds
+    /// on addresses derived with `legacy_coin_type`, like the Chrysalis wallets did, see
+    /// [`Account::find_legacy_funds()`](crate::wallet::Account::find_legacy_funds). Accounts that only have legacy
+    /// funds are kept as well.
+    ///
+    /// Returns:
+    ///
+    /// The legacy funds of all accounts, which can be swept with
+    /// [`Account::sweep_legacy_funds()`](crate::wallet::Account::sweep_legacy_funds).
+    pub async fn recover_legacy_funds(
+        &self,
+        legacy_coin_type: u32,
+        account_start_index: u32,
+        account_gap_limit: u32,
+        address_gap_limit: u32,
+        sync_options: Option<SyncOptions>,
+    ) -> crate::wallet::Result<Vec<LegacyAddressFunds>> {
+        self.recover(
+            account_start_index,
+            account_gap_limit,
+            address_gap_limit,
+            sync_options,
+            Some(legacy_coin_type),
+        )
+        .await
+    }
+
+    /// Recovers the accounts and returns the funds found on addresses of the legacy coin type, if provided.
+    async fn recover(
+        &self,
+        account_start_index: u32,
+        account_gap_limit: u32,
+        address_gap_limit: u32,
+        sync_options: Option<SyncOptions>,
+        legacy_coin_type: Option<u32>,
+    ) -> crate::wallet::Result<Vec<LegacyAddressFunds>> {
         log::debug!("[recover_accounts]");
         let start_time = Instant::now();
         let mut max_account_index_to_keep = None;
+        let mut legacy_funds = Vec::new();
 
         // Search for addresses in current accounts
         for account in self.accounts.read().await.iter() {
@@ -43,6 +92,11 @@ impl Wallet {
                     .search_addresses_with_outputs(address_gap_limit, sync_options.clone())
                     .await?;
             }
+            if let Some(legacy_coin_type) = legacy_coin_type {
+                if !*account.details().await.watch_only() {
+                    legacy_funds.extend(account.find_legacy_funds(legacy_coin_type, address_gap_limit).await?);
+                }
+            }
             let account_index = *account.details().await.index();
             match max_account_index_to_keep {
                 Some(max_account_index) => {
@@ -68,6 +122,7 @@ impl Wallet {
                 address_gap_limit,
                 &mut max_account_index_to_keep,
                 sync_options.clone(),
+                legacy_coin_type,
             )
             .await;
 
@@ -100,20 +155,23 @@ impl Wallet {
         drop(accounts);
 
         // Handle result after cleaning up the empty accounts
-        new_accounts_discovery_result?;
+        legacy_funds.extend(new_accounts_discovery_result?);
 
         log::debug!("[recover_accounts] finished in {:?}", start_time.elapsed());
-        Ok(self.accounts.read().await.clone())
+        Ok(legacy_funds)
     }
 
-    /// Generate new accounts and search for unspent outputs
+    /// Generate new accounts and search for unspent outputs, also on addresses of the legacy coin type if provided.
+    /// Returns the legacy funds of the new accounts.
     async fn search_new_accounts(
         &self,
         account_gap_limit: u32,
         address_gap_limit: u32,
         max_account_index_to_keep: &mut Option<u32>,
         sync_options: Option<SyncOptions>,
-    ) -> crate::wallet::Result<()> {
+        legacy_coin_type: Option<u32>,
+    ) -> crate::wallet::Result<Vec<LegacyAddressFunds>> {
+        let mut legacy_funds = Vec::new();
         let mut updated_account_gap_limit = account_gap_limit;
         loop {
             log::debug!("[recover_accounts] generating {updated_account_gap_limit} new accounts");
@@ -129,21 +187,31 @@ impl Wallet {
                         let account_outputs_count = new_account
                             .search_addresses_with_outputs(address_gap_limit, sync_options_)
                             .await?;
+                        let legacy_funds = match legacy_coin_type {
+                            Some(legacy_coin_type) => {
+                                new_account
+                                    .find_legacy_funds(legacy_coin_type, address_gap_limit)
+                                    .await?
+                            }
+                            None => Vec::new(),
+                        };
                         let account_index = *new_account.details().await.index();
-                        Ok((account_index, account_outputs_count))
+                        Ok((account_index, account_outputs_count, legacy_funds))
                     })
                     .await
                 });
             }
 
-            let results: Vec<crate::wallet::Result<(u32, usize)>> = futures::future::try_join_all(tasks).await?;
+            let results: Vec<crate::wallet::Result<(u32, usize, Vec<LegacyAddressFunds>)>> =
+                futures::future::try_join_all(tasks).await?;
 
             let mut new_accounts_with_outputs = 0;
             let mut highest_account_index = 0;
             for res in results {
-                let (account_index, outputs_count): (u32, usize) = res?;
-                if outputs_count != 0 {
+                let (account_index, outputs_count, account_legacy_funds) = res?;
+                if outputs_count != 0 || !account_legacy_funds.is_empty() {
                     new_accounts_with_outputs += 1;
+                    legacy_funds.extend(account_legacy_funds);
 
                     match *max_account_index_to_keep {
                         Some(max_account_index) => {
@@ -174,6 +242,6 @@ impl Wallet {
             }
         }
 
-        Ok(())
+        Ok(legacy_funds)
     }
 }
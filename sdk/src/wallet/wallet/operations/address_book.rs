@@ -0,0 +1,325 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    types::block::{
+        address::{Address, AliasAddress, Bech32Address, Hrp, NftAddress},
+        output::Output,
+    },
+    wallet::{
+        account::{Account, AccountDetails},
+        wallet::WalletInner,
+    },
+};
+
+/// Known destinations for outgoing transactions. If verification is enabled, transactions can only send outputs to
+/// addresses of the address book, addresses matching the allow-list or addresses of the sending account, unless
+/// [`TransactionOptions::force`](crate::wallet::account::TransactionOptions::force) is set.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AddressBook {
+    /// Whether the destinations of outgoing transactions are verified.
+    #[serde(default)]
+    pub verification_enabled: bool,
+    /// Known addresses with a name.
+    #[serde(default)]
+    pub entries: HashMap<Bech32Address, String>,
+    /// Patterns of allowed bech32 addresses, `*` matches any sequence of characters.
+    #[serde(default)]
+    pub allow_list: Vec<String>,
+}
+
+impl AddressBook {
+    /// Returns whether the address is in the address book or matches a pattern of the allow-list.
+    pub fn is_allowed(&self, address: &Bech32Address) -> bool {
+        // Compare without the HRP, entries could be added with another one
+        self.entries.keys().any(|entry| entry.inner() == address.inner()) || {
+            let address = address.to_string();
+            self.allow_list.iter().any(|pattern| matches_pattern(pattern, &address))
+        }
+    }
+}
+
+// Simple glob matching, where `*` matches any sequence of characters.
+fn matches_pattern(pattern: &str, value: &str) -> bool {
+    let mut parts = pattern.split('*');
+    // There is always at least one part
+    let Some(mut rest) = value.strip_prefix(parts.next().unwrap_or_default()) else {
+        return false;
+    };
+    let parts = parts.collect::<Vec<_>>();
+    let Some((last, middle)) = parts.split_last() else {
+        // No wildcard, the whole value needs to match
+        return rest.is_empty();
+    };
+
+    for part in middle {
+        match rest.find(part) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+
+    rest.ends_with(last)
+}
+
+impl WalletInner {
+    /// Returns the address book used to verify the destinations of outgoing transactions.
+    pub async fn address_book(&self) -> AddressBook {
+        self.address_book.read().await.clone()
+    }
+
+    /// Sets the address book used to verify the destinations of outgoing transactions.
+    /// If storage is enabled, will persist during restarts.
+    pub async fn set_address_book(&self, address_book: AddressBook) -> crate::wallet::Result<()> {
+        #[cfg(feature = "storage")]
+        self.storage_manager
+            .read()
+            .await
+            .set_address_book(&address_book)
+            .await?;
+
+        *self.address_book.write().await = address_book;
+        Ok(())
+    }
+}
+
+impl AddressBook {
+    /// Checks that all destinations of the outputs are allowed by the address book, if its verification is enabled.
+    /// The destinations include the return addresses and the alias controlling a foundry. Addresses of the accounts
+    /// and of the alias and nft outputs they own are always allowed.
+    pub(crate) fn verify_destinations(
+        &self,
+        bech32_hrp: Hrp,
+        accounts_details: &[&AccountDetails],
+        outputs: &[Output],
+    ) -> crate::wallet::Result<()> {
+        if !self.verification_enabled {
+            return Ok(());
+        }
+
+        for output in outputs {
+            let Some(unlock_conditions) = output.unlock_conditions() else {
+                continue;
+            };
+            let destinations = [
+                unlock_conditions.address().map(|uc| uc.address()),
+                unlock_conditions.state_controller_address().map(|uc| uc.address()),
+                unlock_conditions.governor_address().map(|uc| uc.address()),
+                unlock_conditions.immutable_alias_address().map(|uc| uc.address()),
+                unlock_conditions.storage_deposit_return().map(|uc| uc.return_address()),
+                unlock_conditions.expiration().map(|uc| uc.return_address()),
+            ];
+
+            for destination in destinations.into_iter().flatten() {
+                let own_destination = accounts_details.iter().any(|account_details| {
+                    let own_address = account_details
+                        .public_addresses
+                        .iter()
+                        .chain(account_details.internal_addresses.iter())
+                        .any(|account_address| account_address.address.inner() == destination);
+                    // Alias and nft addresses of chains owned by the account
+                    let own_chain =
+                        account_details
+                            .unspent_outputs()
+                            .values()
+                            .any(|output_data| match &output_data.output {
+                                Output::Alias(alias) => {
+                                    Address::Alias(AliasAddress::new(alias.alias_id_non_null(&output_data.output_id)))
+                                        == *destination
+                                }
+                                Output::Nft(nft) => {
+                                    Address::Nft(NftAddress::new(nft.nft_id_non_null(&output_data.output_id)))
+                                        == *destination
+                                }
+                                _ => false,
+                            });
+                    own_address || own_chain
+                });
+
+                let destination = Bech32Address::new(bech32_hrp, *destination);
+                if !own_destination && !self.is_allowed(&destination) {
+                    return Err(crate::wallet::Error::AddressNotInAddressBook(destination));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Account {
+    /// Checks that all destinations of the outputs are allowed by the address book, if its verification is enabled.
+    pub(crate) async fn verify_destinations(&self, outputs: &[Output]) -> crate::wallet::Result<()> {
+        let address_book = self.wallet.address_book.read().await;
+        if !address_book.verification_enabled {
+            return Ok(());
+        }
+        let bech32_hrp = self.client().get_bech32_hrp().await?;
+        let account_details = self.details().await;
+
+        address_book.verify_destinations(bech32_hrp, &[&account_details], outputs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use primitive_types::U256;
+
+    use super::*;
+    use crate::types::block::{
+        address::Ed25519Address,
+        output::{
+            unlock_condition::{
+                AddressUnlockCondition, ExpirationUnlockCondition, ImmutableAliasAddressUnlockCondition,
+                StorageDepositReturnUnlockCondition,
+            },
+            AliasId, BasicOutputBuilder, FoundryOutputBuilder, SimpleTokenScheme, TokenScheme,
+        },
+    };
+
+    const TOKEN_SUPPLY: u64 = 1_813_620_509_061_365;
+
+    fn address(byte: u8) -> Address {
+        Address::Ed25519(Ed25519Address::new([byte; Ed25519Address::LENGTH]))
+    }
+
+    fn own_address() -> Address {
+        *Bech32Address::from_str("rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy")
+            .unwrap()
+            .inner()
+    }
+
+    fn address_book(entries: &[Address]) -> AddressBook {
+        AddressBook {
+            verification_enabled: true,
+            entries: entries
+                .iter()
+                .map(|address| {
+                    (
+                        Bech32Address::new(Hrp::from_str_unchecked("rms"), *address),
+                        String::new(),
+                    )
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    fn verify(address_book: &AddressBook, output: Output) -> crate::wallet::Result<()> {
+        address_book.verify_destinations(Hrp::from_str_unchecked("rms"), &[&AccountDetails::mock()], &[output])
+    }
+
+    fn basic_output(address: Address) -> BasicOutputBuilder {
+        BasicOutputBuilder::new_with_amount(1_000_000).add_unlock_condition(AddressUnlockCondition::new(address))
+    }
+
+    #[test]
+    fn verify_destination_addresses() {
+        let address_book = address_book(&[address(1)]);
+
+        assert!(verify(
+            &address_book,
+            basic_output(own_address()).finish_output(TOKEN_SUPPLY).unwrap()
+        )
+        .is_ok());
+        assert!(verify(
+            &address_book,
+            basic_output(address(1)).finish_output(TOKEN_SUPPLY).unwrap()
+        )
+        .is_ok());
+        match verify(
+            &address_book,
+            basic_output(address(2)).finish_output(TOKEN_SUPPLY).unwrap(),
+        ) {
+            Err(crate::wallet::Error::AddressNotInAddressBook(destination)) => {
+                assert_eq!(destination.inner(), &address(2))
+            }
+            other => panic!("expected AddressNotInAddressBook, got {other:?}"),
+        }
+        // Nothing is enforced without the verification
+        let address_book = AddressBook {
+            verification_enabled: false,
+            ..address_book
+        };
+        assert!(verify(
+            &address_book,
+            basic_output(address(2)).finish_output(TOKEN_SUPPLY).unwrap()
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn verify_destination_return_addresses() {
+        let address_book = address_book(&[address(1)]);
+
+        let storage_deposit_return = basic_output(address(1))
+            .add_unlock_condition(StorageDepositReturnUnlockCondition::new(address(2), 50_000, TOKEN_SUPPLY).unwrap())
+            .finish_output(TOKEN_SUPPLY)
+            .unwrap();
+        assert!(matches!(
+            verify(&address_book, storage_deposit_return),
+            Err(crate::wallet::Error::AddressNotInAddressBook(destination)) if destination.inner() == &address(2)
+        ));
+
+        let expiration = basic_output(address(1))
+            .add_unlock_condition(ExpirationUnlockCondition::new(address(3), 1_700_000_000).unwrap())
+            .finish_output(TOKEN_SUPPLY)
+            .unwrap();
+        assert!(matches!(
+            verify(&address_book, expiration),
+            Err(crate::wallet::Error::AddressNotInAddressBook(destination)) if destination.inner() == &address(3)
+        ));
+
+        // Returned to the account
+        let own_return = basic_output(address(1))
+            .add_unlock_condition(
+                StorageDepositReturnUnlockCondition::new(own_address(), 50_000, TOKEN_SUPPLY).unwrap(),
+            )
+            .add_unlock_condition(ExpirationUnlockCondition::new(own_address(), 1_700_000_000).unwrap())
+            .finish_output(TOKEN_SUPPLY)
+            .unwrap();
+        assert!(verify(&address_book, own_return).is_ok());
+    }
+
+    #[test]
+    fn verify_destination_immutable_alias_address() {
+        let alias_address = AliasAddress::new(AliasId::new([4; AliasId::LENGTH]));
+        let foundry = FoundryOutputBuilder::new_with_amount(
+            1_000_000,
+            1,
+            TokenScheme::Simple(SimpleTokenScheme::new(U256::from(10), U256::from(0), U256::from(100)).unwrap()),
+        )
+        .add_unlock_condition(ImmutableAliasAddressUnlockCondition::new(alias_address))
+        .finish_output(TOKEN_SUPPLY)
+        .unwrap();
+
+        assert!(matches!(
+            verify(&address_book(&[]), foundry.clone()),
+            Err(crate::wallet::Error::AddressNotInAddressBook(destination))
+                if destination.inner() == &Address::Alias(alias_address)
+        ));
+        assert!(verify(&address_book(&[Address::Alias(alias_address)]), foundry).is_ok());
+    }
+
+    #[test]
+    fn address_pattern_matching() {
+        let address = "rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy";
+
+        assert!(matches_pattern(address, address));
+        assert!(matches_pattern("*", address));
+        assert!(matches_pattern("rms1*", address));
+        assert!(matches_pattern("rms1qpllaj*70zy", address));
+        assert!(matches_pattern("rms1*aj0*gtyr*", address));
+        assert!(!matches_pattern("smr1*", address));
+        assert!(!matches_pattern("rms1qpllaj", address));
+        assert!(!matches_pattern("rms1*aj0*zzz*", address));
+        assert!(!matches_pattern("*70zy*70zy", address));
+    }
+}
@@ -4,7 +4,7 @@
 use std::sync::atomic::Ordering;
 
 #[cfg(all(feature = "events", feature = "ledger_nano"))]
-use crate::wallet::events::types::{AddressData, WalletEvent};
+use crate::wallet::events::types::{AddressData, EventOrigin, WalletEvent};
 use crate::{
     client::secret::{GenerateAddressOptions, SecretManage, SecretManager},
     types::block::address::{Address, Hrp},
@@ -60,6 +60,7 @@ impl Wallet {
                             WalletEvent::LedgerAddressGeneration(AddressData {
                                 address: address[0].to_bech32(bech32_hrp),
                             }),
+                            EventOrigin::new("generate_address"),
                         )
                         .await;
                     }
@@ -3,6 +3,9 @@
 
 use std::{sync::atomic::Ordering, time::Duration};
 
+#[cfg(feature = "storage")]
+use instant::Instant;
+use serde::{Deserialize, Serialize};
 use tokio::time::sleep;
 
 use crate::wallet::{account::operations::syncing::SyncOptions, Wallet};
@@ -10,8 +13,35 @@ use crate::wallet::{account::operations::syncing::SyncOptions, Wallet};
 /// The default interval for background syncing
 pub(crate) const DEFAULT_BACKGROUNDSYNCING_INTERVAL: Duration = Duration::from_secs(7);
 
+/// The status of the background syncing process.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[repr(usize)]
+pub enum BackgroundSyncStatus {
+    /// The process isn't running.
+    Stopped = 0,
+    /// The accounts are synced in the configured interval.
+    Running = 1,
+    /// The process got requested to stop and will stop before syncing the next account.
+    Stopping = 2,
+    /// The process is running, but doesn't sync the accounts until it gets resumed.
+    Paused = 3,
+}
+
+impl From<usize> for BackgroundSyncStatus {
+    fn from(status: usize) -> Self {
+        match status {
+            1 => Self::Running,
+            2 => Self::Stopping,
+            3 => Self::Paused,
+            _ => Self::Stopped,
+        }
+    }
+}
+
 impl Wallet {
-    /// Start the background syncing process for all accounts, default interval is 7 seconds
+    /// Start the background syncing process for all accounts, default interval is 7 seconds. New and spent outputs
+    /// are emitted as [`NewOutput`](crate::wallet::events::types::WalletEvent::NewOutput) and
+    /// [`SpentOutput`](crate::wallet::events::types::WalletEvent::SpentOutput) events.
     pub async fn start_background_syncing(
         &self,
         options: Option<SyncOptions>,
@@ -19,15 +49,18 @@ impl Wallet {
     ) -> crate::wallet::Result<()> {
         log::debug!("[start_background_syncing]");
         // stop existing process if running
-        if self.background_syncing_status.load(Ordering::Relaxed) == 1 {
-            self.background_syncing_status.store(2, Ordering::Relaxed);
+        if matches!(
+            self.background_syncing_status(),
+            BackgroundSyncStatus::Running | BackgroundSyncStatus::Paused
+        ) {
+            self.set_background_syncing_status(BackgroundSyncStatus::Stopping);
         };
-        while self.background_syncing_status.load(Ordering::Relaxed) == 2 {
+        while self.background_syncing_status() == BackgroundSyncStatus::Stopping {
             log::debug!("[background_syncing]: waiting for the old process to stop");
             sleep(Duration::from_secs(1)).await;
         }
 
-        self.background_syncing_status.store(1, Ordering::Relaxed);
+        self.set_background_syncing_status(BackgroundSyncStatus::Running);
         let wallet = self.clone();
         let _background_syncing = std::thread::spawn(move || {
             #[cfg(not(target_family = "wasm"))]
@@ -41,30 +74,53 @@ impl Wallet {
                 .build()
                 .unwrap();
             runtime.block_on(async {
+                #[cfg(feature = "storage")]
+                let mut last_storage_compaction = Instant::now();
                 'outer: loop {
-                    log::debug!("[background_syncing]: syncing accounts");
-                    for account in wallet.accounts.read().await.iter() {
-                        // Check if the process should stop before syncing each account so it stops faster
-                        if wallet.background_syncing_status.load(Ordering::Relaxed) == 2 {
-                            log::debug!("[background_syncing]: stopping");
-                            break 'outer;
+                    if wallet.background_syncing_status() == BackgroundSyncStatus::Paused {
+                        log::debug!("[background_syncing]: paused");
+                    } else {
+                        log::debug!("[background_syncing]: syncing accounts");
+                        for account in wallet.accounts.read().await.iter() {
+                            // Check if the process should stop or pause before syncing each account so it reacts
+                            // faster
+                            match wallet.background_syncing_status() {
+                                BackgroundSyncStatus::Stopping => {
+                                    log::debug!("[background_syncing]: stopping");
+                                    break 'outer;
+                                }
+                                BackgroundSyncStatus::Paused => break,
+                                _ => {}
+                            }
+                            match account.sync(options.clone()).await {
+                                Ok(_) => {}
+                                Err(err) => log::debug!("[background_syncing] error: {}", err),
+                            };
+                        }
+                        if let Err(err) = wallet.check_clock_skew().await {
+                            log::debug!("[background_syncing] clock skew check error: {}", err);
+                        }
+                        #[cfg(feature = "storage")]
+                        if let Some(compaction_interval) = wallet.storage_compaction_interval() {
+                            if last_storage_compaction.elapsed() >= compaction_interval {
+                                if let Err(err) = wallet.compact_storage().await {
+                                    log::debug!("[background_syncing] storage compaction error: {}", err);
+                                }
+                                last_storage_compaction = Instant::now();
+                            }
                         }
-                        match account.sync(options.clone()).await {
-                            Ok(_) => {}
-                            Err(err) => log::debug!("[background_syncing] error: {}", err),
-                        };
                     }
                     // split interval syncing to seconds so stopping the process doesn't have to wait long
                     let seconds = interval.unwrap_or(DEFAULT_BACKGROUNDSYNCING_INTERVAL).as_secs();
                     for _ in 0..seconds {
-                        if wallet.background_syncing_status.load(Ordering::Relaxed) == 2 {
+                        if wallet.background_syncing_status() == BackgroundSyncStatus::Stopping {
                             log::debug!("[background_syncing]: stopping");
                             break 'outer;
                         }
                         sleep(Duration::from_secs(1)).await;
                     }
                 }
-                wallet.background_syncing_status.store(0, Ordering::Relaxed);
+                wallet.set_background_syncing_status(BackgroundSyncStatus::Stopped);
                 log::debug!("[background_syncing]: stopped");
             });
         });
@@ -75,13 +131,13 @@ impl Wallet {
     pub async fn stop_background_syncing(&self) -> crate::wallet::Result<()> {
         log::debug!("[stop_background_syncing]");
         // immediately return if not running
-        if self.background_syncing_status.load(Ordering::Relaxed) == 0 {
+        if self.background_syncing_status() == BackgroundSyncStatus::Stopped {
             return Ok(());
         }
         // send stop request
-        self.background_syncing_status.store(2, Ordering::Relaxed);
+        self.set_background_syncing_status(BackgroundSyncStatus::Stopping);
         // wait until it stopped
-        while self.background_syncing_status.load(Ordering::Relaxed) != 0 {
+        while self.background_syncing_status() != BackgroundSyncStatus::Stopped {
             #[cfg(target_family = "wasm")]
             gloo_timers::future::TimeoutFuture::new(10).await;
             #[cfg(not(target_family = "wasm"))]
@@ -89,4 +145,41 @@ impl Wallet {
         }
         Ok(())
     }
+
+    /// Pause the background syncing of the accounts, an account that is currently synced is finished first. Returns
+    /// whether the process got paused, which is only the case if it's running.
+    pub fn pause_background_syncing(&self) -> bool {
+        log::debug!("[pause_background_syncing]");
+        self.background_syncing_status
+            .compare_exchange(
+                BackgroundSyncStatus::Running as usize,
+                BackgroundSyncStatus::Paused as usize,
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            )
+            .is_ok()
+    }
+
+    /// Resume the paused background syncing of the accounts, they are synced again in the next interval. Returns
+    /// whether the process got resumed, which is only the case if it's paused.
+    pub fn resume_background_syncing(&self) -> bool {
+        log::debug!("[resume_background_syncing]");
+        self.background_syncing_status
+            .compare_exchange(
+                BackgroundSyncStatus::Paused as usize,
+                BackgroundSyncStatus::Running as usize,
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+            )
+            .is_ok()
+    }
+
+    /// Returns the status of the background syncing process.
+    pub fn background_syncing_status(&self) -> BackgroundSyncStatus {
+        self.background_syncing_status.load(Ordering::Relaxed).into()
+    }
+
+    fn set_background_syncing_status(&self, status: BackgroundSyncStatus) {
+        self.background_syncing_status.store(status as usize, Ordering::Relaxed);
+    }
 }
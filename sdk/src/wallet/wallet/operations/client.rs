@@ -1,18 +1,29 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::collections::{HashMap, HashSet};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::atomic::Ordering,
+};
 
+#[cfg(feature = "events")]
+use crate::wallet::events::types::{ClientOptionsChangedEvent, EventOrigin, WalletEvent};
 #[cfg(feature = "storage")]
 use crate::wallet::WalletBuilder;
 use crate::{
     client::{
+        api::TipCache,
+        constants::{
+            IOTA_BECH32_HRP, IOTA_COIN_TYPE, IOTA_TESTNET_BECH32_HRP, SHIMMER_BECH32_HRP, SHIMMER_COIN_TYPE,
+            SHIMMER_TESTNET_BECH32_HRP,
+        },
         node_manager::{
             builder::NodeManagerBuilder,
             node::{Node, NodeAuth, NodeDto},
         },
         Client, ClientBuilder, NodeInfoWrapper,
     },
+    types::block::address::Hrp,
     wallet::Wallet,
     Url,
 };
@@ -26,6 +37,12 @@ impl Wallet {
         ClientBuilder::from_client(self.client()).await
     }
 
+    /// Sets new client options. Only the parts affected by changed options are rebuilt: the node pool is only synced
+    /// again if the nodes changed and the MQTT subscriptions are only reconnected if the broker options or the nodes
+    /// changed. If the network changed, its bech32 HRP is validated against the coin type of the existing accounts,
+    /// which get their addresses updated to the new HRP. Emits a
+    /// [`ClientOptionsChanged`](crate::wallet::events::types::WalletEvent::ClientOptionsChanged) event if anything
+    /// changed.
     pub async fn set_client_options(&self, client_options: ClientBuilder) -> crate::wallet::Result<()> {
         let ClientBuilder {
             node_manager_builder,
@@ -36,21 +53,117 @@ impl Wallet {
             remote_pow_timeout,
             #[cfg(not(target_family = "wasm"))]
             pow_worker_count,
+            #[cfg(not(target_family = "wasm"))]
+            pow_provider,
+            tip_cache,
+            offline,
+            rent_structure,
+            time_source,
         } = client_options;
-        self.client
-            .update_node_manager(node_manager_builder.build(HashMap::new()))
-            .await?;
-        *self.client.network_info.write().await = network_info;
-        *self.client.api_timeout.write().await = api_timeout;
-        *self.client.remote_pow_timeout.write().await = remote_pow_timeout;
+        let current_options = self.client_options().await;
+        let network_info = network_info.with_rent_structure(rent_structure)?;
+
+        // Switching the offline mode starts or stops the node syncing, like a change of the nodes
+        let offline_changed = offline != current_options.offline;
+        let nodes_changed = offline_changed || node_manager_builder != current_options.node_manager_builder;
+        let network_info_changed = network_info != current_options.network_info;
+        #[cfg(feature = "mqtt")]
+        let mqtt_changed = nodes_changed || broker_options != current_options.broker_options;
+        #[cfg(not(feature = "mqtt"))]
+        let mqtt_changed = false;
+        let timeouts_changed =
+            api_timeout != current_options.api_timeout || remote_pow_timeout != current_options.remote_pow_timeout;
         #[cfg(not(target_family = "wasm"))]
+        let pow_changed =
+            pow_worker_count != current_options.pow_worker_count || pow_provider != current_options.pow_provider;
+        #[cfg(target_family = "wasm")]
+        let pow_changed = false;
+        let tip_cache_changed = tip_cache != current_options.tip_cache;
+        let time_source_changed = time_source != current_options.time_source;
+
+        if !(nodes_changed
+            || network_info_changed
+            || mqtt_changed
+            || timeouts_changed
+            || pow_changed
+            || tip_cache_changed
+            || time_source_changed)
         {
+            log::debug!("[set_client_options] nothing changed");
+            return Ok(());
+        }
+
+        let network_changed = nodes_changed || network_info_changed;
+        // The network info is set before the nodes are synced, so the protocol parameters of the nodes take precedence
+        if network_info_changed {
+            *self.client.network_info.write().await = network_info;
+        }
+        if nodes_changed {
+            self.client.offline.store(offline, Ordering::Relaxed);
+            self.client
+                .update_node_manager(node_manager_builder.build(HashMap::new()))
+                .await?;
+        }
+        if network_changed {
+            if let Err(err) = self.validate_network().await {
+                // Restore the previous network, so the client stays usable for the existing accounts
+                *self.client.network_info.write().await = current_options.network_info;
+                if nodes_changed {
+                    self.client.offline.store(current_options.offline, Ordering::Relaxed);
+                    self.client
+                        .update_node_manager(current_options.node_manager_builder.build(HashMap::new()))
+                        .await?;
+                }
+                return Err(err);
+            }
+        }
+
+        if timeouts_changed {
+            *self.client.api_timeout.write().await = api_timeout;
+            *self.client.remote_pow_timeout.write().await = remote_pow_timeout;
+        }
+        #[cfg(not(target_family = "wasm"))]
+        if pow_changed {
             *self.client.pow_worker_count.write().await = pow_worker_count;
+            *self.client.pow_provider.write().await = pow_provider;
+        }
+        if tip_cache_changed {
+            *self.client.tip_cache.lock().expect("failed to lock the tip cache") = TipCache::new(tip_cache);
+        }
+        if time_source_changed {
+            *self.client.time_source.write().await = time_source;
         }
         #[cfg(feature = "mqtt")]
-        {
+        if mqtt_changed {
             *self.client.mqtt.broker_options.write().await = broker_options;
+            self.client
+                .subscriber()
+                .reconnect()
+                .await
+                .map_err(crate::client::Error::from)?;
         }
+
+        // The changed bech32 HRP of the accounts, only used for the event
+        #[cfg_attr(not(feature = "events"), allow(unused_variables))]
+        let bech32_hrp = if network_changed {
+            // The cached network parameters need to be fetched again
+            for account in self.accounts.read().await.iter() {
+                account.invalidate_network_params().await;
+            }
+            let new_bech32_hrp = self.client.get_bech32_hrp().await?;
+            let accounts_bech32_hrp = self.accounts_bech32_hrp().await;
+            if accounts_bech32_hrp.map_or(false, |hrp| hrp != new_bech32_hrp) {
+                for account in self.accounts.write().await.iter_mut() {
+                    account.update_account_bech32_hrp().await?;
+                }
+                Some(new_bech32_hrp)
+            } else {
+                None
+            }
+        } else {
+            None
+        };
+
         #[cfg(feature = "storage")]
         {
             self.storage_manager
@@ -59,9 +172,56 @@ impl Wallet {
                 .save_wallet_data(&WalletBuilder::from_wallet(self).await)
                 .await?;
         }
+
+        #[cfg(feature = "events")]
+        self.emit(
+            None,
+            WalletEvent::ClientOptionsChanged(ClientOptionsChangedEvent {
+                nodes: nodes_changed,
+                network_info: network_info_changed,
+                mqtt: mqtt_changed,
+                timeouts: timeouts_changed,
+                pow: pow_changed,
+                bech32_hrp,
+            }),
+            EventOrigin::new("set_client_options"),
+        )
+        .await;
+
         Ok(())
     }
 
+    /// Checks that the bech32 HRP of the network belongs to the coin type of the existing accounts. HRPs of unknown
+    /// networks can't be checked and are accepted.
+    async fn validate_network(&self) -> crate::wallet::Result<()> {
+        if self.accounts.read().await.is_empty() {
+            return Ok(());
+        }
+        let bech32_hrp = self.client.get_bech32_hrp().await?;
+        let coin_type = self.coin_type.load(Ordering::Relaxed);
+        let expected_coin_type = if [IOTA_BECH32_HRP, IOTA_TESTNET_BECH32_HRP].contains(&bech32_hrp) {
+            IOTA_COIN_TYPE
+        } else if [SHIMMER_BECH32_HRP, SHIMMER_TESTNET_BECH32_HRP].contains(&bech32_hrp) {
+            SHIMMER_COIN_TYPE
+        } else {
+            return Ok(());
+        };
+        if coin_type != expected_coin_type {
+            return Err(crate::wallet::Error::NetworkMismatch { bech32_hrp, coin_type });
+        }
+        Ok(())
+    }
+
+    /// Returns the bech32 HRP of the addresses of the first account, if there is one.
+    async fn accounts_bech32_hrp(&self) -> Option<Hrp> {
+        let accounts = self.accounts.read().await;
+        let account_details = accounts.first()?.details().await;
+        account_details
+            .public_addresses()
+            .first()
+            .map(|address| address.address.hrp)
+    }
+
     /// Get the node info.
     pub async fn get_node_info(&self) -> crate::wallet::Result<NodeInfoWrapper> {
         let node_info_wrapper = self.client().get_info().await?;
@@ -0,0 +1,30 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#[cfg(feature = "events")]
+use crate::wallet::events::types::{EventOrigin, WalletEvent};
+use crate::{client::api::ClockSkew, wallet::Wallet};
+
+impl Wallet {
+    /// Estimates the skew of the local clock against the timestamp of the latest milestone, see
+    /// [`ClientInner::clock_skew()`](crate::client::ClientInner::clock_skew). A skewed clock makes timelocks and
+    /// expirations be evaluated at the wrong time, so a
+    /// [`ClockSkew`](crate::wallet::events::types::WalletEvent::ClockSkew) event is emitted if it exceeds the warning
+    /// threshold. Called during syncing.
+    pub async fn check_clock_skew(&self) -> crate::wallet::Result<Option<ClockSkew>> {
+        let clock_skew = self.client().clock_skew().await?;
+        log::debug!("[check_clock_skew] {clock_skew:?}");
+
+        #[cfg(feature = "events")]
+        if let Some(clock_skew) = clock_skew.filter(ClockSkew::exceeds_threshold) {
+            self.emit(
+                None,
+                WalletEvent::ClockSkew(clock_skew),
+                EventOrigin::new("check_clock_skew"),
+            )
+            .await;
+        }
+
+        Ok(clock_skew)
+    }
+}
@@ -0,0 +1,346 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{fs::File, io::Write, path::Path, sync::atomic::Ordering};
+
+use crypto::hashes::{blake2b::Blake2b256, Digest};
+use packable::{Packable, PackableExt};
+use serde::{Deserialize, Serialize};
+use zip::{write::FileOptions, CompressionMethod, ZipWriter};
+
+#[cfg(feature = "events")]
+use crate::wallet::events::types::{Event, EventOrigin, WalletEvent};
+use crate::{
+    client::NetworkInfoDto,
+    types::{
+        api::core::response::InfoResponse,
+        block::{
+            address::{Address, Hrp},
+            payload::transaction::TransactionEssence,
+        },
+    },
+    utils::unix_timestamp_now,
+    wallet::{
+        account::{
+            types::{AccountAddress, InclusionState, OutputData, Transaction},
+            AccountDetails,
+        },
+        Wallet,
+    },
+};
+
+/// Options for [`Wallet::create_debug_bundle()`].
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DebugBundleOptions {
+    /// Replace addresses, output ids, transaction ids and block ids with a keyed Blake2b-256 hash. The key is random
+    /// and discarded after the bundle has been written, so values can be correlated within the bundle, but not looked
+    /// up on the ledger.
+    #[serde(default)]
+    pub hash_identifiers: bool,
+}
+
+/// General information about the bundle and the wallet.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct BundleInfo {
+    sdk_version: &'static str,
+    created_at: u64,
+    coin_type: u32,
+    account_count: usize,
+    hash_identifiers: bool,
+}
+
+/// Information about the network and the node the client is connected to.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ClientSnapshot {
+    network_info: Option<NetworkInfoDto>,
+    node_info: Option<InfoResponse>,
+    errors: Vec<String>,
+}
+
+/// The state of an account without keys, labels, notes and other user provided data.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AccountSnapshot {
+    index: u32,
+    coin_type: u32,
+    public_addresses: Vec<AddressSnapshot>,
+    internal_addresses: Vec<AddressSnapshot>,
+    outputs: Vec<OutputSnapshot>,
+    locked_outputs: Vec<String>,
+    transactions: Vec<TransactionSnapshot>,
+    pending_transactions: Vec<String>,
+    incoming_transaction_count: usize,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AddressSnapshot {
+    address: String,
+    key_index: u32,
+    used: bool,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct OutputSnapshot {
+    output_id: String,
+    kind: u8,
+    amount: String,
+    native_token_count: usize,
+    address: String,
+    is_spent: bool,
+    remainder: bool,
+    milestone_index_booked: u32,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct TransactionSnapshot {
+    transaction_id: String,
+    block_id: Option<String>,
+    inclusion_state: InclusionState,
+    timestamp: String,
+    incoming: bool,
+    input_count: usize,
+    output_count: usize,
+}
+
+/// An emitted event without its payload, which can contain addresses and outputs.
+#[cfg(feature = "events")]
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct EventSnapshot {
+    account_index: Option<u32>,
+    sequence_number: u64,
+    timestamp: String,
+    origin: EventOrigin,
+    event_type: &'static str,
+    inclusion_state: Option<InclusionState>,
+}
+
+/// Redacts addresses and ids the same way for all files of a bundle.
+struct Redactor {
+    hrp: Hrp,
+    hash_key: Option<[u8; 32]>,
+}
+
+impl Redactor {
+    fn new(hrp: Hrp, hash_identifiers: bool) -> crate::wallet::Result<Self> {
+        let hash_key = if hash_identifiers {
+            let mut key = [0u8; 32];
+            crypto::utils::rand::fill(&mut key)?;
+            Some(key)
+        } else {
+            None
+        };
+        Ok(Self { hrp, hash_key })
+    }
+
+    fn hash(&self, value: &impl Packable) -> Option<String> {
+        self.hash_key.map(|key| {
+            let hash = Blake2b256::new()
+                .chain_update(key)
+                .chain_update(value.pack_to_vec())
+                .finalize();
+            prefix_hex::encode(hash.as_slice())
+        })
+    }
+
+    fn redact(&self, address: &Address) -> String {
+        self.hash(address)
+            .unwrap_or_else(|| address.to_bech32(self.hrp).to_string())
+    }
+
+    fn id(&self, id: &(impl Packable + core::fmt::Display)) -> String {
+        self.hash(id).unwrap_or_else(|| id.to_string())
+    }
+
+    fn account_address(&self, address: &AccountAddress) -> AddressSnapshot {
+        AddressSnapshot {
+            address: self.redact(address.address().inner()),
+            key_index: *address.key_index(),
+            used: *address.used(),
+        }
+    }
+
+    fn output(&self, output_data: &OutputData) -> OutputSnapshot {
+        OutputSnapshot {
+            output_id: self.id(&output_data.output_id),
+            kind: output_data.output.kind(),
+            amount: output_data.output.amount().to_string(),
+            native_token_count: output_data
+                .output
+                .native_tokens()
+                .map_or(0, |native_tokens| native_tokens.len()),
+            address: self.redact(&output_data.address),
+            is_spent: output_data.is_spent,
+            remainder: output_data.remainder,
+            milestone_index_booked: output_data.metadata.milestone_index_booked(),
+        }
+    }
+
+    fn account(&self, account_details: &AccountDetails) -> AccountSnapshot {
+        AccountSnapshot {
+            index: *account_details.index(),
+            coin_type: *account_details.coin_type(),
+            public_addresses: account_details
+                .public_addresses()
+                .iter()
+                .map(|address| self.account_address(address))
+                .collect(),
+            internal_addresses: account_details
+                .internal_addresses()
+                .iter()
+                .map(|address| self.account_address(address))
+                .collect(),
+            outputs: account_details
+                .outputs()
+                .values()
+                .map(|output_data| self.output(output_data))
+                .collect(),
+            locked_outputs: account_details
+                .locked_outputs()
+                .iter()
+                .map(|output_id| self.id(output_id))
+                .collect(),
+            transactions: account_details
+                .transactions()
+                .values()
+                .map(|transaction| self.transaction(transaction))
+                .collect(),
+            pending_transactions: account_details
+                .pending_transactions()
+                .iter()
+                .map(|transaction_id| self.id(transaction_id))
+                .collect(),
+            incoming_transaction_count: account_details.incoming_transactions().len(),
+        }
+    }
+
+    fn transaction(&self, transaction: &Transaction) -> TransactionSnapshot {
+        let TransactionEssence::Regular(essence) = transaction.payload.essence();
+        TransactionSnapshot {
+            transaction_id: self.id(&transaction.transaction_id),
+            block_id: transaction.block_id.as_ref().map(|block_id| self.id(block_id)),
+            inclusion_state: transaction.inclusion_state,
+            timestamp: transaction.timestamp.to_string(),
+            incoming: transaction.incoming,
+            input_count: essence.inputs().len(),
+            output_count: essence.outputs().len(),
+        }
+    }
+}
+
+#[cfg(feature = "events")]
+fn event_snapshot(event: Event) -> EventSnapshot {
+    let (event_type, inclusion_state) = match &event.event {
+        #[cfg(feature = "stronghold")]
+        WalletEvent::AutoBackup(_) => ("AutoBackup", None),
+        WalletEvent::ClientOptionsChanged(_) => ("ClientOptionsChanged", None),
+        WalletEvent::ClockSkew(_) => ("ClockSkew", None),
+        WalletEvent::ConsolidationRequired => ("ConsolidationRequired", None),
+        #[cfg(feature = "ledger_nano")]
+        WalletEvent::LedgerAddressGeneration(_) => ("LedgerAddressGeneration", None),
+        WalletEvent::NewOutput(_) => ("NewOutput", None),
+        WalletEvent::SpentOutput(_) => ("SpentOutput", None),
+        WalletEvent::SyncCompleted(_) => ("SyncCompleted", None),
+        WalletEvent::SyncSummary(_) => ("SyncSummary", None),
+        WalletEvent::ThresholdAlert(_) => ("ThresholdAlert", None),
+        WalletEvent::TransactionInclusion(inclusion) => ("TransactionInclusion", Some(inclusion.inclusion_state)),
+        WalletEvent::TransactionProgress(_) => ("TransactionProgress", None),
+    };
+    EventSnapshot {
+        account_index: event.account_index,
+        sequence_number: event.sequence_number,
+        timestamp: event.timestamp.to_string(),
+        origin: event.origin,
+        event_type,
+        inclusion_state,
+    }
+}
+
+impl Wallet {
+    /// Writes a zip file to `path` that can be attached to bug reports. It contains the SDK version, information about
+    /// the network and node, the recently emitted events and the state of all accounts. Keys, mnemonics, address
+    /// labels, notes and other user provided data are never included, addresses and ids can be hashed with `options`.
+    pub async fn create_debug_bundle(
+        &self,
+        path: impl AsRef<Path> + Send,
+        options: DebugBundleOptions,
+    ) -> crate::wallet::Result<()> {
+        log::debug!("[create_debug_bundle]");
+        let mut errors = Vec::new();
+        let network_info = match self.client().get_network_info().await {
+            Ok(network_info) => Some(NetworkInfoDto::from(network_info)),
+            Err(e) => {
+                errors.push(e.to_string());
+                None
+            }
+        };
+        let node_info = match self.client().get_info().await {
+            Ok(node_info) => Some(node_info.node_info),
+            Err(e) => {
+                errors.push(e.to_string());
+                None
+            }
+        };
+        let client = ClientSnapshot {
+            network_info,
+            node_info,
+            errors,
+        };
+
+        let redactor = Redactor::new(self.client().get_bech32_hrp().await?, options.hash_identifiers)?;
+        let mut accounts = Vec::new();
+        for account in self.accounts.read().await.iter() {
+            accounts.push(redactor.account(&*account.details().await));
+        }
+
+        let info = BundleInfo {
+            sdk_version: env!("CARGO_PKG_VERSION"),
+            created_at: unix_timestamp_now().as_secs(),
+            coin_type: self.coin_type.load(Ordering::Relaxed),
+            account_count: accounts.len(),
+            hash_identifiers: options.hash_identifiers,
+        };
+
+        let mut files = vec![
+            ("info.json".to_string(), serde_json::to_vec_pretty(&info)?),
+            ("client.json".to_string(), serde_json::to_vec_pretty(&client)?),
+        ];
+        #[cfg(feature = "events")]
+        {
+            let events = self
+                .event_emitter
+                .read()
+                .await
+                .recent_events()
+                .into_iter()
+                .map(event_snapshot)
+                .collect::<Vec<_>>();
+            files.push(("events.json".to_string(), serde_json::to_vec_pretty(&events)?));
+        }
+        for account in &accounts {
+            files.push((
+                format!("accounts/{}.json", account.index),
+                serde_json::to_vec_pretty(account)?,
+            ));
+        }
+
+        let path = path.as_ref().to_path_buf();
+        tokio::task::spawn_blocking(move || {
+            let mut zip = ZipWriter::new(File::create(path)?);
+            let file_options = FileOptions::default().compression_method(CompressionMethod::Deflated);
+            for (name, content) in files {
+                zip.start_file(name, file_options)?;
+                zip.write_all(&content)?;
+            }
+            zip.finish()?;
+            Ok(())
+        })
+        .await?
+    }
+}
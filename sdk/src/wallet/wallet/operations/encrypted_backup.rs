@@ -0,0 +1,283 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Backups of the wallet data in a password encrypted JSON file, which doesn't depend on the Stronghold snapshot
+//! format. The key is derived from the password with Argon2id and the data is encrypted with XChaCha20-Poly1305.
+
+use std::{path::PathBuf, sync::atomic::Ordering};
+
+use crypto::ciphers::chacha;
+use futures::{future::try_join_all, FutureExt};
+use serde::{Deserialize, Serialize};
+use zeroize::{Zeroize, Zeroizing};
+
+#[cfg(feature = "storage")]
+use crate::wallet::WalletBuilder;
+use crate::{
+    client::secret::{SecretManager, SecretManagerDto},
+    wallet::{
+        account::{builder::get_first_public_address, AccountDetails},
+        Account, ClientOptions, Error, Wallet,
+    },
+};
+
+const BACKUP_VERSION: u8 = 1;
+const KDF_ARGON2ID: &str = "argon2id";
+const CIPHER_XCHACHA20_POLY1305: &str = "xchacha20poly1305";
+const SALT_LENGTH: usize = 16;
+// Upper bounds for the key derivation parameters read from a backup file, so a crafted file can't make the restore
+// allocate gigabytes of memory or run for hours
+const MAX_MEM_COST: u32 = 1024 * 1024;
+const MAX_TIME_COST: u32 = 16;
+const MAX_LANES: u32 = 16;
+
+// The content of an encrypted backup file, the parameters are stored so they can be changed for new backups
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct EncryptedBackup {
+    version: u8,
+    kdf: KdfParams,
+    cipher: String,
+    // Nonce, tag and ciphertext of the serialized `BackupData`
+    ciphertext: String,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct KdfParams {
+    name: String,
+    salt: String,
+    mem_cost: u32,
+    time_cost: u32,
+    lanes: u32,
+}
+
+impl Default for KdfParams {
+    fn default() -> Self {
+        Self {
+            name: KDF_ARGON2ID.to_string(),
+            salt: String::new(),
+            // 64 MiB
+            mem_cost: 65536,
+            time_cost: 3,
+            lanes: 1,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BackupData {
+    client_options: ClientOptions,
+    coin_type: u32,
+    secret_manager: Option<SecretManagerDto>,
+    accounts: Vec<AccountDetails>,
+}
+
+impl Wallet {
+    /// Backup the wallet data in a JSON file encrypted with the password, as a portable alternative to
+    /// [`Wallet::backup()`](crate::wallet::Wallet) for wallets without a Stronghold secret manager. Only the
+    /// configuration of Ledger Nano secret managers is backed up, neither mnemonics nor Stronghold seeds are included.
+    /// An existing file at the path is overwritten.
+    pub async fn backup_to_encrypted_json(
+        &self,
+        backup_path: PathBuf,
+        mut password: String,
+    ) -> crate::wallet::Result<()> {
+        log::debug!("[backup_to_encrypted_json] creating an encrypted backup");
+
+        let secret_manager = match SecretManagerDto::from(&*self.secret_manager.read().await) {
+            // The seed can't be serialized
+            SecretManagerDto::Mnemonic(_) => None,
+            #[cfg(feature = "stronghold")]
+            SecretManagerDto::Stronghold(_) => None,
+            secret_manager => Some(secret_manager),
+        };
+        let mut accounts = Vec::new();
+        for account in self.accounts.read().await.iter() {
+            accounts.push(account.details().await.clone());
+        }
+        let data = Zeroizing::new(serde_json::to_vec(&BackupData {
+            client_options: self.client_options().await,
+            coin_type: self.coin_type.load(Ordering::Relaxed),
+            secret_manager,
+            accounts,
+        })?);
+
+        // The key derivation is expensive on purpose and would block the executor
+        tokio::task::spawn_blocking(move || {
+            let mut salt = [0u8; SALT_LENGTH];
+            crypto::utils::rand::fill(&mut salt)?;
+            let kdf = KdfParams {
+                salt: prefix_hex::encode(salt),
+                ..Default::default()
+            };
+            let key = derive_key(&password, &kdf);
+            password.zeroize();
+
+            let backup = EncryptedBackup {
+                version: BACKUP_VERSION,
+                kdf,
+                cipher: CIPHER_XCHACHA20_POLY1305.to_string(),
+                ciphertext: prefix_hex::encode(chacha::aead_encrypt(key?.as_slice(), &data)?),
+            };
+            std::fs::write(backup_path, serde_json::to_string_pretty(&backup)?)?;
+
+            Ok(())
+        })
+        .await?
+    }
+
+    /// Restore a backup created with [`Wallet::backup_to_encrypted_json()`]. Replaces the client options, coin type
+    /// and accounts, and the secret manager if one was backed up. Returns an error if accounts were already created,
+    /// or if the accounts weren't derived from the secret manager.
+    pub async fn restore_from_encrypted_json(
+        &self,
+        backup_path: PathBuf,
+        mut password: String,
+    ) -> crate::wallet::Result<()> {
+        log::debug!("[restore_from_encrypted_json] loading encrypted backup");
+
+        // The key derivation is expensive on purpose and would block the executor
+        let data = tokio::task::spawn_blocking(move || {
+            let backup: EncryptedBackup = serde_json::from_slice(&std::fs::read(backup_path)?)?;
+            if backup.version != BACKUP_VERSION {
+                return Err(Error::Backup("unsupported backup version"));
+            }
+            if backup.kdf.name != KDF_ARGON2ID || backup.cipher != CIPHER_XCHACHA20_POLY1305 {
+                return Err(Error::Backup("unsupported key derivation or cipher"));
+            }
+
+            let key = derive_key(&password, &backup.kdf);
+            password.zeroize();
+
+            let ciphertext: Vec<u8> =
+                prefix_hex::decode(&backup.ciphertext).map_err(|_| Error::Backup("invalid ciphertext"))?;
+            let data = Zeroizing::new(
+                chacha::aead_decrypt(key?.as_slice(), &ciphertext)
+                    .map_err(|_| Error::Backup("invalid password or corrupted backup"))?,
+            );
+            Ok(serde_json::from_slice::<BackupData>(&data)?)
+        })
+        .await??;
+
+        // We don't want to overwrite possible existing accounts
+        if !self.accounts.read().await.is_empty() {
+            return Err(Error::Backup("can't restore backup when there are already accounts"));
+        }
+
+        let secret_manager = data
+            .secret_manager
+            .as_ref()
+            .map(|secret_manager| {
+                SecretManager::try_from(secret_manager).map_err(|_| Error::Backup("invalid secret_manager"))
+            })
+            .transpose()?;
+        // Like for Stronghold backups, the first address of each account has to be derived from the secret manager,
+        // otherwise the restored accounts would show funds that can't be spent
+        {
+            let restored_secret_manager = secret_manager.map(tokio::sync::RwLock::new);
+            let secret_manager = restored_secret_manager.as_ref().unwrap_or(&*self.secret_manager);
+            for account in data.accounts.iter().filter(|account| !account.watch_only) {
+                let first_address = account
+                    .public_addresses()
+                    .first()
+                    .ok_or(Error::Backup("account without a public address"))?;
+                if get_first_public_address(secret_manager, data.coin_type, *account.index()).await?
+                    != first_address.address.inner
+                {
+                    return Err(Error::Backup("the accounts don't belong to the secret manager"));
+                }
+            }
+            if let Some(restored_secret_manager) = restored_secret_manager {
+                *self.secret_manager.write().await = restored_secret_manager.into_inner();
+            }
+        }
+        self.coin_type.store(data.coin_type, Ordering::Relaxed);
+        // Set before the accounts are locked, because it accesses them
+        self.set_client_options(data.client_options).await?;
+
+        let mut accounts = self.accounts.write().await;
+        *accounts = try_join_all(
+            data.accounts
+                .into_iter()
+                .map(|account_details| Account::new(account_details, self.inner.clone()).boxed()),
+        )
+        .await?;
+
+        #[cfg(feature = "storage")]
+        {
+            let wallet_builder = WalletBuilder::new()
+                .with_secret_manager_arc(self.secret_manager.clone())
+                .with_storage_path(
+                    &self
+                        .storage_options
+                        .storage_path
+                        .clone()
+                        .into_os_string()
+                        .into_string()
+                        .expect("can't convert os string"),
+                )
+                .with_client_options(self.client_options().await)
+                .with_coin_type(self.coin_type.load(Ordering::Relaxed));
+            self.storage_manager
+                .read()
+                .await
+                .save_wallet_data(&wallet_builder)
+                .await?;
+            for account in accounts.iter() {
+                account.save(None).await?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn derive_key(password: &str, kdf: &KdfParams) -> crate::wallet::Result<Zeroizing<[u8; 32]>> {
+    if kdf.mem_cost > MAX_MEM_COST || kdf.time_cost > MAX_TIME_COST || kdf.lanes > MAX_LANES {
+        return Err(Error::Backup("key derivation parameters exceed the limits"));
+    }
+    let salt: Vec<u8> = prefix_hex::decode(&kdf.salt).map_err(|_| Error::Backup("invalid salt"))?;
+    let config = argon2::Config {
+        variant: argon2::Variant::Argon2id,
+        version: argon2::Version::Version13,
+        mem_cost: kdf.mem_cost,
+        time_cost: kdf.time_cost,
+        lanes: kdf.lanes,
+        hash_length: 32,
+        ..Default::default()
+    };
+    let hash = Zeroizing::new(
+        argon2::hash_raw(password.as_bytes(), &salt, &config).map_err(|_| Error::Backup("key derivation failed"))?,
+    );
+
+    let mut key = Zeroizing::new([0u8; 32]);
+    key.copy_from_slice(&hash);
+    Ok(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn derived_key_depends_on_password_and_salt() {
+        let kdf = KdfParams {
+            salt: prefix_hex::encode([1u8; SALT_LENGTH]),
+            // Keep the test fast
+            mem_cost: 8,
+            time_cost: 1,
+            ..Default::default()
+        };
+        let key = derive_key("password", &kdf).unwrap();
+        assert_eq!(key, derive_key("password", &kdf).unwrap());
+        assert_ne!(key, derive_key("other password", &kdf).unwrap());
+
+        let other_salt = KdfParams {
+            salt: prefix_hex::encode([2u8; SALT_LENGTH]),
+            ..kdf
+        };
+        assert_ne!(key, derive_key("password", &other_salt).unwrap());
+    }
+}
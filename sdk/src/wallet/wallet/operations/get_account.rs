@@ -7,7 +7,9 @@ use crate::wallet::{
 };
 
 impl Wallet {
-    /// Get an account with an AccountIdentifier
+    /// Get an account with an AccountIdentifier. A string that looks like an
+    /// [`AccountId`](crate::wallet::account::types::AccountId) also matches an account with that alias, if no account
+    /// has it as id.
     pub async fn get_account<I: Into<AccountIdentifier> + Send>(
         &self,
         identifier: I,
@@ -34,10 +36,50 @@ impl Wallet {
                     }
                 }
             }
+            AccountIdentifier::Id(id) => {
+                for account in accounts.iter() {
+                    let account_details = account.details().await;
+
+                    if account_details.account_id() == id {
+                        return Ok(account.clone());
+                    }
+                }
+                // Aliases that look like an id can't be told apart from one when parsing the identifier
+                let id = id.to_string();
+                for account in accounts.iter() {
+                    if account.details().await.alias().to_lowercase() == id {
+                        return Ok(account.clone());
+                    }
+                }
+            }
         };
 
         Err(crate::wallet::Error::AccountNotFound(serde_json::to_string(
             &account_id,
         )?))
     }
+
+    /// Renames an account, fails if another account already uses the alias. The account keeps its index and
+    /// [`AccountId`](crate::wallet::account::types::AccountId), so it can still be found with them.
+    pub async fn rename_account<I: Into<AccountIdentifier> + Send>(
+        &self,
+        identifier: I,
+        alias: &str,
+    ) -> crate::wallet::Result<()> {
+        let account = self.get_account(identifier).await?;
+        // Hold the lock so no account with the same alias can be created in the meantime
+        let accounts = self.accounts.read().await;
+        let index = *account.details().await.index();
+
+        for other_account in accounts.iter() {
+            let other_account_details = other_account.details().await;
+            if *other_account_details.index() != index
+                && other_account_details.alias().to_lowercase() == alias.to_lowercase()
+            {
+                return Err(crate::wallet::Error::AccountAliasAlreadyExists(alias.to_string()));
+            }
+        }
+
+        account.set_alias(alias).await
+    }
 }
@@ -2,15 +2,30 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub(crate) mod account_recovery;
+pub(crate) mod address_book;
 pub(crate) mod address_generation;
 pub(crate) mod background_syncing;
 pub(crate) mod client;
+pub(crate) mod clock_skew;
+#[cfg(feature = "debug_bundle")]
+pub(crate) mod debug_bundle;
+#[cfg(feature = "encrypted_backup")]
+pub(crate) mod encrypted_backup;
 pub(crate) mod get_account;
 #[cfg(feature = "ledger_nano")]
 pub(crate) mod ledger_nano;
+pub(crate) mod multi_account_transaction;
+#[cfg(feature = "mqtt")]
+pub(crate) mod node_events;
+#[cfg(feature = "wallet-participation")]
+pub(crate) mod participation;
+#[cfg(feature = "storage")]
+pub(crate) mod storage_compaction;
 #[cfg(feature = "stronghold")]
 pub(crate) mod stronghold;
 #[cfg(feature = "stronghold")]
 pub(crate) mod stronghold_backup;
+pub(crate) mod sync_all;
+pub(crate) mod transaction_templates;
 #[cfg(debug_assertions)]
 pub(crate) mod verify_integrity;
@@ -0,0 +1,492 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+
+#[cfg(feature = "events")]
+use crate::wallet::events::types::{EventOrigin, TransactionProgressEvent, WalletEvent};
+use crate::{
+    client::{
+        api::{
+            input_selection::InputSelection, transaction::validate_transaction_payload_length, PreparedTransactionData,
+            SignedTransactionData,
+        },
+        secret::{types::InputSigningData, SignTransactionEssence},
+    },
+    types::block::{
+        address::{Address, Hrp},
+        output::{Output, OutputId},
+        payload::transaction::TransactionPayload,
+    },
+    wallet::{
+        account::{
+            operations::{
+                token_policy::verify_token_policy,
+                transaction::{
+                    input_selection::{filter_inputs, verify_burn_capabilities},
+                    pending_transaction, verify_signed_transaction, PreparedTransactionContext, RemainderValueStrategy,
+                    TransactionOptions,
+                },
+            },
+            types::{AccountIdentifier, Transaction},
+            Account,
+        },
+        Error, Wallet,
+    },
+};
+
+impl Wallet {
+    /// Sends a single transaction that consumes inputs of multiple accounts of the wallet, for example to fund a
+    /// payment that no account can cover alone. The inputs are selected from all participating accounts together and
+    /// the transaction is stored in each account that contributed inputs.
+    /// ```ignore
+    /// let transaction = wallet
+    ///     .send_from_accounts(
+    ///         vec![AccountIdentifier::from("Alice"), AccountIdentifier::from("Bob")],
+    ///         outputs,
+    ///         None,
+    ///     )
+    ///     .await?;
+    /// ```
+    pub async fn send_from_accounts(
+        &self,
+        accounts: Vec<AccountIdentifier>,
+        outputs: Vec<Output>,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<Transaction> {
+        let prepared_transaction_data = self
+            .prepare_multi_account_transaction(accounts, outputs, options)
+            .await?;
+
+        self.sign_and_submit_multi_account_transaction(prepared_transaction_data)
+            .await
+    }
+
+    /// Selects inputs from multiple accounts and builds the transaction essence, see
+    /// [`Wallet::send_from_accounts()`]. The selected inputs are locked in the accounts they belong to.
+    ///
+    /// A remainder goes to the address of an input, unless the options set a custom address or a change address,
+    /// which is generated by the first of the accounts. Remainder splits, spending allowances, storage deposit budgets
+    /// and confirmation depths aren't supported.
+    pub async fn prepare_multi_account_transaction(
+        &self,
+        accounts: Vec<AccountIdentifier>,
+        outputs: Vec<Output>,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<PreparedTransactionData> {
+        log::debug!("[TRANSACTION] prepare_multi_account_transaction");
+        let options = options.into();
+
+        if let Some(options) = &options {
+            if options.remainder_split.is_some()
+                || matches!(options.remainder_value_strategy, RemainderValueStrategy::Split { .. })
+                || options.allowance.is_some()
+                || options.max_storage_deposit.is_some()
+                || options.confirmation_depth.is_some()
+            {
+                return Err(Error::InvalidMultiAccountTransaction(
+                    "remainder splits, allowances, storage deposit budgets and confirmation depths aren't supported"
+                        .to_string(),
+                ));
+            }
+        }
+
+        let mut participating_accounts: Vec<Account> = Vec::with_capacity(accounts.len());
+        let mut account_indexes = Vec::with_capacity(accounts.len());
+        for identifier in accounts {
+            let account = self.get_account(identifier).await?;
+            let account_index = *account.details().await.index();
+            if account_indexes.contains(&account_index) {
+                return Err(Error::InvalidMultiAccountTransaction(format!(
+                    "account {account_index} is provided more than once"
+                )));
+            }
+            account.check_not_watch_only("sign transactions").await?;
+            account_indexes.push(account_index);
+            participating_accounts.push(account);
+        }
+        let first_account = participating_accounts
+            .first()
+            .ok_or_else(|| Error::InvalidMultiAccountTransaction("no accounts provided".to_string()))?;
+        // Nothing is prepared if one of the accounts can't send the transaction
+        for account in &participating_accounts {
+            account.check_rate_limit().await?;
+        }
+
+        let network_params = first_account.network_params().await?;
+        for output in &outputs {
+            output.verify_storage_deposit(network_params.rent_structure, network_params.token_supply)?;
+        }
+
+        // The change address that is reserved for this transaction
+        let mut reserved_remainder_address = None;
+        let remainder_address = match options.as_ref().map(|options| &options.remainder_value_strategy) {
+            Some(RemainderValueStrategy::ChangeAddress) => {
+                reserved_remainder_address = Some(first_account.generate_remainder_address().await?.address().inner);
+                reserved_remainder_address
+            }
+            Some(RemainderValueStrategy::CustomAddress(address)) => Some(address.address().inner),
+            // The input selection selects an address from the inputs
+            _ => None,
+        };
+
+        let note = options.as_ref().and_then(|options| options.note.clone());
+        let prepared_transaction_data = match self
+            .select_and_build_multi_account_transaction(
+                &participating_accounts,
+                &account_indexes,
+                outputs,
+                options,
+                remainder_address,
+                network_params.bech32_hrp,
+            )
+            .await
+        {
+            Ok(prepared_transaction_data) => prepared_transaction_data,
+            Err(err) => {
+                // release the change address so it's used for the next transaction
+                if let Some(address) = &reserved_remainder_address {
+                    first_account.release_remainder_address(address).await;
+                }
+                return Err(err);
+            }
+        };
+
+        first_account
+            .set_prepared_transaction_context(
+                &prepared_transaction_data,
+                PreparedTransactionContext {
+                    note,
+                    allowance_usage: None,
+                    remainder_address: reserved_remainder_address,
+                    ..Default::default()
+                },
+            )
+            .await;
+        Ok(prepared_transaction_data)
+    }
+
+    // Selects the inputs of the accounts and builds the transaction essence, the selected inputs are locked in the
+    // accounts they belong to unless it fails
+    async fn select_and_build_multi_account_transaction(
+        &self,
+        participating_accounts: &[Account],
+        account_indexes: &[u32],
+        outputs: Vec<Output>,
+        options: Option<TransactionOptions>,
+        remainder_address: Option<Address>,
+        bech32_hrp: Hrp,
+    ) -> crate::wallet::Result<PreparedTransactionData> {
+        let first_account = &participating_accounts[0];
+        let custom_inputs = options
+            .as_ref()
+            .and_then(|options| options.custom_inputs.as_ref())
+            .map(|inputs| HashSet::from_iter(inputs.clone()));
+        let mandatory_inputs = options
+            .as_ref()
+            .and_then(|options| options.mandatory_inputs.as_ref())
+            .map(|inputs| HashSet::from_iter(inputs.clone()));
+        let required_inputs: Option<&HashSet<OutputId>> = custom_inputs.as_ref().or(mandatory_inputs.as_ref());
+        let burn = options.as_ref().and_then(|options| options.burn.as_ref());
+
+        let mut forbidden_inputs = HashSet::new();
+
+        // Voting outputs need to be requested before to prevent a deadlock
+        #[cfg(feature = "wallet-participation")]
+        for account in participating_accounts {
+            if let Some(voting_output) = account.get_voting_output().await? {
+                if !required_inputs.map_or(false, |inputs| inputs.contains(&voting_output.output_id)) {
+                    forbidden_inputs.insert(voting_output.output_id);
+                }
+            }
+        }
+
+        let protocol_parameters = self.client().get_protocol_parameters().await?;
+        let current_time = self.client().get_time_checked().await?;
+
+        #[cfg(feature = "events")]
+        for account_index in account_indexes {
+            self.emit(
+                *account_index,
+                WalletEvent::TransactionProgress(TransactionProgressEvent::SelectingInputs),
+                EventOrigin::new("prepare_multi_account_transaction"),
+            )
+            .await;
+        }
+
+        // Lock the accounts in the order of their indexes, so concurrent calls can't deadlock
+        let mut locking_order = participating_accounts.iter().zip(account_indexes).collect::<Vec<_>>();
+        locking_order.sort_by_key(|(_, account_index)| **account_index);
+        let mut accounts_details = Vec::with_capacity(locking_order.len());
+        for (account, _) in locking_order {
+            accounts_details.push(account.details_mut().await);
+        }
+
+        let mut available_inputs = Vec::new();
+        let mut addresses = Vec::new();
+        for account_details in &accounts_details {
+            forbidden_inputs.extend(account_details.locked_outputs.iter().copied());
+            addresses.extend(
+                account_details
+                    .public_addresses()
+                    .iter()
+                    .chain(account_details.internal_addresses().iter())
+                    .map(|address| *address.address.as_ref()),
+            );
+            available_inputs.extend(filter_inputs(
+                account_details,
+                account_details.unspent_outputs().values(),
+                current_time,
+                &outputs,
+                burn,
+                custom_inputs.as_ref(),
+                mandatory_inputs.as_ref(),
+            )?);
+        }
+
+        let mut input_selection = InputSelection::new(available_inputs, outputs, addresses, protocol_parameters)
+            .strategy(
+                options
+                    .as_ref()
+                    .map(|options| options.input_selection_strategy)
+                    .unwrap_or_default(),
+            )
+            .timestamp(current_time);
+        if let Some(required_inputs) = required_inputs {
+            // Check that no input got already locked
+            for input in required_inputs {
+                if accounts_details
+                    .iter()
+                    .any(|account_details| account_details.locked_outputs.contains(input))
+                {
+                    return Err(Error::CustomInput(format!(
+                        "provided custom input {input} is already used in another transaction",
+                    )));
+                }
+            }
+            input_selection = input_selection.required_inputs(required_inputs.clone());
+        }
+        input_selection = input_selection.forbidden_inputs(forbidden_inputs);
+        if let Some(address) = remainder_address {
+            input_selection = input_selection.remainder_address(address);
+        }
+        if let Some(burn) = burn {
+            input_selection = input_selection.burn(burn.clone());
+        }
+
+        let selected_transaction_data = input_selection.select()?;
+
+        // The outputs created by the input selection, like remainders, are verified too
+        if !options.as_ref().map_or(false, |options| options.force) {
+            self.address_book.read().await.verify_destinations(
+                bech32_hrp,
+                &accounts_details
+                    .iter()
+                    .map(|account_details| &**account_details)
+                    .collect::<Vec<_>>(),
+                &selected_transaction_data.outputs,
+            )?;
+        }
+
+        verify_burn_capabilities(
+            &selected_transaction_data,
+            burn,
+            options.as_ref().map_or(false, |options| options.allow_burning_tokens),
+            options
+                .as_ref()
+                .map_or(false, |options| options.allow_destroying_chains),
+        )?;
+        if !options
+            .as_ref()
+            .map_or(false, |options| options.allow_restricted_tokens)
+        {
+            for account_details in &accounts_details {
+                verify_token_policy(account_details, &selected_transaction_data)?;
+            }
+        }
+
+        // Lock the inputs in the accounts they belong to, so they don't get used by another transaction
+        for input in &selected_transaction_data.inputs {
+            if let Some(account_details) = accounts_details
+                .iter_mut()
+                .find(|account_details| account_details.unspent_outputs().contains_key(input.output_id()))
+            {
+                log::debug!(
+                    "[TRANSACTION] locking {} in account {}",
+                    input.output_id(),
+                    account_details.index()
+                );
+                account_details.locked_outputs.insert(*input.output_id());
+            }
+        }
+        drop(accounts_details);
+
+        match first_account
+            .build_transaction_essence(selected_transaction_data.clone(), options)
+            .await
+        {
+            Ok(prepared_transaction_data) => Ok(prepared_transaction_data),
+            Err(err) => {
+                // unlock outputs so they are available for a new transaction
+                unlock_inputs(&participating_accounts, &selected_transaction_data.inputs).await?;
+                Err(err)
+            }
+        }
+    }
+
+    /// Signs a transaction prepared with [`Wallet::prepare_multi_account_transaction()`], submits it to a node and
+    /// stores it in each account that contributed inputs, with the sequence number of that account if it has the
+    /// transaction sequencing enabled. Returns the transaction as stored in the participating account with the lowest
+    /// index.
+    pub async fn sign_and_submit_multi_account_transaction(
+        &self,
+        prepared_transaction_data: PreparedTransactionData,
+    ) -> crate::wallet::Result<Transaction> {
+        log::debug!("[TRANSACTION] sign_and_submit_multi_account_transaction");
+
+        // The accounts which locked the inputs, sorted by their indexes
+        let mut participating_accounts = Vec::new();
+        for account in self.get_accounts().await? {
+            let account_index = {
+                let account_details = account.details().await;
+                prepared_transaction_data
+                    .inputs_data
+                    .iter()
+                    .any(|input| account_details.unspent_outputs().contains_key(input.output_id()))
+                    .then_some(*account_details.index())
+            };
+            if let Some(account_index) = account_index {
+                participating_accounts.push((account_index, account));
+            }
+        }
+        participating_accounts.sort_by_key(|(account_index, _)| *account_index);
+        let participating_accounts = participating_accounts
+            .into_iter()
+            .map(|(_, account)| account)
+            .collect::<Vec<_>>();
+        if participating_accounts.is_empty() {
+            return Err(Error::InvalidMultiAccountTransaction(
+                "the inputs don't belong to any account of the wallet".to_string(),
+            ));
+        }
+
+        for account in &participating_accounts {
+            if let Err(err) = account.check_rate_limit().await {
+                // unlock outputs so they are available for a new transaction
+                discard_prepared_transaction(&participating_accounts, &prepared_transaction_data).await?;
+                return Err(err);
+            }
+        }
+
+        let signed_transaction_data = match self
+            .sign_multi_account_transaction(&participating_accounts, &prepared_transaction_data)
+            .await
+        {
+            Ok(signed_transaction_data) => signed_transaction_data,
+            Err(err) => {
+                // unlock outputs so they are available for a new transaction
+                discard_prepared_transaction(&participating_accounts, &prepared_transaction_data).await?;
+                return Err(err);
+            }
+        };
+
+        // Held until the transaction is stored, so it's counted for the next transactions
+        let mut rate_limit_guards = Vec::with_capacity(participating_accounts.len());
+        for account in &participating_accounts {
+            match account.enforce_rate_limit().await {
+                Ok(guard) => rate_limit_guards.push(guard),
+                Err(err) => {
+                    drop(rate_limit_guards);
+                    // unlock outputs so they are available for a new transaction
+                    discard_prepared_transaction(&participating_accounts, &prepared_transaction_data).await?;
+                    return Err(err);
+                }
+            }
+        }
+
+        // Validate transaction before sending and storing it
+        if let Err(err) = verify_signed_transaction(self.client(), &signed_transaction_data).await {
+            drop(rate_limit_guards);
+            // unlock outputs so they are available for a new transaction
+            discard_prepared_transaction(&participating_accounts, &prepared_transaction_data).await?;
+            return Err(err);
+        }
+
+        // The context was kept by the first account passed to `prepare_multi_account_transaction()`
+        let mut note = None;
+        for account in &participating_accounts {
+            note = note.or(account
+                .take_prepared_transaction_context(&prepared_transaction_data.essence.hash())
+                .await
+                .note);
+        }
+
+        let block_id = participating_accounts[0]
+            .try_submit_transaction_payload(signed_transaction_data.transaction_payload.clone())
+            .await;
+
+        let network_id = self.client().get_network_id().await?;
+        let transaction = pending_transaction(signed_transaction_data, block_id, network_id, note, None);
+
+        let mut stored_transactions = Vec::with_capacity(participating_accounts.len());
+        for account in &participating_accounts {
+            stored_transactions.push(account.store_transaction(transaction.clone()).await?);
+        }
+        #[cfg(feature = "stronghold")]
+        self.auto_backup_transaction_sent();
+
+        Ok(stored_transactions.remove(0))
+    }
+
+    // Signs the inputs of all participating accounts at once, they share the secret manager of the wallet
+    async fn sign_multi_account_transaction(
+        &self,
+        participating_accounts: &[Account],
+        prepared_transaction_data: &PreparedTransactionData,
+    ) -> crate::wallet::Result<SignedTransactionData> {
+        for account in participating_accounts {
+            account.check_not_watch_only("sign transactions").await?;
+            #[cfg(feature = "events")]
+            self.emit(
+                *account.details().await.index(),
+                WalletEvent::TransactionProgress(TransactionProgressEvent::SigningTransaction),
+                EventOrigin::new("sign_and_submit_multi_account_transaction"),
+            )
+            .await;
+        }
+
+        let unlocks = self
+            .secret_manager
+            .read()
+            .await
+            .sign_transaction_essence(prepared_transaction_data, None)
+            .await?;
+        let transaction_payload = TransactionPayload::new(prepared_transaction_data.essence.clone(), unlocks)?;
+
+        validate_transaction_payload_length(&transaction_payload)?;
+
+        Ok(SignedTransactionData {
+            transaction_payload,
+            inputs_data: prepared_transaction_data.inputs_data.clone(),
+        })
+    }
+}
+
+// Unlocks the inputs in all accounts, accounts ignore inputs that weren't locked by them
+async fn unlock_inputs(accounts: &[Account], inputs: &[InputSigningData]) -> crate::wallet::Result<()> {
+    for account in accounts {
+        account.unlock_inputs(inputs).await?;
+    }
+    Ok(())
+}
+
+// Discards the prepared transaction in all accounts
+async fn discard_prepared_transaction(
+    accounts: &[Account],
+    prepared_transaction_data: &PreparedTransactionData,
+) -> crate::wallet::Result<()> {
+    for account in accounts {
+        account.discard_prepared_transaction(prepared_transaction_data).await?;
+    }
+    Ok(())
+}
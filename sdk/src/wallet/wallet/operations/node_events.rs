@@ -0,0 +1,225 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::atomic::Ordering;
+
+use crate::{
+    client::{
+        async_runtime,
+        node_api::mqtt::{MqttEvent, MqttPayload, Topic, TopicEvent},
+    },
+    types::{
+        api::core::response::OutputWithMetadataResponse,
+        block::{
+            address::Bech32Address,
+            output::{Output, OutputMetadata, OutputWithMetadata},
+        },
+    },
+    wallet::{account::update::NodeOutputUpdate, Account, BackgroundSyncStatus, Wallet},
+};
+#[cfg(feature = "events")]
+use crate::{
+    types::block::payload::transaction::dto::TransactionPayloadDto,
+    wallet::{
+        account::types::OutputDataDto,
+        events::types::{EventOrigin, NewOutputEvent, SpentOutputEvent, WalletEvent},
+    },
+};
+
+const ADDRESS_TOPIC_PREFIX: &str = "outputs/unlock/address/";
+
+impl Wallet {
+    /// Subscribes to the output topics of the MQTT broker of the node for all addresses of all accounts, so new and
+    /// spent outputs are added to the accounts and emitted as
+    /// [`NewOutput`](crate::wallet::events::types::WalletEvent::NewOutput) and
+    /// [`SpentOutput`](crate::wallet::events::types::WalletEvent::SpentOutput) events right away, instead of with the
+    /// next sync. Addresses generated afterwards are only tracked after calling this method again.
+    /// If the broker disconnects, which only happens after
+    /// [`BrokerOptions::max_reconnection_attempts()`](crate::client::mqtt::BrokerOptions::max_reconnection_attempts)
+    /// failed reconnection attempts, background syncing is started as fallback if it's not already running. It's
+    /// stopped again when the broker reconnects or [`Wallet::stop_listening_to_node_events()`] is called.
+    pub async fn listen_to_node_events(&self) -> crate::wallet::Result<()> {
+        log::debug!("[listen_to_node_events]");
+        let mut topics = Vec::new();
+        for account in self.accounts.read().await.iter() {
+            let account_details = account.details().await;
+            for account_address in account_details
+                .public_addresses
+                .iter()
+                .chain(account_details.internal_addresses.iter())
+            {
+                topics.push(Topic::try_new(format!(
+                    "{ADDRESS_TOPIC_PREFIX}{}",
+                    account_address.address
+                ))?);
+                topics.push(Topic::try_new(format!(
+                    "{ADDRESS_TOPIC_PREFIX}{}/spent",
+                    account_address.address
+                ))?);
+            }
+        }
+
+        // Replace the previous subscriptions, so addresses generated in the meantime are included
+        self.stop_listening_to_node_events().await?;
+        if topics.is_empty() {
+            return Ok(());
+        }
+
+        let wallet = self.clone();
+        self.client
+            .subscribe(topics.clone(), move |event| {
+                let wallet = wallet.clone();
+                let event = event.clone();
+                async_runtime::spawn(async move {
+                    if let Err(err) = wallet.handle_node_output_event(event).await {
+                        log::debug!("[listen_to_node_events] error: {}", err);
+                    }
+                });
+            })
+            .await?;
+        *self.node_event_topics.write().await = topics;
+
+        // Fall back to polling if the broker disconnects
+        let mut mqtt_event_receiver = self.client.mqtt_event_receiver().await;
+        let wallet = self.clone();
+        async_runtime::spawn(async move {
+            while mqtt_event_receiver.changed().await.is_ok() {
+                if wallet.node_event_topics.read().await.is_empty() {
+                    break;
+                }
+                let mqtt_event = *mqtt_event_receiver.borrow();
+                match mqtt_event {
+                    MqttEvent::Disconnected if wallet.background_syncing_status() == BackgroundSyncStatus::Stopped => {
+                        log::debug!("[listen_to_node_events] broker disconnected, starting background syncing");
+                        if let Err(err) = wallet.start_background_syncing(None, None).await {
+                            log::debug!("[listen_to_node_events] background syncing error: {}", err);
+                        } else {
+                            wallet.node_events_fallback_syncing.store(true, Ordering::Relaxed);
+                        }
+                    }
+                    MqttEvent::Connected => {
+                        log::debug!("[listen_to_node_events] broker reconnected");
+                        if let Err(err) = wallet.stop_fallback_syncing().await {
+                            log::debug!("[listen_to_node_events] background syncing error: {}", err);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        });
+
+        Ok(())
+    }
+
+    /// Unsubscribes from the topics subscribed to by [`Wallet::listen_to_node_events()`].
+    pub async fn stop_listening_to_node_events(&self) -> crate::wallet::Result<()> {
+        log::debug!("[stop_listening_to_node_events]");
+        let topics = std::mem::take(&mut *self.node_event_topics.write().await);
+        if !topics.is_empty() {
+            self.client.unsubscribe(topics).await?;
+        }
+        self.stop_fallback_syncing().await
+    }
+
+    // Stops the background syncing if it was started because the broker disconnected
+    async fn stop_fallback_syncing(&self) -> crate::wallet::Result<()> {
+        if self.node_events_fallback_syncing.swap(false, Ordering::Relaxed) {
+            log::debug!("[listen_to_node_events] stopping the fallback background syncing");
+            self.stop_background_syncing().await?;
+        }
+        Ok(())
+    }
+
+    // Applies an output received from an address topic to the account the address belongs to
+    async fn handle_node_output_event(&self, event: TopicEvent) -> crate::wallet::Result<()> {
+        let (Some(address), MqttPayload::Json(payload)) =
+            (event.topic.strip_prefix(ADDRESS_TOPIC_PREFIX), event.payload)
+        else {
+            return Ok(());
+        };
+        let address = Bech32Address::try_from_str(address.trim_end_matches("/spent"))?;
+
+        let output_response: OutputWithMetadataResponse = serde_json::from_value(payload)?;
+        let token_supply = self.client.get_token_supply().await?;
+        let output_with_metadata = OutputWithMetadata::new(
+            Output::try_from_dto(&output_response.output, token_supply)?,
+            OutputMetadata::try_from(&output_response.metadata)?,
+        );
+
+        for account in self.accounts.read().await.iter() {
+            if account.apply_node_output(&address, &output_with_metadata).await? {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Account {
+    // Adds a new or updates a spent output received from the node, returns false if the address doesn't belong to the
+    // account. Events are emitted after the account is unlocked, so listeners can access it.
+    async fn apply_node_output(
+        &self,
+        address: &Bech32Address,
+        output_with_metadata: &OutputWithMetadata,
+    ) -> crate::wallet::Result<bool> {
+        let network_id = self.client().get_network_id().await?;
+        let mut account_details = self.details_mut().await;
+        let update = account_details.apply_node_output(address, output_with_metadata, network_id);
+
+        #[cfg(feature = "events")]
+        let event = match &update {
+            NodeOutputUpdate::New(output_data) => {
+                log::debug!("[listen_to_node_events] new output {}", output_data.output_id);
+                let transaction = account_details
+                    .incoming_transactions()
+                    .get(output_data.output_id.transaction_id());
+                Some((
+                    WalletEvent::NewOutput(Box::new(NewOutputEvent {
+                        output: OutputDataDto::from(output_data),
+                        transaction: transaction.map(|tx| TransactionPayloadDto::from(&tx.payload)),
+                        transaction_inputs: transaction.map(|tx| {
+                            tx.inputs
+                                .clone()
+                                .into_iter()
+                                .map(OutputWithMetadataResponse::from)
+                                .collect()
+                        }),
+                    })),
+                    EventOrigin::new("listen_to_node_events")
+                        .with_transaction_id(*output_data.output_id.transaction_id()),
+                ))
+            }
+            NodeOutputUpdate::Spent(output_data) => {
+                log::debug!("[listen_to_node_events] spent output {}", output_data.output_id);
+                let mut origin = EventOrigin::new("listen_to_node_events");
+                if let Some(transaction_id) = output_data.metadata.transaction_id_spent() {
+                    origin = origin.with_transaction_id(*transaction_id);
+                }
+                Some((
+                    WalletEvent::SpentOutput(Box::new(SpentOutputEvent {
+                        output: OutputDataDto::from(output_data),
+                    })),
+                    origin,
+                ))
+            }
+            NodeOutputUpdate::UnknownAddress | NodeOutputUpdate::Unchanged => None,
+        };
+
+        #[cfg(feature = "storage")]
+        if matches!(update, NodeOutputUpdate::New(_) | NodeOutputUpdate::Spent(_)) {
+            self.save(Some(&account_details)).await?;
+        }
+        #[cfg(feature = "events")]
+        let account_index = *account_details.index();
+        drop(account_details);
+
+        #[cfg(feature = "events")]
+        if let Some((event, origin)) = event {
+            self.emit(account_index, event, origin).await;
+        }
+
+        Ok(update != NodeOutputUpdate::UnknownAddress)
+    }
+}
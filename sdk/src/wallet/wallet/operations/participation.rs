@@ -0,0 +1,192 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    types::api::plugins::participation::{
+        responses::TrackedParticipation,
+        types::{ParticipationEventId, ParticipationEventPayload},
+    },
+    wallet::{
+        account::{AccountParticipationOverview, ParticipationEventWithNodes},
+        Result, Wallet,
+    },
+};
+
+/// The participation overview of all accounts of a wallet, returned from [`Wallet::get_participation_overview()`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletParticipationOverview {
+    /// The participation overviews of the accounts by account index.
+    pub accounts: HashMap<u32, AccountParticipationOverview>,
+    /// The participations of all accounts summed up per event.
+    pub events: HashMap<ParticipationEventId, ParticipationEventTotals>,
+    /// Whether the overview of any account was restored from the cache, because the event nodes couldn't be reached.
+    pub stale: bool,
+}
+
+/// The participations of all accounts of a wallet for an event.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParticipationEventTotals {
+    /// The indices of the accounts participating in the event.
+    pub account_indices: Vec<u32>,
+    /// The amount of outputs that participated in the event.
+    pub output_count: usize,
+    /// The amount of the participations that are still active.
+    #[serde(with = "crate::utils::serde::string")]
+    pub active_amount: u64,
+    /// The milestone index of the event status, `None` if no account registered the event.
+    pub milestone_index: Option<u32>,
+    /// The staking rewards accumulated up to the milestone index of the event status, `None` for voting events or
+    /// if no account registered the event.
+    #[serde(default, with = "crate::utils::serde::option_string")]
+    pub rewards: Option<u64>,
+    /// The staking rewards at the end of the event if the active participations are kept, `None` for voting events or
+    /// if no account registered the event.
+    #[serde(default, with = "crate::utils::serde::option_string")]
+    pub projected_rewards: Option<u64>,
+}
+
+impl Wallet {
+    /// Calculates the participation overview of all accounts, see
+    /// [`Account::get_participation_overview()`](crate::wallet::Account::get_participation_overview), and sums it up
+    /// per event. If event_ids are provided, only these events are included. The client and the status of an event are
+    /// only requested once for all accounts. The rewards of staking events are projected with the milestone index of
+    /// the event status.
+    pub async fn get_participation_overview(
+        &self,
+        event_ids: Option<Vec<ParticipationEventId>>,
+    ) -> Result<WalletParticipationOverview> {
+        log::debug!("[get_participation_overview] wallet");
+        let accounts = self.accounts.read().await.clone();
+
+        let mut event_clients = HashMap::new();
+        let mut account_overviews = HashMap::new();
+        // The registered events and the first account that registered them, to request the event status once
+        let mut registered_events: HashMap<ParticipationEventId, (ParticipationEventWithNodes, usize)> = HashMap::new();
+        for (position, account) in accounts.iter().enumerate() {
+            let overview = account
+                .get_participation_overview_with_clients(event_ids.clone(), &mut event_clients)
+                .await?;
+            account_overviews.insert(*account.details().await.index(), overview);
+            for (event_id, event) in account.get_participation_events().await? {
+                registered_events.entry(event_id).or_insert((event, position));
+            }
+        }
+
+        let mut events: HashMap<ParticipationEventId, ParticipationEventTotals> = HashMap::new();
+        for (account_index, overview) in &account_overviews {
+            for (event_id, participations) in &overview.participations {
+                let totals = events.entry(*event_id).or_default();
+                totals.account_indices.push(*account_index);
+                totals.output_count += participations.len();
+                totals.active_amount += participations
+                    .values()
+                    .filter(|participation| participation.end_milestone_index == 0)
+                    .map(|participation| participation.amount)
+                    .sum::<u64>();
+            }
+        }
+
+        for (event_id, totals) in &mut events {
+            totals.account_indices.sort_unstable();
+            let Some((event, position)) = registered_events.get(event_id) else {
+                continue;
+            };
+            let status = accounts[*position].get_participation_event_status(event_id).await?;
+            let milestone_index = *status.milestone_index();
+            totals.milestone_index = Some(milestone_index);
+
+            if let ParticipationEventPayload::StakingEventPayload(staking) = event.data.payload() {
+                let end_milestone_index = *event.data.milestone_index_end();
+                let (mut rewards, mut projected_rewards) = (0, 0);
+                for participation in account_overviews
+                    .values()
+                    .filter_map(|overview| overview.participations.get(event_id))
+                    .flat_map(|participations| participations.values())
+                {
+                    let (reward, projected_reward) = staking_rewards(
+                        participation,
+                        (*event.data.milestone_index_start(), end_milestone_index),
+                        milestone_index,
+                        (*staking.numerator(), *staking.denominator()),
+                    );
+                    rewards += reward;
+                    projected_rewards += projected_reward;
+                }
+                totals.rewards = Some(rewards);
+                totals.projected_rewards = Some(projected_rewards);
+            }
+        }
+
+        Ok(WalletParticipationOverview {
+            stale: account_overviews.values().any(|overview| overview.stale),
+            accounts: account_overviews,
+            events,
+        })
+    }
+}
+
+// Returns the rewards of a participation up to the milestone index and at the end of the event, every milestone
+// between the start and the end of the participation in the event adds `amount * numerator / denominator`
+fn staking_rewards(
+    participation: &TrackedParticipation,
+    (event_start, event_end): (u32, u32),
+    milestone_index: u32,
+    (numerator, denominator): (u64, u64),
+) -> (u64, u64) {
+    if denominator == 0 {
+        return (0, 0);
+    }
+    let start = participation.start_milestone_index.max(event_start);
+    let end = match participation.end_milestone_index {
+        0 => event_end,
+        end => end.min(event_end),
+    };
+    let rewards_per_milestone = participation.amount as u128 * numerator as u128 / denominator as u128;
+    let rewards =
+        |until: u32| (rewards_per_milestone * until.saturating_sub(start) as u128).min(u64::MAX as u128) as u64;
+
+    (rewards(end.min(milestone_index)), rewards(end))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::block::BlockId;
+
+    #[test]
+    fn staking_rewards_projection() {
+        let mut participation = TrackedParticipation {
+            block_id: BlockId::new([0; 32]),
+            amount: 1_000_000,
+            start_milestone_index: 90,
+            end_milestone_index: 0,
+            answers: None,
+        };
+
+        // Rewards only count from the start of the event, 1_000_000 * 1 / 2 per milestone
+        assert_eq!(
+            staking_rewards(&participation, (100, 200), 150, (1, 2)),
+            (25_000_000, 50_000_000)
+        );
+        // The milestone index can't be after the end of the event
+        assert_eq!(
+            staking_rewards(&participation, (100, 200), 250, (1, 2)),
+            (50_000_000, 50_000_000)
+        );
+
+        // Ended participations don't get rewards afterwards
+        participation.end_milestone_index = 120;
+        assert_eq!(
+            staking_rewards(&participation, (100, 200), 150, (1, 2)),
+            (10_000_000, 10_000_000)
+        );
+
+        assert_eq!(staking_rewards(&participation, (100, 200), 150, (1, 0)), (0, 0));
+    }
+}
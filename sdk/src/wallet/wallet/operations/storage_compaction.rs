@@ -0,0 +1,61 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{sync::atomic::Ordering, time::Duration};
+
+use serde::{Deserialize, Serialize};
+
+use crate::wallet::{wallet::WalletInner, Wallet};
+
+/// The result of [`Wallet::compact_storage()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StorageCompaction {
+    /// The size of the storage in bytes before the compaction, if the storage adapter knows it.
+    pub size_before: Option<u64>,
+    /// The size of the storage in bytes after the compaction, if the storage adapter knows it.
+    pub size_after: Option<u64>,
+}
+
+impl StorageCompaction {
+    /// Returns the amount of bytes that got reclaimed by the compaction, if the size of the storage is known.
+    pub fn reclaimed(&self) -> Option<u64> {
+        Some(self.size_before?.saturating_sub(self.size_after?))
+    }
+}
+
+impl Wallet {
+    /// Compacts the storage to reclaim the space of removed and overwritten records, which accumulate in long-lived
+    /// wallets.
+    pub async fn compact_storage(&self) -> crate::wallet::Result<StorageCompaction> {
+        log::debug!("[compact_storage]");
+        let storage_manager = self.storage_manager.read().await;
+        let size_before = storage_manager.storage.size().await?;
+        storage_manager.storage.compact().await?;
+        let size_after = storage_manager.storage.size().await?;
+
+        let compaction = StorageCompaction {
+            size_before,
+            size_after,
+        };
+        log::debug!("[compact_storage] reclaimed {:?} bytes", compaction.reclaimed());
+        Ok(compaction)
+    }
+}
+
+impl WalletInner {
+    /// Sets the interval in which the background syncing compacts the storage, `None` disables it. Disabled by
+    /// default.
+    pub fn set_storage_compaction_interval(&self, interval: Option<Duration>) {
+        let seconds = interval.map_or(0, |interval| interval.as_secs().max(1));
+        self.storage_compaction_interval.store(seconds, Ordering::Relaxed);
+    }
+
+    /// Returns the interval in which the background syncing compacts the storage.
+    pub fn storage_compaction_interval(&self) -> Option<Duration> {
+        match self.storage_compaction_interval.load(Ordering::Relaxed) {
+            0 => None,
+            seconds => Some(Duration::from_secs(seconds)),
+        }
+    }
+}
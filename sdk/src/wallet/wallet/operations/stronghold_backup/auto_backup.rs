@@ -0,0 +1,299 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU32, AtomicU64, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::Notify;
+
+use super::stronghold_snapshot::store_data_to_stronghold;
+#[cfg(feature = "events")]
+use crate::wallet::events::types::{AutoBackupEvent, EventOrigin, WalletEvent};
+use crate::{
+    client::secret::{SecretManager, SecretManagerDto},
+    wallet::{wallet::WalletInner, Error, Wallet},
+};
+
+const AUTO_BACKUP_FILE_PREFIX: &str = "auto-backup-";
+const AUTO_BACKUP_FILE_EXTENSION: &str = ".stronghold";
+
+/// Options for the automatic Stronghold backups of a wallet, see
+/// [`WalletBuilder::with_auto_backup()`](crate::wallet::WalletBuilder::with_auto_backup). A backup is written when
+/// the interval elapsed or the amount of transactions was sent since the last backup, whatever happens first.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AutoBackupOptions {
+    /// The interval in which backups are written, `None` disables it.
+    pub interval: Option<Duration>,
+    /// The amount of sent transactions after which a backup is written, `None` disables it.
+    pub transactions: Option<u32>,
+    /// The directory the timestamped backup files are written to.
+    pub directory: PathBuf,
+    /// The amount of backups that are kept, older ones are removed.
+    pub keep_last_n: usize,
+}
+
+// The state of the automatic backups, shared between the wallet and the backup task
+#[derive(Debug)]
+pub(crate) struct AutoBackup {
+    pub(crate) options: AutoBackupOptions,
+    // Transactions sent since the last backup
+    sent_transactions: AtomicU32,
+    // The timestamp of the last backup file name, so backups written in the same millisecond get different names
+    last_timestamp: AtomicU64,
+    // Wakes the backup task, which only holds weak references to the wallet
+    trigger: Arc<Notify>,
+}
+
+impl AutoBackup {
+    pub(crate) fn new(options: AutoBackupOptions) -> crate::wallet::Result<Self> {
+        if options.keep_last_n == 0 {
+            return Err(Error::Backup("automatic backups need to keep at least one backup"));
+        }
+        Ok(Self {
+            options,
+            sent_transactions: AtomicU32::new(0),
+            last_timestamp: AtomicU64::new(0),
+            trigger: Arc::new(Notify::new()),
+        })
+    }
+
+    // Counts a sent transaction and wakes the backup task once the amount of the options is reached
+    fn transaction_sent(&self) {
+        if let Some(transactions) = self.options.transactions {
+            if self.sent_transactions.fetch_add(1, Ordering::Relaxed) + 1 >= transactions {
+                self.trigger.notify_one();
+            }
+        }
+    }
+
+    // Returns a timestamp in milliseconds that is greater than the one of the previous backup
+    fn next_timestamp(&self) -> u64 {
+        let now = crate::utils::unix_timestamp_now().as_millis() as u64;
+        let previous = self
+            .last_timestamp
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |last| Some(now.max(last + 1)))
+            .unwrap_or_else(|last| last);
+        now.max(previous + 1)
+    }
+}
+
+impl Drop for AutoBackup {
+    fn drop(&mut self) {
+        // Wake the task, so it notices that the wallet got dropped
+        self.trigger.notify_one();
+    }
+}
+
+impl WalletInner {
+    /// Counts a sent transaction and triggers a backup once the amount of the auto backup options is reached.
+    pub(crate) fn auto_backup_transaction_sent(&self) {
+        if let Some(auto_backup) = &self.auto_backup {
+            auto_backup.transaction_sent();
+        }
+    }
+}
+
+impl Wallet {
+    /// Writes a backup with the auto backup options now and removes the oldest backups exceeding `keep_last_n`.
+    /// Returns the path of the backup. The current password of the Stronghold secret manager is used, so the backup
+    /// fails if it got cleared.
+    pub async fn write_auto_backup(&self) -> crate::wallet::Result<PathBuf> {
+        let auto_backup = self
+            .auto_backup
+            .as_ref()
+            .ok_or(Error::Backup("automatic backups aren't enabled"))?;
+        log::debug!(
+            "[write_auto_backup] writing backup to {:?}",
+            auto_backup.options.directory
+        );
+
+        let secret_manager = self.secret_manager.read().await;
+        let SecretManager::Stronghold(stronghold) = &*secret_manager else {
+            return Err(Error::Backup("automatic backups require a Stronghold secret manager"));
+        };
+
+        // Transactions sent while the backup is written are counted for the next one
+        let sent_transactions = auto_backup.sent_transactions.load(Ordering::Relaxed);
+        let directory = auto_backup.options.directory.clone();
+        tokio::task::spawn_blocking(move || std::fs::create_dir_all(directory)).await??;
+        let backup_path = auto_backup.options.directory.join(format!(
+            "{AUTO_BACKUP_FILE_PREFIX}{}{AUTO_BACKUP_FILE_EXTENSION}",
+            auto_backup.next_timestamp()
+        ));
+        store_data_to_stronghold(self, stronghold, SecretManagerDto::from(&*secret_manager)).await?;
+        stronghold.write_stronghold_snapshot(Some(&backup_path)).await?;
+        drop(secret_manager);
+        auto_backup
+            .sent_transactions
+            .fetch_sub(sent_transactions, Ordering::Relaxed);
+
+        let directory = auto_backup.options.directory.clone();
+        let keep_last_n = auto_backup.options.keep_last_n;
+        tokio::task::spawn_blocking(move || remove_old_backups(&directory, keep_last_n)).await??;
+
+        Ok(backup_path)
+    }
+
+    // Writes a backup and emits the result as event, errors are only logged, because nobody awaits the backup
+    async fn run_auto_backup(&self) {
+        let result = self.write_auto_backup().await;
+        if let Err(err) = &result {
+            log::warn!("[auto_backup] writing backup failed: {err}");
+        }
+
+        #[cfg(feature = "events")]
+        self.emit(
+            None,
+            WalletEvent::AutoBackup(match result {
+                Ok(path) => AutoBackupEvent {
+                    path: Some(path),
+                    error: None,
+                },
+                Err(err) => AutoBackupEvent {
+                    path: None,
+                    error: Some(err.to_string()),
+                },
+            }),
+            EventOrigin::new("auto_backup"),
+        )
+        .await;
+    }
+
+    /// Spawns the task that writes the automatic backups, it stops once the wallet is dropped.
+    pub(crate) fn spawn_auto_backup_task(&self) {
+        let Some(auto_backup) = &self.auto_backup else {
+            return;
+        };
+        let interval = auto_backup.options.interval;
+        let trigger = auto_backup.trigger.clone();
+        let inner = Arc::downgrade(&self.inner);
+        let accounts = Arc::downgrade(&self.accounts);
+
+        tokio::spawn(async move {
+            loop {
+                match interval {
+                    Some(interval) => {
+                        tokio::time::timeout(interval, trigger.notified()).await.ok();
+                    }
+                    None => trigger.notified().await,
+                }
+                let (Some(inner), Some(accounts)) = (inner.upgrade(), accounts.upgrade()) else {
+                    break;
+                };
+                Wallet { inner, accounts }.run_auto_backup().await;
+            }
+        });
+    }
+}
+
+// Removes the oldest backups exceeding `keep_last_n`, the timestamps have the same length, so they're sorted by their
+// names
+fn remove_old_backups(directory: &Path, keep_last_n: usize) -> std::io::Result<()> {
+    let mut backup_paths = std::fs::read_dir(directory)?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.file_name().and_then(|name| name.to_str()).map_or(false, |name| {
+                name.starts_with(AUTO_BACKUP_FILE_PREFIX) && name.ends_with(AUTO_BACKUP_FILE_EXTENSION)
+            })
+        })
+        .collect::<Vec<_>>();
+    backup_paths.sort();
+    let remove_count = backup_paths.len().saturating_sub(keep_last_n);
+    for path in backup_paths.into_iter().take(remove_count) {
+        std::fs::remove_file(path)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn auto_backup(transactions: Option<u32>) -> AutoBackup {
+        AutoBackup::new(AutoBackupOptions {
+            interval: None,
+            transactions,
+            directory: PathBuf::new(),
+            keep_last_n: 1,
+        })
+        .unwrap()
+    }
+
+    async fn is_triggered(auto_backup: &AutoBackup) -> bool {
+        tokio::time::timeout(Duration::from_millis(10), auto_backup.trigger.notified())
+            .await
+            .is_ok()
+    }
+
+    #[tokio::test]
+    async fn transaction_trigger() {
+        let auto_backup = auto_backup(Some(2));
+        auto_backup.transaction_sent();
+        assert!(!is_triggered(&auto_backup).await);
+        auto_backup.transaction_sent();
+        assert!(is_triggered(&auto_backup).await);
+
+        // A transaction sent while the backup is written is kept for the next backup
+        let sent_transactions = auto_backup.sent_transactions.load(Ordering::Relaxed);
+        auto_backup.transaction_sent();
+        auto_backup
+            .sent_transactions
+            .fetch_sub(sent_transactions, Ordering::Relaxed);
+        assert_eq!(auto_backup.sent_transactions.load(Ordering::Relaxed), 1);
+        assert!(is_triggered(&auto_backup).await);
+
+        let auto_backup = self::auto_backup(None);
+        auto_backup.transaction_sent();
+        auto_backup.transaction_sent();
+        assert!(!is_triggered(&auto_backup).await);
+    }
+
+    #[test]
+    fn unique_timestamps() {
+        let auto_backup = auto_backup(None);
+        let timestamps = (0..100).map(|_| auto_backup.next_timestamp()).collect::<Vec<_>>();
+        assert!(timestamps.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn backup_removal() {
+        let directory = std::env::temp_dir().join(format!("auto_backup_removal_{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+        for timestamp in [1000, 1001, 1002] {
+            std::fs::write(
+                directory.join(format!(
+                    "{AUTO_BACKUP_FILE_PREFIX}{timestamp}{AUTO_BACKUP_FILE_EXTENSION}"
+                )),
+                [],
+            )
+            .unwrap();
+        }
+        std::fs::write(directory.join("other.stronghold"), []).unwrap();
+
+        remove_old_backups(&directory, 2).unwrap();
+        let mut names = std::fs::read_dir(&directory)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect::<Vec<_>>();
+        names.sort();
+        assert_eq!(
+            names,
+            [
+                "auto-backup-1001.stronghold",
+                "auto-backup-1002.stronghold",
+                "other.stronghold"
+            ]
+        );
+
+        std::fs::remove_dir_all(directory).unwrap();
+    }
+}
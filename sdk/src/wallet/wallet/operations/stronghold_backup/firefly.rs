@@ -0,0 +1,199 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{fs, path::PathBuf, sync::atomic::Ordering};
+
+use serde_json::Value;
+use zeroize::Zeroize;
+
+use super::stronghold_snapshot::{ACCOUNTS_KEY, COIN_TYPE_KEY};
+#[cfg(feature = "storage")]
+use crate::wallet::WalletBuilder;
+use crate::{
+    client::{
+        secret::{stronghold::StrongholdSecretManager, GenerateAddressOptions, SecretManage, SecretManager},
+        storage::StorageProvider,
+        stronghold::{Error as StrongholdError, StrongholdAdapter},
+    },
+    types::block::address::{Bech32Address, Hrp},
+    wallet::{Account, Error, Wallet},
+};
+
+/// The data of an account in a Firefly backup that is needed to recreate it.
+#[derive(Debug)]
+struct FireflyAccount {
+    index: u32,
+    alias: String,
+    coin_type: Option<u32>,
+    bech32_hrp: Option<Hrp>,
+    public_addresses: u32,
+    internal_addresses: u32,
+}
+
+impl FireflyAccount {
+    // Only reads the fields that are needed, so accounts stored by older wallet versions can still be imported
+    fn from_json(account: &Value) -> crate::wallet::Result<Self> {
+        let index = account["index"]
+            .as_u64()
+            .and_then(|index| u32::try_from(index).ok())
+            .ok_or(Error::Backup("invalid account index in Firefly backup"))?;
+        let alias = account["alias"]
+            .as_str()
+            .map_or_else(|| index.to_string(), String::from);
+        let addresses = |key: &str| account[key].as_array().map_or(0, |addresses| addresses.len() as u32);
+
+        Ok(Self {
+            index,
+            alias,
+            coin_type: account["coinType"]
+                .as_u64()
+                .and_then(|coin_type| u32::try_from(coin_type).ok()),
+            bech32_hrp: account["publicAddresses"][0]["address"]
+                .as_str()
+                .and_then(|address| Bech32Address::try_from_str(address).ok())
+                .map(|address| *address.hrp()),
+            public_addresses: addresses("publicAddresses"),
+            internal_addresses: addresses("internalAddresses"),
+        })
+    }
+}
+
+impl Wallet {
+    /// Imports a Stronghold backup created by Firefly. The seed of the backup is imported into the current Stronghold
+    /// secret manager, the coin type is taken over and the accounts are recreated with their aliases and the same
+    /// amount of addresses, so they don't need to be recovered with a full rescan. The accounts only contain
+    /// addresses after the import, their outputs and transactions are added by syncing them.
+    /// Backups of the Chrysalis Firefly need to be migrated with
+    /// [`StrongholdAdapter::migrate_snapshot_v2_to_v3()`] first. Returns an error if accounts were already created, if
+    /// the current Stronghold already contains a mnemonic, or if its password isn't the one of the backup, so neither
+    /// a seed nor the password of the wallet are replaced.
+    pub async fn import_firefly_backup(
+        &self,
+        backup_path: PathBuf,
+        mut stronghold_password: String,
+    ) -> crate::wallet::Result<Vec<Account>> {
+        log::debug!("[import_firefly_backup] loading Firefly backup");
+
+        if !backup_path.is_file() {
+            return Err(Error::Backup("backup path doesn't exist"));
+        }
+        if !self.accounts.read().await.is_empty() {
+            return Err(Error::Backup("can't import backup when there are already accounts"));
+        }
+
+        let mut secret_manager = self.secret_manager.write().await;
+        let SecretManager::Stronghold(stronghold) = &*secret_manager else {
+            return Err(Error::Backup(
+                "importing a Firefly backup requires a Stronghold secret manager",
+            ));
+        };
+        let snapshot_path = stronghold.snapshot_path.clone();
+
+        // Fails if the current Stronghold is encrypted with another password
+        stronghold.set_password(&stronghold_password).await?;
+        match stronghold.generate_addresses(0, 0, 0..1, None).await {
+            Err(StrongholdError::MnemonicMissing) => {}
+            Ok(_) => {
+                return Err(Error::Backup(
+                    "the Stronghold secret manager already contains a mnemonic",
+                ))
+            }
+            Err(e) => return Err(e.into()),
+        }
+
+        let backup_stronghold = StrongholdSecretManager::builder()
+            .password(&stronghold_password)
+            .build(backup_path.clone())?;
+        let (coin_type, mut firefly_accounts) = read_firefly_backup(&backup_stronghold).await?;
+        drop(backup_stronghold);
+
+        firefly_accounts.sort_by_key(|account| account.index);
+        if firefly_accounts
+            .iter()
+            .enumerate()
+            .any(|(position, account)| account.index != position as u32)
+        {
+            return Err(Error::Backup("accounts in Firefly backup aren't consecutive"));
+        }
+
+        // Copy the Stronghold file, so the seed is available in the current location, it's moved in place at once so a
+        // failed copy doesn't leave a corrupted snapshot
+        let import_path = snapshot_path.with_extension("import");
+        fs::copy(&backup_path, &import_path)?;
+        fs::rename(&import_path, &snapshot_path)?;
+        let mut imported_stronghold = StrongholdSecretManager::builder()
+            .password(&stronghold_password)
+            .build(snapshot_path)?;
+        stronghold_password.zeroize();
+        // Keep the key clearing timeout of the current Stronghold
+        imported_stronghold.set_timeout(stronghold.get_timeout()).await;
+        *secret_manager = SecretManager::Stronghold(imported_stronghold);
+        drop(secret_manager);
+
+        if let Some(coin_type) = coin_type.or_else(|| firefly_accounts.first().and_then(|account| account.coin_type)) {
+            self.coin_type.store(coin_type, Ordering::Relaxed);
+        }
+
+        #[cfg(feature = "storage")]
+        {
+            let wallet_builder = WalletBuilder::from_wallet(self).await;
+            self.storage_manager
+                .read()
+                .await
+                .save_wallet_data(&wallet_builder)
+                .await?;
+        }
+
+        let mut accounts = Vec::with_capacity(firefly_accounts.len());
+        for firefly_account in firefly_accounts {
+            log::debug!("[import_firefly_backup] importing account {}", firefly_account.alias);
+            let account = self
+                .create_account()
+                .with_alias(firefly_account.alias)
+                .with_bech32_hrp(firefly_account.bech32_hrp)
+                .with_initial_addresses(firefly_account.public_addresses)
+                .finish()
+                .await?;
+            account
+                .generate_addresses(
+                    firefly_account.internal_addresses,
+                    Some(GenerateAddressOptions::internal()),
+                )
+                .await?;
+            accounts.push(account);
+        }
+
+        Ok(accounts)
+    }
+}
+
+// Reads the coin type and the accounts from a Firefly backup
+async fn read_firefly_backup(
+    stronghold: &StrongholdAdapter,
+) -> crate::wallet::Result<(Option<u32>, Vec<FireflyAccount>)> {
+    let coin_type = match stronghold.get(COIN_TYPE_KEY.as_bytes()).await? {
+        Some(coin_type_bytes) => Some(u32::from_le_bytes(
+            coin_type_bytes
+                .try_into()
+                .map_err(|_| Error::Backup("invalid coin_type"))?,
+        )),
+        None => None,
+    };
+
+    let accounts = match stronghold.get(ACCOUNTS_KEY.as_bytes()).await? {
+        Some(accounts_bytes) => {
+            let accounts: Vec<String> = serde_json::from_slice(&accounts_bytes)?;
+            accounts
+                .iter()
+                .map(|account| FireflyAccount::from_json(&serde_json::from_str(account)?))
+                .collect::<crate::wallet::Result<Vec<_>>>()?
+        }
+        None => Vec::new(),
+    };
+    log::debug!(
+        "[import_firefly_backup] found coin type {coin_type:?} and {} accounts",
+        accounts.len()
+    );
+
+    Ok((coin_type, accounts))
+}
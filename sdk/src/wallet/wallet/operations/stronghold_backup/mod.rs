@@ -1,22 +1,127 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+pub(crate) mod auto_backup;
+pub(crate) mod firefly;
 pub(crate) mod stronghold_snapshot;
 
-use std::{fs, path::PathBuf, sync::atomic::Ordering};
+use std::{collections::HashSet, fs, path::PathBuf, sync::atomic::Ordering};
 
 use futures::{future::try_join_all, FutureExt};
+use serde::{Deserialize, Serialize};
 use zeroize::Zeroize;
 
 use self::stronghold_snapshot::{read_data_from_stronghold_snapshot, store_data_to_stronghold};
 #[cfg(feature = "storage")]
 use crate::wallet::WalletBuilder;
 use crate::{
-    client::secret::{stronghold::StrongholdSecretManager, SecretManager, SecretManagerDto},
-    types::block::address::Hrp,
-    wallet::{Account, Wallet},
+    client::{
+        secret::{stronghold::StrongholdSecretManager, SecretManage, SecretManager, SecretManagerDto},
+        stronghold::Error as StrongholdError,
+    },
+    types::block::address::{Bech32Address, Hrp},
+    wallet::{
+        account::{builder::get_first_public_address, types::AccountIdentifier, AccountDetails},
+        Account, ClientOptions, Error, Wallet,
+    },
 };
 
+/// A report about the content of a Stronghold backup, see [`Wallet::verify_backup()`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupReport {
+    /// The coin type of the backed up wallet.
+    pub coin_type: Option<u32>,
+    /// The client options of the backed up wallet.
+    pub client_options: Option<ClientOptions>,
+    /// The kind of the backed up secret manager.
+    pub secret_manager: Option<BackupSecretManagerKind>,
+    /// Whether the backup contains a seed, which is the case for backups of Stronghold secret managers.
+    pub has_seed: bool,
+    /// The backed up accounts.
+    pub accounts: Vec<BackupAccount>,
+    /// Inconsistencies found in the backup, it can be restored without issues if there are none.
+    pub issues: Vec<BackupIssue>,
+}
+
+/// The kind of secret manager in a Stronghold backup, see [`BackupReport`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum BackupSecretManagerKind {
+    /// A Stronghold secret manager.
+    Stronghold,
+    /// A Ledger Nano secret manager.
+    LedgerNano,
+    /// A mnemonic secret manager.
+    Mnemonic,
+    /// A hex seed secret manager.
+    HexSeed,
+    /// A placeholder secret manager.
+    Placeholder,
+}
+
+impl From<&SecretManagerDto> for BackupSecretManagerKind {
+    fn from(value: &SecretManagerDto) -> Self {
+        match value {
+            SecretManagerDto::Stronghold(_) => Self::Stronghold,
+            #[cfg(feature = "ledger_nano")]
+            SecretManagerDto::LedgerNano(_) => Self::LedgerNano,
+            SecretManagerDto::Mnemonic(_) => Self::Mnemonic,
+            SecretManagerDto::HexSeed(_) => Self::HexSeed,
+            SecretManagerDto::Placeholder => Self::Placeholder,
+        }
+    }
+}
+
+/// An inconsistency in a Stronghold backup, see [`BackupReport`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum BackupIssue {
+    /// The account isn't stored at the position of its index.
+    #[serde(rename_all = "camelCase")]
+    AccountIndexMismatch { account_index: u32, position: usize },
+    /// The account has another coin type than the wallet.
+    #[serde(rename_all = "camelCase")]
+    CoinTypeMismatch {
+        account_index: u32,
+        coin_type: u32,
+        wallet_coin_type: u32,
+    },
+    /// The account has no public address.
+    #[serde(rename_all = "camelCase")]
+    MissingPublicAddress { account_index: u32 },
+    /// The key indexes of the public or internal addresses of the account have a gap.
+    #[serde(rename_all = "camelCase")]
+    AddressGap { account_index: u32 },
+    /// The account has an address with another bech32 HRP than the addresses before it.
+    #[serde(rename_all = "camelCase")]
+    Bech32HrpMismatch {
+        account_index: u32,
+        bech32_hrp: Hrp,
+        expected_bech32_hrp: Hrp,
+    },
+    /// The first address derived from the seed isn't the first address of the first account.
+    SeedMismatch,
+}
+
+/// An account in a Stronghold backup, see [`BackupReport`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BackupAccount {
+    /// The account index.
+    pub index: u32,
+    /// The account alias.
+    pub alias: String,
+    /// The first public address of the account.
+    pub first_address: Option<Bech32Address>,
+    /// The amount of public addresses.
+    pub public_address_count: usize,
+    /// The amount of internal addresses.
+    pub internal_address_count: usize,
+    /// The amount of transactions sent by the account.
+    pub transaction_count: usize,
+}
+
 impl Wallet {
     /// Backup the wallet data in a Stronghold file
     /// stronghold_password must be the current one when Stronghold is used as SecretManager.
@@ -55,6 +160,69 @@ impl Wallet {
         Ok(())
     }
 
+    /// Opens a Stronghold backup to check that it can be restored, without changing the wallet or the backup. Returns
+    /// an error if the file doesn't exist, the password is wrong or the records can't be decoded, otherwise a report
+    /// of the content and the inconsistencies between the records.
+    pub async fn verify_backup(
+        &self,
+        backup_path: PathBuf,
+        mut stronghold_password: String,
+    ) -> crate::wallet::Result<BackupReport> {
+        log::debug!("[verify_backup] loading stronghold backup");
+
+        // Building the Stronghold would create a missing snapshot
+        if !backup_path.is_file() {
+            return Err(crate::wallet::Error::Backup("backup path doesn't exist"));
+        }
+
+        let backup_stronghold = StrongholdSecretManager::builder()
+            .password(&stronghold_password)
+            .build(backup_path)?;
+        stronghold_password.zeroize();
+
+        let (client_options, coin_type, secret_manager, accounts) =
+            read_data_from_stronghold_snapshot(&backup_stronghold).await?;
+        let accounts = accounts.unwrap_or_default();
+        let mut issues = account_issues(&accounts, coin_type);
+
+        // Compare the first address derived from the backed up seed with the stored one
+        let has_seed = match backup_stronghold
+            .generate_addresses(coin_type.unwrap_or_default(), 0, 0..1, None)
+            .await
+        {
+            Ok(addresses) => {
+                let first_account = accounts.first().filter(|account| !account.watch_only);
+                if let Some(first_address) = first_account.and_then(|account| account.public_addresses.first()) {
+                    if coin_type.is_some() && addresses[0] != first_address.address.inner {
+                        issues.push(BackupIssue::SeedMismatch);
+                    }
+                }
+                true
+            }
+            Err(StrongholdError::MnemonicMissing) => false,
+            Err(e) => return Err(e.into()),
+        };
+
+        Ok(BackupReport {
+            coin_type,
+            client_options,
+            secret_manager: secret_manager.as_ref().map(BackupSecretManagerKind::from),
+            has_seed,
+            accounts: accounts
+                .iter()
+                .map(|account| BackupAccount {
+                    index: *account.index(),
+                    alias: account.alias().clone(),
+                    first_address: account.public_addresses.first().map(|address| address.address),
+                    public_address_count: account.public_addresses.len(),
+                    internal_address_count: account.internal_addresses.len(),
+                    transaction_count: account.transactions().len(),
+                })
+                .collect(),
+            issues,
+        })
+    }
+
     /// Restore a backup from a Stronghold file
     /// Replaces client_options, coin_type, secret_manager and accounts. Returns an error if accounts were already
     /// created If Stronghold is used as secret_manager, the existing Stronghold file will be overwritten. If a
@@ -205,4 +373,291 @@ impl Wallet {
 
         Ok(())
     }
+
+    /// Restores a subset of the accounts of a Stronghold backup, identified by their index, alias or id in the backup.
+    /// Unlike [`Wallet::restore_backup()`], the accounts are added to the existing ones and the client options, coin
+    /// type and secret manager of the wallet are kept.
+    ///
+    /// No account is restored if one conflicts with the wallet: if its coin type differs, its index, alias or one of
+    /// its addresses is already used, its addresses don't belong to the seed of the wallet or have another bech32 hrp,
+    /// or if restoring it would leave a gap in the account indexes.
+    pub async fn restore_accounts_from_backup(
+        &self,
+        backup_path: PathBuf,
+        mut stronghold_password: String,
+        account_ids: Vec<AccountIdentifier>,
+    ) -> crate::wallet::Result<Vec<Account>> {
+        log::debug!("[restore_accounts_from_backup] loading stronghold backup");
+
+        if !backup_path.is_file() {
+            return Err(Error::Backup("backup path doesn't exist"));
+        }
+
+        let backup_stronghold = StrongholdSecretManager::builder()
+            .password(&stronghold_password)
+            .build(backup_path)?;
+        stronghold_password.zeroize();
+        let (_, read_coin_type, _, read_accounts) = read_data_from_stronghold_snapshot(&backup_stronghold).await?;
+        drop(backup_stronghold);
+        let read_accounts = read_accounts.unwrap_or_default();
+
+        let coin_type = self.coin_type.load(Ordering::Relaxed);
+        if let Some(read_coin_type) = read_coin_type {
+            if read_coin_type != coin_type {
+                return Err(Error::InvalidCoinType {
+                    new_coin_type: read_coin_type,
+                    existing_coin_type: coin_type,
+                });
+            }
+        }
+
+        let mut selected_accounts: Vec<AccountDetails> = Vec::new();
+        for account_id in &account_ids {
+            let Some(account) = read_accounts.iter().find(|account| match account_id {
+                AccountIdentifier::Index(index) => account.index() == index,
+                AccountIdentifier::Alias(alias) => account.alias() == alias,
+                AccountIdentifier::Id(id) => account.account_id() == id,
+            }) else {
+                return Err(Error::AccountNotFound(account_id.to_string()));
+            };
+            if !selected_accounts
+                .iter()
+                .any(|selected_account| selected_account.index() == account.index())
+            {
+                selected_accounts.push(account.clone());
+            }
+        }
+        selected_accounts.sort_by_key(|account| *account.index());
+
+        let mut accounts = self.accounts.write().await;
+        let mut used_aliases = HashSet::new();
+        let mut used_addresses = HashSet::new();
+        let mut bech32_hrp = None;
+        for account in accounts.iter() {
+            let account_details = account.details().await;
+            used_aliases.insert(account_details.alias().to_lowercase());
+            for address in account_details
+                .public_addresses
+                .iter()
+                .chain(account_details.internal_addresses.iter())
+            {
+                used_addresses.insert(address.address.inner);
+                bech32_hrp.get_or_insert(address.address.hrp);
+            }
+        }
+
+        // Check all accounts before any of them is restored
+        for (position, account) in selected_accounts.iter().enumerate() {
+            let index = *account.index();
+            // The index of a new account is the amount of accounts, so there can't be gaps
+            if index as usize != accounts.len() + position {
+                return Err(Error::BackupConflict(if (index as usize) < accounts.len() {
+                    format!("account index {index} is already used")
+                } else {
+                    format!("restoring account {index} would leave a gap in the account indexes")
+                }));
+            }
+            if *account.coin_type() != coin_type {
+                return Err(Error::InvalidCoinType {
+                    new_coin_type: *account.coin_type(),
+                    existing_coin_type: coin_type,
+                });
+            }
+            if !used_aliases.insert(account.alias().to_lowercase()) {
+                return Err(Error::AccountAliasAlreadyExists(account.alias().clone()));
+            }
+            for address in account.public_addresses.iter().chain(account.internal_addresses.iter()) {
+                if !used_addresses.insert(address.address.inner) {
+                    return Err(Error::BackupConflict(format!(
+                        "address {} of account {index} is already used",
+                        address.address
+                    )));
+                }
+                match bech32_hrp {
+                    None => bech32_hrp = Some(address.address.hrp),
+                    Some(hrp) if hrp != address.address.hrp => {
+                        return Err(Error::BackupConflict(format!(
+                            "account {index} has an address with bech32 hrp {}, but the wallet {hrp}",
+                            address.address.hrp
+                        )));
+                    }
+                    _ => {}
+                }
+            }
+            // Watch-only accounts aren't derived from the seed
+            if !account.watch_only {
+                let first_address = account
+                    .public_addresses
+                    .first()
+                    .ok_or_else(|| Error::BackupConflict(format!("account {index} has no public address")))?;
+                if get_first_public_address(&self.secret_manager, coin_type, index).await?
+                    != first_address.address.inner
+                {
+                    return Err(Error::BackupConflict(format!(
+                        "account {index} doesn't belong to the seed of the wallet"
+                    )));
+                }
+            }
+        }
+
+        let restored_accounts = try_join_all(
+            selected_accounts
+                .into_iter()
+                .map(|account_details| Account::new(account_details, self.inner.clone()).boxed()),
+        )
+        .await?;
+
+        // Every saved account is also added to the wallet, so a failed save can't leave an account in the storage that
+        // a later account would reuse the index of
+        for account in restored_accounts.iter() {
+            #[cfg(feature = "storage")]
+            account.save(None).await?;
+            accounts.push(account.clone());
+        }
+
+        Ok(restored_accounts)
+    }
+}
+
+// Checks that the backed up accounts are consistent with each other and with the coin type of the wallet
+fn account_issues(accounts: &[AccountDetails], coin_type: Option<u32>) -> Vec<BackupIssue> {
+    let mut issues = Vec::new();
+    let mut expected_bech32_hrp = None;
+
+    for (position, account) in accounts.iter().enumerate() {
+        let account_index = *account.index();
+        if account_index as usize != position {
+            issues.push(BackupIssue::AccountIndexMismatch {
+                account_index,
+                position,
+            });
+        }
+        if let Some(wallet_coin_type) = coin_type {
+            if *account.coin_type() != wallet_coin_type {
+                issues.push(BackupIssue::CoinTypeMismatch {
+                    account_index,
+                    coin_type: *account.coin_type(),
+                    wallet_coin_type,
+                });
+            }
+        }
+        if account.public_addresses.is_empty() {
+            issues.push(BackupIssue::MissingPublicAddress { account_index });
+        }
+        for addresses in [&account.public_addresses, &account.internal_addresses] {
+            if addresses
+                .iter()
+                .enumerate()
+                .any(|(index, address)| address.key_index != index as u32)
+            {
+                issues.push(BackupIssue::AddressGap { account_index });
+            }
+            for address in addresses.iter() {
+                match expected_bech32_hrp {
+                    None => expected_bech32_hrp = Some(address.address.hrp),
+                    Some(expected_bech32_hrp) if expected_bech32_hrp != address.address.hrp => {
+                        issues.push(BackupIssue::Bech32HrpMismatch {
+                            account_index,
+                            bech32_hrp: address.address.hrp,
+                            expected_bech32_hrp,
+                        });
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn consistent_accounts() {
+        let mut second_account = AccountDetails::mock();
+        second_account.index = 1;
+        second_account.alias = "Bob".to_string();
+
+        assert!(account_issues(&[AccountDetails::mock(), second_account], Some(4218)).is_empty());
+        assert!(account_issues(&[], Some(4218)).is_empty());
+    }
+
+    #[test]
+    fn inconsistent_accounts() {
+        let mut account = AccountDetails::mock();
+        account.index = 1;
+        assert_eq!(
+            account_issues(&[account], None),
+            [BackupIssue::AccountIndexMismatch {
+                account_index: 1,
+                position: 0
+            }]
+        );
+
+        assert_eq!(
+            account_issues(&[AccountDetails::mock()], Some(4219)),
+            [BackupIssue::CoinTypeMismatch {
+                account_index: 0,
+                coin_type: 4218,
+                wallet_coin_type: 4219
+            }]
+        );
+
+        let mut account = AccountDetails::mock();
+        account.public_addresses.clear();
+        assert_eq!(
+            account_issues(&[account], Some(4218)),
+            [BackupIssue::MissingPublicAddress { account_index: 0 }]
+        );
+
+        let mut account = AccountDetails::mock();
+        account.public_addresses[0].key_index = 1;
+        assert_eq!(
+            account_issues(&[account], Some(4218)),
+            [BackupIssue::AddressGap { account_index: 0 }]
+        );
+
+        let mut account = AccountDetails::mock();
+        let mut internal_address = account.public_addresses[0].clone();
+        internal_address.internal = true;
+        internal_address.address = Bech32Address::new(Hrp::from_str_unchecked("smr"), internal_address.address.inner);
+        account.internal_addresses.push(internal_address);
+        assert_eq!(
+            account_issues(&[account], Some(4218)),
+            [BackupIssue::Bech32HrpMismatch {
+                account_index: 0,
+                bech32_hrp: Hrp::from_str_unchecked("smr"),
+                expected_bech32_hrp: Hrp::from_str_unchecked("rms"),
+            }]
+        );
+    }
+
+    #[test]
+    fn issue_serialization() {
+        assert_eq!(
+            serde_json::to_value(BackupIssue::CoinTypeMismatch {
+                account_index: 0,
+                coin_type: 4218,
+                wallet_coin_type: 4219
+            })
+            .unwrap(),
+            serde_json::json!({
+                "type": "coinTypeMismatch",
+                "accountIndex": 0,
+                "coinType": 4218,
+                "walletCoinType": 4219
+            })
+        );
+        assert_eq!(
+            serde_json::to_value(BackupIssue::SeedMismatch).unwrap(),
+            serde_json::json!({ "type": "seedMismatch" })
+        );
+        assert_eq!(
+            serde_json::to_value(BackupSecretManagerKind::LedgerNano).unwrap(),
+            serde_json::json!("ledgerNano")
+        );
+    }
 }
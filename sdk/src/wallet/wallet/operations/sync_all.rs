@@ -0,0 +1,95 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::sync::Arc;
+
+use futures::StreamExt;
+use instant::Instant;
+
+#[cfg(feature = "events")]
+use crate::wallet::events::types::{EventOrigin, SyncSummaryEvent, WalletEvent};
+use crate::wallet::{
+    account::{
+        constants::DEFAULT_SYNC_ALL_CONCURRENCY,
+        operations::syncing::{cache::SyncCache, SyncOptions},
+        types::AccountBalance,
+    },
+    Wallet,
+};
+
+/// The result of syncing a single account with [`Wallet::sync_all()`].
+#[derive(Debug)]
+pub struct AccountSyncResult {
+    /// The index of the account.
+    pub account_index: u32,
+    /// The balance of the account after syncing, or the error that made syncing fail.
+    pub result: crate::wallet::Result<AccountBalance>,
+}
+
+impl Wallet {
+    /// Syncs all accounts, with at most `concurrency` accounts being synced at the same time. An error of one account
+    /// doesn't stop the others from being synced, the results are returned per account, ordered by account index.
+    /// The output ids of the addresses of all accounts are queried from the indexer together, before the accounts are
+    /// synced. Node data that is needed by multiple accounts, like outputs, foundries, incoming transactions and the
+    /// confirmed milestone index, is only requested once.
+    /// Emits a [`SyncSummary`](crate::wallet::events::types::WalletEvent::SyncSummary) event when done and checks the
+    /// local clock with [`Wallet::check_clock_skew()`].
+    pub async fn sync_all(&self, options: Option<SyncOptions>, concurrency: usize) -> Vec<AccountSyncResult> {
+        log::debug!("[sync_all]");
+        let start_time = Instant::now();
+        // Clone the accounts so the lock isn't held while syncing
+        let accounts = self.accounts.read().await.clone();
+        let sync_cache = Arc::new(SyncCache::default());
+        sync_cache.prefetch_output_ids(&accounts, options.as_ref()).await;
+
+        let mut results = futures::stream::iter(accounts)
+            .map(|account| {
+                let account = account.with_sync_cache(sync_cache.clone());
+                let options = options.clone();
+                async move {
+                    let account_index = *account.details().await.index();
+                    let result = account.sync(options).await;
+                    if let Err(e) = &result {
+                        log::debug!("[sync_all] account {account_index} error: {e}");
+                    }
+                    AccountSyncResult { account_index, result }
+                }
+            })
+            .buffer_unordered(concurrency.max(1))
+            .collect::<Vec<_>>()
+            .await;
+        results.sort_by_key(|result| result.account_index);
+        log::debug!("[sync_all] finished in {:?}", start_time.elapsed());
+
+        if let Err(e) = self.check_clock_skew().await {
+            log::debug!("[sync_all] clock skew check error: {e}");
+        }
+
+        #[cfg(feature = "events")]
+        {
+            let (synced, failed): (Vec<_>, Vec<_>) = results.iter().partition(|result| result.result.is_ok());
+            self.emit(
+                None,
+                WalletEvent::SyncSummary(SyncSummaryEvent {
+                    synced_accounts: synced.iter().map(|result| result.account_index).collect(),
+                    failed_accounts: failed.iter().map(|result| result.account_index).collect(),
+                    duration: start_time.elapsed().as_millis() as u64,
+                }),
+                EventOrigin::new("sync_all"),
+            )
+            .await;
+        }
+
+        results
+    }
+
+    /// Syncs all accounts like [`Wallet::sync_all()`], but fails if syncing any of the accounts failed. Returns the
+    /// balances ordered by account index.
+    pub async fn sync_all_accounts(&self, options: Option<SyncOptions>) -> crate::wallet::Result<Vec<AccountBalance>> {
+        self.sync_all(options, DEFAULT_SYNC_ALL_CONCURRENCY)
+            .await
+            .into_iter()
+            .map(|result| result.result)
+            .collect()
+    }
+}
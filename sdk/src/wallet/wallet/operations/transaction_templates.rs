@@ -0,0 +1,308 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{collections::HashMap, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::api::PreparedTransactionData,
+    types::block::address::Bech32Address,
+    wallet::{
+        account::{
+            operations::transaction::prepare_output::{Features, OutputParams, StorageDeposit, Unlocks},
+            types::Transaction,
+            Account, TransactionOptions, TransactionOptionsDto,
+        },
+        wallet::WalletInner,
+        Error,
+    },
+};
+
+/// A named skeleton of a transaction, sent with [`Account::send_from_template()`]. The address and amount of the
+/// outputs can contain `{name}` placeholders, which are replaced by the parameter with that name when the template is
+/// used.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionTemplate {
+    /// The outputs of the transaction.
+    pub outputs: Vec<TemplateOutput>,
+    /// Fixed options for the transaction.
+    #[serde(default)]
+    pub options: Option<TransactionOptionsDto>,
+    /// Tags to group and find templates.
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+/// An output of a [`TransactionTemplate`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateOutput {
+    /// Bech32 encoded address, can contain `{name}` placeholders.
+    pub address: String,
+    /// Amount, can contain `{name}` placeholders.
+    pub amount: String,
+    #[serde(default)]
+    pub features: Option<Features>,
+    #[serde(default)]
+    pub unlocks: Option<Unlocks>,
+    #[serde(default)]
+    pub storage_deposit: Option<StorageDeposit>,
+}
+
+impl TransactionTemplate {
+    /// Returns the names of the placeholders that need a parameter.
+    pub fn placeholders(&self) -> Vec<&str> {
+        let mut placeholders = Vec::new();
+        for output in &self.outputs {
+            for value in [&output.address, &output.amount] {
+                for name in placeholders_in(value) {
+                    if !placeholders.contains(&name) {
+                        placeholders.push(name);
+                    }
+                }
+            }
+        }
+        placeholders
+    }
+
+    // Replaces the placeholders with the parameters
+    fn output_params(&self, params: &HashMap<String, String>) -> crate::wallet::Result<Vec<OutputParams>> {
+        self.outputs
+            .iter()
+            .map(|output| {
+                let amount = resolve(&output.amount, params)?;
+                Ok(OutputParams {
+                    recipient_address: Bech32Address::try_from_str(resolve(&output.address, params)?)?,
+                    amount: u64::from_str(&amount).map_err(|_| crate::client::Error::InvalidAmount(amount.clone()))?,
+                    assets: None,
+                    features: output.features.clone(),
+                    unlocks: output.unlocks.clone(),
+                    storage_deposit: output.storage_deposit.clone(),
+                })
+            })
+            .collect()
+    }
+}
+
+// Splits the value into the text before the next `{name}` placeholder, the name and the rest after it
+fn next_placeholder(value: &str) -> Option<(&str, &str, &str)> {
+    let (before, rest) = value.split_once('{')?;
+    let (name, after) = rest.split_once('}')?;
+    Some((before, name, after))
+}
+
+// Returns the names of the `{name}` placeholders in the value
+fn placeholders_in(mut value: &str) -> Vec<&str> {
+    let mut names = Vec::new();
+    while let Some((_, name, after)) = next_placeholder(value) {
+        names.push(name);
+        value = after;
+    }
+    names
+}
+
+// Replaces the `{name}` placeholders in the value with the parameters
+fn resolve(mut value: &str, params: &HashMap<String, String>) -> crate::wallet::Result<String> {
+    let mut resolved = String::with_capacity(value.len());
+    while let Some((before, name, after)) = next_placeholder(value) {
+        let param = params
+            .get(name)
+            .ok_or_else(|| Error::MissingTemplateParameter(name.to_string()))?;
+        resolved.push_str(before);
+        resolved.push_str(param);
+        value = after;
+    }
+    resolved.push_str(value);
+    Ok(resolved)
+}
+
+impl WalletInner {
+    /// Returns the transaction templates by their name.
+    pub async fn transaction_templates(&self) -> HashMap<String, TransactionTemplate> {
+        self.transaction_templates.read().await.clone()
+    }
+
+    /// Adds a transaction template, replacing an existing one with the same name.
+    /// If storage is enabled, will persist during restarts.
+    pub async fn set_transaction_template(
+        &self,
+        name: impl Into<String> + Send,
+        template: TransactionTemplate,
+    ) -> crate::wallet::Result<()> {
+        let mut transaction_templates = self.transaction_templates.write().await;
+        transaction_templates.insert(name.into(), template);
+
+        #[cfg(feature = "storage")]
+        self.storage_manager
+            .read()
+            .await
+            .set_transaction_templates(&transaction_templates)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Removes a transaction template and returns it.
+    /// If storage is enabled, will persist during restarts.
+    pub async fn remove_transaction_template(&self, name: &str) -> crate::wallet::Result<TransactionTemplate> {
+        let mut transaction_templates = self.transaction_templates.write().await;
+        let template = transaction_templates
+            .remove(name)
+            .ok_or_else(|| Error::TransactionTemplateNotFound(name.to_string()))?;
+
+        #[cfg(feature = "storage")]
+        self.storage_manager
+            .read()
+            .await
+            .set_transaction_templates(&transaction_templates)
+            .await?;
+
+        Ok(template)
+    }
+}
+
+impl Account {
+    /// Sends the outputs of a [`TransactionTemplate`] with its options, the placeholders are replaced by the
+    /// parameters with the same name.
+    /// ```ignore
+    /// let params = HashMap::from([("address".to_string(), address.to_string()), ("amount".to_string(), "1000000".to_string())]);
+    /// let transaction = account.send_from_template("payout", &params).await?;
+    /// ```
+    pub async fn send_from_template(
+        &self,
+        name: &str,
+        params: &HashMap<String, String>,
+    ) -> crate::wallet::Result<Transaction> {
+        let prepared_transaction = self.prepare_send_from_template(name, params).await?;
+        self.sign_and_submit_transaction(prepared_transaction).await
+    }
+
+    /// Function to prepare the transaction for
+    /// [Account.send_from_template()](crate::account::Account.send_from_template)
+    pub async fn prepare_send_from_template(
+        &self,
+        name: &str,
+        params: &HashMap<String, String>,
+    ) -> crate::wallet::Result<PreparedTransactionData> {
+        log::debug!("[TRANSACTION] prepare_send_from_template {name}");
+        let template = self
+            .wallet
+            .transaction_templates
+            .read()
+            .await
+            .get(name)
+            .cloned()
+            .ok_or_else(|| Error::TransactionTemplateNotFound(name.to_string()))?;
+
+        let options = template
+            .options
+            .as_ref()
+            .map(TransactionOptions::try_from_dto)
+            .transpose()?;
+
+        let mut outputs = Vec::new();
+        for output_params in template.output_params(params)? {
+            outputs.push(self.prepare_output(output_params, options.clone()).await?);
+        }
+
+        self.prepare_transaction(outputs, options).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn template_placeholders() {
+        let address = "rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy";
+        let template = TransactionTemplate {
+            outputs: vec![
+                TemplateOutput {
+                    address: "{recipient}".to_string(),
+                    amount: "{amount}".to_string(),
+                    features: None,
+                    unlocks: None,
+                    storage_deposit: None,
+                },
+                TemplateOutput {
+                    address: address.to_string(),
+                    amount: "{amount}".to_string(),
+                    features: None,
+                    unlocks: None,
+                    storage_deposit: None,
+                },
+            ],
+            options: None,
+            tags: Vec::new(),
+        };
+        assert_eq!(template.placeholders(), vec!["recipient", "amount"]);
+
+        let mut params = HashMap::from([("recipient".to_string(), address.to_string())]);
+        assert!(matches!(
+            template.output_params(&params),
+            Err(Error::MissingTemplateParameter(name)) if name == "amount"
+        ));
+
+        params.insert("amount".to_string(), "1000000".to_string());
+        let output_params = template.output_params(&params).unwrap();
+        assert_eq!(output_params.len(), 2);
+        assert_eq!(output_params[0].recipient_address.to_string(), address);
+        assert_eq!(output_params[1].amount, 1_000_000);
+    }
+
+    #[test]
+    fn template_embedded_placeholders() {
+        let template = TransactionTemplate {
+            outputs: vec![TemplateOutput {
+                address: "{hrp}1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy".to_string(),
+                amount: "{millions}{thousands}000".to_string(),
+                features: None,
+                unlocks: None,
+                storage_deposit: None,
+            }],
+            options: None,
+            tags: Vec::new(),
+        };
+        assert_eq!(template.placeholders(), vec!["hrp", "millions", "thousands"]);
+
+        let params = HashMap::from([
+            ("hrp".to_string(), "rms".to_string()),
+            ("millions".to_string(), "2".to_string()),
+            ("thousands".to_string(), "500".to_string()),
+        ]);
+        let output_params = template.output_params(&params).unwrap();
+        assert_eq!(
+            output_params[0].recipient_address.to_string(),
+            "rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy"
+        );
+        assert_eq!(output_params[0].amount, 2_500_000);
+
+        // Text without a closing brace is kept as it is
+        assert_eq!(resolve("{millions", &params).unwrap(), "{millions");
+        assert_eq!(resolve("a{millions}b{", &params).unwrap(), "a2b{");
+    }
+
+    #[test]
+    fn template_invalid_parameters() {
+        let template = serde_json::from_value::<TransactionTemplate>(serde_json::json!({
+            "outputs": [{
+                "address": "rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy",
+                "amount": "{amount}",
+            }],
+            "options": { "note": "payout", "allowMicroAmount": true },
+        }))
+        .unwrap();
+        let options = TransactionOptions::try_from_dto(template.options.as_ref().unwrap()).unwrap();
+        assert_eq!(options.note.as_deref(), Some("payout"));
+        assert!(options.allow_micro_amount);
+
+        let params = HashMap::from([("amount".to_string(), "1 SMR".to_string())]);
+        let Err(Error::Client(error)) = template.output_params(&params) else {
+            panic!("the amount must be invalid");
+        };
+        assert!(matches!(*error, crate::client::Error::InvalidAmount(amount) if amount == "1 SMR"));
+    }
+}
@@ -7,10 +7,10 @@ use iota_sdk::{
     client::{
         api::GetAddressesBuilder,
         constants::{IOTA_BECH32_HRP, IOTA_COIN_TYPE, IOTA_TESTNET_BECH32_HRP, SHIMMER_BECH32_HRP, SHIMMER_COIN_TYPE},
-        secret::{GenerateAddressOptions, SecretManager},
+        secret::{GenerateAddressOptions, SecretManage, SecretManager},
         Client,
     },
-    types::block::address::{Address, Hrp},
+    types::block::address::{Address, Bech32Address, Hrp},
 };
 use serde::{Deserialize, Serialize};
 
@@ -53,6 +53,22 @@ async fn public_key_to_address() {
     );
 }
 
+#[test]
+fn chrysalis_address_to_bech32() {
+    let chrysalis_address = "atoi1qzt0nhsf38nh6rs4p6zs5knqp6psgha9wsv74uajqgjmwc75ugupx3y7x0r";
+
+    let address = iota_sdk::client::chrysalis_address_to_bech32(chrysalis_address, "rms").unwrap();
+
+    assert_eq!(address.hrp(), "rms");
+    assert_eq!(
+        address.inner(),
+        Bech32Address::try_from_str(chrysalis_address).unwrap().inner()
+    );
+
+    let alias_address = "rms1pr5m4q9dz4s7gdakvwslrmal4025fvxhmfamx0s2vt5ekg8wg597um6lcnn";
+    assert!(iota_sdk::client::chrysalis_address_to_bech32(alias_address, "rms").is_err());
+}
+
 #[tokio::test]
 async fn mnemonic_address_generation_iota() {
     let mnemonic = "acoustic trophy damage hint search taste love bicycle foster cradle brown govern endless depend situate athlete pudding blame question genius transfer van random vast";
@@ -93,6 +109,28 @@ async fn mnemonic_address_generation_iota() {
     );
 }
 
+#[tokio::test]
+async fn mnemonic_batch_address_generation() {
+    let mnemonic = "acoustic trophy damage hint search taste love bicycle foster cradle brown govern endless depend situate athlete pudding blame question genius transfer van random vast";
+    let secret_manager = SecretManager::try_from_mnemonic(mnemonic).unwrap();
+
+    // Large enough to be derived in parallel
+    let addresses = secret_manager
+        .generate_addresses(IOTA_COIN_TYPE, 0, 0..200, None)
+        .await
+        .unwrap();
+    assert_eq!(addresses.len(), 200);
+
+    // The order must be the same as for single derivations
+    for address_index in [0, 63, 64, 150, 199] {
+        let address = secret_manager
+            .generate_addresses(IOTA_COIN_TYPE, 0, address_index..address_index + 1, None)
+            .await
+            .unwrap();
+        assert_eq!(addresses[address_index as usize], address[0]);
+    }
+}
+
 #[tokio::test]
 async fn mnemonic_address_generation_shimmer() {
     let mnemonic = "acoustic trophy damage hint search taste love bicycle foster cradle brown govern endless depend situate athlete pudding blame question genius transfer van random vast";
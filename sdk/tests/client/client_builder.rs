@@ -1,7 +1,26 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use iota_sdk::client::{Client, ClientBuilder};
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use iota_sdk::{
+    client::{
+        api::{PowProvider, SharedPowProvider, TipCacheOptions},
+        node_manager::{
+            health::{NodeHealth, NodeSelectionPolicy},
+            node::{NodeCapabilities, RequiredCapability},
+        },
+        Client, ClientBuilder, Result, TimeSource,
+    },
+    pow::miner::MinerCancel,
+    types::block::{output::RentStructure, protocol::ProtocolParameters, rand::parents::rand_parents},
+};
 
 #[tokio::test]
 async fn invalid_url() {
@@ -15,6 +34,45 @@ async fn valid_url() {
     assert!(client.is_ok());
 }
 
+#[test]
+fn node_capabilities_from_routes() {
+    let routes = ["core/v2", "indexer/v1", "mqtt/v1"].map(String::from);
+    assert_eq!(
+        NodeCapabilities::from_routes(&routes),
+        NodeCapabilities {
+            indexer: true,
+            mqtt: true,
+            participation: false,
+            ..Default::default()
+        }
+    );
+    assert_eq!(NodeCapabilities::from_routes(&[]), NodeCapabilities::default());
+}
+
+#[test]
+fn required_node_capabilities() {
+    assert_eq!(
+        RequiredCapability::for_request("api/indexer/v1/outputs/basic", false),
+        [RequiredCapability::Indexer]
+    );
+    assert_eq!(
+        RequiredCapability::for_request("api/core/v2/milestones/by-index/42/utxo-changes", true),
+        [RequiredCapability::Pow, RequiredCapability::MilestoneIndex(42)]
+    );
+    assert!(RequiredCapability::for_request("api/core/v2/info", false).is_empty());
+
+    let capabilities = NodeCapabilities {
+        participation: true,
+        pruning_index: 41,
+        ..Default::default()
+    };
+    assert!(capabilities.supports(RequiredCapability::Participation));
+    assert!(capabilities.supports(RequiredCapability::MilestoneIndex(42)));
+    assert!(!capabilities.supports(RequiredCapability::MilestoneIndex(41)));
+    assert!(!capabilities.supports(RequiredCapability::Indexer));
+    assert!(!capabilities.supports(RequiredCapability::Pow));
+}
+
 #[tokio::test]
 async fn client_builder() {
     let client_builder_json = r#"{
@@ -126,3 +184,159 @@ async fn client_builder() {
 
     let _client_builder = serde_json::from_str::<ClientBuilder>(client_builder_json).unwrap();
 }
+
+struct FixedNoncePowProvider;
+
+#[async_trait::async_trait]
+impl PowProvider for FixedNoncePowProvider {
+    async fn nonce(&self, _bytes: &[u8], _min_pow_score: u32, _cancel: MinerCancel) -> Result<Option<u64>> {
+        Ok(Some(42))
+    }
+}
+
+#[tokio::test]
+async fn custom_pow_provider() {
+    let client = Client::builder()
+        .with_pow_provider(SharedPowProvider::new(FixedNoncePowProvider))
+        .finish()
+        .await
+        .unwrap();
+
+    let block = client.finish_block_builder(Some(rand_parents()), None).await.unwrap();
+
+    assert_eq!(block.nonce(), 42);
+}
+
+// Takes longer than the tips interval and counts how often it was started
+struct SlowPowProvider(AtomicUsize);
+
+#[async_trait::async_trait]
+impl PowProvider for SlowPowProvider {
+    async fn nonce(&self, _bytes: &[u8], _min_pow_score: u32, _cancel: MinerCancel) -> Result<Option<u64>> {
+        self.0.fetch_add(1, Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_millis(1500)).await;
+        Ok(Some(42))
+    }
+}
+
+#[tokio::test]
+async fn pow_provider_keeps_provided_parents() {
+    let pow_provider = Arc::new(SlowPowProvider(AtomicUsize::new(0)));
+    let client = Client::builder()
+        .with_pow_provider(SharedPowProvider(pow_provider.clone()))
+        .with_tips_interval(1)
+        .finish()
+        .await
+        .unwrap();
+
+    let block = client.finish_block_builder(Some(rand_parents()), None).await.unwrap();
+
+    // The provider isn't restarted after the tips interval, because the parents don't change
+    assert_eq!(block.nonce(), 42);
+    assert_eq!(pow_provider.0.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn tip_cache() {
+    let client = Client::builder()
+        .with_tip_cache(TipCacheOptions::default())
+        .finish()
+        .await
+        .unwrap();
+
+    let parents = rand_parents();
+    client.add_cached_tips(parents.iter().copied());
+
+    // The cached tips are used without requesting them from a node
+    assert_eq!(client.get_parents().await.unwrap(), parents);
+}
+
+#[test]
+fn node_health_score() {
+    let fast = NodeHealth {
+        requests: 1,
+        ..Default::default()
+    };
+    let slow = NodeHealth {
+        latency_ms: 500.0,
+        ..fast
+    };
+    let lagging = NodeHealth {
+        milestone_lag: 1,
+        ..fast
+    };
+    let failing = NodeHealth {
+        success_rate: 0.2,
+        ..slow
+    };
+
+    assert_eq!(fast.score(), 1.0);
+    assert_eq!(slow.score(), 0.5);
+    assert_eq!(lagging.score(), 0.5);
+    assert!(failing.score() < slow.score());
+    // Unmeasured nodes don't outrank fast nodes
+    assert_eq!(NodeHealth::default().score(), 0.5);
+}
+
+#[test]
+fn node_selection_policy() {
+    let client_builder = Client::builder().with_node_selection_policy(NodeSelectionPolicy::RoundRobin);
+    let mut json = serde_json::to_value(&client_builder).unwrap();
+    assert_eq!(json["nodeSelectionPolicy"], "roundRobin");
+
+    // The policy defaults to primary with fallback
+    json.as_object_mut().unwrap().remove("nodeSelectionPolicy");
+    let client_builder = serde_json::from_value::<ClientBuilder>(json).unwrap();
+    assert_eq!(
+        client_builder.node_manager_builder.node_selection_policy,
+        NodeSelectionPolicy::PrimaryWithFallback
+    );
+}
+
+#[test]
+fn read_quorum() {
+    let client_builder = Client::builder()
+        .with_read_quorum(true)
+        .with_min_quorum_size(3)
+        .with_quorum_threshold(120);
+
+    assert!(client_builder.node_manager_builder.read_quorum);
+    // Read quorum doesn't enable quorum for the other requests
+    assert!(!client_builder.node_manager_builder.quorum);
+    assert_eq!(client_builder.node_manager_builder.min_quorum_size, 3);
+    // The threshold is a percentage
+    assert_eq!(client_builder.node_manager_builder.quorum_threshold, 100);
+}
+
+#[tokio::test]
+async fn offline_mode() {
+    let protocol_parameters = ProtocolParameters::new(
+        2,
+        String::from("offline"),
+        "rms",
+        1500,
+        15,
+        RentStructure::default(),
+        1_000_000,
+    )
+    .unwrap();
+    let rent_structure = RentStructure::new(200, 10, 1);
+
+    // The rent structure isn't overwritten by the protocol parameters of the offline mode
+    let client = Client::builder()
+        .with_rent_structure(rent_structure)
+        .with_offline_mode(protocol_parameters)
+        .with_time_source(TimeSource::Fixed {
+            timestamp: 1_700_000_000,
+        })
+        .finish()
+        .await
+        .unwrap();
+
+    // Everything is available without a node
+    assert!(client.is_offline());
+    assert_eq!(client.get_token_supply().await.unwrap(), 1_000_000);
+    assert_eq!(client.get_rent_structure().await.unwrap(), rent_structure);
+    assert_eq!(client.get_bech32_hrp().await.unwrap(), "rms");
+    assert_eq!(client.get_time_checked().await.unwrap(), 1_700_000_000);
+}
@@ -0,0 +1,74 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::{
+    client::api::input_selection::{InputSelection, InputSelectionStrategy},
+    types::block::protocol::protocol_parameters,
+};
+
+use crate::client::{addresses, build_inputs, build_outputs, Build::Basic, BECH32_ADDRESS_ED25519_0};
+
+fn selected_amounts(strategy: InputSelectionStrategy) -> Vec<u64> {
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs(vec![
+        Basic(1_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+        Basic(3_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+        Basic(5_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+    ]);
+    let outputs = build_outputs(vec![Basic(
+        2_000_000,
+        BECH32_ADDRESS_ED25519_0,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let selected = InputSelection::new(
+        inputs,
+        outputs,
+        addresses(vec![BECH32_ADDRESS_ED25519_0]),
+        protocol_parameters,
+    )
+    .strategy(strategy)
+    .select()
+    .unwrap();
+
+    let mut amounts = selected
+        .inputs
+        .iter()
+        .map(|input| input.output.amount())
+        .collect::<Vec<_>>();
+    amounts.sort_unstable();
+    amounts
+}
+
+#[test]
+fn smallest_first() {
+    assert_eq!(
+        selected_amounts(InputSelectionStrategy::SmallestFirst),
+        [1_000_000, 3_000_000]
+    );
+}
+
+#[test]
+fn largest_first() {
+    assert_eq!(selected_amounts(InputSelectionStrategy::LargestFirst), [5_000_000]);
+}
+
+#[test]
+fn minimize_dust_change() {
+    assert_eq!(
+        selected_amounts(InputSelectionStrategy::MinimizeDustChange),
+        [3_000_000]
+    );
+}
+
+#[test]
+fn random() {
+    let amounts = selected_amounts(InputSelectionStrategy::Random);
+    assert!(amounts.iter().sum::<u64>() >= 2_000_000);
+}
@@ -13,6 +13,7 @@ mod mqtt;
 mod node_api;
 mod secret_manager;
 mod signing;
+mod storage_deposit;
 mod transactions;
 
 use std::{
@@ -5,8 +5,8 @@
 
 use iota_sdk::{
     client::{
-        bech32_to_hex, node_api::indexer::query_parameters::QueryParameter, request_funds_from_faucet,
-        secret::SecretManager, Client,
+        api::MilestoneTarget, bech32_to_hex, node_api::indexer::query_parameters::QueryParameter,
+        request_funds_from_faucet, secret::SecretManager, Client,
     },
     types::block::{
         output::OutputId,
@@ -269,6 +269,24 @@ async fn test_get_milestone_by_index() {
     println!("{r:#?}");
 }
 
+#[ignore]
+#[tokio::test]
+async fn test_wait_for_milestone() {
+    let client = setup_client_with_node_health_ignored().await;
+
+    let node_info = client.get_info().await.unwrap();
+    let next_index = node_info.node_info.status.confirmed_milestone.index + 1;
+
+    let confirmed_milestone = client
+        .wait_for_milestone(MilestoneTarget::Index(next_index), Some(1), Some(60))
+        .await
+        .unwrap();
+    assert!(confirmed_milestone.index >= next_index);
+
+    let timelock = client.timelock_in_milestones(10).await.unwrap();
+    assert!(timelock.timestamp() > confirmed_milestone.timestamp.unwrap());
+}
+
 #[ignore]
 #[tokio::test]
 async fn test_get_utxo_changes_by_id() {
@@ -4,10 +4,13 @@
 use crypto::keys::slip10::Chain;
 use iota_sdk::{
     client::{
-        api::{transaction::validate_transaction_payload_length, verify_semantic, PreparedTransactionData},
+        api::{
+            transaction::validate_transaction_payload_length, verify_semantic, PreparedTransactionData,
+            SignedTransactionData,
+        },
         constants::{HD_WALLET_TYPE, SHIMMER_COIN_TYPE, SHIMMER_TESTNET_BECH32_HRP},
         secret::{SecretManage, SecretManager, SignTransactionEssence},
-        Client, Result,
+        Client, Error, Result,
     },
     types::block::{
         input::{Input, UtxoInput},
@@ -356,3 +359,93 @@ async fn two_signature_unlocks() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn offline_envelope_roundtrip() -> Result<()> {
+    let secret_manager = SecretManager::try_from_mnemonic(&Client::generate_mnemonic()?)?;
+
+    let bech32_address_0 = &secret_manager
+        .generate_addresses(SHIMMER_COIN_TYPE, 0, 0..1, None)
+        .await?[0]
+        .to_bech32(SHIMMER_TESTNET_BECH32_HRP);
+
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs(vec![Basic(
+        1_000_000,
+        &bech32_address_0.to_string(),
+        None,
+        None,
+        None,
+        None,
+        None,
+        Some(Chain::from_u32_hardened(vec![
+            HD_WALLET_TYPE,
+            SHIMMER_COIN_TYPE,
+            0,
+            0,
+            0,
+        ])),
+    )]);
+
+    let outputs = build_outputs(vec![Basic(
+        1_000_000,
+        &bech32_address_0.to_string(),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let essence = TransactionEssence::Regular(
+        RegularTransactionEssence::builder(
+            protocol_parameters.network_id(),
+            InputsCommitment::new(inputs.iter().map(|i| &i.output)),
+        )
+        .with_inputs(
+            inputs
+                .iter()
+                .map(|i| Input::Utxo(UtxoInput::from(*i.output_metadata.output_id())))
+                .collect(),
+        )
+        .with_outputs(outputs)
+        .finish(&protocol_parameters)?,
+    );
+
+    let prepared_transaction_data = PreparedTransactionData {
+        essence,
+        inputs_data: inputs,
+        remainder: None,
+    };
+
+    let envelope_json = prepared_transaction_data.to_envelope_json()?;
+    assert_eq!(
+        PreparedTransactionData::from_envelope_json(&envelope_json, &protocol_parameters)?,
+        prepared_transaction_data
+    );
+
+    // A prepared transaction can't be read as signed transaction
+    assert!(matches!(
+        SignedTransactionData::from_envelope_json(&envelope_json, &protocol_parameters),
+        Err(Error::InvalidOfflineEnvelope(_))
+    ));
+
+    // The formatting of the envelope doesn't matter
+    let mut envelope = serde_json::from_str::<serde_json::Value>(&envelope_json)?;
+    assert_eq!(
+        PreparedTransactionData::from_envelope_json(&envelope.to_string(), &protocol_parameters)?,
+        prepared_transaction_data
+    );
+
+    // Corrupted data is detected
+    envelope = serde_json::from_str::<serde_json::Value>(&envelope_json)?;
+    envelope["data"]["inputsData"][0]["output"]["amount"] = serde_json::Value::from("2000000");
+    assert!(matches!(
+        PreparedTransactionData::from_envelope_json(&envelope.to_string(), &protocol_parameters),
+        Err(Error::InvalidOfflineEnvelope(_))
+    ));
+
+    Ok(())
+}
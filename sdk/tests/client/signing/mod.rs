@@ -4,6 +4,7 @@
 mod alias;
 mod basic;
 mod nft;
+mod partial;
 
 use std::str::FromStr;
 
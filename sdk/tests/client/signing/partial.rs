@@ -0,0 +1,156 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crypto::keys::slip10::Chain;
+use iota_sdk::{
+    client::{
+        api::{PartiallySignedTransaction, PartiallySignedTransactionDto, PreparedTransactionData},
+        constants::{HD_WALLET_TYPE, SHIMMER_COIN_TYPE, SHIMMER_TESTNET_BECH32_HRP},
+        secret::{SecretManage, SecretManager},
+        Client, Error, Result,
+    },
+    types::block::{
+        input::{Input, UtxoInput},
+        output::InputsCommitment,
+        payload::transaction::{RegularTransactionEssence, TransactionEssence},
+        protocol::protocol_parameters,
+        unlock::{SignatureUnlock, Unlock},
+    },
+};
+
+use crate::client::{build_inputs, build_outputs, Build::Basic};
+
+#[tokio::test]
+async fn multi_party_signing() -> Result<()> {
+    let secret_manager_0 = SecretManager::try_from_mnemonic(&Client::generate_mnemonic()?)?;
+    let secret_manager_1 = SecretManager::try_from_mnemonic(&Client::generate_mnemonic()?)?;
+
+    let bech32_address_0 = &secret_manager_0
+        .generate_addresses(SHIMMER_COIN_TYPE, 0, 0..1, None)
+        .await?[0]
+        .to_bech32(SHIMMER_TESTNET_BECH32_HRP);
+    let bech32_address_1 = &secret_manager_1
+        .generate_addresses(SHIMMER_COIN_TYPE, 0, 0..1, None)
+        .await?[0]
+        .to_bech32(SHIMMER_TESTNET_BECH32_HRP);
+    let chain = Chain::from_u32_hardened(vec![HD_WALLET_TYPE, SHIMMER_COIN_TYPE, 0, 0, 0]);
+
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs(vec![
+        Basic(
+            1_000_000,
+            &bech32_address_0.to_string(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(chain.clone()),
+        ),
+        Basic(
+            1_000_000,
+            &bech32_address_0.to_string(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(chain.clone()),
+        ),
+        Basic(
+            1_000_000,
+            &bech32_address_1.to_string(),
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(chain),
+        ),
+    ]);
+
+    let outputs = build_outputs(vec![Basic(
+        3_000_000,
+        &bech32_address_0.to_string(),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let essence = TransactionEssence::Regular(
+        RegularTransactionEssence::builder(
+            protocol_parameters.network_id(),
+            InputsCommitment::new(inputs.iter().map(|i| &i.output)),
+        )
+        .with_inputs(
+            inputs
+                .iter()
+                .map(|i| Input::Utxo(UtxoInput::from(*i.output_metadata.output_id())))
+                .collect(),
+        )
+        .with_outputs(outputs)
+        .finish(&protocol_parameters)?,
+    );
+
+    let prepared_transaction_data = PreparedTransactionData {
+        essence,
+        inputs_data: inputs,
+        remainder: None,
+    };
+
+    let mut partially_signed_transaction = PartiallySignedTransaction::new(prepared_transaction_data, 100)?;
+    assert_eq!(partially_signed_transaction.missing_signatures()?.len(), 2);
+    assert!(matches!(
+        partially_signed_transaction.unlocks[1],
+        Some(Unlock::Reference(_))
+    ));
+
+    // The first party can only sign the inputs of its own address
+    assert_eq!(
+        secret_manager_0
+            .sign_partially_signed_transaction(&mut partially_signed_transaction)
+            .await?,
+        1
+    );
+    assert!(!partially_signed_transaction.is_complete());
+    assert!(matches!(
+        partially_signed_transaction.clone().finalize(),
+        Err(Error::MissingSignatures(1))
+    ));
+
+    // Pass the transaction to the second party
+    let dto = serde_json::to_string(&PartiallySignedTransactionDto::from(&partially_signed_transaction))?;
+    let mut partially_signed_transaction =
+        PartiallySignedTransaction::try_from_dto(&serde_json::from_str(&dto)?, &protocol_parameters)?;
+
+    assert_eq!(
+        secret_manager_1
+            .sign_partially_signed_transaction(&mut partially_signed_transaction)
+            .await?,
+        1
+    );
+    assert!(partially_signed_transaction.is_complete());
+
+    // The unlocks have to match the inputs
+    let mut invalid_dto = PartiallySignedTransactionDto::from(&partially_signed_transaction);
+    invalid_dto.unlocks.push(None);
+    assert!(PartiallySignedTransaction::try_from_dto(&invalid_dto, &protocol_parameters).is_err());
+    let mut invalid = partially_signed_transaction.clone();
+    invalid.unlocks.pop();
+    assert!(matches!(
+        invalid.finalize(),
+        Err(Error::UnlockCountMismatch { inputs: 3, unlocks: 2 })
+    ));
+
+    let signed_transaction_data = partially_signed_transaction.finalize()?;
+    let unlocks = signed_transaction_data.transaction_payload.unlocks();
+    assert_eq!(unlocks.len(), 3);
+    assert_eq!(unlocks.get(0).unwrap().kind(), SignatureUnlock::KIND);
+    assert_eq!(unlocks.get(2).unwrap().kind(), SignatureUnlock::KIND);
+
+    Ok(())
+}
@@ -0,0 +1,74 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::{
+    client::api::{PlannedOutputKind, StorageDepositParams},
+    types::block::{
+        output::{
+            feature::{IssuerFeature, MetadataFeature, TagFeature},
+            unlock_condition::{AddressUnlockCondition, ExpirationUnlockCondition},
+            BasicOutputBuilder, NativeToken, NftId, NftOutputBuilder, Rent, TokenId,
+        },
+        protocol::protocol_parameters,
+        rand::{address::rand_address, bytes::rand_bytes_array},
+    },
+};
+use primitive_types::U256;
+
+#[test]
+fn storage_deposit_params_match_built_outputs() {
+    let protocol_parameters = protocol_parameters();
+    let rent_structure = *protocol_parameters.rent_structure();
+    let token_supply = protocol_parameters.token_supply();
+
+    let basic_output = BasicOutputBuilder::new_with_amount(1)
+        .add_unlock_condition(AddressUnlockCondition::new(rand_address()))
+        .add_unlock_condition(ExpirationUnlockCondition::new(rand_address(), 1_700_000_000).unwrap())
+        .add_native_token(NativeToken::new(TokenId::new(rand_bytes_array()), U256::from(100)).unwrap())
+        .add_native_token(NativeToken::new(TokenId::new(rand_bytes_array()), U256::MAX).unwrap())
+        .add_feature(MetadataFeature::new(vec![42; 200]).unwrap())
+        .add_feature(TagFeature::new(b"storage deposit".to_vec()).unwrap())
+        .finish_output(token_supply)
+        .unwrap();
+    let params = StorageDepositParams {
+        native_tokens: 2,
+        metadata_length: Some(200),
+        tag_length: Some(15),
+        expiration: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        params.minimum_storage_deposit(rent_structure, token_supply).unwrap(),
+        basic_output.rent_cost(&rent_structure)
+    );
+
+    let nft_output = NftOutputBuilder::new_with_amount(1, NftId::null())
+        .add_unlock_condition(AddressUnlockCondition::new(rand_address()))
+        .add_immutable_feature(IssuerFeature::new(rand_address()))
+        .add_immutable_feature(MetadataFeature::new(vec![42; 500]).unwrap())
+        .finish_output(token_supply)
+        .unwrap();
+    let params = StorageDepositParams {
+        kind: PlannedOutputKind::Nft,
+        issuer: true,
+        immutable_metadata_length: Some(500),
+        ..Default::default()
+    };
+    assert_eq!(
+        params.minimum_storage_deposit(rent_structure, token_supply).unwrap(),
+        nft_output.rent_cost(&rent_structure)
+    );
+
+    // Basic outputs can't have immutable features and alias outputs can't have expirations
+    let params = StorageDepositParams {
+        issuer: true,
+        ..Default::default()
+    };
+    assert!(params.minimum_storage_deposit(rent_structure, token_supply).is_err());
+    let params = StorageDepositParams {
+        kind: PlannedOutputKind::Alias,
+        expiration: true,
+        ..Default::default()
+    };
+    assert!(params.minimum_storage_deposit(rent_structure, token_supply).is_err());
+}
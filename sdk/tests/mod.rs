@@ -6,5 +6,5 @@ mod client;
 #[cfg(feature = "pow")]
 mod pow;
 mod types;
-#[cfg(feature = "wallet")]
+#[cfg(feature = "wallet-core")]
 mod wallet;
@@ -4,12 +4,15 @@
 use iota_sdk::{
     pow::{miner::get_miner, score::PowScorer},
     types::block::{
+        helper::RawBytes,
+        output::Output,
         parent::Parents,
         payload::{Payload, TaggedDataPayload},
         protocol::protocol_parameters,
         rand::{
             block::rand_block_ids,
             number::rand_number,
+            output::rand_output,
             parents::rand_parents,
             payload::{rand_tagged_data_payload, rand_treasury_transaction_payload},
         },
@@ -106,6 +109,29 @@ fn pack_unpack_valid() {
     );
 }
 
+#[test]
+fn hex_round_trip() {
+    let protocol_parameters = protocol_parameters();
+    let block = BlockBuilder::new(rand_parents()).finish().unwrap();
+    let hex = block.to_hex();
+
+    assert_eq!(
+        Block::from_bytes(block.to_bytes(), &protocol_parameters).unwrap(),
+        block
+    );
+    assert_eq!(Block::from_hex(hex, &protocol_parameters).unwrap(), block);
+    assert!(Block::from_hex("0x00", &protocol_parameters).is_err());
+
+    let output = rand_output(protocol_parameters.token_supply());
+    let mut bytes = output.to_bytes();
+    assert_eq!(Output::from_bytes(&bytes, &protocol_parameters).unwrap(), output);
+    bytes.push(0);
+    assert!(matches!(
+        Output::from_bytes(bytes, &protocol_parameters),
+        Err(UnpackError::Packable(Error::RemainingBytes))
+    ));
+}
+
 #[test]
 fn getters() {
     let protocol_parameters = protocol_parameters();
@@ -0,0 +1,87 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::types::block::{
+    address::AliasAddress,
+    output::{
+        unlock_condition::ImmutableAliasAddressUnlockCondition, AliasOutput, FoundryOutput, FoundryTransitionBuilder,
+        SimpleTokenScheme, TokenScheme,
+    },
+    protocol::protocol_parameters,
+    rand::output::{rand_alias_output, rand_output_id},
+    Error,
+};
+use primitive_types::U256;
+
+fn foundry_output(alias_output: &AliasOutput, minted: u64, melted: u64, max: u64) -> FoundryOutput {
+    let token_scheme = SimpleTokenScheme::new(U256::from(minted), U256::from(melted), U256::from(max)).unwrap();
+
+    FoundryOutput::build_with_amount(1_000_000, 1, TokenScheme::Simple(token_scheme))
+        .add_unlock_condition(ImmutableAliasAddressUnlockCondition::new(AliasAddress::from(
+            *alias_output.alias_id(),
+        )))
+        .finish(protocol_parameters().token_supply())
+        .unwrap()
+}
+
+#[test]
+fn mint_and_melt() {
+    let token_supply = protocol_parameters().token_supply();
+    let alias_output = rand_alias_output(token_supply);
+    let foundry_output = foundry_output(&alias_output, 50, 10, 100);
+
+    let (next_alias_output, next_foundry_output) =
+        FoundryTransitionBuilder::new(alias_output.clone(), &rand_output_id(), foundry_output.clone())
+            .with_mint_amount(20u64)
+            .with_melt_amount(30u64)
+            .finish(token_supply)
+            .unwrap();
+
+    assert_eq!(next_alias_output.alias_id(), alias_output.alias_id());
+    assert_eq!(next_alias_output.state_index(), alias_output.state_index() + 1);
+    assert_eq!(next_foundry_output.id(), foundry_output.id());
+    assert_eq!(
+        next_foundry_output.token_scheme(),
+        &TokenScheme::Simple(SimpleTokenScheme::new(U256::from(70u8), U256::from(40u8), U256::from(100u8)).unwrap())
+    );
+}
+
+#[test]
+fn invalid_supply() {
+    let token_supply = protocol_parameters().token_supply();
+    let alias_output = rand_alias_output(token_supply);
+    let foundry_output = foundry_output(&alias_output, 50, 10, 100);
+
+    // Exceeds the maximum supply.
+    assert!(matches!(
+        FoundryTransitionBuilder::new(alias_output.clone(), &rand_output_id(), foundry_output.clone())
+            .with_mint_amount(61u64)
+            .finish(token_supply),
+        Err(Error::InvalidFoundryOutputSupply { .. })
+    ));
+    // Melts more than the circulating supply.
+    assert!(matches!(
+        FoundryTransitionBuilder::new(alias_output.clone(), &rand_output_id(), foundry_output.clone())
+            .with_melt_amount(41u64)
+            .finish(token_supply),
+        Err(Error::InvalidFoundryOutputSupply { .. })
+    ));
+    assert!(matches!(
+        FoundryTransitionBuilder::new(alias_output, &rand_output_id(), foundry_output)
+            .with_mint_amount(U256::MAX)
+            .finish(token_supply),
+        Err(Error::NativeTokensOverflow)
+    ));
+}
+
+#[test]
+fn alias_mismatch() {
+    let token_supply = protocol_parameters().token_supply();
+    let alias_output = rand_alias_output(token_supply);
+    let foundry_output = foundry_output(&rand_alias_output(token_supply), 50, 10, 100);
+
+    assert!(matches!(
+        FoundryTransitionBuilder::new(alias_output, &rand_output_id(), foundry_output).finish(token_supply),
+        Err(Error::FoundryAliasMismatch { .. })
+    ));
+}
@@ -1,6 +1,12 @@
 // Copyright 2020-2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+#[cfg(feature = "client")]
+use crypto::signatures::ed25519;
+#[cfg(feature = "client")]
+use iota_sdk::types::block::payload::milestone::{
+    MilestoneChainValidator, MilestoneId, MilestoneKeyManager, MilestoneKeyRange, MilestoneValidationError,
+};
 use iota_sdk::types::block::{
     parent::Parents,
     payload::milestone::{MilestoneEssence, MilestoneIndex, MilestoneOptions, MilestonePayload},
@@ -168,3 +174,92 @@ fn getters() {
         assert_eq!(s1, s2);
     }
 }
+
+// Generating keys needs the random feature of crypto, which is enabled by the client
+#[cfg(feature = "client")]
+fn signed_milestone(
+    index: u32,
+    timestamp: u32,
+    previous_milestone_id: MilestoneId,
+    secret_key: &ed25519::SecretKey,
+) -> MilestonePayload {
+    let essence = MilestoneEssence::new(
+        MilestoneIndex(index),
+        timestamp,
+        protocol_parameters().protocol_version(),
+        previous_milestone_id,
+        rand_parents(),
+        rand_merkle_root(),
+        rand_merkle_root(),
+        vec![],
+        MilestoneOptions::from_vec(vec![]).unwrap(),
+    )
+    .unwrap();
+    let signature = Ed25519Signature::new(
+        secret_key.public_key().to_bytes(),
+        secret_key.sign(&essence.hash()).to_bytes(),
+    );
+
+    MilestonePayload::new(essence, vec![Signature::from(signature)]).unwrap()
+}
+
+#[cfg(feature = "client")]
+#[test]
+fn validate_chain() {
+    let secret_key = ed25519::SecretKey::generate().unwrap();
+    let key_manager = MilestoneKeyManager::new(vec![MilestoneKeyRange::new(
+        prefix_hex::encode(secret_key.public_key().to_bytes()),
+        MilestoneIndex(1),
+        MilestoneIndex(0),
+    )]);
+
+    let first = signed_milestone(1, 10, rand_milestone_id(), &secret_key);
+    let second = signed_milestone(2, 20, first.id(), &secret_key);
+    let third = signed_milestone(3, 30, second.id(), &secret_key);
+
+    let mut validator = MilestoneChainValidator::new(key_manager.clone(), 1);
+    assert_eq!(
+        validator.validate_all([&first, &second, &third]).unwrap(),
+        Some((MilestoneIndex(3), third.id()))
+    );
+
+    // A gap in the chain
+    let mut validator = MilestoneChainValidator::with_trusted_milestone(key_manager.clone(), 1, &first);
+    assert!(matches!(
+        validator.validate_all([&third]),
+        Err((
+            0,
+            MilestoneValidationError::UnexpectedIndex(MilestoneIndex(2), MilestoneIndex(3))
+        ))
+    ));
+
+    // A milestone that doesn't reference the previous one
+    let forked = signed_milestone(2, 20, rand_milestone_id(), &secret_key);
+    assert!(matches!(
+        validator.validate_next(&forked),
+        Err(MilestoneValidationError::PreviousMilestoneIdMismatch(..))
+    ));
+
+    // A milestone signed with a key that isn't applicable
+    let unknown_key = ed25519::SecretKey::generate().unwrap();
+    let unsigned = signed_milestone(2, 20, first.id(), &unknown_key);
+    assert!(matches!(
+        validator.validate_next(&unsigned),
+        Err(MilestoneValidationError::UnapplicablePublicKey(..))
+    ));
+
+    // Invalid milestones don't change the chain
+    assert_eq!(validator.validate_next(&second).unwrap(), second.id());
+
+    // No milestone can follow the one with the maximum index
+    let last = signed_milestone(u32::MAX, 40, rand_milestone_id(), &secret_key);
+    let mut validator = MilestoneChainValidator::with_trusted_milestone(key_manager, 1, &last);
+    assert!(matches!(
+        validator.validate_next(&signed_milestone(0, 50, last.id(), &secret_key)),
+        Err(MilestoneValidationError::IndexOverflow(MilestoneIndex(u32::MAX)))
+    ));
+    assert_eq!(
+        validator.latest_milestone(),
+        Some((MilestoneIndex(u32::MAX), last.id()))
+    );
+}
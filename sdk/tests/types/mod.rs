@@ -8,6 +8,7 @@ mod block;
 mod block_id;
 mod ed25519_signature;
 mod foundry_id;
+mod foundry_transition;
 mod input;
 mod migrated_funds_entry;
 mod milestone_id;
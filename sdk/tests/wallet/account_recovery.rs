@@ -7,7 +7,7 @@ use std::time::Duration;
 
 use iota_sdk::{
     client::{
-        constants::SHIMMER_COIN_TYPE,
+        constants::{IOTA_COIN_TYPE, SHIMMER_COIN_TYPE},
         secret::{mnemonic::MnemonicSecretManager, SecretManager},
         Client,
     },
@@ -30,6 +30,22 @@ async fn account_recovery_empty() -> Result<()> {
     tear_down(storage_path)
 }
 
+#[ignore]
+#[tokio::test]
+async fn account_recovery_legacy_funds_empty() -> Result<()> {
+    let storage_path = "test-storage/account_recovery_legacy_funds_empty";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    wallet.create_account().finish().await?;
+    let legacy_funds = wallet.recover_legacy_funds(IOTA_COIN_TYPE, 0, 2, 2, None).await?;
+
+    // No legacy funds were found, so only the existing account is kept
+    assert!(legacy_funds.is_empty());
+    assert_eq!(1, wallet.get_accounts().await?.len());
+    tear_down(storage_path)
+}
+
 #[ignore]
 #[tokio::test]
 async fn account_recovery_existing_accounts() -> Result<()> {
@@ -1,7 +1,22 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use iota_sdk::wallet::Result;
+use std::{collections::BTreeMap, str::FromStr};
+
+use iota_sdk::{
+    client::secret::GenerateAddressOptions,
+    types::block::{
+        address::{Bech32Address, Hrp},
+        output::TokenId,
+    },
+    wallet::{
+        account::{
+            SpendingAllowance, SyncOptions, TokenPolicy, TransactionExportFormat, TransactionExportRange,
+            TransactionRateLimit,
+        },
+        Error, Result,
+    },
+};
 #[cfg(feature = "stronghold")]
 use {
     iota_sdk::client::{
@@ -167,6 +182,57 @@ async fn account_rename_alias() -> Result<()> {
     tear_down(storage_path)
 }
 
+#[tokio::test]
+async fn account_rename_keeps_id() -> Result<()> {
+    let storage_path = "test-storage/account_rename_keeps_id";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = wallet.create_account().with_alias("Alice".to_string()).finish().await?;
+    wallet.create_account().with_alias("Bob".to_string()).finish().await?;
+    let account_id = *account.details().await.account_id();
+
+    // Aliases are unique, ignoring the case
+    assert!(matches!(
+        wallet.rename_account("Alice", "bob").await,
+        Err(Error::AccountAliasAlreadyExists(_))
+    ));
+
+    wallet.rename_account(account_id, "Carol").await?;
+    wallet.rename_account("Carol", "Dave").await?;
+    let alias_history = account.details().await.alias_history().clone();
+    assert_eq!(
+        alias_history
+            .iter()
+            .map(|change| change.alias.as_str())
+            .collect::<Vec<_>>(),
+        vec!["Alice", "Carol"]
+    );
+
+    // The id and the index still find the renamed account, the old alias doesn't
+    assert_eq!(wallet.get_account(account_id).await?.alias().await, "Dave");
+    assert_eq!(wallet.get_account(account_id.to_string()).await?.alias().await, "Dave");
+    assert_eq!(wallet.get_account(0).await?.alias().await, "Dave");
+    assert!(matches!(
+        wallet.get_account("Alice").await,
+        Err(Error::AccountNotFound(_))
+    ));
+
+    // An alias that looks like an id is still found
+    let id_like_alias = "0123abcd-0123-4567-89ab-0123456789ab";
+    wallet
+        .create_account()
+        .with_alias(id_like_alias.to_uppercase())
+        .finish()
+        .await?;
+    assert_eq!(
+        wallet.get_account(id_like_alias).await?.alias().await,
+        id_like_alias.to_uppercase()
+    );
+
+    tear_down(storage_path)
+}
+
 #[tokio::test]
 async fn account_first_address_exists() -> Result<()> {
     let storage_path = "test-storage/account_first_address_exists";
@@ -183,6 +249,267 @@ async fn account_first_address_exists() -> Result<()> {
     tear_down(storage_path)
 }
 
+#[tokio::test]
+async fn account_address_label_and_metadata() -> Result<()> {
+    let storage_path = "test-storage/account_address_label_and_metadata";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = wallet.create_account().with_alias("Alice".to_string()).finish().await?;
+    let address = *account.addresses().await?[0].address();
+
+    assert!(account.find_address_by_label("user-1").await.is_none());
+
+    account.set_address_label(&address, Some("user-1".to_string())).await?;
+    account
+        .set_address_metadata(&address, BTreeMap::from([("tier".to_string(), "gold".to_string())]))
+        .await?;
+
+    let account_address = account.find_address_by_label("user-1").await.unwrap();
+    assert_eq!(account_address.address(), &address);
+    assert_eq!(account_address.metadata().get("tier").map(String::as_str), Some("gold"));
+
+    account.set_address_label(&address, None).await?;
+    assert!(account.find_address_by_label("user-1").await.is_none());
+    assert_eq!(account.addresses().await?[0].metadata().len(), 1);
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn account_spending_allowances() -> Result<()> {
+    let storage_path = "test-storage/account_spending_allowances";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = wallet.create_account().finish().await?;
+
+    account
+        .set_spending_allowance(SpendingAllowance::new("bot-1", 1_000_000, None))
+        .await?;
+    account
+        .set_spending_allowance(SpendingAllowance::new("bot-2", 2_000_000, Some(1)))
+        .await?;
+    assert_eq!(account.spending_allowances().await.len(), 2);
+
+    let removed = account.remove_spending_allowance("bot-2").await?;
+    assert_eq!(removed.map(|allowance| allowance.max_total), Some(2_000_000));
+    assert!(account.remove_spending_allowance("bot-2").await?.is_none());
+
+    let allowances = account.spending_allowances().await;
+    assert_eq!(allowances.len(), 1);
+    assert_eq!(allowances[0].remaining(), 1_000_000);
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn account_token_policy() -> Result<()> {
+    let storage_path = "test-storage/account_token_policy";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = wallet.create_account().finish().await?;
+    assert!(account.token_policy().await.is_none());
+
+    let token_id = TokenId::from_str("0x087d205988b733d97fb145ae340e27a8b19554d1ceee64574d7e5ff66c45f69e7a0100000000")?;
+    account.set_token_policy(Some(TokenPolicy::new([token_id]))).await?;
+    let token_policy = account.token_policy().await.unwrap();
+    assert!(token_policy.is_allowed(&token_id));
+    assert!(!token_policy.is_allowed(&TokenId::null()));
+
+    account.set_token_policy(None).await?;
+    assert!(account.token_policy().await.is_none());
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn account_rate_limit() -> Result<()> {
+    let storage_path = "test-storage/account_rate_limit";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = wallet.create_account().finish().await?;
+    assert!(account.rate_limit().await.is_none());
+
+    let rate_limit = TransactionRateLimit {
+        max_per_minute: Some(2),
+        max_per_hour: Some(10),
+        queue: false,
+    };
+    account.set_rate_limit(Some(rate_limit)).await?;
+    assert_eq!(account.rate_limit().await, Some(rate_limit));
+
+    drop(account);
+    drop(wallet);
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = wallet.get_account(0).await?;
+    assert_eq!(account.rate_limit().await, Some(rate_limit));
+
+    account.set_rate_limit(None).await?;
+    assert!(account.rate_limit().await.is_none());
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn account_sequence_numbers() -> Result<()> {
+    let storage_path = "test-storage/account_sequence_numbers";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = wallet.create_account().finish().await?;
+
+    assert!(!account.transaction_sequencing().await);
+    assert!(account.last_sequence_number().await.is_none());
+    assert!(account.get_transaction_by_sequence_number(0).await.is_none());
+    assert!(matches!(
+        account.wait_for_sequence_confirmed(0, None, None).await,
+        Err(Error::UnknownSequenceNumber(0))
+    ));
+
+    account.set_transaction_sequencing(true).await?;
+    assert!(account.transaction_sequencing().await);
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn account_network_params() -> Result<()> {
+    let storage_path = "test-storage/account_network_params";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = wallet.create_account().finish().await?;
+
+    let network_params = account.network_params().await?;
+    assert_eq!(network_params.bech32_hrp, wallet.client().get_bech32_hrp().await?);
+    assert_eq!(network_params.token_supply, wallet.client().get_token_supply().await?);
+    assert_eq!(
+        network_params.rent_structure,
+        wallet.client().get_rent_structure().await?
+    );
+
+    account.invalidate_network_params().await;
+    assert_eq!(account.refresh_network_params().await?, network_params);
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn account_export_transactions() -> Result<()> {
+    let storage_path = "test-storage/account_export_transactions";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = wallet.create_account().finish().await?;
+
+    let csv = account
+        .export_transactions(TransactionExportRange::default(), TransactionExportFormat::Csv)
+        .await?;
+    assert_eq!(
+        csv,
+        "transactionId,blockId,timestamp,inclusionState,incoming,note,asset,address,delta\n"
+    );
+    let json = account
+        .export_transactions(TransactionExportRange::default(), TransactionExportFormat::Json)
+        .await?;
+    assert_eq!(json, "[]");
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn account_builder_options() -> Result<()> {
+    let storage_path = "test-storage/account_builder_options";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let sync_options = SyncOptions {
+        sync_only_most_basic_outputs: true,
+        ..Default::default()
+    };
+    let account = wallet
+        .create_account()
+        .with_initial_addresses(3)
+        .with_default_sync_options(sync_options.clone())
+        .finish()
+        .await?;
+
+    assert_eq!(account.addresses().await?.len(), 3);
+    assert_eq!(account.default_sync_options().await, sync_options);
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn internal_address_pool_status() -> Result<()> {
+    let storage_path = "test-storage/internal_address_pool_status";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = wallet.create_account().finish().await?;
+
+    let status = account.internal_address_pool_status().await;
+    assert_eq!((status.total, status.unused, status.min_unused), (0, 0, 0));
+
+    account
+        .generate_addresses(2, Some(GenerateAddressOptions::internal()))
+        .await?;
+    account
+        .set_default_sync_options(SyncOptions {
+            min_unused_internal_addresses: 5,
+            ..Default::default()
+        })
+        .await?;
+
+    let status = account.internal_address_pool_status().await;
+    assert_eq!((status.total, status.unused, status.min_unused), (2, 2, 5));
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn address_rotation() -> Result<()> {
+    let storage_path = "test-storage/address_rotation";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = wallet.create_account().finish().await?;
+    let first_address = account.addresses().await?[0].clone();
+    assert_eq!(account.current_receive_address().await?, first_address);
+
+    account.set_address_rotation(true).await?;
+    assert!(*account.details().await.address_rotation());
+    // The first address is unused, so it doesn't get rotated
+    assert_eq!(account.current_receive_address().await?, first_address);
+    assert!(account.address_rotation_history().await.is_empty());
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn verify_address_on_device() -> Result<()> {
+    let storage_path = "test-storage/verify_address_on_device";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = wallet.create_account().finish().await?;
+    let address = *account.addresses().await?[0].address();
+    let coin_type = *account.details().await.coin_type();
+
+    let proof = account.verify_address_on_device(address).await?;
+    assert!(proof.verified);
+    assert!(!proof.shown_on_device);
+    assert_eq!(proof.bip32_path, format!("m/44'/{coin_type}'/0'/0'/0'"));
+    // Hex encoded 32 bytes
+    assert_eq!(proof.public_key.map(|public_key| public_key.len()), Some(66));
+
+    tear_down(storage_path)
+}
+
 #[cfg(feature = "stronghold")]
 #[tokio::test]
 async fn account_creation_stronghold() -> Result<()> {
@@ -215,3 +542,64 @@ async fn account_creation_stronghold() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[cfg(feature = "wallet-participation")]
+#[tokio::test]
+async fn set_voting_power_unchanged() -> Result<()> {
+    let storage_path = "test-storage/set_voting_power_unchanged";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account = wallet.create_account().finish().await?;
+    assert_eq!(account.get_voting_power().await?, 0);
+    // No transaction is needed if the voting power already equals the target amount
+    assert!(account.prepare_set_voting_power(0).await?.is_none());
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn watch_only_account() -> Result<()> {
+    let storage_path = "test-storage/watch_only_account";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let address = Bech32Address::new(
+        wallet.client().get_bech32_hrp().await?,
+        *Bech32Address::try_from_str("rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy")?.inner(),
+    );
+    let watch_only_account = wallet
+        .create_account()
+        .with_watch_only_addresses([address])
+        .finish()
+        .await?;
+    assert!(*watch_only_account.details().await.watch_only());
+    assert_eq!(*watch_only_account.addresses().await?[0].address(), address);
+    assert!(matches!(
+        watch_only_account.generate_addresses(1, None).await,
+        Err(Error::WatchOnlyAccount(..))
+    ));
+
+    // Watch-only accounts need addresses for the connected network
+    assert!(matches!(
+        wallet.create_account().with_watch_only_addresses([]).finish().await,
+        Err(Error::WatchOnlyAccount(..))
+    ));
+    let other_network_address = Bech32Address::new(Hrp::from_str_unchecked("other"), *address.inner());
+    assert!(matches!(
+        wallet
+            .create_account()
+            .with_watch_only_addresses([other_network_address])
+            .finish()
+            .await,
+        Err(Error::Client(error)) if matches!(*error, iota_sdk::client::Error::Bech32HrpMismatch { .. })
+    ));
+
+    // The watch-only account isn't used to check the seed of new accounts
+    let account = wallet.create_account().finish().await?;
+    assert!(!*account.details().await.watch_only());
+    assert_eq!(account.generate_addresses(1, None).await?.len(), 1);
+
+    tear_down(storage_path)
+}
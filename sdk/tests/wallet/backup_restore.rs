@@ -5,13 +5,15 @@
 use std::path::PathBuf;
 
 #[cfg(all(feature = "stronghold", feature = "storage"))]
-use iota_sdk::wallet::{ClientOptions, Result, Wallet};
+use iota_sdk::wallet::{AutoBackupOptions, BackupSecretManagerKind, ClientOptions, Error, Result, Wallet};
 #[cfg(all(feature = "stronghold", feature = "storage"))]
 use iota_sdk::{
     client::{
         constants::{IOTA_COIN_TYPE, SHIMMER_COIN_TYPE},
         node_manager::node::{Node, NodeDto},
         secret::{mnemonic::MnemonicSecretManager, stronghold::StrongholdSecretManager, SecretManager},
+        stronghold::Error as StrongholdError,
+        Client, Error as ClientError,
     },
     Url,
 };
@@ -523,3 +525,415 @@ async fn backup_and_restore_bech32_hrp_mismatch() -> Result<()> {
     );
     tear_down(storage_path)
 }
+
+#[tokio::test]
+#[cfg(all(feature = "stronghold", feature = "storage"))]
+// Verify a backup without restoring it
+async fn verify_backup() -> Result<()> {
+    let storage_path = "test-storage/verify_backup";
+    setup(storage_path)?;
+
+    let stronghold_password = "some_hopefully_secure_password";
+
+    // Create directory if not existing, because stronghold panics otherwise
+    std::fs::create_dir_all(storage_path).ok();
+    let stronghold = StrongholdSecretManager::builder()
+        .password(stronghold_password)
+        .build("test-storage/verify_backup/1.stronghold")?;
+
+    stronghold.store_mnemonic("inhale gorilla deny three celery song category owner lottery rent author wealth penalty crawl hobby obtain glad warm early rain clutch slab august bleak".to_string()).await.unwrap();
+
+    let wallet = Wallet::builder()
+        .with_secret_manager(SecretManager::Stronghold(stronghold))
+        .with_client_options(ClientOptions::new().with_node(NODE_LOCAL)?)
+        .with_coin_type(SHIMMER_COIN_TYPE)
+        .with_storage_path("test-storage/verify_backup/1")
+        .finish()
+        .await?;
+
+    let account = wallet.create_account().with_alias("Alice".to_string()).finish().await?;
+
+    let backup_path = PathBuf::from("test-storage/verify_backup/backup.stronghold");
+    wallet
+        .backup(backup_path.clone(), stronghold_password.to_string())
+        .await?;
+
+    // Wrong password fails
+    wallet
+        .verify_backup(backup_path.clone(), "wrong password".to_string())
+        .await
+        .unwrap_err();
+
+    let report = wallet
+        .verify_backup(backup_path, stronghold_password.to_string())
+        .await?;
+    assert!(report.issues.is_empty());
+    assert!(report.has_seed);
+    assert_eq!(report.secret_manager, Some(BackupSecretManagerKind::Stronghold));
+    assert_eq!(report.coin_type, Some(SHIMMER_COIN_TYPE));
+    assert_eq!(report.accounts.len(), 1);
+    assert_eq!(report.accounts[0].alias, "Alice");
+    assert_eq!(
+        report.accounts[0].first_address.as_ref(),
+        Some(account.addresses().await?[0].address())
+    );
+
+    // Nothing was restored
+    assert_eq!(wallet.get_accounts().await?.len(), 1);
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+#[cfg(all(feature = "stronghold", feature = "storage"))]
+// Restore only some accounts of a backup into a wallet with existing accounts
+async fn restore_accounts_from_backup() -> Result<()> {
+    let storage_path = "test-storage/restore_accounts_from_backup";
+    setup(storage_path)?;
+
+    let mnemonic = "inhale gorilla deny three celery song category owner lottery rent author wealth penalty crawl hobby obtain glad warm early rain clutch slab august bleak";
+    let stronghold_password = "some_hopefully_secure_password";
+
+    // Create directory if not existing, because stronghold panics otherwise
+    std::fs::create_dir_all(storage_path).ok();
+    let stronghold = StrongholdSecretManager::builder()
+        .password(stronghold_password)
+        .build("test-storage/restore_accounts_from_backup/1.stronghold")?;
+    stronghold.store_mnemonic(mnemonic.to_string()).await.unwrap();
+
+    let wallet = Wallet::builder()
+        .with_secret_manager(SecretManager::Stronghold(stronghold))
+        .with_client_options(ClientOptions::new().with_node(NODE_LOCAL)?)
+        .with_coin_type(SHIMMER_COIN_TYPE)
+        .with_storage_path("test-storage/restore_accounts_from_backup/1")
+        .finish()
+        .await?;
+    let mut backed_up_accounts = Vec::new();
+    for alias in ["Alice", "Bob", "Carol"] {
+        backed_up_accounts.push(wallet.create_account().with_alias(alias.to_string()).finish().await?);
+    }
+
+    let backup_path = PathBuf::from("test-storage/restore_accounts_from_backup/backup.stronghold");
+    wallet
+        .backup(backup_path.clone(), stronghold_password.to_string())
+        .await?;
+
+    // Restore into a wallet with the same seed, which already has the first account
+    let restore_wallet = Wallet::builder()
+        .with_secret_manager(SecretManager::Mnemonic(MnemonicSecretManager::try_from_mnemonic(
+            mnemonic,
+        )?))
+        .with_client_options(ClientOptions::new().with_node(NODE_LOCAL)?)
+        .with_coin_type(SHIMMER_COIN_TYPE)
+        .with_storage_path("test-storage/restore_accounts_from_backup/2")
+        .finish()
+        .await?;
+    restore_wallet
+        .create_account()
+        .with_alias("Alice".to_string())
+        .finish()
+        .await?;
+
+    // The index is already used
+    assert!(matches!(
+        restore_wallet
+            .restore_accounts_from_backup(backup_path.clone(), stronghold_password.to_string(), vec![0.into()])
+            .await,
+        Err(Error::BackupConflict(_))
+    ));
+    // Account 1 would be missing
+    assert!(matches!(
+        restore_wallet
+            .restore_accounts_from_backup(
+                backup_path.clone(),
+                stronghold_password.to_string(),
+                vec!["Carol".into()]
+            )
+            .await,
+        Err(Error::BackupConflict(_))
+    ));
+    // The account isn't in the backup
+    assert!(matches!(
+        restore_wallet
+            .restore_accounts_from_backup(backup_path.clone(), stronghold_password.to_string(), vec!["Dave".into()])
+            .await,
+        Err(Error::AccountNotFound(alias)) if alias == "Dave"
+    ));
+    assert_eq!(restore_wallet.get_accounts().await?.len(), 1);
+
+    let restored_accounts = restore_wallet
+        .restore_accounts_from_backup(
+            backup_path.clone(),
+            stronghold_password.to_string(),
+            vec!["Carol".into(), 1.into()],
+        )
+        .await?;
+    assert_eq!(restored_accounts.len(), 2);
+    assert_eq!(restore_wallet.get_accounts().await?.len(), 3);
+    for index in [1, 2] {
+        let restored_account = restore_wallet.get_account(index).await?;
+        let backed_up_account = &backed_up_accounts[index as usize];
+        assert_eq!(restored_account.alias().await, backed_up_account.alias().await);
+        assert_eq!(
+            restored_account.addresses().await?,
+            backed_up_account.addresses().await?
+        );
+    }
+
+    // The accounts don't belong to another seed
+    let other_wallet = Wallet::builder()
+        .with_secret_manager(SecretManager::Mnemonic(MnemonicSecretManager::try_from_mnemonic(
+            &Client::generate_mnemonic()?,
+        )?))
+        .with_client_options(ClientOptions::new().with_node(NODE_LOCAL)?)
+        .with_coin_type(SHIMMER_COIN_TYPE)
+        .with_storage_path("test-storage/restore_accounts_from_backup/3")
+        .finish()
+        .await?;
+    assert!(matches!(
+        other_wallet
+            .restore_accounts_from_backup(backup_path, stronghold_password.to_string(), vec![0.into()])
+            .await,
+        Err(Error::BackupConflict(_))
+    ));
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+#[cfg(all(feature = "stronghold", feature = "storage"))]
+// Automatic backups are rotated and require a Stronghold secret manager
+async fn auto_backup_rotation() -> Result<()> {
+    let storage_path = "test-storage/auto_backup_rotation";
+    setup(storage_path)?;
+
+    let stronghold_password = "some_hopefully_secure_password";
+    let backup_directory = PathBuf::from("test-storage/auto_backup_rotation/backups");
+    let auto_backup_options = AutoBackupOptions {
+        interval: None,
+        transactions: Some(5),
+        directory: backup_directory.clone(),
+        keep_last_n: 2,
+    };
+
+    // Create directory if not existing, because stronghold panics otherwise
+    std::fs::create_dir_all(storage_path).ok();
+    let stronghold = StrongholdSecretManager::builder()
+        .password(stronghold_password)
+        .build("test-storage/auto_backup_rotation/1.stronghold")?;
+
+    stronghold.store_mnemonic("inhale gorilla deny three celery song category owner lottery rent author wealth penalty crawl hobby obtain glad warm early rain clutch slab august bleak".to_string()).await.unwrap();
+
+    let wallet = Wallet::builder()
+        .with_secret_manager(SecretManager::Stronghold(stronghold))
+        .with_client_options(ClientOptions::new().with_node(NODE_LOCAL)?)
+        .with_coin_type(SHIMMER_COIN_TYPE)
+        .with_storage_path("test-storage/auto_backup_rotation/1")
+        .with_auto_backup(auto_backup_options.clone())
+        .finish()
+        .await?;
+    wallet.create_account().with_alias("Alice".to_string()).finish().await?;
+
+    let mut backup_paths = Vec::new();
+    // Backups written in the same millisecond get different names
+    for _ in 0..3 {
+        backup_paths.push(wallet.write_auto_backup().await?);
+    }
+    assert_eq!(std::fs::read_dir(&backup_directory)?.count(), 2);
+    assert!(!backup_paths[0].exists());
+    assert!(backup_paths[1].exists() && backup_paths[2].exists());
+
+    let report = wallet
+        .verify_backup(backup_paths[2].clone(), stronghold_password.to_string())
+        .await?;
+    assert_eq!(report.accounts.len(), 1);
+
+    // Other secret managers can't be used
+    let secret_manager = MnemonicSecretManager::try_from_mnemonic(&Client::generate_mnemonic()?)?;
+    assert!(matches!(
+        Wallet::builder()
+            .with_secret_manager(SecretManager::Mnemonic(secret_manager))
+            .with_client_options(ClientOptions::new().with_node(NODE_LOCAL)?)
+            .with_coin_type(SHIMMER_COIN_TYPE)
+            .with_storage_path("test-storage/auto_backup_rotation/2")
+            .with_auto_backup(auto_backup_options)
+            .finish()
+            .await,
+        Err(Error::Backup(_))
+    ));
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+#[cfg(all(feature = "stronghold", feature = "storage", feature = "events"))]
+// The backup task writes backups in the interval and emits the results as events
+async fn auto_backup_interval() -> Result<()> {
+    use iota_sdk::wallet::events::types::{WalletEvent, WalletEventType};
+
+    let storage_path = "test-storage/auto_backup_interval";
+    setup(storage_path)?;
+
+    let stronghold_password = "some_hopefully_secure_password";
+    let backup_directory = PathBuf::from("test-storage/auto_backup_interval/backups");
+
+    // Create directory if not existing, because stronghold panics otherwise
+    std::fs::create_dir_all(storage_path).ok();
+    let stronghold = StrongholdSecretManager::builder()
+        .password(stronghold_password)
+        .build("test-storage/auto_backup_interval/1.stronghold")?;
+
+    stronghold.store_mnemonic("inhale gorilla deny three celery song category owner lottery rent author wealth penalty crawl hobby obtain glad warm early rain clutch slab august bleak".to_string()).await.unwrap();
+
+    let wallet = Wallet::builder()
+        .with_secret_manager(SecretManager::Stronghold(stronghold))
+        .with_client_options(ClientOptions::new().with_node(NODE_LOCAL)?)
+        .with_coin_type(SHIMMER_COIN_TYPE)
+        .with_storage_path("test-storage/auto_backup_interval/1")
+        .with_auto_backup(AutoBackupOptions {
+            interval: Some(std::time::Duration::from_millis(100)),
+            transactions: None,
+            directory: backup_directory.clone(),
+            keep_last_n: 1,
+        })
+        .finish()
+        .await?;
+    wallet.create_account().with_alias("Alice".to_string()).finish().await?;
+
+    let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+    wallet
+        .listen(vec![WalletEventType::AutoBackup], move |event| {
+            if let WalletEvent::AutoBackup(auto_backup) = &event.event {
+                sender.send(auto_backup.clone()).ok();
+            }
+        })
+        .await;
+
+    for _ in 0..2 {
+        let event = tokio::time::timeout(std::time::Duration::from_secs(30), receiver.recv())
+            .await
+            .expect("no backup was written in the interval")
+            .unwrap();
+        assert_eq!(event.error, None);
+        assert!(event.path.unwrap().starts_with(&backup_directory));
+    }
+    // Older backups are removed
+    assert_eq!(std::fs::read_dir(&backup_directory)?.count(), 1);
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+#[cfg(all(feature = "stronghold", feature = "storage"))]
+// Import a Stronghold backup in the format Firefly uses
+async fn import_firefly_backup() -> Result<()> {
+    let storage_path = "test-storage/import_firefly_backup";
+    setup(storage_path)?;
+
+    let stronghold_password = "some_hopefully_secure_password";
+
+    // Create directory if not existing, because stronghold panics otherwise
+    std::fs::create_dir_all(storage_path).ok();
+    let stronghold = StrongholdSecretManager::builder()
+        .password(stronghold_password)
+        .build("test-storage/import_firefly_backup/1.stronghold")?;
+
+    stronghold.store_mnemonic("inhale gorilla deny three celery song category owner lottery rent author wealth penalty crawl hobby obtain glad warm early rain clutch slab august bleak".to_string()).await.unwrap();
+
+    let wallet = Wallet::builder()
+        .with_secret_manager(SecretManager::Stronghold(stronghold))
+        .with_client_options(ClientOptions::new().with_node(NODE_LOCAL)?)
+        .with_coin_type(SHIMMER_COIN_TYPE)
+        .with_storage_path("test-storage/import_firefly_backup/1")
+        .finish()
+        .await?;
+
+    let alice = wallet.create_account().with_alias("Alice".to_string()).finish().await?;
+    alice.generate_addresses(2, None).await?;
+    let bob = wallet.create_account().with_alias("Bob".to_string()).finish().await?;
+
+    wallet
+        .backup(
+            PathBuf::from("test-storage/import_firefly_backup/backup.stronghold"),
+            stronghold_password.to_string(),
+        )
+        .await?;
+
+    // The password of the wallet isn't replaced
+    let stronghold = StrongholdSecretManager::builder()
+        .password("another_password")
+        .build("test-storage/import_firefly_backup/3.stronghold")?;
+    let other_password_wallet = Wallet::builder()
+        .with_storage_path("test-storage/import_firefly_backup/3")
+        .with_secret_manager(SecretManager::Stronghold(stronghold))
+        .with_client_options(ClientOptions::new().with_node(NODE_OTHER)?)
+        .with_coin_type(SHIMMER_COIN_TYPE)
+        .finish()
+        .await?;
+    assert!(matches!(
+        other_password_wallet
+            .import_firefly_backup(
+                PathBuf::from("test-storage/import_firefly_backup/backup.stronghold"),
+                stronghold_password.to_string(),
+            )
+            .await,
+        Err(Error::Client(error)) if matches!(*error, ClientError::Stronghold(StrongholdError::InvalidPassword))
+    ));
+
+    // An existing mnemonic isn't replaced
+    let stronghold = StrongholdSecretManager::builder()
+        .password(stronghold_password)
+        .build("test-storage/import_firefly_backup/4.stronghold")?;
+    stronghold.store_mnemonic(Client::generate_mnemonic()?).await?;
+    let mnemonic_wallet = Wallet::builder()
+        .with_storage_path("test-storage/import_firefly_backup/4")
+        .with_secret_manager(SecretManager::Stronghold(stronghold))
+        .with_client_options(ClientOptions::new().with_node(NODE_OTHER)?)
+        .with_coin_type(SHIMMER_COIN_TYPE)
+        .finish()
+        .await?;
+    assert!(matches!(
+        mnemonic_wallet
+            .import_firefly_backup(
+                PathBuf::from("test-storage/import_firefly_backup/backup.stronghold"),
+                stronghold_password.to_string(),
+            )
+            .await,
+        Err(Error::Backup(_))
+    ));
+
+    let stronghold = StrongholdSecretManager::builder().build("test-storage/import_firefly_backup/2.stronghold")?;
+
+    let import_wallet = Wallet::builder()
+        .with_storage_path("test-storage/import_firefly_backup/2")
+        .with_secret_manager(SecretManager::Stronghold(stronghold))
+        .with_client_options(ClientOptions::new().with_node(NODE_OTHER)?)
+        // Build with a different coin type, to check if it gets replaced by the one from the backup
+        .with_coin_type(IOTA_COIN_TYPE)
+        .finish()
+        .await?;
+
+    let imported_accounts = import_wallet
+        .import_firefly_backup(
+            PathBuf::from("test-storage/import_firefly_backup/backup.stronghold"),
+            stronghold_password.to_string(),
+        )
+        .await?;
+    assert_eq!(imported_accounts.len(), 2);
+
+    let imported_alice = import_wallet.get_account("Alice").await?;
+    assert_eq!(imported_alice.details().await.coin_type(), &SHIMMER_COIN_TYPE);
+    assert_eq!(alice.addresses().await?, imported_alice.addresses().await?);
+    let imported_bob = import_wallet.get_account("Bob").await?;
+    assert_eq!(bob.addresses().await?, imported_bob.addresses().await?);
+
+    // Importing again fails, because the accounts exist already
+    import_wallet
+        .import_firefly_backup(
+            PathBuf::from("test-storage/import_firefly_backup/backup.stronghold"),
+            stronghold_password.to_string(),
+        )
+        .await
+        .unwrap_err();
+
+    tear_down(storage_path)
+}
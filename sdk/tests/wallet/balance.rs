@@ -8,13 +8,89 @@ use iota_sdk::{
         BasicOutputBuilder, UnlockCondition,
     },
     wallet::{
-        account::types::{AccountBalance, AccountBalanceDto},
+        account::types::{format_native_token_amount, parse_native_token_amount, AccountBalance, AccountBalanceDto},
         Result,
     },
 };
 
 use crate::wallet::common::{create_accounts_with_funds, make_wallet, setup, tear_down};
 
+#[test]
+fn native_token_amount_formatting() {
+    use iota_sdk::U256;
+
+    assert_eq!(format_native_token_amount(U256::from(1_234_500u32), 4), "123.45");
+    assert_eq!(format_native_token_amount(U256::from(5u8), 3), "0.005");
+    assert_eq!(format_native_token_amount(U256::from(1_000u32), 3), "1");
+    assert_eq!(format_native_token_amount(U256::from(42u8), 0), "42");
+}
+
+#[test]
+fn native_token_amount_parsing() {
+    use iota_sdk::U256;
+
+    assert_eq!(parse_native_token_amount("123.45", 4), Some(U256::from(1_234_500u32)));
+    assert_eq!(parse_native_token_amount("0.005", 3), Some(U256::from(5u8)));
+    assert_eq!(parse_native_token_amount("42", 0), Some(U256::from(42u8)));
+    // Too many fractional digits
+    assert_eq!(parse_native_token_amount("0.0005", 3), None);
+    assert_eq!(parse_native_token_amount("1.", 3), None);
+    assert_eq!(parse_native_token_amount(".5", 3), None);
+    assert_eq!(parse_native_token_amount("-1", 3), None);
+}
+
+#[test]
+fn token_registry() {
+    use iota_sdk::{
+        types::block::{
+            address::AliasAddress,
+            output::{
+                feature::MetadataFeature, unlock_condition::ImmutableAliasAddressUnlockCondition, AliasId,
+                FoundryOutputBuilder, SimpleTokenScheme, TokenScheme,
+            },
+            protocol::protocol_parameters,
+        },
+        wallet::account::TokenRegistry,
+        U256,
+    };
+
+    let token_supply = protocol_parameters().token_supply();
+    let foundry = |serial_number, metadata: &str| {
+        FoundryOutputBuilder::new_with_amount(
+            1_000_000,
+            serial_number,
+            TokenScheme::Simple(SimpleTokenScheme::new(U256::from(100u8), U256::from(0u8), U256::from(100u8)).unwrap()),
+        )
+        .add_unlock_condition(ImmutableAliasAddressUnlockCondition::new(AliasAddress::from(
+            AliasId::null(),
+        )))
+        .add_immutable_feature(MetadataFeature::new(metadata.as_bytes()).unwrap())
+        .finish(token_supply)
+        .unwrap()
+    };
+    let irc30_foundry = foundry(
+        1,
+        r#"{"standard":"IRC30","name":"NativeToken","description":"A native token","symbol":"NT","decimals":2}"#,
+    );
+    let other_foundry = foundry(2, r#"{"standard":"IRC27","symbol":"NFT","decimals":0}"#);
+
+    let token_registry = TokenRegistry::new([&irc30_foundry, &other_foundry]);
+    let metadata = token_registry.get(&irc30_foundry.token_id()).unwrap();
+    assert_eq!(metadata.name, "NativeToken");
+    assert_eq!(metadata.description.as_deref(), Some("A native token"));
+    assert_eq!(token_registry.symbol(&irc30_foundry.token_id()), Some("NT"));
+    assert_eq!(token_registry.decimals(&irc30_foundry.token_id()), Some(2));
+    assert!(token_registry.get(&other_foundry.token_id()).is_none());
+
+    let mut balance_dto = AccountBalanceDto::from(&AccountBalance::rand_mock());
+    balance_dto.native_tokens[0].token_id = irc30_foundry.token_id();
+    balance_dto.native_tokens[0].total = U256::from(1_234u32);
+    let balance_dto = balance_dto.with_native_token_metadata(&token_registry);
+    assert_eq!(balance_dto.native_tokens[0].symbol.as_deref(), Some("NT"));
+    assert_eq!(balance_dto.native_tokens[0].decimals, Some(2));
+    assert_eq!(balance_dto.native_tokens[0].formatted_total.as_deref(), Some("12.34"));
+}
+
 #[test]
 fn balance_to_dto() {
     let balance = AccountBalance::rand_mock();
@@ -22,7 +98,7 @@ fn balance_to_dto() {
 
     assert_eq!(balance.base_coin().total(), balance_dto.base_coin.total());
     assert_eq!(balance.base_coin().available(), balance_dto.base_coin.available());
-    #[cfg(feature = "participation")]
+    #[cfg(feature = "wallet-participation")]
     assert_eq!(balance.base_coin().voting_power(), balance_dto.base_coin.voting_power());
 
     assert_eq!(
@@ -41,6 +117,7 @@ fn balance_to_dto() {
         balance.required_storage_deposit().nft(),
         balance_dto.required_storage_deposit.nft()
     );
+    assert_eq!(balance.claimable().to_string(), balance_dto.claimable);
 
     assert_eq!(balance.native_tokens().len(), balance_dto.native_tokens.len());
     assert_eq!(balance.nfts().len(), balance_dto.nfts.len());
@@ -59,7 +136,7 @@ fn balance_add_assign() {
     let mut balance1 = AccountBalance::rand_mock();
     let total1 = balance1.base_coin().total();
     let available1 = balance1.base_coin().available();
-    #[cfg(feature = "participation")]
+    #[cfg(feature = "wallet-participation")]
     let voting_power1 = balance1.base_coin().voting_power();
 
     let sdr_alias1 = balance1.required_storage_deposit().alias();
@@ -67,6 +144,8 @@ fn balance_add_assign() {
     let sdr_foundry1 = balance1.required_storage_deposit().foundry();
     let sdr_nft1 = balance1.required_storage_deposit().nft();
 
+    let claimable1 = *balance1.claimable();
+
     let native_tokens1 = balance1.native_tokens().clone();
     let num_aliases1 = balance1.aliases().len();
     let num_foundries1 = balance1.foundries().len();
@@ -75,7 +154,7 @@ fn balance_add_assign() {
     let balance2 = AccountBalance::rand_mock();
     let total2 = balance2.base_coin().total();
     let available2 = balance2.base_coin().available();
-    #[cfg(feature = "participation")]
+    #[cfg(feature = "wallet-participation")]
     let voting_power2 = balance2.base_coin().voting_power();
 
     let sdr_alias2 = balance2.required_storage_deposit().alias();
@@ -83,6 +162,8 @@ fn balance_add_assign() {
     let sdr_foundry2 = balance2.required_storage_deposit().foundry();
     let sdr_nft2 = balance2.required_storage_deposit().nft();
 
+    let claimable2 = *balance2.claimable();
+
     let native_tokens2 = balance2.native_tokens().clone();
     let num_aliases2 = balance2.aliases().len();
     let num_foundries2 = balance2.foundries().len();
@@ -92,7 +173,7 @@ fn balance_add_assign() {
 
     assert_eq!(balance1.base_coin().total(), total1 + total2);
     assert_eq!(balance1.base_coin().available(), available1 + available2);
-    #[cfg(feature = "participation")]
+    #[cfg(feature = "wallet-participation")]
     assert_eq!(balance1.base_coin().voting_power(), voting_power1 + voting_power2);
 
     assert_eq!(balance1.required_storage_deposit().alias(), sdr_alias1 + sdr_alias2);
@@ -103,6 +184,8 @@ fn balance_add_assign() {
     );
     assert_eq!(balance1.required_storage_deposit().nft(), sdr_nft1 + sdr_nft2);
 
+    assert_eq!(*balance1.claimable(), claimable1 + claimable2);
+
     assert_eq!(balance1.aliases().len(), num_aliases1 + num_aliases2);
     assert_eq!(balance1.foundries().len(), num_foundries1 + num_foundries2);
     assert_eq!(balance1.nfts().len(), num_nfts1 + num_nfts2);
@@ -213,7 +296,7 @@ async fn balance_expiration() -> Result<()> {
 
 #[ignore]
 #[tokio::test]
-#[cfg(feature = "participation")]
+#[cfg(feature = "wallet-participation")]
 async fn balance_voting_power() -> Result<()> {
     let storage_path = "test-storage/balance_voting_power";
     setup(storage_path)?;
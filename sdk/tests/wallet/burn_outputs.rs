@@ -31,15 +31,18 @@ async fn mint_and_burn_nft() -> Result<()> {
         immutable_metadata: Some(b"some immutable nft metadata".to_vec()),
     }];
 
-    let transaction = account.mint_nfts(nft_options, None).await.unwrap();
+    let mint_transaction = account.mint_nfts(nft_options, None).await.unwrap();
     account
-        .retry_transaction_until_included(&transaction.transaction_id, None, None)
+        .retry_transaction_until_included(&mint_transaction.transaction.transaction_id, None, None)
         .await?;
 
     let balance = account.sync(None).await.unwrap();
 
-    let output_id = OutputId::new(transaction.transaction_id, 0u16).unwrap();
-    let nft_id = NftId::from(&output_id);
+    let nft_id = mint_transaction.nft_ids[0];
+    assert_eq!(
+        nft_id,
+        NftId::from(&OutputId::new(mint_transaction.transaction.transaction_id, 0u16).unwrap())
+    );
 
     let search = balance.nfts().iter().find(|&balance_nft_id| *balance_nft_id == nft_id);
     println!("account balance -> {}", serde_json::to_string(&balance).unwrap());
@@ -115,7 +118,7 @@ async fn mint_and_decrease_native_token_supply() -> Result<()> {
     let account = &create_accounts_with_funds(&wallet, 1).await?[0];
 
     // First create an alias output, this needs to be done only once, because an alias can have many foundry outputs
-    let transaction = account.create_alias_output(None, None).await?;
+    let transaction = account.create_alias_output(None, None).await?.transaction;
 
     // Wait for transaction to get included
     account
@@ -246,7 +249,7 @@ async fn mint_and_burn_native_tokens() -> Result<()> {
 
     let native_token_amount = U256::from(100);
 
-    let tx = account.create_alias_output(None, None).await?;
+    let tx = account.create_alias_output(None, None).await?.transaction;
     account
         .retry_transaction_until_included(&tx.transaction_id, None, None)
         .await?;
@@ -290,7 +293,7 @@ async fn mint_and_burn_nft_with_alias() -> Result<()> {
     let wallet = make_wallet(storage_path, None, None).await?;
     let account = &create_accounts_with_funds(&wallet, 1).await?[0];
 
-    let tx = account.create_alias_output(None, None).await?;
+    let tx = account.create_alias_output(None, None).await?.transaction;
     account
         .retry_transaction_until_included(&tx.transaction_id, None, None)
         .await?;
@@ -301,7 +304,7 @@ async fn mint_and_burn_nft_with_alias() -> Result<()> {
         immutable_metadata: Some(b"some immutable nft metadata".to_vec()),
         ..Default::default()
     }];
-    let nft_tx = account.mint_nfts(nft_options, None).await.unwrap();
+    let nft_tx = account.mint_nfts(nft_options, None).await.unwrap().transaction;
     account
         .retry_transaction_until_included(&nft_tx.transaction_id, None, None)
         .await?;
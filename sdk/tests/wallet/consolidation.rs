@@ -1,7 +1,10 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use iota_sdk::wallet::{Result, SendAmountParams};
+use iota_sdk::wallet::{
+    account::{ConsolidationParams, UtxoRecommendation},
+    Result, SendAmountParams,
+};
 
 use crate::wallet::common::{create_accounts_with_funds, make_wallet, setup, tear_down};
 
@@ -33,7 +36,19 @@ async fn consolidation() -> Result<()> {
     assert_eq!(balance.base_coin().available(), 10 * amount);
     assert_eq!(account_1.unspent_outputs(None).await?.len(), 10);
 
-    let tx = account_1.consolidate_outputs(true, None).await?;
+    let report = account_1.utxo_health().await?;
+    assert_eq!(report.output_count, 10);
+    assert_eq!(report.free_output_count, 10);
+    assert_eq!(report.size_distribution[1].output_count, 10);
+    // Below the consolidation threshold
+    assert!(report.recommendations.is_empty());
+
+    let tx = account_1
+        .consolidate_outputs(ConsolidationParams {
+            force: true,
+            ..Default::default()
+        })
+        .await?;
     account_1
         .retry_transaction_until_included(&tx.transaction_id, None, None)
         .await?;
@@ -44,5 +59,53 @@ async fn consolidation() -> Result<()> {
     // Only one unspent output
     assert_eq!(account_1.unspent_outputs(None).await?.len(), 1);
 
+    // A single large output should be split to send transactions in parallel
+    let report = account_1.utxo_health().await?;
+    assert!(matches!(report.recommendations[..], [UtxoRecommendation::Split { .. }]));
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn consolidate_all_outputs() -> Result<()> {
+    let storage_path = "test-storage/consolidate_all_outputs";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+
+    // Send more outputs to account_1 than fit into a single transaction
+    let amount = 100_000;
+    for _ in 0..2 {
+        let tx = account_0
+            .send_amount(
+                vec![SendAmountParams::new(*account_1.addresses().await?[0].address(), amount); 100],
+                None,
+            )
+            .await?;
+        account_0
+            .retry_transaction_until_included(&tx.transaction_id, None, None)
+            .await?;
+    }
+
+    let balance = account_1.sync(None).await.unwrap();
+    assert_eq!(balance.base_coin().available(), 200 * amount);
+    assert_eq!(account_1.unspent_outputs(None).await?.len(), 200);
+
+    let transactions = account_1
+        .consolidate_all_outputs(ConsolidationParams {
+            force: true,
+            ..Default::default()
+        })
+        .await?;
+    assert_eq!(transactions.len(), 2);
+
+    let balance = account_1.sync(None).await.unwrap();
+    assert_eq!(balance.base_coin().available(), 200 * amount);
+    assert_eq!(account_1.unspent_outputs(None).await?.len(), 1);
+
     tear_down(storage_path)
 }
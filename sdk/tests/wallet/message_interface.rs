@@ -1,15 +1,30 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::sync::{atomic::Ordering, Arc};
+use std::{
+    collections::BTreeMap,
+    sync::{atomic::Ordering, Arc},
+};
 
 #[cfg(feature = "events")]
 use iota_sdk::wallet::events::types::WalletEvent;
 use iota_sdk::{
     client::{constants::SHIMMER_COIN_TYPE, secret::GenerateAddressOptions, ClientBuilder},
     types::block::{
-        address::{Address, Bech32Address, Hrp},
+        address::{Address, Bech32Address, Ed25519Address, Hrp},
+        input::{Input, UtxoInput},
         output::{dto::OutputDto, unlock_condition::AddressUnlockCondition, BasicOutputBuilder},
+        payload::{
+            transaction::{
+                dto::TransactionPayloadDto, RegularTransactionEssence, TransactionEssence, TransactionEssenceHash,
+            },
+            TransactionPayload,
+        },
+        protocol::ProtocolParameters,
+        rand::{output::rand_output_id, parents::rand_parents},
+        signature::{Ed25519Signature, Signature},
+        unlock::{SignatureUnlock, Unlock, Unlocks},
+        BlockBuilder, BlockDto,
     },
     wallet::{
         message_interface::{create_message_handler, AccountMethod, ManagerOptions, Message, Response},
@@ -99,6 +114,7 @@ async fn message_interface_create_account() -> Result<()> {
         .send_message(Message::CreateAccount {
             alias: None,
             bech32_hrp: None,
+            watch_only_addresses: None,
         })
         .await;
 
@@ -114,6 +130,75 @@ async fn message_interface_create_account() -> Result<()> {
     tear_down(storage_path)
 }
 
+#[tokio::test]
+async fn message_interface_address_label_and_metadata() -> Result<()> {
+    let storage_path = "test-storage/message_interface_address_label_and_metadata";
+    setup(storage_path)?;
+
+    let secret_manager = r#"{"Mnemonic":"acoustic trophy damage hint search taste love bicycle foster cradle brown govern endless depend situate athlete pudding blame question genius transfer van random vast"}"#;
+    let client_options = r#"{"nodes":["http://localhost:14265"]}"#;
+
+    let options = ManagerOptions {
+        #[cfg(feature = "storage")]
+        storage_path: Some(storage_path.to_string()),
+        client_options: Some(ClientBuilder::new().from_json(client_options).unwrap()),
+        coin_type: Some(SHIMMER_COIN_TYPE),
+        secret_manager: Some(serde_json::from_str(secret_manager).unwrap()),
+    };
+
+    let wallet_handle = create_message_handler(Some(options)).await.unwrap();
+
+    let response = wallet_handle
+        .send_message(Message::CreateAccount {
+            alias: Some("alias".to_string()),
+            bech32_hrp: Some(Hrp::from_str_unchecked("rms")),
+            watch_only_addresses: None,
+        })
+        .await;
+    let Response::Account(account) = response else {
+        panic!("unexpected response {response:?}");
+    };
+    let address = *account.public_addresses[0].address();
+
+    // The setters return the updated address
+    let response = wallet_handle
+        .send_message(Message::CallAccountMethod {
+            account_id: "alias".into(),
+            method: AccountMethod::SetAddressLabel {
+                address,
+                label: Some("user-1".to_string()),
+            },
+            audit_context: None,
+            dry_run: false,
+        })
+        .await;
+    let Response::Address(Some(account_address)) = response else {
+        panic!("unexpected response {response:?}");
+    };
+    assert_eq!(*account_address.address(), address);
+    assert_eq!(account_address.label(), &Some("user-1".to_string()));
+
+    let metadata = BTreeMap::from([("purpose".to_string(), "invoices".to_string())]);
+    let response = wallet_handle
+        .send_message(Message::CallAccountMethod {
+            account_id: "alias".into(),
+            method: AccountMethod::SetAddressMetadata {
+                address,
+                metadata: metadata.clone(),
+            },
+            audit_context: None,
+            dry_run: false,
+        })
+        .await;
+    let Response::Address(Some(account_address)) = response else {
+        panic!("unexpected response {response:?}");
+    };
+    assert_eq!(account_address.label(), &Some("user-1".to_string()));
+    assert_eq!(account_address.metadata(), &metadata);
+
+    tear_down(storage_path)
+}
+
 #[ignore]
 #[cfg(feature = "events")]
 #[tokio::test]
@@ -155,6 +240,7 @@ async fn message_interface_events() -> Result<()> {
         .send_message(Message::CreateAccount {
             alias: Some("alias".to_string()),
             bech32_hrp: None,
+            watch_only_addresses: None,
         })
         .await;
 
@@ -169,6 +255,8 @@ async fn message_interface_events() -> Result<()> {
                     url: FAUCET_URL.to_string(),
                     address: *account.public_addresses[0].address(),
                 },
+                audit_context: None,
+                dry_run: false,
             };
 
             let _response = wallet_handle.send_message(transaction).await;
@@ -182,6 +270,8 @@ async fn message_interface_events() -> Result<()> {
     let sync_method = Message::CallAccountMethod {
         account_id: "alias".into(),
         method: AccountMethod::SyncAccount { options: None },
+        audit_context: None,
+        dry_run: false,
     };
 
     let _response = wallet_handle.send_message(sync_method).await;
@@ -199,6 +289,8 @@ async fn message_interface_events() -> Result<()> {
     let transaction = Message::CallAccountMethod {
         account_id: "alias".into(),
         method: AccountMethod::SendOutputs { outputs, options: None },
+        audit_context: None,
+        dry_run: false,
     };
 
     let response = wallet_handle.send_message(transaction).await;
@@ -310,6 +402,7 @@ async fn message_interface_stronghold() -> Result<()> {
         .send_message(Message::CreateAccount {
             alias: None,
             bech32_hrp: None,
+            watch_only_addresses: None,
         })
         .await;
 
@@ -374,6 +467,144 @@ async fn address_conversion_methods() -> Result<()> {
     tear_down(storage_path)
 }
 
+#[tokio::test]
+async fn message_interface_block_id() -> Result<()> {
+    let storage_path = "test-storage/message_interface_block_id";
+    setup(storage_path)?;
+
+    let secret_manager = r#"{"Mnemonic":"acoustic trophy damage hint search taste love bicycle foster cradle brown govern endless depend situate athlete pudding blame question genius transfer van random vast"}"#;
+    let client_options = r#"{"nodes":["http://localhost:14265"]}"#;
+
+    let options = ManagerOptions {
+        #[cfg(feature = "storage")]
+        storage_path: Some(storage_path.to_string()),
+        client_options: Some(ClientBuilder::new().from_json(client_options).unwrap()),
+        coin_type: Some(SHIMMER_COIN_TYPE),
+        secret_manager: Some(serde_json::from_str(secret_manager).unwrap()),
+    };
+
+    let wallet_handle = create_message_handler(Some(options)).await.unwrap();
+
+    let block = BlockBuilder::new(rand_parents()).finish().unwrap();
+
+    let response = wallet_handle
+        .send_message(Message::BlockId {
+            block: BlockDto::from(&block),
+        })
+        .await;
+
+    match response {
+        Response::BlockId(block_id) => {
+            assert_eq!(block_id, block.id());
+        }
+        response_type => panic!("Unexpected response type: {response_type:?}"),
+    }
+
+    tear_down(storage_path)
+}
+
+// A transaction payload with a single input and output, the signature isn't valid
+fn transaction_payload() -> TransactionPayload {
+    let protocol_parameters = ProtocolParameters::default();
+    let output = BasicOutputBuilder::new_with_amount(1_000_000)
+        .add_unlock_condition(AddressUnlockCondition::new(Address::Ed25519(Ed25519Address::new(
+            [1; Ed25519Address::LENGTH],
+        ))))
+        .finish_output(TOKEN_SUPPLY)
+        .unwrap();
+    let essence = RegularTransactionEssence::builder(protocol_parameters.network_id(), [0; 32].into())
+        .with_inputs(vec![Input::Utxo(UtxoInput::from(rand_output_id()))])
+        .with_outputs(vec![output])
+        .finish(&protocol_parameters)
+        .unwrap();
+    let unlocks = Unlocks::new(vec![Unlock::Signature(SignatureUnlock::from(Signature::Ed25519(
+        Ed25519Signature::new(
+            [0; Ed25519Signature::PUBLIC_KEY_LENGTH],
+            [0; Ed25519Signature::SIGNATURE_LENGTH],
+        ),
+    )))])
+    .unwrap();
+
+    TransactionPayload::new(TransactionEssence::Regular(essence), unlocks).unwrap()
+}
+
+#[tokio::test]
+async fn message_interface_transaction_id() -> Result<()> {
+    let storage_path = "test-storage/message_interface_transaction_id";
+    setup(storage_path)?;
+
+    let secret_manager = r#"{"Mnemonic":"acoustic trophy damage hint search taste love bicycle foster cradle brown govern endless depend situate athlete pudding blame question genius transfer van random vast"}"#;
+    let client_options = r#"{"nodes":["http://localhost:14265"]}"#;
+
+    let options = ManagerOptions {
+        #[cfg(feature = "storage")]
+        storage_path: Some(storage_path.to_string()),
+        client_options: Some(ClientBuilder::new().from_json(client_options).unwrap()),
+        coin_type: Some(SHIMMER_COIN_TYPE),
+        secret_manager: Some(serde_json::from_str(secret_manager).unwrap()),
+    };
+
+    let wallet_handle = create_message_handler(Some(options)).await.unwrap();
+
+    let payload = transaction_payload();
+
+    let response = wallet_handle
+        .send_message(Message::TransactionId {
+            payload: TransactionPayloadDto::from(&payload),
+        })
+        .await;
+
+    match response {
+        Response::TransactionId(transaction_id) => {
+            assert_eq!(transaction_id, payload.id());
+        }
+        response_type => panic!("Unexpected response type: {response_type:?}"),
+    }
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn message_interface_hash_transaction_essence() -> Result<()> {
+    let storage_path = "test-storage/message_interface_hash_transaction_essence";
+    setup(storage_path)?;
+
+    let secret_manager = r#"{"Mnemonic":"acoustic trophy damage hint search taste love bicycle foster cradle brown govern endless depend situate athlete pudding blame question genius transfer van random vast"}"#;
+    let client_options = r#"{"nodes":["http://localhost:14265"]}"#;
+
+    let options = ManagerOptions {
+        #[cfg(feature = "storage")]
+        storage_path: Some(storage_path.to_string()),
+        client_options: Some(ClientBuilder::new().from_json(client_options).unwrap()),
+        coin_type: Some(SHIMMER_COIN_TYPE),
+        secret_manager: Some(serde_json::from_str(secret_manager).unwrap()),
+    };
+
+    let wallet_handle = create_message_handler(Some(options)).await.unwrap();
+
+    let payload = transaction_payload();
+
+    let response = wallet_handle
+        .send_message(Message::HashTransactionEssence {
+            payload: TransactionPayloadDto::from(&payload),
+        })
+        .await;
+
+    match response {
+        Response::TransactionEssenceHash(essence_hash) => {
+            assert_eq!(essence_hash, TransactionEssenceHash::new(payload.essence().hash()));
+            // Serialized as a hex string, like the other ids
+            assert_eq!(
+                serde_json::to_value(essence_hash)?,
+                serde_json::Value::String(prefix_hex::encode(payload.essence().hash()))
+            );
+        }
+        response_type => panic!("Unexpected response type: {response_type:?}"),
+    }
+
+    tear_down(storage_path)
+}
+
 #[tokio::test]
 async fn message_interface_address_generation() -> Result<()> {
     let storage_path = "test-storage/message_interface_address_generation";
@@ -432,3 +663,216 @@ async fn message_interface_address_generation() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[cfg(feature = "storage")]
+#[tokio::test]
+async fn message_interface_audit_log() -> Result<()> {
+    let storage_path = "test-storage/message_interface_audit_log";
+    setup(storage_path)?;
+
+    let secret_manager = r#"{"Mnemonic":"acoustic trophy damage hint search taste love bicycle foster cradle brown govern endless depend situate athlete pudding blame question genius transfer van random vast"}"#;
+    let client_options = r#"{"nodes":["http://localhost:14265"]}"#;
+
+    let options = ManagerOptions {
+        storage_path: Some(storage_path.to_string()),
+        client_options: Some(ClientBuilder::new().from_json(client_options).unwrap()),
+        coin_type: Some(SHIMMER_COIN_TYPE),
+        secret_manager: Some(serde_json::from_str(secret_manager).unwrap()),
+    };
+
+    let wallet_handle = create_message_handler(Some(options)).await.unwrap();
+
+    let response = wallet_handle
+        .send_message(Message::CreateAccount {
+            alias: Some("alias".to_string()),
+            bech32_hrp: Some(Hrp::from_str_unchecked("rms")),
+            watch_only_addresses: None,
+        })
+        .await;
+    let Response::Account(_) = response else {
+        panic!("unexpected response {response:?}");
+    };
+
+    // Not recorded, the audit log is disabled
+    wallet_handle
+        .send_message(Message::CallAccountMethod {
+            account_id: "alias".into(),
+            method: AccountMethod::SetAlias {
+                alias: "first".to_string(),
+            },
+            audit_context: None,
+            dry_run: false,
+        })
+        .await;
+
+    let response = wallet_handle
+        .send_message(Message::SetAuditLogEnabled { enabled: true })
+        .await;
+    let Response::Ok(_) = response else {
+        panic!("unexpected response {response:?}");
+    };
+
+    wallet_handle
+        .send_message(Message::CallAccountMethod {
+            account_id: "first".into(),
+            method: AccountMethod::SetAlias {
+                alias: "second".to_string(),
+            },
+            audit_context: Some("operator".to_string()),
+            dry_run: false,
+        })
+        .await;
+    // Not recorded, doesn't mutate the account
+    wallet_handle
+        .send_message(Message::CallAccountMethod {
+            account_id: "second".into(),
+            method: AccountMethod::Addresses,
+            audit_context: None,
+            dry_run: false,
+        })
+        .await;
+
+    let response = wallet_handle
+        .send_message(Message::CallAccountMethod {
+            account_id: "second".into(),
+            method: AccountMethod::GetAuditLog {
+                filter: Default::default(),
+            },
+            audit_context: None,
+            dry_run: false,
+        })
+        .await;
+    let Response::AuditLog(entries) = response else {
+        panic!("unexpected response {response:?}");
+    };
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].method, "setAlias");
+    assert_eq!(entries[0].context.as_deref(), Some("operator"));
+    assert_eq!(entries[0].transaction_id, None);
+    assert_eq!(entries[0].error, None);
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn message_interface_dry_run() -> Result<()> {
+    let storage_path = "test-storage/message_interface_dry_run";
+    setup(storage_path)?;
+
+    let secret_manager = r#"{"Mnemonic":"acoustic trophy damage hint search taste love bicycle foster cradle brown govern endless depend situate athlete pudding blame question genius transfer van random vast"}"#;
+    let client_options = r#"{"nodes":["http://localhost:14265"]}"#;
+
+    let options = ManagerOptions {
+        storage_path: Some(storage_path.to_string()),
+        client_options: Some(ClientBuilder::new().from_json(client_options).unwrap()),
+        coin_type: Some(SHIMMER_COIN_TYPE),
+        secret_manager: Some(serde_json::from_str(secret_manager).unwrap()),
+    };
+
+    let wallet_handle = create_message_handler(Some(options)).await.unwrap();
+
+    let response = wallet_handle
+        .send_message(Message::CreateAccount {
+            alias: Some("alias".to_string()),
+            bech32_hrp: Some(Hrp::from_str_unchecked("rms")),
+            watch_only_addresses: None,
+        })
+        .await;
+    let Response::Account(_) = response else {
+        panic!("unexpected response {response:?}");
+    };
+
+    // Methods without a transaction can't be previewed
+    let response = wallet_handle
+        .send_message(Message::CallAccountMethod {
+            account_id: "alias".into(),
+            method: AccountMethod::SetAlias {
+                alias: "renamed".to_string(),
+            },
+            audit_context: None,
+            dry_run: true,
+        })
+        .await;
+    let Response::Error(iota_sdk::wallet::Error::DryRunNotSupported(method)) = response else {
+        panic!("unexpected response {response:?}");
+    };
+    assert_eq!(method, "setAlias");
+
+    // Methods that don't change the account are called as usual
+    let response = wallet_handle
+        .send_message(Message::CallAccountMethod {
+            account_id: "alias".into(),
+            method: AccountMethod::Addresses,
+            audit_context: None,
+            dry_run: true,
+        })
+        .await;
+    let Response::Addresses(addresses) = response else {
+        panic!("unexpected response {response:?}");
+    };
+    assert_eq!(addresses.len(), 1);
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn message_interface_prepare_methods() -> Result<()> {
+    let storage_path = "test-storage/message_interface_prepare_methods";
+    setup(storage_path)?;
+
+    // Burning uses the same method as the bindings, covering nfts, aliases, foundries and native tokens
+    let nft_id = "0x1111111111111111111111111111111111111111111111111111111111111111";
+    let method: AccountMethod = serde_json::from_str(&format!(
+        r#"{{"name":"prepareBurn","data":{{"burn":{{"nfts":["{nft_id}"]}}}}}}"#
+    ))
+    .unwrap();
+    assert!(matches!(method, AccountMethod::PrepareBurn { options: None, .. }));
+    assert_eq!(
+        serde_json::to_value(&method).unwrap()["data"]["burn"]["nfts"][0],
+        nft_id
+    );
+
+    #[cfg(feature = "wallet-participation")]
+    {
+        let secret_manager = r#"{"Mnemonic":"acoustic trophy damage hint search taste love bicycle foster cradle brown govern endless depend situate athlete pudding blame question genius transfer van random vast"}"#;
+        let client_options = r#"{"nodes":["http://localhost:14265"]}"#;
+
+        let options = ManagerOptions {
+            storage_path: Some(storage_path.to_string()),
+            client_options: Some(ClientBuilder::new().from_json(client_options).unwrap()),
+            coin_type: Some(SHIMMER_COIN_TYPE),
+            secret_manager: Some(serde_json::from_str(secret_manager).unwrap()),
+        };
+
+        let wallet_handle = create_message_handler(Some(options)).await.unwrap();
+
+        let response = wallet_handle
+            .send_message(Message::CreateAccount {
+                alias: Some("alias".to_string()),
+                bech32_hrp: Some(Hrp::from_str_unchecked("rms")),
+                watch_only_addresses: None,
+            })
+            .await;
+        let Response::Account(_) = response else {
+            panic!("unexpected response {response:?}");
+        };
+
+        // The amount is converted the same way for the preparing and the sending method
+        let response = wallet_handle
+            .send_message(Message::CallAccountMethod {
+                account_id: "alias".into(),
+                method: AccountMethod::PrepareIncreaseVotingPower {
+                    amount: "one".to_string(),
+                },
+                audit_context: None,
+                dry_run: false,
+            })
+            .await;
+        let Response::Error(iota_sdk::wallet::Error::Client(error)) = response else {
+            panic!("unexpected response {response:?}");
+        };
+        assert!(matches!(*error, iota_sdk::client::Error::InvalidAmount(amount) if amount == "one"));
+    }
+
+    tear_down(storage_path)
+}
@@ -9,14 +9,20 @@ mod bech32_hrp_validation;
 mod burn_outputs;
 mod claim_outputs;
 mod common;
+#[cfg(feature = "wallet-consolidation")]
 mod consolidation;
 mod error;
-#[cfg(feature = "message_interface")]
+#[cfg(feature = "message-interface")]
 mod message_interface;
 mod migrate_stronghold_snapshot_v2_to_v3;
 mod native_tokens;
+mod nft_metadata;
 mod output_preparation;
+#[cfg(feature = "service")]
+mod service;
 mod syncing;
+#[cfg(feature = "testkit")]
+mod testkit;
 mod transactions;
 #[allow(clippy::module_inception)]
 mod wallet;
@@ -18,7 +18,7 @@ async fn mint_and_increase_native_token_supply() -> Result<()> {
 
     let account = &create_accounts_with_funds(&wallet, 1).await?[0];
 
-    let tx = account.create_alias_output(None, None).await?;
+    let tx = account.create_alias_output(None, None).await?.transaction;
     account
         .retry_transaction_until_included(&tx.transaction_id, None, None)
         .await?;
@@ -81,7 +81,7 @@ async fn native_token_foundry_metadata() -> Result<()> {
 
     let account = &create_accounts_with_funds(&wallet, 1).await?[0];
 
-    let tx = account.create_alias_output(None, None).await?;
+    let tx = account.create_alias_output(None, None).await?.transaction;
     account
         .retry_transaction_until_included(&tx.transaction_id, None, None)
         .await?;
@@ -0,0 +1,43 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::{
+    types::block::{
+        output::{feature::MetadataFeature, NftId, NftOutputBuilder},
+        protocol::protocol_parameters,
+        rand::output::unlock_condition::rand_address_unlock_condition,
+    },
+    wallet::account::NftMetadata,
+};
+
+#[test]
+fn nft_metadata_decoding() {
+    let nft_output = |metadata: Option<&[u8]>| {
+        let mut builder = NftOutputBuilder::new_with_amount(1_000_000, NftId::null())
+            .add_unlock_condition(rand_address_unlock_condition());
+        if let Some(metadata) = metadata {
+            builder = builder.add_immutable_feature(MetadataFeature::new(metadata).unwrap());
+        }
+        builder.finish(protocol_parameters().token_supply()).unwrap()
+    };
+
+    let irc27: &[u8] = br#"{"standard":"IRC27","version":"v1.0","type":"image/png","uri":"https://mywebsite.com/my-nft-files-1.png","name":"My NFT #0001","collectionName":"My Collection","royalties":{"iota1qpf0mlq8yxpx2nck8a0slxnzr4ef2ek8f5gqxlzd0wasgp73utryj430ldu":0.025},"attributes":[{"trait_type":"Background","value":"Purple"}]}"#;
+    let Some(NftMetadata::Irc27(metadata)) = NftMetadata::from_nft_output(&nft_output(Some(irc27))) else {
+        panic!("expected IRC27 metadata");
+    };
+    assert_eq!(metadata.name, "My NFT #0001");
+    assert_eq!(metadata.media_type, "image/png");
+    assert_eq!(metadata.collection_name.as_deref(), Some("My Collection"));
+    assert_eq!(metadata.royalties.values().copied().collect::<Vec<_>>(), vec![0.025]);
+    assert_eq!(metadata.attributes[0].trait_type, "Background");
+
+    let raw: &[u8] = br#"{"standard":"IRC30","name":"Not an NFT"}"#;
+    let metadata = NftMetadata::from_nft_output(&nft_output(Some(raw))).unwrap();
+    assert_eq!(metadata, NftMetadata::Raw(raw.to_vec()));
+    assert_eq!(
+        serde_json::to_value(&metadata).unwrap(),
+        serde_json::json!({ "type": "raw", "data": prefix_hex::encode(raw) })
+    );
+
+    assert_eq!(NftMetadata::from_nft_output(&nft_output(None)), None);
+}
@@ -512,7 +512,7 @@ async fn prepare_nft_output_features_update() -> Result<()> {
         immutable_metadata: Some(b"some immutable nft metadata".to_vec()),
     }];
 
-    let transaction = accounts[0].mint_nfts(nft_options, None).await.unwrap();
+    let transaction = accounts[0].mint_nfts(nft_options, None).await.unwrap().transaction;
     accounts[0]
         .retry_transaction_until_included(&transaction.transaction_id, None, None)
         .await?;
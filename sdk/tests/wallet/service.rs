@@ -0,0 +1,225 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+use iota_sdk::wallet::{
+    events::types::WalletEvent,
+    service::{RpcAccess, RpcOptions, ServiceHealth, WalletService},
+    Result,
+};
+use serde_json::{json, Value};
+
+use crate::wallet::common::{make_wallet, setup, tear_down};
+
+const AUTH_TOKEN: &str = "secret";
+
+async fn rpc_request(http_client: &reqwest::Client, url: &str, auth_token: &str, request: Value) -> reqwest::Response {
+    http_client
+        .post(url)
+        .bearer_auth(auth_token)
+        .json(&request)
+        .send()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn service_json_rpc() -> Result<()> {
+    let storage_path = "test-storage/service_json_rpc";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    wallet.create_account().with_alias("Alice".to_string()).finish().await?;
+
+    let service = WalletService::builder(wallet)
+        .with_rpc(RpcOptions::new(AUTH_TOKEN).with_address("127.0.0.1:0".parse().unwrap()))
+        .start()
+        .await?;
+    let url = format!("http://{}/", service.rpc_address().unwrap());
+    let http_client = reqwest::Client::new();
+
+    let response: Value = rpc_request(
+        &http_client,
+        &url,
+        AUTH_TOKEN,
+        json!({ "jsonrpc": "2.0", "id": 1, "method": "getAccountIndexes" }),
+    )
+    .await
+    .json()
+    .await
+    .unwrap();
+    assert_eq!(response["id"], 1);
+    assert_eq!(response["result"], json!({ "type": "accountIndexes", "payload": [0] }));
+
+    let response: Value = rpc_request(
+        &http_client,
+        &url,
+        AUTH_TOKEN,
+        json!({ "jsonrpc": "2.0", "id": 2, "method": "unknownMethod" }),
+    )
+    .await
+    .json()
+    .await
+    .unwrap();
+    assert_eq!(response["error"]["code"], -32602);
+
+    // Requests without the auth token are rejected
+    let response = rpc_request(
+        &http_client,
+        &url,
+        "other",
+        json!({ "jsonrpc": "2.0", "id": 3, "method": "getAccountIndexes" }),
+    )
+    .await;
+    assert_eq!(response.status(), reqwest::StatusCode::UNAUTHORIZED);
+    let response = http_client.get(format!("{url}health")).send().await.unwrap();
+    assert_eq!(response.status(), reqwest::StatusCode::UNAUTHORIZED);
+
+    // The default access is read-only
+    let response: Value = rpc_request(
+        &http_client,
+        &url,
+        AUTH_TOKEN,
+        json!({ "jsonrpc": "2.0", "id": 4, "method": "removeLatestAccount" }),
+    )
+    .await
+    .json()
+    .await
+    .unwrap();
+    assert_eq!(response["error"]["code"], -32601);
+
+    let health: ServiceHealth = http_client
+        .get(format!("{url}health"))
+        .bearer_auth(AUTH_TOKEN)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(health.accounts, 1);
+    assert_eq!(health.healthy_nodes, service.health().await.healthy_nodes);
+
+    let wallet = service.wallet().clone();
+    service.shutdown().await?;
+
+    // With full access, mutating messages are handled
+    let service = WalletService::builder(wallet)
+        .with_rpc(
+            RpcOptions::new(AUTH_TOKEN)
+                .with_address("127.0.0.1:0".parse().unwrap())
+                .with_access(RpcAccess::Full),
+        )
+        .start()
+        .await?;
+    let url = format!("http://{}/", service.rpc_address().unwrap());
+
+    let response: Value = rpc_request(
+        &http_client,
+        &url,
+        AUTH_TOKEN,
+        json!({ "jsonrpc": "2.0", "id": 5, "method": "removeLatestAccount" }),
+    )
+    .await
+    .json()
+    .await
+    .unwrap();
+    assert_eq!(response["result"]["type"], "ok");
+    assert!(service.wallet().get_accounts().await?.is_empty());
+
+    // Messages with a path chosen by the caller are rejected, also with full access
+    #[cfg(feature = "encrypted_backup")]
+    {
+        let destination = format!("{storage_path}/backup.json");
+        let response: Value = rpc_request(
+            &http_client,
+            &url,
+            AUTH_TOKEN,
+            json!({
+                "jsonrpc": "2.0",
+                "id": 6,
+                "method": "backupToEncryptedJson",
+                "params": { "destination": destination, "password": "password" }
+            }),
+        )
+        .await
+        .json()
+        .await
+        .unwrap();
+        assert_eq!(response["error"]["code"], -32601);
+        assert!(!std::path::Path::new(&destination).exists());
+    }
+
+    service.shutdown().await?;
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn service_requires_auth_token() -> Result<()> {
+    let storage_path = "test-storage/service_requires_auth_token";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    assert!(WalletService::builder(wallet)
+        .with_rpc(RpcOptions::new("").with_address("127.0.0.1:0".parse().unwrap()))
+        .start()
+        .await
+        .is_err());
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn service_event_sinks() -> Result<()> {
+    let storage_path = "test-storage/service_event_sinks";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let listener_events = Arc::new(AtomicUsize::new(0));
+    let listener_events_clone = Arc::clone(&listener_events);
+    wallet
+        .listen(vec![], move |_| {
+            listener_events_clone.fetch_add(1, Ordering::SeqCst);
+        })
+        .await;
+
+    let sink_events = Arc::new(AtomicUsize::new(0));
+    let sink_events_clone = Arc::clone(&sink_events);
+    let service = WalletService::builder(wallet)
+        .with_event_sink(vec![], move |_| {
+            sink_events_clone.fetch_add(1, Ordering::SeqCst);
+        })
+        .start()
+        .await?;
+
+    service
+        .wallet()
+        .emit_test_event(WalletEvent::ConsolidationRequired)
+        .await;
+    // The sinks are called from their own task
+    for _ in 0..100 {
+        if sink_events.load(Ordering::SeqCst) == 1 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+    assert_eq!(sink_events.load(Ordering::SeqCst), 1);
+    assert_eq!(listener_events.load(Ordering::SeqCst), 1);
+
+    // Only the sinks of the service are removed on shutdown
+    let wallet = service.wallet().clone();
+    service.shutdown().await?;
+    wallet.emit_test_event(WalletEvent::ConsolidationRequired).await;
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert_eq!(sink_events.load(Ordering::SeqCst), 1);
+    assert_eq!(listener_events.load(Ordering::SeqCst), 2);
+
+    tear_down(storage_path)
+}
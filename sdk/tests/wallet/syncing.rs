@@ -148,3 +148,33 @@ async fn sync_only_most_basic_outputs() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[ignore]
+#[tokio::test]
+async fn sync_all_accounts() -> Result<()> {
+    let storage_path = "test-storage/sync_all_accounts";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    wallet.create_account().finish().await?;
+    wallet.create_account().finish().await?;
+
+    let results = wallet.sync_all(None, 2).await;
+
+    assert_eq!(results.len(), 3);
+    assert_eq!(
+        results.iter().map(|result| result.account_index).collect::<Vec<_>>(),
+        vec![0, 1, 2]
+    );
+    let balance_0 = results[0].result.as_ref().unwrap();
+    assert_eq!(balance_0, &account_0.balance().await?);
+    assert!(results[1..].iter().all(|result| result.result.is_ok()));
+
+    let balances = wallet.sync_all_accounts(None).await?;
+    assert_eq!(balances.len(), 3);
+    assert_eq!(balances[0], account_0.balance().await?);
+
+    tear_down(storage_path)
+}
@@ -0,0 +1,1246 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+#[cfg(feature = "wallet-participation")]
+use iota_sdk::types::{
+    api::plugins::participation::types::{Participation, ParticipationEventId, Participations, PARTICIPATION_TAG},
+    block::output::feature::{MetadataFeature, TagFeature},
+};
+#[cfg(feature = "storage")]
+use iota_sdk::wallet::account::AuditLogFilter;
+#[cfg(feature = "message-interface")]
+use iota_sdk::wallet::message_interface::{AccountMethod, Message, Response, WalletMessageHandler};
+use iota_sdk::{
+    client::{
+        constants::SHIMMER_COIN_TYPE,
+        secret::{mnemonic::MnemonicSecretManager, SecretManager},
+        Client,
+    },
+    types::block::{
+        address::{Address, AliasAddress},
+        output::{
+            unlock_condition::{
+                AddressUnlockCondition, ExpirationUnlockCondition, GovernorAddressUnlockCondition,
+                StateControllerAddressUnlockCondition, StorageDepositReturnUnlockCondition, TimelockUnlockCondition,
+            },
+            AliasId, AliasOutputBuilder, BasicOutputBuilder, NftId, NftOutputBuilder, Output, OutputId, Rent,
+            RentStructure, UnlockCondition,
+        },
+        payload::transaction::TransactionEssence,
+        protocol::ProtocolParameters,
+    },
+    wallet::{
+        account::{
+            types::InclusionState, DustPolicy, OutputsToClaim, RemainderValueStrategy, SpendingAllowance, SyncOptions,
+            TransactionOptions, TransactionRateLimit,
+        },
+        testkit::Testkit,
+        AddressBook, ClientOptions, Error, Result, SendAmountParams, TransactionTemplate, Wallet,
+    },
+};
+#[cfg(feature = "wallet-consolidation")]
+use iota_sdk::{
+    types::block::{
+        input::INPUT_COUNT_MAX,
+        output::{NativeToken, TokenId},
+    },
+    wallet::account::ConsolidationParams,
+    U256,
+};
+
+use crate::wallet::common::{make_wallet, setup, tear_down};
+
+#[tokio::test]
+async fn testkit_balance_expiration() -> Result<()> {
+    let storage_path = "test-storage/testkit_balance_expiration";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let testkit = Testkit::new(&wallet, 1_680_000_000).await?;
+
+    let account_0 = wallet.create_account().finish().await?;
+    let account_1 = wallet.create_account().finish().await?;
+
+    testkit.fund(&account_0, 1_000_000).await?;
+    let balance = account_0.balance().await?;
+    assert_eq!(balance.base_coin().total(), 1_000_000);
+    assert_eq!(balance.base_coin().available(), 1_000_000);
+
+    let seconds_until_expired = 20;
+    let token_supply = wallet.client().get_token_supply().await?;
+    // Owned by account 0 with expiration to account 1
+    let output = BasicOutputBuilder::new_with_amount(1_000_000)
+        .with_unlock_conditions(vec![
+            UnlockCondition::Address(AddressUnlockCondition::new(
+                *account_0.addresses().await?[0].address().as_ref(),
+            )),
+            UnlockCondition::Expiration(ExpirationUnlockCondition::new(
+                *account_1.addresses().await?[0].address().as_ref(),
+                testkit.time().await + seconds_until_expired,
+            )?),
+        ])
+        .finish_output(token_supply)?;
+    testkit.add_output(&account_0, output.clone()).await?;
+    testkit.add_output(&account_1, output).await?;
+
+    // Balances before expiration
+    let balance = account_0.balance().await?;
+    assert_eq!(balance.potentially_locked_outputs().len(), 1);
+    assert_eq!(balance.base_coin().total(), 1_000_000);
+    let balance = account_1.balance().await?;
+    assert_eq!(balance.potentially_locked_outputs().len(), 1);
+    assert_eq!(balance.base_coin().total(), 0);
+
+    testkit.advance_time(seconds_until_expired).await?;
+
+    // Balances after expiration
+    let balance = account_0.balance().await?;
+    assert_eq!(balance.potentially_locked_outputs().len(), 0);
+    assert_eq!(balance.base_coin().total(), 1_000_000);
+    let balance = account_1.balance().await?;
+    assert_eq!(balance.potentially_locked_outputs().len(), 0);
+    assert_eq!(balance.base_coin().total(), 1_000_000);
+    assert_eq!(balance.base_coin().available(), 1_000_000);
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn testkit_create_account_failed_setup() -> Result<()> {
+    let storage_path = "test-storage/testkit_create_account_failed_setup";
+    setup(storage_path)?;
+
+    let wallet = make_offline_wallet(storage_path, RentStructure::default()).await?;
+
+    // The node isn't reachable, so the initial sync fails and no account is created
+    assert!(wallet
+        .create_account()
+        .with_initial_addresses(3)
+        .with_initial_sync(SyncOptions::default())
+        .finish()
+        .await
+        .is_err());
+    assert!(wallet.get_accounts().await?.is_empty());
+
+    // Retrying doesn't create a second account
+    let account = wallet.create_account().finish().await?;
+    assert_eq!(*account.details().await.index(), 0);
+    assert_eq!(wallet.get_accounts().await?.len(), 1);
+
+    // The memory storage isn't persisted, so only a reloaded database shows the stored accounts
+    #[cfg(feature = "rocksdb")]
+    {
+        drop(account);
+        drop(wallet);
+        let wallet = make_offline_wallet(storage_path, RentStructure::default()).await?;
+        assert_eq!(wallet.get_accounts().await?.len(), 1);
+    }
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn testkit_create_account_initial_sync() -> Result<()> {
+    let storage_path = "test-storage/testkit_create_account_initial_sync";
+    setup(storage_path)?;
+
+    let wallet = make_offline_wallet(storage_path, RentStructure::default()).await?;
+
+    // No address is requested from the node, the sync only generates the unused internal addresses
+    let account = wallet
+        .create_account()
+        .with_initial_addresses(2)
+        .with_initial_sync(SyncOptions {
+            address_start_index: 2,
+            address_start_index_internal: 2,
+            sync_pending_transactions: false,
+            min_unused_internal_addresses: 2,
+            ..Default::default()
+        })
+        .finish()
+        .await?;
+    let addresses = account.addresses().await?;
+    assert_eq!(addresses.iter().filter(|address| !*address.internal()).count(), 2);
+    assert_eq!(addresses.iter().filter(|address| *address.internal()).count(), 2);
+    assert_eq!(wallet.get_accounts().await?.len(), 1);
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn testkit_advance_time() -> Result<()> {
+    let storage_path = "test-storage/testkit_advance_time";
+    setup(storage_path)?;
+
+    let wallet = make_offline_wallet(storage_path, RentStructure::default()).await?;
+    let testkit = Testkit::new(&wallet, 1_680_000_000).await?;
+
+    assert_eq!(testkit.advance_time(60).await?, 2);
+    assert_eq!(testkit.time().await, 1_680_000_060);
+    assert_eq!(wallet.client().local_time().await, 1_680_000_060);
+
+    // The time can't go backwards or overflow
+    assert!(matches!(
+        testkit.issue_milestone(1_680_000_000).await,
+        Err(Error::Testkit(_))
+    ));
+    assert!(matches!(testkit.advance_time(u32::MAX).await, Err(Error::Testkit(_))));
+    assert_eq!(testkit.milestone_index().await, 2);
+    assert_eq!(testkit.time().await, 1_680_000_060);
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn testkit_dust_policy_claimable_micro_outputs() -> Result<()> {
+    let storage_path = "test-storage/testkit_dust_policy_claimable_micro_outputs";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let testkit = Testkit::new(&wallet, 1_680_000_000).await?;
+
+    let account = wallet.create_account().finish().await?;
+    let sender = *wallet.create_account().finish().await?.addresses().await?[0].address();
+    account
+        .set_dust_policy(Some(DustPolicy {
+            micro_amount: 1_000_000,
+            decline_below: 100_000,
+            max_micro_outputs: 1,
+            consolidate_above: None,
+        }))
+        .await?;
+
+    let address = *account.addresses().await?[0].address();
+    let token_supply = wallet.client().get_token_supply().await?;
+    let incoming_output = |amount: u64, return_amount: u64| {
+        BasicOutputBuilder::new_with_amount(amount)
+            .add_unlock_condition(AddressUnlockCondition::new(*address.inner()))
+            .add_unlock_condition(StorageDepositReturnUnlockCondition::new(
+                *sender.inner(),
+                return_amount,
+                token_supply,
+            )?)
+            .finish_output(token_supply)
+    };
+
+    // Adds too little
+    let declined = testkit
+        .add_output(&account, incoming_output(1_000_000, 950_000)?)
+        .await?;
+    // Micro outputs, only one fits in the limit
+    let mut micro_outputs = Vec::new();
+    for _ in 0..2 {
+        micro_outputs.push(
+            testkit
+                .add_output(&account, incoming_output(1_000_000, 500_000)?)
+                .await?,
+        );
+    }
+    // Not a micro output
+    let accepted = testkit
+        .add_output(&account, incoming_output(2_000_000, 500_000)?)
+        .await?;
+
+    let claimable = account
+        .get_unlockable_outputs_with_additional_unlock_conditions(OutputsToClaim::All)
+        .await?;
+    assert_eq!(claimable.len(), 2);
+    assert!(claimable.contains(&accepted));
+    assert!(!claimable.contains(&declined));
+    assert_eq!(
+        micro_outputs
+            .iter()
+            .filter(|output_id| claimable.contains(output_id))
+            .count(),
+        1
+    );
+
+    tear_down(storage_path)
+}
+
+// Creates a wallet with an offline client, so the testkit can be used to sign transactions
+async fn make_offline_wallet(storage_path: &str, rent_structure: RentStructure) -> Result<Wallet> {
+    let protocol_parameters = ProtocolParameters::new(
+        2,
+        String::from("offline"),
+        "rms",
+        1500,
+        15,
+        RentStructure::default(),
+        1_813_620_509_061_365,
+    )?;
+    // Nothing listens on the port, so every request to the node would fail
+    let client_options = ClientOptions::new()
+        .with_node("http://127.0.0.1:1")?
+        .with_offline_mode(protocol_parameters)
+        .with_rent_structure(rent_structure);
+    #[allow(unused_mut)]
+    let mut wallet_builder = Wallet::builder()
+        .with_secret_manager(SecretManager::Mnemonic(MnemonicSecretManager::try_from_mnemonic(
+            &Client::generate_mnemonic()?,
+        )?))
+        .with_client_options(client_options)
+        .with_coin_type(SHIMMER_COIN_TYPE);
+    #[cfg(feature = "storage")]
+    {
+        wallet_builder = wallet_builder.with_storage_path(storage_path);
+    }
+    #[cfg(not(feature = "storage"))]
+    let _ = storage_path;
+    let wallet = wallet_builder.finish().await?;
+    assert!(wallet.client().is_offline());
+
+    Ok(wallet)
+}
+
+#[tokio::test]
+async fn testkit_offline_prepare_transaction() -> Result<()> {
+    let storage_path = "test-storage/testkit_offline_prepare_transaction";
+    setup(storage_path)?;
+
+    let rent_structure = RentStructure::new(200, 10, 1);
+    let wallet = make_offline_wallet(storage_path, rent_structure).await?;
+
+    let testkit = Testkit::new(&wallet, 1_700_000_000).await?;
+    let account = wallet.create_account().finish().await?;
+    let input_id = testkit.fund(&account, 1_000_000).await?;
+
+    // The output is built with the rent structure of the offline client
+    let address = *account.addresses().await?[0].address().inner();
+    let output = BasicOutputBuilder::new_with_minimum_storage_deposit(wallet.client().get_rent_structure().await?)
+        .add_unlock_condition(AddressUnlockCondition::new(address))
+        .finish_output(wallet.client().get_token_supply().await?)?;
+    assert_eq!(output.amount(), output.rent_cost(&rent_structure));
+
+    let prepared_transaction = account.prepare_transaction(vec![output.clone()], None).await?;
+    assert_eq!(prepared_transaction.inputs_data.len(), 1);
+    assert_eq!(*prepared_transaction.inputs_data[0].output_id(), input_id);
+    let TransactionEssence::Regular(essence) = &prepared_transaction.essence;
+    assert_eq!(essence.network_id(), wallet.client().get_network_id().await?);
+    assert!(essence.outputs().contains(&output));
+    assert_eq!(essence.outputs().iter().map(Output::amount).sum::<u64>(), 1_000_000);
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn testkit_max_storage_deposit() -> Result<()> {
+    let storage_path = "test-storage/testkit_max_storage_deposit";
+    setup(storage_path)?;
+
+    let rent_structure = RentStructure::new(200, 10, 1);
+    let wallet = make_offline_wallet(storage_path, rent_structure).await?;
+
+    let testkit = Testkit::new(&wallet, 1_700_000_000).await?;
+    let account = wallet.create_account().finish().await?;
+    testkit.fund(&account, 1_000_000).await?;
+
+    let address = *account.addresses().await?[0].address().inner();
+    let output = BasicOutputBuilder::new_with_amount(100_000)
+        .add_unlock_condition(AddressUnlockCondition::new(address))
+        .finish_output(wallet.client().get_token_supply().await?)?;
+    // The input and the remainder have the same storage deposit, so only the one of the new output is required
+    let required = output.rent_cost(&rent_structure);
+    let options = |max_storage_deposit| TransactionOptions {
+        max_storage_deposit: Some(max_storage_deposit),
+        ..Default::default()
+    };
+
+    let err = account
+        .prepare_transaction(vec![output.clone()], options(required - 1))
+        .await
+        .unwrap_err();
+    match err {
+        Error::StorageDepositBudgetExceeded {
+            max,
+            required: err_required,
+        } => {
+            assert_eq!(max, required - 1);
+            assert_eq!(err_required, required);
+        }
+        _ => panic!("unexpected error: {err}"),
+    }
+    // The inputs are unlocked again
+    assert_eq!(account.balance().await?.base_coin().available(), 1_000_000);
+
+    let prepared_transaction = account.prepare_transaction(vec![output], options(required)).await?;
+    assert_eq!(prepared_transaction.inputs_data.len(), 1);
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "storage")]
+#[tokio::test]
+async fn testkit_audit_log_records_transactions() -> Result<()> {
+    let storage_path = "test-storage/testkit_audit_log_records_transactions";
+    setup(storage_path)?;
+
+    let wallet = make_offline_wallet(storage_path, RentStructure::default()).await?;
+    let testkit = Testkit::new(&wallet, 1_700_000_000).await?;
+    let account = wallet.create_account().finish().await?;
+    testkit.fund(&account, 1_000_000).await?;
+    testkit.fund(&account, 1_000_000).await?;
+    let address = *account.addresses().await?[0].address();
+
+    // Not recorded, the audit log is disabled
+    account
+        .send_amount(vec![SendAmountParams::new(address, 1_000_000)], None)
+        .await?;
+
+    wallet.set_audit_log_enabled(true);
+    let transaction = account
+        .send_amount(vec![SendAmountParams::new(address, 1_000_000)], None)
+        .await?;
+
+    // Transactions sent through the Rust API are recorded without a message interface
+    let entries = account.audit_log(AuditLogFilter::default()).await?;
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].method, "submitAndStoreTransaction");
+    assert_eq!(entries[0].transaction_id, Some(transaction.transaction_id));
+    assert_eq!(entries[0].error, None);
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn testkit_primary_alias_remainder() -> Result<()> {
+    let storage_path = "test-storage/testkit_primary_alias_remainder";
+    setup(storage_path)?;
+
+    let wallet = make_offline_wallet(storage_path, RentStructure::default()).await?;
+    let testkit = Testkit::new(&wallet, 1_700_000_000).await?;
+    let account = wallet.create_account().finish().await?;
+    let address = *account.addresses().await?[0].address();
+    assert_eq!(account.primary_address().await?, address);
+
+    // The alias output isn't an output of the account yet
+    let token_supply = wallet.client().get_token_supply().await?;
+    let alias_output_id = testkit
+        .add_output(
+            &account,
+            AliasOutputBuilder::new_with_amount(1_000_000, AliasId::null())
+                .add_unlock_condition(StateControllerAddressUnlockCondition::new(*address.inner()))
+                .add_unlock_condition(GovernorAddressUnlockCondition::new(*address.inner()))
+                .finish_output(token_supply)?,
+        )
+        .await?;
+    let alias_id = AliasId::from(&alias_output_id);
+    let other_alias_id = AliasId::from(&OutputId::new(*alias_output_id.transaction_id(), 1)?);
+    assert!(matches!(
+        account.set_primary_alias(Some(other_alias_id)).await,
+        Err(Error::AliasNotControlled(id)) if id == other_alias_id
+    ));
+
+    account.set_primary_alias(Some(alias_id)).await?;
+    let alias_address = Address::Alias(AliasAddress::from(alias_id));
+    assert_eq!(account.primary_alias().await, Some(alias_id));
+    assert_eq!(*account.primary_address().await?.inner(), alias_address);
+
+    // Prepared transactions lock their inputs, so every transaction gets its own
+    for _ in 0..4 {
+        testkit.fund(&account, 10_000_000).await?;
+    }
+    let recipient = *wallet.create_account().finish().await?.addresses().await?[0].address();
+    let output = BasicOutputBuilder::new_with_amount(1_000_000)
+        .add_unlock_condition(AddressUnlockCondition::new(*recipient.inner()))
+        .finish_output(token_supply)?;
+
+    // The remainder of the default strategy goes to the alias address
+    for options in [
+        None,
+        Some(TransactionOptions {
+            remainder_value_strategy: RemainderValueStrategy::ReuseAddress,
+            ..Default::default()
+        }),
+    ] {
+        let prepared_transaction = account.prepare_transaction(vec![output.clone()], options).await?;
+        let remainder = prepared_transaction
+            .remainder
+            .as_ref()
+            .expect("transaction has a remainder");
+        assert_eq!(remainder.address, alias_address);
+        assert_eq!(
+            remainder
+                .output
+                .unlock_conditions()
+                .unwrap()
+                .address()
+                .unwrap()
+                .address(),
+            &alias_address
+        );
+    }
+
+    // Other strategies aren't changed
+    let prepared_transaction = account
+        .prepare_transaction(
+            vec![output.clone()],
+            TransactionOptions {
+                remainder_value_strategy: RemainderValueStrategy::ChangeAddress,
+                ..Default::default()
+            },
+        )
+        .await?;
+    let remainder_address = prepared_transaction.remainder.as_ref().unwrap().address;
+    assert_ne!(remainder_address, alias_address);
+    assert_ne!(remainder_address, *address.inner());
+
+    // Without the primary alias, the remainder stays on the address of the inputs
+    account.set_primary_alias(None).await?;
+    assert_eq!(account.primary_address().await?, address);
+    let prepared_transaction = account.prepare_transaction(vec![output], None).await?;
+    assert_eq!(
+        prepared_transaction.remainder.as_ref().unwrap().address,
+        *address.inner()
+    );
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn testkit_spending_allowance_charged_on_submit() -> Result<()> {
+    let storage_path = "test-storage/testkit_spending_allowance_charged_on_submit";
+    setup(storage_path)?;
+
+    let wallet = make_offline_wallet(storage_path, RentStructure::default()).await?;
+    let testkit = Testkit::new(&wallet, 1_700_000_000).await?;
+    let account = wallet.create_account().finish().await?;
+    for _ in 0..3 {
+        testkit.fund(&account, 1_000_000).await?;
+    }
+    let address = *account.addresses().await?[0].address();
+    let recipient = *wallet.create_account().finish().await?.addresses().await?[0].address();
+    let token_supply = wallet.client().get_token_supply().await?;
+    account
+        .set_spending_allowance(SpendingAllowance::new("bot", 1_500_000, None))
+        .await?;
+    let options = TransactionOptions {
+        allowance: Some("bot".to_string()),
+        ..Default::default()
+    };
+    let output = |amount: u64| {
+        BasicOutputBuilder::new_with_amount(amount)
+            .add_unlock_condition(AddressUnlockCondition::new(*recipient.inner()))
+            .finish_output(token_supply)
+    };
+
+    // Preparing a transaction doesn't use the allowance
+    let prepared_transaction = account
+        .prepare_transaction(vec![output(1_000_000)?], options.clone())
+        .await?;
+    assert_eq!(account.spending_allowances().await[0].spent, 0);
+
+    let transaction = account.sign_and_submit_transaction(prepared_transaction).await?;
+    let allowance = &account.spending_allowances().await[0];
+    assert_eq!(allowance.spent, 1_000_000);
+    assert_eq!(allowance.usage.len(), 1);
+    assert_eq!(allowance.usage[0].transaction_id, Some(transaction.transaction_id));
+
+    // Exceeding the allowance fails without using it
+    match account.send(vec![output(600_000)?], options.clone()).await {
+        Err(Error::AllowanceExceeded {
+            remaining, required, ..
+        }) => {
+            assert_eq!(remaining, 500_000);
+            assert_eq!(required, 600_000);
+        }
+        other => panic!("expected AllowanceExceeded, got {other:?}"),
+    }
+    assert_eq!(account.spending_allowances().await[0].spent, 1_000_000);
+
+    // The storage deposit that is returned to the account isn't charged
+    let output = BasicOutputBuilder::new_with_amount(600_000)
+        .add_unlock_condition(AddressUnlockCondition::new(*recipient.inner()))
+        .add_unlock_condition(StorageDepositReturnUnlockCondition::new(
+            *address.inner(),
+            300_000,
+            token_supply,
+        )?)
+        .finish_output(token_supply)?;
+    account.send(vec![output], options).await?;
+    assert_eq!(account.spending_allowances().await[0].spent, 1_300_000);
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn testkit_send_from_accounts() -> Result<()> {
+    let storage_path = "test-storage/testkit_send_from_accounts";
+    setup(storage_path)?;
+
+    let wallet = make_offline_wallet(storage_path, RentStructure::default()).await?;
+    let testkit = Testkit::new(&wallet, 1_700_000_000).await?;
+    let account_0 = wallet.create_account().with_alias("a".to_string()).finish().await?;
+    let account_1 = wallet.create_account().with_alias("b".to_string()).finish().await?;
+    testkit.fund(&account_0, 1_000_000).await?;
+    testkit.fund(&account_1, 1_000_000).await?;
+    let recipient = *wallet.create_account().finish().await?.addresses().await?[0].address();
+    let output = BasicOutputBuilder::new_with_amount(1_500_000)
+        .add_unlock_condition(AddressUnlockCondition::new(*recipient.inner()))
+        .finish_output(wallet.client().get_token_supply().await?)?;
+    let options = TransactionOptions {
+        note: Some("shared".to_string()),
+        ..Default::default()
+    };
+
+    // Nothing is prepared if one of the accounts exceeds its rate limit
+    account_1
+        .set_rate_limit(Some(TransactionRateLimit {
+            max_per_minute: Some(0),
+            ..Default::default()
+        }))
+        .await?;
+    assert!(matches!(
+        wallet
+            .send_from_accounts(vec!["a".into(), "b".into()], vec![output.clone()], options.clone())
+            .await,
+        Err(Error::RateLimitExceeded { .. })
+    ));
+    assert!(account_0.details().await.locked_outputs().is_empty());
+    assert!(account_1.details().await.locked_outputs().is_empty());
+    account_1.set_rate_limit(None).await?;
+    account_0.set_transaction_sequencing(true).await?;
+
+    let transaction = wallet
+        .send_from_accounts(vec!["a".into(), "b".into()], vec![output], options)
+        .await?;
+    assert_eq!(transaction.inputs.len(), 2);
+
+    // Stored in both accounts with the note, only the account with the transaction sequencing gives it a sequence
+    // number
+    for (account, sequence_number) in [(&account_0, Some(0)), (&account_1, None)] {
+        let stored_transaction = account.get_transaction(&transaction.transaction_id).await.unwrap();
+        assert_eq!(stored_transaction.note.as_deref(), Some("shared"));
+        assert_eq!(stored_transaction.sequence_number, sequence_number);
+    }
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn testkit_remainder_split_to_addresses() -> Result<()> {
+    let storage_path = "test-storage/testkit_remainder_split_to_addresses";
+    setup(storage_path)?;
+
+    let wallet = make_offline_wallet(storage_path, RentStructure::default()).await?;
+    let testkit = Testkit::new(&wallet, 1_700_000_000).await?;
+    let account = wallet.create_account().finish().await?;
+    testkit.fund(&account, 3_000_000).await?;
+    let own_address = *account.addresses().await?[0].address();
+    let recipient = *wallet.create_account().finish().await?.addresses().await?[0].address();
+    let outsider = *wallet.create_account().finish().await?.addresses().await?[0].address();
+    wallet
+        .set_address_book(AddressBook {
+            verification_enabled: true,
+            entries: [(recipient, "recipient".to_string())].into_iter().collect(),
+            ..Default::default()
+        })
+        .await?;
+    let params = || vec![SendAmountParams::new(recipient, 1_000_000)];
+    let options = |addresses: Vec<_>| TransactionOptions {
+        remainder_value_strategy: RemainderValueStrategy::Split {
+            addresses,
+            weights: vec![1, 1],
+        },
+        ..Default::default()
+    };
+
+    // The split outputs are verified like the provided ones
+    match account
+        .prepare_send_amount(params(), options(vec![own_address, outsider]))
+        .await
+    {
+        Err(Error::AddressNotInAddressBook(address)) => assert_eq!(address.inner(), outsider.inner()),
+        other => panic!("expected AddressNotInAddressBook, got {other:?}"),
+    }
+    assert!(account.details().await.locked_outputs().is_empty());
+
+    // A remainder on an address of the account can be derived by a Ledger Nano
+    let prepared_transaction = account
+        .prepare_send_amount(params(), options(vec![own_address, recipient]))
+        .await?;
+    let TransactionEssence::Regular(essence) = &prepared_transaction.essence;
+    assert_eq!(essence.outputs().len(), 3);
+    assert!(prepared_transaction.remainder.unwrap().chain.is_some());
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn testkit_address_book_return_addresses() -> Result<()> {
+    let storage_path = "test-storage/testkit_address_book_return_addresses";
+    setup(storage_path)?;
+
+    let wallet = make_offline_wallet(storage_path, RentStructure::default()).await?;
+    let testkit = Testkit::new(&wallet, 1_700_000_000).await?;
+    let account = wallet.create_account().finish().await?;
+    testkit.fund(&account, 1_000_000).await?;
+    let recipient = *wallet.create_account().finish().await?.addresses().await?[0].address();
+    let outsider = *wallet.create_account().finish().await?.addresses().await?[0].address();
+    wallet
+        .set_address_book(AddressBook {
+            verification_enabled: true,
+            entries: [(recipient, "recipient".to_string())].into_iter().collect(),
+            ..Default::default()
+        })
+        .await?;
+    // The recipient is known, but the output returns to an unknown address once it expires
+    let output = BasicOutputBuilder::new_with_amount(500_000)
+        .add_unlock_condition(AddressUnlockCondition::new(*recipient.inner()))
+        .add_unlock_condition(ExpirationUnlockCondition::new(*outsider.inner(), 1_800_000_000)?)
+        .finish_output(wallet.client().get_token_supply().await?)?;
+
+    match account.prepare_transaction(vec![output.clone()], None).await {
+        Err(Error::AddressNotInAddressBook(address)) => assert_eq!(address.inner(), outsider.inner()),
+        other => panic!("expected AddressNotInAddressBook, got {other:?}"),
+    }
+    assert!(account.details().await.locked_outputs().is_empty());
+
+    // Forced transactions skip the verification
+    let options = TransactionOptions {
+        force: true,
+        ..Default::default()
+    };
+    let prepared_transaction = account.prepare_transaction(vec![output.clone()], options).await?;
+    let TransactionEssence::Regular(essence) = &prepared_transaction.essence;
+    assert!(essence.outputs().contains(&output));
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn testkit_confirmation_depth_stored() -> Result<()> {
+    let storage_path = "test-storage/testkit_confirmation_depth_stored";
+    setup(storage_path)?;
+
+    let wallet = make_offline_wallet(storage_path, RentStructure::default()).await?;
+    let testkit = Testkit::new(&wallet, 1_700_000_000).await?;
+    let account = wallet.create_account().finish().await?;
+    testkit.fund(&account, 1_000_000).await?;
+    testkit.fund(&account, 1_000_000).await?;
+    let recipient = *wallet.create_account().finish().await?.addresses().await?[0].address();
+    let params = || vec![SendAmountParams::new(recipient, 1_000_000)];
+    let options = |confirmation_depth| TransactionOptions {
+        confirmation_depth,
+        ..Default::default()
+    };
+
+    // Prepared transactions keep their own confirmation depth, also if they're submitted in another order
+    let deep_transaction = account.prepare_send_amount(params(), options(Some(3))).await?;
+    let other_transaction = account.prepare_send_amount(params(), options(None)).await?;
+    let other_transaction = account.sign_and_submit_transaction(other_transaction).await?;
+    let deep_transaction = account.sign_and_submit_transaction(deep_transaction).await?;
+    assert_eq!(other_transaction.confirmation_depth, None);
+    assert_eq!(deep_transaction.confirmation_depth, Some(3));
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn testkit_retry_cancelled() -> Result<()> {
+    let storage_path = "test-storage/testkit_retry_cancelled";
+    setup(storage_path)?;
+
+    let wallet = make_offline_wallet(storage_path, RentStructure::default()).await?;
+    let testkit = Testkit::new(&wallet, 1_700_000_000).await?;
+    let account = wallet.create_account().finish().await?;
+    testkit.fund(&account, 1_000_000).await?;
+    let recipient = *wallet.create_account().finish().await?.addresses().await?[0].address();
+    let transaction = account
+        .send_amount(vec![SendAmountParams::new(recipient, 1_000_000)], None)
+        .await?;
+
+    // Cancelled before the retrying started, it stops without sending any request
+    let handle = account.retry_transaction_until_included_with_handle(transaction.transaction_id, None, None);
+    handle.cancel();
+    assert!(matches!(
+        handle.wait().await,
+        Err(Error::RetryCancelled(transaction_id)) if transaction_id == transaction.transaction_id
+    ));
+    assert_eq!(
+        account
+            .get_transaction(&transaction.transaction_id)
+            .await
+            .unwrap()
+            .inclusion_state,
+        InclusionState::Pending
+    );
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn testkit_remainder_address_reserved() -> Result<()> {
+    let storage_path = "test-storage/testkit_remainder_address_reserved";
+    setup(storage_path)?;
+
+    let wallet = make_offline_wallet(storage_path, RentStructure::default()).await?;
+    let testkit = Testkit::new(&wallet, 1_700_000_000).await?;
+    let account = wallet.create_account().finish().await?;
+    testkit.fund(&account, 1_000_000).await?;
+    testkit.fund(&account, 1_000_000).await?;
+    let recipient = *wallet.create_account().finish().await?.addresses().await?[0].address();
+    let params = |amount| vec![SendAmountParams::new(recipient, amount)];
+    let options = TransactionOptions {
+        remainder_value_strategy: RemainderValueStrategy::ChangeAddress,
+        ..Default::default()
+    };
+
+    // Transactions that are prepared at the same time get different change addresses
+    let prepared_transaction = account.prepare_send_amount(params(500_000), options.clone()).await?;
+    let other_prepared_transaction = account.prepare_send_amount(params(500_000), options.clone()).await?;
+    let remainder_address = prepared_transaction.remainder.as_ref().unwrap().address;
+    assert_ne!(remainder_address, other_prepared_transaction.remainder.unwrap().address);
+    let status = account.internal_address_pool_status().await;
+    assert_eq!((status.total, status.unused), (2, 0));
+
+    // The change address of a submitted transaction stays unavailable while it's pending
+    account.sign_and_submit_transaction(prepared_transaction).await?;
+    assert_eq!(account.internal_address_pool_status().await.unused, 0);
+
+    // The change address of a transaction that can't be prepared is released
+    assert!(matches!(
+        account.prepare_send_amount(params(5_000_000), options).await,
+        Err(Error::Client(_))
+    ));
+    let status = account.internal_address_pool_status().await;
+    assert_eq!((status.total, status.unused), (3, 1));
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn testkit_transaction_sequence() -> Result<()> {
+    let storage_path = "test-storage/testkit_transaction_sequence";
+    setup(storage_path)?;
+
+    let wallet = make_offline_wallet(storage_path, RentStructure::default()).await?;
+    let testkit = Testkit::new(&wallet, 1_700_000_000).await?;
+    let account = wallet.create_account().finish().await?;
+    testkit.fund(&account, 1_000_000).await?;
+    testkit.fund(&account, 1_000_000).await?;
+    testkit.fund(&account, 1_000_000).await?;
+    let recipient = *wallet.create_account().finish().await?.addresses().await?[0].address();
+    let params = || vec![SendAmountParams::new(recipient, 1_000_000)];
+
+    // Without the transaction sequencing, sent transactions don't get a sequence number
+    let transaction = account.send_amount(params(), None).await?;
+    assert!(transaction.sequence_number.is_none());
+    assert!(account.last_sequence_number().await.is_none());
+
+    account.set_transaction_sequencing(true).await?;
+    let first_transaction = account.send_amount(params(), None).await?;
+    let second_transaction = account.send_amount(params(), None).await?;
+    assert_eq!(first_transaction.sequence_number, Some(0));
+    assert_eq!(second_transaction.sequence_number, Some(1));
+    assert_eq!(account.last_sequence_number().await, Some(1));
+    assert_eq!(
+        account
+            .get_transaction_by_sequence_number(1)
+            .await
+            .map(|transaction| transaction.transaction_id),
+        Some(second_transaction.transaction_id)
+    );
+
+    // The transactions couldn't be submitted offline, waiting for them doesn't send them again
+    assert!(matches!(
+        account.wait_for_sequence_confirmed(1, Some(0), Some(2)).await,
+        Err(Error::Client(_))
+    ));
+    for transaction in [first_transaction, second_transaction] {
+        let transaction = account.get_transaction(&transaction.transaction_id).await.unwrap();
+        assert!(transaction.block_id.is_none());
+        assert_eq!(transaction.inclusion_state, InclusionState::Pending);
+    }
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "wallet-consolidation")]
+#[tokio::test]
+async fn testkit_consolidate_all_outputs_interrupted() -> Result<()> {
+    let storage_path = "test-storage/testkit_consolidate_all_outputs_interrupted";
+    setup(storage_path)?;
+
+    let wallet = make_offline_wallet(storage_path, RentStructure::default()).await?;
+    let testkit = Testkit::new(&wallet, 1_700_000_000).await?;
+    let account = wallet.create_account().finish().await?;
+    // More outputs than fit into a single transaction
+    for _ in 0..130 {
+        testkit.fund(&account, 100_000).await?;
+    }
+
+    // The first transaction is sent, but it can't be confirmed without a node
+    let Err(Error::ConsolidationInterrupted { transaction_ids, .. }) = account
+        .consolidate_all_outputs(ConsolidationParams {
+            force: true,
+            ..Default::default()
+        })
+        .await
+    else {
+        panic!("expected the consolidation to be interrupted");
+    };
+    assert_eq!(transaction_ids.len(), 1);
+    let transaction = account.get_transaction(&transaction_ids[0]).await.unwrap();
+    let TransactionEssence::Regular(essence) = transaction.payload.essence();
+    assert_eq!(essence.inputs().len(), INPUT_COUNT_MAX as usize);
+    assert_eq!(account.pending_transactions().await.len(), 1);
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "wallet-consolidation")]
+#[tokio::test]
+async fn testkit_consolidate_to_target_address_split_native_tokens() -> Result<()> {
+    let storage_path = "test-storage/testkit_consolidate_to_target_address_split_native_tokens";
+    setup(storage_path)?;
+
+    let wallet = make_offline_wallet(storage_path, RentStructure::default()).await?;
+    let testkit = Testkit::new(&wallet, 1_700_000_000).await?;
+    let account = wallet.create_account().finish().await?;
+    let address = *account.addresses().await?[0].address().inner();
+    let token_supply = wallet.client().get_token_supply().await?;
+    for byte in 0..3 {
+        let output = BasicOutputBuilder::new_with_amount(200_000)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .add_native_token(NativeToken::new(TokenId::new([byte; TokenId::LENGTH]), U256::from(10))?)
+            .finish_output(token_supply)?;
+        testkit.add_output(&account, output).await?;
+    }
+    let target_address = *wallet.create_account().finish().await?.addresses().await?[0].address();
+
+    let prepared_transaction = account
+        .prepare_consolidate_outputs(ConsolidationParams {
+            force: true,
+            target_address: Some(target_address),
+            split_native_tokens: true,
+            ..Default::default()
+        })
+        .await?;
+    let TransactionEssence::Regular(essence) = &prepared_transaction.essence;
+    assert_eq!(essence.inputs().len(), 3);
+    // Every native token gets its own output at the target address
+    assert_eq!(essence.outputs().len(), 3);
+    for output in essence.outputs().iter() {
+        assert_eq!(output.native_tokens().unwrap().len(), 1);
+        assert_eq!(
+            output.unlock_conditions().unwrap().address().unwrap().address(),
+            target_address.inner()
+        );
+    }
+    assert_eq!(essence.outputs().iter().map(Output::amount).sum::<u64>(), 600_000);
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "message-interface")]
+#[tokio::test]
+async fn testkit_dry_run_has_no_side_effects() -> Result<()> {
+    let storage_path = "test-storage/testkit_dry_run_has_no_side_effects";
+    setup(storage_path)?;
+
+    let wallet = make_offline_wallet(storage_path, RentStructure::default()).await?;
+    let testkit = Testkit::new(&wallet, 1_700_000_000).await?;
+    let account = wallet.create_account().with_alias("alias".to_string()).finish().await?;
+    testkit.fund(&account, 1_000_000).await?;
+    testkit.fund(&account, 1_000_000).await?;
+    account
+        .set_spending_allowance(SpendingAllowance::new("bot", 2_000_000, None))
+        .await?;
+    let recipient = *wallet.create_account().finish().await?.addresses().await?[0].address();
+    let message_handler = WalletMessageHandler::with_manager(wallet.clone());
+
+    let details_before = account.details().await.clone();
+    let response = message_handler
+        .send_message(Message::CallAccountMethod {
+            account_id: "alias".into(),
+            method: AccountMethod::SendAmount {
+                params: vec![SendAmountParams::new(recipient, 2_000_000)],
+                options: Some(serde_json::from_value(serde_json::json!({ "allowance": "bot" })).unwrap()),
+            },
+            audit_context: None,
+            dry_run: true,
+        })
+        .await;
+    let Response::PreparedTransaction(prepared_transaction) = response else {
+        panic!("unexpected response {response:?}");
+    };
+    assert_eq!(prepared_transaction.inputs_data.len(), 2);
+
+    // Neither the inputs are locked nor the allowance is used
+    assert_eq!(*account.details().await, details_before);
+    account
+        .send_amount(
+            vec![SendAmountParams::new(recipient, 2_000_000)],
+            TransactionOptions {
+                allowance: Some("bot".to_string()),
+                ..Default::default()
+            },
+        )
+        .await?;
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn testkit_send_from_template() -> Result<()> {
+    let storage_path = "test-storage/testkit_send_from_template";
+    setup(storage_path)?;
+
+    let wallet = make_offline_wallet(storage_path, RentStructure::default()).await?;
+    let testkit = Testkit::new(&wallet, 1_700_000_000).await?;
+    let account = wallet.create_account().finish().await?;
+    testkit.fund(&account, 2_000_000).await?;
+    let recipient = *wallet.create_account().finish().await?.addresses().await?[0].address();
+    let template = serde_json::from_value::<TransactionTemplate>(serde_json::json!({
+        "outputs": [{ "address": "{recipient}", "amount": "{millions}000000" }],
+        "options": { "note": "payout" },
+    }))
+    .unwrap();
+    wallet.set_transaction_template("payout", template).await?;
+
+    let mut params = HashMap::from([("recipient".to_string(), recipient.to_string())]);
+    assert!(matches!(
+        account.prepare_send_from_template("payout", &params).await,
+        Err(Error::MissingTemplateParameter(name)) if name == "millions"
+    ));
+
+    params.insert("millions".to_string(), "1".to_string());
+    let transaction = account.send_from_template("payout", &params).await?;
+    assert_eq!(transaction.note.as_deref(), Some("payout"));
+    let TransactionEssence::Regular(essence) = transaction.payload.essence();
+    assert!(essence.outputs().iter().any(|output| {
+        output.amount() == 1_000_000
+            && output.unlock_conditions().unwrap().address().unwrap().address() == recipient.inner()
+    }));
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn testkit_send_nfts_isolates_failing_nft() -> Result<()> {
+    let storage_path = "test-storage/testkit_send_nfts_isolates_failing_nft";
+    setup(storage_path)?;
+
+    let wallet = make_offline_wallet(storage_path, RentStructure::default()).await?;
+    let testkit = Testkit::new(&wallet, 1_700_000_000).await?;
+    let account = wallet.create_account().finish().await?;
+    let address = *account.addresses().await?[0].address().inner();
+    let recipient = *wallet.create_account().finish().await?.addresses().await?[0].address();
+    let token_supply = wallet.client().get_token_supply().await?;
+
+    let mut nfts = Vec::new();
+    for timelocked in [false, true, false] {
+        let mut output_builder = NftOutputBuilder::new_with_amount(1_000_000, NftId::null())
+            .add_unlock_condition(AddressUnlockCondition::new(address));
+        if timelocked {
+            output_builder = output_builder.add_unlock_condition(TimelockUnlockCondition::new(1_800_000_000)?);
+        }
+        let output_id = testkit
+            .add_output(&account, output_builder.finish_output(token_supply)?)
+            .await?;
+        nfts.push((NftId::from(&output_id), recipient));
+    }
+    let unknown_nft = NftId::from([1; NftId::LENGTH]);
+    nfts.push((unknown_nft, recipient));
+
+    // The timelocked NFT makes the transaction fail, the NFTs before and after it are still sent
+    let results = account.send_nfts(nfts.clone(), None).await?;
+    assert_eq!(
+        results.iter().map(|result| result.nft_id).collect::<Vec<_>>(),
+        nfts.iter().map(|(nft_id, _)| *nft_id).collect::<Vec<_>>()
+    );
+    let first_transaction_id = *results[0].result.as_ref().unwrap();
+    assert!(results[1].result.is_err());
+    assert_ne!(*results[2].result.as_ref().unwrap(), first_transaction_id);
+    assert!(matches!(results[3].result, Err(Error::NftNotFoundInUnspentOutputs)));
+    assert_eq!(account.pending_transactions().await.len(), 2);
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "wallet-participation")]
+#[tokio::test]
+async fn testkit_auto_revote_withdrawn_participations() -> Result<()> {
+    let storage_path = "test-storage/testkit_auto_revote_withdrawn_participations";
+    setup(storage_path)?;
+
+    let wallet = make_offline_wallet(storage_path, RentStructure::default()).await?;
+    let testkit = Testkit::new(&wallet, 1_700_000_000).await?;
+    let account = wallet.create_account().finish().await?;
+    account.set_auto_revote(true).await?;
+    testkit.fund(&account, 2_000_000).await?;
+    let address = *account.addresses().await?[0].address();
+    let participations = Participations {
+        participations: vec![Participation {
+            event_id: ParticipationEventId::new([1; 32]),
+            answers: vec![0],
+        }],
+    };
+    let voting_output = BasicOutputBuilder::new_with_amount(1_000_000)
+        .add_unlock_condition(AddressUnlockCondition::new(address))
+        .add_feature(TagFeature::new(PARTICIPATION_TAG)?)
+        .add_feature(MetadataFeature::new(participations.to_bytes()?)?)
+        .finish_output(wallet.client().get_token_supply().await?)?;
+    testkit.add_output(&account, voting_output).await?;
+    assert_eq!(account.get_voting_power().await?, 1_000_000);
+
+    // Removing the whole voting power withdraws the participations, so there's nothing to revote
+    let transaction = account.decrease_voting_power(1_000_000).await?;
+    assert_eq!(transaction.note, None);
+    let TransactionEssence::Regular(essence) = transaction.payload.essence();
+    assert!(essence.payload().is_none());
+    assert!(essence
+        .outputs()
+        .iter()
+        .all(|output| output.features().map_or(true, |features| features.is_empty())));
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "wallet-participation")]
+#[tokio::test]
+async fn testkit_set_voting_power() -> Result<()> {
+    let storage_path = "test-storage/testkit_set_voting_power";
+    setup(storage_path)?;
+
+    let wallet = make_offline_wallet(storage_path, RentStructure::default()).await?;
+    let testkit = Testkit::new(&wallet, 1_700_000_000).await?;
+    let token_supply = wallet.client().get_token_supply().await?;
+
+    // Returns the amounts of the voting outputs of the prepared transaction
+    let voting_output_amounts = |essence: &TransactionEssence| {
+        let TransactionEssence::Regular(essence) = essence;
+        essence
+            .outputs()
+            .iter()
+            .filter(|output| {
+                output.features().map_or(false, |features| {
+                    features
+                        .tag()
+                        .map_or(false, |tag| tag.tag() == PARTICIPATION_TAG.as_bytes())
+                })
+            })
+            .map(Output::amount)
+            .collect::<Vec<_>>()
+    };
+
+    // Increased from no voting power, increased and decreased by the difference, the current voting output is always
+    // replaced. Prepared transactions lock their inputs, so every case uses a new account.
+    for (voting_power, target_amount) in [(0, 2_000_000), (1_000_000, 3_000_000), (1_000_000, 500_000)] {
+        let account = wallet.create_account().finish().await?;
+        testkit.fund(&account, 5_000_000).await?;
+        let voting_output_id = if voting_power > 0 {
+            let address = *account.addresses().await?[0].address();
+            let voting_output = BasicOutputBuilder::new_with_amount(voting_power)
+                .add_unlock_condition(AddressUnlockCondition::new(address))
+                .add_feature(TagFeature::new(PARTICIPATION_TAG)?)
+                .finish_output(token_supply)?;
+            Some(testkit.add_output(&account, voting_output).await?)
+        } else {
+            None
+        };
+        assert_eq!(account.get_voting_power().await?, voting_power);
+
+        let prepared_transaction = account.prepare_set_voting_power(target_amount).await?.unwrap();
+        assert_eq!(
+            voting_output_amounts(&prepared_transaction.essence),
+            vec![target_amount]
+        );
+        if let Some(voting_output_id) = voting_output_id {
+            assert!(prepared_transaction
+                .inputs_data
+                .iter()
+                .any(|input| *input.output_id() == voting_output_id));
+        }
+    }
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "events")]
+#[tokio::test]
+async fn testkit_sync_all_summary_event() -> Result<()> {
+    use iota_sdk::wallet::events::types::{WalletEvent, WalletEventType};
+
+    let storage_path = "test-storage/testkit_sync_all_summary_event";
+    setup(storage_path)?;
+
+    let wallet = make_offline_wallet(storage_path, RentStructure::default()).await?;
+    wallet.create_account().finish().await?;
+    wallet.create_account().finish().await?;
+
+    let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+    wallet
+        .listen(vec![WalletEventType::SyncSummary], move |event| {
+            sender.send(event.clone()).ok();
+        })
+        .await;
+
+    // The node isn't reachable, so syncing fails for every account
+    let results = wallet.sync_all(None, 2).await;
+    assert!(results.iter().all(|result| result.result.is_err()));
+
+    let event = receiver.try_recv().expect("no sync summary was emitted");
+    assert_eq!(event.account_index, None);
+    let WalletEvent::SyncSummary(summary) = event.event else {
+        panic!("expected a sync summary");
+    };
+    assert!(summary.synced_accounts.is_empty());
+    assert_eq!(summary.failed_accounts, vec![0, 1]);
+
+    tear_down(storage_path)
+}
+
+#[tokio::test]
+async fn testkit_pause_resume_background_syncing() -> Result<()> {
+    use iota_sdk::wallet::BackgroundSyncStatus;
+
+    let storage_path = "test-storage/testkit_pause_resume_background_syncing";
+    setup(storage_path)?;
+
+    let wallet = make_offline_wallet(storage_path, RentStructure::default()).await?;
+    wallet.create_account().finish().await?;
+
+    // Only a running process can be paused and only a paused one resumed
+    assert_eq!(wallet.background_syncing_status(), BackgroundSyncStatus::Stopped);
+    assert!(!wallet.pause_background_syncing());
+    assert!(!wallet.resume_background_syncing());
+
+    wallet
+        .start_background_syncing(None, Some(std::time::Duration::from_secs(1)))
+        .await?;
+    assert_eq!(wallet.background_syncing_status(), BackgroundSyncStatus::Running);
+    assert!(!wallet.resume_background_syncing());
+
+    assert!(wallet.pause_background_syncing());
+    assert_eq!(wallet.background_syncing_status(), BackgroundSyncStatus::Paused);
+    assert!(!wallet.pause_background_syncing());
+    // The paused process keeps running
+    tokio::time::sleep(std::time::Duration::from_secs(2)).await;
+    assert_eq!(wallet.background_syncing_status(), BackgroundSyncStatus::Paused);
+
+    assert!(wallet.resume_background_syncing());
+    assert_eq!(wallet.background_syncing_status(), BackgroundSyncStatus::Running);
+
+    // A paused process is stopped as well
+    assert!(wallet.pause_background_syncing());
+    wallet.stop_background_syncing().await?;
+    assert_eq!(wallet.background_syncing_status(), BackgroundSyncStatus::Stopped);
+
+    // Starting again replaces a paused process
+    wallet
+        .start_background_syncing(None, Some(std::time::Duration::from_secs(1)))
+        .await?;
+    assert!(wallet.pause_background_syncing());
+    wallet
+        .start_background_syncing(None, Some(std::time::Duration::from_secs(1)))
+        .await?;
+    assert_eq!(wallet.background_syncing_status(), BackgroundSyncStatus::Running);
+    wallet.stop_background_syncing().await?;
+    assert_eq!(wallet.background_syncing_status(), BackgroundSyncStatus::Stopped);
+
+    tear_down(storage_path)
+}
@@ -1,7 +1,16 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use iota_sdk::wallet::{account::TransactionOptions, MintNftParams, Result, SendAmountParams, SendNftParams};
+use iota_sdk::{
+    types::block::{
+        output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder, NftId},
+        payload::transaction::TransactionEssence,
+    },
+    wallet::{
+        account::{SweepOptions, TransactionOptions},
+        AssetTransfer, Error, MintNftParams, Result, SendAmountParams, SendNftParams,
+    },
+};
 
 use crate::wallet::common::{create_accounts_with_funds, make_wallet, setup, tear_down};
 
@@ -37,6 +46,155 @@ async fn send_amount() -> Result<()> {
     tear_down(storage_path)
 }
 
+#[ignore]
+#[tokio::test]
+async fn estimate_transaction() -> Result<()> {
+    let storage_path = "test-storage/estimate_transaction";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+    let address = *account_1.addresses().await?[0].address();
+
+    let amount = 1_000_000;
+    let output = BasicOutputBuilder::new_with_amount(amount)
+        .add_unlock_condition(AddressUnlockCondition::new(address))
+        .finish_output(account_0.client().get_token_supply().await?)?;
+    let estimate = account_0.estimate_transaction(vec![output.clone()], None).await?;
+
+    assert_eq!(estimate.outputs.len(), 1);
+    assert_eq!(estimate.outputs[0].amount, amount);
+    assert!(estimate.outputs[0].required_storage_deposit <= amount);
+    assert!(!estimate.requires_claiming);
+    assert!(estimate.input_count > 0);
+    assert_eq!(
+        estimate.inputs_amount,
+        amount + estimate.remainder_amount.unwrap_or_default()
+    );
+
+    // The inputs aren't locked, so the transaction can still be sent with them
+    let prepared_transaction = account_0.prepare_transaction(vec![output], None).await?;
+    assert_eq!(prepared_transaction.inputs_data.len(), estimate.input_count);
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn send_from_accounts() -> Result<()> {
+    let storage_path = "test-storage/send_from_accounts";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let accounts = create_accounts_with_funds(&wallet, 2).await?;
+    let account_2 = wallet.create_account().finish().await?;
+
+    // More than each of the accounts owns
+    let amount = accounts[0]
+        .balance()
+        .await?
+        .base_coin()
+        .available()
+        .max(accounts[1].balance().await?.base_coin().available())
+        + 1_000_000;
+    let output = BasicOutputBuilder::new_with_amount(amount)
+        .add_unlock_condition(AddressUnlockCondition::new(*account_2.addresses().await?[0].address()))
+        .finish_output(wallet.client().get_token_supply().await?)?;
+
+    // The same account can't be provided twice
+    assert!(matches!(
+        wallet
+            .prepare_multi_account_transaction(vec![0.into(), 0.into()], vec![output.clone()], None)
+            .await,
+        Err(Error::InvalidMultiAccountTransaction(_))
+    ));
+
+    let tx = wallet
+        .send_from_accounts(vec![0.into(), 1.into()], vec![output], None)
+        .await?;
+
+    // Stored in both accounts
+    assert!(accounts[1].get_transaction(&tx.transaction_id).await.is_some());
+
+    accounts[0]
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+
+    let balance = account_2.sync(None).await.unwrap();
+    assert_eq!(balance.base_coin().available(), amount);
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn normalized_transaction() -> Result<()> {
+    let storage_path = "test-storage/normalized_transaction";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+    let address = *account_1.addresses().await?[0].address();
+
+    let amount = 1_000_000;
+    let tx = account_0
+        .send_amount(vec![SendAmountParams::new(address, amount)], None)
+        .await?;
+
+    let normalized = tx.normalized(*address.hrp());
+    assert_eq!(normalized.inputs.len(), tx.inputs.len());
+    assert!(normalized.inputs.iter().all(|input| input.amount.is_some()));
+    assert!(
+        normalized
+            .outputs
+            .iter()
+            .any(|output| output.address == Some(address) && output.amount == amount)
+    );
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn trace_output() -> Result<()> {
+    let storage_path = "test-storage/trace_output";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+    let address = *account_1.addresses().await?[0].address();
+
+    let amount = 1_000_000;
+    let tx = account_0
+        .send_amount(vec![SendAmountParams::new(address, amount)], None)
+        .await?;
+    account_0
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+    account_0.sync(None).await?;
+
+    let input_output_id = tx.inputs[0].metadata.output_id().unwrap();
+    let lineage = account_0.trace_output(input_output_id).await?;
+    assert_eq!(lineage.entries[0].output_id, input_output_id);
+    assert_eq!(lineage.entries[0].spent_in, Some(tx.transaction_id));
+    // The output sent to the other account is part of the lineage, but isn't traced further
+    assert!(
+        lineage
+            .entries
+            .iter()
+            .any(|entry| entry.address == Some(address) && entry.amount == amount && !entry.owned)
+    );
+
+    tear_down(storage_path)
+}
+
 #[ignore]
 #[tokio::test]
 async fn send_amount_127_outputs() -> Result<()> {
@@ -139,7 +297,7 @@ async fn send_nft() -> Result<()> {
         immutable_metadata: Some(b"some immutable nft metadata".to_vec()),
     }];
 
-    let transaction = accounts[0].mint_nfts(nft_options, None).await.unwrap();
+    let transaction = accounts[0].mint_nfts(nft_options, None).await.unwrap().transaction;
     accounts[0]
         .retry_transaction_until_included(&transaction.transaction_id, None, None)
         .await?;
@@ -166,3 +324,165 @@ async fn send_nft() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[ignore]
+#[tokio::test]
+async fn send_nfts() -> Result<()> {
+    let storage_path = "test-storage/send_nfts";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let accounts = &create_accounts_with_funds(&wallet, 2).await?;
+
+    let nft_options = (0..2)
+        .map(|_| MintNftParams {
+            address: None,
+            sender: None,
+            metadata: None,
+            tag: None,
+            issuer: None,
+            immutable_metadata: Some(b"some immutable nft metadata".to_vec()),
+        })
+        .collect();
+
+    let transaction = accounts[0].mint_nfts(nft_options, None).await?.transaction;
+    accounts[0]
+        .retry_transaction_until_included(&transaction.transaction_id, None, None)
+        .await?;
+    let nft_ids = accounts[0].sync(None).await?.nfts().clone();
+    assert_eq!(nft_ids.len(), 2);
+
+    let address = *accounts[1].addresses().await?[0].address();
+    let mut nfts = nft_ids.iter().map(|nft_id| (*nft_id, address)).collect::<Vec<_>>();
+    // An NFT that the account doesn't own fails on its own
+    nfts.push((NftId::from([1; 32]), address));
+
+    let results = accounts[0].send_nfts(nfts, None).await?;
+    assert_eq!(results.len(), 3);
+    assert!(results[0].result.is_ok());
+    assert_eq!(results[0].result.as_ref().ok(), results[1].result.as_ref().ok());
+    assert!(results[2].result.is_err());
+
+    accounts[0]
+        .retry_transaction_until_included(results[0].result.as_ref().unwrap(), None, None)
+        .await?;
+    let balance = accounts[1].sync(None).await?;
+    assert_eq!(balance.nfts().len(), 2);
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn send_assets() -> Result<()> {
+    let storage_path = "test-storage/send_assets";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let accounts = &create_accounts_with_funds(&wallet, 2).await?;
+
+    let nft_options = vec![MintNftParams {
+        address: None,
+        sender: None,
+        metadata: None,
+        tag: None,
+        issuer: None,
+        immutable_metadata: Some(b"some immutable nft metadata".to_vec()),
+    }];
+
+    let transaction = accounts[0].mint_nfts(nft_options, None).await.unwrap().transaction;
+    accounts[0]
+        .retry_transaction_until_included(&transaction.transaction_id, None, None)
+        .await?;
+    let nft_id = *accounts[0].sync(None).await?.nfts().first().unwrap();
+
+    // Both transfers go to the same address, so the amount is added to the nft output
+    let address = *accounts[1].addresses().await?[0].address();
+    let amount = 1_000_000;
+    let transaction = accounts[0]
+        .send_assets(
+            vec![
+                AssetTransfer {
+                    address,
+                    amount,
+                    native_tokens: Vec::new(),
+                    nft_id: None,
+                },
+                AssetTransfer {
+                    address,
+                    amount: 0,
+                    native_tokens: Vec::new(),
+                    nft_id: Some(nft_id),
+                },
+            ],
+            None,
+        )
+        .await?;
+    let TransactionEssence::Regular(essence) = transaction.payload.essence();
+    assert_eq!(essence.outputs().iter().filter(|output| output.is_nft()).count(), 1);
+    accounts[0]
+        .retry_transaction_until_included(&transaction.transaction_id, None, None)
+        .await?;
+
+    let balance = accounts[1].sync(None).await?;
+    assert_eq!(*balance.nfts().first().unwrap(), nft_id);
+    assert!(balance.base_coin().total() > amount);
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn sweep() -> Result<()> {
+    let storage_path = "test-storage/sweep";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+
+    let nft_options = vec![MintNftParams {
+        address: None,
+        sender: None,
+        metadata: None,
+        tag: None,
+        issuer: None,
+        immutable_metadata: Some(b"some immutable nft metadata".to_vec()),
+    }];
+    let transaction = account_0.mint_nfts(nft_options, None).await?.transaction;
+    account_0
+        .retry_transaction_until_included(&transaction.transaction_id, None, None)
+        .await?;
+    let balance = account_0.sync(None).await?;
+    let total_amount = balance.base_coin().total();
+
+    let transactions = account_0
+        .sweep(
+            *account_1.addresses().await?[0].address(),
+            SweepOptions {
+                nfts: true,
+                ..Default::default()
+            },
+        )
+        .await?;
+    assert_eq!(transactions.len(), 1);
+    account_0
+        .retry_transaction_until_included(&transactions[0].transaction_id, None, None)
+        .await?;
+
+    let balance = account_0.sync(None).await?;
+    assert_eq!(balance.base_coin().total(), 0);
+    let balance = account_1.sync(None).await?;
+    assert_eq!(balance.base_coin().total(), total_amount);
+    assert_eq!(balance.nfts().len(), 1);
+
+    // Nothing is left to sweep
+    assert!(
+        account_0
+            .sweep(*account_1.addresses().await?[0].address(), SweepOptions::default())
+            .await?
+            .is_empty()
+    );
+
+    tear_down(storage_path)
+}
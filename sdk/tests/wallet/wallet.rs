@@ -1,8 +1,15 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+#[cfg(feature = "encrypted_backup")]
+use std::path::PathBuf;
+
 #[cfg(feature = "stronghold")]
 use iota_sdk::client::secret::stronghold::StrongholdSecretManager;
+#[cfg(feature = "encrypted_backup")]
+use iota_sdk::client::Client;
+#[cfg(feature = "debug_bundle")]
+use iota_sdk::wallet::DebugBundleOptions;
 #[cfg(feature = "storage")]
 use iota_sdk::{
     client::node_manager::node::{Node, NodeDto},
@@ -13,8 +20,8 @@ use iota_sdk::{
         constants::IOTA_COIN_TYPE,
         secret::{mnemonic::MnemonicSecretManager, SecretManager},
     },
-    types::block::address::Bech32Address,
-    wallet::{ClientOptions, Result, Wallet},
+    types::block::{address::Bech32Address, protocol::ProtocolParameters},
+    wallet::{ClientOptions, Error, Result, Wallet},
 };
 
 use crate::wallet::common::{make_wallet, setup, tear_down, DEFAULT_MNEMONIC, NODE_LOCAL, NODE_OTHER};
@@ -52,6 +59,83 @@ async fn update_client_options() -> Result<()> {
     tear_down(storage_path)
 }
 
+#[tokio::test]
+async fn client_options_network_mismatch() -> Result<()> {
+    let storage_path = "test-storage/client_options_network_mismatch";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    wallet.create_account().finish().await?;
+
+    let current_options = wallet.client_options().await;
+    let protocol_parameters = current_options.network_info.protocol_parameters.clone();
+
+    // The wallet uses the Shimmer coin type, so it can't be used with an IOTA network
+    let mut client_options = current_options.clone();
+    client_options.network_info.protocol_parameters = ProtocolParameters::new(
+        protocol_parameters.protocol_version(),
+        protocol_parameters.network_name().to_string(),
+        "iota",
+        protocol_parameters.min_pow_score(),
+        protocol_parameters.below_max_depth(),
+        *protocol_parameters.rent_structure(),
+        protocol_parameters.token_supply(),
+    )?;
+    let error = wallet.set_client_options(client_options).await.unwrap_err();
+    assert!(matches!(error, Error::NetworkMismatch { coin_type: 4219, .. }));
+
+    // The previous network is restored
+    assert_eq!(wallet.client_options().await.network_info, current_options.network_info);
+
+    tear_down(storage_path)
+}
+
+#[cfg(all(feature = "mqtt", feature = "events"))]
+#[tokio::test]
+async fn client_options_reconnect_mqtt() -> Result<()> {
+    use iota_sdk::{
+        client::mqtt::BrokerOptions,
+        wallet::events::types::{WalletEvent, WalletEventType},
+    };
+
+    let storage_path = "test-storage/client_options_reconnect_mqtt";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let (sender, receiver) = std::sync::mpsc::channel();
+    wallet
+        .listen(vec![WalletEventType::ClientOptionsChanged], move |event| {
+            if let WalletEvent::ClientOptionsChanged(changed) = &event.event {
+                sender.send(*changed).ok();
+            }
+        })
+        .await;
+
+    let broker_options = BrokerOptions::new()
+        .use_ws(false)
+        .port(1884)
+        .timeout(std::time::Duration::from_secs(5))
+        .max_reconnection_attempts(3);
+    let mut client_options = wallet.client_options().await;
+    client_options.broker_options = broker_options;
+    wallet.set_client_options(client_options).await?;
+
+    // Only the MQTT connection got rebuilt, with all of the new broker options
+    let changed = receiver
+        .try_recv()
+        .expect("no client options changed event was emitted");
+    assert!(changed.mqtt);
+    assert!(!changed.nodes);
+    assert!(!changed.network_info);
+    assert_eq!(wallet.client_options().await.broker_options, broker_options);
+
+    // Setting the same options again doesn't reconnect
+    wallet.set_client_options(wallet.client_options().await).await?;
+    assert!(receiver.try_recv().is_err());
+
+    tear_down(storage_path)
+}
+
 #[cfg(feature = "storage")]
 #[tokio::test]
 async fn different_seed() -> Result<()> {
@@ -253,3 +337,132 @@ async fn update_node_auth() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[cfg(all(feature = "debug_bundle", feature = "testkit"))]
+#[tokio::test]
+async fn create_debug_bundle() -> Result<()> {
+    use std::io::Read;
+
+    use iota_sdk::wallet::testkit::Testkit;
+
+    // Returns the names of the files in the bundle and the content of the account file
+    fn read_bundle(path: &str) -> (Vec<String>, serde_json::Value) {
+        let mut zip = zip::ZipArchive::new(std::fs::File::open(path).unwrap()).unwrap();
+        let names = zip.file_names().map(ToString::to_string).collect::<Vec<_>>();
+        let mut account = String::new();
+        zip.by_name("accounts/0.json")
+            .unwrap()
+            .read_to_string(&mut account)
+            .unwrap();
+        (names, serde_json::from_str(&account).unwrap())
+    }
+
+    let storage_path = "test-storage/create_debug_bundle";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    let testkit = Testkit::new(&wallet, 1_680_000_000).await?;
+    let account = wallet.create_account().finish().await?;
+    let output_id = testkit.fund(&account, 1_000_000).await?;
+    let address = account.addresses().await?[0].address().to_string();
+
+    std::fs::create_dir_all(storage_path)?;
+    let plain_path = format!("{storage_path}/plain.zip");
+    wallet
+        .create_debug_bundle(
+            &plain_path,
+            DebugBundleOptions {
+                hash_identifiers: false,
+            },
+        )
+        .await?;
+    let (mut names, plain) = read_bundle(&plain_path);
+    names.sort();
+    let mut expected_names = vec!["accounts/0.json", "client.json", "info.json"];
+    #[cfg(feature = "events")]
+    expected_names.push("events.json");
+    expected_names.sort();
+    assert_eq!(names, expected_names);
+    assert_eq!(plain["publicAddresses"][0]["address"], address);
+    assert_eq!(plain["outputs"][0]["outputId"], output_id.to_string());
+    assert_eq!(plain["outputs"][0]["amount"], "1000000");
+
+    let hashed_paths = [
+        format!("{storage_path}/hashed_0.zip"),
+        format!("{storage_path}/hashed_1.zip"),
+    ];
+    for path in &hashed_paths {
+        wallet
+            .create_debug_bundle(path, DebugBundleOptions { hash_identifiers: true })
+            .await?;
+    }
+    let (_, hashed) = read_bundle(&hashed_paths[0]);
+    let (_, other_hashed) = read_bundle(&hashed_paths[1]);
+
+    // Neither the address nor the ids can be found in the bundle
+    let content = hashed.to_string();
+    assert!(!content.contains(&address));
+    assert!(!content.contains(&output_id.to_string()));
+    assert!(!content.contains(&output_id.transaction_id().to_string()));
+    // The address is hashed the same way within a bundle, the outputs can still be correlated with it
+    assert_eq!(hashed["publicAddresses"][0]["address"], hashed["outputs"][0]["address"]);
+    assert_eq!(hashed["outputs"][0]["amount"], "1000000");
+    // The key is random for each bundle, so the hashes can't be looked up
+    assert_ne!(
+        hashed["publicAddresses"][0]["address"],
+        other_hashed["publicAddresses"][0]["address"]
+    );
+    assert_ne!(hashed["outputs"][0]["outputId"], other_hashed["outputs"][0]["outputId"]);
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "encrypted_backup")]
+#[tokio::test]
+async fn backup_and_restore_encrypted_json() -> Result<()> {
+    let storage_path = "test-storage/backup_and_restore_encrypted_json";
+    setup(storage_path)?;
+
+    let mnemonic = Client::generate_mnemonic()?;
+    let wallet = make_wallet(&format!("{storage_path}/1"), Some(&mnemonic), None).await?;
+    let account = wallet.create_account().with_alias("Alice".to_string()).finish().await?;
+
+    std::fs::create_dir_all(storage_path)?;
+    let backup_path = PathBuf::from(format!("{storage_path}/backup.json"));
+    wallet
+        .backup_to_encrypted_json(backup_path.clone(), "password".to_string())
+        .await?;
+
+    // The accounts can't be restored with another mnemonic
+    let other_wallet = make_wallet(&format!("{storage_path}/3"), None, None).await?;
+    assert!(matches!(
+        other_wallet
+            .restore_from_encrypted_json(backup_path.clone(), "password".to_string())
+            .await,
+        Err(Error::Backup(_))
+    ));
+    assert!(other_wallet.get_accounts().await?.is_empty());
+
+    // The mnemonic secret manager needs to be provided again
+    let restore_wallet = make_wallet(&format!("{storage_path}/2"), Some(&mnemonic), None).await?;
+
+    assert!(matches!(
+        restore_wallet
+            .restore_from_encrypted_json(backup_path.clone(), "wrong password".to_string())
+            .await,
+        Err(Error::Backup(_))
+    ));
+
+    restore_wallet
+        .restore_from_encrypted_json(backup_path, "password".to_string())
+        .await?;
+
+    let restored_account = restore_wallet.get_account("Alice").await?;
+    assert_eq!(account.addresses().await?, restored_account.addresses().await?);
+    assert_eq!(
+        account.generate_addresses(1, None).await?,
+        restored_account.generate_addresses(1, None).await?
+    );
+
+    tear_down(storage_path)
+}
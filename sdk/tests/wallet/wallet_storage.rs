@@ -8,7 +8,7 @@ use iota_sdk::{
     wallet::{Result, Wallet},
 };
 
-use crate::wallet::common::{setup, tear_down};
+use crate::wallet::common::{make_wallet, setup, tear_down};
 
 #[cfg(all(feature = "stronghold", feature = "rocksdb"))]
 #[tokio::test]
@@ -83,6 +83,29 @@ async fn check_existing_db() -> Result<()> {
     tear_down(storage_path)
 }
 
+#[cfg(feature = "rocksdb")]
+#[tokio::test]
+async fn compact_storage() -> Result<()> {
+    let storage_path = "test-storage/compact_storage";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+    for _ in 0..3 {
+        wallet.create_account().finish().await?;
+    }
+    wallet.remove_latest_account().await?;
+
+    let compaction = wallet.compact_storage().await?;
+    assert!(compaction.size_before.is_some());
+    assert!(compaction.size_after.is_some());
+    assert!(compaction.reclaimed().is_some());
+
+    // The accounts are still there after the compaction
+    assert_eq!(wallet.get_accounts().await?.len(), 2);
+
+    tear_down(storage_path)
+}
+
 fn copy_folder(src: impl AsRef<Path>, dest: impl AsRef<Path>) -> io::Result<()> {
     fs::create_dir_all(&dest)?;
     for entry in fs::read_dir(src)? {